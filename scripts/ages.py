@@ -0,0 +1,31 @@
+#!/usr/bin/env python3
+
+# Regenerates the `AGES` table in `src/unicode.rs` from `DerivedAge.txt`. Neighbouring ranges from
+# the same version are merged.
+#
+#     python3 scripts/ages.py [DerivedAge.txt]
+
+import sys
+from ucd import VERSION, codepoint_range, pack, read, records, replace
+
+URL = f'https://www.unicode.org/Public/{VERSION}/ucd/DerivedAge.txt'
+
+
+# Each line is `range ; version`, like `0000..001F ; 1.1`.
+def main(argv):
+    ranges = []
+
+    for (start, end), age in sorted((codepoint_range(field), age)
+                                    for field, age in records(read(argv, URL))):
+        if ranges and ranges[-1][1] == start - 1 and ranges[-1][2] == age:
+            ranges[-1][1] = end
+        else:
+            ranges.append([start, end, age])
+
+    entries = pack(f'(0x{start:X}, 0x{end:X}, {age.replace(".", ", ")})'
+                   for start, end, age in ranges)
+    replace('AGES', 'const AGES: &[(u32, u32, u8, u8)] = &[\n' + '\n'.join(entries) + '\n];')
+
+
+if __name__ == '__main__':
+    main(sys.argv)
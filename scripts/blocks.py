@@ -0,0 +1,21 @@
+#!/usr/bin/env python3
+
+# Regenerates the `BLOCKS` table in `src/unicode.rs` from `Blocks.txt`.
+#
+#     python3 scripts/blocks.py [Blocks.txt]
+
+import sys
+from ucd import VERSION, codepoint_range, read, records, replace
+
+URL = f'https://www.unicode.org/Public/{VERSION}/ucd/Blocks.txt'
+
+
+# Each line is `range ; name`, like `0000..007F; Basic Latin`.
+def main(argv):
+    blocks = sorted((codepoint_range(field), name) for field, name in records(read(argv, URL)))
+    entries = (f'    (0x{start:04X}, 0x{end:04X}, "{name}"),' for (start, end), name in blocks)
+    replace('BLOCKS', 'const BLOCKS: &[(u32, u32, &str)] = &[\n' + '\n'.join(entries) + '\n];')
+
+
+if __name__ == '__main__':
+    main(sys.argv)
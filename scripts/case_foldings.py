@@ -0,0 +1,24 @@
+#!/usr/bin/env python3
+
+# Regenerates the `FULL_CASE_FOLDINGS` table in `src/unicode.rs` from `CaseFolding.txt`.
+#
+#     python3 scripts/case_foldings.py [CaseFolding.txt]
+
+import sys
+from ucd import VERSION, chars, literal, pack, read, records, replace
+
+URL = f'https://www.unicode.org/Public/{VERSION}/ucd/CaseFolding.txt'
+
+
+# Each line is `code; status; mapping;`. Only the full foldings with status `F` are kept, because
+# the common ones with status `C` come from `unicode-case-mapping`.
+def main(argv):
+    foldings = sorted((int(code, 16), chars(mapping))
+                      for code, status, mapping, *_ in records(read(argv, URL)) if status == 'F')
+    entries = pack(f'(0x{c:X}, "{literal(mapping)}")' for c, mapping in foldings)
+    replace('FULL_CASE_FOLDINGS',
+            'const FULL_CASE_FOLDINGS: &[(u32, &str)] = &[\n' + '\n'.join(entries) + '\n];')
+
+
+if __name__ == '__main__':
+    main(sys.argv)
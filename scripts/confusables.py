@@ -0,0 +1,23 @@
+#!/usr/bin/env python3
+
+# Regenerates the `CONFUSABLES` table in `src/unicode.rs` from `confusables.txt` in UTS #39.
+#
+#     python3 scripts/confusables.py [confusables.txt]
+
+import sys
+from ucd import VERSION, chars, literal, pack, read, records, replace
+
+URL = f'https://www.unicode.org/Public/security/{VERSION}/confusables.txt'
+
+
+# Each line is `source ; prototype ; type`, with codepoints in hexadecimal.
+def main(argv):
+    prototypes = sorted((int(source, 16), chars(prototype))
+                        for source, prototype, _ in records(read(argv, URL)))
+
+    entries = pack(f'(0x{c:X}, "{literal(prototype)}")' for c, prototype in prototypes)
+    replace('CONFUSABLES', 'const CONFUSABLES: &[(u32, &str)] = &[\n' + '\n'.join(entries) + '\n];')
+
+
+if __name__ == '__main__':
+    main(sys.argv)
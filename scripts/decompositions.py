@@ -0,0 +1,40 @@
+#!/usr/bin/env python3
+
+# Regenerates the `DECOMPOSITIONS` table in `src/unicode.rs` from `UnicodeData.txt`.
+#
+#     python3 scripts/decompositions.py [UnicodeData.txt]
+
+import sys
+from ucd import VERSION, chars, literal, pack, read, records, replace
+
+URL = f'https://www.unicode.org/Public/{VERSION}/ucd/UnicodeData.txt'
+
+
+# The sixth field is the mapping, after a tag like `<noBreak>` unless it's canonical. Hangul
+# syllables are a range with no mapping, because theirs are computed.
+def main(argv):
+    decompositions = []
+
+    for fields in records(read(argv, URL)):
+        tag, _, mapping = fields[5].rpartition('>')
+        if mapping:
+            kind = tag.lstrip('<').capitalize() or 'Canonical'
+            decompositions.append((int(fields[0], 16), kind, chars(mapping)))
+
+    entries = pack(
+        (f'(0x{c:X}, Dt::{kind}, "{literal(mapping)}")' for c, kind, mapping in decompositions),
+        indent='        ',
+    )
+    replace('DECOMPOSITIONS', '\n'.join([
+        'const DECOMPOSITIONS: &[(u32, DecompositionType, &str)] = {',
+        '    use DecompositionType as Dt;',
+        '',
+        '    &[',
+        *entries,
+        '    ]',
+        '};',
+    ]))
+
+
+if __name__ == '__main__':
+    main(sys.argv)
@@ -0,0 +1,79 @@
+# Helpers for the scripts that regenerate the tables in `src/unicode.rs` from the Unicode Character
+# Database. Each script downloads its file for `VERSION`, or reads it from the path it's given.
+
+import re
+import sys
+import urllib.request
+from pathlib import Path
+
+VERSION = '16.0.0'
+TABLES = Path(__file__).resolve().parent.parent / 'src' / 'unicode.rs'
+WIDTH = 100
+
+
+def read(argv, url):
+    if len(argv) > 1:
+        return Path(argv[1]).read_text(encoding='utf-8-sig')
+
+    with urllib.request.urlopen(url) as response:
+        return response.read().decode('utf-8-sig')
+
+
+# The semicolon-separated fields of each line, without comments and blank lines.
+def records(text):
+    for line in text.splitlines():
+        line = line.split('#', 1)[0].strip()
+        if line:
+            yield [field.strip() for field in line.split(';')]
+
+
+# A range like `0000..007F`, or a single codepoint.
+def codepoint_range(field):
+    start, _, end = field.partition('..')
+    return int(start, 16), int(end or start, 16)
+
+
+def chars(field):
+    return ''.join(chr(int(c, 16)) for c in field.split())
+
+
+# Printable ASCII is kept as it is, except for quotes and backslashes, and everything else is
+# escaped, so the tables don't depend on how an editor displays them.
+def literal(s):
+    return ''.join(c if ' ' <= c <= '~' and c not in '"\\' else f'\\u{{{ord(c):X}}}' for c in s)
+
+
+# Packs entries into lines of at most `WIDTH` columns. An entry that's too long for a line of its
+# own is continued on the next with a backslash, which is put before an escape because the
+# whitespace after it is skipped.
+def pack(entries, indent='    '):
+    lines, line = [], indent[:-1]
+
+    for entry in entries:
+        entry += ','
+
+        if len(line) + 1 + len(entry) <= WIDTH:
+            line += ' ' + entry
+            continue
+
+        lines.append(line)
+        line = indent + entry
+
+        while len(line) > WIDTH:
+            split = max(m.start() for m in re.finditer(r'\\u\{', line[:WIDTH - 1]))
+            lines.append(line[:split] + '\\')
+            line = indent + '    ' + line[split:]
+
+    return lines + [line]
+
+
+# Replaces the constant with the given name, from its declaration to the closing bracket or brace
+# at the start of a line.
+def replace(name, table):
+    source = TABLES.read_text(encoding='utf-8')
+    pattern = rf'const {name}: .*?\n[\]}}];'
+    source, count = re.subn(pattern, lambda _: table, source, flags=re.S)
+    if count != 1:
+        sys.exit(f'{TABLES}: expected one `{name}` table, found {count}')
+
+    TABLES.write_text(source, encoding='utf-8')
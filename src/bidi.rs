@@ -0,0 +1,187 @@
+use unicode_bidi::BidiInfo;
+use crate::{span_of, BidiClass, Codepoint, Direction, Span, Text};
+
+// A paragraph of text after running the Unicode Bidirectional Algorithm (UAX #9) over it, treating
+// the whole paragraph as a single line.
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    codepoints: Vec<Codepoint>,
+    level: u8,
+    levels: Vec<Option<u8>>,
+    visual: Vec<usize>,
+    unmatched: Vec<usize>,
+    nested: Vec<usize>,
+}
+
+impl Paragraph {
+    // The paragraph embedding level, which is even for left-to-right paragraphs and odd for
+    // right-to-left ones.
+    pub fn level(&self) -> u8 { self.level }
+
+    pub fn direction(&self) -> Direction {
+        if self.level.is_multiple_of(2) { Direction::Ltr } else { Direction::Rtl }
+    }
+
+    pub fn span(&self) -> Span { span_of(self.codepoints.iter()) }
+
+    // The codepoints of the paragraph in logical (memory) order, including its separator.
+    pub fn codepoints(&self) -> impl Iterator<Item = &Codepoint> { self.codepoints.iter() }
+
+    // The resolved embedding level of each codepoint in logical order, after trailing whitespace is
+    // reset by rule L1. Explicit embeddings and overrides and boundary neutrals have no level,
+    // because they're removed by rule X9.
+    pub fn levels(&self) -> &[Option<u8>] { &self.levels }
+
+    // The codepoints of the paragraph in the order they're displayed from left to right.
+    pub fn visual(&self) -> impl Iterator<Item = &Codepoint> {
+        self.visual.iter().map(|&i| &self.codepoints[i])
+    }
+
+    // Embeddings, overrides and isolates that aren't terminated in the paragraph, and terminators
+    // that don't match anything. Embeddings that are terminated by the end of an isolate instead of
+    // a PDF are unmatched too.
+    pub fn unmatched(&self) -> impl Iterator<Item = &Codepoint> {
+        self.unmatched.iter().map(|&i| &self.codepoints[i])
+    }
+
+    // Embeddings, overrides and isolates that start inside another one.
+    pub fn nested(&self) -> impl Iterator<Item = &Codepoint> {
+        self.nested.iter().map(|&i| &self.codepoints[i])
+    }
+
+    // The paragraph as it's displayed from left to right, without explicit formatting characters or
+    // its separator.
+    pub fn displayed(&self) -> String {
+        self.visual()
+            .filter(|codepoint| !explicit(codepoint))
+            .filter(|codepoint| codepoint.bidi_class() != Some(BidiClass::ParagraphSeparator))
+            .map(|codepoint| codepoint.value().unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    }
+}
+
+// Splits text into paragraphs and resolves their embedding levels and visual order. Surrogates and
+// invalid data are treated like U+FFFD REPLACEMENT CHARACTER, which is a neutral.
+pub fn paragraphs(text: &Text) -> Vec<Paragraph> {
+    let codepoints = text.codepoints().cloned().collect::<Vec<_>>();
+    let string = codepoints.iter()
+        .map(|codepoint| codepoint.value().unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect::<String>();
+
+    let info = BidiInfo::new(&string, None);
+    let mut start = 0;
+
+    info.paragraphs.iter()
+        .map(|paragraph| {
+            let levels = info.reordered_levels_per_char(paragraph, paragraph.range.clone());
+            let len = string[paragraph.range.clone()].chars().count();
+            let range = start..start + len;
+            let (codepoints, levels) = (&codepoints[range.clone()], &levels[range]);
+            let (unmatched, nested) = matching(codepoints);
+            start += len;
+
+            Paragraph {
+                codepoints: codepoints.to_vec(),
+                level: paragraph.level.number(),
+                levels: codepoints.iter()
+                    .zip(levels)
+                    .map(|(codepoint, level)| (!removed(codepoint)).then(|| level.number()))
+                    .collect(),
+                visual: BidiInfo::reorder_visual(levels),
+                unmatched,
+                nested,
+            }
+        })
+        .collect()
+}
+
+// Whether a codepoint is ignored by rule X9.
+fn removed(codepoint: &Codepoint) -> bool {
+    use BidiClass as Bidi;
+
+    matches!(
+        codepoint.bidi_class(),
+        Some(
+            Bidi::LeftToRightEmbedding |
+            Bidi::RightToLeftEmbedding |
+            Bidi::LeftToRightOverride |
+            Bidi::RightToLeftOverride |
+            Bidi::PopDirectionalFormat |
+            Bidi::BoundaryNeutral
+        ),
+    )
+}
+
+// Whether a codepoint is an embedding, override, isolate or terminator.
+fn explicit(codepoint: &Codepoint) -> bool {
+    use BidiClass as Bidi;
+
+    matches!(
+        codepoint.bidi_class(),
+        Some(
+            Bidi::LeftToRightEmbedding |
+            Bidi::RightToLeftEmbedding |
+            Bidi::LeftToRightOverride |
+            Bidi::RightToLeftOverride |
+            Bidi::PopDirectionalFormat |
+            Bidi::LeftToRightIsolate |
+            Bidi::RightToLeftIsolate |
+            Bidi::FirstStrongIsolate |
+            Bidi::PopDirectionalIsolate
+        ),
+    )
+}
+
+// Matches explicit formatting characters like rules X1 to X8 do, ignoring overflow, and returns the
+// ones that are unmatched and the ones that are nested.
+fn matching(codepoints: &[Codepoint]) -> (Vec<usize>, Vec<usize>) {
+    use BidiClass as Bidi;
+
+    // The positions of the embeddings and isolates that are still open, and whether each one is an
+    // isolate.
+    let mut open = Vec::<(usize, bool)>::new();
+    let (mut unmatched, mut nested) = (Vec::new(), Vec::new());
+
+    for (i, codepoint) in codepoints.iter().enumerate() {
+        let Some(class) = codepoint.bidi_class() else { continue };
+
+        // Whether an embedding or isolate was opened, and if so, which.
+        let opened = match class {
+            Bidi::LeftToRightEmbedding |
+            Bidi::RightToLeftEmbedding |
+            Bidi::LeftToRightOverride |
+            Bidi::RightToLeftOverride => Some(false),
+            Bidi::LeftToRightIsolate |
+            Bidi::RightToLeftIsolate |
+            Bidi::FirstStrongIsolate => Some(true),
+            Bidi::PopDirectionalFormat => {
+                match open.last() {
+                    Some((_, false)) => { open.pop(); },
+                    _ => unmatched.push(i),
+                }
+
+                None
+            },
+            Bidi::PopDirectionalIsolate => {
+                match open.iter().rposition(|&(_, isolate)| isolate) {
+                    Some(isolate) => {
+                        unmatched.extend(open.drain(isolate..).skip(1).map(|(i, _)| i));
+                    },
+                    None => unmatched.push(i),
+                }
+
+                None
+            },
+            _ => None,
+        };
+
+        if let Some(isolate) = opened {
+            if !open.is_empty() { nested.push(i) }
+            open.push((i, isolate));
+        }
+    }
+
+    unmatched.extend(open.into_iter().map(|(i, _)| i));
+    unmatched.sort();
+    (unmatched, nested)
+}
@@ -0,0 +1,125 @@
+use unicode_segmentation::UnicodeSegmentation;
+use crate::{Text, normalize, unicode::Character};
+
+// The case conversions from section 3.13 of the Unicode Standard.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Case {
+    Upper,
+    Lower,
+    Title,
+    Folded,
+}
+
+// The languages with their own rules in `SpecialCasing.txt` and `CaseFolding.txt`. Turkish and
+// Azerbaijani share theirs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum CaseLocale {
+    #[default]
+    Default,
+    Turkic,
+    Lithuanian,
+}
+
+impl Case {
+    pub const ALL: [Case; 4] = [Self::Upper, Self::Lower, Self::Title, Self::Folded];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Case::Upper => "uppercase",
+            Case::Lower => "lowercase",
+            Case::Title => "titlecase",
+            Case::Folded => "case folding",
+        }
+    }
+}
+
+// Converts each run of valid text separately, keeping surrogates and invalid data as they are.
+// Titlecasing maps the first cased character of each word to titlecase and the rest to lowercase.
+pub fn to_case(text: &Text, case: Case, locale: CaseLocale) -> Text {
+    normalize::map(text, |s| {
+        let chars = s.chars().collect::<Vec<_>>();
+        let mut mapped = String::new();
+
+        if case != Case::Title {
+            for i in 0..chars.len() { mapped.extend(map(&chars, i, case, locale)) }
+            return mapped;
+        }
+
+        let mut i = 0;
+        for word in s.split_word_bounds() {
+            let mut cased = false;
+            for _ in word.chars() {
+                let case = if cased || !chars[i].cased() { Case::Lower } else { Case::Title };
+                cased |= chars[i].cased();
+                mapped.extend(map(&chars, i, case, locale));
+                i += 1;
+            }
+        }
+
+        mapped
+    })
+}
+
+// Maps one character with the rules that depend on its context and language, or its full case
+// mapping if none of them apply.
+fn map(chars: &[char], i: usize, case: Case, locale: CaseLocale) -> Vec<char> {
+    use Case::*;
+    use CaseLocale::*;
+
+    let c = chars[i];
+    match (case, locale, c) {
+        (Lower, _, 'Σ') if final_sigma(chars, i) => vec!['ς'],
+
+        (Lower, Turkic, 'İ') | (Folded, Turkic, 'İ') => vec!['i'],
+        (Lower, Turkic, '\u{0307}') if after_i(chars, i) => vec![],
+        (Lower, Turkic, 'I') if !before_dot(chars, i) => vec!['ı'],
+        (Folded, Turkic, 'I') => vec!['ı'],
+        (Upper | Title, Turkic, 'i') => vec!['İ'],
+
+        (Lower, Lithuanian, 'I' | 'J' | 'Į') if more_above(chars, i) => {
+            c.lowercase().into_iter().chain(['\u{0307}']).collect()
+        },
+        (Lower, Lithuanian, 'Ì') => vec!['i', '\u{0307}', '\u{0300}'],
+        (Lower, Lithuanian, 'Í') => vec!['i', '\u{0307}', '\u{0301}'],
+        (Lower, Lithuanian, 'Ĩ') => vec!['i', '\u{0307}', '\u{0303}'],
+        (Upper | Title, Lithuanian, '\u{0307}') if after_soft_dotted(chars, i) => vec![],
+
+        (Upper, ..) => c.uppercase(),
+        (Lower, ..) => c.lowercase(),
+        (Title, ..) => c.titlecase(),
+        (Folded, ..) => c.case_folding(),
+    }
+}
+
+// A capital sigma at the end of a word, which is preceded by a cased letter and not followed by
+// one, skipping case-ignorable characters like apostrophes and accents.
+fn final_sigma(chars: &[char], i: usize) -> bool {
+    let cased = |c: Option<&char>| c.is_some_and(|c| c.cased());
+
+    cased(chars[..i].iter().rev().find(|c| !c.case_ignorable()))
+        && !cased(chars[i + 1..].iter().find(|c| !c.case_ignorable()))
+}
+
+// Whether the first character that matches or that has a combining class of 0 (a base character)
+// or 230 (Above) matches. The other combining marks in between don't affect the rules.
+fn find_mark(mut chars: impl Iterator<Item = char>, f: impl Fn(char) -> bool) -> bool {
+    chars
+        .find(|&c| f(c) || matches!(c.canonical_combining_class(), 0 | 230))
+        .is_some_and(f)
+}
+
+fn after_i(chars: &[char], i: usize) -> bool {
+    find_mark(chars[..i].iter().copied().rev(), |c| c == 'I')
+}
+
+fn after_soft_dotted(chars: &[char], i: usize) -> bool {
+    find_mark(chars[..i].iter().copied().rev(), |c| c.soft_dotted())
+}
+
+fn before_dot(chars: &[char], i: usize) -> bool {
+    find_mark(chars[i + 1..].iter().copied(), |c| c == '\u{0307}')
+}
+
+fn more_above(chars: &[char], i: usize) -> bool {
+    find_mark(chars[i + 1..].iter().copied(), |c| c.canonical_combining_class() == 230)
+}
@@ -0,0 +1,734 @@
+use std::{
+    collections::BTreeSet, ffi::{OsStr, OsString}, fs::File, io::{self, BufRead, BufReader},
+    path::PathBuf,
+};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use unicode_analyze::{
+    Age, Bracket, ByteOrder, CaseLocale, Codepoint, Diacritic, Direction, Encoding, EscapeStyle,
+    Grapheme, InvalidKind, Kind, NormalizationForm, Offset, RestrictionLevel, Script, Text,
+    TextReader,
+};
+
+// The version of the JSON output schema, which changes whenever a field is changed or removed.
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, ValueEnum)]
+pub enum Format {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// A single JSON document
+    Json,
+    /// One JSON object per line, as soon as it's available
+    Ndjson,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum Order {
+    /// Big-endian
+    Be,
+    /// Little-endian
+    Le,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum InputEncoding {
+    #[value(name = "utf-8", alias = "utf8")]
+    Utf8,
+    #[value(name = "wtf-8", alias = "wtf8")]
+    Wtf8,
+    #[value(name = "utf-16le", alias = "utf16le")]
+    Utf16Le,
+    #[value(name = "utf-16be", alias = "utf16be")]
+    Utf16Be,
+    #[value(name = "utf-32le", alias = "utf32le")]
+    Utf32Le,
+    #[value(name = "utf-32be", alias = "utf32be")]
+    Utf32Be,
+    #[value(name = "latin-1", alias = "latin1", alias = "iso-8859-1")]
+    Latin1,
+    #[value(name = "windows-1252", alias = "cp1252")]
+    Windows1252,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum Normalization {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum Language {
+    /// Azerbaijani
+    Az,
+    /// Lithuanian
+    Lt,
+    /// Turkish
+    Tr,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum Level {
+    AsciiOnly,
+    SingleScript,
+    HighlyRestrictive,
+    ModeratelyRestrictive,
+    MinimallyRestrictive,
+    Unrestricted,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+pub enum Escape {
+    Rust,
+    C,
+    Json,
+    Python,
+    Js,
+    Html,
+    Url,
+}
+
+// Optional information shown for each codepoint in text output.
+#[derive(Debug, Copy, Clone, Default, Args)]
+pub struct Columns {
+    /// Show the byte, char and UTF-16 offset of each codepoint, separated by colons
+    #[arg(long)]
+    pub offsets: bool,
+
+    /// Show the UTF-8 bytes of each codepoint
+    #[arg(long)]
+    pub bytes: bool,
+
+    /// Show the UTF-16 code units of each codepoint
+    #[arg(long, value_name = "ORDER", num_args = 0..=1, default_missing_value = "be")]
+    pub utf16: Option<Order>,
+
+    /// Show the UTF-32 code unit of each codepoint
+    #[arg(long, value_name = "ORDER", num_args = 0..=1, default_missing_value = "be")]
+    pub utf32: Option<Order>,
+
+    /// Show the General Category of each codepoint, like 'Lu Uppercase_Letter'
+    #[arg(long)]
+    pub category: bool,
+
+    /// Show the Bidi_Class of each codepoint, and whether it's mirrored or a paired bracket
+    #[arg(long)]
+    pub bidi: bool,
+
+    /// Show the script of each codepoint, and the other scripts it's used with
+    #[arg(long)]
+    pub script: bool,
+
+    /// Show the block that each codepoint is in
+    #[arg(long)]
+    pub block: bool,
+
+    /// Show the version of Unicode that each codepoint was added in
+    #[arg(long)]
+    pub age: bool,
+
+    /// Show the canonical combining class of each codepoint, and what it decomposes to
+    #[arg(long)]
+    pub decomposition: bool,
+
+    /// Show full canonical and compatibility decompositions, instead of just the first step
+    #[arg(long)]
+    pub full_decomposition: bool,
+
+    /// Show the characters that each confusable codepoint looks like, from UTS #39
+    #[arg(long)]
+    pub confusable: bool,
+
+    /// Show whether each codepoint is White_Space or a Default_Ignorable_Code_Point
+    #[arg(long)]
+    pub properties: bool,
+}
+
+#[derive(Clone)]
+pub enum Input {
+    Argument(usize, OsString),
+    File(PathBuf),
+    Stdin,
+}
+
+pub type Texts<'a> = &'a mut dyn Iterator<Item = io::Result<Text>>;
+
+// How inputs are turned into text.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Decoder {
+    pub encoding: Option<Encoding>,
+    pub unescape: bool,
+    pub normalize: Option<NormalizationForm>,
+}
+
+pub fn for_each_input(
+    inputs: Vec<Input>,
+    decoder: Decoder,
+    mut f: impl FnMut(&Input, Texts) -> io::Result<()>,
+) -> bool {
+    let mut success = true;
+
+    for input in inputs {
+        let result = match &input {
+            Input::Argument(_, arg) => decoder.argument(arg)
+                .and_then(|text| f(&input, &mut [Ok(text)].into_iter()))
+                .map_err(|e| e.to_string()),
+            Input::File(path) => File::open(path)
+                .and_then(|file| decoder.read(BufReader::new(file), |texts| f(&input, texts)))
+                .map_err(|e| format!("{}: {e}", path.display())),
+            Input::Stdin => decoder.read(io::stdin().lock(), |texts| f(&input, texts))
+                .map_err(|e| format!("standard input: {e}")),
+        };
+
+        if let Err(e) = result {
+            eprintln!("unicode-analyze: {e}");
+            success = false;
+        }
+    }
+
+    success
+}
+
+// Without an encoding, arguments are decoded as the platform does, and files and standard input
+// are detected from their byte order marks or read as UTF-8. Escaped input is read all at once, and
+// is unescaped after it's decoded. Input that's normalized is read all at once too, so that its
+// offsets stay in order.
+impl Decoder {
+    fn argument(&self, arg: &OsStr) -> io::Result<Text> {
+        let text = match self.encoding {
+            Some(encoding) => Text::decode(arg.as_encoded_bytes(), encoding),
+            None => Text::parse_os_str(arg),
+        };
+
+        Ok(self.normalized(self.unescaped(text)?))
+    }
+
+    fn read<R: BufRead>(
+        &self,
+        mut reader: R,
+        f: impl FnOnce(Texts) -> io::Result<()>,
+    ) -> io::Result<()> {
+        if self.unescape || self.normalize.is_some() {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+
+            let encoding = self.encoding.or_else(|| Encoding::sniff(&bytes)).unwrap_or_default();
+            let text = self.unescaped(Text::decode(&bytes, encoding))?;
+            return f(&mut [Ok(self.normalized(text))].into_iter());
+        }
+
+        match self.encoding {
+            Some(encoding) => f(&mut TextReader::with_encoding(reader, encoding)),
+            None => f(&mut TextReader::detect_encoding(reader, Encoding::Utf8)?),
+        }
+    }
+
+    // The escapes are unescaped as UTF-8, whatever the input was encoded as, and the result is read
+    // as WTF-8 so that escaped surrogates are kept. Invalid data stays as the bytes it was.
+    fn unescaped(&self, text: Text) -> io::Result<Text> {
+        if !self.unescape { return Ok(text) }
+
+        let mut escaped = Vec::new();
+        for codepoint in text.codepoints() {
+            match codepoint.value() {
+                Ok(c) => escaped.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+                Err(bytes) => escaped.extend_from_slice(bytes),
+            }
+        }
+
+        Text::unescape(&escaped).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn normalized(&self, text: Text) -> Text {
+        match self.normalize {
+            Some(form) => text.normalize(form),
+            None => text,
+        }
+    }
+}
+
+impl Input {
+    pub fn print_header(&self) {
+        match self {
+            Input::Argument(..) => (),
+            Input::File(path) => println!("==> {} <==", path.display()),
+            Input::Stdin => println!("==> standard input <=="),
+        }
+    }
+}
+
+// Prints analyses of inputs in any format, streaming JSON output as it goes. In JSON, each input
+// ends with the scripts used in it. Characters added after the maximum age are reported as errors.
+pub struct Analysis {
+    format: Format,
+    columns: Columns,
+    max_age: Option<Age>,
+    inputs: usize,
+    graphemes: usize,
+    scripts: BTreeSet<Script>,
+    too_new: bool,
+}
+
+impl Analysis {
+    pub fn new(format: Format, columns: Columns, max_age: Option<Age>) -> Self {
+        if format == Format::Json { print!(r#"{{"version":{SCHEMA_VERSION},"inputs":["#) }
+        let scripts = BTreeSet::new();
+        Analysis { format, columns, max_age, inputs: 0, graphemes: 0, scripts, too_new: false }
+    }
+
+    pub fn input(&mut self, input: &Input, texts: Texts) -> io::Result<()> {
+        self.graphemes = 0;
+        self.scripts.clear();
+
+        match self.format {
+            Format::Text => input.print_header(),
+            Format::Json => {
+                if self.inputs > 0 { print!(",") }
+                print!(r#"{{"source":{},"graphemes":["#, to_json(&Source::new(input)));
+            },
+            Format::Ndjson => (),
+        }
+
+        let result = (|| {
+            for text in texts { self.text(input, &text?) }
+            Ok(())
+        })();
+
+        if self.format == Format::Json {
+            let scripts = self.scripts.iter().map(|s| s.short_name()).collect::<Vec<_>>();
+            print!(r#"],"scripts":{}}}"#, to_json(&scripts));
+        }
+
+        self.inputs += 1;
+        result
+    }
+
+    fn text(&mut self, input: &Input, text: &Text) {
+        if self.format == Format::Text { println!("{text}") }
+        self.scripts.extend(text.scripts());
+
+        for grapheme in text.graphemes().filter(|g| !g.is_empty()) {
+            for codepoint in grapheme.codepoints() { self.check_age(input, codepoint) }
+
+            match self.format {
+                Format::Text => for codepoint in grapheme.codepoints() {
+                    print_codepoint(codepoint, self.columns);
+                },
+                Format::Json => {
+                    if self.graphemes > 0 { print!(",") }
+                    print!("{}", to_json(&GraphemeRecord::new(grapheme)));
+                },
+                Format::Ndjson => println!("{}", to_json(&NdjsonRecord {
+                    source: Source::new(input),
+                    grapheme: GraphemeRecord::new(grapheme),
+                })),
+            }
+
+            self.graphemes += 1;
+        }
+    }
+
+    fn check_age(&mut self, input: &Input, codepoint: &Codepoint) {
+        let Some(max_age) = self.max_age else { return };
+        let Some(age) = codepoint.age().filter(|&age| age > max_age) else { return };
+        let path = match input {
+            Input::Argument(..) => String::new(),
+            Input::File(path) => format!("{}: ", path.display()),
+            Input::Stdin => "standard input: ".to_owned(),
+        };
+
+        eprintln!(
+            "unicode-analyze: {path}{} {} at byte {} was added in Unicode {age}, after {max_age}",
+            codepoint.display_value(),
+            codepoint.display_name(),
+            codepoint.offset().byte,
+        );
+
+        self.too_new = true;
+    }
+
+    // Returns whether every character was old enough.
+    pub fn finish(self) -> bool {
+        if self.format == Format::Json { println!("]}}") }
+        !self.too_new
+    }
+}
+
+pub fn print_codepoints<'a>(
+    format: Format,
+    columns: Columns,
+    codepoints: impl IntoIterator<Item = &'a Codepoint>,
+) {
+    if format == Format::Json { print!(r#"{{"version":{SCHEMA_VERSION},"codepoints":["#) }
+
+    for (i, codepoint) in codepoints.into_iter().enumerate() {
+        let record = || to_json(&CodepointRecord::new(codepoint, false));
+
+        match format {
+            Format::Text => print_codepoint(codepoint, columns),
+            Format::Json => print!("{}{}", if i > 0 { "," } else { "" }, record()),
+            Format::Ndjson => println!("{}", record()),
+        }
+    }
+
+    if format == Format::Json { println!("]}}") }
+}
+
+pub fn print_codepoint(codepoint: &Codepoint, columns: Columns) {
+    let (value, character, name) = (
+        codepoint.display_value(),
+        codepoint.display_character(),
+        codepoint.display_name(),
+    );
+
+    if columns.offsets {
+        let Offset { byte, char, utf16 } = codepoint.offset();
+        print!("{byte}:{char}:{utf16} ");
+    }
+
+    print!("{value} {character} ");
+
+    if columns.bytes { print!("[{}] ", codepoint.display_bytes()) }
+    if let Some(order) = columns.utf16 { print!("[{}] ", codepoint.display_utf16(order.into())) }
+    if let Some(order) = columns.utf32 { print!("[{}] ", codepoint.display_utf32(order.into())) }
+
+    if let Some(category) = codepoint.general_category().filter(|_| columns.category) {
+        print!("[{} {}] ", category.short_name(), category.long_name());
+    }
+
+    if let Some(class) = codepoint.bidi_class().filter(|_| columns.bidi) {
+        print!("[{} {}", class.short_name(), class.long_name());
+        if codepoint.bidi_mirrored() { print!(", mirrored") }
+
+        match codepoint.bidi_paired_bracket() {
+            Some(Bracket::Open(pair)) => print!(", opens '{pair}'"),
+            Some(Bracket::Close(pair)) => print!(", closes '{pair}'"),
+            None => (),
+        }
+
+        print!("] ");
+    }
+
+    if let Some(script) = codepoint.script().filter(|_| columns.script) {
+        let extensions = codepoint.script_extensions().unwrap_or_default();
+
+        if extensions == [script] {
+            print!("[{script}] ");
+        } else {
+            let extensions = extensions.iter().map(|s| s.full_name()).collect::<Vec<_>>();
+            print!("[{script}: {}] ", extensions.join(", "));
+        }
+    }
+
+    if columns.block {
+        print!("[{}] ", match codepoint.block() {
+            Some(block) => block.name,
+            None if codepoint.kind() == Kind::Invalid => "-",
+            None => "No_Block",
+        });
+    }
+
+    if let Some(age) = codepoint.age().filter(|_| columns.age) { print!("[{age}] ") }
+
+    let decomposition = columns.decomposition || columns.full_decomposition;
+
+    if let Some(class) = codepoint.canonical_combining_class().filter(|_| decomposition) {
+        print!("[ccc {class}");
+
+        if columns.full_decomposition {
+            let canonical = codepoint.canonical_decomposition();
+            let compatibility = codepoint.compatibility_decomposition();
+            if let Some(canonical) = &canonical { print!(", → {}", values(canonical)) }
+
+            if let Some(compatibility) = compatibility.filter(|c| Some(c) != canonical.as_ref()) {
+                print!(", <compat> → {}", values(&compatibility));
+            }
+        } else if let Some(decomposition) = codepoint.decomposition() {
+            match decomposition.kind.tag() {
+                Some(tag) => print!(", <{tag}> → {}", values(&decomposition.mapping)),
+                None => print!(", → {}", values(&decomposition.mapping)),
+            }
+        }
+
+        print!("] ");
+    }
+
+    if let Some(prototype) = codepoint.prototype().filter(|_| columns.confusable) {
+        print!("[looks like {}] ", values(&prototype));
+    }
+
+    if columns.properties {
+        let properties = [
+            (codepoint.white_space(), "White_Space"),
+            (codepoint.default_ignorable(), "Default_Ignorable_Code_Point"),
+        ];
+
+        let properties = properties.iter().filter(|(has, _)| *has).map(|(_, name)| *name);
+        let properties = properties.collect::<Vec<_>>();
+        if !properties.is_empty() { print!("[{}] ", properties.join(", ")) }
+    }
+
+    println!("{name}");
+}
+
+fn values(chars: &[char]) -> String {
+    chars.iter()
+        .map(|&c| Codepoint::from_valid(c).display_value().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Parses a Unicode version like `9.0`, `9` or `15.1.0`.
+pub fn parse_age(version: &str) -> Result<Age, String> {
+    let parts = version.split('.').map(|part| part.parse().ok()).collect::<Option<Vec<u8>>>();
+
+    match parts.as_deref() {
+        Some(&[major]) => Ok(Age { major, minor: 0 }),
+        Some(&[major, minor] | &[major, minor, _]) => Ok(Age { major, minor }),
+        _ => Err(format!("'{version}' isn't a Unicode version")),
+    }
+}
+
+impl From<InputEncoding> for Encoding {
+    fn from(encoding: InputEncoding) -> Self {
+        match encoding {
+            InputEncoding::Utf8 => Encoding::Utf8,
+            InputEncoding::Wtf8 => Encoding::Wtf8,
+            InputEncoding::Utf16Le => Encoding::Utf16Le,
+            InputEncoding::Utf16Be => Encoding::Utf16Be,
+            InputEncoding::Utf32Le => Encoding::Utf32Le,
+            InputEncoding::Utf32Be => Encoding::Utf32Be,
+            InputEncoding::Latin1 => Encoding::Latin1,
+            InputEncoding::Windows1252 => Encoding::Windows1252,
+        }
+    }
+}
+
+impl From<Normalization> for NormalizationForm {
+    fn from(normalization: Normalization) -> Self {
+        match normalization {
+            Normalization::Nfc => NormalizationForm::Nfc,
+            Normalization::Nfd => NormalizationForm::Nfd,
+            Normalization::Nfkc => NormalizationForm::Nfkc,
+            Normalization::Nfkd => NormalizationForm::Nfkd,
+        }
+    }
+}
+
+impl From<Language> for CaseLocale {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::Az | Language::Tr => CaseLocale::Turkic,
+            Language::Lt => CaseLocale::Lithuanian,
+        }
+    }
+}
+
+impl From<Level> for RestrictionLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::AsciiOnly => RestrictionLevel::AsciiOnly,
+            Level::SingleScript => RestrictionLevel::SingleScript,
+            Level::HighlyRestrictive => RestrictionLevel::HighlyRestrictive,
+            Level::ModeratelyRestrictive => RestrictionLevel::ModeratelyRestrictive,
+            Level::MinimallyRestrictive => RestrictionLevel::MinimallyRestrictive,
+            Level::Unrestricted => RestrictionLevel::Unrestricted,
+        }
+    }
+}
+
+impl From<Escape> for EscapeStyle {
+    fn from(escape: Escape) -> Self {
+        match escape {
+            Escape::Rust => EscapeStyle::Rust,
+            Escape::C => EscapeStyle::C,
+            Escape::Json => EscapeStyle::Json,
+            Escape::Python => EscapeStyle::Python,
+            Escape::Js => EscapeStyle::JavaScript,
+            Escape::Html => EscapeStyle::Html,
+            Escape::Url => EscapeStyle::Url,
+        }
+    }
+}
+
+impl From<Order> for ByteOrder {
+    fn from(order: Order) -> Self {
+        match order {
+            Order::Be => ByteOrder::BigEndian,
+            Order::Le => ByteOrder::LittleEndian,
+        }
+    }
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).expect("records should always serialize")
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Source {
+    Argument { index: usize },
+    File { path: String },
+    Stdin,
+}
+
+#[derive(Serialize)]
+struct NdjsonRecord {
+    source: Source,
+    #[serde(flatten)]
+    grapheme: GraphemeRecord,
+}
+
+#[derive(Serialize)]
+struct GraphemeRecord {
+    offset: usize,
+    char_offset: usize,
+    utf16_offset: usize,
+    codepoints: Vec<CodepointRecord>,
+}
+
+#[derive(Serialize)]
+struct CodepointRecord {
+    value: String,
+    scalar: Option<u32>,
+    bytes: Vec<u8>,
+    offset: Option<usize>,
+    char_offset: Option<usize>,
+    utf16_offset: Option<usize>,
+    character: String,
+    name: String,
+    kind: &'static str,
+    invalid: Option<&'static str>,
+    width: Option<usize>,
+    diacritic: Option<&'static str>,
+    direction: Option<&'static str>,
+    general_category: Option<&'static str>,
+    bidi_class: Option<&'static str>,
+    bidi_mirrored: bool,
+    bidi_paired_bracket: Option<char>,
+    bidi_paired_bracket_type: Option<&'static str>,
+    script: Option<&'static str>,
+    script_extensions: Option<Vec<&'static str>>,
+    block: Option<&'static str>,
+    age: Option<String>,
+    canonical_combining_class: Option<u8>,
+    decomposition_type: Option<&'static str>,
+    decomposition_mapping: Option<String>,
+    canonical_decomposition: Option<String>,
+    compatibility_decomposition: Option<String>,
+    uppercase: Option<String>,
+    lowercase: Option<String>,
+    titlecase: Option<String>,
+    case_folding: Option<String>,
+    prototype: Option<String>,
+    white_space: bool,
+    default_ignorable: bool,
+}
+
+impl Source {
+    fn new(input: &Input) -> Self {
+        match input {
+            Input::Argument(index, _) => Source::Argument { index: *index },
+            Input::File(path) => Source::File { path: path.to_string_lossy().into_owned() },
+            Input::Stdin => Source::Stdin,
+        }
+    }
+}
+
+impl GraphemeRecord {
+    fn new(grapheme: &Grapheme) -> Self {
+        let Offset { byte, char, utf16 } = grapheme.span().start;
+
+        GraphemeRecord {
+            offset: byte,
+            char_offset: char,
+            utf16_offset: utf16,
+            codepoints: grapheme.codepoints().map(|c| CodepointRecord::new(c, true)).collect(),
+        }
+    }
+}
+
+impl CodepointRecord {
+    fn new(codepoint: &Codepoint, positioned: bool) -> Self {
+        let offset = Some(codepoint.offset()).filter(|_| positioned);
+
+        CodepointRecord {
+            value: codepoint.display_value().to_string(),
+            scalar: codepoint.value().ok().map(u32::from),
+            bytes: match codepoint.value() {
+                Ok(c) => c.encode_utf8(&mut [0; 4]).as_bytes().to_vec(),
+                Err(bytes) => bytes.to_vec(),
+            },
+            offset: offset.map(|offset| offset.byte),
+            char_offset: offset.map(|offset| offset.char),
+            utf16_offset: offset.map(|offset| offset.utf16),
+            character: codepoint.display_character().to_string(),
+            name: codepoint.name(),
+            kind: match codepoint.kind() {
+                Kind::Character => "character",
+                Kind::ControlCode => "control_code",
+                Kind::NonCharacter => "non_character",
+                Kind::PrivateUse => "private_use",
+                Kind::Surrogate => "surrogate",
+                Kind::Unknown => "unknown",
+                Kind::Invalid => "invalid",
+            },
+            invalid: codepoint.invalid_kind().map(|kind| match kind {
+                InvalidKind::UnexpectedContinuation => "unexpected_continuation",
+                InvalidKind::Truncated => "truncated",
+                InvalidKind::Overlong(_) => "overlong",
+                InvalidKind::Surrogate(_) => "surrogate",
+                InvalidKind::SurrogatePair(_) => "surrogate_pair",
+                InvalidKind::OutOfRange(_) => "out_of_range",
+                InvalidKind::UnpairedSurrogate(_) => "unpaired_surrogate",
+                InvalidKind::InvalidByte => "invalid_byte",
+                InvalidKind::Undefined => "undefined",
+            }),
+            width: codepoint.width(),
+            diacritic: codepoint.diacritic().map(|diacritic| match diacritic {
+                Diacritic::None => "none",
+                Diacritic::Single => "single",
+                Diacritic::Double => "double",
+            }),
+            direction: codepoint.direction().map(|direction| match direction {
+                Direction::Ltr => "ltr",
+                Direction::Rtl => "rtl",
+                Direction::Neutral => "neutral",
+            }),
+            general_category: codepoint.general_category().map(|category| category.short_name()),
+            bidi_class: codepoint.bidi_class().map(|class| class.short_name()),
+            bidi_mirrored: codepoint.bidi_mirrored(),
+            bidi_paired_bracket: codepoint.bidi_paired_bracket().map(|bracket| match bracket {
+                Bracket::Open(pair) | Bracket::Close(pair) => pair,
+            }),
+            bidi_paired_bracket_type: codepoint.bidi_paired_bracket().map(|bracket| match bracket {
+                Bracket::Open(_) => "open",
+                Bracket::Close(_) => "close",
+            }),
+            script: codepoint.script().map(|script| script.short_name()),
+            script_extensions: codepoint.script_extensions()
+                .map(|scripts| scripts.into_iter().map(|script| script.short_name()).collect()),
+            block: codepoint.block().map(|block| block.name),
+            age: codepoint.age().map(|age| age.to_string()),
+            canonical_combining_class: codepoint.canonical_combining_class(),
+            decomposition_type: codepoint.decomposition()
+                .map(|decomposition| decomposition.kind.short_name()),
+            decomposition_mapping: codepoint.decomposition()
+                .map(|decomposition| decomposition.mapping.into_iter().collect()),
+            canonical_decomposition: codepoint.canonical_decomposition()
+                .map(|decomposition| decomposition.into_iter().collect()),
+            compatibility_decomposition: codepoint.compatibility_decomposition()
+                .map(|decomposition| decomposition.into_iter().collect()),
+            uppercase: codepoint.uppercase().map(|mapping| mapping.into_iter().collect()),
+            lowercase: codepoint.lowercase().map(|mapping| mapping.into_iter().collect()),
+            titlecase: codepoint.titlecase().map(|mapping| mapping.into_iter().collect()),
+            case_folding: codepoint.case_folding().map(|mapping| mapping.into_iter().collect()),
+            prototype: codepoint.prototype().map(|prototype| prototype.into_iter().collect()),
+            white_space: codepoint.white_space(),
+            default_ignorable: codepoint.default_ignorable(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use unicode_normalization::UnicodeNormalization;
+use crate::{Text, normalize, unicode::Character};
+
+// The skeleton of some text from UTS #39, which replaces each character with its prototype between
+// two canonical decompositions. Texts that look alike have the same skeleton. Each run of valid
+// text is done separately, keeping surrogates and invalid data as they are.
+pub fn skeleton(text: &Text) -> Text {
+    normalize::map(text, |s| {
+        s.nfd().flat_map(|c| c.prototype().unwrap_or_else(|| vec![c])).nfd().collect()
+    })
+}
@@ -0,0 +1,312 @@
+use std::{borrow::Cow, fmt::{self, Display}, iter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// The encodings that text can be decoded from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Encoding {
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "utf-8"))]
+    Utf8,
+    // UTF-8 that also allows lone surrogates, as used for strings on Windows.
+    #[cfg_attr(feature = "serde", serde(rename = "wtf-8"))]
+    Wtf8,
+    #[cfg_attr(feature = "serde", serde(rename = "utf-16le"))]
+    Utf16Le,
+    #[cfg_attr(feature = "serde", serde(rename = "utf-16be"))]
+    Utf16Be,
+    #[cfg_attr(feature = "serde", serde(rename = "utf-32le"))]
+    Utf32Le,
+    #[cfg_attr(feature = "serde", serde(rename = "utf-32be"))]
+    Utf32Be,
+    #[cfg_attr(feature = "serde", serde(rename = "latin-1"))]
+    Latin1,
+    #[cfg_attr(feature = "serde", serde(rename = "windows-1252"))]
+    Windows1252,
+}
+
+// The reasons that a sequence of bytes can't be decoded.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum InvalidKind {
+    UnexpectedContinuation,
+    Truncated,
+    Overlong(u32),
+    Surrogate(u16),
+    SurrogatePair(char),
+    OutOfRange(u32),
+    UnpairedSurrogate(u16),
+    InvalidByte,
+    Undefined,
+}
+
+// A run of decoded text, a lone surrogate, or a single sequence that couldn't be decoded.
+pub enum Chunk<'a> {
+    Valid(Cow<'a, str>),
+    Surrogate(u16),
+    Invalid(InvalidSequence),
+}
+
+// The characters of Windows-1252 that differ from Latin-1, from 0x80 to 0x9F.
+const WINDOWS_1252: [Option<char>; 32] = [
+    Some('€'), None, Some('‚'), Some('ƒ'), Some('„'), Some('…'), Some('†'), Some('‡'),
+    Some('ˆ'), Some('‰'), Some('Š'), Some('‹'), Some('Œ'), None, Some('Ž'), None,
+    None, Some('‘'), Some('’'), Some('“'), Some('”'), Some('•'), Some('–'), Some('—'),
+    Some('˜'), Some('™'), Some('š'), Some('›'), Some('œ'), None, Some('ž'), Some('Ÿ'),
+];
+
+impl Encoding {
+    // Detects the encoding of some bytes from the byte order mark at their start, if there is one.
+    // A UTF-32LE byte order mark is preferred over a UTF-16LE one followed by a null character.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0xEF, 0xBB, 0xBF, ..] => Some(Encoding::Utf8),
+            [0xFF, 0xFE, 0x00, 0x00, ..] => Some(Encoding::Utf32Le),
+            [0x00, 0x00, 0xFE, 0xFF, ..] => Some(Encoding::Utf32Be),
+            [0xFF, 0xFE, ..] => Some(Encoding::Utf16Le),
+            [0xFE, 0xFF, ..] => Some(Encoding::Utf16Be),
+            _ => None,
+        }
+    }
+
+    // The number of bytes that encode a character in this encoding.
+    pub fn encoded_len(self, c: char) -> usize {
+        match self {
+            Encoding::Utf8 | Encoding::Wtf8 => c.len_utf8(),
+            Encoding::Utf16Le | Encoding::Utf16Be => 2 * c.len_utf16(),
+            Encoding::Utf32Le | Encoding::Utf32Be => 4,
+            Encoding::Latin1 | Encoding::Windows1252 => 1,
+        }
+    }
+
+    // Decodes the character at the start of `bytes`, returning it with the number of bytes it took.
+    fn decode_one(self, bytes: &[u8]) -> Result<(char, usize), InvalidSequence> {
+        match self {
+            Encoding::Utf8 | Encoding::Wtf8 => {
+                match bytes.utf8_chunks().next().and_then(|chunk| chunk.valid().chars().next()) {
+                    Some(c) => Ok((c, c.len_utf8())),
+                    None => Err(InvalidSequence::utf8(bytes)),
+                }
+            },
+            Encoding::Utf16Le => InvalidSequence::utf16(bytes, u16::from_le_bytes),
+            Encoding::Utf16Be => InvalidSequence::utf16(bytes, u16::from_be_bytes),
+            Encoding::Utf32Le => InvalidSequence::utf32(bytes, u32::from_le_bytes),
+            Encoding::Utf32Be => InvalidSequence::utf32(bytes, u32::from_be_bytes),
+            Encoding::Latin1 => Ok((bytes[0] as char, 1)),
+            Encoding::Windows1252 => match bytes[0] {
+                b @ 0x80..=0x9F => WINDOWS_1252[b as usize - 0x80]
+                    .map(|c| (c, 1))
+                    .ok_or(InvalidSequence::new(&bytes[..1], InvalidKind::Undefined)),
+                b => Ok((b as char, 1)),
+            },
+        }
+    }
+}
+
+// Splits bytes into runs of valid text and the invalid sequences between them.
+pub fn decode(bytes: &[u8], encoding: Encoding) -> impl Iterator<Item = Chunk<'_>> {
+    let mut rest = bytes;
+
+    iter::from_fn(move || {
+        if rest.is_empty() { return None }
+
+        // UTF-8 can be borrowed instead of being decoded one character at a time.
+        if matches!(encoding, Encoding::Utf8 | Encoding::Wtf8) {
+            let valid = rest.utf8_chunks().next()?.valid();
+
+            if !valid.is_empty() {
+                rest = &rest[valid.len()..];
+                return Some(Chunk::Valid(Cow::Borrowed(valid)));
+            }
+        }
+
+        // Surrogates are decoded one at a time even if they would form a pair, as in generalized
+        // UTF-8, so that no input is rejected for encoding them.
+        if encoding == Encoding::Wtf8 {
+            if let [0xED, 0xA0..=0xBF, 0x80..=0xBF, ..] = rest {
+                let surrogate = decode_utf8(&rest[..3]) as u16;
+                rest = &rest[3..];
+                return Some(Chunk::Surrogate(surrogate));
+            }
+        }
+
+        let mut valid = String::new();
+
+        while !rest.is_empty() {
+            match encoding.decode_one(rest) {
+                Ok((c, len)) => {
+                    valid.push(c);
+                    rest = &rest[len..];
+                },
+                Err(invalid) if valid.is_empty() => {
+                    rest = &rest[invalid.bytes().len()..];
+                    return Some(Chunk::Invalid(invalid));
+                },
+                Err(_) => break,
+            }
+        }
+
+        Some(Chunk::Valid(Cow::Owned(valid)))
+    })
+}
+
+// A sequence of bytes that couldn't be decoded, which is treated as a single codepoint.
+#[derive(Debug, Copy, Clone)]
+pub struct InvalidSequence {
+    bytes: [u8; 6],
+    len: u8,
+    kind: InvalidKind,
+}
+
+impl InvalidSequence {
+    fn new(bytes: &[u8], kind: InvalidKind) -> Self {
+        let mut sequence = InvalidSequence { bytes: [0; 6], len: bytes.len() as u8, kind };
+        sequence.bytes[..bytes.len()].copy_from_slice(bytes);
+        sequence
+    }
+
+    pub fn bytes(&self) -> &[u8] { &self.bytes[..self.len as usize] }
+    pub fn kind(&self) -> InvalidKind { self.kind }
+
+    // Classifies the invalid UTF-8 sequence at the start of `bytes`, grouping together any bytes
+    // that were meant to be part of the same character.
+    pub fn utf8(bytes: &[u8]) -> Self {
+        use InvalidKind as Ik;
+
+        let Some(&lead) = bytes.first() else { return InvalidSequence::new(&[], Ik::Truncated) };
+
+        let (len, min) = match lead {
+            0x80..=0xBF => return InvalidSequence::new(&bytes[..1], Ik::UnexpectedContinuation),
+            0xC0..=0xDF => (2, 0x80),
+            0xE0..=0xEF => (3, 0x800),
+            0xF0..=0xF7 => (4, 0x10000),
+            _ => return InvalidSequence::new(&bytes[..1], Ik::InvalidByte),
+        };
+
+        let continuations = bytes[1..].iter().take(len - 1).take_while(|&&b| is_continuation(b));
+
+        if continuations.count() < len - 1 {
+            return match lead {
+                0xC0 | 0xC1 | 0xF5.. => InvalidSequence::new(&bytes[..1], Ik::InvalidByte),
+                _ => {
+                    let continuations = bytes[1..].iter().take_while(|&&b| is_continuation(b));
+                    InvalidSequence::new(&bytes[..1 + continuations.count()], Ik::Truncated)
+                },
+            };
+        }
+
+        let value = decode_utf8(&bytes[..len]);
+
+        match value {
+            _ if value < min => InvalidSequence::new(&bytes[..len], Ik::Overlong(value)),
+            0xD800..=0xDBFF => match bytes.get(3..6) {
+                Some(low @ [0xED, 0xB0..=0xBF, 0x80..=0xBF]) => {
+                    let low = decode_utf8(low);
+                    let c = 0x10000 + ((value - 0xD800) << 10) + (low - 0xDC00);
+                    let c = char::from_u32(c).expect("surrogate pairs should be valid");
+                    InvalidSequence::new(&bytes[..6], Ik::SurrogatePair(c))
+                },
+                _ => InvalidSequence::new(&bytes[..len], Ik::Surrogate(value as u16)),
+            },
+            0xDC00..=0xDFFF => InvalidSequence::new(&bytes[..len], Ik::Surrogate(value as u16)),
+            0x110000.. => InvalidSequence::new(&bytes[..len], Ik::OutOfRange(value)),
+            _ => InvalidSequence::new(&bytes[..1], Ik::InvalidByte),
+        }
+    }
+
+    // Decodes the UTF-16 character at the start of `bytes`, or classifies why it can't be decoded.
+    fn utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> Result<(char, usize), Self> {
+        use InvalidKind as Ik;
+
+        let unit_at = |i: usize| bytes.get(i..i + 2).map(|b| unit([b[0], b[1]]));
+
+        let Some(first) = unit_at(0) else {
+            return Err(InvalidSequence::new(bytes, Ik::Truncated));
+        };
+
+        match first {
+            0xD800..=0xDBFF => match unit_at(2) {
+                Some(second @ 0xDC00..=0xDFFF) => {
+                    let c = 0x10000 + ((first as u32 - 0xD800) << 10) + (second as u32 - 0xDC00);
+                    Ok((char::from_u32(c).expect("surrogate pairs should be valid"), 4))
+                },
+                _ => Err(InvalidSequence::new(&bytes[..2], Ik::UnpairedSurrogate(first))),
+            },
+            0xDC00..=0xDFFF => Err(InvalidSequence::new(&bytes[..2], Ik::UnpairedSurrogate(first))),
+            _ => Ok((char::from_u32(first as u32).expect("non-surrogates should be valid"), 2)),
+        }
+    }
+
+    // Decodes the UTF-32 character at the start of `bytes`, or classifies why it can't be decoded.
+    fn utf32(bytes: &[u8], unit: fn([u8; 4]) -> u32) -> Result<(char, usize), Self> {
+        use InvalidKind as Ik;
+
+        let Some(&[a, b, c, d]) = bytes.get(..4) else {
+            return Err(InvalidSequence::new(bytes, Ik::Truncated));
+        };
+
+        let value = unit([a, b, c, d]);
+
+        match value {
+            0xD800..=0xDFFF => Err(InvalidSequence::new(&bytes[..4], Ik::Surrogate(value as u16))),
+            0x110000.. => Err(InvalidSequence::new(&bytes[..4], Ik::OutOfRange(value))),
+            _ => Ok((char::from_u32(value).expect("non-surrogates should be valid"), 4)),
+        }
+    }
+}
+
+// Encodes a surrogate in WTF-8, the same way that UTF-8 would encode it if it were allowed to.
+pub fn encode_wtf8(surrogate: u16) -> [u8; 3] {
+    let [high, low] = surrogate.to_be_bytes();
+    [0xE0 | high >> 4, 0x80 | (high & 0x0F) << 2 | low >> 6, 0x80 | low & 0x3F]
+}
+
+fn is_continuation(byte: u8) -> bool { byte & 0xC0 == 0x80 }
+
+fn decode_utf8(bytes: &[u8]) -> u32 {
+    let lead = bytes[0] as u32 & (0x7F >> bytes.len());
+    bytes[1..].iter().fold(lead, |value, &b| value << 6 | (b & 0x3F) as u32)
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Wtf8 => "WTF-8",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Utf32Le => "UTF-32LE",
+            Encoding::Utf32Be => "UTF-32BE",
+            Encoding::Latin1 => "LATIN-1",
+            Encoding::Windows1252 => "WINDOWS-1252",
+        })
+    }
+}
+
+// Names an invalid sequence by why it couldn't be decoded from its encoding.
+pub fn display_invalid(kind: InvalidKind, encoding: Encoding) -> impl Display {
+    use InvalidKind as Ik;
+
+    crate::display_with(move |f| match kind {
+        Ik::UnexpectedContinuation => f.write_str("UNEXPECTED CONTINUATION BYTE"),
+        Ik::Truncated => write!(f, "TRUNCATED {encoding} SEQUENCE"),
+        Ik::Overlong(value) => write!(f, "OVERLONG ENCODING OF {}", display_scalar(value)),
+        Ik::Surrogate(value) => write!(f, "ENCODED SURROGATE {}", display_scalar(value as u32)),
+        Ik::SurrogatePair(c) => write!(f, "CESU-8 ENCODING OF {}", display_scalar(c as u32)),
+        Ik::OutOfRange(value) => write!(f, "OUT OF RANGE VALUE 0x{value:X}"),
+        Ik::UnpairedSurrogate(value) => {
+            write!(f, "UNPAIRED SURROGATE {}", display_scalar(value as u32))
+        },
+        Ik::InvalidByte => write!(f, "INVALID {encoding} BYTE"),
+        Ik::Undefined => write!(f, "UNDEFINED {encoding} BYTE"),
+    })
+}
+
+pub fn display_scalar(value: u32) -> impl Display {
+    crate::display_with(move |f| match value {
+        ..=0xFFFF => write!(f, "U+{value:04X}"),
+        _ => write!(f, "U+{value:06X}"),
+    })
+}
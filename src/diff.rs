@@ -0,0 +1,116 @@
+use unicode_normalization::UnicodeNormalization;
+use crate::{normalize, unicode::Character, Codepoint, Grapheme, NormalizationForm, Text};
+
+// One step of turning one sequence into another.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Edit<T> {
+    Equal(T, T),
+    Delete(T),
+    Insert(T),
+    Substitute(T, T),
+}
+
+// Ways that two texts can be the same. Texts are identical if they have the same codepoints,
+// wherever they are and however they're encoded.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Equivalence {
+    Identical,
+    Nfc,
+    Nfkc,
+    CaseFolding,
+    Skeleton,
+}
+
+impl Equivalence {
+    pub const ALL: [Equivalence; 5] = [
+        Equivalence::Identical,
+        Equivalence::Nfc,
+        Equivalence::Nfkc,
+        Equivalence::CaseFolding,
+        Equivalence::Skeleton,
+    ];
+}
+
+// Aligns the graphemes of two texts.
+pub fn graphemes<'a>(a: &'a Text, b: &'a Text) -> Vec<Edit<&'a Grapheme>> { align(a, b) }
+
+// Aligns the codepoints of two graphemes.
+pub fn codepoints<'a>(a: &'a Grapheme, b: &'a Grapheme) -> Vec<Edit<&'a Codepoint>> {
+    align(a, b)
+}
+
+// Case folding is compared with canonical caseless matching, separately for each run of valid text,
+// and surrogates and invalid data have to be identical.
+pub fn is_equivalent(a: &Text, b: &Text, equivalence: Equivalence) -> bool {
+    let compare = |f: fn(&str) -> String| transformed(a, f) == transformed(b, f);
+
+    match equivalence {
+        Equivalence::Identical => a == b,
+        Equivalence::Nfc => {
+            a.normalize(NormalizationForm::Nfc) == b.normalize(NormalizationForm::Nfc)
+        },
+        Equivalence::Nfkc => {
+            a.normalize(NormalizationForm::Nfkc) == b.normalize(NormalizationForm::Nfkc)
+        },
+        Equivalence::CaseFolding => compare(|s| {
+            s.nfd().flat_map(|c| c.case_folding()).nfd().collect()
+        }),
+        Equivalence::Skeleton => a.skeleton() == b.skeleton(),
+    }
+}
+
+fn transformed(text: &Text, f: fn(&str) -> String) -> Vec<Result<String, &Codepoint>> {
+    normalize::runs(text).map(|run| run.map(|(s, _)| f(&s))).collect()
+}
+
+// Finds a longest common subsequence, then pairs up the deletions and insertions between each
+// common element as substitutions. This takes quadratic time and space, which is fine for the
+// short strings that are usually compared.
+fn align<'a, T: PartialEq>(a: &'a [T], b: &'a [T]) -> Vec<Edit<&'a T>> {
+    // The length of the longest common subsequence of `a[i..]` and `b[j..]`.
+    let mut lengths = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i][j] = if a[i] == b[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let (mut deleted, mut inserted) = (Vec::new(), Vec::new());
+    let mut edits = Vec::new();
+
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            substitute(&mut edits, &mut deleted, &mut inserted);
+            edits.push(Edit::Equal(&a[i], &b[j]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == b.len() || i < a.len() && lengths[i + 1][j] >= lengths[i][j + 1] {
+            deleted.push(&a[i]);
+            i += 1;
+        } else {
+            inserted.push(&b[j]);
+            j += 1;
+        }
+    }
+
+    substitute(&mut edits, &mut deleted, &mut inserted);
+    edits
+}
+
+// Pairs up deletions and insertions as substitutions, and adds them to the edits.
+fn substitute<'a, T>(
+    edits: &mut Vec<Edit<&'a T>>,
+    deleted: &mut Vec<&'a T>,
+    inserted: &mut Vec<&'a T>,
+) {
+    let pairs = deleted.len().min(inserted.len());
+    let substituted = deleted.drain(..pairs).zip(inserted.drain(..pairs));
+    edits.extend(substituted.map(|(a, b)| Edit::Substitute(a, b)));
+    edits.extend(deleted.drain(..).map(Edit::Delete));
+    edits.extend(inserted.drain(..).map(Edit::Insert));
+}
@@ -0,0 +1,364 @@
+use std::{error::Error, fmt::{self, Display, Write}};
+use entities::ENTITIES;
+use crate::{decode, unicode::{self, Character}, Codepoint, Diacritic, Kind, Text};
+
+// The kinds of literal that text can be escaped as.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EscapeStyle {
+    Rust,
+    C,
+    Json,
+    Python,
+    JavaScript,
+    Html,
+    Url,
+}
+
+// An escape sequence that couldn't be decoded, and where it was found.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct UnescapeError {
+    offset: usize,
+    escape: String,
+    reason: &'static str,
+}
+
+impl UnescapeError {
+    // The byte offset of the escape sequence in the escaped input.
+    pub fn offset(&self) -> usize { self.offset }
+    pub fn escape(&self) -> &str { &self.escape }
+}
+
+// Writes text as a literal that can be pasted into source code, escaping control codes, invisible
+// characters, combining marks, surrogates and invalid data. Rust and Python text with invalid data
+// becomes a byte string, and JSON, JavaScript and HTML have no way to write invalid data, so it's
+// replaced with U+FFFD REPLACEMENT CHARACTER. URLs are percent-encoded except for unreserved
+// characters.
+pub fn escape(text: &Text, style: EscapeStyle) -> String {
+    use EscapeStyle as Es;
+
+    let bytes = match style {
+        Es::Rust => text.codepoints().any(|c| c.value().is_err()),
+        Es::Python => text.codepoints().any(|c| c.kind() == Kind::Invalid),
+        _ => false,
+    };
+
+    let quote = match style {
+        _ if bytes => "b\"",
+        Es::Rust | Es::C | Es::Json | Es::Python | Es::JavaScript => "\"",
+        Es::Html | Es::Url => "",
+    };
+
+    let mut escaped = String::from(quote);
+
+    for codepoint in text.codepoints() {
+        escape_codepoint(&mut escaped, codepoint, style, bytes)
+            .expect("strings should be writable");
+    }
+
+    escaped.push_str(quote.trim_start_matches('b'));
+    escaped
+}
+
+fn escape_codepoint(
+    f: &mut String,
+    codepoint: &Codepoint,
+    style: EscapeStyle,
+    bytes: bool,
+) -> fmt::Result {
+    use EscapeStyle as Es;
+
+    let mut buf = [0; 4];
+    let encoded = match codepoint.value() {
+        Ok(c) => c.encode_utf8(&mut buf).as_bytes(),
+        Err(invalid) => invalid,
+    };
+
+    if style == Es::Url {
+        for &b in encoded {
+            match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    f.push(b as char)
+                },
+                _ => write!(f, "%{b:02X}")?,
+            }
+        }
+
+        return Ok(());
+    }
+
+    let c = match (codepoint.value(), codepoint.surrogate()) {
+        (Ok(c), _) => c,
+        (Err(_), Some(surrogate)) if matches!(style, Es::Json | Es::JavaScript | Es::Python)
+            && !bytes => {
+            return write!(f, "\\u{surrogate:04x}");
+        },
+        (Err(_), _) => '\u{FFFD}',
+    };
+
+    if let Some(simple) = simple_escape(c, style) { return f.write_str(simple) }
+
+    let visible = match codepoint.kind() {
+        Kind::Character => {
+            codepoint.width() != Some(0)
+                && codepoint.diacritic() == Some(Diacritic::None)
+                && (c == ' ' || !c.is_whitespace())
+                && !c.default_ignorable()
+        },
+        _ => false,
+    };
+
+    match style {
+        _ if visible && (!bytes || c.is_ascii()) => f.push(c),
+        Es::Rust | Es::Python if bytes => {
+            for b in encoded { write!(f, "\\x{b:02x}")? }
+        },
+        Es::C if codepoint.value().is_err() || (c as u32) < 0xA0 => {
+            for b in encoded { write!(f, "\\{b:03o}")? }
+        },
+        Es::Rust => write!(f, "\\u{{{:x}}}", c as u32)?,
+        Es::C | Es::Python if c as u32 > 0xFFFF => write!(f, "\\U{:08x}", c as u32)?,
+        Es::JavaScript if c as u32 > 0xFFFF => write!(f, "\\u{{{:x}}}", c as u32)?,
+        Es::JavaScript | Es::Python if (c as u32) < 0x80 => write!(f, "\\x{:02x}", c as u32)?,
+        Es::C | Es::Json | Es::Python | Es::JavaScript => {
+            for unit in c.encode_utf16(&mut [0; 2]) { write!(f, "\\u{unit:04x}")? }
+        },
+        Es::Html => write!(f, "&#x{:X};", c as u32)?,
+        Es::Url => unreachable!(),
+    }
+
+    Ok(())
+}
+
+// Escapes that each style has a special form for, which are always used.
+fn simple_escape(c: char, style: EscapeStyle) -> Option<&'static str> {
+    use EscapeStyle as Es;
+
+    match (style, c) {
+        (Es::Html, '&') => Some("&amp;"),
+        (Es::Html, '<') => Some("&lt;"),
+        (Es::Html, '>') => Some("&gt;"),
+        (Es::Html, '"') => Some("&quot;"),
+        (Es::Html, '\'') => Some("&#39;"),
+        (Es::Html | Es::Url, _) => None,
+        (_, '\\') => Some("\\\\"),
+        (_, '"') => Some("\\\""),
+        (_, '\n') => Some("\\n"),
+        (_, '\r') => Some("\\r"),
+        (_, '\t') => Some("\\t"),
+        (Es::Rust, '\0') => Some("\\0"),
+        (Es::C | Es::Json | Es::JavaScript, '\x08') => Some("\\b"),
+        (Es::C | Es::Json | Es::JavaScript, '\x0C') => Some("\\f"),
+        (Es::C | Es::JavaScript, '\x0B') => Some("\\v"),
+        (Es::C, '\x07') => Some("\\a"),
+        _ => None,
+    }
+}
+
+// Replaces the escape sequences of Rust, C, JSON, JavaScript and Python strings, HTML character
+// references, and lists of `U+XXXX` values with the bytes they stand for. Everything else is left
+// as it is, including any invalid UTF-8. Escaped surrogates that aren't part of a pair are encoded
+// in WTF-8.
+pub fn unescape(text: &[u8]) -> Result<Vec<u8>, UnescapeError> {
+    let mut unescaped = Vec::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let error = |len: usize, reason| UnescapeError {
+            offset: i,
+            escape: String::from_utf8_lossy(&rest[..len.min(rest.len())]).into_owned(),
+            reason,
+        };
+
+        let (value, len) = match rest {
+            [b'\\', ..] => backslash(rest).map_err(|(len, reason)| error(len, reason))?,
+            [b'&', ..] => match reference(rest).map_err(|(len, reason)| error(len, reason))? {
+                Some(reference) => reference,
+                None => (Unescaped::Byte(b'&'), 1),
+            },
+            [b'U' | b'u', b'+', ..] => match code_point_list(rest) {
+                Some((value, len, separators)) => {
+                    (scalar(value).ok_or_else(|| error(len, INVALID))?, len + separators)
+                },
+                None => (Unescaped::Byte(rest[0]), 1),
+            },
+            [b, ..] => (Unescaped::Byte(*b), 1),
+            [] => unreachable!(),
+        };
+
+        match value {
+            Unescaped::Byte(b) => unescaped.push(b),
+            Unescaped::Char(c) => {
+                unescaped.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes())
+            },
+            Unescaped::Surrogate(s) => unescaped.extend_from_slice(&decode::encode_wtf8(s)),
+            Unescaped::Str(s) => unescaped.extend_from_slice(s.as_bytes()),
+        }
+
+        i += len;
+    }
+
+    Ok(unescaped)
+}
+
+enum Unescaped {
+    Byte(u8),
+    Char(char),
+    Surrogate(u16),
+    Str(&'static str),
+}
+
+const UNKNOWN: &str = "unknown escape";
+const INCOMPLETE: &str = "incomplete escape";
+const INVALID: &str = "out of range escape";
+const UNKNOWN_NAME: &str = "unknown character name in escape";
+const UNKNOWN_ENTITY: &str = "unknown HTML entity";
+
+type Escape = Result<(Unescaped, usize), (usize, &'static str)>;
+
+// Escapes that start with a backslash, from any of the supported languages.
+fn backslash(text: &[u8]) -> Escape {
+    let Some(&kind) = text.get(1) else { return Err((1, INCOMPLETE)) };
+
+    let byte = |b| Ok((Unescaped::Byte(b), 2));
+
+    match kind {
+        b'n' => byte(b'\n'),
+        b't' => byte(b'\t'),
+        b'r' => byte(b'\r'),
+        b'a' => byte(0x07),
+        b'b' => byte(0x08),
+        b'f' => byte(0x0C),
+        b'v' => byte(0x0B),
+        b'e' => byte(0x1B),
+        b'\\' | b'\'' | b'"' | b'/' | b'?' => byte(kind),
+        b'\n' => Ok((Unescaped::Str(""), 2)),
+        b'\r' if text.get(2) == Some(&b'\n') => Ok((Unescaped::Str(""), 3)),
+        b'0'..=b'7' => {
+            let digits = text[1..].iter().take(3).take_while(|b| matches!(b, b'0'..=b'7')).count();
+            let value = digits_value(&text[1..1 + digits], 8);
+            let value = u8::try_from(value).map_err(|_| (1 + digits, INVALID))?;
+            Ok((Unescaped::Byte(value), 1 + digits))
+        },
+        b'x' => match hex(&text[2..], 2) {
+            (_, 0) => Err((2, INCOMPLETE)),
+            (value, digits) => Ok((Unescaped::Byte(value as u8), 2 + digits)),
+        },
+        b'u' if text.get(2) == Some(&b'{') => {
+            let (value, digits) = hex(&text[3..], 6);
+            let len = 3 + digits + 1;
+
+            if digits == 0 || text.get(len - 1) != Some(&b'}') { return Err((len, INCOMPLETE)) }
+            Ok((scalar(value).ok_or((len, INVALID))?, len))
+        },
+        b'u' => match hex(&text[2..], 4) {
+            (high @ 0xD800..=0xDBFF, 4) => match text.get(6..8) {
+                Some(b"\\u") => match hex(&text[8..], 4) {
+                    (low @ 0xDC00..=0xDFFF, 4) => {
+                        let c = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                        Ok((scalar(c).ok_or((12, INVALID))?, 12))
+                    },
+                    _ => Ok((Unescaped::Surrogate(high as u16), 6)),
+                },
+                _ => Ok((Unescaped::Surrogate(high as u16), 6)),
+            },
+            (value, 4) => Ok((scalar(value).ok_or((6, INVALID))?, 6)),
+            (_, digits) => Err((2 + digits, INCOMPLETE)),
+        },
+        b'U' => match hex(&text[2..], 8) {
+            (value, 8) => Ok((scalar(value).ok_or((10, INVALID))?, 10)),
+            (_, digits) => Err((2 + digits, INCOMPLETE)),
+        },
+        b'N' if text.get(2) == Some(&b'{') => {
+            let Some(end) = text.iter().position(|&b| b == b'}') else {
+                return Err((text.len(), INCOMPLETE));
+            };
+
+            let name = std::str::from_utf8(&text[3..end]).ok();
+            let c = name.and_then(unicode::character).ok_or((end + 1, UNKNOWN_NAME))?;
+            Ok((Unescaped::Char(c), end + 1))
+        },
+        _ => Err((1 + utf8_len(kind), UNKNOWN)),
+    }
+}
+
+// HTML character references. An ampersand that doesn't start a reference is left alone.
+fn reference(text: &[u8]) -> Result<Option<(Unescaped, usize)>, (usize, &'static str)> {
+    if let Some(numeric) = text.strip_prefix(b"&#") {
+        let (value, digits, len) = match numeric {
+            [b'x' | b'X', hex @ ..] => {
+                let digits = hex.iter().take_while(|b| b.is_ascii_hexdigit()).count();
+                (digits_value(&hex[..digits], 16), digits, 3 + digits)
+            },
+            decimal => {
+                let digits = decimal.iter().take_while(|b| b.is_ascii_digit()).count();
+                (digits_value(&decimal[..digits], 10), digits, 2 + digits)
+            },
+        };
+
+        if digits == 0 || text.get(len) != Some(&b';') { return Err((len + 1, INCOMPLETE)) }
+        return Ok(Some((scalar(value).ok_or((len + 1, INVALID))?, len + 1)));
+    }
+
+    let name = text[1..].iter().take_while(|b| b.is_ascii_alphanumeric()).count();
+    if name == 0 || text.get(1 + name) != Some(&b';') { return Ok(None) }
+
+    let reference = &text[..name + 2];
+    let entity = ENTITIES.iter()
+        .find(|entity| entity.entity.as_bytes() == reference)
+        .ok_or((reference.len(), UNKNOWN_ENTITY))?;
+
+    Ok(Some((Unescaped::Str(entity.characters), reference.len())))
+}
+
+// A `U+XXXX` value and its length, along with the length of any separators that come before
+// another value in the same list.
+fn code_point_list(text: &[u8]) -> Option<(u32, usize, usize)> {
+    let (value, digits) = hex(&text[2..], 6);
+    if digits < 4 || text.get(2 + digits).is_some_and(u8::is_ascii_hexdigit) { return None }
+
+    let len = 2 + digits;
+    let separators = text[len..].iter().take_while(|b| matches!(b, b' ' | b'\t' | b',')).count();
+    let next = &text[len + separators..];
+
+    let continues = matches!(next, [b'U' | b'u', b'+', ..])
+        && next[2..].iter().take_while(|b| b.is_ascii_hexdigit()).count() >= 4;
+
+    Some((value, len, if continues { separators } else { 0 }))
+}
+
+// Parses up to `max` hexadecimal digits, returning their value and how many there were.
+fn hex(text: &[u8], max: usize) -> (u32, usize) {
+    let digits = text.iter().take(max).take_while(|b| b.is_ascii_hexdigit()).count();
+    (digits_value(&text[..digits], 16), digits)
+}
+
+fn digits_value(digits: &[u8], radix: u32) -> u32 {
+    digits.iter().fold(0u32, |value, &b| {
+        value.saturating_mul(radix).saturating_add((b as char).to_digit(radix).unwrap_or(0))
+    })
+}
+
+fn scalar(value: u32) -> Option<Unescaped> {
+    match value {
+        0xD800..=0xDFFF => Some(Unescaped::Surrogate(value as u16)),
+        _ => char::from_u32(value).map(Unescaped::Char),
+    }
+}
+
+fn utf8_len(lead: u8) -> usize {
+    match lead {
+        0xF0.. => 4,
+        0xE0.. => 3,
+        0xC0.. => 2,
+        _ => 1,
+    }
+}
+
+impl Display for UnescapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} '{}' at byte {}", self.reason, self.escape, self.offset)
+    }
+}
+
+impl Error for UnescapeError { }
@@ -1,67 +1,199 @@
-use std::{cmp, ffi::OsStr, fmt::{self, Display}, hash, ops};
-use either::Either;
+use std::{cmp, collections::BTreeSet, ffi::OsStr, fmt::{self, Display}, hash, io::BufRead, ops};
 use smallvec::{SmallVec, smallvec};
 use unicode_segmentation::UnicodeSegmentation;
-use unicode::{Character, CharName, Diacritic, Direction};
-
+use decode::{Chunk, InvalidSequence};
+use unicode::{Character, CharName};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod bidi;
+mod case;
+mod confusable;
+mod decode;
+mod diff;
+mod escape;
+mod normalize;
+mod reader;
+mod restriction;
+mod span;
 mod unicode;
 
+pub use bidi::Paragraph;
+pub use case::{Case, CaseLocale};
+pub use confusable::skeleton;
+pub use decode::{Encoding, InvalidKind};
+pub use diff::{Edit, Equivalence};
+pub use escape::{EscapeStyle, UnescapeError, unescape};
+pub use normalize::{NormalizationForm, QuickCheck};
+pub use reader::TextReader;
+pub use restriction::{AugmentedScript, Restriction, RestrictionLevel};
+pub use span::{Offset, Span};
+pub use unicode::{
+    Age, BidiClass, Block, Bracket, Decomposition, DecompositionType, Diacritic, Direction,
+    GeneralCategory, Script,
+};
+
 // TODO: Reduce the size of each `Codepoint` or switch to some kind of iteration.
 
 // TODO: Add doc comments.
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Text(Vec<Grapheme>);
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Grapheme(SmallVec<[Codepoint; 1]>);
 
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Hash)]
-pub struct Codepoint(CodepointKind);
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerdeCodepoint", into = "SerdeCodepoint"))]
+pub struct Codepoint(CodepointKind, Offset, Encoding);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum Kind {
+    Character,
+    ControlCode,
+    NonCharacter,
+    PrivateUse,
+    Surrogate,
+    Unknown,
+    Invalid,
+}
 
 #[derive(Debug, Clone)]
 enum CodepointKind {
-    Character { c: char, width: u8, name: CharName, diacritic: Diacritic, direction: Direction },
+    Character {
+        c: char,
+        width: u8,
+        name: CharName,
+        diacritic: Diacritic,
+        direction: Direction,
+        category: GeneralCategory,
+        script: Script,
+    },
     ControlCode { c: char, code: &'static str, name: &'static str },
     NonCharacter(char),
     PrivateUse(char),
+    Surrogate { value: u16, bytes: [u8; 3] },
     Unknown(char),
-    Invalid(u8),
+    Invalid(InvalidSequence),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+// Codepoints are serialized as just their values and offsets, and their properties are found again
+// when they're deserialized.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Codepoint")]
+struct SerdeCodepoint {
+    value: CodepointValue,
+    offset: Offset,
+    #[serde(default, skip_serializing_if = "is_default")]
+    encoding: Encoding,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CodepointValue {
+    Scalar(char),
+    Surrogate(u16),
+    Invalid(Vec<u8>),
 }
 
 impl Text {
     pub fn parse_str(text: &str) -> Self {
-        Text(text.graphemes(true).map(Grapheme::from_valid).collect())
+        Text(text.graphemes(true).map(Grapheme::from_valid).collect()).at(Offset::default())
     }
 
+    // On Windows, OS strings are WTF-8, so any lone surrogates in them are kept.
     pub fn parse_os_str(text: &OsStr) -> Self {
-        Text::parse_bytes(text.as_encoded_bytes())
+        if cfg!(windows) {
+            Text::decode(text.as_encoded_bytes(), Encoding::Wtf8)
+        } else {
+            Text::parse_bytes(text.as_encoded_bytes())
+        }
     }
 
     pub fn parse_bytes(text: &[u8]) -> Self {
-        enum Utf8<'a> {
-            Valid(&'a str),
-            Invalid(&'a [u8]),
+        Text::decode(text, Encoding::Utf8)
+    }
+
+    // Decodes text from the given encoding. Byte order marks are kept as U+FEFF, and aren't used to
+    // detect the encoding; use `Encoding::sniff` for that.
+    pub fn decode(text: &[u8], encoding: Encoding) -> Self {
+        let mut graphemes = Vec::new();
+
+        for chunk in decode::decode(text, encoding) {
+            match chunk {
+                Chunk::Valid(valid) => {
+                    graphemes.extend(valid.graphemes(true).map(Grapheme::from_valid));
+                },
+                Chunk::Surrogate(surrogate) => graphemes.push(Grapheme(smallvec![
+                    Codepoint::from_surrogate(surrogate).expect("surrogates should be in range")
+                ])),
+                Chunk::Invalid(invalid) => graphemes.push(Grapheme(smallvec![
+                    Codepoint(CodepointKind::Invalid(invalid), Offset::default(), encoding)
+                ])),
+            }
+        }
+
+        for codepoint in graphemes.iter_mut().flat_map(|grapheme| grapheme.0.iter_mut()) {
+            codepoint.2 = encoding;
         }
 
-        Text(text.utf8_chunks()
-            .flat_map(|chunk| {
-                let (valid, invalid) = (chunk.valid(), chunk.invalid());
-                let mut items = SmallVec::<[Utf8; 2]>::new();
+        Text(graphemes).at(Offset::default())
+    }
+
+    // Decodes the escape sequences in some text, then parses the resulting bytes as WTF-8, so that
+    // escaped surrogates that aren't part of a pair are kept as surrogates.
+    pub fn unescape(text: &[u8]) -> Result<Self, UnescapeError> {
+        Ok(Text::decode(&escape::unescape(text)?, Encoding::Wtf8))
+    }
+
+    pub fn escape(&self, style: EscapeStyle) -> String { escape::escape(self, style) }
 
-                if !valid.is_empty() { items.push(Utf8::Valid(valid)) }
-                if !invalid.is_empty() { items.push(Utf8::Invalid(invalid)) }
+    pub fn normalize(&self, form: NormalizationForm) -> Text { normalize::normalize(self, form) }
 
-                items
-            })
-            .flat_map(|chunk| match chunk {
-                Utf8::Valid(chunk) => Either::Left(chunk.graphemes(true).map(Grapheme::from_valid)),
-                Utf8::Invalid(chunk) => Either::Right(
-                    chunk.iter().copied().map(Grapheme::from_invalid)
-                ),
-            })
-            .collect()
-        )
+    // Whether the text is normalized, according to the quick check algorithm.
+    pub fn quick_check(&self, form: NormalizationForm) -> QuickCheck {
+        normalize::quick_check(self, form)
+    }
+
+    pub fn is_normalized(&self, form: NormalizationForm) -> bool {
+        normalize::is_normalized(self, form)
+    }
+
+    // Converts the text to a case with the full case mappings, including the rules that depend on
+    // context or language, like the final form of sigma.
+    pub fn to_case(&self, case: Case, locale: CaseLocale) -> Text {
+        case::to_case(self, case, locale)
+    }
+
+    pub fn skeleton(&self) -> Text { confusable::skeleton(self) }
+
+    // Aligns this text with another by grapheme, to show how to turn one into the other.
+    pub fn diff<'a>(&'a self, other: &'a Text) -> Vec<Edit<&'a Grapheme>> {
+        diff::graphemes(self, other)
+    }
+
+    pub fn is_equivalent(&self, other: &Text, equivalence: Equivalence) -> bool {
+        diff::is_equivalent(self, other, equivalence)
+    }
+
+    // Runs the Unicode Bidirectional Algorithm over each paragraph of the text.
+    pub fn bidi_paragraphs(&self) -> Vec<Paragraph> { bidi::paragraphs(self) }
+
+    pub fn parse_reader<R: BufRead>(reader: R) -> TextReader<R> {
+        TextReader::new(reader)
     }
 
     pub fn graphemes(&self) -> impl Iterator<Item = &Grapheme> { self.0.iter() }
@@ -74,6 +206,30 @@ impl Text {
     pub fn into_codepoints(self) -> impl Iterator<Item = Codepoint> {
         self.into_graphemes().flat_map(Grapheme::into_codepoints)
     }
+
+    pub fn span(&self) -> Span { span_of(self.codepoints()) }
+
+    // The most restrictive level of UTS #39 that the text meets, which tells whether it's safe to
+    // use as an identifier.
+    pub fn restriction(&self) -> Restriction<'_> { restriction::restriction(self) }
+
+    // Every script used in the text, including Common and Inherited, sorted by name.
+    pub fn scripts(&self) -> Vec<Script> {
+        let scripts = self.codepoints().filter_map(Codepoint::script).collect::<BTreeSet<_>>();
+        scripts.into_iter().collect()
+    }
+
+    // Moves the text so that it starts at the given offset.
+    fn at(mut self, start: Offset) -> Self {
+        let mut offset = start;
+
+        for codepoint in self.0.iter_mut().flat_map(|grapheme| grapheme.0.iter_mut()) {
+            codepoint.1 = offset;
+            offset = codepoint.span().end;
+        }
+
+        self
+    }
 }
 
 impl Grapheme {
@@ -87,6 +243,13 @@ impl Grapheme {
 
     pub fn codepoints(&self) -> impl Iterator<Item = &Codepoint> { self.0.iter() }
     pub fn into_codepoints(self) -> impl Iterator<Item = Codepoint> { self.0.into_iter() }
+
+    pub fn span(&self) -> Span { span_of(self.codepoints()) }
+
+    // Aligns this grapheme with another by codepoint.
+    pub fn diff<'a>(&'a self, other: &'a Grapheme) -> Vec<Edit<&'a Codepoint>> {
+        diff::codepoints(self, other)
+    }
 }
 
 impl Codepoint {
@@ -106,26 +269,236 @@ impl Codepoint {
                     name,
                     diacritic: c.diacritic(),
                     direction: c.direction(),
+                    category: c.general_category(),
+                    script: c.script(),
                 }
             } else { Ck::Unknown(c) },
-        })
+        }, Offset::default(), Encoding::Utf8)
     }
 
     pub fn from_invalid(byte: u8) -> Self {
-        Codepoint(CodepointKind::Invalid(byte))
+        let invalid = InvalidSequence::utf8(&[byte]);
+        Codepoint(CodepointKind::Invalid(invalid), Offset::default(), Encoding::Utf8)
+    }
+
+    // Lone surrogates are encoded in WTF-8.
+    pub fn from_surrogate(value: u16) -> Option<Self> {
+        (0xD800..=0xDFFF).contains(&value).then(|| {
+            let kind = CodepointKind::Surrogate { value, bytes: decode::encode_wtf8(value) };
+            Codepoint(kind, Offset::default(), Encoding::Wtf8)
+        })
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        unicode::character(name).map(Codepoint::from_valid)
+    }
+
+    pub fn value(&self) -> Result<char, &[u8]> { self.0.value() }
+    pub fn kind(&self) -> Kind { self.0.kind() }
+
+    pub fn invalid_kind(&self) -> Option<InvalidKind> {
+        match self.0 {
+            CodepointKind::Invalid(invalid) => Some(invalid.kind()),
+            _ => None,
+        }
+    }
+
+    pub fn surrogate(&self) -> Option<u16> {
+        match self.0 {
+            CodepointKind::Surrogate { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn offset(&self) -> Offset { self.1 }
+    pub fn encoding(&self) -> Encoding { self.2 }
+
+    // Bytes are counted in the encoding the codepoint was decoded from. Invalid sequences are
+    // otherwise counted as if they had been replaced with U+FFFD REPLACEMENT CHARACTER.
+    pub fn span(&self) -> Span {
+        let len = match self.value() {
+            Ok(c) => Offset { byte: self.2.encoded_len(c), char: 1, utf16: c.len_utf16() },
+            Err(bytes) => Offset { byte: bytes.len(), char: 1, utf16: 1 },
+        };
+
+        Span { start: self.1, end: self.1 + len }
+    }
+
+    pub fn width(&self) -> Option<usize> {
+        match self.0 {
+            CodepointKind::Character { width, .. } => Some(width as usize),
+            _ => None,
+        }
+    }
+
+    pub fn diacritic(&self) -> Option<Diacritic> {
+        match self.0 {
+            CodepointKind::Character { diacritic, .. } => Some(diacritic),
+            _ => None,
+        }
+    }
+
+    pub fn direction(&self) -> Option<Direction> {
+        match self.0 {
+            CodepointKind::Character { direction, .. } => Some(direction),
+            _ => None,
+        }
+    }
+
+    // Unlike `direction`, which is simplified for display, these are the full bidi properties of
+    // any scalar value, including defaults for unassigned codepoints.
+    pub fn bidi_class(&self) -> Option<BidiClass> { self.value().ok().map(|c| c.bidi_class()) }
+    pub fn bidi_mirrored(&self) -> bool { self.value().is_ok_and(|c| c.bidi_mirrored()) }
+
+    pub fn bidi_paired_bracket(&self) -> Option<Bracket> {
+        self.value().ok().and_then(|c| c.bidi_paired_bracket())
+    }
+
+    // Every codepoint except an invalid sequence has a general category, even if it's unassigned.
+    pub fn general_category(&self) -> Option<GeneralCategory> {
+        use CodepointKind as Ck;
+
+        match self.0 {
+            Ck::Character { category, .. } => Some(category),
+            Ck::ControlCode { c, .. } | Ck::Unknown(c) => Some(c.general_category()),
+            Ck::NonCharacter(_) => Some(GeneralCategory::Unassigned),
+            Ck::PrivateUse(_) => Some(GeneralCategory::PrivateUse),
+            Ck::Surrogate { .. } => Some(GeneralCategory::Surrogate),
+            Ck::Invalid(_) => None,
+        }
+    }
+
+    // Codepoints that aren't assigned to characters have the Unknown script.
+    pub fn script(&self) -> Option<Script> {
+        use CodepointKind as Ck;
+
+        match self.0 {
+            Ck::Character { script, .. } => Some(script),
+            Ck::ControlCode { c, .. } => Some(c.script()),
+            Ck::NonCharacter(_) | Ck::PrivateUse(_) | Ck::Surrogate { .. } | Ck::Unknown(_) => {
+                Some(Script::UNKNOWN)
+            },
+            Ck::Invalid(_) => None,
+        }
+    }
+
+    // The scripts that a character is used with, which are usually just its own script. Common and
+    // Inherited characters that are only used with some scripts are listed with those scripts.
+    pub fn script_extensions(&self) -> Option<Vec<Script>> {
+        match self.0 {
+            CodepointKind::Character { c, .. } | CodepointKind::ControlCode { c, .. } => {
+                Some(c.script_extensions())
+            },
+            _ => self.script().map(|script| vec![script]),
+        }
+    }
+
+    pub fn block(&self) -> Option<Block> {
+        match (self.value(), self.surrogate()) {
+            (_, Some(surrogate)) => unicode::block(surrogate as u32),
+            (Ok(c), _) => c.block(),
+            (Err(_), _) => None,
+        }
+    }
+
+    pub fn age(&self) -> Option<Age> {
+        match (self.value(), self.surrogate()) {
+            (_, Some(surrogate)) => unicode::age(surrogate as u32),
+            (Ok(c), _) => c.age(),
+            (Err(_), _) => None,
+        }
+    }
+
+    // The Decomposition_Type and Decomposition_Mapping of the character, which are just one step of
+    // decomposition.
+    pub fn decomposition(&self) -> Option<Decomposition> {
+        self.value().ok().and_then(|c| c.decomposition())
+    }
+
+    // The full canonical decomposition of the character, as in NFD.
+    pub fn canonical_decomposition(&self) -> Option<Vec<char>> {
+        self.value().ok().and_then(|c| c.canonical_decomposition())
+    }
+
+    // The full compatibility decomposition of the character, as in NFKD.
+    pub fn compatibility_decomposition(&self) -> Option<Vec<char>> {
+        self.value().ok().and_then(|c| c.compatibility_decomposition())
+    }
+
+    // Surrogates have a combining class of 0, like every codepoint that isn't a combining mark.
+    pub fn canonical_combining_class(&self) -> Option<u8> {
+        match (self.value(), self.surrogate()) {
+            (_, Some(_)) => Some(0),
+            (Ok(c), _) => Some(c.canonical_combining_class()),
+            (Err(_), _) => None,
+        }
+    }
+
+    // The full case mappings of the character, without the rules that depend on its context or
+    // language. See `Text::to_case` for those.
+    pub fn uppercase(&self) -> Option<Vec<char>> { self.value().ok().map(|c| c.uppercase()) }
+    pub fn lowercase(&self) -> Option<Vec<char>> { self.value().ok().map(|c| c.lowercase()) }
+    pub fn titlecase(&self) -> Option<Vec<char>> { self.value().ok().map(|c| c.titlecase()) }
+    pub fn case_folding(&self) -> Option<Vec<char>> { self.value().ok().map(|c| c.case_folding()) }
+
+    pub fn default_ignorable(&self) -> bool { self.value().is_ok_and(|c| c.default_ignorable()) }
+    pub fn white_space(&self) -> bool { self.value().is_ok_and(|c| c.white_space()) }
+
+    // The confusable prototype of the character from UTS #39, or `None` if it's its own prototype.
+    pub fn prototype(&self) -> Option<Vec<char>> {
+        self.value().ok().and_then(|c| c.prototype())
     }
 
-    pub fn value(&self) -> Result<char, u8> { self.0.value() }
     pub fn name(&self) -> String { self.display_name().to_string() }
 
     pub fn display_value(&self) -> impl Display + '_ {
+        display_with(|f| match (self.value(), self.surrogate()) {
+            (_, Some(surrogate)) => decode::display_scalar(surrogate as u32).fmt(f),
+            (Ok(c), _) => decode::display_scalar(c as u32).fmt(f),
+            (Err(bytes), _) => {
+                for (i, b) in bytes.iter().enumerate() {
+                    if i > 0 { f.write_str(" ")? }
+                    write!(f, "0x{b:02X}")?;
+                }
+
+                Ok(())
+            },
+        })
+    }
+
+    // The UTF-8 encoding of the codepoint, or the bytes of an invalid sequence.
+    pub fn display_bytes(&self) -> impl Display + '_ {
         display_with(|f| match self.value() {
-            Ok(c @ ..='\u{FFFF}') => write!(f, "U+{:04X}", c as u16),
-            Ok(c @ '\u{10000}'..) => write!(f, "U+{:06X}", c as u32),
-            Err(b) => write!(f, "0x{b:02X}"),
+            Ok(_) => write_units(f, self.0.encode(|c, buf| c.encode_utf8(buf).as_bytes()), |b| b),
+            Err(bytes) => write_units(f, Some(SmallVec::<[u8; 4]>::from_slice(bytes)), |b| b),
         })
     }
 
+    // The UTF-16 code units of the codepoint, with the bytes of each unit in the given order. Lone
+    // surrogates are a single code unit, as they would be in potentially ill-formed UTF-16.
+    pub fn display_utf16(&self, order: ByteOrder) -> impl Display + '_ {
+        display_with(move |f| write_units(
+            f,
+            match self.surrogate() {
+                Some(surrogate) => Some(smallvec![surrogate]),
+                None => self.0.encode(|c, buf| c.encode_utf16(buf)),
+            },
+            |unit| if order == ByteOrder::LittleEndian { unit.swap_bytes() } else { unit },
+        ))
+    }
+
+    // The UTF-32 code unit of the codepoint, with its bytes in the given order.
+    pub fn display_utf32(&self, order: ByteOrder) -> impl Display + '_ {
+        display_with(move |f| write_units(
+            f,
+            match self.surrogate() {
+                Some(surrogate) => Some(smallvec![surrogate as u32]),
+                None => self.0.encode(|c, buf| { buf[0] = c as u32; &buf[..1] }),
+            },
+            |unit| if order == ByteOrder::LittleEndian { unit.swap_bytes() } else { unit },
+        ))
+    }
+
     pub fn display_character(&self) -> impl Display + '_ {
         use CodepointKind as Ck;
         use Diacritic as Dc;
@@ -144,6 +517,7 @@ impl Codepoint {
             Ck::ControlCode { code, .. } => write!(f, "{code}"),
             Ck::NonCharacter(_) => write!(f, "∅"),
             Ck::PrivateUse(_) => write!(f, "▨"),
+            Ck::Surrogate { .. } => write!(f, "◐"),
             Ck::Unknown(_) => write!(f, "?"),
             Ck::Invalid(_) => write!(f, "�"),
         })
@@ -157,12 +531,41 @@ impl Codepoint {
             Ck::ControlCode { name, .. } => name.fmt(f),
             Ck::NonCharacter(_) => f.write_str("NOT A CHARACTER"),
             Ck::PrivateUse(_) => f.write_str("RESERVED FOR PRIVATE USE"),
+            Ck::Surrogate { value: 0xD800..=0xDB7F, .. } => f.write_str("HIGH SURROGATE"),
+            Ck::Surrogate { value: 0xDB80..=0xDBFF, .. } => {
+                f.write_str("HIGH PRIVATE USE SURROGATE")
+            },
+            Ck::Surrogate { .. } => f.write_str("LOW SURROGATE"),
             Ck::Unknown(_) => f.write_str("UNKNOWN CHARACTER"),
-            Ck::Invalid(_) => f.write_str("INVALID UTF-8"),
+            Ck::Invalid(invalid) => decode::display_invalid(invalid.kind(), self.2).fmt(f),
         })
     }
 }
 
+fn span_of<'a>(mut codepoints: impl Iterator<Item = &'a Codepoint>) -> Span {
+    match codepoints.next() {
+        Some(first) => Span { start: first.1, end: codepoints.last().unwrap_or(first).span().end },
+        None => Span::default(),
+    }
+}
+
+// Writes code units in hexadecimal, or a dash if there aren't any.
+fn write_units<T: fmt::UpperHex + Copy>(
+    f: &mut fmt::Formatter,
+    units: Option<SmallVec<[T; 4]>>,
+    order: impl Fn(T) -> T,
+) -> fmt::Result {
+    let Some(units) = units else { return f.write_str("-") };
+    let width = 2 * size_of::<T>();
+
+    for (i, unit) in units.iter().enumerate() {
+        if i > 0 { f.write_str(" ")? }
+        write!(f, "{:0width$X}", order(*unit))?;
+    }
+
+    Ok(())
+}
+
 fn display_with(f: impl Fn(&mut fmt::Formatter) -> fmt::Result) -> impl Display {
     struct Displayer<F>(F);
     impl<F> Display for Displayer<F> where F: Fn(&mut fmt::Formatter) -> fmt::Result {
@@ -210,14 +613,14 @@ impl Display for Codepoint {
 
         match self.0 {
             Ck::Character { .. } | Ck::ControlCode { .. } => self.display_character().fmt(f),
-            Ck::NonCharacter(_) | Ck::PrivateUse(_) | Ck::Unknown(_) | Ck::Invalid(_)
-                => self.display_value().fmt(f),
+            Ck::NonCharacter(_) | Ck::PrivateUse(_) | Ck::Surrogate { .. } | Ck::Unknown(_) |
+            Ck::Invalid(_) => self.display_value().fmt(f),
         }
     }
 }
 
 impl CodepointKind {
-    fn value(&self) -> Result<char, u8> {
+    fn value(&self) -> Result<char, &[u8]> {
         use CodepointKind as Ck;
 
         match &self {
@@ -226,26 +629,121 @@ impl CodepointKind {
             Ck::ControlCode { c, .. } |
             Ck::PrivateUse(c) |
             Ck::Unknown(c) => Ok(*c),
-            Ck::Invalid(b) => Err(*b),
+            Ck::Surrogate { bytes, .. } => Err(bytes),
+            Ck::Invalid(invalid) => Err(invalid.bytes()),
+        }
+    }
+
+    fn key(&self) -> (Result<char, &[u8]>, bool) {
+        (self.value(), matches!(self, CodepointKind::Surrogate { .. }))
+    }
+
+    // Encodes the codepoint's value into code units, if it's valid.
+    fn encode<T: Copy + Default>(
+        &self,
+        encode: impl Fn(char, &mut [T; 4]) -> &[T],
+    ) -> Option<SmallVec<[T; 4]>> {
+        self.value().ok().map(|c| SmallVec::from_slice(encode(c, &mut [T::default(); 4])))
+    }
+
+    fn kind(&self) -> Kind {
+        use CodepointKind as Ck;
+
+        match self {
+            Ck::Character { .. } => Kind::Character,
+            Ck::ControlCode { .. } => Kind::ControlCode,
+            Ck::NonCharacter(_) => Kind::NonCharacter,
+            Ck::PrivateUse(_) => Kind::PrivateUse,
+            Ck::Surrogate { .. } => Kind::Surrogate,
+            Ck::Unknown(_) => Kind::Unknown,
+            Ck::Invalid(_) => Kind::Invalid,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerdeCodepoint> for Codepoint {
+    type Error = String;
+
+    fn try_from(
+        SerdeCodepoint { value, offset, encoding }: SerdeCodepoint,
+    ) -> Result<Self, String> {
+        let kind = match value {
+            CodepointValue::Scalar(c) => Codepoint::from_valid(c).0,
+            CodepointValue::Surrogate(value) => match Codepoint::from_surrogate(value) {
+                Some(surrogate) => surrogate.0,
+                None => return Err(format!("0x{value:04X} is not a surrogate")),
+            },
+            CodepointValue::Invalid(bytes) => match decode::decode(&bytes, encoding).next() {
+                Some(Chunk::Invalid(invalid)) if invalid.bytes() == bytes => {
+                    CodepointKind::Invalid(invalid)
+                },
+                _ => {
+                    return Err(format!("{bytes:02X?} is not a single invalid {encoding} sequence"));
+                },
+            },
+        };
+
+        Ok(Codepoint(kind, offset, encoding))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Codepoint> for SerdeCodepoint {
+    fn from(codepoint: Codepoint) -> Self {
+        SerdeCodepoint {
+            value: match (codepoint.value(), codepoint.surrogate()) {
+                (_, Some(surrogate)) => CodepointValue::Surrogate(surrogate),
+                (Ok(c), _) => CodepointValue::Scalar(c),
+                (Err(bytes), _) => CodepointValue::Invalid(bytes.to_vec()),
+            },
+            offset: codepoint.1,
+            encoding: codepoint.2,
         }
     }
 }
 
+#[cfg(feature = "serde")]
+fn is_default<T: Default + PartialEq>(value: &T) -> bool { *value == T::default() }
+
+// Codepoints are compared by value only, regardless of where they are.
+impl cmp::Eq for Codepoint { }
+
+impl cmp::PartialEq for Codepoint {
+    fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+}
+
+impl cmp::PartialOrd for Codepoint {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> { self.0.partial_cmp(&other.0) }
+}
+
+impl hash::Hash for Codepoint {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) { self.0.hash(state) }
+}
+
 impl cmp::Eq for CodepointKind { }
 
+// Surrogates have the same bytes as the invalid UTF-8 that would encode them, so they're also
+// distinguished by whether they're surrogates.
 impl cmp::PartialEq for CodepointKind {
-    fn eq(&self, other: &Self) -> bool { self.value() == other.value() }
+    fn eq(&self, other: &Self) -> bool { self.key() == other.key() }
 }
 
 impl cmp::PartialOrd for CodepointKind {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.value().partial_cmp(&other.value())
+        self.key().partial_cmp(&other.key())
     }
 }
 
 impl hash::Hash for CodepointKind {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.value().hash(state)
+        self.key().hash(state)
+    }
+}
+
+impl FromIterator<Grapheme> for Text {
+    fn from_iter<I: IntoIterator<Item = Grapheme>>(graphemes: I) -> Self {
+        Text(graphemes.into_iter().collect())
     }
 }
 
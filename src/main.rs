@@ -1,25 +1,695 @@
-use std::env;
-use unicode_analyze::Text;
+use std::{
+    collections::BTreeSet, ffi::OsString, io::{self, IsTerminal}, iter, path::PathBuf, process,
+};
+use clap::{ArgMatches, Args, FromArgMatches, Parser, Subcommand};
+use unicode_analyze::{
+    Age, BidiClass, Case, CaseLocale, Codepoint, Direction, Edit, Encoding, Equivalence, Kind,
+    NormalizationForm, QuickCheck, RestrictionLevel, Text,
+};
+use cli::{
+    Analysis, Columns, Decoder, Escape, Format, Input, InputEncoding, Language, Level,
+    Normalization, Texts,
+};
 
-// TODO: Use `clap` to parse arguments, allowing for `--help`, `--license`, `--file`, etc. Blocked
-// on binary-only dependencies: see <https://github.com/rust-lang/cargo/issues/1982>.
+mod cli;
+
+const LICENSE: &str = "\
+Copyright © 2023–2025 Esper Thomson
+
+This program is free software: you can redistribute it and/or modify it under the terms of version 3
+of the GNU Affero General Public License, as published by the Free Software Foundation.
+
+This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without
+even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+General Public License for more details.
+
+You should have received a copy of the GNU Affero General Public License along with this program. If
+not, see <https://www.gnu.org/licenses/>.";
+
+/// Investigate strings of UTF-8 text.
+///
+/// With no subcommand, the given text is analyzed.
+#[derive(Parser)]
+#[command(version, args_conflicts_with_subcommands = true)]
+struct Cli {
+    /// Print license information and exit
+    #[arg(long)]
+    license: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    analyze: AnalyzeArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show the graphemes and codepoints that make up some text
+    Analyze(AnalyzeArgs),
+    /// Look up codepoints by value (such as U+00E9) or by name
+    Lookup(LookupArgs),
+    /// Search for characters with names containing all of the given words
+    Search(SearchArgs),
+    /// Count the bytes, codepoints and graphemes in some text
+    Stats(InputArgs),
+    /// Run the Unicode Bidirectional Algorithm, showing embedding levels and visual order
+    Bidi(BidiArgs),
+    /// Report bidirectional controls that are unterminated or nested, and fail if there are any
+    Lint(LintArgs),
+    /// Compare two texts codepoint by codepoint, and fail if they aren't identical
+    Diff(DiffArgs),
+    /// Show the uppercase, lowercase, titlecase and case folded forms of some text
+    Case(CaseArgs),
+    /// Find the UTS #39 restriction level of an identifier, and the codepoints that lowered it
+    Restriction(RestrictionArgs),
+}
+
+#[derive(Args)]
+struct InputArgs {
+    #[command(flatten)]
+    sources: Sources,
+
+    /// Decode text from this encoding, instead of detecting it from a byte order mark
+    #[arg(long, value_enum)]
+    encoding: Option<InputEncoding>,
+
+    /// Decode escape sequences, like '\u{1F600}', '\x41', 'U+0041' or '&amp;', before analyzing
+    #[arg(long)]
+    unescape: bool,
+
+    /// Normalize text to this form before analyzing it
+    #[arg(long, value_enum, value_name = "FORM")]
+    normalize: Option<Normalization>,
+}
+
+// The texts and files to read, in the order they were given. Clap collects them separately, so
+// they're put back in order by the indices of their values.
+struct Sources(Vec<Input>);
+
+#[derive(Args)]
+struct SourceArgs {
+    /// Text to analyze, or '-' to read standard input
+    #[arg(value_name = "TEXT")]
+    text: Vec<OsString>,
+
+    /// Read text from a file
+    #[arg(short, long, value_name = "PATH")]
+    file: Vec<PathBuf>,
+}
+
+#[derive(Args)]
+struct OutputArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct AnalyzeArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Print the text as an escaped literal instead of analyzing it
+    #[arg(long, value_enum, value_name = "STYLE", conflicts_with = "format")]
+    escape: Option<Escape>,
+
+    /// Print the confusable skeleton of the text, and fail if any codepoint looks like another
+    #[arg(long, conflicts_with_all = ["format", "escape"])]
+    skeleton: bool,
+
+    /// List codepoints that are invisible or blank, other than spaces, tabs and line breaks, and
+    /// fail if there are any
+    #[arg(long, conflicts_with_all = ["format", "escape", "skeleton"])]
+    invisible: bool,
+
+    /// Report characters added after this version of Unicode, like '9.0', and fail if there are any
+    #[arg(long, value_name = "VERSION", value_parser = cli::parse_age, conflicts_with = "escape")]
+    max_age: Option<Age>,
+
+    #[command(flatten)]
+    columns: Columns,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args)]
+struct LintArgs {
+    /// Files to check, or '-' to read standard input
+    #[arg(value_name = "PATH")]
+    path: Vec<PathBuf>,
+
+    /// Decode files from this encoding, instead of detecting it from a byte order mark
+    #[arg(long, value_enum)]
+    encoding: Option<InputEncoding>,
+}
+
+#[derive(Args)]
+struct BidiArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    columns: Columns,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    columns: Columns,
+}
+
+#[derive(Args)]
+struct CaseArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Use the case mapping rules of this language
+    #[arg(long, value_enum, value_name = "LANGUAGE")]
+    language: Option<Language>,
+
+    #[command(flatten)]
+    columns: Columns,
+}
+
+#[derive(Args)]
+struct RestrictionArgs {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Fail if an input is less restrictive than this level
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    max_level: Option<Level>,
+
+    #[command(flatten)]
+    columns: Columns,
+}
+
+#[derive(Args)]
+struct LookupArgs {
+    /// Codepoint values or character names
+    #[arg(value_name = "QUERY", required = true)]
+    queries: Vec<String>,
+
+    #[command(flatten)]
+    columns: Columns,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args)]
+struct SearchArgs {
+    /// Words to search for
+    #[arg(value_name = "WORD", required = true)]
+    words: Vec<String>,
+
+    #[command(flatten)]
+    columns: Columns,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
 
 fn main() {
-    let mut args = env::args_os();
-    args.next();
+    let cli = Cli::parse();
+
+    if cli.license {
+        println!("{LICENSE}");
+        return;
+    }
+
+    let success = match cli.command {
+        None => analyze(&cli.analyze),
+        Some(Command::Analyze(args)) => analyze(&args),
+        Some(Command::Lookup(args)) => lookup(&args),
+        Some(Command::Search(args)) => search(&args),
+        Some(Command::Stats(input)) => stats(&input),
+        Some(Command::Bidi(args)) => bidi(&args),
+        Some(Command::Lint(args)) => lint(&args),
+        Some(Command::Diff(args)) => diff(&args),
+        Some(Command::Case(args)) => case(&args),
+        Some(Command::Restriction(args)) => restriction(&args),
+    };
+
+    if !success { process::exit(1) }
+}
+
+fn analyze(args: &AnalyzeArgs) -> bool {
+    if let Some(style) = args.escape { return escape(args, style) }
+    if args.skeleton { return skeleton(args) }
+    if args.invisible { return invisible(args) }
 
-    for arg in args {
-        let text = Text::parse_os_str(&arg);
-        println!("{text}");
+    let mut analysis = Analysis::new(args.output.format, args.columns, args.max_age);
+    let success = cli::for_each_input(args.input.inputs(), args.input.decoder(), |input, texts| {
+        analysis.input(input, texts)
+    });
+
+    analysis.finish() && success
+}
+
+fn escape(args: &AnalyzeArgs, style: Escape) -> bool {
+    cli::for_each_input(args.input.inputs(), args.input.decoder(), |input, texts| {
+        input.print_header();
+        let texts = texts.collect::<io::Result<Vec<_>>>()?;
+        let text = texts.into_iter().flat_map(Text::into_graphemes).collect::<Text>();
+        println!("{}", text.escape(style.into()));
+        Ok(())
+    })
+}
+
+// Marks each codepoint that isn't its own prototype, meaning it can be mistaken for other
+// characters.
+fn skeleton(args: &AnalyzeArgs) -> bool {
+    let columns = Columns { confusable: true, ..args.columns };
+    let mut confusable = false;
+    let success = cli::for_each_input(args.input.inputs(), args.input.decoder(), |input, texts| {
+        input.print_header();
+        let texts = texts.collect::<io::Result<Vec<_>>>()?;
+        let text = texts.into_iter().flat_map(Text::into_graphemes).collect::<Text>();
+        println!("skeleton: {}", text.skeleton());
 
         for codepoint in text.codepoints() {
-            let (value, character, name) = (
-                codepoint.display_value(),
-                codepoint.display_character(),
-                codepoint.display_name(),
-            );
+            let marker = if codepoint.prototype().is_some() { '!' } else { ' ' };
+            confusable |= marker == '!';
+            print!("{marker} ");
+            cli::print_codepoint(codepoint, columns);
+        }
+
+        Ok(())
+    });
+
+    success && !confusable
+}
+
+// Finds the codepoints that render as nothing or as blank space, which can hide in text that looks
+// normal, like a no-break space in a config file.
+fn invisible(args: &AnalyzeArgs) -> bool {
+    let columns = Columns { offsets: true, properties: true, ..args.columns };
+    let mut found = false;
+    let success = cli::for_each_input(args.input.inputs(), args.input.decoder(), |input, texts| {
+        input.print_header();
+
+        for text in texts {
+            for codepoint in text?.codepoints() {
+                if matches!(codepoint.value(), Ok(' ' | '\t' | '\n' | '\r')) { continue }
+
+                if codepoint.white_space() || codepoint.default_ignorable()
+                    || codepoint.kind() == Kind::ControlCode
+                {
+                    cli::print_codepoint(codepoint, columns);
+                    found = true;
+                }
+            }
+        }
+
+        Ok(())
+    });
+
+    success && !found
+}
+
+fn lookup(args: &LookupArgs) -> bool {
+    let mut success = true;
+    let codepoints = args.queries.iter()
+        .filter_map(|query| {
+            let codepoint = parse_query(query);
+
+            if codepoint.is_none() {
+                eprintln!("unicode-analyze: no character found for '{query}'");
+                success = false;
+            }
+
+            codepoint
+        })
+        .collect::<Vec<_>>();
+
+    cli::print_codepoints(args.output.format, args.columns, &codepoints);
+    success
+}
+
+fn search(args: &SearchArgs) -> bool {
+    let words = args.words.iter().map(|word| word.to_uppercase()).collect::<Vec<_>>();
+    let codepoints = ('\0'..=char::MAX)
+        .map(Codepoint::from_valid)
+        .filter(|codepoint| matches!(codepoint.kind(), Kind::Character | Kind::ControlCode))
+        .filter(|codepoint| {
+            let name = codepoint.name();
+            words.iter().all(|word| name.contains(word.as_str()))
+        })
+        .collect::<Vec<_>>();
+
+    cli::print_codepoints(args.output.format, args.columns, &codepoints);
+    !codepoints.is_empty()
+}
+
+fn stats(input: &InputArgs) -> bool {
+    const KINDS: [(Kind, &str); 7] = [
+        (Kind::Character, "characters"),
+        (Kind::ControlCode, "control codes"),
+        (Kind::NonCharacter, "noncharacters"),
+        (Kind::PrivateUse, "private use"),
+        (Kind::Surrogate, "surrogates"),
+        (Kind::Unknown, "unknown"),
+        (Kind::Invalid, "invalid"),
+    ];
+
+    cli::for_each_input(input.inputs(), input.decoder(), |input, texts| {
+        input.print_header();
+        let (mut bytes, mut codepoints, mut graphemes) = (0, 0, 0);
+        let mut kinds = [0; KINDS.len()];
+        let mut scripts = BTreeSet::new();
+        let mut normalized = NormalizationForm::ALL.map(|form| (form, QuickCheck::Yes, true));
+
+        for text in texts {
+            let text = text?;
+            graphemes += text.graphemes().filter(|g| !g.is_empty()).count();
+            scripts.extend(text.scripts());
+
+            for (form, check, is_normalized) in &mut normalized {
+                *check = text.quick_check(*form).max(*check);
+                *is_normalized &= text.is_normalized(*form);
+            }
+
+            for codepoint in text.codepoints() {
+                bytes += codepoint.span().bytes().len();
+                codepoints += 1;
+                kinds[KINDS.iter().position(|(k, _)| *k == codepoint.kind()).unwrap()] += 1;
+            }
+        }
+
+        println!("bytes: {bytes}");
+        println!("codepoints: {codepoints}");
+        println!("graphemes: {graphemes}");
+
+        for ((_, label), count) in KINDS.iter().zip(kinds) {
+            if count > 0 { println!("{label}: {count}") }
+        }
+
+        if !scripts.is_empty() {
+            let scripts = scripts.iter().map(|script| script.full_name()).collect::<Vec<_>>();
+            println!("scripts: {}", scripts.join(", "));
+        }
+
+        for (form, check, is_normalized) in normalized {
+            let answer = if is_normalized { "yes" } else { "no" };
+
+            match check {
+                QuickCheck::Maybe => println!("{}: {answer} (quick check: maybe)", form.name()),
+                _ => println!("{}: {answer}", form.name()),
+            }
+        }
+
+        Ok(())
+    })
+}
+
+fn bidi(args: &BidiArgs) -> bool {
+    cli::for_each_input(args.input.inputs(), args.input.decoder(), |input, texts| {
+        input.print_header();
+
+        for text in texts {
+            for paragraph in text?.bidi_paragraphs() {
+                let direction = if paragraph.direction() == Direction::Rtl { "rtl" } else { "ltr" };
+                let visual = paragraph.visual().map(|c| c.to_string()).collect::<Vec<_>>();
+                println!("paragraph: {direction}, level {}", paragraph.level());
+                println!("visual: [{}]", visual.join(", "));
+
+                for (codepoint, level) in paragraph.codepoints().zip(paragraph.levels()) {
+                    match level {
+                        Some(level) => print!("{level} "),
+                        None => print!("x "),
+                    }
+
+                    cli::print_codepoint(codepoint, args.columns);
+                }
+
+                for codepoint in paragraph.unmatched() {
+                    let (value, name) = (codepoint.display_value(), codepoint.display_name());
+                    println!("unmatched: {value} {name} at byte {}", codepoint.offset().byte);
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
+
+// Finds the embeddings, overrides and isolates that Trojan Source attacks (CVE-2021-42574) use to
+// make code display differently from how it's compiled, line by line.
+// Checks files rather than text, so that it can be given the paths of changed files.
+fn lint(args: &LintArgs) -> bool {
+    let mut inputs = args.path.iter()
+        .map(|path| match path.to_str() {
+            Some("-") => Input::Stdin,
+            _ => Input::File(path.clone()),
+        })
+        .collect::<Vec<_>>();
+
+    if inputs.is_empty() && !io::stdin().is_terminal() { inputs.push(Input::Stdin) }
+
+    let decoder = Decoder { encoding: args.encoding.map(Encoding::from), ..Decoder::default() };
+    let mut clean = true;
+    let success = cli::for_each_input(inputs, decoder, |input, texts| {
+        let mut line = 1;
+
+        for text in whole_lines(texts) {
+            for paragraph in text?.bidi_paragraphs() {
+                let location = match input {
+                    Input::Argument(..) => format!("line {line}"),
+                    Input::File(path) => format!("{}:{line}", path.display()),
+                    Input::Stdin => format!("standard input:{line}"),
+                };
+
+                let unmatched = paragraph.unmatched().map(|c| c.offset()).collect::<Vec<_>>();
+                let nested = paragraph.nested().map(|c| c.offset()).collect::<Vec<_>>();
+
+                for codepoint in paragraph.codepoints() {
+                    let closes = matches!(
+                        codepoint.bidi_class(),
+                        Some(BidiClass::PopDirectionalFormat | BidiClass::PopDirectionalIsolate),
+                    );
+
+                    let problem = match (
+                        unmatched.contains(&codepoint.offset()),
+                        nested.contains(&codepoint.offset()),
+                    ) {
+                        (true, _) if closes => "unmatched",
+                        (true, true) => "nested and unterminated",
+                        (true, false) => "unterminated",
+                        (false, true) => "nested",
+                        (false, false) => continue,
+                    };
+
+                    let (value, name) = (codepoint.display_value(), codepoint.display_name());
+                    let byte = codepoint.offset().byte;
+                    println!("{location}: {problem} {value} {name} at byte {byte}");
+                }
+
+                if !unmatched.is_empty() || !nested.is_empty() {
+                    // Force the line to be shown in the order it's given in, in case the terminal
+                    // runs the bidirectional algorithm too.
+                    println!("{location}: displayed as: \u{202D}{}\u{202C}", paragraph.displayed());
+                    clean = false;
+                }
+
+                line += paragraph.codepoints().filter(|c| c.value() == Ok('\n')).count();
+            }
+        }
+
+        Ok(())
+    });
+
+    success && clean
+}
+
+// Puts lines that were too long to read at once back together, so that controls are matched across
+// the whole line.
+fn whole_lines(texts: Texts<'_>) -> impl Iterator<Item = io::Result<Text>> + '_ {
+    iter::from_fn(|| {
+        let mut graphemes = Vec::new();
+
+        for text in &mut *texts {
+            let text = match text {
+                Ok(text) => text,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let ends_line = text.codepoints().last().is_some_and(|c| c.value() == Ok('\n'));
+            graphemes.extend(text.into_graphemes());
+            if ends_line { break }
+        }
+
+        (!graphemes.is_empty()).then(|| Ok(graphemes.into_iter().collect()))
+    })
+}
+
+fn diff(args: &DiffArgs) -> bool {
+    let mut inputs = Vec::new();
+    let success = cli::for_each_input(args.input.inputs(), args.input.decoder(), |_, texts| {
+        let texts = texts.collect::<io::Result<Vec<_>>>()?;
+        inputs.push(texts.into_iter().flat_map(Text::into_graphemes).collect::<Text>());
+        Ok(())
+    });
+
+    let [a, b] = &inputs[..] else {
+        if success { eprintln!("unicode-analyze: expected two inputs, got {}", inputs.len()) }
+        return false;
+    };
+
+    for equivalence in Equivalence::ALL {
+        let answer = if a.is_equivalent(b, equivalence) { "yes" } else { "no" };
+
+        match equivalence {
+            Equivalence::Identical => println!("identical: {answer}"),
+            Equivalence::Nfc => println!("NFC: {answer}"),
+            Equivalence::Nfkc => println!("NFKC: {answer}"),
+            Equivalence::CaseFolding => println!("case folding: {answer}"),
+            Equivalence::Skeleton => println!("skeleton: {answer}"),
+        }
+    }
+
+    let print = |marker, codepoint| {
+        print!("{marker} ");
+        cli::print_codepoint(codepoint, args.columns);
+    };
+
+    for edit in a.diff(b) {
+        match edit {
+            Edit::Equal(a, _) => a.codepoints().for_each(|c| print(' ', c)),
+            Edit::Delete(a) => a.codepoints().for_each(|c| print('-', c)),
+            Edit::Insert(b) => b.codepoints().for_each(|c| print('+', c)),
+            Edit::Substitute(a, b) => for edit in a.diff(b) {
+                match edit {
+                    Edit::Equal(a, _) => print(' ', a),
+                    Edit::Delete(a) => print('-', a),
+                    Edit::Insert(b) => print('+', b),
+                    Edit::Substitute(a, b) => {
+                        print('<', a);
+                        print('>', b);
+                    },
+                }
+            },
+        }
+    }
+
+    success && a == b
+}
+
+fn case(args: &CaseArgs) -> bool {
+    let locale = args.language.map(CaseLocale::from).unwrap_or_default();
+
+    cli::for_each_input(args.input.inputs(), args.input.decoder(), |input, texts| {
+        input.print_header();
+        let texts = texts.collect::<io::Result<Vec<_>>>()?;
+        let text = texts.into_iter().flat_map(Text::into_graphemes).collect::<Text>();
+
+        for case in Case::ALL {
+            let mapped = text.to_case(case, locale);
+            println!("{}: {mapped}", case.name());
+
+            for codepoint in mapped.codepoints() { cli::print_codepoint(codepoint, args.columns) }
+        }
+
+        Ok(())
+    })
+}
+
+fn restriction(args: &RestrictionArgs) -> bool {
+    let max_level = args.max_level.map(RestrictionLevel::from);
+    let columns = Columns { script: true, ..args.columns };
+    let mut restricted = true;
+
+    let success = cli::for_each_input(args.input.inputs(), args.input.decoder(), |input, texts| {
+        input.print_header();
+        let texts = texts.collect::<io::Result<Vec<_>>>()?;
+        let text = texts.into_iter().flat_map(Text::into_graphemes).collect::<Text>();
+        let restriction = text.restriction();
+        println!("level: {}", restriction.level.name());
+
+        match &restriction.scripts {
+            None => println!("scripts: any"),
+            Some(scripts) if scripts.is_empty() => println!("scripts: none"),
+            Some(scripts) => {
+                let scripts = scripts.iter().map(|script| script.full_name()).collect::<Vec<_>>();
+                println!("scripts: {}", scripts.join(", "));
+            },
+        }
+
+        if !restriction.downgraded_by.is_empty() { println!("downgraded by:") }
+        for codepoint in restriction.downgraded_by { cli::print_codepoint(codepoint, columns) }
+
+        restricted &= max_level.is_none_or(|max_level| restriction.level <= max_level);
+        Ok(())
+    });
+
+    success && restricted
+}
+
+fn parse_query(query: &str) -> Option<Codepoint> {
+    let value = |hex| u32::from_str_radix(hex, 16).ok().and_then(|value| {
+        match char::from_u32(value) {
+            Some(c) => Some(Codepoint::from_valid(c)),
+            None => u16::try_from(value).ok().and_then(Codepoint::from_surrogate),
+        }
+    });
+
+    match ["U+", "u+", "0x", "0X"].iter().find_map(|prefix| query.strip_prefix(prefix)) {
+        Some(hex) => value(hex),
+        None => Codepoint::from_name(query).or_else(|| value(query)),
+    }
+}
+
+impl Args for Sources {
+    fn augment_args(cmd: clap::Command) -> clap::Command { SourceArgs::augment_args(cmd) }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        SourceArgs::augment_args_for_update(cmd)
+    }
+}
+
+impl FromArgMatches for Sources {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let args = SourceArgs::from_arg_matches(matches)?;
+        let indices = |id| matches.indices_of(id).into_iter().flatten();
+
+        let texts = args.text.into_iter().enumerate().map(|(i, text)| match text.to_str() {
+            Some("-") => Input::Stdin,
+            _ => Input::Argument(i, text),
+        });
+
+        let mut inputs = indices("text")
+            .zip(texts)
+            .chain(indices("file").zip(args.file.into_iter().map(Input::File)))
+            .collect::<Vec<_>>();
+
+        inputs.sort_by_key(|&(index, _)| index);
+        Ok(Sources(inputs.into_iter().map(|(_, input)| input).collect()))
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Sources::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl InputArgs {
+    fn inputs(&self) -> Vec<Input> {
+        let mut inputs = self.sources.0.clone();
+
+        if inputs.is_empty() && !io::stdin().is_terminal() { inputs.push(Input::Stdin) }
+        inputs
+    }
 
-            println!("{value} {character} {name}");
+    fn decoder(&self) -> Decoder {
+        Decoder {
+            encoding: self.encoding.map(Encoding::from),
+            unescape: self.unescape,
+            normalize: self.normalize.map(NormalizationForm::from),
         }
     }
 }
@@ -0,0 +1,134 @@
+use std::iter;
+use smallvec::smallvec;
+use unicode_normalization::{IsNormalized, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+use crate::{Codepoint, Encoding, Grapheme, Text};
+
+// The four Unicode normalization forms, from UAX #15.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+// The answer to a normalization quick check. `Maybe` means that only a full check can tell. They're
+// ordered from most to least normalized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum QuickCheck {
+    Yes,
+    Maybe,
+    No,
+}
+
+impl NormalizationForm {
+    pub const ALL: [NormalizationForm; 4] = [Self::Nfc, Self::Nfd, Self::Nfkc, Self::Nfkd];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            NormalizationForm::Nfc => "NFC",
+            NormalizationForm::Nfd => "NFD",
+            NormalizationForm::Nfkc => "NFKC",
+            NormalizationForm::Nfkd => "NFKD",
+        }
+    }
+
+    fn normalize(self, s: &str) -> String {
+        match self {
+            NormalizationForm::Nfc => s.nfc().collect(),
+            NormalizationForm::Nfd => s.nfd().collect(),
+            NormalizationForm::Nfkc => s.nfkc().collect(),
+            NormalizationForm::Nfkd => s.nfkd().collect(),
+        }
+    }
+
+    fn quick_check(self, s: &str) -> QuickCheck {
+        let result = match self {
+            NormalizationForm::Nfc => unicode_normalization::is_nfc_quick(s.chars()),
+            NormalizationForm::Nfd => unicode_normalization::is_nfd_quick(s.chars()),
+            NormalizationForm::Nfkc => unicode_normalization::is_nfkc_quick(s.chars()),
+            NormalizationForm::Nfkd => unicode_normalization::is_nfkd_quick(s.chars()),
+        };
+
+        match result {
+            IsNormalized::Yes => QuickCheck::Yes,
+            IsNormalized::No => QuickCheck::No,
+            IsNormalized::Maybe => QuickCheck::Maybe,
+        }
+    }
+}
+
+// Normalizes each run of valid text separately, keeping surrogates and invalid data as they are.
+pub fn normalize(text: &Text, form: NormalizationForm) -> Text {
+    map(text, |s| form.normalize(s))
+}
+
+// Quick checks each run of valid text, answering no if any run isn't normalized, and maybe if any
+// run might not be.
+pub fn quick_check(text: &Text, form: NormalizationForm) -> QuickCheck {
+    runs(text)
+        .filter_map(Result::ok)
+        .map(|(s, _)| form.quick_check(&s))
+        .max()
+        .unwrap_or(QuickCheck::Yes)
+}
+
+// Fully checks whether the text is normalized, only normalizing it when the quick check can't tell.
+pub fn is_normalized(text: &Text, form: NormalizationForm) -> bool {
+    match quick_check(text, form) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => runs(text)
+            .filter_map(Result::ok)
+            .all(|(s, _)| form.normalize(&s) == s),
+    }
+}
+
+// Splits text into runs of valid characters, with the encoding of their first codepoint, and the
+// surrogates and invalid data between them.
+pub(crate) fn runs(
+    text: &Text,
+) -> impl Iterator<Item = Result<(String, Encoding), &Codepoint>> {
+    let mut codepoints = text.codepoints().peekable();
+
+    iter::from_fn(move || {
+        let first = codepoints.next()?;
+        let Ok(c) = first.value() else { return Some(Err(first)) };
+        let mut s = String::from(c);
+
+        while let Some(c) = codepoints.peek().and_then(|codepoint| codepoint.value().ok()) {
+            s.push(c);
+            codepoints.next();
+        }
+
+        Some(Ok((s, first.encoding())))
+    })
+}
+
+// Replaces each run of valid text, keeping surrogates and invalid data as they are. New characters
+// are encoded like the first codepoint of their run, or as UTF-8 if that's a single-byte encoding
+// that might not have them, and the new text starts where the original did.
+pub(crate) fn map(text: &Text, mut f: impl FnMut(&str) -> String) -> Text {
+    let mut graphemes = Vec::new();
+
+    for run in runs(text) {
+        match run {
+            Ok((s, encoding)) => {
+                let encoding = match encoding {
+                    Encoding::Latin1 | Encoding::Windows1252 => Encoding::Utf8,
+                    encoding => encoding,
+                };
+
+                for grapheme in f(&s).graphemes(true) {
+                    let mut grapheme = Grapheme::from_valid(grapheme);
+                    for codepoint in grapheme.0.iter_mut() { codepoint.2 = encoding }
+                    graphemes.push(grapheme);
+                }
+            },
+            Err(codepoint) => graphemes.push(Grapheme(smallvec![codepoint.clone()])),
+        }
+    }
+
+    Text(graphemes).at(text.span().start)
+}
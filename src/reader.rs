@@ -0,0 +1,140 @@
+use std::{io::{self, BufRead}, mem};
+use crate::{Encoding, Offset, Text};
+
+// The longest chunk that will be read before splitting a line that doesn't end.
+const MAX_CHUNK: usize = 64 * 1024;
+
+// Parses text from a reader one line at a time, so large inputs don't need to be loaded at once.
+// Lines are yielded with their terminators, and overlong lines are split between graphemes.
+#[derive(Debug)]
+pub struct TextReader<R> {
+    reader: R,
+    encoding: Encoding,
+    pending: Vec<u8>,
+    offset: Offset,
+}
+
+impl<R: BufRead> TextReader<R> {
+    pub fn new(reader: R) -> Self {
+        TextReader::with_encoding(reader, Encoding::Utf8)
+    }
+
+    pub fn with_encoding(reader: R, encoding: Encoding) -> Self {
+        TextReader { reader, encoding, pending: Vec::new(), offset: Offset::default() }
+    }
+
+    // Detects the encoding from a byte order mark at the start of the reader, or uses `fallback` if
+    // there isn't one. The byte order mark is still read as part of the text. The reader's buffer
+    // can be shorter than a mark, so up to four bytes are read ahead to find it.
+    pub fn detect_encoding(mut reader: R, fallback: Encoding) -> io::Result<Self> {
+        let mut start = Vec::new();
+
+        while start.len() < 4 {
+            let available = match reader.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            if available.is_empty() { break }
+
+            let len = available.len().min(4 - start.len());
+            start.extend_from_slice(&available[..len]);
+            reader.consume(len);
+        }
+
+        let encoding = Encoding::sniff(&start).unwrap_or(fallback);
+        Ok(TextReader { pending: start, ..TextReader::with_encoding(reader, encoding) })
+    }
+
+    pub fn encoding(&self) -> Encoding { self.encoding }
+    pub fn into_inner(self) -> R { self.reader }
+}
+
+impl<R: BufRead> Iterator for TextReader<R> {
+    type Item = io::Result<Text>;
+
+    fn next(&mut self) -> Option<io::Result<Text>> {
+        let newline = newline(self.encoding);
+        let (unit, lf) = (newline.len(), newline.iter().position(|&b| b == b'\n').unwrap());
+        let ends_line = |chunk: &[u8]| chunk.len().is_multiple_of(unit) && chunk.ends_with(newline);
+        let line_end = |bytes: &[u8]| bytes.iter()
+            .enumerate()
+            .position(|(i, &b)| b == b'\n' && i % unit == lf)
+            .map(|i| i - lf + unit);
+
+        // Bytes read ahead to detect the encoding can already hold the end of a line.
+        let mut chunk = mem::take(&mut self.pending);
+        if let Some(end) = line_end(&chunk) { self.pending = chunk.split_off(end) }
+
+        while !ends_line(&chunk) && chunk.len() < MAX_CHUNK {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if available.is_empty() { break }
+
+            // Finish any code unit that was split between reads before looking for a line feed.
+            let len = match chunk.len() % unit {
+                0 => line_end(available).unwrap_or(available.len()),
+                partial => unit - partial,
+            };
+
+            let len = len.min(available.len()).min(MAX_CHUNK - chunk.len());
+            chunk.extend_from_slice(&available[..len]);
+            self.reader.consume(len);
+        }
+
+        if chunk.is_empty() { return None }
+
+        let overlong = !ends_line(&chunk) && chunk.len() >= MAX_CHUNK;
+        if overlong {
+            let boundary = boundary(self.encoding, &chunk);
+            self.pending = chunk.split_off(boundary);
+        }
+
+        let mut text = Text::decode(&chunk, self.encoding);
+
+        // The last grapheme of an overlong line might go on past the split, so it's read again with
+        // the rest of the line, unless it's the only one.
+        if overlong && text.0.len() > 1 {
+            let last = text.0.pop().expect("the text should have graphemes");
+            let start = last.codepoints().next().map_or(chunk.len(), |c| c.offset().byte);
+            self.pending.splice(0..0, chunk.drain(start..));
+        }
+
+        let text = text.at(self.offset);
+        self.offset = text.span().end;
+        Some(Ok(text))
+    }
+}
+
+// The encoding of U+000A LINE FEED.
+fn newline(encoding: Encoding) -> &'static [u8] {
+    match encoding {
+        Encoding::Utf16Le => b"\n\0",
+        Encoding::Utf16Be => b"\0\n",
+        Encoding::Utf32Le => b"\n\0\0\0",
+        Encoding::Utf32Be => b"\0\0\0\n",
+        Encoding::Utf8 | Encoding::Wtf8 | Encoding::Latin1 | Encoding::Windows1252 => b"\n",
+    }
+}
+
+// The last position in a full chunk where a character could start, so that it can be split there.
+fn boundary(encoding: Encoding, chunk: &[u8]) -> usize {
+    match encoding {
+        Encoding::Utf8 | Encoding::Wtf8 => chunk.iter()
+            .rposition(|b| b & 0xC0 != 0x80)
+            .filter(|&i| i > 0)
+            .unwrap_or(chunk.len()),
+        Encoding::Utf16Le | Encoding::Utf16Be => {
+            let end = chunk.len() - chunk.len() % 2;
+            let high = if encoding == Encoding::Utf16Le { chunk[end - 1] } else { chunk[end - 2] };
+            if (0xD8..=0xDB).contains(&high) { end - 2 } else { end }
+        },
+        Encoding::Utf32Le | Encoding::Utf32Be => chunk.len() - chunk.len() % 4,
+        Encoding::Latin1 | Encoding::Windows1252 => chunk.len(),
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::BTreeSet;
+use crate::{Codepoint, Script, Text, unicode::Character};
+
+// The restriction levels from UTS #39, ordered from most to least restrictive.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum RestrictionLevel {
+    AsciiOnly,
+    SingleScript,
+    HighlyRestrictive,
+    ModeratelyRestrictive,
+    MinimallyRestrictive,
+    Unrestricted,
+}
+
+// A script in an augmented script set from UTS #39, which adds the writing systems that combine Han
+// with other scripts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum AugmentedScript {
+    Script(Script),
+    HanWithBopomofo,
+    Japanese,
+    Korean,
+}
+
+// The most restrictive level that some text meets, its resolved script set, and the codepoints that
+// keep it from meeting the level above. The resolved script set is `None` if every character is
+// Common or Inherited, meaning it could be any script, and empty if the scripts are mixed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Restriction<'a> {
+    pub level: RestrictionLevel,
+    pub scripts: Option<Vec<AugmentedScript>>,
+    pub downgraded_by: Vec<&'a Codepoint>,
+}
+
+impl RestrictionLevel {
+    pub const ALL: [RestrictionLevel; 6] = [
+        Self::AsciiOnly,
+        Self::SingleScript,
+        Self::HighlyRestrictive,
+        Self::ModeratelyRestrictive,
+        Self::MinimallyRestrictive,
+        Self::Unrestricted,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RestrictionLevel::AsciiOnly => "ASCII-Only",
+            RestrictionLevel::SingleScript => "Single Script",
+            RestrictionLevel::HighlyRestrictive => "Highly Restrictive",
+            RestrictionLevel::ModeratelyRestrictive => "Moderately Restrictive",
+            RestrictionLevel::MinimallyRestrictive => "Minimally Restrictive",
+            RestrictionLevel::Unrestricted => "Unrestricted",
+        }
+    }
+}
+
+impl AugmentedScript {
+    // The four-letter ISO 15924 code, like `Latn` or `Jpan`.
+    pub fn short_name(self) -> &'static str {
+        match self {
+            AugmentedScript::Script(script) => script.short_name(),
+            AugmentedScript::HanWithBopomofo => "Hanb",
+            AugmentedScript::Japanese => "Jpan",
+            AugmentedScript::Korean => "Kore",
+        }
+    }
+
+    pub fn full_name(self) -> &'static str {
+        match self {
+            AugmentedScript::Script(script) => script.full_name(),
+            AugmentedScript::HanWithBopomofo => "Han_with_Bopomofo",
+            AugmentedScript::Japanese => "Japanese",
+            AugmentedScript::Korean => "Korean",
+        }
+    }
+}
+
+// The levels below Single Script are about which sets of scripts cover the text, which they do if
+// every character is in at least one of them. When text doesn't meet a level, the codepoints that
+// downgraded it are the ones left out by the set that covers the most of it.
+pub fn restriction(text: &Text) -> Restriction<'_> {
+    use RestrictionLevel::*;
+
+    let codepoints = text.codepoints().collect::<Vec<_>>();
+    let sets = codepoints.iter()
+        .map(|codepoint| codepoint.value().ok().and_then(augmented_scripts))
+        .collect::<Vec<_>>();
+
+    let scripts = sets.iter().flatten().fold(None, |resolved: Option<BTreeSet<_>>, set| {
+        Some(match resolved {
+            Some(resolved) => resolved.intersection(set).copied().collect(),
+            None => set.clone(),
+        })
+    });
+
+    let restriction = |level, downgraded_by| Restriction {
+        level,
+        scripts: scripts.clone().map(|scripts| scripts.into_iter().collect()),
+        downgraded_by,
+    };
+
+    let disallowed = codepoints.iter()
+        .filter(|codepoint| !codepoint.value().is_ok_and(|c| c.identifier_allowed()))
+        .copied()
+        .collect::<Vec<_>>();
+
+    if !disallowed.is_empty() { return restriction(Unrestricted, disallowed) }
+
+    let non_ascii = codepoints.iter()
+        .filter(|codepoint| !codepoint.value().is_ok_and(|c| c.is_ascii()))
+        .copied()
+        .collect::<Vec<_>>();
+
+    if non_ascii.is_empty() { return restriction(AsciiOnly, non_ascii) }
+    if scripts.as_ref().is_none_or(|scripts| !scripts.is_empty()) {
+        return restriction(SingleScript, non_ascii);
+    }
+
+    let uncovered = |target: &Vec<AugmentedScript>| {
+        codepoints.iter()
+            .zip(&sets)
+            .filter(|(_, set)| {
+                set.as_ref().is_some_and(|set| !target.iter().any(|script| set.contains(script)))
+            })
+            .map(|(&codepoint, _)| codepoint)
+            .collect::<Vec<_>>()
+    };
+
+    let used = sets.iter().flatten().flatten().copied().collect::<BTreeSet<_>>();
+    let latin = AugmentedScript::Script(Script::from_name("Latn").expect("Latin should exist"));
+    let others = used.iter()
+        .filter(|&&script| match script {
+            AugmentedScript::Script(script) => {
+                script.is_recommended() && !matches!(script.short_name(), "Cyrl" | "Grek")
+            },
+            _ => false,
+        })
+        .map(|&script| vec![latin, script]);
+
+    let levels = [
+        (SingleScript, used.iter().map(|&script| vec![script]).collect::<Vec<_>>()),
+        (HighlyRestrictive, [
+            AugmentedScript::Japanese,
+            AugmentedScript::HanWithBopomofo,
+            AugmentedScript::Korean,
+        ].map(|script| vec![latin, script]).to_vec()),
+        (ModeratelyRestrictive, others.chain([vec![latin]]).collect()),
+    ];
+
+    let mut downgraded_by = Vec::new();
+
+    for (level, targets) in levels {
+        let mut uncovered = targets.iter().map(uncovered).collect::<Vec<_>>();
+        uncovered.sort_by_key(Vec::len);
+        let fewest = uncovered.into_iter().next().unwrap_or_default();
+        if fewest.is_empty() { return restriction(level, downgraded_by) }
+        downgraded_by = fewest;
+    }
+
+    restriction(MinimallyRestrictive, downgraded_by)
+}
+
+// The augmented script set of a character, or `None` for Common and Inherited characters that can
+// be used with any script.
+fn augmented_scripts(c: char) -> Option<BTreeSet<AugmentedScript>> {
+    let extensions = c.script_extensions();
+    if extensions.iter().all(|&s| s == Script::COMMON || s == Script::INHERITED) { return None }
+
+    let mut set = BTreeSet::new();
+
+    for script in extensions {
+        set.insert(AugmentedScript::Script(script));
+
+        match script.short_name() {
+            "Hani" => set.extend([
+                AugmentedScript::HanWithBopomofo,
+                AugmentedScript::Japanese,
+                AugmentedScript::Korean,
+            ]),
+            "Hira" | "Kana" => { set.insert(AugmentedScript::Japanese); },
+            "Hang" => { set.insert(AugmentedScript::Korean); },
+            "Bopo" => { set.insert(AugmentedScript::HanWithBopomofo); },
+            _ => (),
+        }
+    }
+
+    Some(set)
+}
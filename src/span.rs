@@ -0,0 +1,38 @@
+use std::ops::{Add, Range};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// A position in some text, counted in bytes of the encoding it was decoded from, in Unicode scalar
+// values, and in UTF-16 code units.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Offset {
+    pub byte: usize,
+    pub char: usize,
+    pub utf16: usize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Span {
+    pub start: Offset,
+    pub end: Offset,
+}
+
+impl Span {
+    pub fn bytes(&self) -> Range<usize> { self.start.byte..self.end.byte }
+    pub fn chars(&self) -> Range<usize> { self.start.char..self.end.char }
+    pub fn utf16(&self) -> Range<usize> { self.start.utf16..self.end.utf16 }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Offset {
+        Offset {
+            byte: self.byte + other.byte,
+            char: self.char + other.char,
+            utf16: self.utf16 + other.utf16,
+        }
+    }
+}
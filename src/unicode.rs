@@ -1,5 +1,9 @@
-use std::fmt::{self, Display};
+use std::{cmp, fmt::{self, Display}};
 use phf::{phf_map, phf_set};
+use unicode_script::UnicodeScript;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
 
 // TODO: Replace `ucd` and `unicode_names2` dependencies with custom tables.
 
@@ -15,6 +19,52 @@ pub trait Character {
     fn diacritic(&self) -> Diacritic;
 
     fn direction(&self) -> Direction;
+
+    fn bidi_class(&self) -> BidiClass;
+
+    fn bidi_mirrored(&self) -> bool;
+
+    fn bidi_paired_bracket(&self) -> Option<Bracket>;
+
+    fn general_category(&self) -> GeneralCategory;
+
+    fn script(&self) -> Script;
+
+    fn script_extensions(&self) -> Vec<Script>;
+
+    fn block(&self) -> Option<Block>;
+
+    fn age(&self) -> Option<Age>;
+
+    fn decomposition(&self) -> Option<Decomposition>;
+
+    fn canonical_decomposition(&self) -> Option<Vec<char>>;
+
+    fn compatibility_decomposition(&self) -> Option<Vec<char>>;
+
+    fn canonical_combining_class(&self) -> u8;
+
+    fn uppercase(&self) -> Vec<char>;
+
+    fn lowercase(&self) -> Vec<char>;
+
+    fn titlecase(&self) -> Vec<char>;
+
+    fn case_folding(&self) -> Vec<char>;
+
+    fn cased(&self) -> bool;
+
+    fn case_ignorable(&self) -> bool;
+
+    fn soft_dotted(&self) -> bool;
+
+    fn prototype(&self) -> Option<Vec<char>>;
+
+    fn identifier_allowed(&self) -> bool;
+
+    fn default_ignorable(&self) -> bool;
+
+    fn white_space(&self) -> bool;
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -23,20 +73,142 @@ pub struct ControlCode {
     pub name: &'static str,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
 pub enum Diacritic {
     None,
     Single,
     Double,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
 pub enum Direction {
     Ltr,
     Rtl,
     Neutral,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum BidiClass {
+    LeftToRight,
+    RightToLeft,
+    ArabicLetter,
+    EuropeanNumber,
+    EuropeanSeparator,
+    EuropeanTerminator,
+    ArabicNumber,
+    CommonSeparator,
+    NonspacingMark,
+    BoundaryNeutral,
+    ParagraphSeparator,
+    SegmentSeparator,
+    WhiteSpace,
+    OtherNeutral,
+    LeftToRightEmbedding,
+    LeftToRightOverride,
+    RightToLeftEmbedding,
+    RightToLeftOverride,
+    PopDirectionalFormat,
+    LeftToRightIsolate,
+    RightToLeftIsolate,
+    FirstStrongIsolate,
+    PopDirectionalIsolate,
+}
+
+// A bracket that's paired by the bidi algorithm, with the bracket that it's paired with.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum Bracket {
+    Open(char),
+    Close(char),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum GeneralCategory {
+    UppercaseLetter,
+    LowercaseLetter,
+    TitlecaseLetter,
+    ModifierLetter,
+    OtherLetter,
+    NonspacingMark,
+    SpacingMark,
+    EnclosingMark,
+    DecimalNumber,
+    LetterNumber,
+    OtherNumber,
+    ConnectorPunctuation,
+    DashPunctuation,
+    OpenPunctuation,
+    ClosePunctuation,
+    InitialPunctuation,
+    FinalPunctuation,
+    OtherPunctuation,
+    MathSymbol,
+    CurrencySymbol,
+    ModifierSymbol,
+    OtherSymbol,
+    SpaceSeparator,
+    LineSeparator,
+    ParagraphSeparator,
+    Control,
+    Format,
+    Surrogate,
+    PrivateUse,
+    Unassigned,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum DecompositionType {
+    Canonical,
+    Compat,
+    Circle,
+    Final,
+    Font,
+    Fraction,
+    Initial,
+    Isolated,
+    Medial,
+    Narrow,
+    Nobreak,
+    Small,
+    Square,
+    Sub,
+    Super,
+    Vertical,
+    Wide,
+}
+
+// A single step of decomposition, from `UnicodeData.txt`. The mapping may decompose further.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Decomposition {
+    pub kind: DecompositionType,
+    pub mapping: Vec<char>,
+}
+
+// A named range of codepoints. The range includes both ends, and may contain surrogates.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Block {
+    pub name: &'static str,
+    pub start: u32,
+    pub end: u32,
+}
+
+// The version of Unicode that a codepoint was first assigned in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Age {
+    pub major: u8,
+    pub minor: u8,
+}
+
+// A value of the Script property, like Latin or Common. Scripts are ordered by their full names.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Script(unicode_script::Script);
+
 impl Character for char {
     type Name = CharName;
 
@@ -59,9 +231,9 @@ impl Character for char {
     }
 
     fn direction(&self) -> Direction {
-        use ucd::BidiClass as Bidi;
+        use BidiClass as Bidi;
 
-        match ucd::Codepoint::bidi_class(*self) {
+        match self.bidi_class() {
             Bidi::LeftToRight |
             Bidi::LeftToRightEmbedding |
             Bidi::LeftToRightOverride |
@@ -76,6 +248,518 @@ impl Character for char {
             _ => Direction::Neutral,
         }
     }
+
+    fn bidi_class(&self) -> BidiClass {
+        use unicode_bidi::BidiClass as Bidi;
+
+        match unicode_bidi::bidi_class(*self) {
+            Bidi::L => BidiClass::LeftToRight,
+            Bidi::R => BidiClass::RightToLeft,
+            Bidi::AL => BidiClass::ArabicLetter,
+            Bidi::EN => BidiClass::EuropeanNumber,
+            Bidi::ES => BidiClass::EuropeanSeparator,
+            Bidi::ET => BidiClass::EuropeanTerminator,
+            Bidi::AN => BidiClass::ArabicNumber,
+            Bidi::CS => BidiClass::CommonSeparator,
+            Bidi::NSM => BidiClass::NonspacingMark,
+            Bidi::BN => BidiClass::BoundaryNeutral,
+            Bidi::B => BidiClass::ParagraphSeparator,
+            Bidi::S => BidiClass::SegmentSeparator,
+            Bidi::WS => BidiClass::WhiteSpace,
+            Bidi::ON => BidiClass::OtherNeutral,
+            Bidi::LRE => BidiClass::LeftToRightEmbedding,
+            Bidi::LRO => BidiClass::LeftToRightOverride,
+            Bidi::RLE => BidiClass::RightToLeftEmbedding,
+            Bidi::RLO => BidiClass::RightToLeftOverride,
+            Bidi::PDF => BidiClass::PopDirectionalFormat,
+            Bidi::LRI => BidiClass::LeftToRightIsolate,
+            Bidi::RLI => BidiClass::RightToLeftIsolate,
+            Bidi::FSI => BidiClass::FirstStrongIsolate,
+            Bidi::PDI => BidiClass::PopDirectionalIsolate,
+        }
+    }
+
+    fn bidi_mirrored(&self) -> bool { unicode_bidi_mirroring::is_mirroring(*self) }
+
+    // Paired brackets are always each other's mirrored glyphs.
+    fn bidi_paired_bracket(&self) -> Option<Bracket> {
+        use unicode_bidi::BidiDataSource;
+
+        let bracket = unicode_bidi::HardcodedBidiData.bidi_matched_opening_bracket(*self)?;
+        let pair = unicode_bidi_mirroring::get_mirrored(*self)?;
+        Some(if bracket.is_open { Bracket::Open(pair) } else { Bracket::Close(pair) })
+    }
+
+    fn general_category(&self) -> GeneralCategory {
+        use unicode_general_category::GeneralCategory as Gc;
+
+        match unicode_general_category::get_general_category(*self) {
+            Gc::UppercaseLetter => GeneralCategory::UppercaseLetter,
+            Gc::LowercaseLetter => GeneralCategory::LowercaseLetter,
+            Gc::TitlecaseLetter => GeneralCategory::TitlecaseLetter,
+            Gc::ModifierLetter => GeneralCategory::ModifierLetter,
+            Gc::OtherLetter => GeneralCategory::OtherLetter,
+            Gc::NonspacingMark => GeneralCategory::NonspacingMark,
+            Gc::SpacingMark => GeneralCategory::SpacingMark,
+            Gc::EnclosingMark => GeneralCategory::EnclosingMark,
+            Gc::DecimalNumber => GeneralCategory::DecimalNumber,
+            Gc::LetterNumber => GeneralCategory::LetterNumber,
+            Gc::OtherNumber => GeneralCategory::OtherNumber,
+            Gc::ConnectorPunctuation => GeneralCategory::ConnectorPunctuation,
+            Gc::DashPunctuation => GeneralCategory::DashPunctuation,
+            Gc::OpenPunctuation => GeneralCategory::OpenPunctuation,
+            Gc::ClosePunctuation => GeneralCategory::ClosePunctuation,
+            Gc::InitialPunctuation => GeneralCategory::InitialPunctuation,
+            Gc::FinalPunctuation => GeneralCategory::FinalPunctuation,
+            Gc::OtherPunctuation => GeneralCategory::OtherPunctuation,
+            Gc::MathSymbol => GeneralCategory::MathSymbol,
+            Gc::CurrencySymbol => GeneralCategory::CurrencySymbol,
+            Gc::ModifierSymbol => GeneralCategory::ModifierSymbol,
+            Gc::OtherSymbol => GeneralCategory::OtherSymbol,
+            Gc::SpaceSeparator => GeneralCategory::SpaceSeparator,
+            Gc::LineSeparator => GeneralCategory::LineSeparator,
+            Gc::ParagraphSeparator => GeneralCategory::ParagraphSeparator,
+            Gc::Control => GeneralCategory::Control,
+            Gc::Format => GeneralCategory::Format,
+            Gc::Surrogate => GeneralCategory::Surrogate,
+            Gc::PrivateUse => GeneralCategory::PrivateUse,
+            Gc::Unassigned => GeneralCategory::Unassigned,
+            // New categories are very unlikely, but the enum is non-exhaustive.
+            _ => GeneralCategory::Unassigned,
+        }
+    }
+
+    fn script(&self) -> Script {
+        Script(UnicodeScript::script(self))
+    }
+
+    // Characters without any extensions, including unassigned ones, have just their own script.
+    fn script_extensions(&self) -> Vec<Script> {
+        let mut extensions = self.script_extension().iter().map(Script).collect::<Vec<_>>();
+        extensions.sort();
+        if extensions.is_empty() { vec![Character::script(self)] } else { extensions }
+    }
+
+    fn block(&self) -> Option<Block> { block(*self as u32) }
+
+    fn age(&self) -> Option<Age> { age(*self as u32) }
+
+    // Hangul syllables aren't in the table, because they're decomposed algorithmically into an LV
+    // syllable and a trailing consonant, or a leading consonant and a vowel.
+    fn decomposition(&self) -> Option<Decomposition> {
+        const S_BASE: u32 = 0xAC00;
+        const L_BASE: u32 = 0x1100;
+        const V_BASE: u32 = 0x1161;
+        const T_BASE: u32 = 0x11A7;
+        const T_COUNT: u32 = 28;
+        const N_COUNT: u32 = 21 * T_COUNT;
+        const S_COUNT: u32 = 19 * N_COUNT;
+
+        let s = (*self as u32).wrapping_sub(S_BASE);
+
+        if s < S_COUNT {
+            let t = s % T_COUNT;
+            let mapping = if t > 0 {
+                [S_BASE + s - t, T_BASE + t]
+            } else {
+                [L_BASE + s / N_COUNT, V_BASE + s % N_COUNT / T_COUNT]
+            };
+
+            let mapping = mapping.into_iter().filter_map(char::from_u32).collect();
+            return Some(Decomposition { kind: DecompositionType::Canonical, mapping });
+        }
+
+        let i = DECOMPOSITIONS.binary_search_by_key(&(*self as u32), |&(c, ..)| c).ok()?;
+        let (_, kind, mapping) = DECOMPOSITIONS[i];
+        Some(Decomposition { kind, mapping: mapping.chars().collect() })
+    }
+
+    fn canonical_decomposition(&self) -> Option<Vec<char>> {
+        let mut decomposition = Vec::new();
+        unicode_normalization::char::decompose_canonical(*self, |c| decomposition.push(c));
+        (decomposition != [*self]).then_some(decomposition)
+    }
+
+    fn compatibility_decomposition(&self) -> Option<Vec<char>> {
+        let mut decomposition = Vec::new();
+        unicode_normalization::char::decompose_compatible(*self, |c| decomposition.push(c));
+        (decomposition != [*self]).then_some(decomposition)
+    }
+
+    fn canonical_combining_class(&self) -> u8 {
+        unicode_normalization::char::canonical_combining_class(*self)
+    }
+
+    // Full case mappings, which include the unconditional special casings but none of the context
+    // or language sensitive ones. Characters that don't change are mapped to themselves.
+    fn uppercase(&self) -> Vec<char> {
+        case_mapping(*self, &unicode_case_mapping::to_uppercase(*self))
+    }
+
+    fn lowercase(&self) -> Vec<char> {
+        case_mapping(*self, &unicode_case_mapping::to_lowercase(*self))
+    }
+
+    fn titlecase(&self) -> Vec<char> {
+        case_mapping(*self, &unicode_case_mapping::to_titlecase(*self))
+    }
+
+    // Full case folding, which uses the simple case folding of characters that don't have a full
+    // one. Characters that don't change are folded to themselves.
+    fn case_folding(&self) -> Vec<char> {
+        if let Ok(i) = FULL_CASE_FOLDINGS.binary_search_by_key(&(*self as u32), |&(c, _)| c) {
+            return FULL_CASE_FOLDINGS[i].1.chars().collect();
+        }
+
+        let folded = unicode_case_mapping::case_folded(*self).and_then(|c| char::from_u32(c.get()));
+        vec![folded.unwrap_or(*self)]
+    }
+
+    fn cased(&self) -> bool {
+        self.is_lowercase() || self.is_uppercase()
+            || self.general_category() == GeneralCategory::TitlecaseLetter
+    }
+
+    // Nonspacing, enclosing and format characters, modifiers, and the punctuation that can appear
+    // inside words, from `DerivedCoreProperties.txt`.
+    fn case_ignorable(&self) -> bool {
+        use GeneralCategory as Gc;
+
+        matches!(
+            self.general_category(),
+            Gc::NonspacingMark | Gc::EnclosingMark | Gc::Format | Gc::ModifierLetter
+                | Gc::ModifierSymbol
+        ) || matches!(
+            self,
+            '\'' | '.' | ':' | '\u{00B7}' | '\u{0387}' | '\u{055F}' | '\u{05F4}' | '\u{2018}'
+                | '\u{2019}' | '\u{2024}' | '\u{2027}' | '\u{FE13}' | '\u{FE52}' | '\u{FE55}'
+                | '\u{FF07}' | '\u{FF0E}' | '\u{FF1A}'
+        )
+    }
+
+    // Characters whose dot disappears under an accent, from `PropList.txt`.
+    fn soft_dotted(&self) -> bool {
+        matches!(
+            self,
+            'i' | 'j' | '\u{012F}' | '\u{0249}' | '\u{0268}' | '\u{029D}' | '\u{02B2}' | '\u{03F3}'
+                | '\u{0456}' | '\u{0458}' | '\u{1D62}' | '\u{1D96}' | '\u{1DA4}' | '\u{1DA8}'
+                | '\u{1E2D}' | '\u{1ECB}' | '\u{2071}' | '\u{2148}' | '\u{2149}' | '\u{2C7C}'
+                | '\u{1DF1A}' | '\u{1E04C}' | '\u{1E04D}' | '\u{1E068}'
+        ) || ('\u{1D422}'..='\u{1D693}').contains(self)
+            && matches!((*self as u32 - 0x1D422) % 0x34, 0 | 1)
+    }
+
+    // The character that this one looks like, which might be more than one character. Characters
+    // that aren't confusable with anything else are their own prototypes.
+    fn prototype(&self) -> Option<Vec<char>> {
+        let i = CONFUSABLES.binary_search_by_key(&(*self as u32), |&(c, _)| c).ok()?;
+        Some(CONFUSABLES[i].1.chars().collect())
+    }
+
+    // Whether the character has an Identifier_Status of Allowed in UTS #39.
+    fn identifier_allowed(&self) -> bool {
+        unicode_security::GeneralSecurityProfile::identifier_allowed(*self)
+    }
+
+    // Characters that are invisible unless they're supported, from `DerivedCoreProperties.txt`.
+    fn default_ignorable(&self) -> bool {
+        matches!(
+            self,
+            '\u{00AD}' | '\u{034F}' | '\u{061C}' | '\u{115F}'..='\u{1160}' | '\u{17B4}'..='\u{17B5}'
+                | '\u{180B}'..='\u{180F}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}'
+                | '\u{2060}'..='\u{206F}' | '\u{3164}' | '\u{FE00}'..='\u{FE0F}' | '\u{FEFF}'
+                | '\u{FFA0}' | '\u{FFF0}'..='\u{FFF8}' | '\u{1BCA0}'..='\u{1BCA3}'
+                | '\u{1D173}'..='\u{1D17A}' | '\u{E0000}'..='\u{E0FFF}'
+        )
+    }
+
+    fn white_space(&self) -> bool { self.is_whitespace() }
+}
+
+// Converts a mapping from `unicode_case_mapping`, which is padded with zeros and empty for
+// characters that map to themselves.
+fn case_mapping(c: char, mapping: &[u32]) -> Vec<char> {
+    let mapping: Vec<char> =
+        mapping.iter().filter(|&&c| c != 0).filter_map(|&c| char::from_u32(c)).collect();
+    if mapping.is_empty() { vec![c] } else { mapping }
+}
+
+// Property value aliases, from `PropertyValueAliases.txt`.
+impl BidiClass {
+    pub fn short_name(self) -> &'static str {
+        match self {
+            BidiClass::LeftToRight => "L",
+            BidiClass::RightToLeft => "R",
+            BidiClass::ArabicLetter => "AL",
+            BidiClass::EuropeanNumber => "EN",
+            BidiClass::EuropeanSeparator => "ES",
+            BidiClass::EuropeanTerminator => "ET",
+            BidiClass::ArabicNumber => "AN",
+            BidiClass::CommonSeparator => "CS",
+            BidiClass::NonspacingMark => "NSM",
+            BidiClass::BoundaryNeutral => "BN",
+            BidiClass::ParagraphSeparator => "B",
+            BidiClass::SegmentSeparator => "S",
+            BidiClass::WhiteSpace => "WS",
+            BidiClass::OtherNeutral => "ON",
+            BidiClass::LeftToRightEmbedding => "LRE",
+            BidiClass::LeftToRightOverride => "LRO",
+            BidiClass::RightToLeftEmbedding => "RLE",
+            BidiClass::RightToLeftOverride => "RLO",
+            BidiClass::PopDirectionalFormat => "PDF",
+            BidiClass::LeftToRightIsolate => "LRI",
+            BidiClass::RightToLeftIsolate => "RLI",
+            BidiClass::FirstStrongIsolate => "FSI",
+            BidiClass::PopDirectionalIsolate => "PDI",
+        }
+    }
+
+    pub fn long_name(self) -> &'static str {
+        match self {
+            BidiClass::LeftToRight => "Left_To_Right",
+            BidiClass::RightToLeft => "Right_To_Left",
+            BidiClass::ArabicLetter => "Arabic_Letter",
+            BidiClass::EuropeanNumber => "European_Number",
+            BidiClass::EuropeanSeparator => "European_Separator",
+            BidiClass::EuropeanTerminator => "European_Terminator",
+            BidiClass::ArabicNumber => "Arabic_Number",
+            BidiClass::CommonSeparator => "Common_Separator",
+            BidiClass::NonspacingMark => "Nonspacing_Mark",
+            BidiClass::BoundaryNeutral => "Boundary_Neutral",
+            BidiClass::ParagraphSeparator => "Paragraph_Separator",
+            BidiClass::SegmentSeparator => "Segment_Separator",
+            BidiClass::WhiteSpace => "White_Space",
+            BidiClass::OtherNeutral => "Other_Neutral",
+            BidiClass::LeftToRightEmbedding => "Left_To_Right_Embedding",
+            BidiClass::LeftToRightOverride => "Left_To_Right_Override",
+            BidiClass::RightToLeftEmbedding => "Right_To_Left_Embedding",
+            BidiClass::RightToLeftOverride => "Right_To_Left_Override",
+            BidiClass::PopDirectionalFormat => "Pop_Directional_Format",
+            BidiClass::LeftToRightIsolate => "Left_To_Right_Isolate",
+            BidiClass::RightToLeftIsolate => "Right_To_Left_Isolate",
+            BidiClass::FirstStrongIsolate => "First_Strong_Isolate",
+            BidiClass::PopDirectionalIsolate => "Pop_Directional_Isolate",
+        }
+    }
+}
+
+impl GeneralCategory {
+    pub fn short_name(self) -> &'static str {
+        match self {
+            GeneralCategory::UppercaseLetter => "Lu",
+            GeneralCategory::LowercaseLetter => "Ll",
+            GeneralCategory::TitlecaseLetter => "Lt",
+            GeneralCategory::ModifierLetter => "Lm",
+            GeneralCategory::OtherLetter => "Lo",
+            GeneralCategory::NonspacingMark => "Mn",
+            GeneralCategory::SpacingMark => "Mc",
+            GeneralCategory::EnclosingMark => "Me",
+            GeneralCategory::DecimalNumber => "Nd",
+            GeneralCategory::LetterNumber => "Nl",
+            GeneralCategory::OtherNumber => "No",
+            GeneralCategory::ConnectorPunctuation => "Pc",
+            GeneralCategory::DashPunctuation => "Pd",
+            GeneralCategory::OpenPunctuation => "Ps",
+            GeneralCategory::ClosePunctuation => "Pe",
+            GeneralCategory::InitialPunctuation => "Pi",
+            GeneralCategory::FinalPunctuation => "Pf",
+            GeneralCategory::OtherPunctuation => "Po",
+            GeneralCategory::MathSymbol => "Sm",
+            GeneralCategory::CurrencySymbol => "Sc",
+            GeneralCategory::ModifierSymbol => "Sk",
+            GeneralCategory::OtherSymbol => "So",
+            GeneralCategory::SpaceSeparator => "Zs",
+            GeneralCategory::LineSeparator => "Zl",
+            GeneralCategory::ParagraphSeparator => "Zp",
+            GeneralCategory::Control => "Cc",
+            GeneralCategory::Format => "Cf",
+            GeneralCategory::Surrogate => "Cs",
+            GeneralCategory::PrivateUse => "Co",
+            GeneralCategory::Unassigned => "Cn",
+        }
+    }
+
+    pub fn long_name(self) -> &'static str {
+        match self {
+            GeneralCategory::UppercaseLetter => "Uppercase_Letter",
+            GeneralCategory::LowercaseLetter => "Lowercase_Letter",
+            GeneralCategory::TitlecaseLetter => "Titlecase_Letter",
+            GeneralCategory::ModifierLetter => "Modifier_Letter",
+            GeneralCategory::OtherLetter => "Other_Letter",
+            GeneralCategory::NonspacingMark => "Nonspacing_Mark",
+            GeneralCategory::SpacingMark => "Spacing_Mark",
+            GeneralCategory::EnclosingMark => "Enclosing_Mark",
+            GeneralCategory::DecimalNumber => "Decimal_Number",
+            GeneralCategory::LetterNumber => "Letter_Number",
+            GeneralCategory::OtherNumber => "Other_Number",
+            GeneralCategory::ConnectorPunctuation => "Connector_Punctuation",
+            GeneralCategory::DashPunctuation => "Dash_Punctuation",
+            GeneralCategory::OpenPunctuation => "Open_Punctuation",
+            GeneralCategory::ClosePunctuation => "Close_Punctuation",
+            GeneralCategory::InitialPunctuation => "Initial_Punctuation",
+            GeneralCategory::FinalPunctuation => "Final_Punctuation",
+            GeneralCategory::OtherPunctuation => "Other_Punctuation",
+            GeneralCategory::MathSymbol => "Math_Symbol",
+            GeneralCategory::CurrencySymbol => "Currency_Symbol",
+            GeneralCategory::ModifierSymbol => "Modifier_Symbol",
+            GeneralCategory::OtherSymbol => "Other_Symbol",
+            GeneralCategory::SpaceSeparator => "Space_Separator",
+            GeneralCategory::LineSeparator => "Line_Separator",
+            GeneralCategory::ParagraphSeparator => "Paragraph_Separator",
+            GeneralCategory::Control => "Control",
+            GeneralCategory::Format => "Format",
+            GeneralCategory::Surrogate => "Surrogate",
+            GeneralCategory::PrivateUse => "Private_Use",
+            GeneralCategory::Unassigned => "Unassigned",
+        }
+    }
+}
+
+impl DecompositionType {
+    pub fn short_name(self) -> &'static str {
+        match self {
+            DecompositionType::Canonical => "Can",
+            DecompositionType::Compat => "Com",
+            DecompositionType::Circle => "Enc",
+            DecompositionType::Final => "Fin",
+            DecompositionType::Font => "Font",
+            DecompositionType::Fraction => "Fra",
+            DecompositionType::Initial => "Init",
+            DecompositionType::Isolated => "Iso",
+            DecompositionType::Medial => "Med",
+            DecompositionType::Narrow => "Nar",
+            DecompositionType::Nobreak => "Nb",
+            DecompositionType::Small => "Sml",
+            DecompositionType::Square => "Sqr",
+            DecompositionType::Sub => "Sub",
+            DecompositionType::Super => "Sup",
+            DecompositionType::Vertical => "Vert",
+            DecompositionType::Wide => "Wide",
+        }
+    }
+
+    pub fn long_name(self) -> &'static str {
+        match self {
+            DecompositionType::Canonical => "Canonical",
+            DecompositionType::Compat => "Compat",
+            DecompositionType::Circle => "Circle",
+            DecompositionType::Final => "Final",
+            DecompositionType::Font => "Font",
+            DecompositionType::Fraction => "Fraction",
+            DecompositionType::Initial => "Initial",
+            DecompositionType::Isolated => "Isolated",
+            DecompositionType::Medial => "Medial",
+            DecompositionType::Narrow => "Narrow",
+            DecompositionType::Nobreak => "Nobreak",
+            DecompositionType::Small => "Small",
+            DecompositionType::Square => "Square",
+            DecompositionType::Sub => "Sub",
+            DecompositionType::Super => "Super",
+            DecompositionType::Vertical => "Vertical",
+            DecompositionType::Wide => "Wide",
+        }
+    }
+
+    // The tag that marks a compatibility mapping in `UnicodeData.txt`, like `compat` or `font`.
+    pub fn tag(self) -> Option<&'static str> {
+        match self {
+            DecompositionType::Canonical => None,
+            DecompositionType::Compat => Some("compat"),
+            DecompositionType::Circle => Some("circle"),
+            DecompositionType::Final => Some("final"),
+            DecompositionType::Font => Some("font"),
+            DecompositionType::Fraction => Some("fraction"),
+            DecompositionType::Initial => Some("initial"),
+            DecompositionType::Isolated => Some("isolated"),
+            DecompositionType::Medial => Some("medial"),
+            DecompositionType::Narrow => Some("narrow"),
+            DecompositionType::Nobreak => Some("noBreak"),
+            DecompositionType::Small => Some("small"),
+            DecompositionType::Square => Some("square"),
+            DecompositionType::Sub => Some("sub"),
+            DecompositionType::Super => Some("super"),
+            DecompositionType::Vertical => Some("vertical"),
+            DecompositionType::Wide => Some("wide"),
+        }
+    }
+}
+
+impl Script {
+    pub const COMMON: Script = Script(unicode_script::Script::Common);
+    pub const INHERITED: Script = Script(unicode_script::Script::Inherited);
+    pub const UNKNOWN: Script = Script(unicode_script::Script::Unknown);
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        unicode_script::Script::from_full_name(name)
+            .or_else(|| unicode_script::Script::from_short_name(name))
+            .map(Script)
+    }
+
+    // The four-letter ISO 15924 code, like `Latn`.
+    pub fn short_name(self) -> &'static str { self.0.short_name() }
+
+    // The property value name, like `Latin` or `Old_Italic`.
+    pub fn full_name(self) -> &'static str { self.0.full_name() }
+
+    // Whether the script is in common modern use, from the Recommended scripts in UAX #31.
+    pub fn is_recommended(self) -> bool { self.0.is_recommended() }
+}
+
+impl Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.full_name()) }
+}
+
+impl PartialOrd for Script {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Script {
+    fn cmp(&self, other: &Self) -> cmp::Ordering { self.full_name().cmp(other.full_name()) }
+}
+
+// Scripts are serialized as their short names.
+#[cfg(feature = "serde")]
+impl Serialize for Script {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.short_name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Script {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Script::from_name(&name).ok_or_else(|| D::Error::custom(format!("unknown script '{name}'")))
+    }
+}
+
+// Finds the block containing a codepoint value, which can be a surrogate.
+pub fn block(value: u32) -> Option<Block> {
+    let i = BLOCKS.partition_point(|&(_, end, _)| end < value);
+    BLOCKS.get(i)
+        .filter(|&&(start, _, _)| start <= value)
+        .map(|&(start, end, name)| Block { name, start, end })
+}
+
+// Finds the age of a codepoint value, which can be a surrogate. Unassigned codepoints have no age.
+pub fn age(value: u32) -> Option<Age> {
+    let i = AGES.partition_point(|&(_, end, ..)| end < value);
+    AGES.get(i)
+        .filter(|&&(start, ..)| start <= value)
+        .map(|&(.., major, minor)| Age { major, minor })
+}
+
+impl Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+pub fn character(name: &str) -> Option<char> {
+    CONTROL_CODES.entries()
+        .find(|(_, names)| [names.0, names.1].iter().any(|n| n.eq_ignore_ascii_case(name)))
+        .map(|(c, _)| *c)
+        .or_else(|| unicode_names2::character(name))
 }
 
 #[derive(Debug, Clone)]
@@ -224,3 +908,5336 @@ const DOUBLE_WIDTH_DIACRITICS: phf::Set<char> = phf_set! {
     '\u{1DCD}', // COMBINING DOUBLE CIRCUMFLEX ABOVE
     '\u{1DFC}', // COMBINING DOUBLE INVERTED BREVE BELOW
 };
+
+// Blocks from `Blocks.txt` for Unicode 16.0.0, in order. Codepoints outside of these ranges have
+// no block. Regenerate it with `scripts/blocks.py`.
+const BLOCKS: &[(u32, u32, &str)] = &[
+    (0x0000, 0x007F, "Basic Latin"),
+    (0x0080, 0x00FF, "Latin-1 Supplement"),
+    (0x0100, 0x017F, "Latin Extended-A"),
+    (0x0180, 0x024F, "Latin Extended-B"),
+    (0x0250, 0x02AF, "IPA Extensions"),
+    (0x02B0, 0x02FF, "Spacing Modifier Letters"),
+    (0x0300, 0x036F, "Combining Diacritical Marks"),
+    (0x0370, 0x03FF, "Greek and Coptic"),
+    (0x0400, 0x04FF, "Cyrillic"),
+    (0x0500, 0x052F, "Cyrillic Supplement"),
+    (0x0530, 0x058F, "Armenian"),
+    (0x0590, 0x05FF, "Hebrew"),
+    (0x0600, 0x06FF, "Arabic"),
+    (0x0700, 0x074F, "Syriac"),
+    (0x0750, 0x077F, "Arabic Supplement"),
+    (0x0780, 0x07BF, "Thaana"),
+    (0x07C0, 0x07FF, "NKo"),
+    (0x0800, 0x083F, "Samaritan"),
+    (0x0840, 0x085F, "Mandaic"),
+    (0x0860, 0x086F, "Syriac Supplement"),
+    (0x0870, 0x089F, "Arabic Extended-B"),
+    (0x08A0, 0x08FF, "Arabic Extended-A"),
+    (0x0900, 0x097F, "Devanagari"),
+    (0x0980, 0x09FF, "Bengali"),
+    (0x0A00, 0x0A7F, "Gurmukhi"),
+    (0x0A80, 0x0AFF, "Gujarati"),
+    (0x0B00, 0x0B7F, "Oriya"),
+    (0x0B80, 0x0BFF, "Tamil"),
+    (0x0C00, 0x0C7F, "Telugu"),
+    (0x0C80, 0x0CFF, "Kannada"),
+    (0x0D00, 0x0D7F, "Malayalam"),
+    (0x0D80, 0x0DFF, "Sinhala"),
+    (0x0E00, 0x0E7F, "Thai"),
+    (0x0E80, 0x0EFF, "Lao"),
+    (0x0F00, 0x0FFF, "Tibetan"),
+    (0x1000, 0x109F, "Myanmar"),
+    (0x10A0, 0x10FF, "Georgian"),
+    (0x1100, 0x11FF, "Hangul Jamo"),
+    (0x1200, 0x137F, "Ethiopic"),
+    (0x1380, 0x139F, "Ethiopic Supplement"),
+    (0x13A0, 0x13FF, "Cherokee"),
+    (0x1400, 0x167F, "Unified Canadian Aboriginal Syllabics"),
+    (0x1680, 0x169F, "Ogham"),
+    (0x16A0, 0x16FF, "Runic"),
+    (0x1700, 0x171F, "Tagalog"),
+    (0x1720, 0x173F, "Hanunoo"),
+    (0x1740, 0x175F, "Buhid"),
+    (0x1760, 0x177F, "Tagbanwa"),
+    (0x1780, 0x17FF, "Khmer"),
+    (0x1800, 0x18AF, "Mongolian"),
+    (0x18B0, 0x18FF, "Unified Canadian Aboriginal Syllabics Extended"),
+    (0x1900, 0x194F, "Limbu"),
+    (0x1950, 0x197F, "Tai Le"),
+    (0x1980, 0x19DF, "New Tai Lue"),
+    (0x19E0, 0x19FF, "Khmer Symbols"),
+    (0x1A00, 0x1A1F, "Buginese"),
+    (0x1A20, 0x1AAF, "Tai Tham"),
+    (0x1AB0, 0x1AFF, "Combining Diacritical Marks Extended"),
+    (0x1B00, 0x1B7F, "Balinese"),
+    (0x1B80, 0x1BBF, "Sundanese"),
+    (0x1BC0, 0x1BFF, "Batak"),
+    (0x1C00, 0x1C4F, "Lepcha"),
+    (0x1C50, 0x1C7F, "Ol Chiki"),
+    (0x1C80, 0x1C8F, "Cyrillic Extended-C"),
+    (0x1C90, 0x1CBF, "Georgian Extended"),
+    (0x1CC0, 0x1CCF, "Sundanese Supplement"),
+    (0x1CD0, 0x1CFF, "Vedic Extensions"),
+    (0x1D00, 0x1D7F, "Phonetic Extensions"),
+    (0x1D80, 0x1DBF, "Phonetic Extensions Supplement"),
+    (0x1DC0, 0x1DFF, "Combining Diacritical Marks Supplement"),
+    (0x1E00, 0x1EFF, "Latin Extended Additional"),
+    (0x1F00, 0x1FFF, "Greek Extended"),
+    (0x2000, 0x206F, "General Punctuation"),
+    (0x2070, 0x209F, "Superscripts and Subscripts"),
+    (0x20A0, 0x20CF, "Currency Symbols"),
+    (0x20D0, 0x20FF, "Combining Diacritical Marks for Symbols"),
+    (0x2100, 0x214F, "Letterlike Symbols"),
+    (0x2150, 0x218F, "Number Forms"),
+    (0x2190, 0x21FF, "Arrows"),
+    (0x2200, 0x22FF, "Mathematical Operators"),
+    (0x2300, 0x23FF, "Miscellaneous Technical"),
+    (0x2400, 0x243F, "Control Pictures"),
+    (0x2440, 0x245F, "Optical Character Recognition"),
+    (0x2460, 0x24FF, "Enclosed Alphanumerics"),
+    (0x2500, 0x257F, "Box Drawing"),
+    (0x2580, 0x259F, "Block Elements"),
+    (0x25A0, 0x25FF, "Geometric Shapes"),
+    (0x2600, 0x26FF, "Miscellaneous Symbols"),
+    (0x2700, 0x27BF, "Dingbats"),
+    (0x27C0, 0x27EF, "Miscellaneous Mathematical Symbols-A"),
+    (0x27F0, 0x27FF, "Supplemental Arrows-A"),
+    (0x2800, 0x28FF, "Braille Patterns"),
+    (0x2900, 0x297F, "Supplemental Arrows-B"),
+    (0x2980, 0x29FF, "Miscellaneous Mathematical Symbols-B"),
+    (0x2A00, 0x2AFF, "Supplemental Mathematical Operators"),
+    (0x2B00, 0x2BFF, "Miscellaneous Symbols and Arrows"),
+    (0x2C00, 0x2C5F, "Glagolitic"),
+    (0x2C60, 0x2C7F, "Latin Extended-C"),
+    (0x2C80, 0x2CFF, "Coptic"),
+    (0x2D00, 0x2D2F, "Georgian Supplement"),
+    (0x2D30, 0x2D7F, "Tifinagh"),
+    (0x2D80, 0x2DDF, "Ethiopic Extended"),
+    (0x2DE0, 0x2DFF, "Cyrillic Extended-A"),
+    (0x2E00, 0x2E7F, "Supplemental Punctuation"),
+    (0x2E80, 0x2EFF, "CJK Radicals Supplement"),
+    (0x2F00, 0x2FDF, "Kangxi Radicals"),
+    (0x2FF0, 0x2FFF, "Ideographic Description Characters"),
+    (0x3000, 0x303F, "CJK Symbols and Punctuation"),
+    (0x3040, 0x309F, "Hiragana"),
+    (0x30A0, 0x30FF, "Katakana"),
+    (0x3100, 0x312F, "Bopomofo"),
+    (0x3130, 0x318F, "Hangul Compatibility Jamo"),
+    (0x3190, 0x319F, "Kanbun"),
+    (0x31A0, 0x31BF, "Bopomofo Extended"),
+    (0x31C0, 0x31EF, "CJK Strokes"),
+    (0x31F0, 0x31FF, "Katakana Phonetic Extensions"),
+    (0x3200, 0x32FF, "Enclosed CJK Letters and Months"),
+    (0x3300, 0x33FF, "CJK Compatibility"),
+    (0x3400, 0x4DBF, "CJK Unified Ideographs Extension A"),
+    (0x4DC0, 0x4DFF, "Yijing Hexagram Symbols"),
+    (0x4E00, 0x9FFF, "CJK Unified Ideographs"),
+    (0xA000, 0xA48F, "Yi Syllables"),
+    (0xA490, 0xA4CF, "Yi Radicals"),
+    (0xA4D0, 0xA4FF, "Lisu"),
+    (0xA500, 0xA63F, "Vai"),
+    (0xA640, 0xA69F, "Cyrillic Extended-B"),
+    (0xA6A0, 0xA6FF, "Bamum"),
+    (0xA700, 0xA71F, "Modifier Tone Letters"),
+    (0xA720, 0xA7FF, "Latin Extended-D"),
+    (0xA800, 0xA82F, "Syloti Nagri"),
+    (0xA830, 0xA83F, "Common Indic Number Forms"),
+    (0xA840, 0xA87F, "Phags-pa"),
+    (0xA880, 0xA8DF, "Saurashtra"),
+    (0xA8E0, 0xA8FF, "Devanagari Extended"),
+    (0xA900, 0xA92F, "Kayah Li"),
+    (0xA930, 0xA95F, "Rejang"),
+    (0xA960, 0xA97F, "Hangul Jamo Extended-A"),
+    (0xA980, 0xA9DF, "Javanese"),
+    (0xA9E0, 0xA9FF, "Myanmar Extended-B"),
+    (0xAA00, 0xAA5F, "Cham"),
+    (0xAA60, 0xAA7F, "Myanmar Extended-A"),
+    (0xAA80, 0xAADF, "Tai Viet"),
+    (0xAAE0, 0xAAFF, "Meetei Mayek Extensions"),
+    (0xAB00, 0xAB2F, "Ethiopic Extended-A"),
+    (0xAB30, 0xAB6F, "Latin Extended-E"),
+    (0xAB70, 0xABBF, "Cherokee Supplement"),
+    (0xABC0, 0xABFF, "Meetei Mayek"),
+    (0xAC00, 0xD7AF, "Hangul Syllables"),
+    (0xD7B0, 0xD7FF, "Hangul Jamo Extended-B"),
+    (0xD800, 0xDB7F, "High Surrogates"),
+    (0xDB80, 0xDBFF, "High Private Use Surrogates"),
+    (0xDC00, 0xDFFF, "Low Surrogates"),
+    (0xE000, 0xF8FF, "Private Use Area"),
+    (0xF900, 0xFAFF, "CJK Compatibility Ideographs"),
+    (0xFB00, 0xFB4F, "Alphabetic Presentation Forms"),
+    (0xFB50, 0xFDFF, "Arabic Presentation Forms-A"),
+    (0xFE00, 0xFE0F, "Variation Selectors"),
+    (0xFE10, 0xFE1F, "Vertical Forms"),
+    (0xFE20, 0xFE2F, "Combining Half Marks"),
+    (0xFE30, 0xFE4F, "CJK Compatibility Forms"),
+    (0xFE50, 0xFE6F, "Small Form Variants"),
+    (0xFE70, 0xFEFF, "Arabic Presentation Forms-B"),
+    (0xFF00, 0xFFEF, "Halfwidth and Fullwidth Forms"),
+    (0xFFF0, 0xFFFF, "Specials"),
+    (0x10000, 0x1007F, "Linear B Syllabary"),
+    (0x10080, 0x100FF, "Linear B Ideograms"),
+    (0x10100, 0x1013F, "Aegean Numbers"),
+    (0x10140, 0x1018F, "Ancient Greek Numbers"),
+    (0x10190, 0x101CF, "Ancient Symbols"),
+    (0x101D0, 0x101FF, "Phaistos Disc"),
+    (0x10280, 0x1029F, "Lycian"),
+    (0x102A0, 0x102DF, "Carian"),
+    (0x102E0, 0x102FF, "Coptic Epact Numbers"),
+    (0x10300, 0x1032F, "Old Italic"),
+    (0x10330, 0x1034F, "Gothic"),
+    (0x10350, 0x1037F, "Old Permic"),
+    (0x10380, 0x1039F, "Ugaritic"),
+    (0x103A0, 0x103DF, "Old Persian"),
+    (0x10400, 0x1044F, "Deseret"),
+    (0x10450, 0x1047F, "Shavian"),
+    (0x10480, 0x104AF, "Osmanya"),
+    (0x104B0, 0x104FF, "Osage"),
+    (0x10500, 0x1052F, "Elbasan"),
+    (0x10530, 0x1056F, "Caucasian Albanian"),
+    (0x10570, 0x105BF, "Vithkuqi"),
+    (0x105C0, 0x105FF, "Todhri"),
+    (0x10600, 0x1077F, "Linear A"),
+    (0x10780, 0x107BF, "Latin Extended-F"),
+    (0x10800, 0x1083F, "Cypriot Syllabary"),
+    (0x10840, 0x1085F, "Imperial Aramaic"),
+    (0x10860, 0x1087F, "Palmyrene"),
+    (0x10880, 0x108AF, "Nabataean"),
+    (0x108E0, 0x108FF, "Hatran"),
+    (0x10900, 0x1091F, "Phoenician"),
+    (0x10920, 0x1093F, "Lydian"),
+    (0x10980, 0x1099F, "Meroitic Hieroglyphs"),
+    (0x109A0, 0x109FF, "Meroitic Cursive"),
+    (0x10A00, 0x10A5F, "Kharoshthi"),
+    (0x10A60, 0x10A7F, "Old South Arabian"),
+    (0x10A80, 0x10A9F, "Old North Arabian"),
+    (0x10AC0, 0x10AFF, "Manichaean"),
+    (0x10B00, 0x10B3F, "Avestan"),
+    (0x10B40, 0x10B5F, "Inscriptional Parthian"),
+    (0x10B60, 0x10B7F, "Inscriptional Pahlavi"),
+    (0x10B80, 0x10BAF, "Psalter Pahlavi"),
+    (0x10C00, 0x10C4F, "Old Turkic"),
+    (0x10C80, 0x10CFF, "Old Hungarian"),
+    (0x10D00, 0x10D3F, "Hanifi Rohingya"),
+    (0x10D40, 0x10D8F, "Garay"),
+    (0x10E60, 0x10E7F, "Rumi Numeral Symbols"),
+    (0x10E80, 0x10EBF, "Yezidi"),
+    (0x10EC0, 0x10EFF, "Arabic Extended-C"),
+    (0x10F00, 0x10F2F, "Old Sogdian"),
+    (0x10F30, 0x10F6F, "Sogdian"),
+    (0x10F70, 0x10FAF, "Old Uyghur"),
+    (0x10FB0, 0x10FDF, "Chorasmian"),
+    (0x10FE0, 0x10FFF, "Elymaic"),
+    (0x11000, 0x1107F, "Brahmi"),
+    (0x11080, 0x110CF, "Kaithi"),
+    (0x110D0, 0x110FF, "Sora Sompeng"),
+    (0x11100, 0x1114F, "Chakma"),
+    (0x11150, 0x1117F, "Mahajani"),
+    (0x11180, 0x111DF, "Sharada"),
+    (0x111E0, 0x111FF, "Sinhala Archaic Numbers"),
+    (0x11200, 0x1124F, "Khojki"),
+    (0x11280, 0x112AF, "Multani"),
+    (0x112B0, 0x112FF, "Khudawadi"),
+    (0x11300, 0x1137F, "Grantha"),
+    (0x11380, 0x113FF, "Tulu-Tigalari"),
+    (0x11400, 0x1147F, "Newa"),
+    (0x11480, 0x114DF, "Tirhuta"),
+    (0x11580, 0x115FF, "Siddham"),
+    (0x11600, 0x1165F, "Modi"),
+    (0x11660, 0x1167F, "Mongolian Supplement"),
+    (0x11680, 0x116CF, "Takri"),
+    (0x116D0, 0x116FF, "Myanmar Extended-C"),
+    (0x11700, 0x1174F, "Ahom"),
+    (0x11800, 0x1184F, "Dogra"),
+    (0x118A0, 0x118FF, "Warang Citi"),
+    (0x11900, 0x1195F, "Dives Akuru"),
+    (0x119A0, 0x119FF, "Nandinagari"),
+    (0x11A00, 0x11A4F, "Zanabazar Square"),
+    (0x11A50, 0x11AAF, "Soyombo"),
+    (0x11AB0, 0x11ABF, "Unified Canadian Aboriginal Syllabics Extended-A"),
+    (0x11AC0, 0x11AFF, "Pau Cin Hau"),
+    (0x11B00, 0x11B5F, "Devanagari Extended-A"),
+    (0x11BC0, 0x11BFF, "Sunuwar"),
+    (0x11C00, 0x11C6F, "Bhaiksuki"),
+    (0x11C70, 0x11CBF, "Marchen"),
+    (0x11D00, 0x11D5F, "Masaram Gondi"),
+    (0x11D60, 0x11DAF, "Gunjala Gondi"),
+    (0x11EE0, 0x11EFF, "Makasar"),
+    (0x11F00, 0x11F5F, "Kawi"),
+    (0x11FB0, 0x11FBF, "Lisu Supplement"),
+    (0x11FC0, 0x11FFF, "Tamil Supplement"),
+    (0x12000, 0x123FF, "Cuneiform"),
+    (0x12400, 0x1247F, "Cuneiform Numbers and Punctuation"),
+    (0x12480, 0x1254F, "Early Dynastic Cuneiform"),
+    (0x12F90, 0x12FFF, "Cypro-Minoan"),
+    (0x13000, 0x1342F, "Egyptian Hieroglyphs"),
+    (0x13430, 0x1345F, "Egyptian Hieroglyph Format Controls"),
+    (0x13460, 0x143FF, "Egyptian Hieroglyphs Extended-A"),
+    (0x14400, 0x1467F, "Anatolian Hieroglyphs"),
+    (0x16100, 0x1613F, "Gurung Khema"),
+    (0x16800, 0x16A3F, "Bamum Supplement"),
+    (0x16A40, 0x16A6F, "Mro"),
+    (0x16A70, 0x16ACF, "Tangsa"),
+    (0x16AD0, 0x16AFF, "Bassa Vah"),
+    (0x16B00, 0x16B8F, "Pahawh Hmong"),
+    (0x16D40, 0x16D7F, "Kirat Rai"),
+    (0x16E40, 0x16E9F, "Medefaidrin"),
+    (0x16F00, 0x16F9F, "Miao"),
+    (0x16FE0, 0x16FFF, "Ideographic Symbols and Punctuation"),
+    (0x17000, 0x187FF, "Tangut"),
+    (0x18800, 0x18AFF, "Tangut Components"),
+    (0x18B00, 0x18CFF, "Khitan Small Script"),
+    (0x18D00, 0x18D7F, "Tangut Supplement"),
+    (0x1AFF0, 0x1AFFF, "Kana Extended-B"),
+    (0x1B000, 0x1B0FF, "Kana Supplement"),
+    (0x1B100, 0x1B12F, "Kana Extended-A"),
+    (0x1B130, 0x1B16F, "Small Kana Extension"),
+    (0x1B170, 0x1B2FF, "Nushu"),
+    (0x1BC00, 0x1BC9F, "Duployan"),
+    (0x1BCA0, 0x1BCAF, "Shorthand Format Controls"),
+    (0x1CC00, 0x1CEBF, "Symbols for Legacy Computing Supplement"),
+    (0x1CF00, 0x1CFCF, "Znamenny Musical Notation"),
+    (0x1D000, 0x1D0FF, "Byzantine Musical Symbols"),
+    (0x1D100, 0x1D1FF, "Musical Symbols"),
+    (0x1D200, 0x1D24F, "Ancient Greek Musical Notation"),
+    (0x1D2C0, 0x1D2DF, "Kaktovik Numerals"),
+    (0x1D2E0, 0x1D2FF, "Mayan Numerals"),
+    (0x1D300, 0x1D35F, "Tai Xuan Jing Symbols"),
+    (0x1D360, 0x1D37F, "Counting Rod Numerals"),
+    (0x1D400, 0x1D7FF, "Mathematical Alphanumeric Symbols"),
+    (0x1D800, 0x1DAAF, "Sutton SignWriting"),
+    (0x1DF00, 0x1DFFF, "Latin Extended-G"),
+    (0x1E000, 0x1E02F, "Glagolitic Supplement"),
+    (0x1E030, 0x1E08F, "Cyrillic Extended-D"),
+    (0x1E100, 0x1E14F, "Nyiakeng Puachue Hmong"),
+    (0x1E290, 0x1E2BF, "Toto"),
+    (0x1E2C0, 0x1E2FF, "Wancho"),
+    (0x1E4D0, 0x1E4FF, "Nag Mundari"),
+    (0x1E5D0, 0x1E5FF, "Ol Onal"),
+    (0x1E7E0, 0x1E7FF, "Ethiopic Extended-B"),
+    (0x1E800, 0x1E8DF, "Mende Kikakui"),
+    (0x1E900, 0x1E95F, "Adlam"),
+    (0x1EC70, 0x1ECBF, "Indic Siyaq Numbers"),
+    (0x1ED00, 0x1ED4F, "Ottoman Siyaq Numbers"),
+    (0x1EE00, 0x1EEFF, "Arabic Mathematical Alphabetic Symbols"),
+    (0x1F000, 0x1F02F, "Mahjong Tiles"),
+    (0x1F030, 0x1F09F, "Domino Tiles"),
+    (0x1F0A0, 0x1F0FF, "Playing Cards"),
+    (0x1F100, 0x1F1FF, "Enclosed Alphanumeric Supplement"),
+    (0x1F200, 0x1F2FF, "Enclosed Ideographic Supplement"),
+    (0x1F300, 0x1F5FF, "Miscellaneous Symbols and Pictographs"),
+    (0x1F600, 0x1F64F, "Emoticons"),
+    (0x1F650, 0x1F67F, "Ornamental Dingbats"),
+    (0x1F680, 0x1F6FF, "Transport and Map Symbols"),
+    (0x1F700, 0x1F77F, "Alchemical Symbols"),
+    (0x1F780, 0x1F7FF, "Geometric Shapes Extended"),
+    (0x1F800, 0x1F8FF, "Supplemental Arrows-C"),
+    (0x1F900, 0x1F9FF, "Supplemental Symbols and Pictographs"),
+    (0x1FA00, 0x1FA6F, "Chess Symbols"),
+    (0x1FA70, 0x1FAFF, "Symbols and Pictographs Extended-A"),
+    (0x1FB00, 0x1FBFF, "Symbols for Legacy Computing"),
+    (0x20000, 0x2A6DF, "CJK Unified Ideographs Extension B"),
+    (0x2A700, 0x2B73F, "CJK Unified Ideographs Extension C"),
+    (0x2B740, 0x2B81F, "CJK Unified Ideographs Extension D"),
+    (0x2B820, 0x2CEAF, "CJK Unified Ideographs Extension E"),
+    (0x2CEB0, 0x2EBEF, "CJK Unified Ideographs Extension F"),
+    (0x2EBF0, 0x2EE5F, "CJK Unified Ideographs Extension I"),
+    (0x2F800, 0x2FA1F, "CJK Compatibility Ideographs Supplement"),
+    (0x30000, 0x3134F, "CJK Unified Ideographs Extension G"),
+    (0x31350, 0x323AF, "CJK Unified Ideographs Extension H"),
+    (0xE0000, 0xE007F, "Tags"),
+    (0xE0100, 0xE01EF, "Variation Selectors Supplement"),
+    (0xF0000, 0xFFFFF, "Supplementary Private Use Area-A"),
+    (0x100000, 0x10FFFF, "Supplementary Private Use Area-B"),
+];
+
+// Ranges of codepoints assigned in each version of Unicode, from `DerivedAge.txt` for Unicode
+// 16.0.0, in order. Regenerate it with `scripts/ages.py`.
+const AGES: &[(u32, u32, u8, u8)] = &[
+    (0x0, 0x1F5, 1, 1), (0x1F6, 0x1F9, 3, 0), (0x1FA, 0x217, 1, 1), (0x218, 0x21F, 3, 0),
+    (0x220, 0x220, 3, 2), (0x221, 0x221, 4, 0), (0x222, 0x233, 3, 0), (0x234, 0x236, 4, 0),
+    (0x237, 0x241, 4, 1), (0x242, 0x24F, 5, 0), (0x250, 0x2A8, 1, 1), (0x2A9, 0x2AD, 3, 0),
+    (0x2AE, 0x2AF, 4, 0), (0x2B0, 0x2DE, 1, 1), (0x2DF, 0x2DF, 3, 0), (0x2E0, 0x2E9, 1, 1),
+    (0x2EA, 0x2EE, 3, 0), (0x2EF, 0x2FF, 4, 0), (0x300, 0x345, 1, 1), (0x346, 0x34E, 3, 0),
+    (0x34F, 0x34F, 3, 2), (0x350, 0x357, 4, 0), (0x358, 0x35C, 4, 1), (0x35D, 0x35F, 4, 0),
+    (0x360, 0x361, 1, 1), (0x362, 0x362, 3, 0), (0x363, 0x36F, 3, 2), (0x370, 0x373, 5, 1),
+    (0x374, 0x375, 1, 1), (0x376, 0x377, 5, 1), (0x37A, 0x37A, 1, 1), (0x37B, 0x37D, 5, 0),
+    (0x37E, 0x37E, 1, 1), (0x37F, 0x37F, 7, 0), (0x384, 0x38A, 1, 1), (0x38C, 0x38C, 1, 1),
+    (0x38E, 0x3A1, 1, 1), (0x3A3, 0x3CE, 1, 1), (0x3CF, 0x3CF, 5, 1), (0x3D0, 0x3D6, 1, 1),
+    (0x3D7, 0x3D7, 3, 0), (0x3D8, 0x3D9, 3, 2), (0x3DA, 0x3DA, 1, 1), (0x3DB, 0x3DB, 3, 0),
+    (0x3DC, 0x3DC, 1, 1), (0x3DD, 0x3DD, 3, 0), (0x3DE, 0x3DE, 1, 1), (0x3DF, 0x3DF, 3, 0),
+    (0x3E0, 0x3E0, 1, 1), (0x3E1, 0x3E1, 3, 0), (0x3E2, 0x3F3, 1, 1), (0x3F4, 0x3F5, 3, 1),
+    (0x3F6, 0x3F6, 3, 2), (0x3F7, 0x3FB, 4, 0), (0x3FC, 0x3FF, 4, 1), (0x400, 0x400, 3, 0),
+    (0x401, 0x40C, 1, 1), (0x40D, 0x40D, 3, 0), (0x40E, 0x44F, 1, 1), (0x450, 0x450, 3, 0),
+    (0x451, 0x45C, 1, 1), (0x45D, 0x45D, 3, 0), (0x45E, 0x486, 1, 1), (0x487, 0x487, 5, 1),
+    (0x488, 0x489, 3, 0), (0x48A, 0x48B, 3, 2), (0x48C, 0x48F, 3, 0), (0x490, 0x4C4, 1, 1),
+    (0x4C5, 0x4C6, 3, 2), (0x4C7, 0x4C8, 1, 1), (0x4C9, 0x4CA, 3, 2), (0x4CB, 0x4CC, 1, 1),
+    (0x4CD, 0x4CE, 3, 2), (0x4CF, 0x4CF, 5, 0), (0x4D0, 0x4EB, 1, 1), (0x4EC, 0x4ED, 3, 0),
+    (0x4EE, 0x4F5, 1, 1), (0x4F6, 0x4F7, 4, 1), (0x4F8, 0x4F9, 1, 1), (0x4FA, 0x4FF, 5, 0),
+    (0x500, 0x50F, 3, 2), (0x510, 0x513, 5, 0), (0x514, 0x523, 5, 1), (0x524, 0x525, 5, 2),
+    (0x526, 0x527, 6, 0), (0x528, 0x52F, 7, 0), (0x531, 0x556, 1, 1), (0x559, 0x55F, 1, 1),
+    (0x560, 0x560, 11, 0), (0x561, 0x587, 1, 1), (0x588, 0x588, 11, 0), (0x589, 0x589, 1, 1),
+    (0x58A, 0x58A, 3, 0), (0x58D, 0x58E, 7, 0), (0x58F, 0x58F, 6, 1), (0x591, 0x5A1, 2, 0),
+    (0x5A2, 0x5A2, 4, 1), (0x5A3, 0x5AF, 2, 0), (0x5B0, 0x5B9, 1, 1), (0x5BA, 0x5BA, 5, 0),
+    (0x5BB, 0x5C3, 1, 1), (0x5C4, 0x5C4, 2, 0), (0x5C5, 0x5C7, 4, 1), (0x5D0, 0x5EA, 1, 1),
+    (0x5EF, 0x5EF, 11, 0), (0x5F0, 0x5F4, 1, 1), (0x600, 0x603, 4, 0), (0x604, 0x604, 6, 1),
+    (0x605, 0x605, 7, 0), (0x606, 0x60A, 5, 1), (0x60B, 0x60B, 4, 1), (0x60C, 0x60C, 1, 1),
+    (0x60D, 0x615, 4, 0), (0x616, 0x61A, 5, 1), (0x61B, 0x61B, 1, 1), (0x61C, 0x61C, 6, 3),
+    (0x61D, 0x61D, 14, 0), (0x61E, 0x61E, 4, 1), (0x61F, 0x61F, 1, 1), (0x620, 0x620, 6, 0),
+    (0x621, 0x63A, 1, 1), (0x63B, 0x63F, 5, 1), (0x640, 0x652, 1, 1), (0x653, 0x655, 3, 0),
+    (0x656, 0x658, 4, 0), (0x659, 0x65E, 4, 1), (0x65F, 0x65F, 6, 0), (0x660, 0x66D, 1, 1),
+    (0x66E, 0x66F, 3, 2), (0x670, 0x6B7, 1, 1), (0x6B8, 0x6B9, 3, 0), (0x6BA, 0x6BE, 1, 1),
+    (0x6BF, 0x6BF, 3, 0), (0x6C0, 0x6CE, 1, 1), (0x6CF, 0x6CF, 3, 0), (0x6D0, 0x6ED, 1, 1),
+    (0x6EE, 0x6EF, 4, 0), (0x6F0, 0x6F9, 1, 1), (0x6FA, 0x6FE, 3, 0), (0x6FF, 0x6FF, 4, 0),
+    (0x700, 0x70D, 3, 0), (0x70F, 0x72C, 3, 0), (0x72D, 0x72F, 4, 0), (0x730, 0x74A, 3, 0),
+    (0x74D, 0x74F, 4, 0), (0x750, 0x76D, 4, 1), (0x76E, 0x77F, 5, 1), (0x780, 0x7B0, 3, 0),
+    (0x7B1, 0x7B1, 3, 2), (0x7C0, 0x7FA, 5, 0), (0x7FD, 0x7FF, 11, 0), (0x800, 0x82D, 5, 2),
+    (0x830, 0x83E, 5, 2), (0x840, 0x85B, 6, 0), (0x85E, 0x85E, 6, 0), (0x860, 0x86A, 10, 0),
+    (0x870, 0x88E, 14, 0), (0x890, 0x891, 14, 0), (0x897, 0x897, 16, 0), (0x898, 0x89F, 14, 0),
+    (0x8A0, 0x8A0, 6, 1), (0x8A1, 0x8A1, 7, 0), (0x8A2, 0x8AC, 6, 1), (0x8AD, 0x8B2, 7, 0),
+    (0x8B3, 0x8B4, 8, 0), (0x8B5, 0x8B5, 14, 0), (0x8B6, 0x8BD, 9, 0), (0x8BE, 0x8C7, 13, 0),
+    (0x8C8, 0x8D2, 14, 0), (0x8D3, 0x8D3, 11, 0), (0x8D4, 0x8E2, 9, 0), (0x8E3, 0x8E3, 8, 0),
+    (0x8E4, 0x8FE, 6, 1), (0x8FF, 0x8FF, 7, 0), (0x900, 0x900, 5, 2), (0x901, 0x903, 1, 1),
+    (0x904, 0x904, 4, 0), (0x905, 0x939, 1, 1), (0x93A, 0x93B, 6, 0), (0x93C, 0x94D, 1, 1),
+    (0x94E, 0x94E, 5, 2), (0x94F, 0x94F, 6, 0), (0x950, 0x954, 1, 1), (0x955, 0x955, 5, 2),
+    (0x956, 0x957, 6, 0), (0x958, 0x970, 1, 1), (0x971, 0x972, 5, 1), (0x973, 0x977, 6, 0),
+    (0x978, 0x978, 7, 0), (0x979, 0x97A, 5, 2), (0x97B, 0x97C, 5, 0), (0x97D, 0x97D, 4, 1),
+    (0x97E, 0x97F, 5, 0), (0x980, 0x980, 7, 0), (0x981, 0x983, 1, 1), (0x985, 0x98C, 1, 1),
+    (0x98F, 0x990, 1, 1), (0x993, 0x9A8, 1, 1), (0x9AA, 0x9B0, 1, 1), (0x9B2, 0x9B2, 1, 1),
+    (0x9B6, 0x9B9, 1, 1), (0x9BC, 0x9BC, 1, 1), (0x9BD, 0x9BD, 4, 0), (0x9BE, 0x9C4, 1, 1),
+    (0x9C7, 0x9C8, 1, 1), (0x9CB, 0x9CD, 1, 1), (0x9CE, 0x9CE, 4, 1), (0x9D7, 0x9D7, 1, 1),
+    (0x9DC, 0x9DD, 1, 1), (0x9DF, 0x9E3, 1, 1), (0x9E6, 0x9FA, 1, 1), (0x9FB, 0x9FB, 5, 2),
+    (0x9FC, 0x9FD, 10, 0), (0x9FE, 0x9FE, 11, 0), (0xA01, 0xA01, 4, 0), (0xA02, 0xA02, 1, 1),
+    (0xA03, 0xA03, 4, 0), (0xA05, 0xA0A, 1, 1), (0xA0F, 0xA10, 1, 1), (0xA13, 0xA28, 1, 1),
+    (0xA2A, 0xA30, 1, 1), (0xA32, 0xA33, 1, 1), (0xA35, 0xA36, 1, 1), (0xA38, 0xA39, 1, 1),
+    (0xA3C, 0xA3C, 1, 1), (0xA3E, 0xA42, 1, 1), (0xA47, 0xA48, 1, 1), (0xA4B, 0xA4D, 1, 1),
+    (0xA51, 0xA51, 5, 1), (0xA59, 0xA5C, 1, 1), (0xA5E, 0xA5E, 1, 1), (0xA66, 0xA74, 1, 1),
+    (0xA75, 0xA75, 5, 1), (0xA76, 0xA76, 11, 0), (0xA81, 0xA83, 1, 1), (0xA85, 0xA8B, 1, 1),
+    (0xA8C, 0xA8C, 4, 0), (0xA8D, 0xA8D, 1, 1), (0xA8F, 0xA91, 1, 1), (0xA93, 0xAA8, 1, 1),
+    (0xAAA, 0xAB0, 1, 1), (0xAB2, 0xAB3, 1, 1), (0xAB5, 0xAB9, 1, 1), (0xABC, 0xAC5, 1, 1),
+    (0xAC7, 0xAC9, 1, 1), (0xACB, 0xACD, 1, 1), (0xAD0, 0xAD0, 1, 1), (0xAE0, 0xAE0, 1, 1),
+    (0xAE1, 0xAE3, 4, 0), (0xAE6, 0xAEF, 1, 1), (0xAF0, 0xAF0, 6, 1), (0xAF1, 0xAF1, 4, 0),
+    (0xAF9, 0xAF9, 8, 0), (0xAFA, 0xAFF, 10, 0), (0xB01, 0xB03, 1, 1), (0xB05, 0xB0C, 1, 1),
+    (0xB0F, 0xB10, 1, 1), (0xB13, 0xB28, 1, 1), (0xB2A, 0xB30, 1, 1), (0xB32, 0xB33, 1, 1),
+    (0xB35, 0xB35, 4, 0), (0xB36, 0xB39, 1, 1), (0xB3C, 0xB43, 1, 1), (0xB44, 0xB44, 5, 1),
+    (0xB47, 0xB48, 1, 1), (0xB4B, 0xB4D, 1, 1), (0xB55, 0xB55, 13, 0), (0xB56, 0xB57, 1, 1),
+    (0xB5C, 0xB5D, 1, 1), (0xB5F, 0xB61, 1, 1), (0xB62, 0xB63, 5, 1), (0xB66, 0xB70, 1, 1),
+    (0xB71, 0xB71, 4, 0), (0xB72, 0xB77, 6, 0), (0xB82, 0xB83, 1, 1), (0xB85, 0xB8A, 1, 1),
+    (0xB8E, 0xB90, 1, 1), (0xB92, 0xB95, 1, 1), (0xB99, 0xB9A, 1, 1), (0xB9C, 0xB9C, 1, 1),
+    (0xB9E, 0xB9F, 1, 1), (0xBA3, 0xBA4, 1, 1), (0xBA8, 0xBAA, 1, 1), (0xBAE, 0xBB5, 1, 1),
+    (0xBB6, 0xBB6, 4, 1), (0xBB7, 0xBB9, 1, 1), (0xBBE, 0xBC2, 1, 1), (0xBC6, 0xBC8, 1, 1),
+    (0xBCA, 0xBCD, 1, 1), (0xBD0, 0xBD0, 5, 1), (0xBD7, 0xBD7, 1, 1), (0xBE6, 0xBE6, 4, 1),
+    (0xBE7, 0xBF2, 1, 1), (0xBF3, 0xBFA, 4, 0), (0xC00, 0xC00, 7, 0), (0xC01, 0xC03, 1, 1),
+    (0xC04, 0xC04, 11, 0), (0xC05, 0xC0C, 1, 1), (0xC0E, 0xC10, 1, 1), (0xC12, 0xC28, 1, 1),
+    (0xC2A, 0xC33, 1, 1), (0xC34, 0xC34, 7, 0), (0xC35, 0xC39, 1, 1), (0xC3C, 0xC3C, 14, 0),
+    (0xC3D, 0xC3D, 5, 1), (0xC3E, 0xC44, 1, 1), (0xC46, 0xC48, 1, 1), (0xC4A, 0xC4D, 1, 1),
+    (0xC55, 0xC56, 1, 1), (0xC58, 0xC59, 5, 1), (0xC5A, 0xC5A, 8, 0), (0xC5D, 0xC5D, 14, 0),
+    (0xC60, 0xC61, 1, 1), (0xC62, 0xC63, 5, 1), (0xC66, 0xC6F, 1, 1), (0xC77, 0xC77, 12, 0),
+    (0xC78, 0xC7F, 5, 1), (0xC80, 0xC80, 9, 0), (0xC81, 0xC81, 7, 0), (0xC82, 0xC83, 1, 1),
+    (0xC84, 0xC84, 11, 0), (0xC85, 0xC8C, 1, 1), (0xC8E, 0xC90, 1, 1), (0xC92, 0xCA8, 1, 1),
+    (0xCAA, 0xCB3, 1, 1), (0xCB5, 0xCB9, 1, 1), (0xCBC, 0xCBD, 4, 0), (0xCBE, 0xCC4, 1, 1),
+    (0xCC6, 0xCC8, 1, 1), (0xCCA, 0xCCD, 1, 1), (0xCD5, 0xCD6, 1, 1), (0xCDD, 0xCDD, 14, 0),
+    (0xCDE, 0xCDE, 1, 1), (0xCE0, 0xCE1, 1, 1), (0xCE2, 0xCE3, 5, 0), (0xCE6, 0xCEF, 1, 1),
+    (0xCF1, 0xCF2, 5, 0), (0xCF3, 0xCF3, 15, 0), (0xD00, 0xD00, 10, 0), (0xD01, 0xD01, 7, 0),
+    (0xD02, 0xD03, 1, 1), (0xD04, 0xD04, 13, 0), (0xD05, 0xD0C, 1, 1), (0xD0E, 0xD10, 1, 1),
+    (0xD12, 0xD28, 1, 1), (0xD29, 0xD29, 6, 0), (0xD2A, 0xD39, 1, 1), (0xD3A, 0xD3A, 6, 0),
+    (0xD3B, 0xD3C, 10, 0), (0xD3D, 0xD3D, 5, 1), (0xD3E, 0xD43, 1, 1), (0xD44, 0xD44, 5, 1),
+    (0xD46, 0xD48, 1, 1), (0xD4A, 0xD4D, 1, 1), (0xD4E, 0xD4E, 6, 0), (0xD4F, 0xD4F, 9, 0),
+    (0xD54, 0xD56, 9, 0), (0xD57, 0xD57, 1, 1), (0xD58, 0xD5E, 9, 0), (0xD5F, 0xD5F, 8, 0),
+    (0xD60, 0xD61, 1, 1), (0xD62, 0xD63, 5, 1), (0xD66, 0xD6F, 1, 1), (0xD70, 0xD75, 5, 1),
+    (0xD76, 0xD78, 9, 0), (0xD79, 0xD7F, 5, 1), (0xD81, 0xD81, 13, 0), (0xD82, 0xD83, 3, 0),
+    (0xD85, 0xD96, 3, 0), (0xD9A, 0xDB1, 3, 0), (0xDB3, 0xDBB, 3, 0), (0xDBD, 0xDBD, 3, 0),
+    (0xDC0, 0xDC6, 3, 0), (0xDCA, 0xDCA, 3, 0), (0xDCF, 0xDD4, 3, 0), (0xDD6, 0xDD6, 3, 0),
+    (0xDD8, 0xDDF, 3, 0), (0xDE6, 0xDEF, 7, 0), (0xDF2, 0xDF4, 3, 0), (0xE01, 0xE3A, 1, 1),
+    (0xE3F, 0xE5B, 1, 1), (0xE81, 0xE82, 1, 1), (0xE84, 0xE84, 1, 1), (0xE86, 0xE86, 12, 0),
+    (0xE87, 0xE88, 1, 1), (0xE89, 0xE89, 12, 0), (0xE8A, 0xE8A, 1, 1), (0xE8C, 0xE8C, 12, 0),
+    (0xE8D, 0xE8D, 1, 1), (0xE8E, 0xE93, 12, 0), (0xE94, 0xE97, 1, 1), (0xE98, 0xE98, 12, 0),
+    (0xE99, 0xE9F, 1, 1), (0xEA0, 0xEA0, 12, 0), (0xEA1, 0xEA3, 1, 1), (0xEA5, 0xEA5, 1, 1),
+    (0xEA7, 0xEA7, 1, 1), (0xEA8, 0xEA9, 12, 0), (0xEAA, 0xEAB, 1, 1), (0xEAC, 0xEAC, 12, 0),
+    (0xEAD, 0xEB9, 1, 1), (0xEBA, 0xEBA, 12, 0), (0xEBB, 0xEBD, 1, 1), (0xEC0, 0xEC4, 1, 1),
+    (0xEC6, 0xEC6, 1, 1), (0xEC8, 0xECD, 1, 1), (0xECE, 0xECE, 15, 0), (0xED0, 0xED9, 1, 1),
+    (0xEDC, 0xEDD, 1, 1), (0xEDE, 0xEDF, 6, 1), (0xF00, 0xF47, 2, 0), (0xF49, 0xF69, 2, 0),
+    (0xF6A, 0xF6A, 3, 0), (0xF6B, 0xF6C, 5, 1), (0xF71, 0xF8B, 2, 0), (0xF8C, 0xF8F, 6, 0),
+    (0xF90, 0xF95, 2, 0), (0xF96, 0xF96, 3, 0), (0xF97, 0xF97, 2, 0), (0xF99, 0xFAD, 2, 0),
+    (0xFAE, 0xFB0, 3, 0), (0xFB1, 0xFB7, 2, 0), (0xFB8, 0xFB8, 3, 0), (0xFB9, 0xFB9, 2, 0),
+    (0xFBA, 0xFBC, 3, 0), (0xFBE, 0xFCC, 3, 0), (0xFCE, 0xFCE, 5, 1), (0xFCF, 0xFCF, 3, 0),
+    (0xFD0, 0xFD1, 4, 1), (0xFD2, 0xFD4, 5, 1), (0xFD5, 0xFD8, 5, 2), (0xFD9, 0xFDA, 6, 0),
+    (0x1000, 0x1021, 3, 0), (0x1022, 0x1022, 5, 1), (0x1023, 0x1027, 3, 0), (0x1028, 0x1028, 5, 1),
+    (0x1029, 0x102A, 3, 0), (0x102B, 0x102B, 5, 1), (0x102C, 0x1032, 3, 0), (0x1033, 0x1035, 5, 1),
+    (0x1036, 0x1039, 3, 0), (0x103A, 0x103F, 5, 1), (0x1040, 0x1059, 3, 0), (0x105A, 0x1099, 5, 1),
+    (0x109A, 0x109D, 5, 2), (0x109E, 0x109F, 5, 1), (0x10A0, 0x10C5, 1, 1), (0x10C7, 0x10C7, 6, 1),
+    (0x10CD, 0x10CD, 6, 1), (0x10D0, 0x10F6, 1, 1), (0x10F7, 0x10F8, 3, 2), (0x10F9, 0x10FA, 4, 1),
+    (0x10FB, 0x10FB, 1, 1), (0x10FC, 0x10FC, 4, 1), (0x10FD, 0x10FF, 6, 1), (0x1100, 0x1159, 1, 1),
+    (0x115A, 0x115E, 5, 2), (0x115F, 0x11A2, 1, 1), (0x11A3, 0x11A7, 5, 2), (0x11A8, 0x11F9, 1, 1),
+    (0x11FA, 0x11FF, 5, 2), (0x1200, 0x1206, 3, 0), (0x1207, 0x1207, 4, 1), (0x1208, 0x1246, 3, 0),
+    (0x1247, 0x1247, 4, 1), (0x1248, 0x1248, 3, 0), (0x124A, 0x124D, 3, 0), (0x1250, 0x1256, 3, 0),
+    (0x1258, 0x1258, 3, 0), (0x125A, 0x125D, 3, 0), (0x1260, 0x1286, 3, 0), (0x1287, 0x1287, 4, 1),
+    (0x1288, 0x1288, 3, 0), (0x128A, 0x128D, 3, 0), (0x1290, 0x12AE, 3, 0), (0x12AF, 0x12AF, 4, 1),
+    (0x12B0, 0x12B0, 3, 0), (0x12B2, 0x12B5, 3, 0), (0x12B8, 0x12BE, 3, 0), (0x12C0, 0x12C0, 3, 0),
+    (0x12C2, 0x12C5, 3, 0), (0x12C8, 0x12CE, 3, 0), (0x12CF, 0x12CF, 4, 1), (0x12D0, 0x12D6, 3, 0),
+    (0x12D8, 0x12EE, 3, 0), (0x12EF, 0x12EF, 4, 1), (0x12F0, 0x130E, 3, 0), (0x130F, 0x130F, 4, 1),
+    (0x1310, 0x1310, 3, 0), (0x1312, 0x1315, 3, 0), (0x1318, 0x131E, 3, 0), (0x131F, 0x131F, 4, 1),
+    (0x1320, 0x1346, 3, 0), (0x1347, 0x1347, 4, 1), (0x1348, 0x135A, 3, 0), (0x135D, 0x135E, 6, 0),
+    (0x135F, 0x1360, 4, 1), (0x1361, 0x137C, 3, 0), (0x1380, 0x1399, 4, 1), (0x13A0, 0x13F4, 3, 0),
+    (0x13F5, 0x13F5, 8, 0), (0x13F8, 0x13FD, 8, 0), (0x1400, 0x1400, 5, 2), (0x1401, 0x1676, 3, 0),
+    (0x1677, 0x167F, 5, 2), (0x1680, 0x169C, 3, 0), (0x16A0, 0x16F0, 3, 0), (0x16F1, 0x16F8, 7, 0),
+    (0x1700, 0x170C, 3, 2), (0x170D, 0x170D, 14, 0), (0x170E, 0x1714, 3, 2),
+    (0x1715, 0x1715, 14, 0), (0x171F, 0x171F, 14, 0), (0x1720, 0x1736, 3, 2),
+    (0x1740, 0x1753, 3, 2), (0x1760, 0x176C, 3, 2), (0x176E, 0x1770, 3, 2), (0x1772, 0x1773, 3, 2),
+    (0x1780, 0x17DC, 3, 0), (0x17DD, 0x17DD, 4, 0), (0x17E0, 0x17E9, 3, 0), (0x17F0, 0x17F9, 4, 0),
+    (0x1800, 0x180E, 3, 0), (0x180F, 0x180F, 14, 0), (0x1810, 0x1819, 3, 0), (0x1820, 0x1877, 3, 0),
+    (0x1878, 0x1878, 11, 0), (0x1880, 0x18A9, 3, 0), (0x18AA, 0x18AA, 5, 1), (0x18B0, 0x18F5, 5, 2),
+    (0x1900, 0x191C, 4, 0), (0x191D, 0x191E, 7, 0), (0x1920, 0x192B, 4, 0), (0x1930, 0x193B, 4, 0),
+    (0x1940, 0x1940, 4, 0), (0x1944, 0x196D, 4, 0), (0x1970, 0x1974, 4, 0), (0x1980, 0x19A9, 4, 1),
+    (0x19AA, 0x19AB, 5, 2), (0x19B0, 0x19C9, 4, 1), (0x19D0, 0x19D9, 4, 1), (0x19DA, 0x19DA, 5, 2),
+    (0x19DE, 0x19DF, 4, 1), (0x19E0, 0x19FF, 4, 0), (0x1A00, 0x1A1B, 4, 1), (0x1A1E, 0x1A1F, 4, 1),
+    (0x1A20, 0x1A5E, 5, 2), (0x1A60, 0x1A7C, 5, 2), (0x1A7F, 0x1A89, 5, 2), (0x1A90, 0x1A99, 5, 2),
+    (0x1AA0, 0x1AAD, 5, 2), (0x1AB0, 0x1ABE, 7, 0), (0x1ABF, 0x1AC0, 13, 0),
+    (0x1AC1, 0x1ACE, 14, 0), (0x1B00, 0x1B4B, 5, 0), (0x1B4C, 0x1B4C, 14, 0),
+    (0x1B4E, 0x1B4F, 16, 0), (0x1B50, 0x1B7C, 5, 0), (0x1B7D, 0x1B7E, 14, 0),
+    (0x1B7F, 0x1B7F, 16, 0), (0x1B80, 0x1BAA, 5, 1), (0x1BAB, 0x1BAD, 6, 1), (0x1BAE, 0x1BB9, 5, 1),
+    (0x1BBA, 0x1BBF, 6, 1), (0x1BC0, 0x1BF3, 6, 0), (0x1BFC, 0x1BFF, 6, 0), (0x1C00, 0x1C37, 5, 1),
+    (0x1C3B, 0x1C49, 5, 1), (0x1C4D, 0x1C7F, 5, 1), (0x1C80, 0x1C88, 9, 0), (0x1C89, 0x1C8A, 16, 0),
+    (0x1C90, 0x1CBA, 11, 0), (0x1CBD, 0x1CBF, 11, 0), (0x1CC0, 0x1CC7, 6, 1),
+    (0x1CD0, 0x1CF2, 5, 2), (0x1CF3, 0x1CF6, 6, 1), (0x1CF7, 0x1CF7, 10, 0), (0x1CF8, 0x1CF9, 7, 0),
+    (0x1CFA, 0x1CFA, 12, 0), (0x1D00, 0x1D6B, 4, 0), (0x1D6C, 0x1DC3, 4, 1), (0x1DC4, 0x1DCA, 5, 0),
+    (0x1DCB, 0x1DE6, 5, 1), (0x1DE7, 0x1DF5, 7, 0), (0x1DF6, 0x1DF9, 10, 0),
+    (0x1DFA, 0x1DFA, 14, 0), (0x1DFB, 0x1DFB, 9, 0), (0x1DFC, 0x1DFC, 6, 0), (0x1DFD, 0x1DFD, 5, 2),
+    (0x1DFE, 0x1DFF, 5, 0), (0x1E00, 0x1E9A, 1, 1), (0x1E9B, 0x1E9B, 2, 0), (0x1E9C, 0x1E9F, 5, 1),
+    (0x1EA0, 0x1EF9, 1, 1), (0x1EFA, 0x1EFF, 5, 1), (0x1F00, 0x1F15, 1, 1), (0x1F18, 0x1F1D, 1, 1),
+    (0x1F20, 0x1F45, 1, 1), (0x1F48, 0x1F4D, 1, 1), (0x1F50, 0x1F57, 1, 1), (0x1F59, 0x1F59, 1, 1),
+    (0x1F5B, 0x1F5B, 1, 1), (0x1F5D, 0x1F5D, 1, 1), (0x1F5F, 0x1F7D, 1, 1), (0x1F80, 0x1FB4, 1, 1),
+    (0x1FB6, 0x1FC4, 1, 1), (0x1FC6, 0x1FD3, 1, 1), (0x1FD6, 0x1FDB, 1, 1), (0x1FDD, 0x1FEF, 1, 1),
+    (0x1FF2, 0x1FF4, 1, 1), (0x1FF6, 0x1FFE, 1, 1), (0x2000, 0x202E, 1, 1), (0x202F, 0x202F, 3, 0),
+    (0x2030, 0x2046, 1, 1), (0x2047, 0x2047, 3, 2), (0x2048, 0x204D, 3, 0), (0x204E, 0x2052, 3, 2),
+    (0x2053, 0x2054, 4, 0), (0x2055, 0x2056, 4, 1), (0x2057, 0x2057, 3, 2), (0x2058, 0x205E, 4, 1),
+    (0x205F, 0x2063, 3, 2), (0x2064, 0x2064, 5, 1), (0x2066, 0x2069, 6, 3), (0x206A, 0x2070, 1, 1),
+    (0x2071, 0x2071, 3, 2), (0x2074, 0x208E, 1, 1), (0x2090, 0x2094, 4, 1), (0x2095, 0x209C, 6, 0),
+    (0x20A0, 0x20AA, 1, 1), (0x20AB, 0x20AB, 2, 0), (0x20AC, 0x20AC, 2, 1), (0x20AD, 0x20AF, 3, 0),
+    (0x20B0, 0x20B1, 3, 2), (0x20B2, 0x20B5, 4, 1), (0x20B6, 0x20B8, 5, 2), (0x20B9, 0x20B9, 6, 0),
+    (0x20BA, 0x20BA, 6, 2), (0x20BB, 0x20BD, 7, 0), (0x20BE, 0x20BE, 8, 0), (0x20BF, 0x20BF, 10, 0),
+    (0x20C0, 0x20C0, 14, 0), (0x20D0, 0x20E1, 1, 1), (0x20E2, 0x20E3, 3, 0), (0x20E4, 0x20EA, 3, 2),
+    (0x20EB, 0x20EB, 4, 1), (0x20EC, 0x20EF, 5, 0), (0x20F0, 0x20F0, 5, 1), (0x2100, 0x2138, 1, 1),
+    (0x2139, 0x213A, 3, 0), (0x213B, 0x213B, 4, 0), (0x213C, 0x213C, 4, 1), (0x213D, 0x214B, 3, 2),
+    (0x214C, 0x214C, 4, 1), (0x214D, 0x214E, 5, 0), (0x214F, 0x214F, 5, 1), (0x2150, 0x2152, 5, 2),
+    (0x2153, 0x2182, 1, 1), (0x2183, 0x2183, 3, 0), (0x2184, 0x2184, 5, 0), (0x2185, 0x2188, 5, 1),
+    (0x2189, 0x2189, 5, 2), (0x218A, 0x218B, 8, 0), (0x2190, 0x21EA, 1, 1), (0x21EB, 0x21F3, 3, 0),
+    (0x21F4, 0x21FF, 3, 2), (0x2200, 0x22F1, 1, 1), (0x22F2, 0x22FF, 3, 2), (0x2300, 0x2300, 1, 1),
+    (0x2301, 0x2301, 3, 0), (0x2302, 0x237A, 1, 1), (0x237B, 0x237B, 3, 0), (0x237C, 0x237C, 3, 2),
+    (0x237D, 0x239A, 3, 0), (0x239B, 0x23CE, 3, 2), (0x23CF, 0x23D0, 4, 0), (0x23D1, 0x23DB, 4, 1),
+    (0x23DC, 0x23E7, 5, 0), (0x23E8, 0x23E8, 5, 2), (0x23E9, 0x23F3, 6, 0), (0x23F4, 0x23FA, 7, 0),
+    (0x23FB, 0x23FE, 9, 0), (0x23FF, 0x23FF, 10, 0), (0x2400, 0x2424, 1, 1), (0x2425, 0x2426, 3, 0),
+    (0x2427, 0x2429, 16, 0), (0x2440, 0x244A, 1, 1), (0x2460, 0x24EA, 1, 1), (0x24EB, 0x24FE, 3, 2),
+    (0x24FF, 0x24FF, 4, 0), (0x2500, 0x2595, 1, 1), (0x2596, 0x259F, 3, 2), (0x25A0, 0x25EF, 1, 1),
+    (0x25F0, 0x25F7, 3, 0), (0x25F8, 0x25FF, 3, 2), (0x2600, 0x2613, 1, 1), (0x2614, 0x2615, 4, 0),
+    (0x2616, 0x2617, 3, 2), (0x2618, 0x2618, 4, 1), (0x2619, 0x2619, 3, 0), (0x261A, 0x266F, 1, 1),
+    (0x2670, 0x2671, 3, 0), (0x2672, 0x267D, 3, 2), (0x267E, 0x267F, 4, 1), (0x2680, 0x2689, 3, 2),
+    (0x268A, 0x2691, 4, 0), (0x2692, 0x269C, 4, 1), (0x269D, 0x269D, 5, 1), (0x269E, 0x269F, 5, 2),
+    (0x26A0, 0x26A1, 4, 0), (0x26A2, 0x26B1, 4, 1), (0x26B2, 0x26B2, 5, 0), (0x26B3, 0x26BC, 5, 1),
+    (0x26BD, 0x26BF, 5, 2), (0x26C0, 0x26C3, 5, 1), (0x26C4, 0x26CD, 5, 2), (0x26CE, 0x26CE, 6, 0),
+    (0x26CF, 0x26E1, 5, 2), (0x26E2, 0x26E2, 6, 0), (0x26E3, 0x26E3, 5, 2), (0x26E4, 0x26E7, 6, 0),
+    (0x26E8, 0x26FF, 5, 2), (0x2700, 0x2700, 7, 0), (0x2701, 0x2704, 1, 1), (0x2705, 0x2705, 6, 0),
+    (0x2706, 0x2709, 1, 1), (0x270A, 0x270B, 6, 0), (0x270C, 0x2727, 1, 1), (0x2728, 0x2728, 6, 0),
+    (0x2729, 0x274B, 1, 1), (0x274C, 0x274C, 6, 0), (0x274D, 0x274D, 1, 1), (0x274E, 0x274E, 6, 0),
+    (0x274F, 0x2752, 1, 1), (0x2753, 0x2755, 6, 0), (0x2756, 0x2756, 1, 1), (0x2757, 0x2757, 5, 2),
+    (0x2758, 0x275E, 1, 1), (0x275F, 0x2760, 6, 0), (0x2761, 0x2767, 1, 1), (0x2768, 0x2775, 3, 2),
+    (0x2776, 0x2794, 1, 1), (0x2795, 0x2797, 6, 0), (0x2798, 0x27AF, 1, 1), (0x27B0, 0x27B0, 6, 0),
+    (0x27B1, 0x27BE, 1, 1), (0x27BF, 0x27BF, 6, 0), (0x27C0, 0x27C6, 4, 1), (0x27C7, 0x27CA, 5, 0),
+    (0x27CB, 0x27CB, 6, 1), (0x27CC, 0x27CC, 5, 1), (0x27CD, 0x27CD, 6, 1), (0x27CE, 0x27CF, 6, 0),
+    (0x27D0, 0x27EB, 3, 2), (0x27EC, 0x27EF, 5, 1), (0x27F0, 0x27FF, 3, 2), (0x2800, 0x28FF, 3, 0),
+    (0x2900, 0x2AFF, 3, 2), (0x2B00, 0x2B0D, 4, 0), (0x2B0E, 0x2B13, 4, 1), (0x2B14, 0x2B1A, 5, 0),
+    (0x2B1B, 0x2B1F, 5, 1), (0x2B20, 0x2B23, 5, 0), (0x2B24, 0x2B4C, 5, 1), (0x2B4D, 0x2B4F, 7, 0),
+    (0x2B50, 0x2B54, 5, 1), (0x2B55, 0x2B59, 5, 2), (0x2B5A, 0x2B73, 7, 0), (0x2B76, 0x2B95, 7, 0),
+    (0x2B97, 0x2B97, 13, 0), (0x2B98, 0x2BB9, 7, 0), (0x2BBA, 0x2BBC, 11, 0),
+    (0x2BBD, 0x2BC8, 7, 0), (0x2BC9, 0x2BC9, 12, 0), (0x2BCA, 0x2BD1, 7, 0),
+    (0x2BD2, 0x2BD2, 10, 0), (0x2BD3, 0x2BEB, 11, 0), (0x2BEC, 0x2BEF, 8, 0),
+    (0x2BF0, 0x2BFE, 11, 0), (0x2BFF, 0x2BFF, 12, 0), (0x2C00, 0x2C2E, 4, 1),
+    (0x2C2F, 0x2C2F, 14, 0), (0x2C30, 0x2C5E, 4, 1), (0x2C5F, 0x2C5F, 14, 0),
+    (0x2C60, 0x2C6C, 5, 0), (0x2C6D, 0x2C6F, 5, 1), (0x2C70, 0x2C70, 5, 2), (0x2C71, 0x2C73, 5, 1),
+    (0x2C74, 0x2C77, 5, 0), (0x2C78, 0x2C7D, 5, 1), (0x2C7E, 0x2C7F, 5, 2), (0x2C80, 0x2CEA, 4, 1),
+    (0x2CEB, 0x2CF1, 5, 2), (0x2CF2, 0x2CF3, 6, 1), (0x2CF9, 0x2D25, 4, 1), (0x2D27, 0x2D27, 6, 1),
+    (0x2D2D, 0x2D2D, 6, 1), (0x2D30, 0x2D65, 4, 1), (0x2D66, 0x2D67, 6, 1), (0x2D6F, 0x2D6F, 4, 1),
+    (0x2D70, 0x2D70, 6, 0), (0x2D7F, 0x2D7F, 6, 0), (0x2D80, 0x2D96, 4, 1), (0x2DA0, 0x2DA6, 4, 1),
+    (0x2DA8, 0x2DAE, 4, 1), (0x2DB0, 0x2DB6, 4, 1), (0x2DB8, 0x2DBE, 4, 1), (0x2DC0, 0x2DC6, 4, 1),
+    (0x2DC8, 0x2DCE, 4, 1), (0x2DD0, 0x2DD6, 4, 1), (0x2DD8, 0x2DDE, 4, 1), (0x2DE0, 0x2DFF, 5, 1),
+    (0x2E00, 0x2E17, 4, 1), (0x2E18, 0x2E1B, 5, 1), (0x2E1C, 0x2E1D, 4, 1), (0x2E1E, 0x2E30, 5, 1),
+    (0x2E31, 0x2E31, 5, 2), (0x2E32, 0x2E3B, 6, 1), (0x2E3C, 0x2E42, 7, 0), (0x2E43, 0x2E44, 9, 0),
+    (0x2E45, 0x2E49, 10, 0), (0x2E4A, 0x2E4E, 11, 0), (0x2E4F, 0x2E4F, 12, 0),
+    (0x2E50, 0x2E52, 13, 0), (0x2E53, 0x2E5D, 14, 0), (0x2E80, 0x2E99, 3, 0),
+    (0x2E9B, 0x2EF3, 3, 0), (0x2F00, 0x2FD5, 3, 0), (0x2FF0, 0x2FFB, 3, 0), (0x2FFC, 0x2FFF, 15, 1),
+    (0x3000, 0x3037, 1, 1), (0x3038, 0x303A, 3, 0), (0x303B, 0x303D, 3, 2), (0x303E, 0x303E, 3, 0),
+    (0x303F, 0x303F, 1, 1), (0x3041, 0x3094, 1, 1), (0x3095, 0x3096, 3, 2), (0x3099, 0x309E, 1, 1),
+    (0x309F, 0x30A0, 3, 2), (0x30A1, 0x30FE, 1, 1), (0x30FF, 0x30FF, 3, 2), (0x3105, 0x312C, 1, 1),
+    (0x312D, 0x312D, 5, 1), (0x312E, 0x312E, 10, 0), (0x312F, 0x312F, 11, 0),
+    (0x3131, 0x318E, 1, 1), (0x3190, 0x319F, 1, 1), (0x31A0, 0x31B7, 3, 0), (0x31B8, 0x31BA, 6, 0),
+    (0x31BB, 0x31BF, 13, 0), (0x31C0, 0x31CF, 4, 1), (0x31D0, 0x31E3, 5, 1),
+    (0x31E4, 0x31E5, 16, 0), (0x31EF, 0x31EF, 15, 1), (0x31F0, 0x31FF, 3, 2),
+    (0x3200, 0x321C, 1, 1), (0x321D, 0x321E, 4, 0), (0x3220, 0x3243, 1, 1), (0x3244, 0x324F, 5, 2),
+    (0x3250, 0x3250, 4, 0), (0x3251, 0x325F, 3, 2), (0x3260, 0x327B, 1, 1), (0x327C, 0x327D, 4, 0),
+    (0x327E, 0x327E, 4, 1), (0x327F, 0x32B0, 1, 1), (0x32B1, 0x32BF, 3, 2), (0x32C0, 0x32CB, 1, 1),
+    (0x32CC, 0x32CF, 4, 0), (0x32D0, 0x32FE, 1, 1), (0x32FF, 0x32FF, 12, 1), (0x3300, 0x3376, 1, 1),
+    (0x3377, 0x337A, 4, 0), (0x337B, 0x33DD, 1, 1), (0x33DE, 0x33DF, 4, 0), (0x33E0, 0x33FE, 1, 1),
+    (0x33FF, 0x33FF, 4, 0), (0x3400, 0x4DB5, 3, 0), (0x4DB6, 0x4DBF, 13, 0), (0x4DC0, 0x4DFF, 4, 0),
+    (0x4E00, 0x9FA5, 1, 1), (0x9FA6, 0x9FBB, 4, 1), (0x9FBC, 0x9FC3, 5, 1), (0x9FC4, 0x9FCB, 5, 2),
+    (0x9FCC, 0x9FCC, 6, 1), (0x9FCD, 0x9FD5, 8, 0), (0x9FD6, 0x9FEA, 10, 0),
+    (0x9FEB, 0x9FEF, 11, 0), (0x9FF0, 0x9FFC, 13, 0), (0x9FFD, 0x9FFF, 14, 0),
+    (0xA000, 0xA48C, 3, 0), (0xA490, 0xA4A1, 3, 0), (0xA4A2, 0xA4A3, 3, 2), (0xA4A4, 0xA4B3, 3, 0),
+    (0xA4B4, 0xA4B4, 3, 2), (0xA4B5, 0xA4C0, 3, 0), (0xA4C1, 0xA4C1, 3, 2), (0xA4C2, 0xA4C4, 3, 0),
+    (0xA4C5, 0xA4C5, 3, 2), (0xA4C6, 0xA4C6, 3, 0), (0xA4D0, 0xA4FF, 5, 2), (0xA500, 0xA62B, 5, 1),
+    (0xA640, 0xA65F, 5, 1), (0xA660, 0xA661, 6, 0), (0xA662, 0xA673, 5, 1), (0xA674, 0xA67B, 6, 1),
+    (0xA67C, 0xA697, 5, 1), (0xA698, 0xA69D, 7, 0), (0xA69E, 0xA69E, 8, 0), (0xA69F, 0xA69F, 6, 1),
+    (0xA6A0, 0xA6F7, 5, 2), (0xA700, 0xA716, 4, 1), (0xA717, 0xA71A, 5, 0), (0xA71B, 0xA71F, 5, 1),
+    (0xA720, 0xA721, 5, 0), (0xA722, 0xA78C, 5, 1), (0xA78D, 0xA78E, 6, 0), (0xA78F, 0xA78F, 8, 0),
+    (0xA790, 0xA791, 6, 0), (0xA792, 0xA793, 6, 1), (0xA794, 0xA79F, 7, 0), (0xA7A0, 0xA7A9, 6, 0),
+    (0xA7AA, 0xA7AA, 6, 1), (0xA7AB, 0xA7AD, 7, 0), (0xA7AE, 0xA7AE, 9, 0), (0xA7AF, 0xA7AF, 11, 0),
+    (0xA7B0, 0xA7B1, 7, 0), (0xA7B2, 0xA7B7, 8, 0), (0xA7B8, 0xA7B9, 11, 0),
+    (0xA7BA, 0xA7BF, 12, 0), (0xA7C0, 0xA7C1, 14, 0), (0xA7C2, 0xA7C6, 12, 0),
+    (0xA7C7, 0xA7CA, 13, 0), (0xA7CB, 0xA7CD, 16, 0), (0xA7D0, 0xA7D1, 14, 0),
+    (0xA7D3, 0xA7D3, 14, 0), (0xA7D5, 0xA7D9, 14, 0), (0xA7DA, 0xA7DC, 16, 0),
+    (0xA7F2, 0xA7F4, 14, 0), (0xA7F5, 0xA7F6, 13, 0), (0xA7F7, 0xA7F7, 7, 0),
+    (0xA7F8, 0xA7F9, 6, 1), (0xA7FA, 0xA7FA, 6, 0), (0xA7FB, 0xA7FF, 5, 1), (0xA800, 0xA82B, 4, 1),
+    (0xA82C, 0xA82C, 13, 0), (0xA830, 0xA839, 5, 2), (0xA840, 0xA877, 5, 0), (0xA880, 0xA8C4, 5, 1),
+    (0xA8C5, 0xA8C5, 9, 0), (0xA8CE, 0xA8D9, 5, 1), (0xA8E0, 0xA8FB, 5, 2), (0xA8FC, 0xA8FD, 8, 0),
+    (0xA8FE, 0xA8FF, 11, 0), (0xA900, 0xA953, 5, 1), (0xA95F, 0xA95F, 5, 1), (0xA960, 0xA97C, 5, 2),
+    (0xA980, 0xA9CD, 5, 2), (0xA9CF, 0xA9D9, 5, 2), (0xA9DE, 0xA9DF, 5, 2), (0xA9E0, 0xA9FE, 7, 0),
+    (0xAA00, 0xAA36, 5, 1), (0xAA40, 0xAA4D, 5, 1), (0xAA50, 0xAA59, 5, 1), (0xAA5C, 0xAA5F, 5, 1),
+    (0xAA60, 0xAA7B, 5, 2), (0xAA7C, 0xAA7F, 7, 0), (0xAA80, 0xAAC2, 5, 2), (0xAADB, 0xAADF, 5, 2),
+    (0xAAE0, 0xAAF6, 6, 1), (0xAB01, 0xAB06, 6, 0), (0xAB09, 0xAB0E, 6, 0), (0xAB11, 0xAB16, 6, 0),
+    (0xAB20, 0xAB26, 6, 0), (0xAB28, 0xAB2E, 6, 0), (0xAB30, 0xAB5F, 7, 0), (0xAB60, 0xAB63, 8, 0),
+    (0xAB64, 0xAB65, 7, 0), (0xAB66, 0xAB67, 12, 0), (0xAB68, 0xAB6B, 13, 0),
+    (0xAB70, 0xABBF, 8, 0), (0xABC0, 0xABED, 5, 2), (0xABF0, 0xABF9, 5, 2), (0xAC00, 0xD7A3, 2, 0),
+    (0xD7B0, 0xD7C6, 5, 2), (0xD7CB, 0xD7FB, 5, 2), (0xD800, 0xDFFF, 2, 0), (0xE000, 0xFA2D, 1, 1),
+    (0xFA2E, 0xFA2F, 6, 1), (0xFA30, 0xFA6A, 3, 2), (0xFA6B, 0xFA6D, 5, 2), (0xFA70, 0xFAD9, 4, 1),
+    (0xFB00, 0xFB06, 1, 1), (0xFB13, 0xFB17, 1, 1), (0xFB1D, 0xFB1D, 3, 0), (0xFB1E, 0xFB36, 1, 1),
+    (0xFB38, 0xFB3C, 1, 1), (0xFB3E, 0xFB3E, 1, 1), (0xFB40, 0xFB41, 1, 1), (0xFB43, 0xFB44, 1, 1),
+    (0xFB46, 0xFBB1, 1, 1), (0xFBB2, 0xFBC1, 6, 0), (0xFBC2, 0xFBC2, 14, 0), (0xFBD3, 0xFD3F, 1, 1),
+    (0xFD40, 0xFD4F, 14, 0), (0xFD50, 0xFD8F, 1, 1), (0xFD92, 0xFDC7, 1, 1),
+    (0xFDCF, 0xFDCF, 14, 0), (0xFDD0, 0xFDEF, 3, 1), (0xFDF0, 0xFDFB, 1, 1), (0xFDFC, 0xFDFC, 3, 2),
+    (0xFDFD, 0xFDFD, 4, 0), (0xFDFE, 0xFDFF, 14, 0), (0xFE00, 0xFE0F, 3, 2), (0xFE10, 0xFE19, 4, 1),
+    (0xFE20, 0xFE23, 1, 1), (0xFE24, 0xFE26, 5, 1), (0xFE27, 0xFE2D, 7, 0), (0xFE2E, 0xFE2F, 8, 0),
+    (0xFE30, 0xFE44, 1, 1), (0xFE45, 0xFE46, 3, 2), (0xFE47, 0xFE48, 4, 0), (0xFE49, 0xFE52, 1, 1),
+    (0xFE54, 0xFE66, 1, 1), (0xFE68, 0xFE6B, 1, 1), (0xFE70, 0xFE72, 1, 1), (0xFE73, 0xFE73, 3, 2),
+    (0xFE74, 0xFE74, 1, 1), (0xFE76, 0xFEFC, 1, 1), (0xFEFF, 0xFEFF, 1, 1), (0xFF01, 0xFF5E, 1, 1),
+    (0xFF5F, 0xFF60, 3, 2), (0xFF61, 0xFFBE, 1, 1), (0xFFC2, 0xFFC7, 1, 1), (0xFFCA, 0xFFCF, 1, 1),
+    (0xFFD2, 0xFFD7, 1, 1), (0xFFDA, 0xFFDC, 1, 1), (0xFFE0, 0xFFE6, 1, 1), (0xFFE8, 0xFFEE, 1, 1),
+    (0xFFF9, 0xFFFB, 3, 0), (0xFFFC, 0xFFFC, 2, 1), (0xFFFD, 0xFFFF, 1, 1),
+    (0x10000, 0x1000B, 4, 0), (0x1000D, 0x10026, 4, 0), (0x10028, 0x1003A, 4, 0),
+    (0x1003C, 0x1003D, 4, 0), (0x1003F, 0x1004D, 4, 0), (0x10050, 0x1005D, 4, 0),
+    (0x10080, 0x100FA, 4, 0), (0x10100, 0x10102, 4, 0), (0x10107, 0x10133, 4, 0),
+    (0x10137, 0x1013F, 4, 0), (0x10140, 0x1018A, 4, 1), (0x1018B, 0x1018C, 7, 0),
+    (0x1018D, 0x1018E, 9, 0), (0x10190, 0x1019B, 5, 1), (0x1019C, 0x1019C, 13, 0),
+    (0x101A0, 0x101A0, 7, 0), (0x101D0, 0x101FD, 5, 1), (0x10280, 0x1029C, 5, 1),
+    (0x102A0, 0x102D0, 5, 1), (0x102E0, 0x102FB, 7, 0), (0x10300, 0x1031E, 3, 1),
+    (0x1031F, 0x1031F, 7, 0), (0x10320, 0x10323, 3, 1), (0x1032D, 0x1032F, 10, 0),
+    (0x10330, 0x1034A, 3, 1), (0x10350, 0x1037A, 7, 0), (0x10380, 0x1039D, 4, 0),
+    (0x1039F, 0x1039F, 4, 0), (0x103A0, 0x103C3, 4, 1), (0x103C8, 0x103D5, 4, 1),
+    (0x10400, 0x10425, 3, 1), (0x10426, 0x10427, 4, 0), (0x10428, 0x1044D, 3, 1),
+    (0x1044E, 0x1049D, 4, 0), (0x104A0, 0x104A9, 4, 0), (0x104B0, 0x104D3, 9, 0),
+    (0x104D8, 0x104FB, 9, 0), (0x10500, 0x10527, 7, 0), (0x10530, 0x10563, 7, 0),
+    (0x1056F, 0x1056F, 7, 0), (0x10570, 0x1057A, 14, 0), (0x1057C, 0x1058A, 14, 0),
+    (0x1058C, 0x10592, 14, 0), (0x10594, 0x10595, 14, 0), (0x10597, 0x105A1, 14, 0),
+    (0x105A3, 0x105B1, 14, 0), (0x105B3, 0x105B9, 14, 0), (0x105BB, 0x105BC, 14, 0),
+    (0x105C0, 0x105F3, 16, 0), (0x10600, 0x10736, 7, 0), (0x10740, 0x10755, 7, 0),
+    (0x10760, 0x10767, 7, 0), (0x10780, 0x10785, 14, 0), (0x10787, 0x107B0, 14, 0),
+    (0x107B2, 0x107BA, 14, 0), (0x10800, 0x10805, 4, 0), (0x10808, 0x10808, 4, 0),
+    (0x1080A, 0x10835, 4, 0), (0x10837, 0x10838, 4, 0), (0x1083C, 0x1083C, 4, 0),
+    (0x1083F, 0x1083F, 4, 0), (0x10840, 0x10855, 5, 2), (0x10857, 0x1085F, 5, 2),
+    (0x10860, 0x1089E, 7, 0), (0x108A7, 0x108AF, 7, 0), (0x108E0, 0x108F2, 8, 0),
+    (0x108F4, 0x108F5, 8, 0), (0x108FB, 0x108FF, 8, 0), (0x10900, 0x10919, 5, 0),
+    (0x1091A, 0x1091B, 5, 2), (0x1091F, 0x1091F, 5, 0), (0x10920, 0x10939, 5, 1),
+    (0x1093F, 0x1093F, 5, 1), (0x10980, 0x109B7, 6, 1), (0x109BC, 0x109BD, 8, 0),
+    (0x109BE, 0x109BF, 6, 1), (0x109C0, 0x109CF, 8, 0), (0x109D2, 0x109FF, 8, 0),
+    (0x10A00, 0x10A03, 4, 1), (0x10A05, 0x10A06, 4, 1), (0x10A0C, 0x10A13, 4, 1),
+    (0x10A15, 0x10A17, 4, 1), (0x10A19, 0x10A33, 4, 1), (0x10A34, 0x10A35, 11, 0),
+    (0x10A38, 0x10A3A, 4, 1), (0x10A3F, 0x10A47, 4, 1), (0x10A48, 0x10A48, 11, 0),
+    (0x10A50, 0x10A58, 4, 1), (0x10A60, 0x10A7F, 5, 2), (0x10A80, 0x10A9F, 7, 0),
+    (0x10AC0, 0x10AE6, 7, 0), (0x10AEB, 0x10AF6, 7, 0), (0x10B00, 0x10B35, 5, 2),
+    (0x10B39, 0x10B55, 5, 2), (0x10B58, 0x10B72, 5, 2), (0x10B78, 0x10B7F, 5, 2),
+    (0x10B80, 0x10B91, 7, 0), (0x10B99, 0x10B9C, 7, 0), (0x10BA9, 0x10BAF, 7, 0),
+    (0x10C00, 0x10C48, 5, 2), (0x10C80, 0x10CB2, 8, 0), (0x10CC0, 0x10CF2, 8, 0),
+    (0x10CFA, 0x10CFF, 8, 0), (0x10D00, 0x10D27, 11, 0), (0x10D30, 0x10D39, 11, 0),
+    (0x10D40, 0x10D65, 16, 0), (0x10D69, 0x10D85, 16, 0), (0x10D8E, 0x10D8F, 16, 0),
+    (0x10E60, 0x10E7E, 5, 2), (0x10E80, 0x10EA9, 13, 0), (0x10EAB, 0x10EAD, 13, 0),
+    (0x10EB0, 0x10EB1, 13, 0), (0x10EC2, 0x10EC4, 16, 0), (0x10EFC, 0x10EFC, 16, 0),
+    (0x10EFD, 0x10EFF, 15, 0), (0x10F00, 0x10F27, 11, 0), (0x10F30, 0x10F59, 11, 0),
+    (0x10F70, 0x10F89, 14, 0), (0x10FB0, 0x10FCB, 13, 0), (0x10FE0, 0x10FF6, 12, 0),
+    (0x11000, 0x1104D, 6, 0), (0x11052, 0x1106F, 6, 0), (0x11070, 0x11075, 14, 0),
+    (0x1107F, 0x1107F, 7, 0), (0x11080, 0x110C1, 5, 2), (0x110C2, 0x110C2, 14, 0),
+    (0x110CD, 0x110CD, 11, 0), (0x110D0, 0x110E8, 6, 1), (0x110F0, 0x110F9, 6, 1),
+    (0x11100, 0x11134, 6, 1), (0x11136, 0x11143, 6, 1), (0x11144, 0x11146, 11, 0),
+    (0x11147, 0x11147, 13, 0), (0x11150, 0x11176, 7, 0), (0x11180, 0x111C8, 6, 1),
+    (0x111C9, 0x111CC, 8, 0), (0x111CD, 0x111CD, 7, 0), (0x111CE, 0x111CF, 13, 0),
+    (0x111D0, 0x111D9, 6, 1), (0x111DA, 0x111DA, 7, 0), (0x111DB, 0x111DF, 8, 0),
+    (0x111E1, 0x111F4, 7, 0), (0x11200, 0x11211, 7, 0), (0x11213, 0x1123D, 7, 0),
+    (0x1123E, 0x1123E, 9, 0), (0x1123F, 0x11241, 15, 0), (0x11280, 0x11286, 8, 0),
+    (0x11288, 0x11288, 8, 0), (0x1128A, 0x1128D, 8, 0), (0x1128F, 0x1129D, 8, 0),
+    (0x1129F, 0x112A9, 8, 0), (0x112B0, 0x112EA, 7, 0), (0x112F0, 0x112F9, 7, 0),
+    (0x11300, 0x11300, 8, 0), (0x11301, 0x11303, 7, 0), (0x11305, 0x1130C, 7, 0),
+    (0x1130F, 0x11310, 7, 0), (0x11313, 0x11328, 7, 0), (0x1132A, 0x11330, 7, 0),
+    (0x11332, 0x11333, 7, 0), (0x11335, 0x11339, 7, 0), (0x1133B, 0x1133B, 11, 0),
+    (0x1133C, 0x11344, 7, 0), (0x11347, 0x11348, 7, 0), (0x1134B, 0x1134D, 7, 0),
+    (0x11350, 0x11350, 8, 0), (0x11357, 0x11357, 7, 0), (0x1135D, 0x11363, 7, 0),
+    (0x11366, 0x1136C, 7, 0), (0x11370, 0x11374, 7, 0), (0x11380, 0x11389, 16, 0),
+    (0x1138B, 0x1138B, 16, 0), (0x1138E, 0x1138E, 16, 0), (0x11390, 0x113B5, 16, 0),
+    (0x113B7, 0x113C0, 16, 0), (0x113C2, 0x113C2, 16, 0), (0x113C5, 0x113C5, 16, 0),
+    (0x113C7, 0x113CA, 16, 0), (0x113CC, 0x113D5, 16, 0), (0x113D7, 0x113D8, 16, 0),
+    (0x113E1, 0x113E2, 16, 0), (0x11400, 0x11459, 9, 0), (0x1145A, 0x1145A, 13, 0),
+    (0x1145B, 0x1145B, 9, 0), (0x1145D, 0x1145D, 9, 0), (0x1145E, 0x1145E, 11, 0),
+    (0x1145F, 0x1145F, 12, 0), (0x11460, 0x11461, 13, 0), (0x11480, 0x114C7, 7, 0),
+    (0x114D0, 0x114D9, 7, 0), (0x11580, 0x115B5, 7, 0), (0x115B8, 0x115C9, 7, 0),
+    (0x115CA, 0x115DD, 8, 0), (0x11600, 0x11644, 7, 0), (0x11650, 0x11659, 7, 0),
+    (0x11660, 0x1166C, 9, 0), (0x11680, 0x116B7, 6, 1), (0x116B8, 0x116B8, 12, 0),
+    (0x116B9, 0x116B9, 14, 0), (0x116C0, 0x116C9, 6, 1), (0x116D0, 0x116E3, 16, 0),
+    (0x11700, 0x11719, 8, 0), (0x1171A, 0x1171A, 11, 0), (0x1171D, 0x1172B, 8, 0),
+    (0x11730, 0x1173F, 8, 0), (0x11740, 0x11746, 14, 0), (0x11800, 0x1183B, 11, 0),
+    (0x118A0, 0x118F2, 7, 0), (0x118FF, 0x118FF, 7, 0), (0x11900, 0x11906, 13, 0),
+    (0x11909, 0x11909, 13, 0), (0x1190C, 0x11913, 13, 0), (0x11915, 0x11916, 13, 0),
+    (0x11918, 0x11935, 13, 0), (0x11937, 0x11938, 13, 0), (0x1193B, 0x11946, 13, 0),
+    (0x11950, 0x11959, 13, 0), (0x119A0, 0x119A7, 12, 0), (0x119AA, 0x119D7, 12, 0),
+    (0x119DA, 0x119E4, 12, 0), (0x11A00, 0x11A47, 10, 0), (0x11A50, 0x11A83, 10, 0),
+    (0x11A84, 0x11A85, 12, 0), (0x11A86, 0x11A9C, 10, 0), (0x11A9D, 0x11A9D, 11, 0),
+    (0x11A9E, 0x11AA2, 10, 0), (0x11AB0, 0x11ABF, 14, 0), (0x11AC0, 0x11AF8, 7, 0),
+    (0x11B00, 0x11B09, 15, 0), (0x11BC0, 0x11BE1, 16, 0), (0x11BF0, 0x11BF9, 16, 0),
+    (0x11C00, 0x11C08, 9, 0), (0x11C0A, 0x11C36, 9, 0), (0x11C38, 0x11C45, 9, 0),
+    (0x11C50, 0x11C6C, 9, 0), (0x11C70, 0x11C8F, 9, 0), (0x11C92, 0x11CA7, 9, 0),
+    (0x11CA9, 0x11CB6, 9, 0), (0x11D00, 0x11D06, 10, 0), (0x11D08, 0x11D09, 10, 0),
+    (0x11D0B, 0x11D36, 10, 0), (0x11D3A, 0x11D3A, 10, 0), (0x11D3C, 0x11D3D, 10, 0),
+    (0x11D3F, 0x11D47, 10, 0), (0x11D50, 0x11D59, 10, 0), (0x11D60, 0x11D65, 11, 0),
+    (0x11D67, 0x11D68, 11, 0), (0x11D6A, 0x11D8E, 11, 0), (0x11D90, 0x11D91, 11, 0),
+    (0x11D93, 0x11D98, 11, 0), (0x11DA0, 0x11DA9, 11, 0), (0x11EE0, 0x11EF8, 11, 0),
+    (0x11F00, 0x11F10, 15, 0), (0x11F12, 0x11F3A, 15, 0), (0x11F3E, 0x11F59, 15, 0),
+    (0x11F5A, 0x11F5A, 16, 0), (0x11FB0, 0x11FB0, 13, 0), (0x11FC0, 0x11FF1, 12, 0),
+    (0x11FFF, 0x11FFF, 12, 0), (0x12000, 0x1236E, 5, 0), (0x1236F, 0x12398, 7, 0),
+    (0x12399, 0x12399, 8, 0), (0x12400, 0x12462, 5, 0), (0x12463, 0x1246E, 7, 0),
+    (0x12470, 0x12473, 5, 0), (0x12474, 0x12474, 7, 0), (0x12480, 0x12543, 8, 0),
+    (0x12F90, 0x12FF2, 14, 0), (0x13000, 0x1342E, 5, 2), (0x1342F, 0x1342F, 15, 0),
+    (0x13430, 0x13438, 12, 0), (0x13439, 0x13455, 15, 0), (0x13460, 0x143FA, 16, 0),
+    (0x14400, 0x14646, 8, 0), (0x16100, 0x16139, 16, 0), (0x16800, 0x16A38, 6, 0),
+    (0x16A40, 0x16A5E, 7, 0), (0x16A60, 0x16A69, 7, 0), (0x16A6E, 0x16A6F, 7, 0),
+    (0x16A70, 0x16ABE, 14, 0), (0x16AC0, 0x16AC9, 14, 0), (0x16AD0, 0x16AED, 7, 0),
+    (0x16AF0, 0x16AF5, 7, 0), (0x16B00, 0x16B45, 7, 0), (0x16B50, 0x16B59, 7, 0),
+    (0x16B5B, 0x16B61, 7, 0), (0x16B63, 0x16B77, 7, 0), (0x16B7D, 0x16B8F, 7, 0),
+    (0x16D40, 0x16D79, 16, 0), (0x16E40, 0x16E9A, 11, 0), (0x16F00, 0x16F44, 6, 1),
+    (0x16F45, 0x16F4A, 12, 0), (0x16F4F, 0x16F4F, 12, 0), (0x16F50, 0x16F7E, 6, 1),
+    (0x16F7F, 0x16F87, 12, 0), (0x16F8F, 0x16F9F, 6, 1), (0x16FE0, 0x16FE0, 9, 0),
+    (0x16FE1, 0x16FE1, 10, 0), (0x16FE2, 0x16FE3, 12, 0), (0x16FE4, 0x16FE4, 13, 0),
+    (0x16FF0, 0x16FF1, 13, 0), (0x17000, 0x187EC, 9, 0), (0x187ED, 0x187F1, 11, 0),
+    (0x187F2, 0x187F7, 12, 0), (0x18800, 0x18AF2, 9, 0), (0x18AF3, 0x18CD5, 13, 0),
+    (0x18CFF, 0x18CFF, 16, 0), (0x18D00, 0x18D08, 13, 0), (0x1AFF0, 0x1AFF3, 14, 0),
+    (0x1AFF5, 0x1AFFB, 14, 0), (0x1AFFD, 0x1AFFE, 14, 0), (0x1B000, 0x1B001, 6, 0),
+    (0x1B002, 0x1B11E, 10, 0), (0x1B11F, 0x1B122, 14, 0), (0x1B132, 0x1B132, 15, 0),
+    (0x1B150, 0x1B152, 12, 0), (0x1B155, 0x1B155, 15, 0), (0x1B164, 0x1B167, 12, 0),
+    (0x1B170, 0x1B2FB, 10, 0), (0x1BC00, 0x1BC6A, 7, 0), (0x1BC70, 0x1BC7C, 7, 0),
+    (0x1BC80, 0x1BC88, 7, 0), (0x1BC90, 0x1BC99, 7, 0), (0x1BC9C, 0x1BCA3, 7, 0),
+    (0x1CC00, 0x1CCF9, 16, 0), (0x1CD00, 0x1CEB3, 16, 0), (0x1CF00, 0x1CF2D, 14, 0),
+    (0x1CF30, 0x1CF46, 14, 0), (0x1CF50, 0x1CFC3, 14, 0), (0x1D000, 0x1D0F5, 3, 1),
+    (0x1D100, 0x1D126, 3, 1), (0x1D129, 0x1D129, 5, 1), (0x1D12A, 0x1D1DD, 3, 1),
+    (0x1D1DE, 0x1D1E8, 8, 0), (0x1D1E9, 0x1D1EA, 14, 0), (0x1D200, 0x1D245, 4, 1),
+    (0x1D2C0, 0x1D2D3, 15, 0), (0x1D2E0, 0x1D2F3, 11, 0), (0x1D300, 0x1D356, 4, 0),
+    (0x1D360, 0x1D371, 5, 0), (0x1D372, 0x1D378, 11, 0), (0x1D400, 0x1D454, 3, 1),
+    (0x1D456, 0x1D49C, 3, 1), (0x1D49E, 0x1D49F, 3, 1), (0x1D4A2, 0x1D4A2, 3, 1),
+    (0x1D4A5, 0x1D4A6, 3, 1), (0x1D4A9, 0x1D4AC, 3, 1), (0x1D4AE, 0x1D4B9, 3, 1),
+    (0x1D4BB, 0x1D4BB, 3, 1), (0x1D4BD, 0x1D4C0, 3, 1), (0x1D4C1, 0x1D4C1, 4, 0),
+    (0x1D4C2, 0x1D4C3, 3, 1), (0x1D4C5, 0x1D505, 3, 1), (0x1D507, 0x1D50A, 3, 1),
+    (0x1D50D, 0x1D514, 3, 1), (0x1D516, 0x1D51C, 3, 1), (0x1D51E, 0x1D539, 3, 1),
+    (0x1D53B, 0x1D53E, 3, 1), (0x1D540, 0x1D544, 3, 1), (0x1D546, 0x1D546, 3, 1),
+    (0x1D54A, 0x1D550, 3, 1), (0x1D552, 0x1D6A3, 3, 1), (0x1D6A4, 0x1D6A5, 4, 1),
+    (0x1D6A8, 0x1D7C9, 3, 1), (0x1D7CA, 0x1D7CB, 5, 0), (0x1D7CE, 0x1D7FF, 3, 1),
+    (0x1D800, 0x1DA8B, 8, 0), (0x1DA9B, 0x1DA9F, 8, 0), (0x1DAA1, 0x1DAAF, 8, 0),
+    (0x1DF00, 0x1DF1E, 14, 0), (0x1DF25, 0x1DF2A, 15, 0), (0x1E000, 0x1E006, 9, 0),
+    (0x1E008, 0x1E018, 9, 0), (0x1E01B, 0x1E021, 9, 0), (0x1E023, 0x1E024, 9, 0),
+    (0x1E026, 0x1E02A, 9, 0), (0x1E030, 0x1E06D, 15, 0), (0x1E08F, 0x1E08F, 15, 0),
+    (0x1E100, 0x1E12C, 12, 0), (0x1E130, 0x1E13D, 12, 0), (0x1E140, 0x1E149, 12, 0),
+    (0x1E14E, 0x1E14F, 12, 0), (0x1E290, 0x1E2AE, 14, 0), (0x1E2C0, 0x1E2F9, 12, 0),
+    (0x1E2FF, 0x1E2FF, 12, 0), (0x1E4D0, 0x1E4F9, 15, 0), (0x1E5D0, 0x1E5FA, 16, 0),
+    (0x1E5FF, 0x1E5FF, 16, 0), (0x1E7E0, 0x1E7E6, 14, 0), (0x1E7E8, 0x1E7EB, 14, 0),
+    (0x1E7ED, 0x1E7EE, 14, 0), (0x1E7F0, 0x1E7FE, 14, 0), (0x1E800, 0x1E8C4, 7, 0),
+    (0x1E8C7, 0x1E8D6, 7, 0), (0x1E900, 0x1E94A, 9, 0), (0x1E94B, 0x1E94B, 12, 0),
+    (0x1E950, 0x1E959, 9, 0), (0x1E95E, 0x1E95F, 9, 0), (0x1EC71, 0x1ECB4, 11, 0),
+    (0x1ED01, 0x1ED3D, 12, 0), (0x1EE00, 0x1EE03, 6, 1), (0x1EE05, 0x1EE1F, 6, 1),
+    (0x1EE21, 0x1EE22, 6, 1), (0x1EE24, 0x1EE24, 6, 1), (0x1EE27, 0x1EE27, 6, 1),
+    (0x1EE29, 0x1EE32, 6, 1), (0x1EE34, 0x1EE37, 6, 1), (0x1EE39, 0x1EE39, 6, 1),
+    (0x1EE3B, 0x1EE3B, 6, 1), (0x1EE42, 0x1EE42, 6, 1), (0x1EE47, 0x1EE47, 6, 1),
+    (0x1EE49, 0x1EE49, 6, 1), (0x1EE4B, 0x1EE4B, 6, 1), (0x1EE4D, 0x1EE4F, 6, 1),
+    (0x1EE51, 0x1EE52, 6, 1), (0x1EE54, 0x1EE54, 6, 1), (0x1EE57, 0x1EE57, 6, 1),
+    (0x1EE59, 0x1EE59, 6, 1), (0x1EE5B, 0x1EE5B, 6, 1), (0x1EE5D, 0x1EE5D, 6, 1),
+    (0x1EE5F, 0x1EE5F, 6, 1), (0x1EE61, 0x1EE62, 6, 1), (0x1EE64, 0x1EE64, 6, 1),
+    (0x1EE67, 0x1EE6A, 6, 1), (0x1EE6C, 0x1EE72, 6, 1), (0x1EE74, 0x1EE77, 6, 1),
+    (0x1EE79, 0x1EE7C, 6, 1), (0x1EE7E, 0x1EE7E, 6, 1), (0x1EE80, 0x1EE89, 6, 1),
+    (0x1EE8B, 0x1EE9B, 6, 1), (0x1EEA1, 0x1EEA3, 6, 1), (0x1EEA5, 0x1EEA9, 6, 1),
+    (0x1EEAB, 0x1EEBB, 6, 1), (0x1EEF0, 0x1EEF1, 6, 1), (0x1F000, 0x1F02B, 5, 1),
+    (0x1F030, 0x1F093, 5, 1), (0x1F0A0, 0x1F0AE, 6, 0), (0x1F0B1, 0x1F0BE, 6, 0),
+    (0x1F0BF, 0x1F0BF, 7, 0), (0x1F0C1, 0x1F0CF, 6, 0), (0x1F0D1, 0x1F0DF, 6, 0),
+    (0x1F0E0, 0x1F0F5, 7, 0), (0x1F100, 0x1F10A, 5, 2), (0x1F10B, 0x1F10C, 7, 0),
+    (0x1F10D, 0x1F10F, 13, 0), (0x1F110, 0x1F12E, 5, 2), (0x1F12F, 0x1F12F, 11, 0),
+    (0x1F130, 0x1F130, 6, 0), (0x1F131, 0x1F131, 5, 2), (0x1F132, 0x1F13C, 6, 0),
+    (0x1F13D, 0x1F13D, 5, 2), (0x1F13E, 0x1F13E, 6, 0), (0x1F13F, 0x1F13F, 5, 2),
+    (0x1F140, 0x1F141, 6, 0), (0x1F142, 0x1F142, 5, 2), (0x1F143, 0x1F145, 6, 0),
+    (0x1F146, 0x1F146, 5, 2), (0x1F147, 0x1F149, 6, 0), (0x1F14A, 0x1F14E, 5, 2),
+    (0x1F14F, 0x1F156, 6, 0), (0x1F157, 0x1F157, 5, 2), (0x1F158, 0x1F15E, 6, 0),
+    (0x1F15F, 0x1F15F, 5, 2), (0x1F160, 0x1F169, 6, 0), (0x1F16A, 0x1F16B, 6, 1),
+    (0x1F16C, 0x1F16C, 12, 0), (0x1F16D, 0x1F16F, 13, 0), (0x1F170, 0x1F178, 6, 0),
+    (0x1F179, 0x1F179, 5, 2), (0x1F17A, 0x1F17A, 6, 0), (0x1F17B, 0x1F17C, 5, 2),
+    (0x1F17D, 0x1F17E, 6, 0), (0x1F17F, 0x1F17F, 5, 2), (0x1F180, 0x1F189, 6, 0),
+    (0x1F18A, 0x1F18D, 5, 2), (0x1F18E, 0x1F18F, 6, 0), (0x1F190, 0x1F190, 5, 2),
+    (0x1F191, 0x1F19A, 6, 0), (0x1F19B, 0x1F1AC, 9, 0), (0x1F1AD, 0x1F1AD, 13, 0),
+    (0x1F1E6, 0x1F1FF, 6, 0), (0x1F200, 0x1F200, 5, 2), (0x1F201, 0x1F202, 6, 0),
+    (0x1F210, 0x1F231, 5, 2), (0x1F232, 0x1F23A, 6, 0), (0x1F23B, 0x1F23B, 9, 0),
+    (0x1F240, 0x1F248, 5, 2), (0x1F250, 0x1F251, 6, 0), (0x1F260, 0x1F265, 10, 0),
+    (0x1F300, 0x1F320, 6, 0), (0x1F321, 0x1F32C, 7, 0), (0x1F32D, 0x1F32F, 8, 0),
+    (0x1F330, 0x1F335, 6, 0), (0x1F336, 0x1F336, 7, 0), (0x1F337, 0x1F37C, 6, 0),
+    (0x1F37D, 0x1F37D, 7, 0), (0x1F37E, 0x1F37F, 8, 0), (0x1F380, 0x1F393, 6, 0),
+    (0x1F394, 0x1F39F, 7, 0), (0x1F3A0, 0x1F3C4, 6, 0), (0x1F3C5, 0x1F3C5, 7, 0),
+    (0x1F3C6, 0x1F3CA, 6, 0), (0x1F3CB, 0x1F3CE, 7, 0), (0x1F3CF, 0x1F3D3, 8, 0),
+    (0x1F3D4, 0x1F3DF, 7, 0), (0x1F3E0, 0x1F3F0, 6, 0), (0x1F3F1, 0x1F3F7, 7, 0),
+    (0x1F3F8, 0x1F3FF, 8, 0), (0x1F400, 0x1F43E, 6, 0), (0x1F43F, 0x1F43F, 7, 0),
+    (0x1F440, 0x1F440, 6, 0), (0x1F441, 0x1F441, 7, 0), (0x1F442, 0x1F4F7, 6, 0),
+    (0x1F4F8, 0x1F4F8, 7, 0), (0x1F4F9, 0x1F4FC, 6, 0), (0x1F4FD, 0x1F4FE, 7, 0),
+    (0x1F4FF, 0x1F4FF, 8, 0), (0x1F500, 0x1F53D, 6, 0), (0x1F53E, 0x1F53F, 7, 0),
+    (0x1F540, 0x1F543, 6, 1), (0x1F544, 0x1F54A, 7, 0), (0x1F54B, 0x1F54F, 8, 0),
+    (0x1F550, 0x1F567, 6, 0), (0x1F568, 0x1F579, 7, 0), (0x1F57A, 0x1F57A, 9, 0),
+    (0x1F57B, 0x1F5A3, 7, 0), (0x1F5A4, 0x1F5A4, 9, 0), (0x1F5A5, 0x1F5FA, 7, 0),
+    (0x1F5FB, 0x1F5FF, 6, 0), (0x1F600, 0x1F600, 6, 1), (0x1F601, 0x1F610, 6, 0),
+    (0x1F611, 0x1F611, 6, 1), (0x1F612, 0x1F614, 6, 0), (0x1F615, 0x1F615, 6, 1),
+    (0x1F616, 0x1F616, 6, 0), (0x1F617, 0x1F617, 6, 1), (0x1F618, 0x1F618, 6, 0),
+    (0x1F619, 0x1F619, 6, 1), (0x1F61A, 0x1F61A, 6, 0), (0x1F61B, 0x1F61B, 6, 1),
+    (0x1F61C, 0x1F61E, 6, 0), (0x1F61F, 0x1F61F, 6, 1), (0x1F620, 0x1F625, 6, 0),
+    (0x1F626, 0x1F627, 6, 1), (0x1F628, 0x1F62B, 6, 0), (0x1F62C, 0x1F62C, 6, 1),
+    (0x1F62D, 0x1F62D, 6, 0), (0x1F62E, 0x1F62F, 6, 1), (0x1F630, 0x1F633, 6, 0),
+    (0x1F634, 0x1F634, 6, 1), (0x1F635, 0x1F640, 6, 0), (0x1F641, 0x1F642, 7, 0),
+    (0x1F643, 0x1F644, 8, 0), (0x1F645, 0x1F64F, 6, 0), (0x1F650, 0x1F67F, 7, 0),
+    (0x1F680, 0x1F6C5, 6, 0), (0x1F6C6, 0x1F6CF, 7, 0), (0x1F6D0, 0x1F6D0, 8, 0),
+    (0x1F6D1, 0x1F6D2, 9, 0), (0x1F6D3, 0x1F6D4, 10, 0), (0x1F6D5, 0x1F6D5, 12, 0),
+    (0x1F6D6, 0x1F6D7, 13, 0), (0x1F6DC, 0x1F6DC, 15, 0), (0x1F6DD, 0x1F6DF, 14, 0),
+    (0x1F6E0, 0x1F6EC, 7, 0), (0x1F6F0, 0x1F6F3, 7, 0), (0x1F6F4, 0x1F6F6, 9, 0),
+    (0x1F6F7, 0x1F6F8, 10, 0), (0x1F6F9, 0x1F6F9, 11, 0), (0x1F6FA, 0x1F6FA, 12, 0),
+    (0x1F6FB, 0x1F6FC, 13, 0), (0x1F700, 0x1F773, 6, 0), (0x1F774, 0x1F776, 15, 0),
+    (0x1F77B, 0x1F77F, 15, 0), (0x1F780, 0x1F7D4, 7, 0), (0x1F7D5, 0x1F7D8, 11, 0),
+    (0x1F7D9, 0x1F7D9, 15, 0), (0x1F7E0, 0x1F7EB, 12, 0), (0x1F7F0, 0x1F7F0, 14, 0),
+    (0x1F800, 0x1F80B, 7, 0), (0x1F810, 0x1F847, 7, 0), (0x1F850, 0x1F859, 7, 0),
+    (0x1F860, 0x1F887, 7, 0), (0x1F890, 0x1F8AD, 7, 0), (0x1F8B0, 0x1F8B1, 13, 0),
+    (0x1F8B2, 0x1F8BB, 16, 0), (0x1F8C0, 0x1F8C1, 16, 0), (0x1F900, 0x1F90B, 10, 0),
+    (0x1F90C, 0x1F90C, 13, 0), (0x1F90D, 0x1F90F, 12, 0), (0x1F910, 0x1F918, 8, 0),
+    (0x1F919, 0x1F91E, 9, 0), (0x1F91F, 0x1F91F, 10, 0), (0x1F920, 0x1F927, 9, 0),
+    (0x1F928, 0x1F92F, 10, 0), (0x1F930, 0x1F930, 9, 0), (0x1F931, 0x1F932, 10, 0),
+    (0x1F933, 0x1F93E, 9, 0), (0x1F93F, 0x1F93F, 12, 0), (0x1F940, 0x1F94B, 9, 0),
+    (0x1F94C, 0x1F94C, 10, 0), (0x1F94D, 0x1F94F, 11, 0), (0x1F950, 0x1F95E, 9, 0),
+    (0x1F95F, 0x1F96B, 10, 0), (0x1F96C, 0x1F970, 11, 0), (0x1F971, 0x1F971, 12, 0),
+    (0x1F972, 0x1F972, 13, 0), (0x1F973, 0x1F976, 11, 0), (0x1F977, 0x1F978, 13, 0),
+    (0x1F979, 0x1F979, 14, 0), (0x1F97A, 0x1F97A, 11, 0), (0x1F97B, 0x1F97B, 12, 0),
+    (0x1F97C, 0x1F97F, 11, 0), (0x1F980, 0x1F984, 8, 0), (0x1F985, 0x1F991, 9, 0),
+    (0x1F992, 0x1F997, 10, 0), (0x1F998, 0x1F9A2, 11, 0), (0x1F9A3, 0x1F9A4, 13, 0),
+    (0x1F9A5, 0x1F9AA, 12, 0), (0x1F9AB, 0x1F9AD, 13, 0), (0x1F9AE, 0x1F9AF, 12, 0),
+    (0x1F9B0, 0x1F9B9, 11, 0), (0x1F9BA, 0x1F9BF, 12, 0), (0x1F9C0, 0x1F9C0, 8, 0),
+    (0x1F9C1, 0x1F9C2, 11, 0), (0x1F9C3, 0x1F9CA, 12, 0), (0x1F9CB, 0x1F9CB, 13, 0),
+    (0x1F9CC, 0x1F9CC, 14, 0), (0x1F9CD, 0x1F9CF, 12, 0), (0x1F9D0, 0x1F9E6, 10, 0),
+    (0x1F9E7, 0x1F9FF, 11, 0), (0x1FA00, 0x1FA53, 12, 0), (0x1FA60, 0x1FA6D, 11, 0),
+    (0x1FA70, 0x1FA73, 12, 0), (0x1FA74, 0x1FA74, 13, 0), (0x1FA75, 0x1FA77, 15, 0),
+    (0x1FA78, 0x1FA7A, 12, 0), (0x1FA7B, 0x1FA7C, 14, 0), (0x1FA80, 0x1FA82, 12, 0),
+    (0x1FA83, 0x1FA86, 13, 0), (0x1FA87, 0x1FA88, 15, 0), (0x1FA89, 0x1FA89, 16, 0),
+    (0x1FA8F, 0x1FA8F, 16, 0), (0x1FA90, 0x1FA95, 12, 0), (0x1FA96, 0x1FAA8, 13, 0),
+    (0x1FAA9, 0x1FAAC, 14, 0), (0x1FAAD, 0x1FAAF, 15, 0), (0x1FAB0, 0x1FAB6, 13, 0),
+    (0x1FAB7, 0x1FABA, 14, 0), (0x1FABB, 0x1FABD, 15, 0), (0x1FABE, 0x1FABE, 16, 0),
+    (0x1FABF, 0x1FABF, 15, 0), (0x1FAC0, 0x1FAC2, 13, 0), (0x1FAC3, 0x1FAC5, 14, 0),
+    (0x1FAC6, 0x1FAC6, 16, 0), (0x1FACE, 0x1FACF, 15, 0), (0x1FAD0, 0x1FAD6, 13, 0),
+    (0x1FAD7, 0x1FAD9, 14, 0), (0x1FADA, 0x1FADB, 15, 0), (0x1FADC, 0x1FADC, 16, 0),
+    (0x1FADF, 0x1FADF, 16, 0), (0x1FAE0, 0x1FAE7, 14, 0), (0x1FAE8, 0x1FAE8, 15, 0),
+    (0x1FAE9, 0x1FAE9, 16, 0), (0x1FAF0, 0x1FAF6, 14, 0), (0x1FAF7, 0x1FAF8, 15, 0),
+    (0x1FB00, 0x1FB92, 13, 0), (0x1FB94, 0x1FBCA, 13, 0), (0x1FBCB, 0x1FBEF, 16, 0),
+    (0x1FBF0, 0x1FBF9, 13, 0), (0x1FFFE, 0x1FFFF, 2, 0), (0x20000, 0x2A6D6, 3, 1),
+    (0x2A6D7, 0x2A6DD, 13, 0), (0x2A6DE, 0x2A6DF, 14, 0), (0x2A700, 0x2B734, 5, 2),
+    (0x2B735, 0x2B738, 14, 0), (0x2B739, 0x2B739, 15, 0), (0x2B740, 0x2B81D, 6, 0),
+    (0x2B820, 0x2CEA1, 8, 0), (0x2CEB0, 0x2EBE0, 10, 0), (0x2EBF0, 0x2EE5D, 15, 1),
+    (0x2F800, 0x2FA1D, 3, 1), (0x2FFFE, 0x2FFFF, 2, 0), (0x30000, 0x3134A, 13, 0),
+    (0x31350, 0x323AF, 15, 0), (0x3FFFE, 0x3FFFF, 2, 0), (0x4FFFE, 0x4FFFF, 2, 0),
+    (0x5FFFE, 0x5FFFF, 2, 0), (0x6FFFE, 0x6FFFF, 2, 0), (0x7FFFE, 0x7FFFF, 2, 0),
+    (0x8FFFE, 0x8FFFF, 2, 0), (0x9FFFE, 0x9FFFF, 2, 0), (0xAFFFE, 0xAFFFF, 2, 0),
+    (0xBFFFE, 0xBFFFF, 2, 0), (0xCFFFE, 0xCFFFF, 2, 0), (0xDFFFE, 0xDFFFF, 2, 0),
+    (0xE0001, 0xE0001, 3, 1), (0xE0020, 0xE007F, 3, 1), (0xE0100, 0xE01EF, 4, 0),
+    (0xEFFFE, 0x10FFFF, 2, 0),
+];
+
+// Case foldings to more than one character, with status `F` in `CaseFolding.txt` for Unicode
+// 16.0.0, in order. The others, with status `C`, come from `unicode-case-mapping` for the same
+// version. Regenerate it with `scripts/case_foldings.py`.
+const FULL_CASE_FOLDINGS: &[(u32, &str)] = &[
+    (0xDF, "ss"), (0x130, "i\u{307}"), (0x149, "\u{2BC}n"), (0x1F0, "j\u{30C}"),
+    (0x390, "\u{3B9}\u{308}\u{301}"), (0x3B0, "\u{3C5}\u{308}\u{301}"), (0x587, "\u{565}\u{582}"),
+    (0x1E96, "h\u{331}"), (0x1E97, "t\u{308}"), (0x1E98, "w\u{30A}"), (0x1E99, "y\u{30A}"),
+    (0x1E9A, "a\u{2BE}"), (0x1E9E, "ss"), (0x1F50, "\u{3C5}\u{313}"),
+    (0x1F52, "\u{3C5}\u{313}\u{300}"), (0x1F54, "\u{3C5}\u{313}\u{301}"),
+    (0x1F56, "\u{3C5}\u{313}\u{342}"), (0x1F80, "\u{1F00}\u{3B9}"), (0x1F81, "\u{1F01}\u{3B9}"),
+    (0x1F82, "\u{1F02}\u{3B9}"), (0x1F83, "\u{1F03}\u{3B9}"), (0x1F84, "\u{1F04}\u{3B9}"),
+    (0x1F85, "\u{1F05}\u{3B9}"), (0x1F86, "\u{1F06}\u{3B9}"), (0x1F87, "\u{1F07}\u{3B9}"),
+    (0x1F88, "\u{1F00}\u{3B9}"), (0x1F89, "\u{1F01}\u{3B9}"), (0x1F8A, "\u{1F02}\u{3B9}"),
+    (0x1F8B, "\u{1F03}\u{3B9}"), (0x1F8C, "\u{1F04}\u{3B9}"), (0x1F8D, "\u{1F05}\u{3B9}"),
+    (0x1F8E, "\u{1F06}\u{3B9}"), (0x1F8F, "\u{1F07}\u{3B9}"), (0x1F90, "\u{1F20}\u{3B9}"),
+    (0x1F91, "\u{1F21}\u{3B9}"), (0x1F92, "\u{1F22}\u{3B9}"), (0x1F93, "\u{1F23}\u{3B9}"),
+    (0x1F94, "\u{1F24}\u{3B9}"), (0x1F95, "\u{1F25}\u{3B9}"), (0x1F96, "\u{1F26}\u{3B9}"),
+    (0x1F97, "\u{1F27}\u{3B9}"), (0x1F98, "\u{1F20}\u{3B9}"), (0x1F99, "\u{1F21}\u{3B9}"),
+    (0x1F9A, "\u{1F22}\u{3B9}"), (0x1F9B, "\u{1F23}\u{3B9}"), (0x1F9C, "\u{1F24}\u{3B9}"),
+    (0x1F9D, "\u{1F25}\u{3B9}"), (0x1F9E, "\u{1F26}\u{3B9}"), (0x1F9F, "\u{1F27}\u{3B9}"),
+    (0x1FA0, "\u{1F60}\u{3B9}"), (0x1FA1, "\u{1F61}\u{3B9}"), (0x1FA2, "\u{1F62}\u{3B9}"),
+    (0x1FA3, "\u{1F63}\u{3B9}"), (0x1FA4, "\u{1F64}\u{3B9}"), (0x1FA5, "\u{1F65}\u{3B9}"),
+    (0x1FA6, "\u{1F66}\u{3B9}"), (0x1FA7, "\u{1F67}\u{3B9}"), (0x1FA8, "\u{1F60}\u{3B9}"),
+    (0x1FA9, "\u{1F61}\u{3B9}"), (0x1FAA, "\u{1F62}\u{3B9}"), (0x1FAB, "\u{1F63}\u{3B9}"),
+    (0x1FAC, "\u{1F64}\u{3B9}"), (0x1FAD, "\u{1F65}\u{3B9}"), (0x1FAE, "\u{1F66}\u{3B9}"),
+    (0x1FAF, "\u{1F67}\u{3B9}"), (0x1FB2, "\u{1F70}\u{3B9}"), (0x1FB3, "\u{3B1}\u{3B9}"),
+    (0x1FB4, "\u{3AC}\u{3B9}"), (0x1FB6, "\u{3B1}\u{342}"), (0x1FB7, "\u{3B1}\u{342}\u{3B9}"),
+    (0x1FBC, "\u{3B1}\u{3B9}"), (0x1FC2, "\u{1F74}\u{3B9}"), (0x1FC3, "\u{3B7}\u{3B9}"),
+    (0x1FC4, "\u{3AE}\u{3B9}"), (0x1FC6, "\u{3B7}\u{342}"), (0x1FC7, "\u{3B7}\u{342}\u{3B9}"),
+    (0x1FCC, "\u{3B7}\u{3B9}"), (0x1FD2, "\u{3B9}\u{308}\u{300}"),
+    (0x1FD3, "\u{3B9}\u{308}\u{301}"), (0x1FD6, "\u{3B9}\u{342}"),
+    (0x1FD7, "\u{3B9}\u{308}\u{342}"), (0x1FE2, "\u{3C5}\u{308}\u{300}"),
+    (0x1FE3, "\u{3C5}\u{308}\u{301}"), (0x1FE4, "\u{3C1}\u{313}"), (0x1FE6, "\u{3C5}\u{342}"),
+    (0x1FE7, "\u{3C5}\u{308}\u{342}"), (0x1FF2, "\u{1F7C}\u{3B9}"), (0x1FF3, "\u{3C9}\u{3B9}"),
+    (0x1FF4, "\u{3CE}\u{3B9}"), (0x1FF6, "\u{3C9}\u{342}"), (0x1FF7, "\u{3C9}\u{342}\u{3B9}"),
+    (0x1FFC, "\u{3C9}\u{3B9}"), (0xFB00, "ff"), (0xFB01, "fi"), (0xFB02, "fl"), (0xFB03, "ffi"),
+    (0xFB04, "ffl"), (0xFB05, "st"), (0xFB06, "st"), (0xFB13, "\u{574}\u{576}"),
+    (0xFB14, "\u{574}\u{565}"), (0xFB15, "\u{574}\u{56B}"), (0xFB16, "\u{57E}\u{576}"),
+    (0xFB17, "\u{574}\u{56D}"),
+];
+
+// Decomposition mappings from `UnicodeData.txt` for Unicode 16.0.0, in order, except for Hangul
+// syllables. Regenerate it with `scripts/decompositions.py`.
+const DECOMPOSITIONS: &[(u32, DecompositionType, &str)] = {
+    use DecompositionType as Dt;
+
+    &[
+        (0xA0, Dt::Nobreak, " "), (0xA8, Dt::Compat, " \u{308}"), (0xAA, Dt::Super, "a"),
+        (0xAF, Dt::Compat, " \u{304}"), (0xB2, Dt::Super, "2"), (0xB3, Dt::Super, "3"),
+        (0xB4, Dt::Compat, " \u{301}"), (0xB5, Dt::Compat, "\u{3BC}"),
+        (0xB8, Dt::Compat, " \u{327}"), (0xB9, Dt::Super, "1"), (0xBA, Dt::Super, "o"),
+        (0xBC, Dt::Fraction, "1\u{2044}4"), (0xBD, Dt::Fraction, "1\u{2044}2"),
+        (0xBE, Dt::Fraction, "3\u{2044}4"), (0xC0, Dt::Canonical, "A\u{300}"),
+        (0xC1, Dt::Canonical, "A\u{301}"), (0xC2, Dt::Canonical, "A\u{302}"),
+        (0xC3, Dt::Canonical, "A\u{303}"), (0xC4, Dt::Canonical, "A\u{308}"),
+        (0xC5, Dt::Canonical, "A\u{30A}"), (0xC7, Dt::Canonical, "C\u{327}"),
+        (0xC8, Dt::Canonical, "E\u{300}"), (0xC9, Dt::Canonical, "E\u{301}"),
+        (0xCA, Dt::Canonical, "E\u{302}"), (0xCB, Dt::Canonical, "E\u{308}"),
+        (0xCC, Dt::Canonical, "I\u{300}"), (0xCD, Dt::Canonical, "I\u{301}"),
+        (0xCE, Dt::Canonical, "I\u{302}"), (0xCF, Dt::Canonical, "I\u{308}"),
+        (0xD1, Dt::Canonical, "N\u{303}"), (0xD2, Dt::Canonical, "O\u{300}"),
+        (0xD3, Dt::Canonical, "O\u{301}"), (0xD4, Dt::Canonical, "O\u{302}"),
+        (0xD5, Dt::Canonical, "O\u{303}"), (0xD6, Dt::Canonical, "O\u{308}"),
+        (0xD9, Dt::Canonical, "U\u{300}"), (0xDA, Dt::Canonical, "U\u{301}"),
+        (0xDB, Dt::Canonical, "U\u{302}"), (0xDC, Dt::Canonical, "U\u{308}"),
+        (0xDD, Dt::Canonical, "Y\u{301}"), (0xE0, Dt::Canonical, "a\u{300}"),
+        (0xE1, Dt::Canonical, "a\u{301}"), (0xE2, Dt::Canonical, "a\u{302}"),
+        (0xE3, Dt::Canonical, "a\u{303}"), (0xE4, Dt::Canonical, "a\u{308}"),
+        (0xE5, Dt::Canonical, "a\u{30A}"), (0xE7, Dt::Canonical, "c\u{327}"),
+        (0xE8, Dt::Canonical, "e\u{300}"), (0xE9, Dt::Canonical, "e\u{301}"),
+        (0xEA, Dt::Canonical, "e\u{302}"), (0xEB, Dt::Canonical, "e\u{308}"),
+        (0xEC, Dt::Canonical, "i\u{300}"), (0xED, Dt::Canonical, "i\u{301}"),
+        (0xEE, Dt::Canonical, "i\u{302}"), (0xEF, Dt::Canonical, "i\u{308}"),
+        (0xF1, Dt::Canonical, "n\u{303}"), (0xF2, Dt::Canonical, "o\u{300}"),
+        (0xF3, Dt::Canonical, "o\u{301}"), (0xF4, Dt::Canonical, "o\u{302}"),
+        (0xF5, Dt::Canonical, "o\u{303}"), (0xF6, Dt::Canonical, "o\u{308}"),
+        (0xF9, Dt::Canonical, "u\u{300}"), (0xFA, Dt::Canonical, "u\u{301}"),
+        (0xFB, Dt::Canonical, "u\u{302}"), (0xFC, Dt::Canonical, "u\u{308}"),
+        (0xFD, Dt::Canonical, "y\u{301}"), (0xFF, Dt::Canonical, "y\u{308}"),
+        (0x100, Dt::Canonical, "A\u{304}"), (0x101, Dt::Canonical, "a\u{304}"),
+        (0x102, Dt::Canonical, "A\u{306}"), (0x103, Dt::Canonical, "a\u{306}"),
+        (0x104, Dt::Canonical, "A\u{328}"), (0x105, Dt::Canonical, "a\u{328}"),
+        (0x106, Dt::Canonical, "C\u{301}"), (0x107, Dt::Canonical, "c\u{301}"),
+        (0x108, Dt::Canonical, "C\u{302}"), (0x109, Dt::Canonical, "c\u{302}"),
+        (0x10A, Dt::Canonical, "C\u{307}"), (0x10B, Dt::Canonical, "c\u{307}"),
+        (0x10C, Dt::Canonical, "C\u{30C}"), (0x10D, Dt::Canonical, "c\u{30C}"),
+        (0x10E, Dt::Canonical, "D\u{30C}"), (0x10F, Dt::Canonical, "d\u{30C}"),
+        (0x112, Dt::Canonical, "E\u{304}"), (0x113, Dt::Canonical, "e\u{304}"),
+        (0x114, Dt::Canonical, "E\u{306}"), (0x115, Dt::Canonical, "e\u{306}"),
+        (0x116, Dt::Canonical, "E\u{307}"), (0x117, Dt::Canonical, "e\u{307}"),
+        (0x118, Dt::Canonical, "E\u{328}"), (0x119, Dt::Canonical, "e\u{328}"),
+        (0x11A, Dt::Canonical, "E\u{30C}"), (0x11B, Dt::Canonical, "e\u{30C}"),
+        (0x11C, Dt::Canonical, "G\u{302}"), (0x11D, Dt::Canonical, "g\u{302}"),
+        (0x11E, Dt::Canonical, "G\u{306}"), (0x11F, Dt::Canonical, "g\u{306}"),
+        (0x120, Dt::Canonical, "G\u{307}"), (0x121, Dt::Canonical, "g\u{307}"),
+        (0x122, Dt::Canonical, "G\u{327}"), (0x123, Dt::Canonical, "g\u{327}"),
+        (0x124, Dt::Canonical, "H\u{302}"), (0x125, Dt::Canonical, "h\u{302}"),
+        (0x128, Dt::Canonical, "I\u{303}"), (0x129, Dt::Canonical, "i\u{303}"),
+        (0x12A, Dt::Canonical, "I\u{304}"), (0x12B, Dt::Canonical, "i\u{304}"),
+        (0x12C, Dt::Canonical, "I\u{306}"), (0x12D, Dt::Canonical, "i\u{306}"),
+        (0x12E, Dt::Canonical, "I\u{328}"), (0x12F, Dt::Canonical, "i\u{328}"),
+        (0x130, Dt::Canonical, "I\u{307}"), (0x132, Dt::Compat, "IJ"), (0x133, Dt::Compat, "ij"),
+        (0x134, Dt::Canonical, "J\u{302}"), (0x135, Dt::Canonical, "j\u{302}"),
+        (0x136, Dt::Canonical, "K\u{327}"), (0x137, Dt::Canonical, "k\u{327}"),
+        (0x139, Dt::Canonical, "L\u{301}"), (0x13A, Dt::Canonical, "l\u{301}"),
+        (0x13B, Dt::Canonical, "L\u{327}"), (0x13C, Dt::Canonical, "l\u{327}"),
+        (0x13D, Dt::Canonical, "L\u{30C}"), (0x13E, Dt::Canonical, "l\u{30C}"),
+        (0x13F, Dt::Compat, "L\u{B7}"), (0x140, Dt::Compat, "l\u{B7}"),
+        (0x143, Dt::Canonical, "N\u{301}"), (0x144, Dt::Canonical, "n\u{301}"),
+        (0x145, Dt::Canonical, "N\u{327}"), (0x146, Dt::Canonical, "n\u{327}"),
+        (0x147, Dt::Canonical, "N\u{30C}"), (0x148, Dt::Canonical, "n\u{30C}"),
+        (0x149, Dt::Compat, "\u{2BC}n"), (0x14C, Dt::Canonical, "O\u{304}"),
+        (0x14D, Dt::Canonical, "o\u{304}"), (0x14E, Dt::Canonical, "O\u{306}"),
+        (0x14F, Dt::Canonical, "o\u{306}"), (0x150, Dt::Canonical, "O\u{30B}"),
+        (0x151, Dt::Canonical, "o\u{30B}"), (0x154, Dt::Canonical, "R\u{301}"),
+        (0x155, Dt::Canonical, "r\u{301}"), (0x156, Dt::Canonical, "R\u{327}"),
+        (0x157, Dt::Canonical, "r\u{327}"), (0x158, Dt::Canonical, "R\u{30C}"),
+        (0x159, Dt::Canonical, "r\u{30C}"), (0x15A, Dt::Canonical, "S\u{301}"),
+        (0x15B, Dt::Canonical, "s\u{301}"), (0x15C, Dt::Canonical, "S\u{302}"),
+        (0x15D, Dt::Canonical, "s\u{302}"), (0x15E, Dt::Canonical, "S\u{327}"),
+        (0x15F, Dt::Canonical, "s\u{327}"), (0x160, Dt::Canonical, "S\u{30C}"),
+        (0x161, Dt::Canonical, "s\u{30C}"), (0x162, Dt::Canonical, "T\u{327}"),
+        (0x163, Dt::Canonical, "t\u{327}"), (0x164, Dt::Canonical, "T\u{30C}"),
+        (0x165, Dt::Canonical, "t\u{30C}"), (0x168, Dt::Canonical, "U\u{303}"),
+        (0x169, Dt::Canonical, "u\u{303}"), (0x16A, Dt::Canonical, "U\u{304}"),
+        (0x16B, Dt::Canonical, "u\u{304}"), (0x16C, Dt::Canonical, "U\u{306}"),
+        (0x16D, Dt::Canonical, "u\u{306}"), (0x16E, Dt::Canonical, "U\u{30A}"),
+        (0x16F, Dt::Canonical, "u\u{30A}"), (0x170, Dt::Canonical, "U\u{30B}"),
+        (0x171, Dt::Canonical, "u\u{30B}"), (0x172, Dt::Canonical, "U\u{328}"),
+        (0x173, Dt::Canonical, "u\u{328}"), (0x174, Dt::Canonical, "W\u{302}"),
+        (0x175, Dt::Canonical, "w\u{302}"), (0x176, Dt::Canonical, "Y\u{302}"),
+        (0x177, Dt::Canonical, "y\u{302}"), (0x178, Dt::Canonical, "Y\u{308}"),
+        (0x179, Dt::Canonical, "Z\u{301}"), (0x17A, Dt::Canonical, "z\u{301}"),
+        (0x17B, Dt::Canonical, "Z\u{307}"), (0x17C, Dt::Canonical, "z\u{307}"),
+        (0x17D, Dt::Canonical, "Z\u{30C}"), (0x17E, Dt::Canonical, "z\u{30C}"),
+        (0x17F, Dt::Compat, "s"), (0x1A0, Dt::Canonical, "O\u{31B}"),
+        (0x1A1, Dt::Canonical, "o\u{31B}"), (0x1AF, Dt::Canonical, "U\u{31B}"),
+        (0x1B0, Dt::Canonical, "u\u{31B}"), (0x1C4, Dt::Compat, "D\u{17D}"),
+        (0x1C5, Dt::Compat, "D\u{17E}"), (0x1C6, Dt::Compat, "d\u{17E}"), (0x1C7, Dt::Compat, "LJ"),
+        (0x1C8, Dt::Compat, "Lj"), (0x1C9, Dt::Compat, "lj"), (0x1CA, Dt::Compat, "NJ"),
+        (0x1CB, Dt::Compat, "Nj"), (0x1CC, Dt::Compat, "nj"), (0x1CD, Dt::Canonical, "A\u{30C}"),
+        (0x1CE, Dt::Canonical, "a\u{30C}"), (0x1CF, Dt::Canonical, "I\u{30C}"),
+        (0x1D0, Dt::Canonical, "i\u{30C}"), (0x1D1, Dt::Canonical, "O\u{30C}"),
+        (0x1D2, Dt::Canonical, "o\u{30C}"), (0x1D3, Dt::Canonical, "U\u{30C}"),
+        (0x1D4, Dt::Canonical, "u\u{30C}"), (0x1D5, Dt::Canonical, "\u{DC}\u{304}"),
+        (0x1D6, Dt::Canonical, "\u{FC}\u{304}"), (0x1D7, Dt::Canonical, "\u{DC}\u{301}"),
+        (0x1D8, Dt::Canonical, "\u{FC}\u{301}"), (0x1D9, Dt::Canonical, "\u{DC}\u{30C}"),
+        (0x1DA, Dt::Canonical, "\u{FC}\u{30C}"), (0x1DB, Dt::Canonical, "\u{DC}\u{300}"),
+        (0x1DC, Dt::Canonical, "\u{FC}\u{300}"), (0x1DE, Dt::Canonical, "\u{C4}\u{304}"),
+        (0x1DF, Dt::Canonical, "\u{E4}\u{304}"), (0x1E0, Dt::Canonical, "\u{226}\u{304}"),
+        (0x1E1, Dt::Canonical, "\u{227}\u{304}"), (0x1E2, Dt::Canonical, "\u{C6}\u{304}"),
+        (0x1E3, Dt::Canonical, "\u{E6}\u{304}"), (0x1E6, Dt::Canonical, "G\u{30C}"),
+        (0x1E7, Dt::Canonical, "g\u{30C}"), (0x1E8, Dt::Canonical, "K\u{30C}"),
+        (0x1E9, Dt::Canonical, "k\u{30C}"), (0x1EA, Dt::Canonical, "O\u{328}"),
+        (0x1EB, Dt::Canonical, "o\u{328}"), (0x1EC, Dt::Canonical, "\u{1EA}\u{304}"),
+        (0x1ED, Dt::Canonical, "\u{1EB}\u{304}"), (0x1EE, Dt::Canonical, "\u{1B7}\u{30C}"),
+        (0x1EF, Dt::Canonical, "\u{292}\u{30C}"), (0x1F0, Dt::Canonical, "j\u{30C}"),
+        (0x1F1, Dt::Compat, "DZ"), (0x1F2, Dt::Compat, "Dz"), (0x1F3, Dt::Compat, "dz"),
+        (0x1F4, Dt::Canonical, "G\u{301}"), (0x1F5, Dt::Canonical, "g\u{301}"),
+        (0x1F8, Dt::Canonical, "N\u{300}"), (0x1F9, Dt::Canonical, "n\u{300}"),
+        (0x1FA, Dt::Canonical, "\u{C5}\u{301}"), (0x1FB, Dt::Canonical, "\u{E5}\u{301}"),
+        (0x1FC, Dt::Canonical, "\u{C6}\u{301}"), (0x1FD, Dt::Canonical, "\u{E6}\u{301}"),
+        (0x1FE, Dt::Canonical, "\u{D8}\u{301}"), (0x1FF, Dt::Canonical, "\u{F8}\u{301}"),
+        (0x200, Dt::Canonical, "A\u{30F}"), (0x201, Dt::Canonical, "a\u{30F}"),
+        (0x202, Dt::Canonical, "A\u{311}"), (0x203, Dt::Canonical, "a\u{311}"),
+        (0x204, Dt::Canonical, "E\u{30F}"), (0x205, Dt::Canonical, "e\u{30F}"),
+        (0x206, Dt::Canonical, "E\u{311}"), (0x207, Dt::Canonical, "e\u{311}"),
+        (0x208, Dt::Canonical, "I\u{30F}"), (0x209, Dt::Canonical, "i\u{30F}"),
+        (0x20A, Dt::Canonical, "I\u{311}"), (0x20B, Dt::Canonical, "i\u{311}"),
+        (0x20C, Dt::Canonical, "O\u{30F}"), (0x20D, Dt::Canonical, "o\u{30F}"),
+        (0x20E, Dt::Canonical, "O\u{311}"), (0x20F, Dt::Canonical, "o\u{311}"),
+        (0x210, Dt::Canonical, "R\u{30F}"), (0x211, Dt::Canonical, "r\u{30F}"),
+        (0x212, Dt::Canonical, "R\u{311}"), (0x213, Dt::Canonical, "r\u{311}"),
+        (0x214, Dt::Canonical, "U\u{30F}"), (0x215, Dt::Canonical, "u\u{30F}"),
+        (0x216, Dt::Canonical, "U\u{311}"), (0x217, Dt::Canonical, "u\u{311}"),
+        (0x218, Dt::Canonical, "S\u{326}"), (0x219, Dt::Canonical, "s\u{326}"),
+        (0x21A, Dt::Canonical, "T\u{326}"), (0x21B, Dt::Canonical, "t\u{326}"),
+        (0x21E, Dt::Canonical, "H\u{30C}"), (0x21F, Dt::Canonical, "h\u{30C}"),
+        (0x226, Dt::Canonical, "A\u{307}"), (0x227, Dt::Canonical, "a\u{307}"),
+        (0x228, Dt::Canonical, "E\u{327}"), (0x229, Dt::Canonical, "e\u{327}"),
+        (0x22A, Dt::Canonical, "\u{D6}\u{304}"), (0x22B, Dt::Canonical, "\u{F6}\u{304}"),
+        (0x22C, Dt::Canonical, "\u{D5}\u{304}"), (0x22D, Dt::Canonical, "\u{F5}\u{304}"),
+        (0x22E, Dt::Canonical, "O\u{307}"), (0x22F, Dt::Canonical, "o\u{307}"),
+        (0x230, Dt::Canonical, "\u{22E}\u{304}"), (0x231, Dt::Canonical, "\u{22F}\u{304}"),
+        (0x232, Dt::Canonical, "Y\u{304}"), (0x233, Dt::Canonical, "y\u{304}"),
+        (0x2B0, Dt::Super, "h"), (0x2B1, Dt::Super, "\u{266}"), (0x2B2, Dt::Super, "j"),
+        (0x2B3, Dt::Super, "r"), (0x2B4, Dt::Super, "\u{279}"), (0x2B5, Dt::Super, "\u{27B}"),
+        (0x2B6, Dt::Super, "\u{281}"), (0x2B7, Dt::Super, "w"), (0x2B8, Dt::Super, "y"),
+        (0x2D8, Dt::Compat, " \u{306}"), (0x2D9, Dt::Compat, " \u{307}"),
+        (0x2DA, Dt::Compat, " \u{30A}"), (0x2DB, Dt::Compat, " \u{328}"),
+        (0x2DC, Dt::Compat, " \u{303}"), (0x2DD, Dt::Compat, " \u{30B}"),
+        (0x2E0, Dt::Super, "\u{263}"), (0x2E1, Dt::Super, "l"), (0x2E2, Dt::Super, "s"),
+        (0x2E3, Dt::Super, "x"), (0x2E4, Dt::Super, "\u{295}"), (0x340, Dt::Canonical, "\u{300}"),
+        (0x341, Dt::Canonical, "\u{301}"), (0x343, Dt::Canonical, "\u{313}"),
+        (0x344, Dt::Canonical, "\u{308}\u{301}"), (0x374, Dt::Canonical, "\u{2B9}"),
+        (0x37A, Dt::Compat, " \u{345}"), (0x37E, Dt::Canonical, ";"),
+        (0x384, Dt::Compat, " \u{301}"), (0x385, Dt::Canonical, "\u{A8}\u{301}"),
+        (0x386, Dt::Canonical, "\u{391}\u{301}"), (0x387, Dt::Canonical, "\u{B7}"),
+        (0x388, Dt::Canonical, "\u{395}\u{301}"), (0x389, Dt::Canonical, "\u{397}\u{301}"),
+        (0x38A, Dt::Canonical, "\u{399}\u{301}"), (0x38C, Dt::Canonical, "\u{39F}\u{301}"),
+        (0x38E, Dt::Canonical, "\u{3A5}\u{301}"), (0x38F, Dt::Canonical, "\u{3A9}\u{301}"),
+        (0x390, Dt::Canonical, "\u{3CA}\u{301}"), (0x3AA, Dt::Canonical, "\u{399}\u{308}"),
+        (0x3AB, Dt::Canonical, "\u{3A5}\u{308}"), (0x3AC, Dt::Canonical, "\u{3B1}\u{301}"),
+        (0x3AD, Dt::Canonical, "\u{3B5}\u{301}"), (0x3AE, Dt::Canonical, "\u{3B7}\u{301}"),
+        (0x3AF, Dt::Canonical, "\u{3B9}\u{301}"), (0x3B0, Dt::Canonical, "\u{3CB}\u{301}"),
+        (0x3CA, Dt::Canonical, "\u{3B9}\u{308}"), (0x3CB, Dt::Canonical, "\u{3C5}\u{308}"),
+        (0x3CC, Dt::Canonical, "\u{3BF}\u{301}"), (0x3CD, Dt::Canonical, "\u{3C5}\u{301}"),
+        (0x3CE, Dt::Canonical, "\u{3C9}\u{301}"), (0x3D0, Dt::Compat, "\u{3B2}"),
+        (0x3D1, Dt::Compat, "\u{3B8}"), (0x3D2, Dt::Compat, "\u{3A5}"),
+        (0x3D3, Dt::Canonical, "\u{3D2}\u{301}"), (0x3D4, Dt::Canonical, "\u{3D2}\u{308}"),
+        (0x3D5, Dt::Compat, "\u{3C6}"), (0x3D6, Dt::Compat, "\u{3C0}"),
+        (0x3F0, Dt::Compat, "\u{3BA}"), (0x3F1, Dt::Compat, "\u{3C1}"),
+        (0x3F2, Dt::Compat, "\u{3C2}"), (0x3F4, Dt::Compat, "\u{398}"),
+        (0x3F5, Dt::Compat, "\u{3B5}"), (0x3F9, Dt::Compat, "\u{3A3}"),
+        (0x400, Dt::Canonical, "\u{415}\u{300}"), (0x401, Dt::Canonical, "\u{415}\u{308}"),
+        (0x403, Dt::Canonical, "\u{413}\u{301}"), (0x407, Dt::Canonical, "\u{406}\u{308}"),
+        (0x40C, Dt::Canonical, "\u{41A}\u{301}"), (0x40D, Dt::Canonical, "\u{418}\u{300}"),
+        (0x40E, Dt::Canonical, "\u{423}\u{306}"), (0x419, Dt::Canonical, "\u{418}\u{306}"),
+        (0x439, Dt::Canonical, "\u{438}\u{306}"), (0x450, Dt::Canonical, "\u{435}\u{300}"),
+        (0x451, Dt::Canonical, "\u{435}\u{308}"), (0x453, Dt::Canonical, "\u{433}\u{301}"),
+        (0x457, Dt::Canonical, "\u{456}\u{308}"), (0x45C, Dt::Canonical, "\u{43A}\u{301}"),
+        (0x45D, Dt::Canonical, "\u{438}\u{300}"), (0x45E, Dt::Canonical, "\u{443}\u{306}"),
+        (0x476, Dt::Canonical, "\u{474}\u{30F}"), (0x477, Dt::Canonical, "\u{475}\u{30F}"),
+        (0x4C1, Dt::Canonical, "\u{416}\u{306}"), (0x4C2, Dt::Canonical, "\u{436}\u{306}"),
+        (0x4D0, Dt::Canonical, "\u{410}\u{306}"), (0x4D1, Dt::Canonical, "\u{430}\u{306}"),
+        (0x4D2, Dt::Canonical, "\u{410}\u{308}"), (0x4D3, Dt::Canonical, "\u{430}\u{308}"),
+        (0x4D6, Dt::Canonical, "\u{415}\u{306}"), (0x4D7, Dt::Canonical, "\u{435}\u{306}"),
+        (0x4DA, Dt::Canonical, "\u{4D8}\u{308}"), (0x4DB, Dt::Canonical, "\u{4D9}\u{308}"),
+        (0x4DC, Dt::Canonical, "\u{416}\u{308}"), (0x4DD, Dt::Canonical, "\u{436}\u{308}"),
+        (0x4DE, Dt::Canonical, "\u{417}\u{308}"), (0x4DF, Dt::Canonical, "\u{437}\u{308}"),
+        (0x4E2, Dt::Canonical, "\u{418}\u{304}"), (0x4E3, Dt::Canonical, "\u{438}\u{304}"),
+        (0x4E4, Dt::Canonical, "\u{418}\u{308}"), (0x4E5, Dt::Canonical, "\u{438}\u{308}"),
+        (0x4E6, Dt::Canonical, "\u{41E}\u{308}"), (0x4E7, Dt::Canonical, "\u{43E}\u{308}"),
+        (0x4EA, Dt::Canonical, "\u{4E8}\u{308}"), (0x4EB, Dt::Canonical, "\u{4E9}\u{308}"),
+        (0x4EC, Dt::Canonical, "\u{42D}\u{308}"), (0x4ED, Dt::Canonical, "\u{44D}\u{308}"),
+        (0x4EE, Dt::Canonical, "\u{423}\u{304}"), (0x4EF, Dt::Canonical, "\u{443}\u{304}"),
+        (0x4F0, Dt::Canonical, "\u{423}\u{308}"), (0x4F1, Dt::Canonical, "\u{443}\u{308}"),
+        (0x4F2, Dt::Canonical, "\u{423}\u{30B}"), (0x4F3, Dt::Canonical, "\u{443}\u{30B}"),
+        (0x4F4, Dt::Canonical, "\u{427}\u{308}"), (0x4F5, Dt::Canonical, "\u{447}\u{308}"),
+        (0x4F8, Dt::Canonical, "\u{42B}\u{308}"), (0x4F9, Dt::Canonical, "\u{44B}\u{308}"),
+        (0x587, Dt::Compat, "\u{565}\u{582}"), (0x622, Dt::Canonical, "\u{627}\u{653}"),
+        (0x623, Dt::Canonical, "\u{627}\u{654}"), (0x624, Dt::Canonical, "\u{648}\u{654}"),
+        (0x625, Dt::Canonical, "\u{627}\u{655}"), (0x626, Dt::Canonical, "\u{64A}\u{654}"),
+        (0x675, Dt::Compat, "\u{627}\u{674}"), (0x676, Dt::Compat, "\u{648}\u{674}"),
+        (0x677, Dt::Compat, "\u{6C7}\u{674}"), (0x678, Dt::Compat, "\u{64A}\u{674}"),
+        (0x6C0, Dt::Canonical, "\u{6D5}\u{654}"), (0x6C2, Dt::Canonical, "\u{6C1}\u{654}"),
+        (0x6D3, Dt::Canonical, "\u{6D2}\u{654}"), (0x929, Dt::Canonical, "\u{928}\u{93C}"),
+        (0x931, Dt::Canonical, "\u{930}\u{93C}"), (0x934, Dt::Canonical, "\u{933}\u{93C}"),
+        (0x958, Dt::Canonical, "\u{915}\u{93C}"), (0x959, Dt::Canonical, "\u{916}\u{93C}"),
+        (0x95A, Dt::Canonical, "\u{917}\u{93C}"), (0x95B, Dt::Canonical, "\u{91C}\u{93C}"),
+        (0x95C, Dt::Canonical, "\u{921}\u{93C}"), (0x95D, Dt::Canonical, "\u{922}\u{93C}"),
+        (0x95E, Dt::Canonical, "\u{92B}\u{93C}"), (0x95F, Dt::Canonical, "\u{92F}\u{93C}"),
+        (0x9CB, Dt::Canonical, "\u{9C7}\u{9BE}"), (0x9CC, Dt::Canonical, "\u{9C7}\u{9D7}"),
+        (0x9DC, Dt::Canonical, "\u{9A1}\u{9BC}"), (0x9DD, Dt::Canonical, "\u{9A2}\u{9BC}"),
+        (0x9DF, Dt::Canonical, "\u{9AF}\u{9BC}"), (0xA33, Dt::Canonical, "\u{A32}\u{A3C}"),
+        (0xA36, Dt::Canonical, "\u{A38}\u{A3C}"), (0xA59, Dt::Canonical, "\u{A16}\u{A3C}"),
+        (0xA5A, Dt::Canonical, "\u{A17}\u{A3C}"), (0xA5B, Dt::Canonical, "\u{A1C}\u{A3C}"),
+        (0xA5E, Dt::Canonical, "\u{A2B}\u{A3C}"), (0xB48, Dt::Canonical, "\u{B47}\u{B56}"),
+        (0xB4B, Dt::Canonical, "\u{B47}\u{B3E}"), (0xB4C, Dt::Canonical, "\u{B47}\u{B57}"),
+        (0xB5C, Dt::Canonical, "\u{B21}\u{B3C}"), (0xB5D, Dt::Canonical, "\u{B22}\u{B3C}"),
+        (0xB94, Dt::Canonical, "\u{B92}\u{BD7}"), (0xBCA, Dt::Canonical, "\u{BC6}\u{BBE}"),
+        (0xBCB, Dt::Canonical, "\u{BC7}\u{BBE}"), (0xBCC, Dt::Canonical, "\u{BC6}\u{BD7}"),
+        (0xC48, Dt::Canonical, "\u{C46}\u{C56}"), (0xCC0, Dt::Canonical, "\u{CBF}\u{CD5}"),
+        (0xCC7, Dt::Canonical, "\u{CC6}\u{CD5}"), (0xCC8, Dt::Canonical, "\u{CC6}\u{CD6}"),
+        (0xCCA, Dt::Canonical, "\u{CC6}\u{CC2}"), (0xCCB, Dt::Canonical, "\u{CCA}\u{CD5}"),
+        (0xD4A, Dt::Canonical, "\u{D46}\u{D3E}"), (0xD4B, Dt::Canonical, "\u{D47}\u{D3E}"),
+        (0xD4C, Dt::Canonical, "\u{D46}\u{D57}"), (0xDDA, Dt::Canonical, "\u{DD9}\u{DCA}"),
+        (0xDDC, Dt::Canonical, "\u{DD9}\u{DCF}"), (0xDDD, Dt::Canonical, "\u{DDC}\u{DCA}"),
+        (0xDDE, Dt::Canonical, "\u{DD9}\u{DDF}"), (0xE33, Dt::Compat, "\u{E4D}\u{E32}"),
+        (0xEB3, Dt::Compat, "\u{ECD}\u{EB2}"), (0xEDC, Dt::Compat, "\u{EAB}\u{E99}"),
+        (0xEDD, Dt::Compat, "\u{EAB}\u{EA1}"), (0xF0C, Dt::Nobreak, "\u{F0B}"),
+        (0xF43, Dt::Canonical, "\u{F42}\u{FB7}"), (0xF4D, Dt::Canonical, "\u{F4C}\u{FB7}"),
+        (0xF52, Dt::Canonical, "\u{F51}\u{FB7}"), (0xF57, Dt::Canonical, "\u{F56}\u{FB7}"),
+        (0xF5C, Dt::Canonical, "\u{F5B}\u{FB7}"), (0xF69, Dt::Canonical, "\u{F40}\u{FB5}"),
+        (0xF73, Dt::Canonical, "\u{F71}\u{F72}"), (0xF75, Dt::Canonical, "\u{F71}\u{F74}"),
+        (0xF76, Dt::Canonical, "\u{FB2}\u{F80}"), (0xF77, Dt::Compat, "\u{FB2}\u{F81}"),
+        (0xF78, Dt::Canonical, "\u{FB3}\u{F80}"), (0xF79, Dt::Compat, "\u{FB3}\u{F81}"),
+        (0xF81, Dt::Canonical, "\u{F71}\u{F80}"), (0xF93, Dt::Canonical, "\u{F92}\u{FB7}"),
+        (0xF9D, Dt::Canonical, "\u{F9C}\u{FB7}"), (0xFA2, Dt::Canonical, "\u{FA1}\u{FB7}"),
+        (0xFA7, Dt::Canonical, "\u{FA6}\u{FB7}"), (0xFAC, Dt::Canonical, "\u{FAB}\u{FB7}"),
+        (0xFB9, Dt::Canonical, "\u{F90}\u{FB5}"), (0x1026, Dt::Canonical, "\u{1025}\u{102E}"),
+        (0x10FC, Dt::Super, "\u{10DC}"), (0x1B06, Dt::Canonical, "\u{1B05}\u{1B35}"),
+        (0x1B08, Dt::Canonical, "\u{1B07}\u{1B35}"), (0x1B0A, Dt::Canonical, "\u{1B09}\u{1B35}"),
+        (0x1B0C, Dt::Canonical, "\u{1B0B}\u{1B35}"), (0x1B0E, Dt::Canonical, "\u{1B0D}\u{1B35}"),
+        (0x1B12, Dt::Canonical, "\u{1B11}\u{1B35}"), (0x1B3B, Dt::Canonical, "\u{1B3A}\u{1B35}"),
+        (0x1B3D, Dt::Canonical, "\u{1B3C}\u{1B35}"), (0x1B40, Dt::Canonical, "\u{1B3E}\u{1B35}"),
+        (0x1B41, Dt::Canonical, "\u{1B3F}\u{1B35}"), (0x1B43, Dt::Canonical, "\u{1B42}\u{1B35}"),
+        (0x1D2C, Dt::Super, "A"), (0x1D2D, Dt::Super, "\u{C6}"), (0x1D2E, Dt::Super, "B"),
+        (0x1D30, Dt::Super, "D"), (0x1D31, Dt::Super, "E"), (0x1D32, Dt::Super, "\u{18E}"),
+        (0x1D33, Dt::Super, "G"), (0x1D34, Dt::Super, "H"), (0x1D35, Dt::Super, "I"),
+        (0x1D36, Dt::Super, "J"), (0x1D37, Dt::Super, "K"), (0x1D38, Dt::Super, "L"),
+        (0x1D39, Dt::Super, "M"), (0x1D3A, Dt::Super, "N"), (0x1D3C, Dt::Super, "O"),
+        (0x1D3D, Dt::Super, "\u{222}"), (0x1D3E, Dt::Super, "P"), (0x1D3F, Dt::Super, "R"),
+        (0x1D40, Dt::Super, "T"), (0x1D41, Dt::Super, "U"), (0x1D42, Dt::Super, "W"),
+        (0x1D43, Dt::Super, "a"), (0x1D44, Dt::Super, "\u{250}"), (0x1D45, Dt::Super, "\u{251}"),
+        (0x1D46, Dt::Super, "\u{1D02}"), (0x1D47, Dt::Super, "b"), (0x1D48, Dt::Super, "d"),
+        (0x1D49, Dt::Super, "e"), (0x1D4A, Dt::Super, "\u{259}"), (0x1D4B, Dt::Super, "\u{25B}"),
+        (0x1D4C, Dt::Super, "\u{25C}"), (0x1D4D, Dt::Super, "g"), (0x1D4F, Dt::Super, "k"),
+        (0x1D50, Dt::Super, "m"), (0x1D51, Dt::Super, "\u{14B}"), (0x1D52, Dt::Super, "o"),
+        (0x1D53, Dt::Super, "\u{254}"), (0x1D54, Dt::Super, "\u{1D16}"),
+        (0x1D55, Dt::Super, "\u{1D17}"), (0x1D56, Dt::Super, "p"), (0x1D57, Dt::Super, "t"),
+        (0x1D58, Dt::Super, "u"), (0x1D59, Dt::Super, "\u{1D1D}"), (0x1D5A, Dt::Super, "\u{26F}"),
+        (0x1D5B, Dt::Super, "v"), (0x1D5C, Dt::Super, "\u{1D25}"), (0x1D5D, Dt::Super, "\u{3B2}"),
+        (0x1D5E, Dt::Super, "\u{3B3}"), (0x1D5F, Dt::Super, "\u{3B4}"),
+        (0x1D60, Dt::Super, "\u{3C6}"), (0x1D61, Dt::Super, "\u{3C7}"), (0x1D62, Dt::Sub, "i"),
+        (0x1D63, Dt::Sub, "r"), (0x1D64, Dt::Sub, "u"), (0x1D65, Dt::Sub, "v"),
+        (0x1D66, Dt::Sub, "\u{3B2}"), (0x1D67, Dt::Sub, "\u{3B3}"), (0x1D68, Dt::Sub, "\u{3C1}"),
+        (0x1D69, Dt::Sub, "\u{3C6}"), (0x1D6A, Dt::Sub, "\u{3C7}"), (0x1D78, Dt::Super, "\u{43D}"),
+        (0x1D9B, Dt::Super, "\u{252}"), (0x1D9C, Dt::Super, "c"), (0x1D9D, Dt::Super, "\u{255}"),
+        (0x1D9E, Dt::Super, "\u{F0}"), (0x1D9F, Dt::Super, "\u{25C}"), (0x1DA0, Dt::Super, "f"),
+        (0x1DA1, Dt::Super, "\u{25F}"), (0x1DA2, Dt::Super, "\u{261}"),
+        (0x1DA3, Dt::Super, "\u{265}"), (0x1DA4, Dt::Super, "\u{268}"),
+        (0x1DA5, Dt::Super, "\u{269}"), (0x1DA6, Dt::Super, "\u{26A}"),
+        (0x1DA7, Dt::Super, "\u{1D7B}"), (0x1DA8, Dt::Super, "\u{29D}"),
+        (0x1DA9, Dt::Super, "\u{26D}"), (0x1DAA, Dt::Super, "\u{1D85}"),
+        (0x1DAB, Dt::Super, "\u{29F}"), (0x1DAC, Dt::Super, "\u{271}"),
+        (0x1DAD, Dt::Super, "\u{270}"), (0x1DAE, Dt::Super, "\u{272}"),
+        (0x1DAF, Dt::Super, "\u{273}"), (0x1DB0, Dt::Super, "\u{274}"),
+        (0x1DB1, Dt::Super, "\u{275}"), (0x1DB2, Dt::Super, "\u{278}"),
+        (0x1DB3, Dt::Super, "\u{282}"), (0x1DB4, Dt::Super, "\u{283}"),
+        (0x1DB5, Dt::Super, "\u{1AB}"), (0x1DB6, Dt::Super, "\u{289}"),
+        (0x1DB7, Dt::Super, "\u{28A}"), (0x1DB8, Dt::Super, "\u{1D1C}"),
+        (0x1DB9, Dt::Super, "\u{28B}"), (0x1DBA, Dt::Super, "\u{28C}"), (0x1DBB, Dt::Super, "z"),
+        (0x1DBC, Dt::Super, "\u{290}"), (0x1DBD, Dt::Super, "\u{291}"),
+        (0x1DBE, Dt::Super, "\u{292}"), (0x1DBF, Dt::Super, "\u{3B8}"),
+        (0x1E00, Dt::Canonical, "A\u{325}"), (0x1E01, Dt::Canonical, "a\u{325}"),
+        (0x1E02, Dt::Canonical, "B\u{307}"), (0x1E03, Dt::Canonical, "b\u{307}"),
+        (0x1E04, Dt::Canonical, "B\u{323}"), (0x1E05, Dt::Canonical, "b\u{323}"),
+        (0x1E06, Dt::Canonical, "B\u{331}"), (0x1E07, Dt::Canonical, "b\u{331}"),
+        (0x1E08, Dt::Canonical, "\u{C7}\u{301}"), (0x1E09, Dt::Canonical, "\u{E7}\u{301}"),
+        (0x1E0A, Dt::Canonical, "D\u{307}"), (0x1E0B, Dt::Canonical, "d\u{307}"),
+        (0x1E0C, Dt::Canonical, "D\u{323}"), (0x1E0D, Dt::Canonical, "d\u{323}"),
+        (0x1E0E, Dt::Canonical, "D\u{331}"), (0x1E0F, Dt::Canonical, "d\u{331}"),
+        (0x1E10, Dt::Canonical, "D\u{327}"), (0x1E11, Dt::Canonical, "d\u{327}"),
+        (0x1E12, Dt::Canonical, "D\u{32D}"), (0x1E13, Dt::Canonical, "d\u{32D}"),
+        (0x1E14, Dt::Canonical, "\u{112}\u{300}"), (0x1E15, Dt::Canonical, "\u{113}\u{300}"),
+        (0x1E16, Dt::Canonical, "\u{112}\u{301}"), (0x1E17, Dt::Canonical, "\u{113}\u{301}"),
+        (0x1E18, Dt::Canonical, "E\u{32D}"), (0x1E19, Dt::Canonical, "e\u{32D}"),
+        (0x1E1A, Dt::Canonical, "E\u{330}"), (0x1E1B, Dt::Canonical, "e\u{330}"),
+        (0x1E1C, Dt::Canonical, "\u{228}\u{306}"), (0x1E1D, Dt::Canonical, "\u{229}\u{306}"),
+        (0x1E1E, Dt::Canonical, "F\u{307}"), (0x1E1F, Dt::Canonical, "f\u{307}"),
+        (0x1E20, Dt::Canonical, "G\u{304}"), (0x1E21, Dt::Canonical, "g\u{304}"),
+        (0x1E22, Dt::Canonical, "H\u{307}"), (0x1E23, Dt::Canonical, "h\u{307}"),
+        (0x1E24, Dt::Canonical, "H\u{323}"), (0x1E25, Dt::Canonical, "h\u{323}"),
+        (0x1E26, Dt::Canonical, "H\u{308}"), (0x1E27, Dt::Canonical, "h\u{308}"),
+        (0x1E28, Dt::Canonical, "H\u{327}"), (0x1E29, Dt::Canonical, "h\u{327}"),
+        (0x1E2A, Dt::Canonical, "H\u{32E}"), (0x1E2B, Dt::Canonical, "h\u{32E}"),
+        (0x1E2C, Dt::Canonical, "I\u{330}"), (0x1E2D, Dt::Canonical, "i\u{330}"),
+        (0x1E2E, Dt::Canonical, "\u{CF}\u{301}"), (0x1E2F, Dt::Canonical, "\u{EF}\u{301}"),
+        (0x1E30, Dt::Canonical, "K\u{301}"), (0x1E31, Dt::Canonical, "k\u{301}"),
+        (0x1E32, Dt::Canonical, "K\u{323}"), (0x1E33, Dt::Canonical, "k\u{323}"),
+        (0x1E34, Dt::Canonical, "K\u{331}"), (0x1E35, Dt::Canonical, "k\u{331}"),
+        (0x1E36, Dt::Canonical, "L\u{323}"), (0x1E37, Dt::Canonical, "l\u{323}"),
+        (0x1E38, Dt::Canonical, "\u{1E36}\u{304}"), (0x1E39, Dt::Canonical, "\u{1E37}\u{304}"),
+        (0x1E3A, Dt::Canonical, "L\u{331}"), (0x1E3B, Dt::Canonical, "l\u{331}"),
+        (0x1E3C, Dt::Canonical, "L\u{32D}"), (0x1E3D, Dt::Canonical, "l\u{32D}"),
+        (0x1E3E, Dt::Canonical, "M\u{301}"), (0x1E3F, Dt::Canonical, "m\u{301}"),
+        (0x1E40, Dt::Canonical, "M\u{307}"), (0x1E41, Dt::Canonical, "m\u{307}"),
+        (0x1E42, Dt::Canonical, "M\u{323}"), (0x1E43, Dt::Canonical, "m\u{323}"),
+        (0x1E44, Dt::Canonical, "N\u{307}"), (0x1E45, Dt::Canonical, "n\u{307}"),
+        (0x1E46, Dt::Canonical, "N\u{323}"), (0x1E47, Dt::Canonical, "n\u{323}"),
+        (0x1E48, Dt::Canonical, "N\u{331}"), (0x1E49, Dt::Canonical, "n\u{331}"),
+        (0x1E4A, Dt::Canonical, "N\u{32D}"), (0x1E4B, Dt::Canonical, "n\u{32D}"),
+        (0x1E4C, Dt::Canonical, "\u{D5}\u{301}"), (0x1E4D, Dt::Canonical, "\u{F5}\u{301}"),
+        (0x1E4E, Dt::Canonical, "\u{D5}\u{308}"), (0x1E4F, Dt::Canonical, "\u{F5}\u{308}"),
+        (0x1E50, Dt::Canonical, "\u{14C}\u{300}"), (0x1E51, Dt::Canonical, "\u{14D}\u{300}"),
+        (0x1E52, Dt::Canonical, "\u{14C}\u{301}"), (0x1E53, Dt::Canonical, "\u{14D}\u{301}"),
+        (0x1E54, Dt::Canonical, "P\u{301}"), (0x1E55, Dt::Canonical, "p\u{301}"),
+        (0x1E56, Dt::Canonical, "P\u{307}"), (0x1E57, Dt::Canonical, "p\u{307}"),
+        (0x1E58, Dt::Canonical, "R\u{307}"), (0x1E59, Dt::Canonical, "r\u{307}"),
+        (0x1E5A, Dt::Canonical, "R\u{323}"), (0x1E5B, Dt::Canonical, "r\u{323}"),
+        (0x1E5C, Dt::Canonical, "\u{1E5A}\u{304}"), (0x1E5D, Dt::Canonical, "\u{1E5B}\u{304}"),
+        (0x1E5E, Dt::Canonical, "R\u{331}"), (0x1E5F, Dt::Canonical, "r\u{331}"),
+        (0x1E60, Dt::Canonical, "S\u{307}"), (0x1E61, Dt::Canonical, "s\u{307}"),
+        (0x1E62, Dt::Canonical, "S\u{323}"), (0x1E63, Dt::Canonical, "s\u{323}"),
+        (0x1E64, Dt::Canonical, "\u{15A}\u{307}"), (0x1E65, Dt::Canonical, "\u{15B}\u{307}"),
+        (0x1E66, Dt::Canonical, "\u{160}\u{307}"), (0x1E67, Dt::Canonical, "\u{161}\u{307}"),
+        (0x1E68, Dt::Canonical, "\u{1E62}\u{307}"), (0x1E69, Dt::Canonical, "\u{1E63}\u{307}"),
+        (0x1E6A, Dt::Canonical, "T\u{307}"), (0x1E6B, Dt::Canonical, "t\u{307}"),
+        (0x1E6C, Dt::Canonical, "T\u{323}"), (0x1E6D, Dt::Canonical, "t\u{323}"),
+        (0x1E6E, Dt::Canonical, "T\u{331}"), (0x1E6F, Dt::Canonical, "t\u{331}"),
+        (0x1E70, Dt::Canonical, "T\u{32D}"), (0x1E71, Dt::Canonical, "t\u{32D}"),
+        (0x1E72, Dt::Canonical, "U\u{324}"), (0x1E73, Dt::Canonical, "u\u{324}"),
+        (0x1E74, Dt::Canonical, "U\u{330}"), (0x1E75, Dt::Canonical, "u\u{330}"),
+        (0x1E76, Dt::Canonical, "U\u{32D}"), (0x1E77, Dt::Canonical, "u\u{32D}"),
+        (0x1E78, Dt::Canonical, "\u{168}\u{301}"), (0x1E79, Dt::Canonical, "\u{169}\u{301}"),
+        (0x1E7A, Dt::Canonical, "\u{16A}\u{308}"), (0x1E7B, Dt::Canonical, "\u{16B}\u{308}"),
+        (0x1E7C, Dt::Canonical, "V\u{303}"), (0x1E7D, Dt::Canonical, "v\u{303}"),
+        (0x1E7E, Dt::Canonical, "V\u{323}"), (0x1E7F, Dt::Canonical, "v\u{323}"),
+        (0x1E80, Dt::Canonical, "W\u{300}"), (0x1E81, Dt::Canonical, "w\u{300}"),
+        (0x1E82, Dt::Canonical, "W\u{301}"), (0x1E83, Dt::Canonical, "w\u{301}"),
+        (0x1E84, Dt::Canonical, "W\u{308}"), (0x1E85, Dt::Canonical, "w\u{308}"),
+        (0x1E86, Dt::Canonical, "W\u{307}"), (0x1E87, Dt::Canonical, "w\u{307}"),
+        (0x1E88, Dt::Canonical, "W\u{323}"), (0x1E89, Dt::Canonical, "w\u{323}"),
+        (0x1E8A, Dt::Canonical, "X\u{307}"), (0x1E8B, Dt::Canonical, "x\u{307}"),
+        (0x1E8C, Dt::Canonical, "X\u{308}"), (0x1E8D, Dt::Canonical, "x\u{308}"),
+        (0x1E8E, Dt::Canonical, "Y\u{307}"), (0x1E8F, Dt::Canonical, "y\u{307}"),
+        (0x1E90, Dt::Canonical, "Z\u{302}"), (0x1E91, Dt::Canonical, "z\u{302}"),
+        (0x1E92, Dt::Canonical, "Z\u{323}"), (0x1E93, Dt::Canonical, "z\u{323}"),
+        (0x1E94, Dt::Canonical, "Z\u{331}"), (0x1E95, Dt::Canonical, "z\u{331}"),
+        (0x1E96, Dt::Canonical, "h\u{331}"), (0x1E97, Dt::Canonical, "t\u{308}"),
+        (0x1E98, Dt::Canonical, "w\u{30A}"), (0x1E99, Dt::Canonical, "y\u{30A}"),
+        (0x1E9A, Dt::Compat, "a\u{2BE}"), (0x1E9B, Dt::Canonical, "\u{17F}\u{307}"),
+        (0x1EA0, Dt::Canonical, "A\u{323}"), (0x1EA1, Dt::Canonical, "a\u{323}"),
+        (0x1EA2, Dt::Canonical, "A\u{309}"), (0x1EA3, Dt::Canonical, "a\u{309}"),
+        (0x1EA4, Dt::Canonical, "\u{C2}\u{301}"), (0x1EA5, Dt::Canonical, "\u{E2}\u{301}"),
+        (0x1EA6, Dt::Canonical, "\u{C2}\u{300}"), (0x1EA7, Dt::Canonical, "\u{E2}\u{300}"),
+        (0x1EA8, Dt::Canonical, "\u{C2}\u{309}"), (0x1EA9, Dt::Canonical, "\u{E2}\u{309}"),
+        (0x1EAA, Dt::Canonical, "\u{C2}\u{303}"), (0x1EAB, Dt::Canonical, "\u{E2}\u{303}"),
+        (0x1EAC, Dt::Canonical, "\u{1EA0}\u{302}"), (0x1EAD, Dt::Canonical, "\u{1EA1}\u{302}"),
+        (0x1EAE, Dt::Canonical, "\u{102}\u{301}"), (0x1EAF, Dt::Canonical, "\u{103}\u{301}"),
+        (0x1EB0, Dt::Canonical, "\u{102}\u{300}"), (0x1EB1, Dt::Canonical, "\u{103}\u{300}"),
+        (0x1EB2, Dt::Canonical, "\u{102}\u{309}"), (0x1EB3, Dt::Canonical, "\u{103}\u{309}"),
+        (0x1EB4, Dt::Canonical, "\u{102}\u{303}"), (0x1EB5, Dt::Canonical, "\u{103}\u{303}"),
+        (0x1EB6, Dt::Canonical, "\u{1EA0}\u{306}"), (0x1EB7, Dt::Canonical, "\u{1EA1}\u{306}"),
+        (0x1EB8, Dt::Canonical, "E\u{323}"), (0x1EB9, Dt::Canonical, "e\u{323}"),
+        (0x1EBA, Dt::Canonical, "E\u{309}"), (0x1EBB, Dt::Canonical, "e\u{309}"),
+        (0x1EBC, Dt::Canonical, "E\u{303}"), (0x1EBD, Dt::Canonical, "e\u{303}"),
+        (0x1EBE, Dt::Canonical, "\u{CA}\u{301}"), (0x1EBF, Dt::Canonical, "\u{EA}\u{301}"),
+        (0x1EC0, Dt::Canonical, "\u{CA}\u{300}"), (0x1EC1, Dt::Canonical, "\u{EA}\u{300}"),
+        (0x1EC2, Dt::Canonical, "\u{CA}\u{309}"), (0x1EC3, Dt::Canonical, "\u{EA}\u{309}"),
+        (0x1EC4, Dt::Canonical, "\u{CA}\u{303}"), (0x1EC5, Dt::Canonical, "\u{EA}\u{303}"),
+        (0x1EC6, Dt::Canonical, "\u{1EB8}\u{302}"), (0x1EC7, Dt::Canonical, "\u{1EB9}\u{302}"),
+        (0x1EC8, Dt::Canonical, "I\u{309}"), (0x1EC9, Dt::Canonical, "i\u{309}"),
+        (0x1ECA, Dt::Canonical, "I\u{323}"), (0x1ECB, Dt::Canonical, "i\u{323}"),
+        (0x1ECC, Dt::Canonical, "O\u{323}"), (0x1ECD, Dt::Canonical, "o\u{323}"),
+        (0x1ECE, Dt::Canonical, "O\u{309}"), (0x1ECF, Dt::Canonical, "o\u{309}"),
+        (0x1ED0, Dt::Canonical, "\u{D4}\u{301}"), (0x1ED1, Dt::Canonical, "\u{F4}\u{301}"),
+        (0x1ED2, Dt::Canonical, "\u{D4}\u{300}"), (0x1ED3, Dt::Canonical, "\u{F4}\u{300}"),
+        (0x1ED4, Dt::Canonical, "\u{D4}\u{309}"), (0x1ED5, Dt::Canonical, "\u{F4}\u{309}"),
+        (0x1ED6, Dt::Canonical, "\u{D4}\u{303}"), (0x1ED7, Dt::Canonical, "\u{F4}\u{303}"),
+        (0x1ED8, Dt::Canonical, "\u{1ECC}\u{302}"), (0x1ED9, Dt::Canonical, "\u{1ECD}\u{302}"),
+        (0x1EDA, Dt::Canonical, "\u{1A0}\u{301}"), (0x1EDB, Dt::Canonical, "\u{1A1}\u{301}"),
+        (0x1EDC, Dt::Canonical, "\u{1A0}\u{300}"), (0x1EDD, Dt::Canonical, "\u{1A1}\u{300}"),
+        (0x1EDE, Dt::Canonical, "\u{1A0}\u{309}"), (0x1EDF, Dt::Canonical, "\u{1A1}\u{309}"),
+        (0x1EE0, Dt::Canonical, "\u{1A0}\u{303}"), (0x1EE1, Dt::Canonical, "\u{1A1}\u{303}"),
+        (0x1EE2, Dt::Canonical, "\u{1A0}\u{323}"), (0x1EE3, Dt::Canonical, "\u{1A1}\u{323}"),
+        (0x1EE4, Dt::Canonical, "U\u{323}"), (0x1EE5, Dt::Canonical, "u\u{323}"),
+        (0x1EE6, Dt::Canonical, "U\u{309}"), (0x1EE7, Dt::Canonical, "u\u{309}"),
+        (0x1EE8, Dt::Canonical, "\u{1AF}\u{301}"), (0x1EE9, Dt::Canonical, "\u{1B0}\u{301}"),
+        (0x1EEA, Dt::Canonical, "\u{1AF}\u{300}"), (0x1EEB, Dt::Canonical, "\u{1B0}\u{300}"),
+        (0x1EEC, Dt::Canonical, "\u{1AF}\u{309}"), (0x1EED, Dt::Canonical, "\u{1B0}\u{309}"),
+        (0x1EEE, Dt::Canonical, "\u{1AF}\u{303}"), (0x1EEF, Dt::Canonical, "\u{1B0}\u{303}"),
+        (0x1EF0, Dt::Canonical, "\u{1AF}\u{323}"), (0x1EF1, Dt::Canonical, "\u{1B0}\u{323}"),
+        (0x1EF2, Dt::Canonical, "Y\u{300}"), (0x1EF3, Dt::Canonical, "y\u{300}"),
+        (0x1EF4, Dt::Canonical, "Y\u{323}"), (0x1EF5, Dt::Canonical, "y\u{323}"),
+        (0x1EF6, Dt::Canonical, "Y\u{309}"), (0x1EF7, Dt::Canonical, "y\u{309}"),
+        (0x1EF8, Dt::Canonical, "Y\u{303}"), (0x1EF9, Dt::Canonical, "y\u{303}"),
+        (0x1F00, Dt::Canonical, "\u{3B1}\u{313}"), (0x1F01, Dt::Canonical, "\u{3B1}\u{314}"),
+        (0x1F02, Dt::Canonical, "\u{1F00}\u{300}"), (0x1F03, Dt::Canonical, "\u{1F01}\u{300}"),
+        (0x1F04, Dt::Canonical, "\u{1F00}\u{301}"), (0x1F05, Dt::Canonical, "\u{1F01}\u{301}"),
+        (0x1F06, Dt::Canonical, "\u{1F00}\u{342}"), (0x1F07, Dt::Canonical, "\u{1F01}\u{342}"),
+        (0x1F08, Dt::Canonical, "\u{391}\u{313}"), (0x1F09, Dt::Canonical, "\u{391}\u{314}"),
+        (0x1F0A, Dt::Canonical, "\u{1F08}\u{300}"), (0x1F0B, Dt::Canonical, "\u{1F09}\u{300}"),
+        (0x1F0C, Dt::Canonical, "\u{1F08}\u{301}"), (0x1F0D, Dt::Canonical, "\u{1F09}\u{301}"),
+        (0x1F0E, Dt::Canonical, "\u{1F08}\u{342}"), (0x1F0F, Dt::Canonical, "\u{1F09}\u{342}"),
+        (0x1F10, Dt::Canonical, "\u{3B5}\u{313}"), (0x1F11, Dt::Canonical, "\u{3B5}\u{314}"),
+        (0x1F12, Dt::Canonical, "\u{1F10}\u{300}"), (0x1F13, Dt::Canonical, "\u{1F11}\u{300}"),
+        (0x1F14, Dt::Canonical, "\u{1F10}\u{301}"), (0x1F15, Dt::Canonical, "\u{1F11}\u{301}"),
+        (0x1F18, Dt::Canonical, "\u{395}\u{313}"), (0x1F19, Dt::Canonical, "\u{395}\u{314}"),
+        (0x1F1A, Dt::Canonical, "\u{1F18}\u{300}"), (0x1F1B, Dt::Canonical, "\u{1F19}\u{300}"),
+        (0x1F1C, Dt::Canonical, "\u{1F18}\u{301}"), (0x1F1D, Dt::Canonical, "\u{1F19}\u{301}"),
+        (0x1F20, Dt::Canonical, "\u{3B7}\u{313}"), (0x1F21, Dt::Canonical, "\u{3B7}\u{314}"),
+        (0x1F22, Dt::Canonical, "\u{1F20}\u{300}"), (0x1F23, Dt::Canonical, "\u{1F21}\u{300}"),
+        (0x1F24, Dt::Canonical, "\u{1F20}\u{301}"), (0x1F25, Dt::Canonical, "\u{1F21}\u{301}"),
+        (0x1F26, Dt::Canonical, "\u{1F20}\u{342}"), (0x1F27, Dt::Canonical, "\u{1F21}\u{342}"),
+        (0x1F28, Dt::Canonical, "\u{397}\u{313}"), (0x1F29, Dt::Canonical, "\u{397}\u{314}"),
+        (0x1F2A, Dt::Canonical, "\u{1F28}\u{300}"), (0x1F2B, Dt::Canonical, "\u{1F29}\u{300}"),
+        (0x1F2C, Dt::Canonical, "\u{1F28}\u{301}"), (0x1F2D, Dt::Canonical, "\u{1F29}\u{301}"),
+        (0x1F2E, Dt::Canonical, "\u{1F28}\u{342}"), (0x1F2F, Dt::Canonical, "\u{1F29}\u{342}"),
+        (0x1F30, Dt::Canonical, "\u{3B9}\u{313}"), (0x1F31, Dt::Canonical, "\u{3B9}\u{314}"),
+        (0x1F32, Dt::Canonical, "\u{1F30}\u{300}"), (0x1F33, Dt::Canonical, "\u{1F31}\u{300}"),
+        (0x1F34, Dt::Canonical, "\u{1F30}\u{301}"), (0x1F35, Dt::Canonical, "\u{1F31}\u{301}"),
+        (0x1F36, Dt::Canonical, "\u{1F30}\u{342}"), (0x1F37, Dt::Canonical, "\u{1F31}\u{342}"),
+        (0x1F38, Dt::Canonical, "\u{399}\u{313}"), (0x1F39, Dt::Canonical, "\u{399}\u{314}"),
+        (0x1F3A, Dt::Canonical, "\u{1F38}\u{300}"), (0x1F3B, Dt::Canonical, "\u{1F39}\u{300}"),
+        (0x1F3C, Dt::Canonical, "\u{1F38}\u{301}"), (0x1F3D, Dt::Canonical, "\u{1F39}\u{301}"),
+        (0x1F3E, Dt::Canonical, "\u{1F38}\u{342}"), (0x1F3F, Dt::Canonical, "\u{1F39}\u{342}"),
+        (0x1F40, Dt::Canonical, "\u{3BF}\u{313}"), (0x1F41, Dt::Canonical, "\u{3BF}\u{314}"),
+        (0x1F42, Dt::Canonical, "\u{1F40}\u{300}"), (0x1F43, Dt::Canonical, "\u{1F41}\u{300}"),
+        (0x1F44, Dt::Canonical, "\u{1F40}\u{301}"), (0x1F45, Dt::Canonical, "\u{1F41}\u{301}"),
+        (0x1F48, Dt::Canonical, "\u{39F}\u{313}"), (0x1F49, Dt::Canonical, "\u{39F}\u{314}"),
+        (0x1F4A, Dt::Canonical, "\u{1F48}\u{300}"), (0x1F4B, Dt::Canonical, "\u{1F49}\u{300}"),
+        (0x1F4C, Dt::Canonical, "\u{1F48}\u{301}"), (0x1F4D, Dt::Canonical, "\u{1F49}\u{301}"),
+        (0x1F50, Dt::Canonical, "\u{3C5}\u{313}"), (0x1F51, Dt::Canonical, "\u{3C5}\u{314}"),
+        (0x1F52, Dt::Canonical, "\u{1F50}\u{300}"), (0x1F53, Dt::Canonical, "\u{1F51}\u{300}"),
+        (0x1F54, Dt::Canonical, "\u{1F50}\u{301}"), (0x1F55, Dt::Canonical, "\u{1F51}\u{301}"),
+        (0x1F56, Dt::Canonical, "\u{1F50}\u{342}"), (0x1F57, Dt::Canonical, "\u{1F51}\u{342}"),
+        (0x1F59, Dt::Canonical, "\u{3A5}\u{314}"), (0x1F5B, Dt::Canonical, "\u{1F59}\u{300}"),
+        (0x1F5D, Dt::Canonical, "\u{1F59}\u{301}"), (0x1F5F, Dt::Canonical, "\u{1F59}\u{342}"),
+        (0x1F60, Dt::Canonical, "\u{3C9}\u{313}"), (0x1F61, Dt::Canonical, "\u{3C9}\u{314}"),
+        (0x1F62, Dt::Canonical, "\u{1F60}\u{300}"), (0x1F63, Dt::Canonical, "\u{1F61}\u{300}"),
+        (0x1F64, Dt::Canonical, "\u{1F60}\u{301}"), (0x1F65, Dt::Canonical, "\u{1F61}\u{301}"),
+        (0x1F66, Dt::Canonical, "\u{1F60}\u{342}"), (0x1F67, Dt::Canonical, "\u{1F61}\u{342}"),
+        (0x1F68, Dt::Canonical, "\u{3A9}\u{313}"), (0x1F69, Dt::Canonical, "\u{3A9}\u{314}"),
+        (0x1F6A, Dt::Canonical, "\u{1F68}\u{300}"), (0x1F6B, Dt::Canonical, "\u{1F69}\u{300}"),
+        (0x1F6C, Dt::Canonical, "\u{1F68}\u{301}"), (0x1F6D, Dt::Canonical, "\u{1F69}\u{301}"),
+        (0x1F6E, Dt::Canonical, "\u{1F68}\u{342}"), (0x1F6F, Dt::Canonical, "\u{1F69}\u{342}"),
+        (0x1F70, Dt::Canonical, "\u{3B1}\u{300}"), (0x1F71, Dt::Canonical, "\u{3AC}"),
+        (0x1F72, Dt::Canonical, "\u{3B5}\u{300}"), (0x1F73, Dt::Canonical, "\u{3AD}"),
+        (0x1F74, Dt::Canonical, "\u{3B7}\u{300}"), (0x1F75, Dt::Canonical, "\u{3AE}"),
+        (0x1F76, Dt::Canonical, "\u{3B9}\u{300}"), (0x1F77, Dt::Canonical, "\u{3AF}"),
+        (0x1F78, Dt::Canonical, "\u{3BF}\u{300}"), (0x1F79, Dt::Canonical, "\u{3CC}"),
+        (0x1F7A, Dt::Canonical, "\u{3C5}\u{300}"), (0x1F7B, Dt::Canonical, "\u{3CD}"),
+        (0x1F7C, Dt::Canonical, "\u{3C9}\u{300}"), (0x1F7D, Dt::Canonical, "\u{3CE}"),
+        (0x1F80, Dt::Canonical, "\u{1F00}\u{345}"), (0x1F81, Dt::Canonical, "\u{1F01}\u{345}"),
+        (0x1F82, Dt::Canonical, "\u{1F02}\u{345}"), (0x1F83, Dt::Canonical, "\u{1F03}\u{345}"),
+        (0x1F84, Dt::Canonical, "\u{1F04}\u{345}"), (0x1F85, Dt::Canonical, "\u{1F05}\u{345}"),
+        (0x1F86, Dt::Canonical, "\u{1F06}\u{345}"), (0x1F87, Dt::Canonical, "\u{1F07}\u{345}"),
+        (0x1F88, Dt::Canonical, "\u{1F08}\u{345}"), (0x1F89, Dt::Canonical, "\u{1F09}\u{345}"),
+        (0x1F8A, Dt::Canonical, "\u{1F0A}\u{345}"), (0x1F8B, Dt::Canonical, "\u{1F0B}\u{345}"),
+        (0x1F8C, Dt::Canonical, "\u{1F0C}\u{345}"), (0x1F8D, Dt::Canonical, "\u{1F0D}\u{345}"),
+        (0x1F8E, Dt::Canonical, "\u{1F0E}\u{345}"), (0x1F8F, Dt::Canonical, "\u{1F0F}\u{345}"),
+        (0x1F90, Dt::Canonical, "\u{1F20}\u{345}"), (0x1F91, Dt::Canonical, "\u{1F21}\u{345}"),
+        (0x1F92, Dt::Canonical, "\u{1F22}\u{345}"), (0x1F93, Dt::Canonical, "\u{1F23}\u{345}"),
+        (0x1F94, Dt::Canonical, "\u{1F24}\u{345}"), (0x1F95, Dt::Canonical, "\u{1F25}\u{345}"),
+        (0x1F96, Dt::Canonical, "\u{1F26}\u{345}"), (0x1F97, Dt::Canonical, "\u{1F27}\u{345}"),
+        (0x1F98, Dt::Canonical, "\u{1F28}\u{345}"), (0x1F99, Dt::Canonical, "\u{1F29}\u{345}"),
+        (0x1F9A, Dt::Canonical, "\u{1F2A}\u{345}"), (0x1F9B, Dt::Canonical, "\u{1F2B}\u{345}"),
+        (0x1F9C, Dt::Canonical, "\u{1F2C}\u{345}"), (0x1F9D, Dt::Canonical, "\u{1F2D}\u{345}"),
+        (0x1F9E, Dt::Canonical, "\u{1F2E}\u{345}"), (0x1F9F, Dt::Canonical, "\u{1F2F}\u{345}"),
+        (0x1FA0, Dt::Canonical, "\u{1F60}\u{345}"), (0x1FA1, Dt::Canonical, "\u{1F61}\u{345}"),
+        (0x1FA2, Dt::Canonical, "\u{1F62}\u{345}"), (0x1FA3, Dt::Canonical, "\u{1F63}\u{345}"),
+        (0x1FA4, Dt::Canonical, "\u{1F64}\u{345}"), (0x1FA5, Dt::Canonical, "\u{1F65}\u{345}"),
+        (0x1FA6, Dt::Canonical, "\u{1F66}\u{345}"), (0x1FA7, Dt::Canonical, "\u{1F67}\u{345}"),
+        (0x1FA8, Dt::Canonical, "\u{1F68}\u{345}"), (0x1FA9, Dt::Canonical, "\u{1F69}\u{345}"),
+        (0x1FAA, Dt::Canonical, "\u{1F6A}\u{345}"), (0x1FAB, Dt::Canonical, "\u{1F6B}\u{345}"),
+        (0x1FAC, Dt::Canonical, "\u{1F6C}\u{345}"), (0x1FAD, Dt::Canonical, "\u{1F6D}\u{345}"),
+        (0x1FAE, Dt::Canonical, "\u{1F6E}\u{345}"), (0x1FAF, Dt::Canonical, "\u{1F6F}\u{345}"),
+        (0x1FB0, Dt::Canonical, "\u{3B1}\u{306}"), (0x1FB1, Dt::Canonical, "\u{3B1}\u{304}"),
+        (0x1FB2, Dt::Canonical, "\u{1F70}\u{345}"), (0x1FB3, Dt::Canonical, "\u{3B1}\u{345}"),
+        (0x1FB4, Dt::Canonical, "\u{3AC}\u{345}"), (0x1FB6, Dt::Canonical, "\u{3B1}\u{342}"),
+        (0x1FB7, Dt::Canonical, "\u{1FB6}\u{345}"), (0x1FB8, Dt::Canonical, "\u{391}\u{306}"),
+        (0x1FB9, Dt::Canonical, "\u{391}\u{304}"), (0x1FBA, Dt::Canonical, "\u{391}\u{300}"),
+        (0x1FBB, Dt::Canonical, "\u{386}"), (0x1FBC, Dt::Canonical, "\u{391}\u{345}"),
+        (0x1FBD, Dt::Compat, " \u{313}"), (0x1FBE, Dt::Canonical, "\u{3B9}"),
+        (0x1FBF, Dt::Compat, " \u{313}"), (0x1FC0, Dt::Compat, " \u{342}"),
+        (0x1FC1, Dt::Canonical, "\u{A8}\u{342}"), (0x1FC2, Dt::Canonical, "\u{1F74}\u{345}"),
+        (0x1FC3, Dt::Canonical, "\u{3B7}\u{345}"), (0x1FC4, Dt::Canonical, "\u{3AE}\u{345}"),
+        (0x1FC6, Dt::Canonical, "\u{3B7}\u{342}"), (0x1FC7, Dt::Canonical, "\u{1FC6}\u{345}"),
+        (0x1FC8, Dt::Canonical, "\u{395}\u{300}"), (0x1FC9, Dt::Canonical, "\u{388}"),
+        (0x1FCA, Dt::Canonical, "\u{397}\u{300}"), (0x1FCB, Dt::Canonical, "\u{389}"),
+        (0x1FCC, Dt::Canonical, "\u{397}\u{345}"), (0x1FCD, Dt::Canonical, "\u{1FBF}\u{300}"),
+        (0x1FCE, Dt::Canonical, "\u{1FBF}\u{301}"), (0x1FCF, Dt::Canonical, "\u{1FBF}\u{342}"),
+        (0x1FD0, Dt::Canonical, "\u{3B9}\u{306}"), (0x1FD1, Dt::Canonical, "\u{3B9}\u{304}"),
+        (0x1FD2, Dt::Canonical, "\u{3CA}\u{300}"), (0x1FD3, Dt::Canonical, "\u{390}"),
+        (0x1FD6, Dt::Canonical, "\u{3B9}\u{342}"), (0x1FD7, Dt::Canonical, "\u{3CA}\u{342}"),
+        (0x1FD8, Dt::Canonical, "\u{399}\u{306}"), (0x1FD9, Dt::Canonical, "\u{399}\u{304}"),
+        (0x1FDA, Dt::Canonical, "\u{399}\u{300}"), (0x1FDB, Dt::Canonical, "\u{38A}"),
+        (0x1FDD, Dt::Canonical, "\u{1FFE}\u{300}"), (0x1FDE, Dt::Canonical, "\u{1FFE}\u{301}"),
+        (0x1FDF, Dt::Canonical, "\u{1FFE}\u{342}"), (0x1FE0, Dt::Canonical, "\u{3C5}\u{306}"),
+        (0x1FE1, Dt::Canonical, "\u{3C5}\u{304}"), (0x1FE2, Dt::Canonical, "\u{3CB}\u{300}"),
+        (0x1FE3, Dt::Canonical, "\u{3B0}"), (0x1FE4, Dt::Canonical, "\u{3C1}\u{313}"),
+        (0x1FE5, Dt::Canonical, "\u{3C1}\u{314}"), (0x1FE6, Dt::Canonical, "\u{3C5}\u{342}"),
+        (0x1FE7, Dt::Canonical, "\u{3CB}\u{342}"), (0x1FE8, Dt::Canonical, "\u{3A5}\u{306}"),
+        (0x1FE9, Dt::Canonical, "\u{3A5}\u{304}"), (0x1FEA, Dt::Canonical, "\u{3A5}\u{300}"),
+        (0x1FEB, Dt::Canonical, "\u{38E}"), (0x1FEC, Dt::Canonical, "\u{3A1}\u{314}"),
+        (0x1FED, Dt::Canonical, "\u{A8}\u{300}"), (0x1FEE, Dt::Canonical, "\u{385}"),
+        (0x1FEF, Dt::Canonical, "`"), (0x1FF2, Dt::Canonical, "\u{1F7C}\u{345}"),
+        (0x1FF3, Dt::Canonical, "\u{3C9}\u{345}"), (0x1FF4, Dt::Canonical, "\u{3CE}\u{345}"),
+        (0x1FF6, Dt::Canonical, "\u{3C9}\u{342}"), (0x1FF7, Dt::Canonical, "\u{1FF6}\u{345}"),
+        (0x1FF8, Dt::Canonical, "\u{39F}\u{300}"), (0x1FF9, Dt::Canonical, "\u{38C}"),
+        (0x1FFA, Dt::Canonical, "\u{3A9}\u{300}"), (0x1FFB, Dt::Canonical, "\u{38F}"),
+        (0x1FFC, Dt::Canonical, "\u{3A9}\u{345}"), (0x1FFD, Dt::Canonical, "\u{B4}"),
+        (0x1FFE, Dt::Compat, " \u{314}"), (0x2000, Dt::Canonical, "\u{2002}"),
+        (0x2001, Dt::Canonical, "\u{2003}"), (0x2002, Dt::Compat, " "), (0x2003, Dt::Compat, " "),
+        (0x2004, Dt::Compat, " "), (0x2005, Dt::Compat, " "), (0x2006, Dt::Compat, " "),
+        (0x2007, Dt::Nobreak, " "), (0x2008, Dt::Compat, " "), (0x2009, Dt::Compat, " "),
+        (0x200A, Dt::Compat, " "), (0x2011, Dt::Nobreak, "\u{2010}"),
+        (0x2017, Dt::Compat, " \u{333}"), (0x2024, Dt::Compat, "."), (0x2025, Dt::Compat, ".."),
+        (0x2026, Dt::Compat, "..."), (0x202F, Dt::Nobreak, " "),
+        (0x2033, Dt::Compat, "\u{2032}\u{2032}"), (0x2034, Dt::Compat, "\u{2032}\u{2032}\u{2032}"),
+        (0x2036, Dt::Compat, "\u{2035}\u{2035}"), (0x2037, Dt::Compat, "\u{2035}\u{2035}\u{2035}"),
+        (0x203C, Dt::Compat, "!!"), (0x203E, Dt::Compat, " \u{305}"), (0x2047, Dt::Compat, "??"),
+        (0x2048, Dt::Compat, "?!"), (0x2049, Dt::Compat, "!?"),
+        (0x2057, Dt::Compat, "\u{2032}\u{2032}\u{2032}\u{2032}"), (0x205F, Dt::Compat, " "),
+        (0x2070, Dt::Super, "0"), (0x2071, Dt::Super, "i"), (0x2074, Dt::Super, "4"),
+        (0x2075, Dt::Super, "5"), (0x2076, Dt::Super, "6"), (0x2077, Dt::Super, "7"),
+        (0x2078, Dt::Super, "8"), (0x2079, Dt::Super, "9"), (0x207A, Dt::Super, "+"),
+        (0x207B, Dt::Super, "\u{2212}"), (0x207C, Dt::Super, "="), (0x207D, Dt::Super, "("),
+        (0x207E, Dt::Super, ")"), (0x207F, Dt::Super, "n"), (0x2080, Dt::Sub, "0"),
+        (0x2081, Dt::Sub, "1"), (0x2082, Dt::Sub, "2"), (0x2083, Dt::Sub, "3"),
+        (0x2084, Dt::Sub, "4"), (0x2085, Dt::Sub, "5"), (0x2086, Dt::Sub, "6"),
+        (0x2087, Dt::Sub, "7"), (0x2088, Dt::Sub, "8"), (0x2089, Dt::Sub, "9"),
+        (0x208A, Dt::Sub, "+"), (0x208B, Dt::Sub, "\u{2212}"), (0x208C, Dt::Sub, "="),
+        (0x208D, Dt::Sub, "("), (0x208E, Dt::Sub, ")"), (0x2090, Dt::Sub, "a"),
+        (0x2091, Dt::Sub, "e"), (0x2092, Dt::Sub, "o"), (0x2093, Dt::Sub, "x"),
+        (0x2094, Dt::Sub, "\u{259}"), (0x2095, Dt::Sub, "h"), (0x2096, Dt::Sub, "k"),
+        (0x2097, Dt::Sub, "l"), (0x2098, Dt::Sub, "m"), (0x2099, Dt::Sub, "n"),
+        (0x209A, Dt::Sub, "p"), (0x209B, Dt::Sub, "s"), (0x209C, Dt::Sub, "t"),
+        (0x20A8, Dt::Compat, "Rs"), (0x2100, Dt::Compat, "a/c"), (0x2101, Dt::Compat, "a/s"),
+        (0x2102, Dt::Font, "C"), (0x2103, Dt::Compat, "\u{B0}C"), (0x2105, Dt::Compat, "c/o"),
+        (0x2106, Dt::Compat, "c/u"), (0x2107, Dt::Compat, "\u{190}"),
+        (0x2109, Dt::Compat, "\u{B0}F"), (0x210A, Dt::Font, "g"), (0x210B, Dt::Font, "H"),
+        (0x210C, Dt::Font, "H"), (0x210D, Dt::Font, "H"), (0x210E, Dt::Font, "h"),
+        (0x210F, Dt::Font, "\u{127}"), (0x2110, Dt::Font, "I"), (0x2111, Dt::Font, "I"),
+        (0x2112, Dt::Font, "L"), (0x2113, Dt::Font, "l"), (0x2115, Dt::Font, "N"),
+        (0x2116, Dt::Compat, "No"), (0x2119, Dt::Font, "P"), (0x211A, Dt::Font, "Q"),
+        (0x211B, Dt::Font, "R"), (0x211C, Dt::Font, "R"), (0x211D, Dt::Font, "R"),
+        (0x2120, Dt::Super, "SM"), (0x2121, Dt::Compat, "TEL"), (0x2122, Dt::Super, "TM"),
+        (0x2124, Dt::Font, "Z"), (0x2126, Dt::Canonical, "\u{3A9}"), (0x2128, Dt::Font, "Z"),
+        (0x212A, Dt::Canonical, "K"), (0x212B, Dt::Canonical, "\u{C5}"), (0x212C, Dt::Font, "B"),
+        (0x212D, Dt::Font, "C"), (0x212F, Dt::Font, "e"), (0x2130, Dt::Font, "E"),
+        (0x2131, Dt::Font, "F"), (0x2133, Dt::Font, "M"), (0x2134, Dt::Font, "o"),
+        (0x2135, Dt::Compat, "\u{5D0}"), (0x2136, Dt::Compat, "\u{5D1}"),
+        (0x2137, Dt::Compat, "\u{5D2}"), (0x2138, Dt::Compat, "\u{5D3}"), (0x2139, Dt::Font, "i"),
+        (0x213B, Dt::Compat, "FAX"), (0x213C, Dt::Font, "\u{3C0}"), (0x213D, Dt::Font, "\u{3B3}"),
+        (0x213E, Dt::Font, "\u{393}"), (0x213F, Dt::Font, "\u{3A0}"),
+        (0x2140, Dt::Font, "\u{2211}"), (0x2145, Dt::Font, "D"), (0x2146, Dt::Font, "d"),
+        (0x2147, Dt::Font, "e"), (0x2148, Dt::Font, "i"), (0x2149, Dt::Font, "j"),
+        (0x2150, Dt::Fraction, "1\u{2044}7"), (0x2151, Dt::Fraction, "1\u{2044}9"),
+        (0x2152, Dt::Fraction, "1\u{2044}10"), (0x2153, Dt::Fraction, "1\u{2044}3"),
+        (0x2154, Dt::Fraction, "2\u{2044}3"), (0x2155, Dt::Fraction, "1\u{2044}5"),
+        (0x2156, Dt::Fraction, "2\u{2044}5"), (0x2157, Dt::Fraction, "3\u{2044}5"),
+        (0x2158, Dt::Fraction, "4\u{2044}5"), (0x2159, Dt::Fraction, "1\u{2044}6"),
+        (0x215A, Dt::Fraction, "5\u{2044}6"), (0x215B, Dt::Fraction, "1\u{2044}8"),
+        (0x215C, Dt::Fraction, "3\u{2044}8"), (0x215D, Dt::Fraction, "5\u{2044}8"),
+        (0x215E, Dt::Fraction, "7\u{2044}8"), (0x215F, Dt::Fraction, "1\u{2044}"),
+        (0x2160, Dt::Compat, "I"), (0x2161, Dt::Compat, "II"), (0x2162, Dt::Compat, "III"),
+        (0x2163, Dt::Compat, "IV"), (0x2164, Dt::Compat, "V"), (0x2165, Dt::Compat, "VI"),
+        (0x2166, Dt::Compat, "VII"), (0x2167, Dt::Compat, "VIII"), (0x2168, Dt::Compat, "IX"),
+        (0x2169, Dt::Compat, "X"), (0x216A, Dt::Compat, "XI"), (0x216B, Dt::Compat, "XII"),
+        (0x216C, Dt::Compat, "L"), (0x216D, Dt::Compat, "C"), (0x216E, Dt::Compat, "D"),
+        (0x216F, Dt::Compat, "M"), (0x2170, Dt::Compat, "i"), (0x2171, Dt::Compat, "ii"),
+        (0x2172, Dt::Compat, "iii"), (0x2173, Dt::Compat, "iv"), (0x2174, Dt::Compat, "v"),
+        (0x2175, Dt::Compat, "vi"), (0x2176, Dt::Compat, "vii"), (0x2177, Dt::Compat, "viii"),
+        (0x2178, Dt::Compat, "ix"), (0x2179, Dt::Compat, "x"), (0x217A, Dt::Compat, "xi"),
+        (0x217B, Dt::Compat, "xii"), (0x217C, Dt::Compat, "l"), (0x217D, Dt::Compat, "c"),
+        (0x217E, Dt::Compat, "d"), (0x217F, Dt::Compat, "m"), (0x2189, Dt::Fraction, "0\u{2044}3"),
+        (0x219A, Dt::Canonical, "\u{2190}\u{338}"), (0x219B, Dt::Canonical, "\u{2192}\u{338}"),
+        (0x21AE, Dt::Canonical, "\u{2194}\u{338}"), (0x21CD, Dt::Canonical, "\u{21D0}\u{338}"),
+        (0x21CE, Dt::Canonical, "\u{21D4}\u{338}"), (0x21CF, Dt::Canonical, "\u{21D2}\u{338}"),
+        (0x2204, Dt::Canonical, "\u{2203}\u{338}"), (0x2209, Dt::Canonical, "\u{2208}\u{338}"),
+        (0x220C, Dt::Canonical, "\u{220B}\u{338}"), (0x2224, Dt::Canonical, "\u{2223}\u{338}"),
+        (0x2226, Dt::Canonical, "\u{2225}\u{338}"), (0x222C, Dt::Compat, "\u{222B}\u{222B}"),
+        (0x222D, Dt::Compat, "\u{222B}\u{222B}\u{222B}"), (0x222F, Dt::Compat, "\u{222E}\u{222E}"),
+        (0x2230, Dt::Compat, "\u{222E}\u{222E}\u{222E}"),
+        (0x2241, Dt::Canonical, "\u{223C}\u{338}"), (0x2244, Dt::Canonical, "\u{2243}\u{338}"),
+        (0x2247, Dt::Canonical, "\u{2245}\u{338}"), (0x2249, Dt::Canonical, "\u{2248}\u{338}"),
+        (0x2260, Dt::Canonical, "=\u{338}"), (0x2262, Dt::Canonical, "\u{2261}\u{338}"),
+        (0x226D, Dt::Canonical, "\u{224D}\u{338}"), (0x226E, Dt::Canonical, "<\u{338}"),
+        (0x226F, Dt::Canonical, ">\u{338}"), (0x2270, Dt::Canonical, "\u{2264}\u{338}"),
+        (0x2271, Dt::Canonical, "\u{2265}\u{338}"), (0x2274, Dt::Canonical, "\u{2272}\u{338}"),
+        (0x2275, Dt::Canonical, "\u{2273}\u{338}"), (0x2278, Dt::Canonical, "\u{2276}\u{338}"),
+        (0x2279, Dt::Canonical, "\u{2277}\u{338}"), (0x2280, Dt::Canonical, "\u{227A}\u{338}"),
+        (0x2281, Dt::Canonical, "\u{227B}\u{338}"), (0x2284, Dt::Canonical, "\u{2282}\u{338}"),
+        (0x2285, Dt::Canonical, "\u{2283}\u{338}"), (0x2288, Dt::Canonical, "\u{2286}\u{338}"),
+        (0x2289, Dt::Canonical, "\u{2287}\u{338}"), (0x22AC, Dt::Canonical, "\u{22A2}\u{338}"),
+        (0x22AD, Dt::Canonical, "\u{22A8}\u{338}"), (0x22AE, Dt::Canonical, "\u{22A9}\u{338}"),
+        (0x22AF, Dt::Canonical, "\u{22AB}\u{338}"), (0x22E0, Dt::Canonical, "\u{227C}\u{338}"),
+        (0x22E1, Dt::Canonical, "\u{227D}\u{338}"), (0x22E2, Dt::Canonical, "\u{2291}\u{338}"),
+        (0x22E3, Dt::Canonical, "\u{2292}\u{338}"), (0x22EA, Dt::Canonical, "\u{22B2}\u{338}"),
+        (0x22EB, Dt::Canonical, "\u{22B3}\u{338}"), (0x22EC, Dt::Canonical, "\u{22B4}\u{338}"),
+        (0x22ED, Dt::Canonical, "\u{22B5}\u{338}"), (0x2329, Dt::Canonical, "\u{3008}"),
+        (0x232A, Dt::Canonical, "\u{3009}"), (0x2460, Dt::Circle, "1"), (0x2461, Dt::Circle, "2"),
+        (0x2462, Dt::Circle, "3"), (0x2463, Dt::Circle, "4"), (0x2464, Dt::Circle, "5"),
+        (0x2465, Dt::Circle, "6"), (0x2466, Dt::Circle, "7"), (0x2467, Dt::Circle, "8"),
+        (0x2468, Dt::Circle, "9"), (0x2469, Dt::Circle, "10"), (0x246A, Dt::Circle, "11"),
+        (0x246B, Dt::Circle, "12"), (0x246C, Dt::Circle, "13"), (0x246D, Dt::Circle, "14"),
+        (0x246E, Dt::Circle, "15"), (0x246F, Dt::Circle, "16"), (0x2470, Dt::Circle, "17"),
+        (0x2471, Dt::Circle, "18"), (0x2472, Dt::Circle, "19"), (0x2473, Dt::Circle, "20"),
+        (0x2474, Dt::Compat, "(1)"), (0x2475, Dt::Compat, "(2)"), (0x2476, Dt::Compat, "(3)"),
+        (0x2477, Dt::Compat, "(4)"), (0x2478, Dt::Compat, "(5)"), (0x2479, Dt::Compat, "(6)"),
+        (0x247A, Dt::Compat, "(7)"), (0x247B, Dt::Compat, "(8)"), (0x247C, Dt::Compat, "(9)"),
+        (0x247D, Dt::Compat, "(10)"), (0x247E, Dt::Compat, "(11)"), (0x247F, Dt::Compat, "(12)"),
+        (0x2480, Dt::Compat, "(13)"), (0x2481, Dt::Compat, "(14)"), (0x2482, Dt::Compat, "(15)"),
+        (0x2483, Dt::Compat, "(16)"), (0x2484, Dt::Compat, "(17)"), (0x2485, Dt::Compat, "(18)"),
+        (0x2486, Dt::Compat, "(19)"), (0x2487, Dt::Compat, "(20)"), (0x2488, Dt::Compat, "1."),
+        (0x2489, Dt::Compat, "2."), (0x248A, Dt::Compat, "3."), (0x248B, Dt::Compat, "4."),
+        (0x248C, Dt::Compat, "5."), (0x248D, Dt::Compat, "6."), (0x248E, Dt::Compat, "7."),
+        (0x248F, Dt::Compat, "8."), (0x2490, Dt::Compat, "9."), (0x2491, Dt::Compat, "10."),
+        (0x2492, Dt::Compat, "11."), (0x2493, Dt::Compat, "12."), (0x2494, Dt::Compat, "13."),
+        (0x2495, Dt::Compat, "14."), (0x2496, Dt::Compat, "15."), (0x2497, Dt::Compat, "16."),
+        (0x2498, Dt::Compat, "17."), (0x2499, Dt::Compat, "18."), (0x249A, Dt::Compat, "19."),
+        (0x249B, Dt::Compat, "20."), (0x249C, Dt::Compat, "(a)"), (0x249D, Dt::Compat, "(b)"),
+        (0x249E, Dt::Compat, "(c)"), (0x249F, Dt::Compat, "(d)"), (0x24A0, Dt::Compat, "(e)"),
+        (0x24A1, Dt::Compat, "(f)"), (0x24A2, Dt::Compat, "(g)"), (0x24A3, Dt::Compat, "(h)"),
+        (0x24A4, Dt::Compat, "(i)"), (0x24A5, Dt::Compat, "(j)"), (0x24A6, Dt::Compat, "(k)"),
+        (0x24A7, Dt::Compat, "(l)"), (0x24A8, Dt::Compat, "(m)"), (0x24A9, Dt::Compat, "(n)"),
+        (0x24AA, Dt::Compat, "(o)"), (0x24AB, Dt::Compat, "(p)"), (0x24AC, Dt::Compat, "(q)"),
+        (0x24AD, Dt::Compat, "(r)"), (0x24AE, Dt::Compat, "(s)"), (0x24AF, Dt::Compat, "(t)"),
+        (0x24B0, Dt::Compat, "(u)"), (0x24B1, Dt::Compat, "(v)"), (0x24B2, Dt::Compat, "(w)"),
+        (0x24B3, Dt::Compat, "(x)"), (0x24B4, Dt::Compat, "(y)"), (0x24B5, Dt::Compat, "(z)"),
+        (0x24B6, Dt::Circle, "A"), (0x24B7, Dt::Circle, "B"), (0x24B8, Dt::Circle, "C"),
+        (0x24B9, Dt::Circle, "D"), (0x24BA, Dt::Circle, "E"), (0x24BB, Dt::Circle, "F"),
+        (0x24BC, Dt::Circle, "G"), (0x24BD, Dt::Circle, "H"), (0x24BE, Dt::Circle, "I"),
+        (0x24BF, Dt::Circle, "J"), (0x24C0, Dt::Circle, "K"), (0x24C1, Dt::Circle, "L"),
+        (0x24C2, Dt::Circle, "M"), (0x24C3, Dt::Circle, "N"), (0x24C4, Dt::Circle, "O"),
+        (0x24C5, Dt::Circle, "P"), (0x24C6, Dt::Circle, "Q"), (0x24C7, Dt::Circle, "R"),
+        (0x24C8, Dt::Circle, "S"), (0x24C9, Dt::Circle, "T"), (0x24CA, Dt::Circle, "U"),
+        (0x24CB, Dt::Circle, "V"), (0x24CC, Dt::Circle, "W"), (0x24CD, Dt::Circle, "X"),
+        (0x24CE, Dt::Circle, "Y"), (0x24CF, Dt::Circle, "Z"), (0x24D0, Dt::Circle, "a"),
+        (0x24D1, Dt::Circle, "b"), (0x24D2, Dt::Circle, "c"), (0x24D3, Dt::Circle, "d"),
+        (0x24D4, Dt::Circle, "e"), (0x24D5, Dt::Circle, "f"), (0x24D6, Dt::Circle, "g"),
+        (0x24D7, Dt::Circle, "h"), (0x24D8, Dt::Circle, "i"), (0x24D9, Dt::Circle, "j"),
+        (0x24DA, Dt::Circle, "k"), (0x24DB, Dt::Circle, "l"), (0x24DC, Dt::Circle, "m"),
+        (0x24DD, Dt::Circle, "n"), (0x24DE, Dt::Circle, "o"), (0x24DF, Dt::Circle, "p"),
+        (0x24E0, Dt::Circle, "q"), (0x24E1, Dt::Circle, "r"), (0x24E2, Dt::Circle, "s"),
+        (0x24E3, Dt::Circle, "t"), (0x24E4, Dt::Circle, "u"), (0x24E5, Dt::Circle, "v"),
+        (0x24E6, Dt::Circle, "w"), (0x24E7, Dt::Circle, "x"), (0x24E8, Dt::Circle, "y"),
+        (0x24E9, Dt::Circle, "z"), (0x24EA, Dt::Circle, "0"),
+        (0x2A0C, Dt::Compat, "\u{222B}\u{222B}\u{222B}\u{222B}"), (0x2A74, Dt::Compat, "::="),
+        (0x2A75, Dt::Compat, "=="), (0x2A76, Dt::Compat, "==="),
+        (0x2ADC, Dt::Canonical, "\u{2ADD}\u{338}"), (0x2C7C, Dt::Sub, "j"),
+        (0x2C7D, Dt::Super, "V"), (0x2D6F, Dt::Super, "\u{2D61}"), (0x2E9F, Dt::Compat, "\u{6BCD}"),
+        (0x2EF3, Dt::Compat, "\u{9F9F}"), (0x2F00, Dt::Compat, "\u{4E00}"),
+        (0x2F01, Dt::Compat, "\u{4E28}"), (0x2F02, Dt::Compat, "\u{4E36}"),
+        (0x2F03, Dt::Compat, "\u{4E3F}"), (0x2F04, Dt::Compat, "\u{4E59}"),
+        (0x2F05, Dt::Compat, "\u{4E85}"), (0x2F06, Dt::Compat, "\u{4E8C}"),
+        (0x2F07, Dt::Compat, "\u{4EA0}"), (0x2F08, Dt::Compat, "\u{4EBA}"),
+        (0x2F09, Dt::Compat, "\u{513F}"), (0x2F0A, Dt::Compat, "\u{5165}"),
+        (0x2F0B, Dt::Compat, "\u{516B}"), (0x2F0C, Dt::Compat, "\u{5182}"),
+        (0x2F0D, Dt::Compat, "\u{5196}"), (0x2F0E, Dt::Compat, "\u{51AB}"),
+        (0x2F0F, Dt::Compat, "\u{51E0}"), (0x2F10, Dt::Compat, "\u{51F5}"),
+        (0x2F11, Dt::Compat, "\u{5200}"), (0x2F12, Dt::Compat, "\u{529B}"),
+        (0x2F13, Dt::Compat, "\u{52F9}"), (0x2F14, Dt::Compat, "\u{5315}"),
+        (0x2F15, Dt::Compat, "\u{531A}"), (0x2F16, Dt::Compat, "\u{5338}"),
+        (0x2F17, Dt::Compat, "\u{5341}"), (0x2F18, Dt::Compat, "\u{535C}"),
+        (0x2F19, Dt::Compat, "\u{5369}"), (0x2F1A, Dt::Compat, "\u{5382}"),
+        (0x2F1B, Dt::Compat, "\u{53B6}"), (0x2F1C, Dt::Compat, "\u{53C8}"),
+        (0x2F1D, Dt::Compat, "\u{53E3}"), (0x2F1E, Dt::Compat, "\u{56D7}"),
+        (0x2F1F, Dt::Compat, "\u{571F}"), (0x2F20, Dt::Compat, "\u{58EB}"),
+        (0x2F21, Dt::Compat, "\u{5902}"), (0x2F22, Dt::Compat, "\u{590A}"),
+        (0x2F23, Dt::Compat, "\u{5915}"), (0x2F24, Dt::Compat, "\u{5927}"),
+        (0x2F25, Dt::Compat, "\u{5973}"), (0x2F26, Dt::Compat, "\u{5B50}"),
+        (0x2F27, Dt::Compat, "\u{5B80}"), (0x2F28, Dt::Compat, "\u{5BF8}"),
+        (0x2F29, Dt::Compat, "\u{5C0F}"), (0x2F2A, Dt::Compat, "\u{5C22}"),
+        (0x2F2B, Dt::Compat, "\u{5C38}"), (0x2F2C, Dt::Compat, "\u{5C6E}"),
+        (0x2F2D, Dt::Compat, "\u{5C71}"), (0x2F2E, Dt::Compat, "\u{5DDB}"),
+        (0x2F2F, Dt::Compat, "\u{5DE5}"), (0x2F30, Dt::Compat, "\u{5DF1}"),
+        (0x2F31, Dt::Compat, "\u{5DFE}"), (0x2F32, Dt::Compat, "\u{5E72}"),
+        (0x2F33, Dt::Compat, "\u{5E7A}"), (0x2F34, Dt::Compat, "\u{5E7F}"),
+        (0x2F35, Dt::Compat, "\u{5EF4}"), (0x2F36, Dt::Compat, "\u{5EFE}"),
+        (0x2F37, Dt::Compat, "\u{5F0B}"), (0x2F38, Dt::Compat, "\u{5F13}"),
+        (0x2F39, Dt::Compat, "\u{5F50}"), (0x2F3A, Dt::Compat, "\u{5F61}"),
+        (0x2F3B, Dt::Compat, "\u{5F73}"), (0x2F3C, Dt::Compat, "\u{5FC3}"),
+        (0x2F3D, Dt::Compat, "\u{6208}"), (0x2F3E, Dt::Compat, "\u{6236}"),
+        (0x2F3F, Dt::Compat, "\u{624B}"), (0x2F40, Dt::Compat, "\u{652F}"),
+        (0x2F41, Dt::Compat, "\u{6534}"), (0x2F42, Dt::Compat, "\u{6587}"),
+        (0x2F43, Dt::Compat, "\u{6597}"), (0x2F44, Dt::Compat, "\u{65A4}"),
+        (0x2F45, Dt::Compat, "\u{65B9}"), (0x2F46, Dt::Compat, "\u{65E0}"),
+        (0x2F47, Dt::Compat, "\u{65E5}"), (0x2F48, Dt::Compat, "\u{66F0}"),
+        (0x2F49, Dt::Compat, "\u{6708}"), (0x2F4A, Dt::Compat, "\u{6728}"),
+        (0x2F4B, Dt::Compat, "\u{6B20}"), (0x2F4C, Dt::Compat, "\u{6B62}"),
+        (0x2F4D, Dt::Compat, "\u{6B79}"), (0x2F4E, Dt::Compat, "\u{6BB3}"),
+        (0x2F4F, Dt::Compat, "\u{6BCB}"), (0x2F50, Dt::Compat, "\u{6BD4}"),
+        (0x2F51, Dt::Compat, "\u{6BDB}"), (0x2F52, Dt::Compat, "\u{6C0F}"),
+        (0x2F53, Dt::Compat, "\u{6C14}"), (0x2F54, Dt::Compat, "\u{6C34}"),
+        (0x2F55, Dt::Compat, "\u{706B}"), (0x2F56, Dt::Compat, "\u{722A}"),
+        (0x2F57, Dt::Compat, "\u{7236}"), (0x2F58, Dt::Compat, "\u{723B}"),
+        (0x2F59, Dt::Compat, "\u{723F}"), (0x2F5A, Dt::Compat, "\u{7247}"),
+        (0x2F5B, Dt::Compat, "\u{7259}"), (0x2F5C, Dt::Compat, "\u{725B}"),
+        (0x2F5D, Dt::Compat, "\u{72AC}"), (0x2F5E, Dt::Compat, "\u{7384}"),
+        (0x2F5F, Dt::Compat, "\u{7389}"), (0x2F60, Dt::Compat, "\u{74DC}"),
+        (0x2F61, Dt::Compat, "\u{74E6}"), (0x2F62, Dt::Compat, "\u{7518}"),
+        (0x2F63, Dt::Compat, "\u{751F}"), (0x2F64, Dt::Compat, "\u{7528}"),
+        (0x2F65, Dt::Compat, "\u{7530}"), (0x2F66, Dt::Compat, "\u{758B}"),
+        (0x2F67, Dt::Compat, "\u{7592}"), (0x2F68, Dt::Compat, "\u{7676}"),
+        (0x2F69, Dt::Compat, "\u{767D}"), (0x2F6A, Dt::Compat, "\u{76AE}"),
+        (0x2F6B, Dt::Compat, "\u{76BF}"), (0x2F6C, Dt::Compat, "\u{76EE}"),
+        (0x2F6D, Dt::Compat, "\u{77DB}"), (0x2F6E, Dt::Compat, "\u{77E2}"),
+        (0x2F6F, Dt::Compat, "\u{77F3}"), (0x2F70, Dt::Compat, "\u{793A}"),
+        (0x2F71, Dt::Compat, "\u{79B8}"), (0x2F72, Dt::Compat, "\u{79BE}"),
+        (0x2F73, Dt::Compat, "\u{7A74}"), (0x2F74, Dt::Compat, "\u{7ACB}"),
+        (0x2F75, Dt::Compat, "\u{7AF9}"), (0x2F76, Dt::Compat, "\u{7C73}"),
+        (0x2F77, Dt::Compat, "\u{7CF8}"), (0x2F78, Dt::Compat, "\u{7F36}"),
+        (0x2F79, Dt::Compat, "\u{7F51}"), (0x2F7A, Dt::Compat, "\u{7F8A}"),
+        (0x2F7B, Dt::Compat, "\u{7FBD}"), (0x2F7C, Dt::Compat, "\u{8001}"),
+        (0x2F7D, Dt::Compat, "\u{800C}"), (0x2F7E, Dt::Compat, "\u{8012}"),
+        (0x2F7F, Dt::Compat, "\u{8033}"), (0x2F80, Dt::Compat, "\u{807F}"),
+        (0x2F81, Dt::Compat, "\u{8089}"), (0x2F82, Dt::Compat, "\u{81E3}"),
+        (0x2F83, Dt::Compat, "\u{81EA}"), (0x2F84, Dt::Compat, "\u{81F3}"),
+        (0x2F85, Dt::Compat, "\u{81FC}"), (0x2F86, Dt::Compat, "\u{820C}"),
+        (0x2F87, Dt::Compat, "\u{821B}"), (0x2F88, Dt::Compat, "\u{821F}"),
+        (0x2F89, Dt::Compat, "\u{826E}"), (0x2F8A, Dt::Compat, "\u{8272}"),
+        (0x2F8B, Dt::Compat, "\u{8278}"), (0x2F8C, Dt::Compat, "\u{864D}"),
+        (0x2F8D, Dt::Compat, "\u{866B}"), (0x2F8E, Dt::Compat, "\u{8840}"),
+        (0x2F8F, Dt::Compat, "\u{884C}"), (0x2F90, Dt::Compat, "\u{8863}"),
+        (0x2F91, Dt::Compat, "\u{897E}"), (0x2F92, Dt::Compat, "\u{898B}"),
+        (0x2F93, Dt::Compat, "\u{89D2}"), (0x2F94, Dt::Compat, "\u{8A00}"),
+        (0x2F95, Dt::Compat, "\u{8C37}"), (0x2F96, Dt::Compat, "\u{8C46}"),
+        (0x2F97, Dt::Compat, "\u{8C55}"), (0x2F98, Dt::Compat, "\u{8C78}"),
+        (0x2F99, Dt::Compat, "\u{8C9D}"), (0x2F9A, Dt::Compat, "\u{8D64}"),
+        (0x2F9B, Dt::Compat, "\u{8D70}"), (0x2F9C, Dt::Compat, "\u{8DB3}"),
+        (0x2F9D, Dt::Compat, "\u{8EAB}"), (0x2F9E, Dt::Compat, "\u{8ECA}"),
+        (0x2F9F, Dt::Compat, "\u{8F9B}"), (0x2FA0, Dt::Compat, "\u{8FB0}"),
+        (0x2FA1, Dt::Compat, "\u{8FB5}"), (0x2FA2, Dt::Compat, "\u{9091}"),
+        (0x2FA3, Dt::Compat, "\u{9149}"), (0x2FA4, Dt::Compat, "\u{91C6}"),
+        (0x2FA5, Dt::Compat, "\u{91CC}"), (0x2FA6, Dt::Compat, "\u{91D1}"),
+        (0x2FA7, Dt::Compat, "\u{9577}"), (0x2FA8, Dt::Compat, "\u{9580}"),
+        (0x2FA9, Dt::Compat, "\u{961C}"), (0x2FAA, Dt::Compat, "\u{96B6}"),
+        (0x2FAB, Dt::Compat, "\u{96B9}"), (0x2FAC, Dt::Compat, "\u{96E8}"),
+        (0x2FAD, Dt::Compat, "\u{9751}"), (0x2FAE, Dt::Compat, "\u{975E}"),
+        (0x2FAF, Dt::Compat, "\u{9762}"), (0x2FB0, Dt::Compat, "\u{9769}"),
+        (0x2FB1, Dt::Compat, "\u{97CB}"), (0x2FB2, Dt::Compat, "\u{97ED}"),
+        (0x2FB3, Dt::Compat, "\u{97F3}"), (0x2FB4, Dt::Compat, "\u{9801}"),
+        (0x2FB5, Dt::Compat, "\u{98A8}"), (0x2FB6, Dt::Compat, "\u{98DB}"),
+        (0x2FB7, Dt::Compat, "\u{98DF}"), (0x2FB8, Dt::Compat, "\u{9996}"),
+        (0x2FB9, Dt::Compat, "\u{9999}"), (0x2FBA, Dt::Compat, "\u{99AC}"),
+        (0x2FBB, Dt::Compat, "\u{9AA8}"), (0x2FBC, Dt::Compat, "\u{9AD8}"),
+        (0x2FBD, Dt::Compat, "\u{9ADF}"), (0x2FBE, Dt::Compat, "\u{9B25}"),
+        (0x2FBF, Dt::Compat, "\u{9B2F}"), (0x2FC0, Dt::Compat, "\u{9B32}"),
+        (0x2FC1, Dt::Compat, "\u{9B3C}"), (0x2FC2, Dt::Compat, "\u{9B5A}"),
+        (0x2FC3, Dt::Compat, "\u{9CE5}"), (0x2FC4, Dt::Compat, "\u{9E75}"),
+        (0x2FC5, Dt::Compat, "\u{9E7F}"), (0x2FC6, Dt::Compat, "\u{9EA5}"),
+        (0x2FC7, Dt::Compat, "\u{9EBB}"), (0x2FC8, Dt::Compat, "\u{9EC3}"),
+        (0x2FC9, Dt::Compat, "\u{9ECD}"), (0x2FCA, Dt::Compat, "\u{9ED1}"),
+        (0x2FCB, Dt::Compat, "\u{9EF9}"), (0x2FCC, Dt::Compat, "\u{9EFD}"),
+        (0x2FCD, Dt::Compat, "\u{9F0E}"), (0x2FCE, Dt::Compat, "\u{9F13}"),
+        (0x2FCF, Dt::Compat, "\u{9F20}"), (0x2FD0, Dt::Compat, "\u{9F3B}"),
+        (0x2FD1, Dt::Compat, "\u{9F4A}"), (0x2FD2, Dt::Compat, "\u{9F52}"),
+        (0x2FD3, Dt::Compat, "\u{9F8D}"), (0x2FD4, Dt::Compat, "\u{9F9C}"),
+        (0x2FD5, Dt::Compat, "\u{9FA0}"), (0x3000, Dt::Wide, " "), (0x3036, Dt::Compat, "\u{3012}"),
+        (0x3038, Dt::Compat, "\u{5341}"), (0x3039, Dt::Compat, "\u{5344}"),
+        (0x303A, Dt::Compat, "\u{5345}"), (0x304C, Dt::Canonical, "\u{304B}\u{3099}"),
+        (0x304E, Dt::Canonical, "\u{304D}\u{3099}"), (0x3050, Dt::Canonical, "\u{304F}\u{3099}"),
+        (0x3052, Dt::Canonical, "\u{3051}\u{3099}"), (0x3054, Dt::Canonical, "\u{3053}\u{3099}"),
+        (0x3056, Dt::Canonical, "\u{3055}\u{3099}"), (0x3058, Dt::Canonical, "\u{3057}\u{3099}"),
+        (0x305A, Dt::Canonical, "\u{3059}\u{3099}"), (0x305C, Dt::Canonical, "\u{305B}\u{3099}"),
+        (0x305E, Dt::Canonical, "\u{305D}\u{3099}"), (0x3060, Dt::Canonical, "\u{305F}\u{3099}"),
+        (0x3062, Dt::Canonical, "\u{3061}\u{3099}"), (0x3065, Dt::Canonical, "\u{3064}\u{3099}"),
+        (0x3067, Dt::Canonical, "\u{3066}\u{3099}"), (0x3069, Dt::Canonical, "\u{3068}\u{3099}"),
+        (0x3070, Dt::Canonical, "\u{306F}\u{3099}"), (0x3071, Dt::Canonical, "\u{306F}\u{309A}"),
+        (0x3073, Dt::Canonical, "\u{3072}\u{3099}"), (0x3074, Dt::Canonical, "\u{3072}\u{309A}"),
+        (0x3076, Dt::Canonical, "\u{3075}\u{3099}"), (0x3077, Dt::Canonical, "\u{3075}\u{309A}"),
+        (0x3079, Dt::Canonical, "\u{3078}\u{3099}"), (0x307A, Dt::Canonical, "\u{3078}\u{309A}"),
+        (0x307C, Dt::Canonical, "\u{307B}\u{3099}"), (0x307D, Dt::Canonical, "\u{307B}\u{309A}"),
+        (0x3094, Dt::Canonical, "\u{3046}\u{3099}"), (0x309B, Dt::Compat, " \u{3099}"),
+        (0x309C, Dt::Compat, " \u{309A}"), (0x309E, Dt::Canonical, "\u{309D}\u{3099}"),
+        (0x309F, Dt::Vertical, "\u{3088}\u{308A}"), (0x30AC, Dt::Canonical, "\u{30AB}\u{3099}"),
+        (0x30AE, Dt::Canonical, "\u{30AD}\u{3099}"), (0x30B0, Dt::Canonical, "\u{30AF}\u{3099}"),
+        (0x30B2, Dt::Canonical, "\u{30B1}\u{3099}"), (0x30B4, Dt::Canonical, "\u{30B3}\u{3099}"),
+        (0x30B6, Dt::Canonical, "\u{30B5}\u{3099}"), (0x30B8, Dt::Canonical, "\u{30B7}\u{3099}"),
+        (0x30BA, Dt::Canonical, "\u{30B9}\u{3099}"), (0x30BC, Dt::Canonical, "\u{30BB}\u{3099}"),
+        (0x30BE, Dt::Canonical, "\u{30BD}\u{3099}"), (0x30C0, Dt::Canonical, "\u{30BF}\u{3099}"),
+        (0x30C2, Dt::Canonical, "\u{30C1}\u{3099}"), (0x30C5, Dt::Canonical, "\u{30C4}\u{3099}"),
+        (0x30C7, Dt::Canonical, "\u{30C6}\u{3099}"), (0x30C9, Dt::Canonical, "\u{30C8}\u{3099}"),
+        (0x30D0, Dt::Canonical, "\u{30CF}\u{3099}"), (0x30D1, Dt::Canonical, "\u{30CF}\u{309A}"),
+        (0x30D3, Dt::Canonical, "\u{30D2}\u{3099}"), (0x30D4, Dt::Canonical, "\u{30D2}\u{309A}"),
+        (0x30D6, Dt::Canonical, "\u{30D5}\u{3099}"), (0x30D7, Dt::Canonical, "\u{30D5}\u{309A}"),
+        (0x30D9, Dt::Canonical, "\u{30D8}\u{3099}"), (0x30DA, Dt::Canonical, "\u{30D8}\u{309A}"),
+        (0x30DC, Dt::Canonical, "\u{30DB}\u{3099}"), (0x30DD, Dt::Canonical, "\u{30DB}\u{309A}"),
+        (0x30F4, Dt::Canonical, "\u{30A6}\u{3099}"), (0x30F7, Dt::Canonical, "\u{30EF}\u{3099}"),
+        (0x30F8, Dt::Canonical, "\u{30F0}\u{3099}"), (0x30F9, Dt::Canonical, "\u{30F1}\u{3099}"),
+        (0x30FA, Dt::Canonical, "\u{30F2}\u{3099}"), (0x30FE, Dt::Canonical, "\u{30FD}\u{3099}"),
+        (0x30FF, Dt::Vertical, "\u{30B3}\u{30C8}"), (0x3131, Dt::Compat, "\u{1100}"),
+        (0x3132, Dt::Compat, "\u{1101}"), (0x3133, Dt::Compat, "\u{11AA}"),
+        (0x3134, Dt::Compat, "\u{1102}"), (0x3135, Dt::Compat, "\u{11AC}"),
+        (0x3136, Dt::Compat, "\u{11AD}"), (0x3137, Dt::Compat, "\u{1103}"),
+        (0x3138, Dt::Compat, "\u{1104}"), (0x3139, Dt::Compat, "\u{1105}"),
+        (0x313A, Dt::Compat, "\u{11B0}"), (0x313B, Dt::Compat, "\u{11B1}"),
+        (0x313C, Dt::Compat, "\u{11B2}"), (0x313D, Dt::Compat, "\u{11B3}"),
+        (0x313E, Dt::Compat, "\u{11B4}"), (0x313F, Dt::Compat, "\u{11B5}"),
+        (0x3140, Dt::Compat, "\u{111A}"), (0x3141, Dt::Compat, "\u{1106}"),
+        (0x3142, Dt::Compat, "\u{1107}"), (0x3143, Dt::Compat, "\u{1108}"),
+        (0x3144, Dt::Compat, "\u{1121}"), (0x3145, Dt::Compat, "\u{1109}"),
+        (0x3146, Dt::Compat, "\u{110A}"), (0x3147, Dt::Compat, "\u{110B}"),
+        (0x3148, Dt::Compat, "\u{110C}"), (0x3149, Dt::Compat, "\u{110D}"),
+        (0x314A, Dt::Compat, "\u{110E}"), (0x314B, Dt::Compat, "\u{110F}"),
+        (0x314C, Dt::Compat, "\u{1110}"), (0x314D, Dt::Compat, "\u{1111}"),
+        (0x314E, Dt::Compat, "\u{1112}"), (0x314F, Dt::Compat, "\u{1161}"),
+        (0x3150, Dt::Compat, "\u{1162}"), (0x3151, Dt::Compat, "\u{1163}"),
+        (0x3152, Dt::Compat, "\u{1164}"), (0x3153, Dt::Compat, "\u{1165}"),
+        (0x3154, Dt::Compat, "\u{1166}"), (0x3155, Dt::Compat, "\u{1167}"),
+        (0x3156, Dt::Compat, "\u{1168}"), (0x3157, Dt::Compat, "\u{1169}"),
+        (0x3158, Dt::Compat, "\u{116A}"), (0x3159, Dt::Compat, "\u{116B}"),
+        (0x315A, Dt::Compat, "\u{116C}"), (0x315B, Dt::Compat, "\u{116D}"),
+        (0x315C, Dt::Compat, "\u{116E}"), (0x315D, Dt::Compat, "\u{116F}"),
+        (0x315E, Dt::Compat, "\u{1170}"), (0x315F, Dt::Compat, "\u{1171}"),
+        (0x3160, Dt::Compat, "\u{1172}"), (0x3161, Dt::Compat, "\u{1173}"),
+        (0x3162, Dt::Compat, "\u{1174}"), (0x3163, Dt::Compat, "\u{1175}"),
+        (0x3164, Dt::Compat, "\u{1160}"), (0x3165, Dt::Compat, "\u{1114}"),
+        (0x3166, Dt::Compat, "\u{1115}"), (0x3167, Dt::Compat, "\u{11C7}"),
+        (0x3168, Dt::Compat, "\u{11C8}"), (0x3169, Dt::Compat, "\u{11CC}"),
+        (0x316A, Dt::Compat, "\u{11CE}"), (0x316B, Dt::Compat, "\u{11D3}"),
+        (0x316C, Dt::Compat, "\u{11D7}"), (0x316D, Dt::Compat, "\u{11D9}"),
+        (0x316E, Dt::Compat, "\u{111C}"), (0x316F, Dt::Compat, "\u{11DD}"),
+        (0x3170, Dt::Compat, "\u{11DF}"), (0x3171, Dt::Compat, "\u{111D}"),
+        (0x3172, Dt::Compat, "\u{111E}"), (0x3173, Dt::Compat, "\u{1120}"),
+        (0x3174, Dt::Compat, "\u{1122}"), (0x3175, Dt::Compat, "\u{1123}"),
+        (0x3176, Dt::Compat, "\u{1127}"), (0x3177, Dt::Compat, "\u{1129}"),
+        (0x3178, Dt::Compat, "\u{112B}"), (0x3179, Dt::Compat, "\u{112C}"),
+        (0x317A, Dt::Compat, "\u{112D}"), (0x317B, Dt::Compat, "\u{112E}"),
+        (0x317C, Dt::Compat, "\u{112F}"), (0x317D, Dt::Compat, "\u{1132}"),
+        (0x317E, Dt::Compat, "\u{1136}"), (0x317F, Dt::Compat, "\u{1140}"),
+        (0x3180, Dt::Compat, "\u{1147}"), (0x3181, Dt::Compat, "\u{114C}"),
+        (0x3182, Dt::Compat, "\u{11F1}"), (0x3183, Dt::Compat, "\u{11F2}"),
+        (0x3184, Dt::Compat, "\u{1157}"), (0x3185, Dt::Compat, "\u{1158}"),
+        (0x3186, Dt::Compat, "\u{1159}"), (0x3187, Dt::Compat, "\u{1184}"),
+        (0x3188, Dt::Compat, "\u{1185}"), (0x3189, Dt::Compat, "\u{1188}"),
+        (0x318A, Dt::Compat, "\u{1191}"), (0x318B, Dt::Compat, "\u{1192}"),
+        (0x318C, Dt::Compat, "\u{1194}"), (0x318D, Dt::Compat, "\u{119E}"),
+        (0x318E, Dt::Compat, "\u{11A1}"), (0x3192, Dt::Super, "\u{4E00}"),
+        (0x3193, Dt::Super, "\u{4E8C}"), (0x3194, Dt::Super, "\u{4E09}"),
+        (0x3195, Dt::Super, "\u{56DB}"), (0x3196, Dt::Super, "\u{4E0A}"),
+        (0x3197, Dt::Super, "\u{4E2D}"), (0x3198, Dt::Super, "\u{4E0B}"),
+        (0x3199, Dt::Super, "\u{7532}"), (0x319A, Dt::Super, "\u{4E59}"),
+        (0x319B, Dt::Super, "\u{4E19}"), (0x319C, Dt::Super, "\u{4E01}"),
+        (0x319D, Dt::Super, "\u{5929}"), (0x319E, Dt::Super, "\u{5730}"),
+        (0x319F, Dt::Super, "\u{4EBA}"), (0x3200, Dt::Compat, "(\u{1100})"),
+        (0x3201, Dt::Compat, "(\u{1102})"), (0x3202, Dt::Compat, "(\u{1103})"),
+        (0x3203, Dt::Compat, "(\u{1105})"), (0x3204, Dt::Compat, "(\u{1106})"),
+        (0x3205, Dt::Compat, "(\u{1107})"), (0x3206, Dt::Compat, "(\u{1109})"),
+        (0x3207, Dt::Compat, "(\u{110B})"), (0x3208, Dt::Compat, "(\u{110C})"),
+        (0x3209, Dt::Compat, "(\u{110E})"), (0x320A, Dt::Compat, "(\u{110F})"),
+        (0x320B, Dt::Compat, "(\u{1110})"), (0x320C, Dt::Compat, "(\u{1111})"),
+        (0x320D, Dt::Compat, "(\u{1112})"), (0x320E, Dt::Compat, "(\u{1100}\u{1161})"),
+        (0x320F, Dt::Compat, "(\u{1102}\u{1161})"), (0x3210, Dt::Compat, "(\u{1103}\u{1161})"),
+        (0x3211, Dt::Compat, "(\u{1105}\u{1161})"), (0x3212, Dt::Compat, "(\u{1106}\u{1161})"),
+        (0x3213, Dt::Compat, "(\u{1107}\u{1161})"), (0x3214, Dt::Compat, "(\u{1109}\u{1161})"),
+        (0x3215, Dt::Compat, "(\u{110B}\u{1161})"), (0x3216, Dt::Compat, "(\u{110C}\u{1161})"),
+        (0x3217, Dt::Compat, "(\u{110E}\u{1161})"), (0x3218, Dt::Compat, "(\u{110F}\u{1161})"),
+        (0x3219, Dt::Compat, "(\u{1110}\u{1161})"), (0x321A, Dt::Compat, "(\u{1111}\u{1161})"),
+        (0x321B, Dt::Compat, "(\u{1112}\u{1161})"), (0x321C, Dt::Compat, "(\u{110C}\u{116E})"),
+        (0x321D, Dt::Compat, "(\u{110B}\u{1169}\u{110C}\u{1165}\u{11AB})"),
+        (0x321E, Dt::Compat, "(\u{110B}\u{1169}\u{1112}\u{116E})"),
+        (0x3220, Dt::Compat, "(\u{4E00})"), (0x3221, Dt::Compat, "(\u{4E8C})"),
+        (0x3222, Dt::Compat, "(\u{4E09})"), (0x3223, Dt::Compat, "(\u{56DB})"),
+        (0x3224, Dt::Compat, "(\u{4E94})"), (0x3225, Dt::Compat, "(\u{516D})"),
+        (0x3226, Dt::Compat, "(\u{4E03})"), (0x3227, Dt::Compat, "(\u{516B})"),
+        (0x3228, Dt::Compat, "(\u{4E5D})"), (0x3229, Dt::Compat, "(\u{5341})"),
+        (0x322A, Dt::Compat, "(\u{6708})"), (0x322B, Dt::Compat, "(\u{706B})"),
+        (0x322C, Dt::Compat, "(\u{6C34})"), (0x322D, Dt::Compat, "(\u{6728})"),
+        (0x322E, Dt::Compat, "(\u{91D1})"), (0x322F, Dt::Compat, "(\u{571F})"),
+        (0x3230, Dt::Compat, "(\u{65E5})"), (0x3231, Dt::Compat, "(\u{682A})"),
+        (0x3232, Dt::Compat, "(\u{6709})"), (0x3233, Dt::Compat, "(\u{793E})"),
+        (0x3234, Dt::Compat, "(\u{540D})"), (0x3235, Dt::Compat, "(\u{7279})"),
+        (0x3236, Dt::Compat, "(\u{8CA1})"), (0x3237, Dt::Compat, "(\u{795D})"),
+        (0x3238, Dt::Compat, "(\u{52B4})"), (0x3239, Dt::Compat, "(\u{4EE3})"),
+        (0x323A, Dt::Compat, "(\u{547C})"), (0x323B, Dt::Compat, "(\u{5B66})"),
+        (0x323C, Dt::Compat, "(\u{76E3})"), (0x323D, Dt::Compat, "(\u{4F01})"),
+        (0x323E, Dt::Compat, "(\u{8CC7})"), (0x323F, Dt::Compat, "(\u{5354})"),
+        (0x3240, Dt::Compat, "(\u{796D})"), (0x3241, Dt::Compat, "(\u{4F11})"),
+        (0x3242, Dt::Compat, "(\u{81EA})"), (0x3243, Dt::Compat, "(\u{81F3})"),
+        (0x3244, Dt::Circle, "\u{554F}"), (0x3245, Dt::Circle, "\u{5E7C}"),
+        (0x3246, Dt::Circle, "\u{6587}"), (0x3247, Dt::Circle, "\u{7B8F}"),
+        (0x3250, Dt::Square, "PTE"), (0x3251, Dt::Circle, "21"), (0x3252, Dt::Circle, "22"),
+        (0x3253, Dt::Circle, "23"), (0x3254, Dt::Circle, "24"), (0x3255, Dt::Circle, "25"),
+        (0x3256, Dt::Circle, "26"), (0x3257, Dt::Circle, "27"), (0x3258, Dt::Circle, "28"),
+        (0x3259, Dt::Circle, "29"), (0x325A, Dt::Circle, "30"), (0x325B, Dt::Circle, "31"),
+        (0x325C, Dt::Circle, "32"), (0x325D, Dt::Circle, "33"), (0x325E, Dt::Circle, "34"),
+        (0x325F, Dt::Circle, "35"), (0x3260, Dt::Circle, "\u{1100}"),
+        (0x3261, Dt::Circle, "\u{1102}"), (0x3262, Dt::Circle, "\u{1103}"),
+        (0x3263, Dt::Circle, "\u{1105}"), (0x3264, Dt::Circle, "\u{1106}"),
+        (0x3265, Dt::Circle, "\u{1107}"), (0x3266, Dt::Circle, "\u{1109}"),
+        (0x3267, Dt::Circle, "\u{110B}"), (0x3268, Dt::Circle, "\u{110C}"),
+        (0x3269, Dt::Circle, "\u{110E}"), (0x326A, Dt::Circle, "\u{110F}"),
+        (0x326B, Dt::Circle, "\u{1110}"), (0x326C, Dt::Circle, "\u{1111}"),
+        (0x326D, Dt::Circle, "\u{1112}"), (0x326E, Dt::Circle, "\u{1100}\u{1161}"),
+        (0x326F, Dt::Circle, "\u{1102}\u{1161}"), (0x3270, Dt::Circle, "\u{1103}\u{1161}"),
+        (0x3271, Dt::Circle, "\u{1105}\u{1161}"), (0x3272, Dt::Circle, "\u{1106}\u{1161}"),
+        (0x3273, Dt::Circle, "\u{1107}\u{1161}"), (0x3274, Dt::Circle, "\u{1109}\u{1161}"),
+        (0x3275, Dt::Circle, "\u{110B}\u{1161}"), (0x3276, Dt::Circle, "\u{110C}\u{1161}"),
+        (0x3277, Dt::Circle, "\u{110E}\u{1161}"), (0x3278, Dt::Circle, "\u{110F}\u{1161}"),
+        (0x3279, Dt::Circle, "\u{1110}\u{1161}"), (0x327A, Dt::Circle, "\u{1111}\u{1161}"),
+        (0x327B, Dt::Circle, "\u{1112}\u{1161}"),
+        (0x327C, Dt::Circle, "\u{110E}\u{1161}\u{11B7}\u{1100}\u{1169}"),
+        (0x327D, Dt::Circle, "\u{110C}\u{116E}\u{110B}\u{1174}"),
+        (0x327E, Dt::Circle, "\u{110B}\u{116E}"), (0x3280, Dt::Circle, "\u{4E00}"),
+        (0x3281, Dt::Circle, "\u{4E8C}"), (0x3282, Dt::Circle, "\u{4E09}"),
+        (0x3283, Dt::Circle, "\u{56DB}"), (0x3284, Dt::Circle, "\u{4E94}"),
+        (0x3285, Dt::Circle, "\u{516D}"), (0x3286, Dt::Circle, "\u{4E03}"),
+        (0x3287, Dt::Circle, "\u{516B}"), (0x3288, Dt::Circle, "\u{4E5D}"),
+        (0x3289, Dt::Circle, "\u{5341}"), (0x328A, Dt::Circle, "\u{6708}"),
+        (0x328B, Dt::Circle, "\u{706B}"), (0x328C, Dt::Circle, "\u{6C34}"),
+        (0x328D, Dt::Circle, "\u{6728}"), (0x328E, Dt::Circle, "\u{91D1}"),
+        (0x328F, Dt::Circle, "\u{571F}"), (0x3290, Dt::Circle, "\u{65E5}"),
+        (0x3291, Dt::Circle, "\u{682A}"), (0x3292, Dt::Circle, "\u{6709}"),
+        (0x3293, Dt::Circle, "\u{793E}"), (0x3294, Dt::Circle, "\u{540D}"),
+        (0x3295, Dt::Circle, "\u{7279}"), (0x3296, Dt::Circle, "\u{8CA1}"),
+        (0x3297, Dt::Circle, "\u{795D}"), (0x3298, Dt::Circle, "\u{52B4}"),
+        (0x3299, Dt::Circle, "\u{79D8}"), (0x329A, Dt::Circle, "\u{7537}"),
+        (0x329B, Dt::Circle, "\u{5973}"), (0x329C, Dt::Circle, "\u{9069}"),
+        (0x329D, Dt::Circle, "\u{512A}"), (0x329E, Dt::Circle, "\u{5370}"),
+        (0x329F, Dt::Circle, "\u{6CE8}"), (0x32A0, Dt::Circle, "\u{9805}"),
+        (0x32A1, Dt::Circle, "\u{4F11}"), (0x32A2, Dt::Circle, "\u{5199}"),
+        (0x32A3, Dt::Circle, "\u{6B63}"), (0x32A4, Dt::Circle, "\u{4E0A}"),
+        (0x32A5, Dt::Circle, "\u{4E2D}"), (0x32A6, Dt::Circle, "\u{4E0B}"),
+        (0x32A7, Dt::Circle, "\u{5DE6}"), (0x32A8, Dt::Circle, "\u{53F3}"),
+        (0x32A9, Dt::Circle, "\u{533B}"), (0x32AA, Dt::Circle, "\u{5B97}"),
+        (0x32AB, Dt::Circle, "\u{5B66}"), (0x32AC, Dt::Circle, "\u{76E3}"),
+        (0x32AD, Dt::Circle, "\u{4F01}"), (0x32AE, Dt::Circle, "\u{8CC7}"),
+        (0x32AF, Dt::Circle, "\u{5354}"), (0x32B0, Dt::Circle, "\u{591C}"),
+        (0x32B1, Dt::Circle, "36"), (0x32B2, Dt::Circle, "37"), (0x32B3, Dt::Circle, "38"),
+        (0x32B4, Dt::Circle, "39"), (0x32B5, Dt::Circle, "40"), (0x32B6, Dt::Circle, "41"),
+        (0x32B7, Dt::Circle, "42"), (0x32B8, Dt::Circle, "43"), (0x32B9, Dt::Circle, "44"),
+        (0x32BA, Dt::Circle, "45"), (0x32BB, Dt::Circle, "46"), (0x32BC, Dt::Circle, "47"),
+        (0x32BD, Dt::Circle, "48"), (0x32BE, Dt::Circle, "49"), (0x32BF, Dt::Circle, "50"),
+        (0x32C0, Dt::Compat, "1\u{6708}"), (0x32C1, Dt::Compat, "2\u{6708}"),
+        (0x32C2, Dt::Compat, "3\u{6708}"), (0x32C3, Dt::Compat, "4\u{6708}"),
+        (0x32C4, Dt::Compat, "5\u{6708}"), (0x32C5, Dt::Compat, "6\u{6708}"),
+        (0x32C6, Dt::Compat, "7\u{6708}"), (0x32C7, Dt::Compat, "8\u{6708}"),
+        (0x32C8, Dt::Compat, "9\u{6708}"), (0x32C9, Dt::Compat, "10\u{6708}"),
+        (0x32CA, Dt::Compat, "11\u{6708}"), (0x32CB, Dt::Compat, "12\u{6708}"),
+        (0x32CC, Dt::Square, "Hg"), (0x32CD, Dt::Square, "erg"), (0x32CE, Dt::Square, "eV"),
+        (0x32CF, Dt::Square, "LTD"), (0x32D0, Dt::Circle, "\u{30A2}"),
+        (0x32D1, Dt::Circle, "\u{30A4}"), (0x32D2, Dt::Circle, "\u{30A6}"),
+        (0x32D3, Dt::Circle, "\u{30A8}"), (0x32D4, Dt::Circle, "\u{30AA}"),
+        (0x32D5, Dt::Circle, "\u{30AB}"), (0x32D6, Dt::Circle, "\u{30AD}"),
+        (0x32D7, Dt::Circle, "\u{30AF}"), (0x32D8, Dt::Circle, "\u{30B1}"),
+        (0x32D9, Dt::Circle, "\u{30B3}"), (0x32DA, Dt::Circle, "\u{30B5}"),
+        (0x32DB, Dt::Circle, "\u{30B7}"), (0x32DC, Dt::Circle, "\u{30B9}"),
+        (0x32DD, Dt::Circle, "\u{30BB}"), (0x32DE, Dt::Circle, "\u{30BD}"),
+        (0x32DF, Dt::Circle, "\u{30BF}"), (0x32E0, Dt::Circle, "\u{30C1}"),
+        (0x32E1, Dt::Circle, "\u{30C4}"), (0x32E2, Dt::Circle, "\u{30C6}"),
+        (0x32E3, Dt::Circle, "\u{30C8}"), (0x32E4, Dt::Circle, "\u{30CA}"),
+        (0x32E5, Dt::Circle, "\u{30CB}"), (0x32E6, Dt::Circle, "\u{30CC}"),
+        (0x32E7, Dt::Circle, "\u{30CD}"), (0x32E8, Dt::Circle, "\u{30CE}"),
+        (0x32E9, Dt::Circle, "\u{30CF}"), (0x32EA, Dt::Circle, "\u{30D2}"),
+        (0x32EB, Dt::Circle, "\u{30D5}"), (0x32EC, Dt::Circle, "\u{30D8}"),
+        (0x32ED, Dt::Circle, "\u{30DB}"), (0x32EE, Dt::Circle, "\u{30DE}"),
+        (0x32EF, Dt::Circle, "\u{30DF}"), (0x32F0, Dt::Circle, "\u{30E0}"),
+        (0x32F1, Dt::Circle, "\u{30E1}"), (0x32F2, Dt::Circle, "\u{30E2}"),
+        (0x32F3, Dt::Circle, "\u{30E4}"), (0x32F4, Dt::Circle, "\u{30E6}"),
+        (0x32F5, Dt::Circle, "\u{30E8}"), (0x32F6, Dt::Circle, "\u{30E9}"),
+        (0x32F7, Dt::Circle, "\u{30EA}"), (0x32F8, Dt::Circle, "\u{30EB}"),
+        (0x32F9, Dt::Circle, "\u{30EC}"), (0x32FA, Dt::Circle, "\u{30ED}"),
+        (0x32FB, Dt::Circle, "\u{30EF}"), (0x32FC, Dt::Circle, "\u{30F0}"),
+        (0x32FD, Dt::Circle, "\u{30F1}"), (0x32FE, Dt::Circle, "\u{30F2}"),
+        (0x32FF, Dt::Square, "\u{4EE4}\u{548C}"),
+        (0x3300, Dt::Square, "\u{30A2}\u{30D1}\u{30FC}\u{30C8}"),
+        (0x3301, Dt::Square, "\u{30A2}\u{30EB}\u{30D5}\u{30A1}"),
+        (0x3302, Dt::Square, "\u{30A2}\u{30F3}\u{30DA}\u{30A2}"),
+        (0x3303, Dt::Square, "\u{30A2}\u{30FC}\u{30EB}"),
+        (0x3304, Dt::Square, "\u{30A4}\u{30CB}\u{30F3}\u{30B0}"),
+        (0x3305, Dt::Square, "\u{30A4}\u{30F3}\u{30C1}"),
+        (0x3306, Dt::Square, "\u{30A6}\u{30A9}\u{30F3}"),
+        (0x3307, Dt::Square, "\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}"),
+        (0x3308, Dt::Square, "\u{30A8}\u{30FC}\u{30AB}\u{30FC}"),
+        (0x3309, Dt::Square, "\u{30AA}\u{30F3}\u{30B9}"),
+        (0x330A, Dt::Square, "\u{30AA}\u{30FC}\u{30E0}"),
+        (0x330B, Dt::Square, "\u{30AB}\u{30A4}\u{30EA}"),
+        (0x330C, Dt::Square, "\u{30AB}\u{30E9}\u{30C3}\u{30C8}"),
+        (0x330D, Dt::Square, "\u{30AB}\u{30ED}\u{30EA}\u{30FC}"),
+        (0x330E, Dt::Square, "\u{30AC}\u{30ED}\u{30F3}"),
+        (0x330F, Dt::Square, "\u{30AC}\u{30F3}\u{30DE}"), (0x3310, Dt::Square, "\u{30AE}\u{30AC}"),
+        (0x3311, Dt::Square, "\u{30AE}\u{30CB}\u{30FC}"),
+        (0x3312, Dt::Square, "\u{30AD}\u{30E5}\u{30EA}\u{30FC}"),
+        (0x3313, Dt::Square, "\u{30AE}\u{30EB}\u{30C0}\u{30FC}"),
+        (0x3314, Dt::Square, "\u{30AD}\u{30ED}"),
+        (0x3315, Dt::Square, "\u{30AD}\u{30ED}\u{30B0}\u{30E9}\u{30E0}"),
+        (0x3316, Dt::Square, "\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}"),
+        (0x3317, Dt::Square, "\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}"),
+        (0x3318, Dt::Square, "\u{30B0}\u{30E9}\u{30E0}"),
+        (0x3319, Dt::Square, "\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}"),
+        (0x331A, Dt::Square, "\u{30AF}\u{30EB}\u{30BC}\u{30A4}\u{30ED}"),
+        (0x331B, Dt::Square, "\u{30AF}\u{30ED}\u{30FC}\u{30CD}"),
+        (0x331C, Dt::Square, "\u{30B1}\u{30FC}\u{30B9}"),
+        (0x331D, Dt::Square, "\u{30B3}\u{30EB}\u{30CA}"),
+        (0x331E, Dt::Square, "\u{30B3}\u{30FC}\u{30DD}"),
+        (0x331F, Dt::Square, "\u{30B5}\u{30A4}\u{30AF}\u{30EB}"),
+        (0x3320, Dt::Square, "\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}"),
+        (0x3321, Dt::Square, "\u{30B7}\u{30EA}\u{30F3}\u{30B0}"),
+        (0x3322, Dt::Square, "\u{30BB}\u{30F3}\u{30C1}"),
+        (0x3323, Dt::Square, "\u{30BB}\u{30F3}\u{30C8}"),
+        (0x3324, Dt::Square, "\u{30C0}\u{30FC}\u{30B9}"), (0x3325, Dt::Square, "\u{30C7}\u{30B7}"),
+        (0x3326, Dt::Square, "\u{30C9}\u{30EB}"), (0x3327, Dt::Square, "\u{30C8}\u{30F3}"),
+        (0x3328, Dt::Square, "\u{30CA}\u{30CE}"), (0x3329, Dt::Square, "\u{30CE}\u{30C3}\u{30C8}"),
+        (0x332A, Dt::Square, "\u{30CF}\u{30A4}\u{30C4}"),
+        (0x332B, Dt::Square, "\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}"),
+        (0x332C, Dt::Square, "\u{30D1}\u{30FC}\u{30C4}"),
+        (0x332D, Dt::Square, "\u{30D0}\u{30FC}\u{30EC}\u{30EB}"),
+        (0x332E, Dt::Square, "\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}"),
+        (0x332F, Dt::Square, "\u{30D4}\u{30AF}\u{30EB}"), (0x3330, Dt::Square, "\u{30D4}\u{30B3}"),
+        (0x3331, Dt::Square, "\u{30D3}\u{30EB}"),
+        (0x3332, Dt::Square, "\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C9}"),
+        (0x3333, Dt::Square, "\u{30D5}\u{30A3}\u{30FC}\u{30C8}"),
+        (0x3334, Dt::Square, "\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}"),
+        (0x3335, Dt::Square, "\u{30D5}\u{30E9}\u{30F3}"),
+        (0x3336, Dt::Square, "\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}"),
+        (0x3337, Dt::Square, "\u{30DA}\u{30BD}"), (0x3338, Dt::Square, "\u{30DA}\u{30CB}\u{30D2}"),
+        (0x3339, Dt::Square, "\u{30D8}\u{30EB}\u{30C4}"),
+        (0x333A, Dt::Square, "\u{30DA}\u{30F3}\u{30B9}"),
+        (0x333B, Dt::Square, "\u{30DA}\u{30FC}\u{30B8}"),
+        (0x333C, Dt::Square, "\u{30D9}\u{30FC}\u{30BF}"),
+        (0x333D, Dt::Square, "\u{30DD}\u{30A4}\u{30F3}\u{30C8}"),
+        (0x333E, Dt::Square, "\u{30DC}\u{30EB}\u{30C8}"), (0x333F, Dt::Square, "\u{30DB}\u{30F3}"),
+        (0x3340, Dt::Square, "\u{30DD}\u{30F3}\u{30C9}"),
+        (0x3341, Dt::Square, "\u{30DB}\u{30FC}\u{30EB}"),
+        (0x3342, Dt::Square, "\u{30DB}\u{30FC}\u{30F3}"),
+        (0x3343, Dt::Square, "\u{30DE}\u{30A4}\u{30AF}\u{30ED}"),
+        (0x3344, Dt::Square, "\u{30DE}\u{30A4}\u{30EB}"),
+        (0x3345, Dt::Square, "\u{30DE}\u{30C3}\u{30CF}"),
+        (0x3346, Dt::Square, "\u{30DE}\u{30EB}\u{30AF}"),
+        (0x3347, Dt::Square, "\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}"),
+        (0x3348, Dt::Square, "\u{30DF}\u{30AF}\u{30ED}\u{30F3}"),
+        (0x3349, Dt::Square, "\u{30DF}\u{30EA}"),
+        (0x334A, Dt::Square, "\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}"),
+        (0x334B, Dt::Square, "\u{30E1}\u{30AC}"),
+        (0x334C, Dt::Square, "\u{30E1}\u{30AC}\u{30C8}\u{30F3}"),
+        (0x334D, Dt::Square, "\u{30E1}\u{30FC}\u{30C8}\u{30EB}"),
+        (0x334E, Dt::Square, "\u{30E4}\u{30FC}\u{30C9}"),
+        (0x334F, Dt::Square, "\u{30E4}\u{30FC}\u{30EB}"),
+        (0x3350, Dt::Square, "\u{30E6}\u{30A2}\u{30F3}"),
+        (0x3351, Dt::Square, "\u{30EA}\u{30C3}\u{30C8}\u{30EB}"),
+        (0x3352, Dt::Square, "\u{30EA}\u{30E9}"), (0x3353, Dt::Square, "\u{30EB}\u{30D4}\u{30FC}"),
+        (0x3354, Dt::Square, "\u{30EB}\u{30FC}\u{30D6}\u{30EB}"),
+        (0x3355, Dt::Square, "\u{30EC}\u{30E0}"),
+        (0x3356, Dt::Square, "\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}"),
+        (0x3357, Dt::Square, "\u{30EF}\u{30C3}\u{30C8}"), (0x3358, Dt::Compat, "0\u{70B9}"),
+        (0x3359, Dt::Compat, "1\u{70B9}"), (0x335A, Dt::Compat, "2\u{70B9}"),
+        (0x335B, Dt::Compat, "3\u{70B9}"), (0x335C, Dt::Compat, "4\u{70B9}"),
+        (0x335D, Dt::Compat, "5\u{70B9}"), (0x335E, Dt::Compat, "6\u{70B9}"),
+        (0x335F, Dt::Compat, "7\u{70B9}"), (0x3360, Dt::Compat, "8\u{70B9}"),
+        (0x3361, Dt::Compat, "9\u{70B9}"), (0x3362, Dt::Compat, "10\u{70B9}"),
+        (0x3363, Dt::Compat, "11\u{70B9}"), (0x3364, Dt::Compat, "12\u{70B9}"),
+        (0x3365, Dt::Compat, "13\u{70B9}"), (0x3366, Dt::Compat, "14\u{70B9}"),
+        (0x3367, Dt::Compat, "15\u{70B9}"), (0x3368, Dt::Compat, "16\u{70B9}"),
+        (0x3369, Dt::Compat, "17\u{70B9}"), (0x336A, Dt::Compat, "18\u{70B9}"),
+        (0x336B, Dt::Compat, "19\u{70B9}"), (0x336C, Dt::Compat, "20\u{70B9}"),
+        (0x336D, Dt::Compat, "21\u{70B9}"), (0x336E, Dt::Compat, "22\u{70B9}"),
+        (0x336F, Dt::Compat, "23\u{70B9}"), (0x3370, Dt::Compat, "24\u{70B9}"),
+        (0x3371, Dt::Square, "hPa"), (0x3372, Dt::Square, "da"), (0x3373, Dt::Square, "AU"),
+        (0x3374, Dt::Square, "bar"), (0x3375, Dt::Square, "oV"), (0x3376, Dt::Square, "pc"),
+        (0x3377, Dt::Square, "dm"), (0x3378, Dt::Square, "dm\u{B2}"),
+        (0x3379, Dt::Square, "dm\u{B3}"), (0x337A, Dt::Square, "IU"),
+        (0x337B, Dt::Square, "\u{5E73}\u{6210}"), (0x337C, Dt::Square, "\u{662D}\u{548C}"),
+        (0x337D, Dt::Square, "\u{5927}\u{6B63}"), (0x337E, Dt::Square, "\u{660E}\u{6CBB}"),
+        (0x337F, Dt::Square, "\u{682A}\u{5F0F}\u{4F1A}\u{793E}"), (0x3380, Dt::Square, "pA"),
+        (0x3381, Dt::Square, "nA"), (0x3382, Dt::Square, "\u{3BC}A"), (0x3383, Dt::Square, "mA"),
+        (0x3384, Dt::Square, "kA"), (0x3385, Dt::Square, "KB"), (0x3386, Dt::Square, "MB"),
+        (0x3387, Dt::Square, "GB"), (0x3388, Dt::Square, "cal"), (0x3389, Dt::Square, "kcal"),
+        (0x338A, Dt::Square, "pF"), (0x338B, Dt::Square, "nF"), (0x338C, Dt::Square, "\u{3BC}F"),
+        (0x338D, Dt::Square, "\u{3BC}g"), (0x338E, Dt::Square, "mg"), (0x338F, Dt::Square, "kg"),
+        (0x3390, Dt::Square, "Hz"), (0x3391, Dt::Square, "kHz"), (0x3392, Dt::Square, "MHz"),
+        (0x3393, Dt::Square, "GHz"), (0x3394, Dt::Square, "THz"),
+        (0x3395, Dt::Square, "\u{3BC}\u{2113}"), (0x3396, Dt::Square, "m\u{2113}"),
+        (0x3397, Dt::Square, "d\u{2113}"), (0x3398, Dt::Square, "k\u{2113}"),
+        (0x3399, Dt::Square, "fm"), (0x339A, Dt::Square, "nm"), (0x339B, Dt::Square, "\u{3BC}m"),
+        (0x339C, Dt::Square, "mm"), (0x339D, Dt::Square, "cm"), (0x339E, Dt::Square, "km"),
+        (0x339F, Dt::Square, "mm\u{B2}"), (0x33A0, Dt::Square, "cm\u{B2}"),
+        (0x33A1, Dt::Square, "m\u{B2}"), (0x33A2, Dt::Square, "km\u{B2}"),
+        (0x33A3, Dt::Square, "mm\u{B3}"), (0x33A4, Dt::Square, "cm\u{B3}"),
+        (0x33A5, Dt::Square, "m\u{B3}"), (0x33A6, Dt::Square, "km\u{B3}"),
+        (0x33A7, Dt::Square, "m\u{2215}s"), (0x33A8, Dt::Square, "m\u{2215}s\u{B2}"),
+        (0x33A9, Dt::Square, "Pa"), (0x33AA, Dt::Square, "kPa"), (0x33AB, Dt::Square, "MPa"),
+        (0x33AC, Dt::Square, "GPa"), (0x33AD, Dt::Square, "rad"),
+        (0x33AE, Dt::Square, "rad\u{2215}s"), (0x33AF, Dt::Square, "rad\u{2215}s\u{B2}"),
+        (0x33B0, Dt::Square, "ps"), (0x33B1, Dt::Square, "ns"), (0x33B2, Dt::Square, "\u{3BC}s"),
+        (0x33B3, Dt::Square, "ms"), (0x33B4, Dt::Square, "pV"), (0x33B5, Dt::Square, "nV"),
+        (0x33B6, Dt::Square, "\u{3BC}V"), (0x33B7, Dt::Square, "mV"), (0x33B8, Dt::Square, "kV"),
+        (0x33B9, Dt::Square, "MV"), (0x33BA, Dt::Square, "pW"), (0x33BB, Dt::Square, "nW"),
+        (0x33BC, Dt::Square, "\u{3BC}W"), (0x33BD, Dt::Square, "mW"), (0x33BE, Dt::Square, "kW"),
+        (0x33BF, Dt::Square, "MW"), (0x33C0, Dt::Square, "k\u{3A9}"),
+        (0x33C1, Dt::Square, "M\u{3A9}"), (0x33C2, Dt::Square, "a.m."), (0x33C3, Dt::Square, "Bq"),
+        (0x33C4, Dt::Square, "cc"), (0x33C5, Dt::Square, "cd"), (0x33C6, Dt::Square, "C\u{2215}kg"),
+        (0x33C7, Dt::Square, "Co."), (0x33C8, Dt::Square, "dB"), (0x33C9, Dt::Square, "Gy"),
+        (0x33CA, Dt::Square, "ha"), (0x33CB, Dt::Square, "HP"), (0x33CC, Dt::Square, "in"),
+        (0x33CD, Dt::Square, "KK"), (0x33CE, Dt::Square, "KM"), (0x33CF, Dt::Square, "kt"),
+        (0x33D0, Dt::Square, "lm"), (0x33D1, Dt::Square, "ln"), (0x33D2, Dt::Square, "log"),
+        (0x33D3, Dt::Square, "lx"), (0x33D4, Dt::Square, "mb"), (0x33D5, Dt::Square, "mil"),
+        (0x33D6, Dt::Square, "mol"), (0x33D7, Dt::Square, "PH"), (0x33D8, Dt::Square, "p.m."),
+        (0x33D9, Dt::Square, "PPM"), (0x33DA, Dt::Square, "PR"), (0x33DB, Dt::Square, "sr"),
+        (0x33DC, Dt::Square, "Sv"), (0x33DD, Dt::Square, "Wb"), (0x33DE, Dt::Square, "V\u{2215}m"),
+        (0x33DF, Dt::Square, "A\u{2215}m"), (0x33E0, Dt::Compat, "1\u{65E5}"),
+        (0x33E1, Dt::Compat, "2\u{65E5}"), (0x33E2, Dt::Compat, "3\u{65E5}"),
+        (0x33E3, Dt::Compat, "4\u{65E5}"), (0x33E4, Dt::Compat, "5\u{65E5}"),
+        (0x33E5, Dt::Compat, "6\u{65E5}"), (0x33E6, Dt::Compat, "7\u{65E5}"),
+        (0x33E7, Dt::Compat, "8\u{65E5}"), (0x33E8, Dt::Compat, "9\u{65E5}"),
+        (0x33E9, Dt::Compat, "10\u{65E5}"), (0x33EA, Dt::Compat, "11\u{65E5}"),
+        (0x33EB, Dt::Compat, "12\u{65E5}"), (0x33EC, Dt::Compat, "13\u{65E5}"),
+        (0x33ED, Dt::Compat, "14\u{65E5}"), (0x33EE, Dt::Compat, "15\u{65E5}"),
+        (0x33EF, Dt::Compat, "16\u{65E5}"), (0x33F0, Dt::Compat, "17\u{65E5}"),
+        (0x33F1, Dt::Compat, "18\u{65E5}"), (0x33F2, Dt::Compat, "19\u{65E5}"),
+        (0x33F3, Dt::Compat, "20\u{65E5}"), (0x33F4, Dt::Compat, "21\u{65E5}"),
+        (0x33F5, Dt::Compat, "22\u{65E5}"), (0x33F6, Dt::Compat, "23\u{65E5}"),
+        (0x33F7, Dt::Compat, "24\u{65E5}"), (0x33F8, Dt::Compat, "25\u{65E5}"),
+        (0x33F9, Dt::Compat, "26\u{65E5}"), (0x33FA, Dt::Compat, "27\u{65E5}"),
+        (0x33FB, Dt::Compat, "28\u{65E5}"), (0x33FC, Dt::Compat, "29\u{65E5}"),
+        (0x33FD, Dt::Compat, "30\u{65E5}"), (0x33FE, Dt::Compat, "31\u{65E5}"),
+        (0x33FF, Dt::Square, "gal"), (0xA69C, Dt::Super, "\u{44A}"), (0xA69D, Dt::Super, "\u{44C}"),
+        (0xA770, Dt::Super, "\u{A76F}"), (0xA7F2, Dt::Super, "C"), (0xA7F3, Dt::Super, "F"),
+        (0xA7F4, Dt::Super, "Q"), (0xA7F8, Dt::Super, "\u{126}"), (0xA7F9, Dt::Super, "\u{153}"),
+        (0xAB5C, Dt::Super, "\u{A727}"), (0xAB5D, Dt::Super, "\u{AB37}"),
+        (0xAB5E, Dt::Super, "\u{26B}"), (0xAB5F, Dt::Super, "\u{AB52}"),
+        (0xAB69, Dt::Super, "\u{28D}"), (0xF900, Dt::Canonical, "\u{8C48}"),
+        (0xF901, Dt::Canonical, "\u{66F4}"), (0xF902, Dt::Canonical, "\u{8ECA}"),
+        (0xF903, Dt::Canonical, "\u{8CC8}"), (0xF904, Dt::Canonical, "\u{6ED1}"),
+        (0xF905, Dt::Canonical, "\u{4E32}"), (0xF906, Dt::Canonical, "\u{53E5}"),
+        (0xF907, Dt::Canonical, "\u{9F9C}"), (0xF908, Dt::Canonical, "\u{9F9C}"),
+        (0xF909, Dt::Canonical, "\u{5951}"), (0xF90A, Dt::Canonical, "\u{91D1}"),
+        (0xF90B, Dt::Canonical, "\u{5587}"), (0xF90C, Dt::Canonical, "\u{5948}"),
+        (0xF90D, Dt::Canonical, "\u{61F6}"), (0xF90E, Dt::Canonical, "\u{7669}"),
+        (0xF90F, Dt::Canonical, "\u{7F85}"), (0xF910, Dt::Canonical, "\u{863F}"),
+        (0xF911, Dt::Canonical, "\u{87BA}"), (0xF912, Dt::Canonical, "\u{88F8}"),
+        (0xF913, Dt::Canonical, "\u{908F}"), (0xF914, Dt::Canonical, "\u{6A02}"),
+        (0xF915, Dt::Canonical, "\u{6D1B}"), (0xF916, Dt::Canonical, "\u{70D9}"),
+        (0xF917, Dt::Canonical, "\u{73DE}"), (0xF918, Dt::Canonical, "\u{843D}"),
+        (0xF919, Dt::Canonical, "\u{916A}"), (0xF91A, Dt::Canonical, "\u{99F1}"),
+        (0xF91B, Dt::Canonical, "\u{4E82}"), (0xF91C, Dt::Canonical, "\u{5375}"),
+        (0xF91D, Dt::Canonical, "\u{6B04}"), (0xF91E, Dt::Canonical, "\u{721B}"),
+        (0xF91F, Dt::Canonical, "\u{862D}"), (0xF920, Dt::Canonical, "\u{9E1E}"),
+        (0xF921, Dt::Canonical, "\u{5D50}"), (0xF922, Dt::Canonical, "\u{6FEB}"),
+        (0xF923, Dt::Canonical, "\u{85CD}"), (0xF924, Dt::Canonical, "\u{8964}"),
+        (0xF925, Dt::Canonical, "\u{62C9}"), (0xF926, Dt::Canonical, "\u{81D8}"),
+        (0xF927, Dt::Canonical, "\u{881F}"), (0xF928, Dt::Canonical, "\u{5ECA}"),
+        (0xF929, Dt::Canonical, "\u{6717}"), (0xF92A, Dt::Canonical, "\u{6D6A}"),
+        (0xF92B, Dt::Canonical, "\u{72FC}"), (0xF92C, Dt::Canonical, "\u{90CE}"),
+        (0xF92D, Dt::Canonical, "\u{4F86}"), (0xF92E, Dt::Canonical, "\u{51B7}"),
+        (0xF92F, Dt::Canonical, "\u{52DE}"), (0xF930, Dt::Canonical, "\u{64C4}"),
+        (0xF931, Dt::Canonical, "\u{6AD3}"), (0xF932, Dt::Canonical, "\u{7210}"),
+        (0xF933, Dt::Canonical, "\u{76E7}"), (0xF934, Dt::Canonical, "\u{8001}"),
+        (0xF935, Dt::Canonical, "\u{8606}"), (0xF936, Dt::Canonical, "\u{865C}"),
+        (0xF937, Dt::Canonical, "\u{8DEF}"), (0xF938, Dt::Canonical, "\u{9732}"),
+        (0xF939, Dt::Canonical, "\u{9B6F}"), (0xF93A, Dt::Canonical, "\u{9DFA}"),
+        (0xF93B, Dt::Canonical, "\u{788C}"), (0xF93C, Dt::Canonical, "\u{797F}"),
+        (0xF93D, Dt::Canonical, "\u{7DA0}"), (0xF93E, Dt::Canonical, "\u{83C9}"),
+        (0xF93F, Dt::Canonical, "\u{9304}"), (0xF940, Dt::Canonical, "\u{9E7F}"),
+        (0xF941, Dt::Canonical, "\u{8AD6}"), (0xF942, Dt::Canonical, "\u{58DF}"),
+        (0xF943, Dt::Canonical, "\u{5F04}"), (0xF944, Dt::Canonical, "\u{7C60}"),
+        (0xF945, Dt::Canonical, "\u{807E}"), (0xF946, Dt::Canonical, "\u{7262}"),
+        (0xF947, Dt::Canonical, "\u{78CA}"), (0xF948, Dt::Canonical, "\u{8CC2}"),
+        (0xF949, Dt::Canonical, "\u{96F7}"), (0xF94A, Dt::Canonical, "\u{58D8}"),
+        (0xF94B, Dt::Canonical, "\u{5C62}"), (0xF94C, Dt::Canonical, "\u{6A13}"),
+        (0xF94D, Dt::Canonical, "\u{6DDA}"), (0xF94E, Dt::Canonical, "\u{6F0F}"),
+        (0xF94F, Dt::Canonical, "\u{7D2F}"), (0xF950, Dt::Canonical, "\u{7E37}"),
+        (0xF951, Dt::Canonical, "\u{964B}"), (0xF952, Dt::Canonical, "\u{52D2}"),
+        (0xF953, Dt::Canonical, "\u{808B}"), (0xF954, Dt::Canonical, "\u{51DC}"),
+        (0xF955, Dt::Canonical, "\u{51CC}"), (0xF956, Dt::Canonical, "\u{7A1C}"),
+        (0xF957, Dt::Canonical, "\u{7DBE}"), (0xF958, Dt::Canonical, "\u{83F1}"),
+        (0xF959, Dt::Canonical, "\u{9675}"), (0xF95A, Dt::Canonical, "\u{8B80}"),
+        (0xF95B, Dt::Canonical, "\u{62CF}"), (0xF95C, Dt::Canonical, "\u{6A02}"),
+        (0xF95D, Dt::Canonical, "\u{8AFE}"), (0xF95E, Dt::Canonical, "\u{4E39}"),
+        (0xF95F, Dt::Canonical, "\u{5BE7}"), (0xF960, Dt::Canonical, "\u{6012}"),
+        (0xF961, Dt::Canonical, "\u{7387}"), (0xF962, Dt::Canonical, "\u{7570}"),
+        (0xF963, Dt::Canonical, "\u{5317}"), (0xF964, Dt::Canonical, "\u{78FB}"),
+        (0xF965, Dt::Canonical, "\u{4FBF}"), (0xF966, Dt::Canonical, "\u{5FA9}"),
+        (0xF967, Dt::Canonical, "\u{4E0D}"), (0xF968, Dt::Canonical, "\u{6CCC}"),
+        (0xF969, Dt::Canonical, "\u{6578}"), (0xF96A, Dt::Canonical, "\u{7D22}"),
+        (0xF96B, Dt::Canonical, "\u{53C3}"), (0xF96C, Dt::Canonical, "\u{585E}"),
+        (0xF96D, Dt::Canonical, "\u{7701}"), (0xF96E, Dt::Canonical, "\u{8449}"),
+        (0xF96F, Dt::Canonical, "\u{8AAA}"), (0xF970, Dt::Canonical, "\u{6BBA}"),
+        (0xF971, Dt::Canonical, "\u{8FB0}"), (0xF972, Dt::Canonical, "\u{6C88}"),
+        (0xF973, Dt::Canonical, "\u{62FE}"), (0xF974, Dt::Canonical, "\u{82E5}"),
+        (0xF975, Dt::Canonical, "\u{63A0}"), (0xF976, Dt::Canonical, "\u{7565}"),
+        (0xF977, Dt::Canonical, "\u{4EAE}"), (0xF978, Dt::Canonical, "\u{5169}"),
+        (0xF979, Dt::Canonical, "\u{51C9}"), (0xF97A, Dt::Canonical, "\u{6881}"),
+        (0xF97B, Dt::Canonical, "\u{7CE7}"), (0xF97C, Dt::Canonical, "\u{826F}"),
+        (0xF97D, Dt::Canonical, "\u{8AD2}"), (0xF97E, Dt::Canonical, "\u{91CF}"),
+        (0xF97F, Dt::Canonical, "\u{52F5}"), (0xF980, Dt::Canonical, "\u{5442}"),
+        (0xF981, Dt::Canonical, "\u{5973}"), (0xF982, Dt::Canonical, "\u{5EEC}"),
+        (0xF983, Dt::Canonical, "\u{65C5}"), (0xF984, Dt::Canonical, "\u{6FFE}"),
+        (0xF985, Dt::Canonical, "\u{792A}"), (0xF986, Dt::Canonical, "\u{95AD}"),
+        (0xF987, Dt::Canonical, "\u{9A6A}"), (0xF988, Dt::Canonical, "\u{9E97}"),
+        (0xF989, Dt::Canonical, "\u{9ECE}"), (0xF98A, Dt::Canonical, "\u{529B}"),
+        (0xF98B, Dt::Canonical, "\u{66C6}"), (0xF98C, Dt::Canonical, "\u{6B77}"),
+        (0xF98D, Dt::Canonical, "\u{8F62}"), (0xF98E, Dt::Canonical, "\u{5E74}"),
+        (0xF98F, Dt::Canonical, "\u{6190}"), (0xF990, Dt::Canonical, "\u{6200}"),
+        (0xF991, Dt::Canonical, "\u{649A}"), (0xF992, Dt::Canonical, "\u{6F23}"),
+        (0xF993, Dt::Canonical, "\u{7149}"), (0xF994, Dt::Canonical, "\u{7489}"),
+        (0xF995, Dt::Canonical, "\u{79CA}"), (0xF996, Dt::Canonical, "\u{7DF4}"),
+        (0xF997, Dt::Canonical, "\u{806F}"), (0xF998, Dt::Canonical, "\u{8F26}"),
+        (0xF999, Dt::Canonical, "\u{84EE}"), (0xF99A, Dt::Canonical, "\u{9023}"),
+        (0xF99B, Dt::Canonical, "\u{934A}"), (0xF99C, Dt::Canonical, "\u{5217}"),
+        (0xF99D, Dt::Canonical, "\u{52A3}"), (0xF99E, Dt::Canonical, "\u{54BD}"),
+        (0xF99F, Dt::Canonical, "\u{70C8}"), (0xF9A0, Dt::Canonical, "\u{88C2}"),
+        (0xF9A1, Dt::Canonical, "\u{8AAA}"), (0xF9A2, Dt::Canonical, "\u{5EC9}"),
+        (0xF9A3, Dt::Canonical, "\u{5FF5}"), (0xF9A4, Dt::Canonical, "\u{637B}"),
+        (0xF9A5, Dt::Canonical, "\u{6BAE}"), (0xF9A6, Dt::Canonical, "\u{7C3E}"),
+        (0xF9A7, Dt::Canonical, "\u{7375}"), (0xF9A8, Dt::Canonical, "\u{4EE4}"),
+        (0xF9A9, Dt::Canonical, "\u{56F9}"), (0xF9AA, Dt::Canonical, "\u{5BE7}"),
+        (0xF9AB, Dt::Canonical, "\u{5DBA}"), (0xF9AC, Dt::Canonical, "\u{601C}"),
+        (0xF9AD, Dt::Canonical, "\u{73B2}"), (0xF9AE, Dt::Canonical, "\u{7469}"),
+        (0xF9AF, Dt::Canonical, "\u{7F9A}"), (0xF9B0, Dt::Canonical, "\u{8046}"),
+        (0xF9B1, Dt::Canonical, "\u{9234}"), (0xF9B2, Dt::Canonical, "\u{96F6}"),
+        (0xF9B3, Dt::Canonical, "\u{9748}"), (0xF9B4, Dt::Canonical, "\u{9818}"),
+        (0xF9B5, Dt::Canonical, "\u{4F8B}"), (0xF9B6, Dt::Canonical, "\u{79AE}"),
+        (0xF9B7, Dt::Canonical, "\u{91B4}"), (0xF9B8, Dt::Canonical, "\u{96B8}"),
+        (0xF9B9, Dt::Canonical, "\u{60E1}"), (0xF9BA, Dt::Canonical, "\u{4E86}"),
+        (0xF9BB, Dt::Canonical, "\u{50DA}"), (0xF9BC, Dt::Canonical, "\u{5BEE}"),
+        (0xF9BD, Dt::Canonical, "\u{5C3F}"), (0xF9BE, Dt::Canonical, "\u{6599}"),
+        (0xF9BF, Dt::Canonical, "\u{6A02}"), (0xF9C0, Dt::Canonical, "\u{71CE}"),
+        (0xF9C1, Dt::Canonical, "\u{7642}"), (0xF9C2, Dt::Canonical, "\u{84FC}"),
+        (0xF9C3, Dt::Canonical, "\u{907C}"), (0xF9C4, Dt::Canonical, "\u{9F8D}"),
+        (0xF9C5, Dt::Canonical, "\u{6688}"), (0xF9C6, Dt::Canonical, "\u{962E}"),
+        (0xF9C7, Dt::Canonical, "\u{5289}"), (0xF9C8, Dt::Canonical, "\u{677B}"),
+        (0xF9C9, Dt::Canonical, "\u{67F3}"), (0xF9CA, Dt::Canonical, "\u{6D41}"),
+        (0xF9CB, Dt::Canonical, "\u{6E9C}"), (0xF9CC, Dt::Canonical, "\u{7409}"),
+        (0xF9CD, Dt::Canonical, "\u{7559}"), (0xF9CE, Dt::Canonical, "\u{786B}"),
+        (0xF9CF, Dt::Canonical, "\u{7D10}"), (0xF9D0, Dt::Canonical, "\u{985E}"),
+        (0xF9D1, Dt::Canonical, "\u{516D}"), (0xF9D2, Dt::Canonical, "\u{622E}"),
+        (0xF9D3, Dt::Canonical, "\u{9678}"), (0xF9D4, Dt::Canonical, "\u{502B}"),
+        (0xF9D5, Dt::Canonical, "\u{5D19}"), (0xF9D6, Dt::Canonical, "\u{6DEA}"),
+        (0xF9D7, Dt::Canonical, "\u{8F2A}"), (0xF9D8, Dt::Canonical, "\u{5F8B}"),
+        (0xF9D9, Dt::Canonical, "\u{6144}"), (0xF9DA, Dt::Canonical, "\u{6817}"),
+        (0xF9DB, Dt::Canonical, "\u{7387}"), (0xF9DC, Dt::Canonical, "\u{9686}"),
+        (0xF9DD, Dt::Canonical, "\u{5229}"), (0xF9DE, Dt::Canonical, "\u{540F}"),
+        (0xF9DF, Dt::Canonical, "\u{5C65}"), (0xF9E0, Dt::Canonical, "\u{6613}"),
+        (0xF9E1, Dt::Canonical, "\u{674E}"), (0xF9E2, Dt::Canonical, "\u{68A8}"),
+        (0xF9E3, Dt::Canonical, "\u{6CE5}"), (0xF9E4, Dt::Canonical, "\u{7406}"),
+        (0xF9E5, Dt::Canonical, "\u{75E2}"), (0xF9E6, Dt::Canonical, "\u{7F79}"),
+        (0xF9E7, Dt::Canonical, "\u{88CF}"), (0xF9E8, Dt::Canonical, "\u{88E1}"),
+        (0xF9E9, Dt::Canonical, "\u{91CC}"), (0xF9EA, Dt::Canonical, "\u{96E2}"),
+        (0xF9EB, Dt::Canonical, "\u{533F}"), (0xF9EC, Dt::Canonical, "\u{6EBA}"),
+        (0xF9ED, Dt::Canonical, "\u{541D}"), (0xF9EE, Dt::Canonical, "\u{71D0}"),
+        (0xF9EF, Dt::Canonical, "\u{7498}"), (0xF9F0, Dt::Canonical, "\u{85FA}"),
+        (0xF9F1, Dt::Canonical, "\u{96A3}"), (0xF9F2, Dt::Canonical, "\u{9C57}"),
+        (0xF9F3, Dt::Canonical, "\u{9E9F}"), (0xF9F4, Dt::Canonical, "\u{6797}"),
+        (0xF9F5, Dt::Canonical, "\u{6DCB}"), (0xF9F6, Dt::Canonical, "\u{81E8}"),
+        (0xF9F7, Dt::Canonical, "\u{7ACB}"), (0xF9F8, Dt::Canonical, "\u{7B20}"),
+        (0xF9F9, Dt::Canonical, "\u{7C92}"), (0xF9FA, Dt::Canonical, "\u{72C0}"),
+        (0xF9FB, Dt::Canonical, "\u{7099}"), (0xF9FC, Dt::Canonical, "\u{8B58}"),
+        (0xF9FD, Dt::Canonical, "\u{4EC0}"), (0xF9FE, Dt::Canonical, "\u{8336}"),
+        (0xF9FF, Dt::Canonical, "\u{523A}"), (0xFA00, Dt::Canonical, "\u{5207}"),
+        (0xFA01, Dt::Canonical, "\u{5EA6}"), (0xFA02, Dt::Canonical, "\u{62D3}"),
+        (0xFA03, Dt::Canonical, "\u{7CD6}"), (0xFA04, Dt::Canonical, "\u{5B85}"),
+        (0xFA05, Dt::Canonical, "\u{6D1E}"), (0xFA06, Dt::Canonical, "\u{66B4}"),
+        (0xFA07, Dt::Canonical, "\u{8F3B}"), (0xFA08, Dt::Canonical, "\u{884C}"),
+        (0xFA09, Dt::Canonical, "\u{964D}"), (0xFA0A, Dt::Canonical, "\u{898B}"),
+        (0xFA0B, Dt::Canonical, "\u{5ED3}"), (0xFA0C, Dt::Canonical, "\u{5140}"),
+        (0xFA0D, Dt::Canonical, "\u{55C0}"), (0xFA10, Dt::Canonical, "\u{585A}"),
+        (0xFA12, Dt::Canonical, "\u{6674}"), (0xFA15, Dt::Canonical, "\u{51DE}"),
+        (0xFA16, Dt::Canonical, "\u{732A}"), (0xFA17, Dt::Canonical, "\u{76CA}"),
+        (0xFA18, Dt::Canonical, "\u{793C}"), (0xFA19, Dt::Canonical, "\u{795E}"),
+        (0xFA1A, Dt::Canonical, "\u{7965}"), (0xFA1B, Dt::Canonical, "\u{798F}"),
+        (0xFA1C, Dt::Canonical, "\u{9756}"), (0xFA1D, Dt::Canonical, "\u{7CBE}"),
+        (0xFA1E, Dt::Canonical, "\u{7FBD}"), (0xFA20, Dt::Canonical, "\u{8612}"),
+        (0xFA22, Dt::Canonical, "\u{8AF8}"), (0xFA25, Dt::Canonical, "\u{9038}"),
+        (0xFA26, Dt::Canonical, "\u{90FD}"), (0xFA2A, Dt::Canonical, "\u{98EF}"),
+        (0xFA2B, Dt::Canonical, "\u{98FC}"), (0xFA2C, Dt::Canonical, "\u{9928}"),
+        (0xFA2D, Dt::Canonical, "\u{9DB4}"), (0xFA2E, Dt::Canonical, "\u{90DE}"),
+        (0xFA2F, Dt::Canonical, "\u{96B7}"), (0xFA30, Dt::Canonical, "\u{4FAE}"),
+        (0xFA31, Dt::Canonical, "\u{50E7}"), (0xFA32, Dt::Canonical, "\u{514D}"),
+        (0xFA33, Dt::Canonical, "\u{52C9}"), (0xFA34, Dt::Canonical, "\u{52E4}"),
+        (0xFA35, Dt::Canonical, "\u{5351}"), (0xFA36, Dt::Canonical, "\u{559D}"),
+        (0xFA37, Dt::Canonical, "\u{5606}"), (0xFA38, Dt::Canonical, "\u{5668}"),
+        (0xFA39, Dt::Canonical, "\u{5840}"), (0xFA3A, Dt::Canonical, "\u{58A8}"),
+        (0xFA3B, Dt::Canonical, "\u{5C64}"), (0xFA3C, Dt::Canonical, "\u{5C6E}"),
+        (0xFA3D, Dt::Canonical, "\u{6094}"), (0xFA3E, Dt::Canonical, "\u{6168}"),
+        (0xFA3F, Dt::Canonical, "\u{618E}"), (0xFA40, Dt::Canonical, "\u{61F2}"),
+        (0xFA41, Dt::Canonical, "\u{654F}"), (0xFA42, Dt::Canonical, "\u{65E2}"),
+        (0xFA43, Dt::Canonical, "\u{6691}"), (0xFA44, Dt::Canonical, "\u{6885}"),
+        (0xFA45, Dt::Canonical, "\u{6D77}"), (0xFA46, Dt::Canonical, "\u{6E1A}"),
+        (0xFA47, Dt::Canonical, "\u{6F22}"), (0xFA48, Dt::Canonical, "\u{716E}"),
+        (0xFA49, Dt::Canonical, "\u{722B}"), (0xFA4A, Dt::Canonical, "\u{7422}"),
+        (0xFA4B, Dt::Canonical, "\u{7891}"), (0xFA4C, Dt::Canonical, "\u{793E}"),
+        (0xFA4D, Dt::Canonical, "\u{7949}"), (0xFA4E, Dt::Canonical, "\u{7948}"),
+        (0xFA4F, Dt::Canonical, "\u{7950}"), (0xFA50, Dt::Canonical, "\u{7956}"),
+        (0xFA51, Dt::Canonical, "\u{795D}"), (0xFA52, Dt::Canonical, "\u{798D}"),
+        (0xFA53, Dt::Canonical, "\u{798E}"), (0xFA54, Dt::Canonical, "\u{7A40}"),
+        (0xFA55, Dt::Canonical, "\u{7A81}"), (0xFA56, Dt::Canonical, "\u{7BC0}"),
+        (0xFA57, Dt::Canonical, "\u{7DF4}"), (0xFA58, Dt::Canonical, "\u{7E09}"),
+        (0xFA59, Dt::Canonical, "\u{7E41}"), (0xFA5A, Dt::Canonical, "\u{7F72}"),
+        (0xFA5B, Dt::Canonical, "\u{8005}"), (0xFA5C, Dt::Canonical, "\u{81ED}"),
+        (0xFA5D, Dt::Canonical, "\u{8279}"), (0xFA5E, Dt::Canonical, "\u{8279}"),
+        (0xFA5F, Dt::Canonical, "\u{8457}"), (0xFA60, Dt::Canonical, "\u{8910}"),
+        (0xFA61, Dt::Canonical, "\u{8996}"), (0xFA62, Dt::Canonical, "\u{8B01}"),
+        (0xFA63, Dt::Canonical, "\u{8B39}"), (0xFA64, Dt::Canonical, "\u{8CD3}"),
+        (0xFA65, Dt::Canonical, "\u{8D08}"), (0xFA66, Dt::Canonical, "\u{8FB6}"),
+        (0xFA67, Dt::Canonical, "\u{9038}"), (0xFA68, Dt::Canonical, "\u{96E3}"),
+        (0xFA69, Dt::Canonical, "\u{97FF}"), (0xFA6A, Dt::Canonical, "\u{983B}"),
+        (0xFA6B, Dt::Canonical, "\u{6075}"), (0xFA6C, Dt::Canonical, "\u{242EE}"),
+        (0xFA6D, Dt::Canonical, "\u{8218}"), (0xFA70, Dt::Canonical, "\u{4E26}"),
+        (0xFA71, Dt::Canonical, "\u{51B5}"), (0xFA72, Dt::Canonical, "\u{5168}"),
+        (0xFA73, Dt::Canonical, "\u{4F80}"), (0xFA74, Dt::Canonical, "\u{5145}"),
+        (0xFA75, Dt::Canonical, "\u{5180}"), (0xFA76, Dt::Canonical, "\u{52C7}"),
+        (0xFA77, Dt::Canonical, "\u{52FA}"), (0xFA78, Dt::Canonical, "\u{559D}"),
+        (0xFA79, Dt::Canonical, "\u{5555}"), (0xFA7A, Dt::Canonical, "\u{5599}"),
+        (0xFA7B, Dt::Canonical, "\u{55E2}"), (0xFA7C, Dt::Canonical, "\u{585A}"),
+        (0xFA7D, Dt::Canonical, "\u{58B3}"), (0xFA7E, Dt::Canonical, "\u{5944}"),
+        (0xFA7F, Dt::Canonical, "\u{5954}"), (0xFA80, Dt::Canonical, "\u{5A62}"),
+        (0xFA81, Dt::Canonical, "\u{5B28}"), (0xFA82, Dt::Canonical, "\u{5ED2}"),
+        (0xFA83, Dt::Canonical, "\u{5ED9}"), (0xFA84, Dt::Canonical, "\u{5F69}"),
+        (0xFA85, Dt::Canonical, "\u{5FAD}"), (0xFA86, Dt::Canonical, "\u{60D8}"),
+        (0xFA87, Dt::Canonical, "\u{614E}"), (0xFA88, Dt::Canonical, "\u{6108}"),
+        (0xFA89, Dt::Canonical, "\u{618E}"), (0xFA8A, Dt::Canonical, "\u{6160}"),
+        (0xFA8B, Dt::Canonical, "\u{61F2}"), (0xFA8C, Dt::Canonical, "\u{6234}"),
+        (0xFA8D, Dt::Canonical, "\u{63C4}"), (0xFA8E, Dt::Canonical, "\u{641C}"),
+        (0xFA8F, Dt::Canonical, "\u{6452}"), (0xFA90, Dt::Canonical, "\u{6556}"),
+        (0xFA91, Dt::Canonical, "\u{6674}"), (0xFA92, Dt::Canonical, "\u{6717}"),
+        (0xFA93, Dt::Canonical, "\u{671B}"), (0xFA94, Dt::Canonical, "\u{6756}"),
+        (0xFA95, Dt::Canonical, "\u{6B79}"), (0xFA96, Dt::Canonical, "\u{6BBA}"),
+        (0xFA97, Dt::Canonical, "\u{6D41}"), (0xFA98, Dt::Canonical, "\u{6EDB}"),
+        (0xFA99, Dt::Canonical, "\u{6ECB}"), (0xFA9A, Dt::Canonical, "\u{6F22}"),
+        (0xFA9B, Dt::Canonical, "\u{701E}"), (0xFA9C, Dt::Canonical, "\u{716E}"),
+        (0xFA9D, Dt::Canonical, "\u{77A7}"), (0xFA9E, Dt::Canonical, "\u{7235}"),
+        (0xFA9F, Dt::Canonical, "\u{72AF}"), (0xFAA0, Dt::Canonical, "\u{732A}"),
+        (0xFAA1, Dt::Canonical, "\u{7471}"), (0xFAA2, Dt::Canonical, "\u{7506}"),
+        (0xFAA3, Dt::Canonical, "\u{753B}"), (0xFAA4, Dt::Canonical, "\u{761D}"),
+        (0xFAA5, Dt::Canonical, "\u{761F}"), (0xFAA6, Dt::Canonical, "\u{76CA}"),
+        (0xFAA7, Dt::Canonical, "\u{76DB}"), (0xFAA8, Dt::Canonical, "\u{76F4}"),
+        (0xFAA9, Dt::Canonical, "\u{774A}"), (0xFAAA, Dt::Canonical, "\u{7740}"),
+        (0xFAAB, Dt::Canonical, "\u{78CC}"), (0xFAAC, Dt::Canonical, "\u{7AB1}"),
+        (0xFAAD, Dt::Canonical, "\u{7BC0}"), (0xFAAE, Dt::Canonical, "\u{7C7B}"),
+        (0xFAAF, Dt::Canonical, "\u{7D5B}"), (0xFAB0, Dt::Canonical, "\u{7DF4}"),
+        (0xFAB1, Dt::Canonical, "\u{7F3E}"), (0xFAB2, Dt::Canonical, "\u{8005}"),
+        (0xFAB3, Dt::Canonical, "\u{8352}"), (0xFAB4, Dt::Canonical, "\u{83EF}"),
+        (0xFAB5, Dt::Canonical, "\u{8779}"), (0xFAB6, Dt::Canonical, "\u{8941}"),
+        (0xFAB7, Dt::Canonical, "\u{8986}"), (0xFAB8, Dt::Canonical, "\u{8996}"),
+        (0xFAB9, Dt::Canonical, "\u{8ABF}"), (0xFABA, Dt::Canonical, "\u{8AF8}"),
+        (0xFABB, Dt::Canonical, "\u{8ACB}"), (0xFABC, Dt::Canonical, "\u{8B01}"),
+        (0xFABD, Dt::Canonical, "\u{8AFE}"), (0xFABE, Dt::Canonical, "\u{8AED}"),
+        (0xFABF, Dt::Canonical, "\u{8B39}"), (0xFAC0, Dt::Canonical, "\u{8B8A}"),
+        (0xFAC1, Dt::Canonical, "\u{8D08}"), (0xFAC2, Dt::Canonical, "\u{8F38}"),
+        (0xFAC3, Dt::Canonical, "\u{9072}"), (0xFAC4, Dt::Canonical, "\u{9199}"),
+        (0xFAC5, Dt::Canonical, "\u{9276}"), (0xFAC6, Dt::Canonical, "\u{967C}"),
+        (0xFAC7, Dt::Canonical, "\u{96E3}"), (0xFAC8, Dt::Canonical, "\u{9756}"),
+        (0xFAC9, Dt::Canonical, "\u{97DB}"), (0xFACA, Dt::Canonical, "\u{97FF}"),
+        (0xFACB, Dt::Canonical, "\u{980B}"), (0xFACC, Dt::Canonical, "\u{983B}"),
+        (0xFACD, Dt::Canonical, "\u{9B12}"), (0xFACE, Dt::Canonical, "\u{9F9C}"),
+        (0xFACF, Dt::Canonical, "\u{2284A}"), (0xFAD0, Dt::Canonical, "\u{22844}"),
+        (0xFAD1, Dt::Canonical, "\u{233D5}"), (0xFAD2, Dt::Canonical, "\u{3B9D}"),
+        (0xFAD3, Dt::Canonical, "\u{4018}"), (0xFAD4, Dt::Canonical, "\u{4039}"),
+        (0xFAD5, Dt::Canonical, "\u{25249}"), (0xFAD6, Dt::Canonical, "\u{25CD0}"),
+        (0xFAD7, Dt::Canonical, "\u{27ED3}"), (0xFAD8, Dt::Canonical, "\u{9F43}"),
+        (0xFAD9, Dt::Canonical, "\u{9F8E}"), (0xFB00, Dt::Compat, "ff"), (0xFB01, Dt::Compat, "fi"),
+        (0xFB02, Dt::Compat, "fl"), (0xFB03, Dt::Compat, "ffi"), (0xFB04, Dt::Compat, "ffl"),
+        (0xFB05, Dt::Compat, "\u{17F}t"), (0xFB06, Dt::Compat, "st"),
+        (0xFB13, Dt::Compat, "\u{574}\u{576}"), (0xFB14, Dt::Compat, "\u{574}\u{565}"),
+        (0xFB15, Dt::Compat, "\u{574}\u{56B}"), (0xFB16, Dt::Compat, "\u{57E}\u{576}"),
+        (0xFB17, Dt::Compat, "\u{574}\u{56D}"), (0xFB1D, Dt::Canonical, "\u{5D9}\u{5B4}"),
+        (0xFB1F, Dt::Canonical, "\u{5F2}\u{5B7}"), (0xFB20, Dt::Font, "\u{5E2}"),
+        (0xFB21, Dt::Font, "\u{5D0}"), (0xFB22, Dt::Font, "\u{5D3}"), (0xFB23, Dt::Font, "\u{5D4}"),
+        (0xFB24, Dt::Font, "\u{5DB}"), (0xFB25, Dt::Font, "\u{5DC}"), (0xFB26, Dt::Font, "\u{5DD}"),
+        (0xFB27, Dt::Font, "\u{5E8}"), (0xFB28, Dt::Font, "\u{5EA}"), (0xFB29, Dt::Font, "+"),
+        (0xFB2A, Dt::Canonical, "\u{5E9}\u{5C1}"), (0xFB2B, Dt::Canonical, "\u{5E9}\u{5C2}"),
+        (0xFB2C, Dt::Canonical, "\u{FB49}\u{5C1}"), (0xFB2D, Dt::Canonical, "\u{FB49}\u{5C2}"),
+        (0xFB2E, Dt::Canonical, "\u{5D0}\u{5B7}"), (0xFB2F, Dt::Canonical, "\u{5D0}\u{5B8}"),
+        (0xFB30, Dt::Canonical, "\u{5D0}\u{5BC}"), (0xFB31, Dt::Canonical, "\u{5D1}\u{5BC}"),
+        (0xFB32, Dt::Canonical, "\u{5D2}\u{5BC}"), (0xFB33, Dt::Canonical, "\u{5D3}\u{5BC}"),
+        (0xFB34, Dt::Canonical, "\u{5D4}\u{5BC}"), (0xFB35, Dt::Canonical, "\u{5D5}\u{5BC}"),
+        (0xFB36, Dt::Canonical, "\u{5D6}\u{5BC}"), (0xFB38, Dt::Canonical, "\u{5D8}\u{5BC}"),
+        (0xFB39, Dt::Canonical, "\u{5D9}\u{5BC}"), (0xFB3A, Dt::Canonical, "\u{5DA}\u{5BC}"),
+        (0xFB3B, Dt::Canonical, "\u{5DB}\u{5BC}"), (0xFB3C, Dt::Canonical, "\u{5DC}\u{5BC}"),
+        (0xFB3E, Dt::Canonical, "\u{5DE}\u{5BC}"), (0xFB40, Dt::Canonical, "\u{5E0}\u{5BC}"),
+        (0xFB41, Dt::Canonical, "\u{5E1}\u{5BC}"), (0xFB43, Dt::Canonical, "\u{5E3}\u{5BC}"),
+        (0xFB44, Dt::Canonical, "\u{5E4}\u{5BC}"), (0xFB46, Dt::Canonical, "\u{5E6}\u{5BC}"),
+        (0xFB47, Dt::Canonical, "\u{5E7}\u{5BC}"), (0xFB48, Dt::Canonical, "\u{5E8}\u{5BC}"),
+        (0xFB49, Dt::Canonical, "\u{5E9}\u{5BC}"), (0xFB4A, Dt::Canonical, "\u{5EA}\u{5BC}"),
+        (0xFB4B, Dt::Canonical, "\u{5D5}\u{5B9}"), (0xFB4C, Dt::Canonical, "\u{5D1}\u{5BF}"),
+        (0xFB4D, Dt::Canonical, "\u{5DB}\u{5BF}"), (0xFB4E, Dt::Canonical, "\u{5E4}\u{5BF}"),
+        (0xFB4F, Dt::Compat, "\u{5D0}\u{5DC}"), (0xFB50, Dt::Isolated, "\u{671}"),
+        (0xFB51, Dt::Final, "\u{671}"), (0xFB52, Dt::Isolated, "\u{67B}"),
+        (0xFB53, Dt::Final, "\u{67B}"), (0xFB54, Dt::Initial, "\u{67B}"),
+        (0xFB55, Dt::Medial, "\u{67B}"), (0xFB56, Dt::Isolated, "\u{67E}"),
+        (0xFB57, Dt::Final, "\u{67E}"), (0xFB58, Dt::Initial, "\u{67E}"),
+        (0xFB59, Dt::Medial, "\u{67E}"), (0xFB5A, Dt::Isolated, "\u{680}"),
+        (0xFB5B, Dt::Final, "\u{680}"), (0xFB5C, Dt::Initial, "\u{680}"),
+        (0xFB5D, Dt::Medial, "\u{680}"), (0xFB5E, Dt::Isolated, "\u{67A}"),
+        (0xFB5F, Dt::Final, "\u{67A}"), (0xFB60, Dt::Initial, "\u{67A}"),
+        (0xFB61, Dt::Medial, "\u{67A}"), (0xFB62, Dt::Isolated, "\u{67F}"),
+        (0xFB63, Dt::Final, "\u{67F}"), (0xFB64, Dt::Initial, "\u{67F}"),
+        (0xFB65, Dt::Medial, "\u{67F}"), (0xFB66, Dt::Isolated, "\u{679}"),
+        (0xFB67, Dt::Final, "\u{679}"), (0xFB68, Dt::Initial, "\u{679}"),
+        (0xFB69, Dt::Medial, "\u{679}"), (0xFB6A, Dt::Isolated, "\u{6A4}"),
+        (0xFB6B, Dt::Final, "\u{6A4}"), (0xFB6C, Dt::Initial, "\u{6A4}"),
+        (0xFB6D, Dt::Medial, "\u{6A4}"), (0xFB6E, Dt::Isolated, "\u{6A6}"),
+        (0xFB6F, Dt::Final, "\u{6A6}"), (0xFB70, Dt::Initial, "\u{6A6}"),
+        (0xFB71, Dt::Medial, "\u{6A6}"), (0xFB72, Dt::Isolated, "\u{684}"),
+        (0xFB73, Dt::Final, "\u{684}"), (0xFB74, Dt::Initial, "\u{684}"),
+        (0xFB75, Dt::Medial, "\u{684}"), (0xFB76, Dt::Isolated, "\u{683}"),
+        (0xFB77, Dt::Final, "\u{683}"), (0xFB78, Dt::Initial, "\u{683}"),
+        (0xFB79, Dt::Medial, "\u{683}"), (0xFB7A, Dt::Isolated, "\u{686}"),
+        (0xFB7B, Dt::Final, "\u{686}"), (0xFB7C, Dt::Initial, "\u{686}"),
+        (0xFB7D, Dt::Medial, "\u{686}"), (0xFB7E, Dt::Isolated, "\u{687}"),
+        (0xFB7F, Dt::Final, "\u{687}"), (0xFB80, Dt::Initial, "\u{687}"),
+        (0xFB81, Dt::Medial, "\u{687}"), (0xFB82, Dt::Isolated, "\u{68D}"),
+        (0xFB83, Dt::Final, "\u{68D}"), (0xFB84, Dt::Isolated, "\u{68C}"),
+        (0xFB85, Dt::Final, "\u{68C}"), (0xFB86, Dt::Isolated, "\u{68E}"),
+        (0xFB87, Dt::Final, "\u{68E}"), (0xFB88, Dt::Isolated, "\u{688}"),
+        (0xFB89, Dt::Final, "\u{688}"), (0xFB8A, Dt::Isolated, "\u{698}"),
+        (0xFB8B, Dt::Final, "\u{698}"), (0xFB8C, Dt::Isolated, "\u{691}"),
+        (0xFB8D, Dt::Final, "\u{691}"), (0xFB8E, Dt::Isolated, "\u{6A9}"),
+        (0xFB8F, Dt::Final, "\u{6A9}"), (0xFB90, Dt::Initial, "\u{6A9}"),
+        (0xFB91, Dt::Medial, "\u{6A9}"), (0xFB92, Dt::Isolated, "\u{6AF}"),
+        (0xFB93, Dt::Final, "\u{6AF}"), (0xFB94, Dt::Initial, "\u{6AF}"),
+        (0xFB95, Dt::Medial, "\u{6AF}"), (0xFB96, Dt::Isolated, "\u{6B3}"),
+        (0xFB97, Dt::Final, "\u{6B3}"), (0xFB98, Dt::Initial, "\u{6B3}"),
+        (0xFB99, Dt::Medial, "\u{6B3}"), (0xFB9A, Dt::Isolated, "\u{6B1}"),
+        (0xFB9B, Dt::Final, "\u{6B1}"), (0xFB9C, Dt::Initial, "\u{6B1}"),
+        (0xFB9D, Dt::Medial, "\u{6B1}"), (0xFB9E, Dt::Isolated, "\u{6BA}"),
+        (0xFB9F, Dt::Final, "\u{6BA}"), (0xFBA0, Dt::Isolated, "\u{6BB}"),
+        (0xFBA1, Dt::Final, "\u{6BB}"), (0xFBA2, Dt::Initial, "\u{6BB}"),
+        (0xFBA3, Dt::Medial, "\u{6BB}"), (0xFBA4, Dt::Isolated, "\u{6C0}"),
+        (0xFBA5, Dt::Final, "\u{6C0}"), (0xFBA6, Dt::Isolated, "\u{6C1}"),
+        (0xFBA7, Dt::Final, "\u{6C1}"), (0xFBA8, Dt::Initial, "\u{6C1}"),
+        (0xFBA9, Dt::Medial, "\u{6C1}"), (0xFBAA, Dt::Isolated, "\u{6BE}"),
+        (0xFBAB, Dt::Final, "\u{6BE}"), (0xFBAC, Dt::Initial, "\u{6BE}"),
+        (0xFBAD, Dt::Medial, "\u{6BE}"), (0xFBAE, Dt::Isolated, "\u{6D2}"),
+        (0xFBAF, Dt::Final, "\u{6D2}"), (0xFBB0, Dt::Isolated, "\u{6D3}"),
+        (0xFBB1, Dt::Final, "\u{6D3}"), (0xFBD3, Dt::Isolated, "\u{6AD}"),
+        (0xFBD4, Dt::Final, "\u{6AD}"), (0xFBD5, Dt::Initial, "\u{6AD}"),
+        (0xFBD6, Dt::Medial, "\u{6AD}"), (0xFBD7, Dt::Isolated, "\u{6C7}"),
+        (0xFBD8, Dt::Final, "\u{6C7}"), (0xFBD9, Dt::Isolated, "\u{6C6}"),
+        (0xFBDA, Dt::Final, "\u{6C6}"), (0xFBDB, Dt::Isolated, "\u{6C8}"),
+        (0xFBDC, Dt::Final, "\u{6C8}"), (0xFBDD, Dt::Isolated, "\u{677}"),
+        (0xFBDE, Dt::Isolated, "\u{6CB}"), (0xFBDF, Dt::Final, "\u{6CB}"),
+        (0xFBE0, Dt::Isolated, "\u{6C5}"), (0xFBE1, Dt::Final, "\u{6C5}"),
+        (0xFBE2, Dt::Isolated, "\u{6C9}"), (0xFBE3, Dt::Final, "\u{6C9}"),
+        (0xFBE4, Dt::Isolated, "\u{6D0}"), (0xFBE5, Dt::Final, "\u{6D0}"),
+        (0xFBE6, Dt::Initial, "\u{6D0}"), (0xFBE7, Dt::Medial, "\u{6D0}"),
+        (0xFBE8, Dt::Initial, "\u{649}"), (0xFBE9, Dt::Medial, "\u{649}"),
+        (0xFBEA, Dt::Isolated, "\u{626}\u{627}"), (0xFBEB, Dt::Final, "\u{626}\u{627}"),
+        (0xFBEC, Dt::Isolated, "\u{626}\u{6D5}"), (0xFBED, Dt::Final, "\u{626}\u{6D5}"),
+        (0xFBEE, Dt::Isolated, "\u{626}\u{648}"), (0xFBEF, Dt::Final, "\u{626}\u{648}"),
+        (0xFBF0, Dt::Isolated, "\u{626}\u{6C7}"), (0xFBF1, Dt::Final, "\u{626}\u{6C7}"),
+        (0xFBF2, Dt::Isolated, "\u{626}\u{6C6}"), (0xFBF3, Dt::Final, "\u{626}\u{6C6}"),
+        (0xFBF4, Dt::Isolated, "\u{626}\u{6C8}"), (0xFBF5, Dt::Final, "\u{626}\u{6C8}"),
+        (0xFBF6, Dt::Isolated, "\u{626}\u{6D0}"), (0xFBF7, Dt::Final, "\u{626}\u{6D0}"),
+        (0xFBF8, Dt::Initial, "\u{626}\u{6D0}"), (0xFBF9, Dt::Isolated, "\u{626}\u{649}"),
+        (0xFBFA, Dt::Final, "\u{626}\u{649}"), (0xFBFB, Dt::Initial, "\u{626}\u{649}"),
+        (0xFBFC, Dt::Isolated, "\u{6CC}"), (0xFBFD, Dt::Final, "\u{6CC}"),
+        (0xFBFE, Dt::Initial, "\u{6CC}"), (0xFBFF, Dt::Medial, "\u{6CC}"),
+        (0xFC00, Dt::Isolated, "\u{626}\u{62C}"), (0xFC01, Dt::Isolated, "\u{626}\u{62D}"),
+        (0xFC02, Dt::Isolated, "\u{626}\u{645}"), (0xFC03, Dt::Isolated, "\u{626}\u{649}"),
+        (0xFC04, Dt::Isolated, "\u{626}\u{64A}"), (0xFC05, Dt::Isolated, "\u{628}\u{62C}"),
+        (0xFC06, Dt::Isolated, "\u{628}\u{62D}"), (0xFC07, Dt::Isolated, "\u{628}\u{62E}"),
+        (0xFC08, Dt::Isolated, "\u{628}\u{645}"), (0xFC09, Dt::Isolated, "\u{628}\u{649}"),
+        (0xFC0A, Dt::Isolated, "\u{628}\u{64A}"), (0xFC0B, Dt::Isolated, "\u{62A}\u{62C}"),
+        (0xFC0C, Dt::Isolated, "\u{62A}\u{62D}"), (0xFC0D, Dt::Isolated, "\u{62A}\u{62E}"),
+        (0xFC0E, Dt::Isolated, "\u{62A}\u{645}"), (0xFC0F, Dt::Isolated, "\u{62A}\u{649}"),
+        (0xFC10, Dt::Isolated, "\u{62A}\u{64A}"), (0xFC11, Dt::Isolated, "\u{62B}\u{62C}"),
+        (0xFC12, Dt::Isolated, "\u{62B}\u{645}"), (0xFC13, Dt::Isolated, "\u{62B}\u{649}"),
+        (0xFC14, Dt::Isolated, "\u{62B}\u{64A}"), (0xFC15, Dt::Isolated, "\u{62C}\u{62D}"),
+        (0xFC16, Dt::Isolated, "\u{62C}\u{645}"), (0xFC17, Dt::Isolated, "\u{62D}\u{62C}"),
+        (0xFC18, Dt::Isolated, "\u{62D}\u{645}"), (0xFC19, Dt::Isolated, "\u{62E}\u{62C}"),
+        (0xFC1A, Dt::Isolated, "\u{62E}\u{62D}"), (0xFC1B, Dt::Isolated, "\u{62E}\u{645}"),
+        (0xFC1C, Dt::Isolated, "\u{633}\u{62C}"), (0xFC1D, Dt::Isolated, "\u{633}\u{62D}"),
+        (0xFC1E, Dt::Isolated, "\u{633}\u{62E}"), (0xFC1F, Dt::Isolated, "\u{633}\u{645}"),
+        (0xFC20, Dt::Isolated, "\u{635}\u{62D}"), (0xFC21, Dt::Isolated, "\u{635}\u{645}"),
+        (0xFC22, Dt::Isolated, "\u{636}\u{62C}"), (0xFC23, Dt::Isolated, "\u{636}\u{62D}"),
+        (0xFC24, Dt::Isolated, "\u{636}\u{62E}"), (0xFC25, Dt::Isolated, "\u{636}\u{645}"),
+        (0xFC26, Dt::Isolated, "\u{637}\u{62D}"), (0xFC27, Dt::Isolated, "\u{637}\u{645}"),
+        (0xFC28, Dt::Isolated, "\u{638}\u{645}"), (0xFC29, Dt::Isolated, "\u{639}\u{62C}"),
+        (0xFC2A, Dt::Isolated, "\u{639}\u{645}"), (0xFC2B, Dt::Isolated, "\u{63A}\u{62C}"),
+        (0xFC2C, Dt::Isolated, "\u{63A}\u{645}"), (0xFC2D, Dt::Isolated, "\u{641}\u{62C}"),
+        (0xFC2E, Dt::Isolated, "\u{641}\u{62D}"), (0xFC2F, Dt::Isolated, "\u{641}\u{62E}"),
+        (0xFC30, Dt::Isolated, "\u{641}\u{645}"), (0xFC31, Dt::Isolated, "\u{641}\u{649}"),
+        (0xFC32, Dt::Isolated, "\u{641}\u{64A}"), (0xFC33, Dt::Isolated, "\u{642}\u{62D}"),
+        (0xFC34, Dt::Isolated, "\u{642}\u{645}"), (0xFC35, Dt::Isolated, "\u{642}\u{649}"),
+        (0xFC36, Dt::Isolated, "\u{642}\u{64A}"), (0xFC37, Dt::Isolated, "\u{643}\u{627}"),
+        (0xFC38, Dt::Isolated, "\u{643}\u{62C}"), (0xFC39, Dt::Isolated, "\u{643}\u{62D}"),
+        (0xFC3A, Dt::Isolated, "\u{643}\u{62E}"), (0xFC3B, Dt::Isolated, "\u{643}\u{644}"),
+        (0xFC3C, Dt::Isolated, "\u{643}\u{645}"), (0xFC3D, Dt::Isolated, "\u{643}\u{649}"),
+        (0xFC3E, Dt::Isolated, "\u{643}\u{64A}"), (0xFC3F, Dt::Isolated, "\u{644}\u{62C}"),
+        (0xFC40, Dt::Isolated, "\u{644}\u{62D}"), (0xFC41, Dt::Isolated, "\u{644}\u{62E}"),
+        (0xFC42, Dt::Isolated, "\u{644}\u{645}"), (0xFC43, Dt::Isolated, "\u{644}\u{649}"),
+        (0xFC44, Dt::Isolated, "\u{644}\u{64A}"), (0xFC45, Dt::Isolated, "\u{645}\u{62C}"),
+        (0xFC46, Dt::Isolated, "\u{645}\u{62D}"), (0xFC47, Dt::Isolated, "\u{645}\u{62E}"),
+        (0xFC48, Dt::Isolated, "\u{645}\u{645}"), (0xFC49, Dt::Isolated, "\u{645}\u{649}"),
+        (0xFC4A, Dt::Isolated, "\u{645}\u{64A}"), (0xFC4B, Dt::Isolated, "\u{646}\u{62C}"),
+        (0xFC4C, Dt::Isolated, "\u{646}\u{62D}"), (0xFC4D, Dt::Isolated, "\u{646}\u{62E}"),
+        (0xFC4E, Dt::Isolated, "\u{646}\u{645}"), (0xFC4F, Dt::Isolated, "\u{646}\u{649}"),
+        (0xFC50, Dt::Isolated, "\u{646}\u{64A}"), (0xFC51, Dt::Isolated, "\u{647}\u{62C}"),
+        (0xFC52, Dt::Isolated, "\u{647}\u{645}"), (0xFC53, Dt::Isolated, "\u{647}\u{649}"),
+        (0xFC54, Dt::Isolated, "\u{647}\u{64A}"), (0xFC55, Dt::Isolated, "\u{64A}\u{62C}"),
+        (0xFC56, Dt::Isolated, "\u{64A}\u{62D}"), (0xFC57, Dt::Isolated, "\u{64A}\u{62E}"),
+        (0xFC58, Dt::Isolated, "\u{64A}\u{645}"), (0xFC59, Dt::Isolated, "\u{64A}\u{649}"),
+        (0xFC5A, Dt::Isolated, "\u{64A}\u{64A}"), (0xFC5B, Dt::Isolated, "\u{630}\u{670}"),
+        (0xFC5C, Dt::Isolated, "\u{631}\u{670}"), (0xFC5D, Dt::Isolated, "\u{649}\u{670}"),
+        (0xFC5E, Dt::Isolated, " \u{64C}\u{651}"), (0xFC5F, Dt::Isolated, " \u{64D}\u{651}"),
+        (0xFC60, Dt::Isolated, " \u{64E}\u{651}"), (0xFC61, Dt::Isolated, " \u{64F}\u{651}"),
+        (0xFC62, Dt::Isolated, " \u{650}\u{651}"), (0xFC63, Dt::Isolated, " \u{651}\u{670}"),
+        (0xFC64, Dt::Final, "\u{626}\u{631}"), (0xFC65, Dt::Final, "\u{626}\u{632}"),
+        (0xFC66, Dt::Final, "\u{626}\u{645}"), (0xFC67, Dt::Final, "\u{626}\u{646}"),
+        (0xFC68, Dt::Final, "\u{626}\u{649}"), (0xFC69, Dt::Final, "\u{626}\u{64A}"),
+        (0xFC6A, Dt::Final, "\u{628}\u{631}"), (0xFC6B, Dt::Final, "\u{628}\u{632}"),
+        (0xFC6C, Dt::Final, "\u{628}\u{645}"), (0xFC6D, Dt::Final, "\u{628}\u{646}"),
+        (0xFC6E, Dt::Final, "\u{628}\u{649}"), (0xFC6F, Dt::Final, "\u{628}\u{64A}"),
+        (0xFC70, Dt::Final, "\u{62A}\u{631}"), (0xFC71, Dt::Final, "\u{62A}\u{632}"),
+        (0xFC72, Dt::Final, "\u{62A}\u{645}"), (0xFC73, Dt::Final, "\u{62A}\u{646}"),
+        (0xFC74, Dt::Final, "\u{62A}\u{649}"), (0xFC75, Dt::Final, "\u{62A}\u{64A}"),
+        (0xFC76, Dt::Final, "\u{62B}\u{631}"), (0xFC77, Dt::Final, "\u{62B}\u{632}"),
+        (0xFC78, Dt::Final, "\u{62B}\u{645}"), (0xFC79, Dt::Final, "\u{62B}\u{646}"),
+        (0xFC7A, Dt::Final, "\u{62B}\u{649}"), (0xFC7B, Dt::Final, "\u{62B}\u{64A}"),
+        (0xFC7C, Dt::Final, "\u{641}\u{649}"), (0xFC7D, Dt::Final, "\u{641}\u{64A}"),
+        (0xFC7E, Dt::Final, "\u{642}\u{649}"), (0xFC7F, Dt::Final, "\u{642}\u{64A}"),
+        (0xFC80, Dt::Final, "\u{643}\u{627}"), (0xFC81, Dt::Final, "\u{643}\u{644}"),
+        (0xFC82, Dt::Final, "\u{643}\u{645}"), (0xFC83, Dt::Final, "\u{643}\u{649}"),
+        (0xFC84, Dt::Final, "\u{643}\u{64A}"), (0xFC85, Dt::Final, "\u{644}\u{645}"),
+        (0xFC86, Dt::Final, "\u{644}\u{649}"), (0xFC87, Dt::Final, "\u{644}\u{64A}"),
+        (0xFC88, Dt::Final, "\u{645}\u{627}"), (0xFC89, Dt::Final, "\u{645}\u{645}"),
+        (0xFC8A, Dt::Final, "\u{646}\u{631}"), (0xFC8B, Dt::Final, "\u{646}\u{632}"),
+        (0xFC8C, Dt::Final, "\u{646}\u{645}"), (0xFC8D, Dt::Final, "\u{646}\u{646}"),
+        (0xFC8E, Dt::Final, "\u{646}\u{649}"), (0xFC8F, Dt::Final, "\u{646}\u{64A}"),
+        (0xFC90, Dt::Final, "\u{649}\u{670}"), (0xFC91, Dt::Final, "\u{64A}\u{631}"),
+        (0xFC92, Dt::Final, "\u{64A}\u{632}"), (0xFC93, Dt::Final, "\u{64A}\u{645}"),
+        (0xFC94, Dt::Final, "\u{64A}\u{646}"), (0xFC95, Dt::Final, "\u{64A}\u{649}"),
+        (0xFC96, Dt::Final, "\u{64A}\u{64A}"), (0xFC97, Dt::Initial, "\u{626}\u{62C}"),
+        (0xFC98, Dt::Initial, "\u{626}\u{62D}"), (0xFC99, Dt::Initial, "\u{626}\u{62E}"),
+        (0xFC9A, Dt::Initial, "\u{626}\u{645}"), (0xFC9B, Dt::Initial, "\u{626}\u{647}"),
+        (0xFC9C, Dt::Initial, "\u{628}\u{62C}"), (0xFC9D, Dt::Initial, "\u{628}\u{62D}"),
+        (0xFC9E, Dt::Initial, "\u{628}\u{62E}"), (0xFC9F, Dt::Initial, "\u{628}\u{645}"),
+        (0xFCA0, Dt::Initial, "\u{628}\u{647}"), (0xFCA1, Dt::Initial, "\u{62A}\u{62C}"),
+        (0xFCA2, Dt::Initial, "\u{62A}\u{62D}"), (0xFCA3, Dt::Initial, "\u{62A}\u{62E}"),
+        (0xFCA4, Dt::Initial, "\u{62A}\u{645}"), (0xFCA5, Dt::Initial, "\u{62A}\u{647}"),
+        (0xFCA6, Dt::Initial, "\u{62B}\u{645}"), (0xFCA7, Dt::Initial, "\u{62C}\u{62D}"),
+        (0xFCA8, Dt::Initial, "\u{62C}\u{645}"), (0xFCA9, Dt::Initial, "\u{62D}\u{62C}"),
+        (0xFCAA, Dt::Initial, "\u{62D}\u{645}"), (0xFCAB, Dt::Initial, "\u{62E}\u{62C}"),
+        (0xFCAC, Dt::Initial, "\u{62E}\u{645}"), (0xFCAD, Dt::Initial, "\u{633}\u{62C}"),
+        (0xFCAE, Dt::Initial, "\u{633}\u{62D}"), (0xFCAF, Dt::Initial, "\u{633}\u{62E}"),
+        (0xFCB0, Dt::Initial, "\u{633}\u{645}"), (0xFCB1, Dt::Initial, "\u{635}\u{62D}"),
+        (0xFCB2, Dt::Initial, "\u{635}\u{62E}"), (0xFCB3, Dt::Initial, "\u{635}\u{645}"),
+        (0xFCB4, Dt::Initial, "\u{636}\u{62C}"), (0xFCB5, Dt::Initial, "\u{636}\u{62D}"),
+        (0xFCB6, Dt::Initial, "\u{636}\u{62E}"), (0xFCB7, Dt::Initial, "\u{636}\u{645}"),
+        (0xFCB8, Dt::Initial, "\u{637}\u{62D}"), (0xFCB9, Dt::Initial, "\u{638}\u{645}"),
+        (0xFCBA, Dt::Initial, "\u{639}\u{62C}"), (0xFCBB, Dt::Initial, "\u{639}\u{645}"),
+        (0xFCBC, Dt::Initial, "\u{63A}\u{62C}"), (0xFCBD, Dt::Initial, "\u{63A}\u{645}"),
+        (0xFCBE, Dt::Initial, "\u{641}\u{62C}"), (0xFCBF, Dt::Initial, "\u{641}\u{62D}"),
+        (0xFCC0, Dt::Initial, "\u{641}\u{62E}"), (0xFCC1, Dt::Initial, "\u{641}\u{645}"),
+        (0xFCC2, Dt::Initial, "\u{642}\u{62D}"), (0xFCC3, Dt::Initial, "\u{642}\u{645}"),
+        (0xFCC4, Dt::Initial, "\u{643}\u{62C}"), (0xFCC5, Dt::Initial, "\u{643}\u{62D}"),
+        (0xFCC6, Dt::Initial, "\u{643}\u{62E}"), (0xFCC7, Dt::Initial, "\u{643}\u{644}"),
+        (0xFCC8, Dt::Initial, "\u{643}\u{645}"), (0xFCC9, Dt::Initial, "\u{644}\u{62C}"),
+        (0xFCCA, Dt::Initial, "\u{644}\u{62D}"), (0xFCCB, Dt::Initial, "\u{644}\u{62E}"),
+        (0xFCCC, Dt::Initial, "\u{644}\u{645}"), (0xFCCD, Dt::Initial, "\u{644}\u{647}"),
+        (0xFCCE, Dt::Initial, "\u{645}\u{62C}"), (0xFCCF, Dt::Initial, "\u{645}\u{62D}"),
+        (0xFCD0, Dt::Initial, "\u{645}\u{62E}"), (0xFCD1, Dt::Initial, "\u{645}\u{645}"),
+        (0xFCD2, Dt::Initial, "\u{646}\u{62C}"), (0xFCD3, Dt::Initial, "\u{646}\u{62D}"),
+        (0xFCD4, Dt::Initial, "\u{646}\u{62E}"), (0xFCD5, Dt::Initial, "\u{646}\u{645}"),
+        (0xFCD6, Dt::Initial, "\u{646}\u{647}"), (0xFCD7, Dt::Initial, "\u{647}\u{62C}"),
+        (0xFCD8, Dt::Initial, "\u{647}\u{645}"), (0xFCD9, Dt::Initial, "\u{647}\u{670}"),
+        (0xFCDA, Dt::Initial, "\u{64A}\u{62C}"), (0xFCDB, Dt::Initial, "\u{64A}\u{62D}"),
+        (0xFCDC, Dt::Initial, "\u{64A}\u{62E}"), (0xFCDD, Dt::Initial, "\u{64A}\u{645}"),
+        (0xFCDE, Dt::Initial, "\u{64A}\u{647}"), (0xFCDF, Dt::Medial, "\u{626}\u{645}"),
+        (0xFCE0, Dt::Medial, "\u{626}\u{647}"), (0xFCE1, Dt::Medial, "\u{628}\u{645}"),
+        (0xFCE2, Dt::Medial, "\u{628}\u{647}"), (0xFCE3, Dt::Medial, "\u{62A}\u{645}"),
+        (0xFCE4, Dt::Medial, "\u{62A}\u{647}"), (0xFCE5, Dt::Medial, "\u{62B}\u{645}"),
+        (0xFCE6, Dt::Medial, "\u{62B}\u{647}"), (0xFCE7, Dt::Medial, "\u{633}\u{645}"),
+        (0xFCE8, Dt::Medial, "\u{633}\u{647}"), (0xFCE9, Dt::Medial, "\u{634}\u{645}"),
+        (0xFCEA, Dt::Medial, "\u{634}\u{647}"), (0xFCEB, Dt::Medial, "\u{643}\u{644}"),
+        (0xFCEC, Dt::Medial, "\u{643}\u{645}"), (0xFCED, Dt::Medial, "\u{644}\u{645}"),
+        (0xFCEE, Dt::Medial, "\u{646}\u{645}"), (0xFCEF, Dt::Medial, "\u{646}\u{647}"),
+        (0xFCF0, Dt::Medial, "\u{64A}\u{645}"), (0xFCF1, Dt::Medial, "\u{64A}\u{647}"),
+        (0xFCF2, Dt::Medial, "\u{640}\u{64E}\u{651}"),
+        (0xFCF3, Dt::Medial, "\u{640}\u{64F}\u{651}"),
+        (0xFCF4, Dt::Medial, "\u{640}\u{650}\u{651}"), (0xFCF5, Dt::Isolated, "\u{637}\u{649}"),
+        (0xFCF6, Dt::Isolated, "\u{637}\u{64A}"), (0xFCF7, Dt::Isolated, "\u{639}\u{649}"),
+        (0xFCF8, Dt::Isolated, "\u{639}\u{64A}"), (0xFCF9, Dt::Isolated, "\u{63A}\u{649}"),
+        (0xFCFA, Dt::Isolated, "\u{63A}\u{64A}"), (0xFCFB, Dt::Isolated, "\u{633}\u{649}"),
+        (0xFCFC, Dt::Isolated, "\u{633}\u{64A}"), (0xFCFD, Dt::Isolated, "\u{634}\u{649}"),
+        (0xFCFE, Dt::Isolated, "\u{634}\u{64A}"), (0xFCFF, Dt::Isolated, "\u{62D}\u{649}"),
+        (0xFD00, Dt::Isolated, "\u{62D}\u{64A}"), (0xFD01, Dt::Isolated, "\u{62C}\u{649}"),
+        (0xFD02, Dt::Isolated, "\u{62C}\u{64A}"), (0xFD03, Dt::Isolated, "\u{62E}\u{649}"),
+        (0xFD04, Dt::Isolated, "\u{62E}\u{64A}"), (0xFD05, Dt::Isolated, "\u{635}\u{649}"),
+        (0xFD06, Dt::Isolated, "\u{635}\u{64A}"), (0xFD07, Dt::Isolated, "\u{636}\u{649}"),
+        (0xFD08, Dt::Isolated, "\u{636}\u{64A}"), (0xFD09, Dt::Isolated, "\u{634}\u{62C}"),
+        (0xFD0A, Dt::Isolated, "\u{634}\u{62D}"), (0xFD0B, Dt::Isolated, "\u{634}\u{62E}"),
+        (0xFD0C, Dt::Isolated, "\u{634}\u{645}"), (0xFD0D, Dt::Isolated, "\u{634}\u{631}"),
+        (0xFD0E, Dt::Isolated, "\u{633}\u{631}"), (0xFD0F, Dt::Isolated, "\u{635}\u{631}"),
+        (0xFD10, Dt::Isolated, "\u{636}\u{631}"), (0xFD11, Dt::Final, "\u{637}\u{649}"),
+        (0xFD12, Dt::Final, "\u{637}\u{64A}"), (0xFD13, Dt::Final, "\u{639}\u{649}"),
+        (0xFD14, Dt::Final, "\u{639}\u{64A}"), (0xFD15, Dt::Final, "\u{63A}\u{649}"),
+        (0xFD16, Dt::Final, "\u{63A}\u{64A}"), (0xFD17, Dt::Final, "\u{633}\u{649}"),
+        (0xFD18, Dt::Final, "\u{633}\u{64A}"), (0xFD19, Dt::Final, "\u{634}\u{649}"),
+        (0xFD1A, Dt::Final, "\u{634}\u{64A}"), (0xFD1B, Dt::Final, "\u{62D}\u{649}"),
+        (0xFD1C, Dt::Final, "\u{62D}\u{64A}"), (0xFD1D, Dt::Final, "\u{62C}\u{649}"),
+        (0xFD1E, Dt::Final, "\u{62C}\u{64A}"), (0xFD1F, Dt::Final, "\u{62E}\u{649}"),
+        (0xFD20, Dt::Final, "\u{62E}\u{64A}"), (0xFD21, Dt::Final, "\u{635}\u{649}"),
+        (0xFD22, Dt::Final, "\u{635}\u{64A}"), (0xFD23, Dt::Final, "\u{636}\u{649}"),
+        (0xFD24, Dt::Final, "\u{636}\u{64A}"), (0xFD25, Dt::Final, "\u{634}\u{62C}"),
+        (0xFD26, Dt::Final, "\u{634}\u{62D}"), (0xFD27, Dt::Final, "\u{634}\u{62E}"),
+        (0xFD28, Dt::Final, "\u{634}\u{645}"), (0xFD29, Dt::Final, "\u{634}\u{631}"),
+        (0xFD2A, Dt::Final, "\u{633}\u{631}"), (0xFD2B, Dt::Final, "\u{635}\u{631}"),
+        (0xFD2C, Dt::Final, "\u{636}\u{631}"), (0xFD2D, Dt::Initial, "\u{634}\u{62C}"),
+        (0xFD2E, Dt::Initial, "\u{634}\u{62D}"), (0xFD2F, Dt::Initial, "\u{634}\u{62E}"),
+        (0xFD30, Dt::Initial, "\u{634}\u{645}"), (0xFD31, Dt::Initial, "\u{633}\u{647}"),
+        (0xFD32, Dt::Initial, "\u{634}\u{647}"), (0xFD33, Dt::Initial, "\u{637}\u{645}"),
+        (0xFD34, Dt::Medial, "\u{633}\u{62C}"), (0xFD35, Dt::Medial, "\u{633}\u{62D}"),
+        (0xFD36, Dt::Medial, "\u{633}\u{62E}"), (0xFD37, Dt::Medial, "\u{634}\u{62C}"),
+        (0xFD38, Dt::Medial, "\u{634}\u{62D}"), (0xFD39, Dt::Medial, "\u{634}\u{62E}"),
+        (0xFD3A, Dt::Medial, "\u{637}\u{645}"), (0xFD3B, Dt::Medial, "\u{638}\u{645}"),
+        (0xFD3C, Dt::Final, "\u{627}\u{64B}"), (0xFD3D, Dt::Isolated, "\u{627}\u{64B}"),
+        (0xFD50, Dt::Initial, "\u{62A}\u{62C}\u{645}"),
+        (0xFD51, Dt::Final, "\u{62A}\u{62D}\u{62C}"),
+        (0xFD52, Dt::Initial, "\u{62A}\u{62D}\u{62C}"),
+        (0xFD53, Dt::Initial, "\u{62A}\u{62D}\u{645}"),
+        (0xFD54, Dt::Initial, "\u{62A}\u{62E}\u{645}"),
+        (0xFD55, Dt::Initial, "\u{62A}\u{645}\u{62C}"),
+        (0xFD56, Dt::Initial, "\u{62A}\u{645}\u{62D}"),
+        (0xFD57, Dt::Initial, "\u{62A}\u{645}\u{62E}"),
+        (0xFD58, Dt::Final, "\u{62C}\u{645}\u{62D}"),
+        (0xFD59, Dt::Initial, "\u{62C}\u{645}\u{62D}"),
+        (0xFD5A, Dt::Final, "\u{62D}\u{645}\u{64A}"), (0xFD5B, Dt::Final, "\u{62D}\u{645}\u{649}"),
+        (0xFD5C, Dt::Initial, "\u{633}\u{62D}\u{62C}"),
+        (0xFD5D, Dt::Initial, "\u{633}\u{62C}\u{62D}"),
+        (0xFD5E, Dt::Final, "\u{633}\u{62C}\u{649}"), (0xFD5F, Dt::Final, "\u{633}\u{645}\u{62D}"),
+        (0xFD60, Dt::Initial, "\u{633}\u{645}\u{62D}"),
+        (0xFD61, Dt::Initial, "\u{633}\u{645}\u{62C}"),
+        (0xFD62, Dt::Final, "\u{633}\u{645}\u{645}"),
+        (0xFD63, Dt::Initial, "\u{633}\u{645}\u{645}"),
+        (0xFD64, Dt::Final, "\u{635}\u{62D}\u{62D}"),
+        (0xFD65, Dt::Initial, "\u{635}\u{62D}\u{62D}"),
+        (0xFD66, Dt::Final, "\u{635}\u{645}\u{645}"), (0xFD67, Dt::Final, "\u{634}\u{62D}\u{645}"),
+        (0xFD68, Dt::Initial, "\u{634}\u{62D}\u{645}"),
+        (0xFD69, Dt::Final, "\u{634}\u{62C}\u{64A}"), (0xFD6A, Dt::Final, "\u{634}\u{645}\u{62E}"),
+        (0xFD6B, Dt::Initial, "\u{634}\u{645}\u{62E}"),
+        (0xFD6C, Dt::Final, "\u{634}\u{645}\u{645}"),
+        (0xFD6D, Dt::Initial, "\u{634}\u{645}\u{645}"),
+        (0xFD6E, Dt::Final, "\u{636}\u{62D}\u{649}"), (0xFD6F, Dt::Final, "\u{636}\u{62E}\u{645}"),
+        (0xFD70, Dt::Initial, "\u{636}\u{62E}\u{645}"),
+        (0xFD71, Dt::Final, "\u{637}\u{645}\u{62D}"),
+        (0xFD72, Dt::Initial, "\u{637}\u{645}\u{62D}"),
+        (0xFD73, Dt::Initial, "\u{637}\u{645}\u{645}"),
+        (0xFD74, Dt::Final, "\u{637}\u{645}\u{64A}"), (0xFD75, Dt::Final, "\u{639}\u{62C}\u{645}"),
+        (0xFD76, Dt::Final, "\u{639}\u{645}\u{645}"),
+        (0xFD77, Dt::Initial, "\u{639}\u{645}\u{645}"),
+        (0xFD78, Dt::Final, "\u{639}\u{645}\u{649}"), (0xFD79, Dt::Final, "\u{63A}\u{645}\u{645}"),
+        (0xFD7A, Dt::Final, "\u{63A}\u{645}\u{64A}"), (0xFD7B, Dt::Final, "\u{63A}\u{645}\u{649}"),
+        (0xFD7C, Dt::Final, "\u{641}\u{62E}\u{645}"),
+        (0xFD7D, Dt::Initial, "\u{641}\u{62E}\u{645}"),
+        (0xFD7E, Dt::Final, "\u{642}\u{645}\u{62D}"), (0xFD7F, Dt::Final, "\u{642}\u{645}\u{645}"),
+        (0xFD80, Dt::Final, "\u{644}\u{62D}\u{645}"), (0xFD81, Dt::Final, "\u{644}\u{62D}\u{64A}"),
+        (0xFD82, Dt::Final, "\u{644}\u{62D}\u{649}"),
+        (0xFD83, Dt::Initial, "\u{644}\u{62C}\u{62C}"),
+        (0xFD84, Dt::Final, "\u{644}\u{62C}\u{62C}"), (0xFD85, Dt::Final, "\u{644}\u{62E}\u{645}"),
+        (0xFD86, Dt::Initial, "\u{644}\u{62E}\u{645}"),
+        (0xFD87, Dt::Final, "\u{644}\u{645}\u{62D}"),
+        (0xFD88, Dt::Initial, "\u{644}\u{645}\u{62D}"),
+        (0xFD89, Dt::Initial, "\u{645}\u{62D}\u{62C}"),
+        (0xFD8A, Dt::Initial, "\u{645}\u{62D}\u{645}"),
+        (0xFD8B, Dt::Final, "\u{645}\u{62D}\u{64A}"),
+        (0xFD8C, Dt::Initial, "\u{645}\u{62C}\u{62D}"),
+        (0xFD8D, Dt::Initial, "\u{645}\u{62C}\u{645}"),
+        (0xFD8E, Dt::Initial, "\u{645}\u{62E}\u{62C}"),
+        (0xFD8F, Dt::Initial, "\u{645}\u{62E}\u{645}"),
+        (0xFD92, Dt::Initial, "\u{645}\u{62C}\u{62E}"),
+        (0xFD93, Dt::Initial, "\u{647}\u{645}\u{62C}"),
+        (0xFD94, Dt::Initial, "\u{647}\u{645}\u{645}"),
+        (0xFD95, Dt::Initial, "\u{646}\u{62D}\u{645}"),
+        (0xFD96, Dt::Final, "\u{646}\u{62D}\u{649}"), (0xFD97, Dt::Final, "\u{646}\u{62C}\u{645}"),
+        (0xFD98, Dt::Initial, "\u{646}\u{62C}\u{645}"),
+        (0xFD99, Dt::Final, "\u{646}\u{62C}\u{649}"), (0xFD9A, Dt::Final, "\u{646}\u{645}\u{64A}"),
+        (0xFD9B, Dt::Final, "\u{646}\u{645}\u{649}"), (0xFD9C, Dt::Final, "\u{64A}\u{645}\u{645}"),
+        (0xFD9D, Dt::Initial, "\u{64A}\u{645}\u{645}"),
+        (0xFD9E, Dt::Final, "\u{628}\u{62E}\u{64A}"), (0xFD9F, Dt::Final, "\u{62A}\u{62C}\u{64A}"),
+        (0xFDA0, Dt::Final, "\u{62A}\u{62C}\u{649}"), (0xFDA1, Dt::Final, "\u{62A}\u{62E}\u{64A}"),
+        (0xFDA2, Dt::Final, "\u{62A}\u{62E}\u{649}"), (0xFDA3, Dt::Final, "\u{62A}\u{645}\u{64A}"),
+        (0xFDA4, Dt::Final, "\u{62A}\u{645}\u{649}"), (0xFDA5, Dt::Final, "\u{62C}\u{645}\u{64A}"),
+        (0xFDA6, Dt::Final, "\u{62C}\u{62D}\u{649}"), (0xFDA7, Dt::Final, "\u{62C}\u{645}\u{649}"),
+        (0xFDA8, Dt::Final, "\u{633}\u{62E}\u{649}"), (0xFDA9, Dt::Final, "\u{635}\u{62D}\u{64A}"),
+        (0xFDAA, Dt::Final, "\u{634}\u{62D}\u{64A}"), (0xFDAB, Dt::Final, "\u{636}\u{62D}\u{64A}"),
+        (0xFDAC, Dt::Final, "\u{644}\u{62C}\u{64A}"), (0xFDAD, Dt::Final, "\u{644}\u{645}\u{64A}"),
+        (0xFDAE, Dt::Final, "\u{64A}\u{62D}\u{64A}"), (0xFDAF, Dt::Final, "\u{64A}\u{62C}\u{64A}"),
+        (0xFDB0, Dt::Final, "\u{64A}\u{645}\u{64A}"), (0xFDB1, Dt::Final, "\u{645}\u{645}\u{64A}"),
+        (0xFDB2, Dt::Final, "\u{642}\u{645}\u{64A}"), (0xFDB3, Dt::Final, "\u{646}\u{62D}\u{64A}"),
+        (0xFDB4, Dt::Initial, "\u{642}\u{645}\u{62D}"),
+        (0xFDB5, Dt::Initial, "\u{644}\u{62D}\u{645}"),
+        (0xFDB6, Dt::Final, "\u{639}\u{645}\u{64A}"), (0xFDB7, Dt::Final, "\u{643}\u{645}\u{64A}"),
+        (0xFDB8, Dt::Initial, "\u{646}\u{62C}\u{62D}"),
+        (0xFDB9, Dt::Final, "\u{645}\u{62E}\u{64A}"),
+        (0xFDBA, Dt::Initial, "\u{644}\u{62C}\u{645}"),
+        (0xFDBB, Dt::Final, "\u{643}\u{645}\u{645}"), (0xFDBC, Dt::Final, "\u{644}\u{62C}\u{645}"),
+        (0xFDBD, Dt::Final, "\u{646}\u{62C}\u{62D}"), (0xFDBE, Dt::Final, "\u{62C}\u{62D}\u{64A}"),
+        (0xFDBF, Dt::Final, "\u{62D}\u{62C}\u{64A}"), (0xFDC0, Dt::Final, "\u{645}\u{62C}\u{64A}"),
+        (0xFDC1, Dt::Final, "\u{641}\u{645}\u{64A}"), (0xFDC2, Dt::Final, "\u{628}\u{62D}\u{64A}"),
+        (0xFDC3, Dt::Initial, "\u{643}\u{645}\u{645}"),
+        (0xFDC4, Dt::Initial, "\u{639}\u{62C}\u{645}"),
+        (0xFDC5, Dt::Initial, "\u{635}\u{645}\u{645}"),
+        (0xFDC6, Dt::Final, "\u{633}\u{62E}\u{64A}"), (0xFDC7, Dt::Final, "\u{646}\u{62C}\u{64A}"),
+        (0xFDF0, Dt::Isolated, "\u{635}\u{644}\u{6D2}"),
+        (0xFDF1, Dt::Isolated, "\u{642}\u{644}\u{6D2}"),
+        (0xFDF2, Dt::Isolated, "\u{627}\u{644}\u{644}\u{647}"),
+        (0xFDF3, Dt::Isolated, "\u{627}\u{643}\u{628}\u{631}"),
+        (0xFDF4, Dt::Isolated, "\u{645}\u{62D}\u{645}\u{62F}"),
+        (0xFDF5, Dt::Isolated, "\u{635}\u{644}\u{639}\u{645}"),
+        (0xFDF6, Dt::Isolated, "\u{631}\u{633}\u{648}\u{644}"),
+        (0xFDF7, Dt::Isolated, "\u{639}\u{644}\u{64A}\u{647}"),
+        (0xFDF8, Dt::Isolated, "\u{648}\u{633}\u{644}\u{645}"),
+        (0xFDF9, Dt::Isolated, "\u{635}\u{644}\u{649}"),
+        (0xFDFA, Dt::Isolated, "\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\
+            \u{644}\u{64A}\u{647} \u{648}\u{633}\u{644}\u{645}"),
+        (0xFDFB, Dt::Isolated, "\u{62C}\u{644} \u{62C}\u{644}\u{627}\u{644}\u{647}"),
+        (0xFDFC, Dt::Isolated, "\u{631}\u{6CC}\u{627}\u{644}"), (0xFE10, Dt::Vertical, ","),
+        (0xFE11, Dt::Vertical, "\u{3001}"), (0xFE12, Dt::Vertical, "\u{3002}"),
+        (0xFE13, Dt::Vertical, ":"), (0xFE14, Dt::Vertical, ";"), (0xFE15, Dt::Vertical, "!"),
+        (0xFE16, Dt::Vertical, "?"), (0xFE17, Dt::Vertical, "\u{3016}"),
+        (0xFE18, Dt::Vertical, "\u{3017}"), (0xFE19, Dt::Vertical, "\u{2026}"),
+        (0xFE30, Dt::Vertical, "\u{2025}"), (0xFE31, Dt::Vertical, "\u{2014}"),
+        (0xFE32, Dt::Vertical, "\u{2013}"), (0xFE33, Dt::Vertical, "_"),
+        (0xFE34, Dt::Vertical, "_"), (0xFE35, Dt::Vertical, "("), (0xFE36, Dt::Vertical, ")"),
+        (0xFE37, Dt::Vertical, "{"), (0xFE38, Dt::Vertical, "}"),
+        (0xFE39, Dt::Vertical, "\u{3014}"), (0xFE3A, Dt::Vertical, "\u{3015}"),
+        (0xFE3B, Dt::Vertical, "\u{3010}"), (0xFE3C, Dt::Vertical, "\u{3011}"),
+        (0xFE3D, Dt::Vertical, "\u{300A}"), (0xFE3E, Dt::Vertical, "\u{300B}"),
+        (0xFE3F, Dt::Vertical, "\u{3008}"), (0xFE40, Dt::Vertical, "\u{3009}"),
+        (0xFE41, Dt::Vertical, "\u{300C}"), (0xFE42, Dt::Vertical, "\u{300D}"),
+        (0xFE43, Dt::Vertical, "\u{300E}"), (0xFE44, Dt::Vertical, "\u{300F}"),
+        (0xFE47, Dt::Vertical, "["), (0xFE48, Dt::Vertical, "]"), (0xFE49, Dt::Compat, "\u{203E}"),
+        (0xFE4A, Dt::Compat, "\u{203E}"), (0xFE4B, Dt::Compat, "\u{203E}"),
+        (0xFE4C, Dt::Compat, "\u{203E}"), (0xFE4D, Dt::Compat, "_"), (0xFE4E, Dt::Compat, "_"),
+        (0xFE4F, Dt::Compat, "_"), (0xFE50, Dt::Small, ","), (0xFE51, Dt::Small, "\u{3001}"),
+        (0xFE52, Dt::Small, "."), (0xFE54, Dt::Small, ";"), (0xFE55, Dt::Small, ":"),
+        (0xFE56, Dt::Small, "?"), (0xFE57, Dt::Small, "!"), (0xFE58, Dt::Small, "\u{2014}"),
+        (0xFE59, Dt::Small, "("), (0xFE5A, Dt::Small, ")"), (0xFE5B, Dt::Small, "{"),
+        (0xFE5C, Dt::Small, "}"), (0xFE5D, Dt::Small, "\u{3014}"), (0xFE5E, Dt::Small, "\u{3015}"),
+        (0xFE5F, Dt::Small, "#"), (0xFE60, Dt::Small, "&"), (0xFE61, Dt::Small, "*"),
+        (0xFE62, Dt::Small, "+"), (0xFE63, Dt::Small, "-"), (0xFE64, Dt::Small, "<"),
+        (0xFE65, Dt::Small, ">"), (0xFE66, Dt::Small, "="), (0xFE68, Dt::Small, "\u{5C}"),
+        (0xFE69, Dt::Small, "$"), (0xFE6A, Dt::Small, "%"), (0xFE6B, Dt::Small, "@"),
+        (0xFE70, Dt::Isolated, " \u{64B}"), (0xFE71, Dt::Medial, "\u{640}\u{64B}"),
+        (0xFE72, Dt::Isolated, " \u{64C}"), (0xFE74, Dt::Isolated, " \u{64D}"),
+        (0xFE76, Dt::Isolated, " \u{64E}"), (0xFE77, Dt::Medial, "\u{640}\u{64E}"),
+        (0xFE78, Dt::Isolated, " \u{64F}"), (0xFE79, Dt::Medial, "\u{640}\u{64F}"),
+        (0xFE7A, Dt::Isolated, " \u{650}"), (0xFE7B, Dt::Medial, "\u{640}\u{650}"),
+        (0xFE7C, Dt::Isolated, " \u{651}"), (0xFE7D, Dt::Medial, "\u{640}\u{651}"),
+        (0xFE7E, Dt::Isolated, " \u{652}"), (0xFE7F, Dt::Medial, "\u{640}\u{652}"),
+        (0xFE80, Dt::Isolated, "\u{621}"), (0xFE81, Dt::Isolated, "\u{622}"),
+        (0xFE82, Dt::Final, "\u{622}"), (0xFE83, Dt::Isolated, "\u{623}"),
+        (0xFE84, Dt::Final, "\u{623}"), (0xFE85, Dt::Isolated, "\u{624}"),
+        (0xFE86, Dt::Final, "\u{624}"), (0xFE87, Dt::Isolated, "\u{625}"),
+        (0xFE88, Dt::Final, "\u{625}"), (0xFE89, Dt::Isolated, "\u{626}"),
+        (0xFE8A, Dt::Final, "\u{626}"), (0xFE8B, Dt::Initial, "\u{626}"),
+        (0xFE8C, Dt::Medial, "\u{626}"), (0xFE8D, Dt::Isolated, "\u{627}"),
+        (0xFE8E, Dt::Final, "\u{627}"), (0xFE8F, Dt::Isolated, "\u{628}"),
+        (0xFE90, Dt::Final, "\u{628}"), (0xFE91, Dt::Initial, "\u{628}"),
+        (0xFE92, Dt::Medial, "\u{628}"), (0xFE93, Dt::Isolated, "\u{629}"),
+        (0xFE94, Dt::Final, "\u{629}"), (0xFE95, Dt::Isolated, "\u{62A}"),
+        (0xFE96, Dt::Final, "\u{62A}"), (0xFE97, Dt::Initial, "\u{62A}"),
+        (0xFE98, Dt::Medial, "\u{62A}"), (0xFE99, Dt::Isolated, "\u{62B}"),
+        (0xFE9A, Dt::Final, "\u{62B}"), (0xFE9B, Dt::Initial, "\u{62B}"),
+        (0xFE9C, Dt::Medial, "\u{62B}"), (0xFE9D, Dt::Isolated, "\u{62C}"),
+        (0xFE9E, Dt::Final, "\u{62C}"), (0xFE9F, Dt::Initial, "\u{62C}"),
+        (0xFEA0, Dt::Medial, "\u{62C}"), (0xFEA1, Dt::Isolated, "\u{62D}"),
+        (0xFEA2, Dt::Final, "\u{62D}"), (0xFEA3, Dt::Initial, "\u{62D}"),
+        (0xFEA4, Dt::Medial, "\u{62D}"), (0xFEA5, Dt::Isolated, "\u{62E}"),
+        (0xFEA6, Dt::Final, "\u{62E}"), (0xFEA7, Dt::Initial, "\u{62E}"),
+        (0xFEA8, Dt::Medial, "\u{62E}"), (0xFEA9, Dt::Isolated, "\u{62F}"),
+        (0xFEAA, Dt::Final, "\u{62F}"), (0xFEAB, Dt::Isolated, "\u{630}"),
+        (0xFEAC, Dt::Final, "\u{630}"), (0xFEAD, Dt::Isolated, "\u{631}"),
+        (0xFEAE, Dt::Final, "\u{631}"), (0xFEAF, Dt::Isolated, "\u{632}"),
+        (0xFEB0, Dt::Final, "\u{632}"), (0xFEB1, Dt::Isolated, "\u{633}"),
+        (0xFEB2, Dt::Final, "\u{633}"), (0xFEB3, Dt::Initial, "\u{633}"),
+        (0xFEB4, Dt::Medial, "\u{633}"), (0xFEB5, Dt::Isolated, "\u{634}"),
+        (0xFEB6, Dt::Final, "\u{634}"), (0xFEB7, Dt::Initial, "\u{634}"),
+        (0xFEB8, Dt::Medial, "\u{634}"), (0xFEB9, Dt::Isolated, "\u{635}"),
+        (0xFEBA, Dt::Final, "\u{635}"), (0xFEBB, Dt::Initial, "\u{635}"),
+        (0xFEBC, Dt::Medial, "\u{635}"), (0xFEBD, Dt::Isolated, "\u{636}"),
+        (0xFEBE, Dt::Final, "\u{636}"), (0xFEBF, Dt::Initial, "\u{636}"),
+        (0xFEC0, Dt::Medial, "\u{636}"), (0xFEC1, Dt::Isolated, "\u{637}"),
+        (0xFEC2, Dt::Final, "\u{637}"), (0xFEC3, Dt::Initial, "\u{637}"),
+        (0xFEC4, Dt::Medial, "\u{637}"), (0xFEC5, Dt::Isolated, "\u{638}"),
+        (0xFEC6, Dt::Final, "\u{638}"), (0xFEC7, Dt::Initial, "\u{638}"),
+        (0xFEC8, Dt::Medial, "\u{638}"), (0xFEC9, Dt::Isolated, "\u{639}"),
+        (0xFECA, Dt::Final, "\u{639}"), (0xFECB, Dt::Initial, "\u{639}"),
+        (0xFECC, Dt::Medial, "\u{639}"), (0xFECD, Dt::Isolated, "\u{63A}"),
+        (0xFECE, Dt::Final, "\u{63A}"), (0xFECF, Dt::Initial, "\u{63A}"),
+        (0xFED0, Dt::Medial, "\u{63A}"), (0xFED1, Dt::Isolated, "\u{641}"),
+        (0xFED2, Dt::Final, "\u{641}"), (0xFED3, Dt::Initial, "\u{641}"),
+        (0xFED4, Dt::Medial, "\u{641}"), (0xFED5, Dt::Isolated, "\u{642}"),
+        (0xFED6, Dt::Final, "\u{642}"), (0xFED7, Dt::Initial, "\u{642}"),
+        (0xFED8, Dt::Medial, "\u{642}"), (0xFED9, Dt::Isolated, "\u{643}"),
+        (0xFEDA, Dt::Final, "\u{643}"), (0xFEDB, Dt::Initial, "\u{643}"),
+        (0xFEDC, Dt::Medial, "\u{643}"), (0xFEDD, Dt::Isolated, "\u{644}"),
+        (0xFEDE, Dt::Final, "\u{644}"), (0xFEDF, Dt::Initial, "\u{644}"),
+        (0xFEE0, Dt::Medial, "\u{644}"), (0xFEE1, Dt::Isolated, "\u{645}"),
+        (0xFEE2, Dt::Final, "\u{645}"), (0xFEE3, Dt::Initial, "\u{645}"),
+        (0xFEE4, Dt::Medial, "\u{645}"), (0xFEE5, Dt::Isolated, "\u{646}"),
+        (0xFEE6, Dt::Final, "\u{646}"), (0xFEE7, Dt::Initial, "\u{646}"),
+        (0xFEE8, Dt::Medial, "\u{646}"), (0xFEE9, Dt::Isolated, "\u{647}"),
+        (0xFEEA, Dt::Final, "\u{647}"), (0xFEEB, Dt::Initial, "\u{647}"),
+        (0xFEEC, Dt::Medial, "\u{647}"), (0xFEED, Dt::Isolated, "\u{648}"),
+        (0xFEEE, Dt::Final, "\u{648}"), (0xFEEF, Dt::Isolated, "\u{649}"),
+        (0xFEF0, Dt::Final, "\u{649}"), (0xFEF1, Dt::Isolated, "\u{64A}"),
+        (0xFEF2, Dt::Final, "\u{64A}"), (0xFEF3, Dt::Initial, "\u{64A}"),
+        (0xFEF4, Dt::Medial, "\u{64A}"), (0xFEF5, Dt::Isolated, "\u{644}\u{622}"),
+        (0xFEF6, Dt::Final, "\u{644}\u{622}"), (0xFEF7, Dt::Isolated, "\u{644}\u{623}"),
+        (0xFEF8, Dt::Final, "\u{644}\u{623}"), (0xFEF9, Dt::Isolated, "\u{644}\u{625}"),
+        (0xFEFA, Dt::Final, "\u{644}\u{625}"), (0xFEFB, Dt::Isolated, "\u{644}\u{627}"),
+        (0xFEFC, Dt::Final, "\u{644}\u{627}"), (0xFF01, Dt::Wide, "!"),
+        (0xFF02, Dt::Wide, "\u{22}"), (0xFF03, Dt::Wide, "#"), (0xFF04, Dt::Wide, "$"),
+        (0xFF05, Dt::Wide, "%"), (0xFF06, Dt::Wide, "&"), (0xFF07, Dt::Wide, "'"),
+        (0xFF08, Dt::Wide, "("), (0xFF09, Dt::Wide, ")"), (0xFF0A, Dt::Wide, "*"),
+        (0xFF0B, Dt::Wide, "+"), (0xFF0C, Dt::Wide, ","), (0xFF0D, Dt::Wide, "-"),
+        (0xFF0E, Dt::Wide, "."), (0xFF0F, Dt::Wide, "/"), (0xFF10, Dt::Wide, "0"),
+        (0xFF11, Dt::Wide, "1"), (0xFF12, Dt::Wide, "2"), (0xFF13, Dt::Wide, "3"),
+        (0xFF14, Dt::Wide, "4"), (0xFF15, Dt::Wide, "5"), (0xFF16, Dt::Wide, "6"),
+        (0xFF17, Dt::Wide, "7"), (0xFF18, Dt::Wide, "8"), (0xFF19, Dt::Wide, "9"),
+        (0xFF1A, Dt::Wide, ":"), (0xFF1B, Dt::Wide, ";"), (0xFF1C, Dt::Wide, "<"),
+        (0xFF1D, Dt::Wide, "="), (0xFF1E, Dt::Wide, ">"), (0xFF1F, Dt::Wide, "?"),
+        (0xFF20, Dt::Wide, "@"), (0xFF21, Dt::Wide, "A"), (0xFF22, Dt::Wide, "B"),
+        (0xFF23, Dt::Wide, "C"), (0xFF24, Dt::Wide, "D"), (0xFF25, Dt::Wide, "E"),
+        (0xFF26, Dt::Wide, "F"), (0xFF27, Dt::Wide, "G"), (0xFF28, Dt::Wide, "H"),
+        (0xFF29, Dt::Wide, "I"), (0xFF2A, Dt::Wide, "J"), (0xFF2B, Dt::Wide, "K"),
+        (0xFF2C, Dt::Wide, "L"), (0xFF2D, Dt::Wide, "M"), (0xFF2E, Dt::Wide, "N"),
+        (0xFF2F, Dt::Wide, "O"), (0xFF30, Dt::Wide, "P"), (0xFF31, Dt::Wide, "Q"),
+        (0xFF32, Dt::Wide, "R"), (0xFF33, Dt::Wide, "S"), (0xFF34, Dt::Wide, "T"),
+        (0xFF35, Dt::Wide, "U"), (0xFF36, Dt::Wide, "V"), (0xFF37, Dt::Wide, "W"),
+        (0xFF38, Dt::Wide, "X"), (0xFF39, Dt::Wide, "Y"), (0xFF3A, Dt::Wide, "Z"),
+        (0xFF3B, Dt::Wide, "["), (0xFF3C, Dt::Wide, "\u{5C}"), (0xFF3D, Dt::Wide, "]"),
+        (0xFF3E, Dt::Wide, "^"), (0xFF3F, Dt::Wide, "_"), (0xFF40, Dt::Wide, "`"),
+        (0xFF41, Dt::Wide, "a"), (0xFF42, Dt::Wide, "b"), (0xFF43, Dt::Wide, "c"),
+        (0xFF44, Dt::Wide, "d"), (0xFF45, Dt::Wide, "e"), (0xFF46, Dt::Wide, "f"),
+        (0xFF47, Dt::Wide, "g"), (0xFF48, Dt::Wide, "h"), (0xFF49, Dt::Wide, "i"),
+        (0xFF4A, Dt::Wide, "j"), (0xFF4B, Dt::Wide, "k"), (0xFF4C, Dt::Wide, "l"),
+        (0xFF4D, Dt::Wide, "m"), (0xFF4E, Dt::Wide, "n"), (0xFF4F, Dt::Wide, "o"),
+        (0xFF50, Dt::Wide, "p"), (0xFF51, Dt::Wide, "q"), (0xFF52, Dt::Wide, "r"),
+        (0xFF53, Dt::Wide, "s"), (0xFF54, Dt::Wide, "t"), (0xFF55, Dt::Wide, "u"),
+        (0xFF56, Dt::Wide, "v"), (0xFF57, Dt::Wide, "w"), (0xFF58, Dt::Wide, "x"),
+        (0xFF59, Dt::Wide, "y"), (0xFF5A, Dt::Wide, "z"), (0xFF5B, Dt::Wide, "{"),
+        (0xFF5C, Dt::Wide, "|"), (0xFF5D, Dt::Wide, "}"), (0xFF5E, Dt::Wide, "~"),
+        (0xFF5F, Dt::Wide, "\u{2985}"), (0xFF60, Dt::Wide, "\u{2986}"),
+        (0xFF61, Dt::Narrow, "\u{3002}"), (0xFF62, Dt::Narrow, "\u{300C}"),
+        (0xFF63, Dt::Narrow, "\u{300D}"), (0xFF64, Dt::Narrow, "\u{3001}"),
+        (0xFF65, Dt::Narrow, "\u{30FB}"), (0xFF66, Dt::Narrow, "\u{30F2}"),
+        (0xFF67, Dt::Narrow, "\u{30A1}"), (0xFF68, Dt::Narrow, "\u{30A3}"),
+        (0xFF69, Dt::Narrow, "\u{30A5}"), (0xFF6A, Dt::Narrow, "\u{30A7}"),
+        (0xFF6B, Dt::Narrow, "\u{30A9}"), (0xFF6C, Dt::Narrow, "\u{30E3}"),
+        (0xFF6D, Dt::Narrow, "\u{30E5}"), (0xFF6E, Dt::Narrow, "\u{30E7}"),
+        (0xFF6F, Dt::Narrow, "\u{30C3}"), (0xFF70, Dt::Narrow, "\u{30FC}"),
+        (0xFF71, Dt::Narrow, "\u{30A2}"), (0xFF72, Dt::Narrow, "\u{30A4}"),
+        (0xFF73, Dt::Narrow, "\u{30A6}"), (0xFF74, Dt::Narrow, "\u{30A8}"),
+        (0xFF75, Dt::Narrow, "\u{30AA}"), (0xFF76, Dt::Narrow, "\u{30AB}"),
+        (0xFF77, Dt::Narrow, "\u{30AD}"), (0xFF78, Dt::Narrow, "\u{30AF}"),
+        (0xFF79, Dt::Narrow, "\u{30B1}"), (0xFF7A, Dt::Narrow, "\u{30B3}"),
+        (0xFF7B, Dt::Narrow, "\u{30B5}"), (0xFF7C, Dt::Narrow, "\u{30B7}"),
+        (0xFF7D, Dt::Narrow, "\u{30B9}"), (0xFF7E, Dt::Narrow, "\u{30BB}"),
+        (0xFF7F, Dt::Narrow, "\u{30BD}"), (0xFF80, Dt::Narrow, "\u{30BF}"),
+        (0xFF81, Dt::Narrow, "\u{30C1}"), (0xFF82, Dt::Narrow, "\u{30C4}"),
+        (0xFF83, Dt::Narrow, "\u{30C6}"), (0xFF84, Dt::Narrow, "\u{30C8}"),
+        (0xFF85, Dt::Narrow, "\u{30CA}"), (0xFF86, Dt::Narrow, "\u{30CB}"),
+        (0xFF87, Dt::Narrow, "\u{30CC}"), (0xFF88, Dt::Narrow, "\u{30CD}"),
+        (0xFF89, Dt::Narrow, "\u{30CE}"), (0xFF8A, Dt::Narrow, "\u{30CF}"),
+        (0xFF8B, Dt::Narrow, "\u{30D2}"), (0xFF8C, Dt::Narrow, "\u{30D5}"),
+        (0xFF8D, Dt::Narrow, "\u{30D8}"), (0xFF8E, Dt::Narrow, "\u{30DB}"),
+        (0xFF8F, Dt::Narrow, "\u{30DE}"), (0xFF90, Dt::Narrow, "\u{30DF}"),
+        (0xFF91, Dt::Narrow, "\u{30E0}"), (0xFF92, Dt::Narrow, "\u{30E1}"),
+        (0xFF93, Dt::Narrow, "\u{30E2}"), (0xFF94, Dt::Narrow, "\u{30E4}"),
+        (0xFF95, Dt::Narrow, "\u{30E6}"), (0xFF96, Dt::Narrow, "\u{30E8}"),
+        (0xFF97, Dt::Narrow, "\u{30E9}"), (0xFF98, Dt::Narrow, "\u{30EA}"),
+        (0xFF99, Dt::Narrow, "\u{30EB}"), (0xFF9A, Dt::Narrow, "\u{30EC}"),
+        (0xFF9B, Dt::Narrow, "\u{30ED}"), (0xFF9C, Dt::Narrow, "\u{30EF}"),
+        (0xFF9D, Dt::Narrow, "\u{30F3}"), (0xFF9E, Dt::Narrow, "\u{3099}"),
+        (0xFF9F, Dt::Narrow, "\u{309A}"), (0xFFA0, Dt::Narrow, "\u{3164}"),
+        (0xFFA1, Dt::Narrow, "\u{3131}"), (0xFFA2, Dt::Narrow, "\u{3132}"),
+        (0xFFA3, Dt::Narrow, "\u{3133}"), (0xFFA4, Dt::Narrow, "\u{3134}"),
+        (0xFFA5, Dt::Narrow, "\u{3135}"), (0xFFA6, Dt::Narrow, "\u{3136}"),
+        (0xFFA7, Dt::Narrow, "\u{3137}"), (0xFFA8, Dt::Narrow, "\u{3138}"),
+        (0xFFA9, Dt::Narrow, "\u{3139}"), (0xFFAA, Dt::Narrow, "\u{313A}"),
+        (0xFFAB, Dt::Narrow, "\u{313B}"), (0xFFAC, Dt::Narrow, "\u{313C}"),
+        (0xFFAD, Dt::Narrow, "\u{313D}"), (0xFFAE, Dt::Narrow, "\u{313E}"),
+        (0xFFAF, Dt::Narrow, "\u{313F}"), (0xFFB0, Dt::Narrow, "\u{3140}"),
+        (0xFFB1, Dt::Narrow, "\u{3141}"), (0xFFB2, Dt::Narrow, "\u{3142}"),
+        (0xFFB3, Dt::Narrow, "\u{3143}"), (0xFFB4, Dt::Narrow, "\u{3144}"),
+        (0xFFB5, Dt::Narrow, "\u{3145}"), (0xFFB6, Dt::Narrow, "\u{3146}"),
+        (0xFFB7, Dt::Narrow, "\u{3147}"), (0xFFB8, Dt::Narrow, "\u{3148}"),
+        (0xFFB9, Dt::Narrow, "\u{3149}"), (0xFFBA, Dt::Narrow, "\u{314A}"),
+        (0xFFBB, Dt::Narrow, "\u{314B}"), (0xFFBC, Dt::Narrow, "\u{314C}"),
+        (0xFFBD, Dt::Narrow, "\u{314D}"), (0xFFBE, Dt::Narrow, "\u{314E}"),
+        (0xFFC2, Dt::Narrow, "\u{314F}"), (0xFFC3, Dt::Narrow, "\u{3150}"),
+        (0xFFC4, Dt::Narrow, "\u{3151}"), (0xFFC5, Dt::Narrow, "\u{3152}"),
+        (0xFFC6, Dt::Narrow, "\u{3153}"), (0xFFC7, Dt::Narrow, "\u{3154}"),
+        (0xFFCA, Dt::Narrow, "\u{3155}"), (0xFFCB, Dt::Narrow, "\u{3156}"),
+        (0xFFCC, Dt::Narrow, "\u{3157}"), (0xFFCD, Dt::Narrow, "\u{3158}"),
+        (0xFFCE, Dt::Narrow, "\u{3159}"), (0xFFCF, Dt::Narrow, "\u{315A}"),
+        (0xFFD2, Dt::Narrow, "\u{315B}"), (0xFFD3, Dt::Narrow, "\u{315C}"),
+        (0xFFD4, Dt::Narrow, "\u{315D}"), (0xFFD5, Dt::Narrow, "\u{315E}"),
+        (0xFFD6, Dt::Narrow, "\u{315F}"), (0xFFD7, Dt::Narrow, "\u{3160}"),
+        (0xFFDA, Dt::Narrow, "\u{3161}"), (0xFFDB, Dt::Narrow, "\u{3162}"),
+        (0xFFDC, Dt::Narrow, "\u{3163}"), (0xFFE0, Dt::Wide, "\u{A2}"),
+        (0xFFE1, Dt::Wide, "\u{A3}"), (0xFFE2, Dt::Wide, "\u{AC}"), (0xFFE3, Dt::Wide, "\u{AF}"),
+        (0xFFE4, Dt::Wide, "\u{A6}"), (0xFFE5, Dt::Wide, "\u{A5}"), (0xFFE6, Dt::Wide, "\u{20A9}"),
+        (0xFFE8, Dt::Narrow, "\u{2502}"), (0xFFE9, Dt::Narrow, "\u{2190}"),
+        (0xFFEA, Dt::Narrow, "\u{2191}"), (0xFFEB, Dt::Narrow, "\u{2192}"),
+        (0xFFEC, Dt::Narrow, "\u{2193}"), (0xFFED, Dt::Narrow, "\u{25A0}"),
+        (0xFFEE, Dt::Narrow, "\u{25CB}"), (0x105C9, Dt::Canonical, "\u{105D2}\u{307}"),
+        (0x105E4, Dt::Canonical, "\u{105DA}\u{307}"), (0x10781, Dt::Super, "\u{2D0}"),
+        (0x10782, Dt::Super, "\u{2D1}"), (0x10783, Dt::Super, "\u{E6}"),
+        (0x10784, Dt::Super, "\u{299}"), (0x10785, Dt::Super, "\u{253}"),
+        (0x10787, Dt::Super, "\u{2A3}"), (0x10788, Dt::Super, "\u{AB66}"),
+        (0x10789, Dt::Super, "\u{2A5}"), (0x1078A, Dt::Super, "\u{2A4}"),
+        (0x1078B, Dt::Super, "\u{256}"), (0x1078C, Dt::Super, "\u{257}"),
+        (0x1078D, Dt::Super, "\u{1D91}"), (0x1078E, Dt::Super, "\u{258}"),
+        (0x1078F, Dt::Super, "\u{25E}"), (0x10790, Dt::Super, "\u{2A9}"),
+        (0x10791, Dt::Super, "\u{264}"), (0x10792, Dt::Super, "\u{262}"),
+        (0x10793, Dt::Super, "\u{260}"), (0x10794, Dt::Super, "\u{29B}"),
+        (0x10795, Dt::Super, "\u{127}"), (0x10796, Dt::Super, "\u{29C}"),
+        (0x10797, Dt::Super, "\u{267}"), (0x10798, Dt::Super, "\u{284}"),
+        (0x10799, Dt::Super, "\u{2AA}"), (0x1079A, Dt::Super, "\u{2AB}"),
+        (0x1079B, Dt::Super, "\u{26C}"), (0x1079C, Dt::Super, "\u{1DF04}"),
+        (0x1079D, Dt::Super, "\u{A78E}"), (0x1079E, Dt::Super, "\u{26E}"),
+        (0x1079F, Dt::Super, "\u{1DF05}"), (0x107A0, Dt::Super, "\u{28E}"),
+        (0x107A1, Dt::Super, "\u{1DF06}"), (0x107A2, Dt::Super, "\u{F8}"),
+        (0x107A3, Dt::Super, "\u{276}"), (0x107A4, Dt::Super, "\u{277}"), (0x107A5, Dt::Super, "q"),
+        (0x107A6, Dt::Super, "\u{27A}"), (0x107A7, Dt::Super, "\u{1DF08}"),
+        (0x107A8, Dt::Super, "\u{27D}"), (0x107A9, Dt::Super, "\u{27E}"),
+        (0x107AA, Dt::Super, "\u{280}"), (0x107AB, Dt::Super, "\u{2A8}"),
+        (0x107AC, Dt::Super, "\u{2A6}"), (0x107AD, Dt::Super, "\u{AB67}"),
+        (0x107AE, Dt::Super, "\u{2A7}"), (0x107AF, Dt::Super, "\u{288}"),
+        (0x107B0, Dt::Super, "\u{2C71}"), (0x107B2, Dt::Super, "\u{28F}"),
+        (0x107B3, Dt::Super, "\u{2A1}"), (0x107B4, Dt::Super, "\u{2A2}"),
+        (0x107B5, Dt::Super, "\u{298}"), (0x107B6, Dt::Super, "\u{1C0}"),
+        (0x107B7, Dt::Super, "\u{1C1}"), (0x107B8, Dt::Super, "\u{1C2}"),
+        (0x107B9, Dt::Super, "\u{1DF0A}"), (0x107BA, Dt::Super, "\u{1DF1E}"),
+        (0x1109A, Dt::Canonical, "\u{11099}\u{110BA}"),
+        (0x1109C, Dt::Canonical, "\u{1109B}\u{110BA}"),
+        (0x110AB, Dt::Canonical, "\u{110A5}\u{110BA}"),
+        (0x1112E, Dt::Canonical, "\u{11131}\u{11127}"),
+        (0x1112F, Dt::Canonical, "\u{11132}\u{11127}"),
+        (0x1134B, Dt::Canonical, "\u{11347}\u{1133E}"),
+        (0x1134C, Dt::Canonical, "\u{11347}\u{11357}"),
+        (0x11383, Dt::Canonical, "\u{11382}\u{113C9}"),
+        (0x11385, Dt::Canonical, "\u{11384}\u{113BB}"),
+        (0x1138E, Dt::Canonical, "\u{1138B}\u{113C2}"),
+        (0x11391, Dt::Canonical, "\u{11390}\u{113C9}"),
+        (0x113C5, Dt::Canonical, "\u{113C2}\u{113C2}"),
+        (0x113C7, Dt::Canonical, "\u{113C2}\u{113B8}"),
+        (0x113C8, Dt::Canonical, "\u{113C2}\u{113C9}"),
+        (0x114BB, Dt::Canonical, "\u{114B9}\u{114BA}"),
+        (0x114BC, Dt::Canonical, "\u{114B9}\u{114B0}"),
+        (0x114BE, Dt::Canonical, "\u{114B9}\u{114BD}"),
+        (0x115BA, Dt::Canonical, "\u{115B8}\u{115AF}"),
+        (0x115BB, Dt::Canonical, "\u{115B9}\u{115AF}"),
+        (0x11938, Dt::Canonical, "\u{11935}\u{11930}"),
+        (0x16121, Dt::Canonical, "\u{1611E}\u{1611E}"),
+        (0x16122, Dt::Canonical, "\u{1611E}\u{16129}"),
+        (0x16123, Dt::Canonical, "\u{1611E}\u{1611F}"),
+        (0x16124, Dt::Canonical, "\u{16129}\u{1611F}"),
+        (0x16125, Dt::Canonical, "\u{1611E}\u{16120}"),
+        (0x16126, Dt::Canonical, "\u{16121}\u{1611F}"),
+        (0x16127, Dt::Canonical, "\u{16122}\u{1611F}"),
+        (0x16128, Dt::Canonical, "\u{16121}\u{16120}"),
+        (0x16D68, Dt::Canonical, "\u{16D67}\u{16D67}"),
+        (0x16D69, Dt::Canonical, "\u{16D63}\u{16D67}"),
+        (0x16D6A, Dt::Canonical, "\u{16D69}\u{16D67}"), (0x1CCD6, Dt::Font, "A"),
+        (0x1CCD7, Dt::Font, "B"), (0x1CCD8, Dt::Font, "C"), (0x1CCD9, Dt::Font, "D"),
+        (0x1CCDA, Dt::Font, "E"), (0x1CCDB, Dt::Font, "F"), (0x1CCDC, Dt::Font, "G"),
+        (0x1CCDD, Dt::Font, "H"), (0x1CCDE, Dt::Font, "I"), (0x1CCDF, Dt::Font, "J"),
+        (0x1CCE0, Dt::Font, "K"), (0x1CCE1, Dt::Font, "L"), (0x1CCE2, Dt::Font, "M"),
+        (0x1CCE3, Dt::Font, "N"), (0x1CCE4, Dt::Font, "O"), (0x1CCE5, Dt::Font, "P"),
+        (0x1CCE6, Dt::Font, "Q"), (0x1CCE7, Dt::Font, "R"), (0x1CCE8, Dt::Font, "S"),
+        (0x1CCE9, Dt::Font, "T"), (0x1CCEA, Dt::Font, "U"), (0x1CCEB, Dt::Font, "V"),
+        (0x1CCEC, Dt::Font, "W"), (0x1CCED, Dt::Font, "X"), (0x1CCEE, Dt::Font, "Y"),
+        (0x1CCEF, Dt::Font, "Z"), (0x1CCF0, Dt::Font, "0"), (0x1CCF1, Dt::Font, "1"),
+        (0x1CCF2, Dt::Font, "2"), (0x1CCF3, Dt::Font, "3"), (0x1CCF4, Dt::Font, "4"),
+        (0x1CCF5, Dt::Font, "5"), (0x1CCF6, Dt::Font, "6"), (0x1CCF7, Dt::Font, "7"),
+        (0x1CCF8, Dt::Font, "8"), (0x1CCF9, Dt::Font, "9"),
+        (0x1D15E, Dt::Canonical, "\u{1D157}\u{1D165}"),
+        (0x1D15F, Dt::Canonical, "\u{1D158}\u{1D165}"),
+        (0x1D160, Dt::Canonical, "\u{1D15F}\u{1D16E}"),
+        (0x1D161, Dt::Canonical, "\u{1D15F}\u{1D16F}"),
+        (0x1D162, Dt::Canonical, "\u{1D15F}\u{1D170}"),
+        (0x1D163, Dt::Canonical, "\u{1D15F}\u{1D171}"),
+        (0x1D164, Dt::Canonical, "\u{1D15F}\u{1D172}"),
+        (0x1D1BB, Dt::Canonical, "\u{1D1B9}\u{1D165}"),
+        (0x1D1BC, Dt::Canonical, "\u{1D1BA}\u{1D165}"),
+        (0x1D1BD, Dt::Canonical, "\u{1D1BB}\u{1D16E}"),
+        (0x1D1BE, Dt::Canonical, "\u{1D1BC}\u{1D16E}"),
+        (0x1D1BF, Dt::Canonical, "\u{1D1BB}\u{1D16F}"),
+        (0x1D1C0, Dt::Canonical, "\u{1D1BC}\u{1D16F}"), (0x1D400, Dt::Font, "A"),
+        (0x1D401, Dt::Font, "B"), (0x1D402, Dt::Font, "C"), (0x1D403, Dt::Font, "D"),
+        (0x1D404, Dt::Font, "E"), (0x1D405, Dt::Font, "F"), (0x1D406, Dt::Font, "G"),
+        (0x1D407, Dt::Font, "H"), (0x1D408, Dt::Font, "I"), (0x1D409, Dt::Font, "J"),
+        (0x1D40A, Dt::Font, "K"), (0x1D40B, Dt::Font, "L"), (0x1D40C, Dt::Font, "M"),
+        (0x1D40D, Dt::Font, "N"), (0x1D40E, Dt::Font, "O"), (0x1D40F, Dt::Font, "P"),
+        (0x1D410, Dt::Font, "Q"), (0x1D411, Dt::Font, "R"), (0x1D412, Dt::Font, "S"),
+        (0x1D413, Dt::Font, "T"), (0x1D414, Dt::Font, "U"), (0x1D415, Dt::Font, "V"),
+        (0x1D416, Dt::Font, "W"), (0x1D417, Dt::Font, "X"), (0x1D418, Dt::Font, "Y"),
+        (0x1D419, Dt::Font, "Z"), (0x1D41A, Dt::Font, "a"), (0x1D41B, Dt::Font, "b"),
+        (0x1D41C, Dt::Font, "c"), (0x1D41D, Dt::Font, "d"), (0x1D41E, Dt::Font, "e"),
+        (0x1D41F, Dt::Font, "f"), (0x1D420, Dt::Font, "g"), (0x1D421, Dt::Font, "h"),
+        (0x1D422, Dt::Font, "i"), (0x1D423, Dt::Font, "j"), (0x1D424, Dt::Font, "k"),
+        (0x1D425, Dt::Font, "l"), (0x1D426, Dt::Font, "m"), (0x1D427, Dt::Font, "n"),
+        (0x1D428, Dt::Font, "o"), (0x1D429, Dt::Font, "p"), (0x1D42A, Dt::Font, "q"),
+        (0x1D42B, Dt::Font, "r"), (0x1D42C, Dt::Font, "s"), (0x1D42D, Dt::Font, "t"),
+        (0x1D42E, Dt::Font, "u"), (0x1D42F, Dt::Font, "v"), (0x1D430, Dt::Font, "w"),
+        (0x1D431, Dt::Font, "x"), (0x1D432, Dt::Font, "y"), (0x1D433, Dt::Font, "z"),
+        (0x1D434, Dt::Font, "A"), (0x1D435, Dt::Font, "B"), (0x1D436, Dt::Font, "C"),
+        (0x1D437, Dt::Font, "D"), (0x1D438, Dt::Font, "E"), (0x1D439, Dt::Font, "F"),
+        (0x1D43A, Dt::Font, "G"), (0x1D43B, Dt::Font, "H"), (0x1D43C, Dt::Font, "I"),
+        (0x1D43D, Dt::Font, "J"), (0x1D43E, Dt::Font, "K"), (0x1D43F, Dt::Font, "L"),
+        (0x1D440, Dt::Font, "M"), (0x1D441, Dt::Font, "N"), (0x1D442, Dt::Font, "O"),
+        (0x1D443, Dt::Font, "P"), (0x1D444, Dt::Font, "Q"), (0x1D445, Dt::Font, "R"),
+        (0x1D446, Dt::Font, "S"), (0x1D447, Dt::Font, "T"), (0x1D448, Dt::Font, "U"),
+        (0x1D449, Dt::Font, "V"), (0x1D44A, Dt::Font, "W"), (0x1D44B, Dt::Font, "X"),
+        (0x1D44C, Dt::Font, "Y"), (0x1D44D, Dt::Font, "Z"), (0x1D44E, Dt::Font, "a"),
+        (0x1D44F, Dt::Font, "b"), (0x1D450, Dt::Font, "c"), (0x1D451, Dt::Font, "d"),
+        (0x1D452, Dt::Font, "e"), (0x1D453, Dt::Font, "f"), (0x1D454, Dt::Font, "g"),
+        (0x1D456, Dt::Font, "i"), (0x1D457, Dt::Font, "j"), (0x1D458, Dt::Font, "k"),
+        (0x1D459, Dt::Font, "l"), (0x1D45A, Dt::Font, "m"), (0x1D45B, Dt::Font, "n"),
+        (0x1D45C, Dt::Font, "o"), (0x1D45D, Dt::Font, "p"), (0x1D45E, Dt::Font, "q"),
+        (0x1D45F, Dt::Font, "r"), (0x1D460, Dt::Font, "s"), (0x1D461, Dt::Font, "t"),
+        (0x1D462, Dt::Font, "u"), (0x1D463, Dt::Font, "v"), (0x1D464, Dt::Font, "w"),
+        (0x1D465, Dt::Font, "x"), (0x1D466, Dt::Font, "y"), (0x1D467, Dt::Font, "z"),
+        (0x1D468, Dt::Font, "A"), (0x1D469, Dt::Font, "B"), (0x1D46A, Dt::Font, "C"),
+        (0x1D46B, Dt::Font, "D"), (0x1D46C, Dt::Font, "E"), (0x1D46D, Dt::Font, "F"),
+        (0x1D46E, Dt::Font, "G"), (0x1D46F, Dt::Font, "H"), (0x1D470, Dt::Font, "I"),
+        (0x1D471, Dt::Font, "J"), (0x1D472, Dt::Font, "K"), (0x1D473, Dt::Font, "L"),
+        (0x1D474, Dt::Font, "M"), (0x1D475, Dt::Font, "N"), (0x1D476, Dt::Font, "O"),
+        (0x1D477, Dt::Font, "P"), (0x1D478, Dt::Font, "Q"), (0x1D479, Dt::Font, "R"),
+        (0x1D47A, Dt::Font, "S"), (0x1D47B, Dt::Font, "T"), (0x1D47C, Dt::Font, "U"),
+        (0x1D47D, Dt::Font, "V"), (0x1D47E, Dt::Font, "W"), (0x1D47F, Dt::Font, "X"),
+        (0x1D480, Dt::Font, "Y"), (0x1D481, Dt::Font, "Z"), (0x1D482, Dt::Font, "a"),
+        (0x1D483, Dt::Font, "b"), (0x1D484, Dt::Font, "c"), (0x1D485, Dt::Font, "d"),
+        (0x1D486, Dt::Font, "e"), (0x1D487, Dt::Font, "f"), (0x1D488, Dt::Font, "g"),
+        (0x1D489, Dt::Font, "h"), (0x1D48A, Dt::Font, "i"), (0x1D48B, Dt::Font, "j"),
+        (0x1D48C, Dt::Font, "k"), (0x1D48D, Dt::Font, "l"), (0x1D48E, Dt::Font, "m"),
+        (0x1D48F, Dt::Font, "n"), (0x1D490, Dt::Font, "o"), (0x1D491, Dt::Font, "p"),
+        (0x1D492, Dt::Font, "q"), (0x1D493, Dt::Font, "r"), (0x1D494, Dt::Font, "s"),
+        (0x1D495, Dt::Font, "t"), (0x1D496, Dt::Font, "u"), (0x1D497, Dt::Font, "v"),
+        (0x1D498, Dt::Font, "w"), (0x1D499, Dt::Font, "x"), (0x1D49A, Dt::Font, "y"),
+        (0x1D49B, Dt::Font, "z"), (0x1D49C, Dt::Font, "A"), (0x1D49E, Dt::Font, "C"),
+        (0x1D49F, Dt::Font, "D"), (0x1D4A2, Dt::Font, "G"), (0x1D4A5, Dt::Font, "J"),
+        (0x1D4A6, Dt::Font, "K"), (0x1D4A9, Dt::Font, "N"), (0x1D4AA, Dt::Font, "O"),
+        (0x1D4AB, Dt::Font, "P"), (0x1D4AC, Dt::Font, "Q"), (0x1D4AE, Dt::Font, "S"),
+        (0x1D4AF, Dt::Font, "T"), (0x1D4B0, Dt::Font, "U"), (0x1D4B1, Dt::Font, "V"),
+        (0x1D4B2, Dt::Font, "W"), (0x1D4B3, Dt::Font, "X"), (0x1D4B4, Dt::Font, "Y"),
+        (0x1D4B5, Dt::Font, "Z"), (0x1D4B6, Dt::Font, "a"), (0x1D4B7, Dt::Font, "b"),
+        (0x1D4B8, Dt::Font, "c"), (0x1D4B9, Dt::Font, "d"), (0x1D4BB, Dt::Font, "f"),
+        (0x1D4BD, Dt::Font, "h"), (0x1D4BE, Dt::Font, "i"), (0x1D4BF, Dt::Font, "j"),
+        (0x1D4C0, Dt::Font, "k"), (0x1D4C1, Dt::Font, "l"), (0x1D4C2, Dt::Font, "m"),
+        (0x1D4C3, Dt::Font, "n"), (0x1D4C5, Dt::Font, "p"), (0x1D4C6, Dt::Font, "q"),
+        (0x1D4C7, Dt::Font, "r"), (0x1D4C8, Dt::Font, "s"), (0x1D4C9, Dt::Font, "t"),
+        (0x1D4CA, Dt::Font, "u"), (0x1D4CB, Dt::Font, "v"), (0x1D4CC, Dt::Font, "w"),
+        (0x1D4CD, Dt::Font, "x"), (0x1D4CE, Dt::Font, "y"), (0x1D4CF, Dt::Font, "z"),
+        (0x1D4D0, Dt::Font, "A"), (0x1D4D1, Dt::Font, "B"), (0x1D4D2, Dt::Font, "C"),
+        (0x1D4D3, Dt::Font, "D"), (0x1D4D4, Dt::Font, "E"), (0x1D4D5, Dt::Font, "F"),
+        (0x1D4D6, Dt::Font, "G"), (0x1D4D7, Dt::Font, "H"), (0x1D4D8, Dt::Font, "I"),
+        (0x1D4D9, Dt::Font, "J"), (0x1D4DA, Dt::Font, "K"), (0x1D4DB, Dt::Font, "L"),
+        (0x1D4DC, Dt::Font, "M"), (0x1D4DD, Dt::Font, "N"), (0x1D4DE, Dt::Font, "O"),
+        (0x1D4DF, Dt::Font, "P"), (0x1D4E0, Dt::Font, "Q"), (0x1D4E1, Dt::Font, "R"),
+        (0x1D4E2, Dt::Font, "S"), (0x1D4E3, Dt::Font, "T"), (0x1D4E4, Dt::Font, "U"),
+        (0x1D4E5, Dt::Font, "V"), (0x1D4E6, Dt::Font, "W"), (0x1D4E7, Dt::Font, "X"),
+        (0x1D4E8, Dt::Font, "Y"), (0x1D4E9, Dt::Font, "Z"), (0x1D4EA, Dt::Font, "a"),
+        (0x1D4EB, Dt::Font, "b"), (0x1D4EC, Dt::Font, "c"), (0x1D4ED, Dt::Font, "d"),
+        (0x1D4EE, Dt::Font, "e"), (0x1D4EF, Dt::Font, "f"), (0x1D4F0, Dt::Font, "g"),
+        (0x1D4F1, Dt::Font, "h"), (0x1D4F2, Dt::Font, "i"), (0x1D4F3, Dt::Font, "j"),
+        (0x1D4F4, Dt::Font, "k"), (0x1D4F5, Dt::Font, "l"), (0x1D4F6, Dt::Font, "m"),
+        (0x1D4F7, Dt::Font, "n"), (0x1D4F8, Dt::Font, "o"), (0x1D4F9, Dt::Font, "p"),
+        (0x1D4FA, Dt::Font, "q"), (0x1D4FB, Dt::Font, "r"), (0x1D4FC, Dt::Font, "s"),
+        (0x1D4FD, Dt::Font, "t"), (0x1D4FE, Dt::Font, "u"), (0x1D4FF, Dt::Font, "v"),
+        (0x1D500, Dt::Font, "w"), (0x1D501, Dt::Font, "x"), (0x1D502, Dt::Font, "y"),
+        (0x1D503, Dt::Font, "z"), (0x1D504, Dt::Font, "A"), (0x1D505, Dt::Font, "B"),
+        (0x1D507, Dt::Font, "D"), (0x1D508, Dt::Font, "E"), (0x1D509, Dt::Font, "F"),
+        (0x1D50A, Dt::Font, "G"), (0x1D50D, Dt::Font, "J"), (0x1D50E, Dt::Font, "K"),
+        (0x1D50F, Dt::Font, "L"), (0x1D510, Dt::Font, "M"), (0x1D511, Dt::Font, "N"),
+        (0x1D512, Dt::Font, "O"), (0x1D513, Dt::Font, "P"), (0x1D514, Dt::Font, "Q"),
+        (0x1D516, Dt::Font, "S"), (0x1D517, Dt::Font, "T"), (0x1D518, Dt::Font, "U"),
+        (0x1D519, Dt::Font, "V"), (0x1D51A, Dt::Font, "W"), (0x1D51B, Dt::Font, "X"),
+        (0x1D51C, Dt::Font, "Y"), (0x1D51E, Dt::Font, "a"), (0x1D51F, Dt::Font, "b"),
+        (0x1D520, Dt::Font, "c"), (0x1D521, Dt::Font, "d"), (0x1D522, Dt::Font, "e"),
+        (0x1D523, Dt::Font, "f"), (0x1D524, Dt::Font, "g"), (0x1D525, Dt::Font, "h"),
+        (0x1D526, Dt::Font, "i"), (0x1D527, Dt::Font, "j"), (0x1D528, Dt::Font, "k"),
+        (0x1D529, Dt::Font, "l"), (0x1D52A, Dt::Font, "m"), (0x1D52B, Dt::Font, "n"),
+        (0x1D52C, Dt::Font, "o"), (0x1D52D, Dt::Font, "p"), (0x1D52E, Dt::Font, "q"),
+        (0x1D52F, Dt::Font, "r"), (0x1D530, Dt::Font, "s"), (0x1D531, Dt::Font, "t"),
+        (0x1D532, Dt::Font, "u"), (0x1D533, Dt::Font, "v"), (0x1D534, Dt::Font, "w"),
+        (0x1D535, Dt::Font, "x"), (0x1D536, Dt::Font, "y"), (0x1D537, Dt::Font, "z"),
+        (0x1D538, Dt::Font, "A"), (0x1D539, Dt::Font, "B"), (0x1D53B, Dt::Font, "D"),
+        (0x1D53C, Dt::Font, "E"), (0x1D53D, Dt::Font, "F"), (0x1D53E, Dt::Font, "G"),
+        (0x1D540, Dt::Font, "I"), (0x1D541, Dt::Font, "J"), (0x1D542, Dt::Font, "K"),
+        (0x1D543, Dt::Font, "L"), (0x1D544, Dt::Font, "M"), (0x1D546, Dt::Font, "O"),
+        (0x1D54A, Dt::Font, "S"), (0x1D54B, Dt::Font, "T"), (0x1D54C, Dt::Font, "U"),
+        (0x1D54D, Dt::Font, "V"), (0x1D54E, Dt::Font, "W"), (0x1D54F, Dt::Font, "X"),
+        (0x1D550, Dt::Font, "Y"), (0x1D552, Dt::Font, "a"), (0x1D553, Dt::Font, "b"),
+        (0x1D554, Dt::Font, "c"), (0x1D555, Dt::Font, "d"), (0x1D556, Dt::Font, "e"),
+        (0x1D557, Dt::Font, "f"), (0x1D558, Dt::Font, "g"), (0x1D559, Dt::Font, "h"),
+        (0x1D55A, Dt::Font, "i"), (0x1D55B, Dt::Font, "j"), (0x1D55C, Dt::Font, "k"),
+        (0x1D55D, Dt::Font, "l"), (0x1D55E, Dt::Font, "m"), (0x1D55F, Dt::Font, "n"),
+        (0x1D560, Dt::Font, "o"), (0x1D561, Dt::Font, "p"), (0x1D562, Dt::Font, "q"),
+        (0x1D563, Dt::Font, "r"), (0x1D564, Dt::Font, "s"), (0x1D565, Dt::Font, "t"),
+        (0x1D566, Dt::Font, "u"), (0x1D567, Dt::Font, "v"), (0x1D568, Dt::Font, "w"),
+        (0x1D569, Dt::Font, "x"), (0x1D56A, Dt::Font, "y"), (0x1D56B, Dt::Font, "z"),
+        (0x1D56C, Dt::Font, "A"), (0x1D56D, Dt::Font, "B"), (0x1D56E, Dt::Font, "C"),
+        (0x1D56F, Dt::Font, "D"), (0x1D570, Dt::Font, "E"), (0x1D571, Dt::Font, "F"),
+        (0x1D572, Dt::Font, "G"), (0x1D573, Dt::Font, "H"), (0x1D574, Dt::Font, "I"),
+        (0x1D575, Dt::Font, "J"), (0x1D576, Dt::Font, "K"), (0x1D577, Dt::Font, "L"),
+        (0x1D578, Dt::Font, "M"), (0x1D579, Dt::Font, "N"), (0x1D57A, Dt::Font, "O"),
+        (0x1D57B, Dt::Font, "P"), (0x1D57C, Dt::Font, "Q"), (0x1D57D, Dt::Font, "R"),
+        (0x1D57E, Dt::Font, "S"), (0x1D57F, Dt::Font, "T"), (0x1D580, Dt::Font, "U"),
+        (0x1D581, Dt::Font, "V"), (0x1D582, Dt::Font, "W"), (0x1D583, Dt::Font, "X"),
+        (0x1D584, Dt::Font, "Y"), (0x1D585, Dt::Font, "Z"), (0x1D586, Dt::Font, "a"),
+        (0x1D587, Dt::Font, "b"), (0x1D588, Dt::Font, "c"), (0x1D589, Dt::Font, "d"),
+        (0x1D58A, Dt::Font, "e"), (0x1D58B, Dt::Font, "f"), (0x1D58C, Dt::Font, "g"),
+        (0x1D58D, Dt::Font, "h"), (0x1D58E, Dt::Font, "i"), (0x1D58F, Dt::Font, "j"),
+        (0x1D590, Dt::Font, "k"), (0x1D591, Dt::Font, "l"), (0x1D592, Dt::Font, "m"),
+        (0x1D593, Dt::Font, "n"), (0x1D594, Dt::Font, "o"), (0x1D595, Dt::Font, "p"),
+        (0x1D596, Dt::Font, "q"), (0x1D597, Dt::Font, "r"), (0x1D598, Dt::Font, "s"),
+        (0x1D599, Dt::Font, "t"), (0x1D59A, Dt::Font, "u"), (0x1D59B, Dt::Font, "v"),
+        (0x1D59C, Dt::Font, "w"), (0x1D59D, Dt::Font, "x"), (0x1D59E, Dt::Font, "y"),
+        (0x1D59F, Dt::Font, "z"), (0x1D5A0, Dt::Font, "A"), (0x1D5A1, Dt::Font, "B"),
+        (0x1D5A2, Dt::Font, "C"), (0x1D5A3, Dt::Font, "D"), (0x1D5A4, Dt::Font, "E"),
+        (0x1D5A5, Dt::Font, "F"), (0x1D5A6, Dt::Font, "G"), (0x1D5A7, Dt::Font, "H"),
+        (0x1D5A8, Dt::Font, "I"), (0x1D5A9, Dt::Font, "J"), (0x1D5AA, Dt::Font, "K"),
+        (0x1D5AB, Dt::Font, "L"), (0x1D5AC, Dt::Font, "M"), (0x1D5AD, Dt::Font, "N"),
+        (0x1D5AE, Dt::Font, "O"), (0x1D5AF, Dt::Font, "P"), (0x1D5B0, Dt::Font, "Q"),
+        (0x1D5B1, Dt::Font, "R"), (0x1D5B2, Dt::Font, "S"), (0x1D5B3, Dt::Font, "T"),
+        (0x1D5B4, Dt::Font, "U"), (0x1D5B5, Dt::Font, "V"), (0x1D5B6, Dt::Font, "W"),
+        (0x1D5B7, Dt::Font, "X"), (0x1D5B8, Dt::Font, "Y"), (0x1D5B9, Dt::Font, "Z"),
+        (0x1D5BA, Dt::Font, "a"), (0x1D5BB, Dt::Font, "b"), (0x1D5BC, Dt::Font, "c"),
+        (0x1D5BD, Dt::Font, "d"), (0x1D5BE, Dt::Font, "e"), (0x1D5BF, Dt::Font, "f"),
+        (0x1D5C0, Dt::Font, "g"), (0x1D5C1, Dt::Font, "h"), (0x1D5C2, Dt::Font, "i"),
+        (0x1D5C3, Dt::Font, "j"), (0x1D5C4, Dt::Font, "k"), (0x1D5C5, Dt::Font, "l"),
+        (0x1D5C6, Dt::Font, "m"), (0x1D5C7, Dt::Font, "n"), (0x1D5C8, Dt::Font, "o"),
+        (0x1D5C9, Dt::Font, "p"), (0x1D5CA, Dt::Font, "q"), (0x1D5CB, Dt::Font, "r"),
+        (0x1D5CC, Dt::Font, "s"), (0x1D5CD, Dt::Font, "t"), (0x1D5CE, Dt::Font, "u"),
+        (0x1D5CF, Dt::Font, "v"), (0x1D5D0, Dt::Font, "w"), (0x1D5D1, Dt::Font, "x"),
+        (0x1D5D2, Dt::Font, "y"), (0x1D5D3, Dt::Font, "z"), (0x1D5D4, Dt::Font, "A"),
+        (0x1D5D5, Dt::Font, "B"), (0x1D5D6, Dt::Font, "C"), (0x1D5D7, Dt::Font, "D"),
+        (0x1D5D8, Dt::Font, "E"), (0x1D5D9, Dt::Font, "F"), (0x1D5DA, Dt::Font, "G"),
+        (0x1D5DB, Dt::Font, "H"), (0x1D5DC, Dt::Font, "I"), (0x1D5DD, Dt::Font, "J"),
+        (0x1D5DE, Dt::Font, "K"), (0x1D5DF, Dt::Font, "L"), (0x1D5E0, Dt::Font, "M"),
+        (0x1D5E1, Dt::Font, "N"), (0x1D5E2, Dt::Font, "O"), (0x1D5E3, Dt::Font, "P"),
+        (0x1D5E4, Dt::Font, "Q"), (0x1D5E5, Dt::Font, "R"), (0x1D5E6, Dt::Font, "S"),
+        (0x1D5E7, Dt::Font, "T"), (0x1D5E8, Dt::Font, "U"), (0x1D5E9, Dt::Font, "V"),
+        (0x1D5EA, Dt::Font, "W"), (0x1D5EB, Dt::Font, "X"), (0x1D5EC, Dt::Font, "Y"),
+        (0x1D5ED, Dt::Font, "Z"), (0x1D5EE, Dt::Font, "a"), (0x1D5EF, Dt::Font, "b"),
+        (0x1D5F0, Dt::Font, "c"), (0x1D5F1, Dt::Font, "d"), (0x1D5F2, Dt::Font, "e"),
+        (0x1D5F3, Dt::Font, "f"), (0x1D5F4, Dt::Font, "g"), (0x1D5F5, Dt::Font, "h"),
+        (0x1D5F6, Dt::Font, "i"), (0x1D5F7, Dt::Font, "j"), (0x1D5F8, Dt::Font, "k"),
+        (0x1D5F9, Dt::Font, "l"), (0x1D5FA, Dt::Font, "m"), (0x1D5FB, Dt::Font, "n"),
+        (0x1D5FC, Dt::Font, "o"), (0x1D5FD, Dt::Font, "p"), (0x1D5FE, Dt::Font, "q"),
+        (0x1D5FF, Dt::Font, "r"), (0x1D600, Dt::Font, "s"), (0x1D601, Dt::Font, "t"),
+        (0x1D602, Dt::Font, "u"), (0x1D603, Dt::Font, "v"), (0x1D604, Dt::Font, "w"),
+        (0x1D605, Dt::Font, "x"), (0x1D606, Dt::Font, "y"), (0x1D607, Dt::Font, "z"),
+        (0x1D608, Dt::Font, "A"), (0x1D609, Dt::Font, "B"), (0x1D60A, Dt::Font, "C"),
+        (0x1D60B, Dt::Font, "D"), (0x1D60C, Dt::Font, "E"), (0x1D60D, Dt::Font, "F"),
+        (0x1D60E, Dt::Font, "G"), (0x1D60F, Dt::Font, "H"), (0x1D610, Dt::Font, "I"),
+        (0x1D611, Dt::Font, "J"), (0x1D612, Dt::Font, "K"), (0x1D613, Dt::Font, "L"),
+        (0x1D614, Dt::Font, "M"), (0x1D615, Dt::Font, "N"), (0x1D616, Dt::Font, "O"),
+        (0x1D617, Dt::Font, "P"), (0x1D618, Dt::Font, "Q"), (0x1D619, Dt::Font, "R"),
+        (0x1D61A, Dt::Font, "S"), (0x1D61B, Dt::Font, "T"), (0x1D61C, Dt::Font, "U"),
+        (0x1D61D, Dt::Font, "V"), (0x1D61E, Dt::Font, "W"), (0x1D61F, Dt::Font, "X"),
+        (0x1D620, Dt::Font, "Y"), (0x1D621, Dt::Font, "Z"), (0x1D622, Dt::Font, "a"),
+        (0x1D623, Dt::Font, "b"), (0x1D624, Dt::Font, "c"), (0x1D625, Dt::Font, "d"),
+        (0x1D626, Dt::Font, "e"), (0x1D627, Dt::Font, "f"), (0x1D628, Dt::Font, "g"),
+        (0x1D629, Dt::Font, "h"), (0x1D62A, Dt::Font, "i"), (0x1D62B, Dt::Font, "j"),
+        (0x1D62C, Dt::Font, "k"), (0x1D62D, Dt::Font, "l"), (0x1D62E, Dt::Font, "m"),
+        (0x1D62F, Dt::Font, "n"), (0x1D630, Dt::Font, "o"), (0x1D631, Dt::Font, "p"),
+        (0x1D632, Dt::Font, "q"), (0x1D633, Dt::Font, "r"), (0x1D634, Dt::Font, "s"),
+        (0x1D635, Dt::Font, "t"), (0x1D636, Dt::Font, "u"), (0x1D637, Dt::Font, "v"),
+        (0x1D638, Dt::Font, "w"), (0x1D639, Dt::Font, "x"), (0x1D63A, Dt::Font, "y"),
+        (0x1D63B, Dt::Font, "z"), (0x1D63C, Dt::Font, "A"), (0x1D63D, Dt::Font, "B"),
+        (0x1D63E, Dt::Font, "C"), (0x1D63F, Dt::Font, "D"), (0x1D640, Dt::Font, "E"),
+        (0x1D641, Dt::Font, "F"), (0x1D642, Dt::Font, "G"), (0x1D643, Dt::Font, "H"),
+        (0x1D644, Dt::Font, "I"), (0x1D645, Dt::Font, "J"), (0x1D646, Dt::Font, "K"),
+        (0x1D647, Dt::Font, "L"), (0x1D648, Dt::Font, "M"), (0x1D649, Dt::Font, "N"),
+        (0x1D64A, Dt::Font, "O"), (0x1D64B, Dt::Font, "P"), (0x1D64C, Dt::Font, "Q"),
+        (0x1D64D, Dt::Font, "R"), (0x1D64E, Dt::Font, "S"), (0x1D64F, Dt::Font, "T"),
+        (0x1D650, Dt::Font, "U"), (0x1D651, Dt::Font, "V"), (0x1D652, Dt::Font, "W"),
+        (0x1D653, Dt::Font, "X"), (0x1D654, Dt::Font, "Y"), (0x1D655, Dt::Font, "Z"),
+        (0x1D656, Dt::Font, "a"), (0x1D657, Dt::Font, "b"), (0x1D658, Dt::Font, "c"),
+        (0x1D659, Dt::Font, "d"), (0x1D65A, Dt::Font, "e"), (0x1D65B, Dt::Font, "f"),
+        (0x1D65C, Dt::Font, "g"), (0x1D65D, Dt::Font, "h"), (0x1D65E, Dt::Font, "i"),
+        (0x1D65F, Dt::Font, "j"), (0x1D660, Dt::Font, "k"), (0x1D661, Dt::Font, "l"),
+        (0x1D662, Dt::Font, "m"), (0x1D663, Dt::Font, "n"), (0x1D664, Dt::Font, "o"),
+        (0x1D665, Dt::Font, "p"), (0x1D666, Dt::Font, "q"), (0x1D667, Dt::Font, "r"),
+        (0x1D668, Dt::Font, "s"), (0x1D669, Dt::Font, "t"), (0x1D66A, Dt::Font, "u"),
+        (0x1D66B, Dt::Font, "v"), (0x1D66C, Dt::Font, "w"), (0x1D66D, Dt::Font, "x"),
+        (0x1D66E, Dt::Font, "y"), (0x1D66F, Dt::Font, "z"), (0x1D670, Dt::Font, "A"),
+        (0x1D671, Dt::Font, "B"), (0x1D672, Dt::Font, "C"), (0x1D673, Dt::Font, "D"),
+        (0x1D674, Dt::Font, "E"), (0x1D675, Dt::Font, "F"), (0x1D676, Dt::Font, "G"),
+        (0x1D677, Dt::Font, "H"), (0x1D678, Dt::Font, "I"), (0x1D679, Dt::Font, "J"),
+        (0x1D67A, Dt::Font, "K"), (0x1D67B, Dt::Font, "L"), (0x1D67C, Dt::Font, "M"),
+        (0x1D67D, Dt::Font, "N"), (0x1D67E, Dt::Font, "O"), (0x1D67F, Dt::Font, "P"),
+        (0x1D680, Dt::Font, "Q"), (0x1D681, Dt::Font, "R"), (0x1D682, Dt::Font, "S"),
+        (0x1D683, Dt::Font, "T"), (0x1D684, Dt::Font, "U"), (0x1D685, Dt::Font, "V"),
+        (0x1D686, Dt::Font, "W"), (0x1D687, Dt::Font, "X"), (0x1D688, Dt::Font, "Y"),
+        (0x1D689, Dt::Font, "Z"), (0x1D68A, Dt::Font, "a"), (0x1D68B, Dt::Font, "b"),
+        (0x1D68C, Dt::Font, "c"), (0x1D68D, Dt::Font, "d"), (0x1D68E, Dt::Font, "e"),
+        (0x1D68F, Dt::Font, "f"), (0x1D690, Dt::Font, "g"), (0x1D691, Dt::Font, "h"),
+        (0x1D692, Dt::Font, "i"), (0x1D693, Dt::Font, "j"), (0x1D694, Dt::Font, "k"),
+        (0x1D695, Dt::Font, "l"), (0x1D696, Dt::Font, "m"), (0x1D697, Dt::Font, "n"),
+        (0x1D698, Dt::Font, "o"), (0x1D699, Dt::Font, "p"), (0x1D69A, Dt::Font, "q"),
+        (0x1D69B, Dt::Font, "r"), (0x1D69C, Dt::Font, "s"), (0x1D69D, Dt::Font, "t"),
+        (0x1D69E, Dt::Font, "u"), (0x1D69F, Dt::Font, "v"), (0x1D6A0, Dt::Font, "w"),
+        (0x1D6A1, Dt::Font, "x"), (0x1D6A2, Dt::Font, "y"), (0x1D6A3, Dt::Font, "z"),
+        (0x1D6A4, Dt::Font, "\u{131}"), (0x1D6A5, Dt::Font, "\u{237}"),
+        (0x1D6A8, Dt::Font, "\u{391}"), (0x1D6A9, Dt::Font, "\u{392}"),
+        (0x1D6AA, Dt::Font, "\u{393}"), (0x1D6AB, Dt::Font, "\u{394}"),
+        (0x1D6AC, Dt::Font, "\u{395}"), (0x1D6AD, Dt::Font, "\u{396}"),
+        (0x1D6AE, Dt::Font, "\u{397}"), (0x1D6AF, Dt::Font, "\u{398}"),
+        (0x1D6B0, Dt::Font, "\u{399}"), (0x1D6B1, Dt::Font, "\u{39A}"),
+        (0x1D6B2, Dt::Font, "\u{39B}"), (0x1D6B3, Dt::Font, "\u{39C}"),
+        (0x1D6B4, Dt::Font, "\u{39D}"), (0x1D6B5, Dt::Font, "\u{39E}"),
+        (0x1D6B6, Dt::Font, "\u{39F}"), (0x1D6B7, Dt::Font, "\u{3A0}"),
+        (0x1D6B8, Dt::Font, "\u{3A1}"), (0x1D6B9, Dt::Font, "\u{3F4}"),
+        (0x1D6BA, Dt::Font, "\u{3A3}"), (0x1D6BB, Dt::Font, "\u{3A4}"),
+        (0x1D6BC, Dt::Font, "\u{3A5}"), (0x1D6BD, Dt::Font, "\u{3A6}"),
+        (0x1D6BE, Dt::Font, "\u{3A7}"), (0x1D6BF, Dt::Font, "\u{3A8}"),
+        (0x1D6C0, Dt::Font, "\u{3A9}"), (0x1D6C1, Dt::Font, "\u{2207}"),
+        (0x1D6C2, Dt::Font, "\u{3B1}"), (0x1D6C3, Dt::Font, "\u{3B2}"),
+        (0x1D6C4, Dt::Font, "\u{3B3}"), (0x1D6C5, Dt::Font, "\u{3B4}"),
+        (0x1D6C6, Dt::Font, "\u{3B5}"), (0x1D6C7, Dt::Font, "\u{3B6}"),
+        (0x1D6C8, Dt::Font, "\u{3B7}"), (0x1D6C9, Dt::Font, "\u{3B8}"),
+        (0x1D6CA, Dt::Font, "\u{3B9}"), (0x1D6CB, Dt::Font, "\u{3BA}"),
+        (0x1D6CC, Dt::Font, "\u{3BB}"), (0x1D6CD, Dt::Font, "\u{3BC}"),
+        (0x1D6CE, Dt::Font, "\u{3BD}"), (0x1D6CF, Dt::Font, "\u{3BE}"),
+        (0x1D6D0, Dt::Font, "\u{3BF}"), (0x1D6D1, Dt::Font, "\u{3C0}"),
+        (0x1D6D2, Dt::Font, "\u{3C1}"), (0x1D6D3, Dt::Font, "\u{3C2}"),
+        (0x1D6D4, Dt::Font, "\u{3C3}"), (0x1D6D5, Dt::Font, "\u{3C4}"),
+        (0x1D6D6, Dt::Font, "\u{3C5}"), (0x1D6D7, Dt::Font, "\u{3C6}"),
+        (0x1D6D8, Dt::Font, "\u{3C7}"), (0x1D6D9, Dt::Font, "\u{3C8}"),
+        (0x1D6DA, Dt::Font, "\u{3C9}"), (0x1D6DB, Dt::Font, "\u{2202}"),
+        (0x1D6DC, Dt::Font, "\u{3F5}"), (0x1D6DD, Dt::Font, "\u{3D1}"),
+        (0x1D6DE, Dt::Font, "\u{3F0}"), (0x1D6DF, Dt::Font, "\u{3D5}"),
+        (0x1D6E0, Dt::Font, "\u{3F1}"), (0x1D6E1, Dt::Font, "\u{3D6}"),
+        (0x1D6E2, Dt::Font, "\u{391}"), (0x1D6E3, Dt::Font, "\u{392}"),
+        (0x1D6E4, Dt::Font, "\u{393}"), (0x1D6E5, Dt::Font, "\u{394}"),
+        (0x1D6E6, Dt::Font, "\u{395}"), (0x1D6E7, Dt::Font, "\u{396}"),
+        (0x1D6E8, Dt::Font, "\u{397}"), (0x1D6E9, Dt::Font, "\u{398}"),
+        (0x1D6EA, Dt::Font, "\u{399}"), (0x1D6EB, Dt::Font, "\u{39A}"),
+        (0x1D6EC, Dt::Font, "\u{39B}"), (0x1D6ED, Dt::Font, "\u{39C}"),
+        (0x1D6EE, Dt::Font, "\u{39D}"), (0x1D6EF, Dt::Font, "\u{39E}"),
+        (0x1D6F0, Dt::Font, "\u{39F}"), (0x1D6F1, Dt::Font, "\u{3A0}"),
+        (0x1D6F2, Dt::Font, "\u{3A1}"), (0x1D6F3, Dt::Font, "\u{3F4}"),
+        (0x1D6F4, Dt::Font, "\u{3A3}"), (0x1D6F5, Dt::Font, "\u{3A4}"),
+        (0x1D6F6, Dt::Font, "\u{3A5}"), (0x1D6F7, Dt::Font, "\u{3A6}"),
+        (0x1D6F8, Dt::Font, "\u{3A7}"), (0x1D6F9, Dt::Font, "\u{3A8}"),
+        (0x1D6FA, Dt::Font, "\u{3A9}"), (0x1D6FB, Dt::Font, "\u{2207}"),
+        (0x1D6FC, Dt::Font, "\u{3B1}"), (0x1D6FD, Dt::Font, "\u{3B2}"),
+        (0x1D6FE, Dt::Font, "\u{3B3}"), (0x1D6FF, Dt::Font, "\u{3B4}"),
+        (0x1D700, Dt::Font, "\u{3B5}"), (0x1D701, Dt::Font, "\u{3B6}"),
+        (0x1D702, Dt::Font, "\u{3B7}"), (0x1D703, Dt::Font, "\u{3B8}"),
+        (0x1D704, Dt::Font, "\u{3B9}"), (0x1D705, Dt::Font, "\u{3BA}"),
+        (0x1D706, Dt::Font, "\u{3BB}"), (0x1D707, Dt::Font, "\u{3BC}"),
+        (0x1D708, Dt::Font, "\u{3BD}"), (0x1D709, Dt::Font, "\u{3BE}"),
+        (0x1D70A, Dt::Font, "\u{3BF}"), (0x1D70B, Dt::Font, "\u{3C0}"),
+        (0x1D70C, Dt::Font, "\u{3C1}"), (0x1D70D, Dt::Font, "\u{3C2}"),
+        (0x1D70E, Dt::Font, "\u{3C3}"), (0x1D70F, Dt::Font, "\u{3C4}"),
+        (0x1D710, Dt::Font, "\u{3C5}"), (0x1D711, Dt::Font, "\u{3C6}"),
+        (0x1D712, Dt::Font, "\u{3C7}"), (0x1D713, Dt::Font, "\u{3C8}"),
+        (0x1D714, Dt::Font, "\u{3C9}"), (0x1D715, Dt::Font, "\u{2202}"),
+        (0x1D716, Dt::Font, "\u{3F5}"), (0x1D717, Dt::Font, "\u{3D1}"),
+        (0x1D718, Dt::Font, "\u{3F0}"), (0x1D719, Dt::Font, "\u{3D5}"),
+        (0x1D71A, Dt::Font, "\u{3F1}"), (0x1D71B, Dt::Font, "\u{3D6}"),
+        (0x1D71C, Dt::Font, "\u{391}"), (0x1D71D, Dt::Font, "\u{392}"),
+        (0x1D71E, Dt::Font, "\u{393}"), (0x1D71F, Dt::Font, "\u{394}"),
+        (0x1D720, Dt::Font, "\u{395}"), (0x1D721, Dt::Font, "\u{396}"),
+        (0x1D722, Dt::Font, "\u{397}"), (0x1D723, Dt::Font, "\u{398}"),
+        (0x1D724, Dt::Font, "\u{399}"), (0x1D725, Dt::Font, "\u{39A}"),
+        (0x1D726, Dt::Font, "\u{39B}"), (0x1D727, Dt::Font, "\u{39C}"),
+        (0x1D728, Dt::Font, "\u{39D}"), (0x1D729, Dt::Font, "\u{39E}"),
+        (0x1D72A, Dt::Font, "\u{39F}"), (0x1D72B, Dt::Font, "\u{3A0}"),
+        (0x1D72C, Dt::Font, "\u{3A1}"), (0x1D72D, Dt::Font, "\u{3F4}"),
+        (0x1D72E, Dt::Font, "\u{3A3}"), (0x1D72F, Dt::Font, "\u{3A4}"),
+        (0x1D730, Dt::Font, "\u{3A5}"), (0x1D731, Dt::Font, "\u{3A6}"),
+        (0x1D732, Dt::Font, "\u{3A7}"), (0x1D733, Dt::Font, "\u{3A8}"),
+        (0x1D734, Dt::Font, "\u{3A9}"), (0x1D735, Dt::Font, "\u{2207}"),
+        (0x1D736, Dt::Font, "\u{3B1}"), (0x1D737, Dt::Font, "\u{3B2}"),
+        (0x1D738, Dt::Font, "\u{3B3}"), (0x1D739, Dt::Font, "\u{3B4}"),
+        (0x1D73A, Dt::Font, "\u{3B5}"), (0x1D73B, Dt::Font, "\u{3B6}"),
+        (0x1D73C, Dt::Font, "\u{3B7}"), (0x1D73D, Dt::Font, "\u{3B8}"),
+        (0x1D73E, Dt::Font, "\u{3B9}"), (0x1D73F, Dt::Font, "\u{3BA}"),
+        (0x1D740, Dt::Font, "\u{3BB}"), (0x1D741, Dt::Font, "\u{3BC}"),
+        (0x1D742, Dt::Font, "\u{3BD}"), (0x1D743, Dt::Font, "\u{3BE}"),
+        (0x1D744, Dt::Font, "\u{3BF}"), (0x1D745, Dt::Font, "\u{3C0}"),
+        (0x1D746, Dt::Font, "\u{3C1}"), (0x1D747, Dt::Font, "\u{3C2}"),
+        (0x1D748, Dt::Font, "\u{3C3}"), (0x1D749, Dt::Font, "\u{3C4}"),
+        (0x1D74A, Dt::Font, "\u{3C5}"), (0x1D74B, Dt::Font, "\u{3C6}"),
+        (0x1D74C, Dt::Font, "\u{3C7}"), (0x1D74D, Dt::Font, "\u{3C8}"),
+        (0x1D74E, Dt::Font, "\u{3C9}"), (0x1D74F, Dt::Font, "\u{2202}"),
+        (0x1D750, Dt::Font, "\u{3F5}"), (0x1D751, Dt::Font, "\u{3D1}"),
+        (0x1D752, Dt::Font, "\u{3F0}"), (0x1D753, Dt::Font, "\u{3D5}"),
+        (0x1D754, Dt::Font, "\u{3F1}"), (0x1D755, Dt::Font, "\u{3D6}"),
+        (0x1D756, Dt::Font, "\u{391}"), (0x1D757, Dt::Font, "\u{392}"),
+        (0x1D758, Dt::Font, "\u{393}"), (0x1D759, Dt::Font, "\u{394}"),
+        (0x1D75A, Dt::Font, "\u{395}"), (0x1D75B, Dt::Font, "\u{396}"),
+        (0x1D75C, Dt::Font, "\u{397}"), (0x1D75D, Dt::Font, "\u{398}"),
+        (0x1D75E, Dt::Font, "\u{399}"), (0x1D75F, Dt::Font, "\u{39A}"),
+        (0x1D760, Dt::Font, "\u{39B}"), (0x1D761, Dt::Font, "\u{39C}"),
+        (0x1D762, Dt::Font, "\u{39D}"), (0x1D763, Dt::Font, "\u{39E}"),
+        (0x1D764, Dt::Font, "\u{39F}"), (0x1D765, Dt::Font, "\u{3A0}"),
+        (0x1D766, Dt::Font, "\u{3A1}"), (0x1D767, Dt::Font, "\u{3F4}"),
+        (0x1D768, Dt::Font, "\u{3A3}"), (0x1D769, Dt::Font, "\u{3A4}"),
+        (0x1D76A, Dt::Font, "\u{3A5}"), (0x1D76B, Dt::Font, "\u{3A6}"),
+        (0x1D76C, Dt::Font, "\u{3A7}"), (0x1D76D, Dt::Font, "\u{3A8}"),
+        (0x1D76E, Dt::Font, "\u{3A9}"), (0x1D76F, Dt::Font, "\u{2207}"),
+        (0x1D770, Dt::Font, "\u{3B1}"), (0x1D771, Dt::Font, "\u{3B2}"),
+        (0x1D772, Dt::Font, "\u{3B3}"), (0x1D773, Dt::Font, "\u{3B4}"),
+        (0x1D774, Dt::Font, "\u{3B5}"), (0x1D775, Dt::Font, "\u{3B6}"),
+        (0x1D776, Dt::Font, "\u{3B7}"), (0x1D777, Dt::Font, "\u{3B8}"),
+        (0x1D778, Dt::Font, "\u{3B9}"), (0x1D779, Dt::Font, "\u{3BA}"),
+        (0x1D77A, Dt::Font, "\u{3BB}"), (0x1D77B, Dt::Font, "\u{3BC}"),
+        (0x1D77C, Dt::Font, "\u{3BD}"), (0x1D77D, Dt::Font, "\u{3BE}"),
+        (0x1D77E, Dt::Font, "\u{3BF}"), (0x1D77F, Dt::Font, "\u{3C0}"),
+        (0x1D780, Dt::Font, "\u{3C1}"), (0x1D781, Dt::Font, "\u{3C2}"),
+        (0x1D782, Dt::Font, "\u{3C3}"), (0x1D783, Dt::Font, "\u{3C4}"),
+        (0x1D784, Dt::Font, "\u{3C5}"), (0x1D785, Dt::Font, "\u{3C6}"),
+        (0x1D786, Dt::Font, "\u{3C7}"), (0x1D787, Dt::Font, "\u{3C8}"),
+        (0x1D788, Dt::Font, "\u{3C9}"), (0x1D789, Dt::Font, "\u{2202}"),
+        (0x1D78A, Dt::Font, "\u{3F5}"), (0x1D78B, Dt::Font, "\u{3D1}"),
+        (0x1D78C, Dt::Font, "\u{3F0}"), (0x1D78D, Dt::Font, "\u{3D5}"),
+        (0x1D78E, Dt::Font, "\u{3F1}"), (0x1D78F, Dt::Font, "\u{3D6}"),
+        (0x1D790, Dt::Font, "\u{391}"), (0x1D791, Dt::Font, "\u{392}"),
+        (0x1D792, Dt::Font, "\u{393}"), (0x1D793, Dt::Font, "\u{394}"),
+        (0x1D794, Dt::Font, "\u{395}"), (0x1D795, Dt::Font, "\u{396}"),
+        (0x1D796, Dt::Font, "\u{397}"), (0x1D797, Dt::Font, "\u{398}"),
+        (0x1D798, Dt::Font, "\u{399}"), (0x1D799, Dt::Font, "\u{39A}"),
+        (0x1D79A, Dt::Font, "\u{39B}"), (0x1D79B, Dt::Font, "\u{39C}"),
+        (0x1D79C, Dt::Font, "\u{39D}"), (0x1D79D, Dt::Font, "\u{39E}"),
+        (0x1D79E, Dt::Font, "\u{39F}"), (0x1D79F, Dt::Font, "\u{3A0}"),
+        (0x1D7A0, Dt::Font, "\u{3A1}"), (0x1D7A1, Dt::Font, "\u{3F4}"),
+        (0x1D7A2, Dt::Font, "\u{3A3}"), (0x1D7A3, Dt::Font, "\u{3A4}"),
+        (0x1D7A4, Dt::Font, "\u{3A5}"), (0x1D7A5, Dt::Font, "\u{3A6}"),
+        (0x1D7A6, Dt::Font, "\u{3A7}"), (0x1D7A7, Dt::Font, "\u{3A8}"),
+        (0x1D7A8, Dt::Font, "\u{3A9}"), (0x1D7A9, Dt::Font, "\u{2207}"),
+        (0x1D7AA, Dt::Font, "\u{3B1}"), (0x1D7AB, Dt::Font, "\u{3B2}"),
+        (0x1D7AC, Dt::Font, "\u{3B3}"), (0x1D7AD, Dt::Font, "\u{3B4}"),
+        (0x1D7AE, Dt::Font, "\u{3B5}"), (0x1D7AF, Dt::Font, "\u{3B6}"),
+        (0x1D7B0, Dt::Font, "\u{3B7}"), (0x1D7B1, Dt::Font, "\u{3B8}"),
+        (0x1D7B2, Dt::Font, "\u{3B9}"), (0x1D7B3, Dt::Font, "\u{3BA}"),
+        (0x1D7B4, Dt::Font, "\u{3BB}"), (0x1D7B5, Dt::Font, "\u{3BC}"),
+        (0x1D7B6, Dt::Font, "\u{3BD}"), (0x1D7B7, Dt::Font, "\u{3BE}"),
+        (0x1D7B8, Dt::Font, "\u{3BF}"), (0x1D7B9, Dt::Font, "\u{3C0}"),
+        (0x1D7BA, Dt::Font, "\u{3C1}"), (0x1D7BB, Dt::Font, "\u{3C2}"),
+        (0x1D7BC, Dt::Font, "\u{3C3}"), (0x1D7BD, Dt::Font, "\u{3C4}"),
+        (0x1D7BE, Dt::Font, "\u{3C5}"), (0x1D7BF, Dt::Font, "\u{3C6}"),
+        (0x1D7C0, Dt::Font, "\u{3C7}"), (0x1D7C1, Dt::Font, "\u{3C8}"),
+        (0x1D7C2, Dt::Font, "\u{3C9}"), (0x1D7C3, Dt::Font, "\u{2202}"),
+        (0x1D7C4, Dt::Font, "\u{3F5}"), (0x1D7C5, Dt::Font, "\u{3D1}"),
+        (0x1D7C6, Dt::Font, "\u{3F0}"), (0x1D7C7, Dt::Font, "\u{3D5}"),
+        (0x1D7C8, Dt::Font, "\u{3F1}"), (0x1D7C9, Dt::Font, "\u{3D6}"),
+        (0x1D7CA, Dt::Font, "\u{3DC}"), (0x1D7CB, Dt::Font, "\u{3DD}"), (0x1D7CE, Dt::Font, "0"),
+        (0x1D7CF, Dt::Font, "1"), (0x1D7D0, Dt::Font, "2"), (0x1D7D1, Dt::Font, "3"),
+        (0x1D7D2, Dt::Font, "4"), (0x1D7D3, Dt::Font, "5"), (0x1D7D4, Dt::Font, "6"),
+        (0x1D7D5, Dt::Font, "7"), (0x1D7D6, Dt::Font, "8"), (0x1D7D7, Dt::Font, "9"),
+        (0x1D7D8, Dt::Font, "0"), (0x1D7D9, Dt::Font, "1"), (0x1D7DA, Dt::Font, "2"),
+        (0x1D7DB, Dt::Font, "3"), (0x1D7DC, Dt::Font, "4"), (0x1D7DD, Dt::Font, "5"),
+        (0x1D7DE, Dt::Font, "6"), (0x1D7DF, Dt::Font, "7"), (0x1D7E0, Dt::Font, "8"),
+        (0x1D7E1, Dt::Font, "9"), (0x1D7E2, Dt::Font, "0"), (0x1D7E3, Dt::Font, "1"),
+        (0x1D7E4, Dt::Font, "2"), (0x1D7E5, Dt::Font, "3"), (0x1D7E6, Dt::Font, "4"),
+        (0x1D7E7, Dt::Font, "5"), (0x1D7E8, Dt::Font, "6"), (0x1D7E9, Dt::Font, "7"),
+        (0x1D7EA, Dt::Font, "8"), (0x1D7EB, Dt::Font, "9"), (0x1D7EC, Dt::Font, "0"),
+        (0x1D7ED, Dt::Font, "1"), (0x1D7EE, Dt::Font, "2"), (0x1D7EF, Dt::Font, "3"),
+        (0x1D7F0, Dt::Font, "4"), (0x1D7F1, Dt::Font, "5"), (0x1D7F2, Dt::Font, "6"),
+        (0x1D7F3, Dt::Font, "7"), (0x1D7F4, Dt::Font, "8"), (0x1D7F5, Dt::Font, "9"),
+        (0x1D7F6, Dt::Font, "0"), (0x1D7F7, Dt::Font, "1"), (0x1D7F8, Dt::Font, "2"),
+        (0x1D7F9, Dt::Font, "3"), (0x1D7FA, Dt::Font, "4"), (0x1D7FB, Dt::Font, "5"),
+        (0x1D7FC, Dt::Font, "6"), (0x1D7FD, Dt::Font, "7"), (0x1D7FE, Dt::Font, "8"),
+        (0x1D7FF, Dt::Font, "9"), (0x1E030, Dt::Super, "\u{430}"), (0x1E031, Dt::Super, "\u{431}"),
+        (0x1E032, Dt::Super, "\u{432}"), (0x1E033, Dt::Super, "\u{433}"),
+        (0x1E034, Dt::Super, "\u{434}"), (0x1E035, Dt::Super, "\u{435}"),
+        (0x1E036, Dt::Super, "\u{436}"), (0x1E037, Dt::Super, "\u{437}"),
+        (0x1E038, Dt::Super, "\u{438}"), (0x1E039, Dt::Super, "\u{43A}"),
+        (0x1E03A, Dt::Super, "\u{43B}"), (0x1E03B, Dt::Super, "\u{43C}"),
+        (0x1E03C, Dt::Super, "\u{43E}"), (0x1E03D, Dt::Super, "\u{43F}"),
+        (0x1E03E, Dt::Super, "\u{440}"), (0x1E03F, Dt::Super, "\u{441}"),
+        (0x1E040, Dt::Super, "\u{442}"), (0x1E041, Dt::Super, "\u{443}"),
+        (0x1E042, Dt::Super, "\u{444}"), (0x1E043, Dt::Super, "\u{445}"),
+        (0x1E044, Dt::Super, "\u{446}"), (0x1E045, Dt::Super, "\u{447}"),
+        (0x1E046, Dt::Super, "\u{448}"), (0x1E047, Dt::Super, "\u{44B}"),
+        (0x1E048, Dt::Super, "\u{44D}"), (0x1E049, Dt::Super, "\u{44E}"),
+        (0x1E04A, Dt::Super, "\u{A689}"), (0x1E04B, Dt::Super, "\u{4D9}"),
+        (0x1E04C, Dt::Super, "\u{456}"), (0x1E04D, Dt::Super, "\u{458}"),
+        (0x1E04E, Dt::Super, "\u{4E9}"), (0x1E04F, Dt::Super, "\u{4AF}"),
+        (0x1E050, Dt::Super, "\u{4CF}"), (0x1E051, Dt::Sub, "\u{430}"),
+        (0x1E052, Dt::Sub, "\u{431}"), (0x1E053, Dt::Sub, "\u{432}"), (0x1E054, Dt::Sub, "\u{433}"),
+        (0x1E055, Dt::Sub, "\u{434}"), (0x1E056, Dt::Sub, "\u{435}"), (0x1E057, Dt::Sub, "\u{436}"),
+        (0x1E058, Dt::Sub, "\u{437}"), (0x1E059, Dt::Sub, "\u{438}"), (0x1E05A, Dt::Sub, "\u{43A}"),
+        (0x1E05B, Dt::Sub, "\u{43B}"), (0x1E05C, Dt::Sub, "\u{43E}"), (0x1E05D, Dt::Sub, "\u{43F}"),
+        (0x1E05E, Dt::Sub, "\u{441}"), (0x1E05F, Dt::Sub, "\u{443}"), (0x1E060, Dt::Sub, "\u{444}"),
+        (0x1E061, Dt::Sub, "\u{445}"), (0x1E062, Dt::Sub, "\u{446}"), (0x1E063, Dt::Sub, "\u{447}"),
+        (0x1E064, Dt::Sub, "\u{448}"), (0x1E065, Dt::Sub, "\u{44A}"), (0x1E066, Dt::Sub, "\u{44B}"),
+        (0x1E067, Dt::Sub, "\u{491}"), (0x1E068, Dt::Sub, "\u{456}"), (0x1E069, Dt::Sub, "\u{455}"),
+        (0x1E06A, Dt::Sub, "\u{45F}"), (0x1E06B, Dt::Super, "\u{4AB}"),
+        (0x1E06C, Dt::Super, "\u{A651}"), (0x1E06D, Dt::Super, "\u{4B1}"),
+        (0x1EE00, Dt::Font, "\u{627}"), (0x1EE01, Dt::Font, "\u{628}"),
+        (0x1EE02, Dt::Font, "\u{62C}"), (0x1EE03, Dt::Font, "\u{62F}"),
+        (0x1EE05, Dt::Font, "\u{648}"), (0x1EE06, Dt::Font, "\u{632}"),
+        (0x1EE07, Dt::Font, "\u{62D}"), (0x1EE08, Dt::Font, "\u{637}"),
+        (0x1EE09, Dt::Font, "\u{64A}"), (0x1EE0A, Dt::Font, "\u{643}"),
+        (0x1EE0B, Dt::Font, "\u{644}"), (0x1EE0C, Dt::Font, "\u{645}"),
+        (0x1EE0D, Dt::Font, "\u{646}"), (0x1EE0E, Dt::Font, "\u{633}"),
+        (0x1EE0F, Dt::Font, "\u{639}"), (0x1EE10, Dt::Font, "\u{641}"),
+        (0x1EE11, Dt::Font, "\u{635}"), (0x1EE12, Dt::Font, "\u{642}"),
+        (0x1EE13, Dt::Font, "\u{631}"), (0x1EE14, Dt::Font, "\u{634}"),
+        (0x1EE15, Dt::Font, "\u{62A}"), (0x1EE16, Dt::Font, "\u{62B}"),
+        (0x1EE17, Dt::Font, "\u{62E}"), (0x1EE18, Dt::Font, "\u{630}"),
+        (0x1EE19, Dt::Font, "\u{636}"), (0x1EE1A, Dt::Font, "\u{638}"),
+        (0x1EE1B, Dt::Font, "\u{63A}"), (0x1EE1C, Dt::Font, "\u{66E}"),
+        (0x1EE1D, Dt::Font, "\u{6BA}"), (0x1EE1E, Dt::Font, "\u{6A1}"),
+        (0x1EE1F, Dt::Font, "\u{66F}"), (0x1EE21, Dt::Font, "\u{628}"),
+        (0x1EE22, Dt::Font, "\u{62C}"), (0x1EE24, Dt::Font, "\u{647}"),
+        (0x1EE27, Dt::Font, "\u{62D}"), (0x1EE29, Dt::Font, "\u{64A}"),
+        (0x1EE2A, Dt::Font, "\u{643}"), (0x1EE2B, Dt::Font, "\u{644}"),
+        (0x1EE2C, Dt::Font, "\u{645}"), (0x1EE2D, Dt::Font, "\u{646}"),
+        (0x1EE2E, Dt::Font, "\u{633}"), (0x1EE2F, Dt::Font, "\u{639}"),
+        (0x1EE30, Dt::Font, "\u{641}"), (0x1EE31, Dt::Font, "\u{635}"),
+        (0x1EE32, Dt::Font, "\u{642}"), (0x1EE34, Dt::Font, "\u{634}"),
+        (0x1EE35, Dt::Font, "\u{62A}"), (0x1EE36, Dt::Font, "\u{62B}"),
+        (0x1EE37, Dt::Font, "\u{62E}"), (0x1EE39, Dt::Font, "\u{636}"),
+        (0x1EE3B, Dt::Font, "\u{63A}"), (0x1EE42, Dt::Font, "\u{62C}"),
+        (0x1EE47, Dt::Font, "\u{62D}"), (0x1EE49, Dt::Font, "\u{64A}"),
+        (0x1EE4B, Dt::Font, "\u{644}"), (0x1EE4D, Dt::Font, "\u{646}"),
+        (0x1EE4E, Dt::Font, "\u{633}"), (0x1EE4F, Dt::Font, "\u{639}"),
+        (0x1EE51, Dt::Font, "\u{635}"), (0x1EE52, Dt::Font, "\u{642}"),
+        (0x1EE54, Dt::Font, "\u{634}"), (0x1EE57, Dt::Font, "\u{62E}"),
+        (0x1EE59, Dt::Font, "\u{636}"), (0x1EE5B, Dt::Font, "\u{63A}"),
+        (0x1EE5D, Dt::Font, "\u{6BA}"), (0x1EE5F, Dt::Font, "\u{66F}"),
+        (0x1EE61, Dt::Font, "\u{628}"), (0x1EE62, Dt::Font, "\u{62C}"),
+        (0x1EE64, Dt::Font, "\u{647}"), (0x1EE67, Dt::Font, "\u{62D}"),
+        (0x1EE68, Dt::Font, "\u{637}"), (0x1EE69, Dt::Font, "\u{64A}"),
+        (0x1EE6A, Dt::Font, "\u{643}"), (0x1EE6C, Dt::Font, "\u{645}"),
+        (0x1EE6D, Dt::Font, "\u{646}"), (0x1EE6E, Dt::Font, "\u{633}"),
+        (0x1EE6F, Dt::Font, "\u{639}"), (0x1EE70, Dt::Font, "\u{641}"),
+        (0x1EE71, Dt::Font, "\u{635}"), (0x1EE72, Dt::Font, "\u{642}"),
+        (0x1EE74, Dt::Font, "\u{634}"), (0x1EE75, Dt::Font, "\u{62A}"),
+        (0x1EE76, Dt::Font, "\u{62B}"), (0x1EE77, Dt::Font, "\u{62E}"),
+        (0x1EE79, Dt::Font, "\u{636}"), (0x1EE7A, Dt::Font, "\u{638}"),
+        (0x1EE7B, Dt::Font, "\u{63A}"), (0x1EE7C, Dt::Font, "\u{66E}"),
+        (0x1EE7E, Dt::Font, "\u{6A1}"), (0x1EE80, Dt::Font, "\u{627}"),
+        (0x1EE81, Dt::Font, "\u{628}"), (0x1EE82, Dt::Font, "\u{62C}"),
+        (0x1EE83, Dt::Font, "\u{62F}"), (0x1EE84, Dt::Font, "\u{647}"),
+        (0x1EE85, Dt::Font, "\u{648}"), (0x1EE86, Dt::Font, "\u{632}"),
+        (0x1EE87, Dt::Font, "\u{62D}"), (0x1EE88, Dt::Font, "\u{637}"),
+        (0x1EE89, Dt::Font, "\u{64A}"), (0x1EE8B, Dt::Font, "\u{644}"),
+        (0x1EE8C, Dt::Font, "\u{645}"), (0x1EE8D, Dt::Font, "\u{646}"),
+        (0x1EE8E, Dt::Font, "\u{633}"), (0x1EE8F, Dt::Font, "\u{639}"),
+        (0x1EE90, Dt::Font, "\u{641}"), (0x1EE91, Dt::Font, "\u{635}"),
+        (0x1EE92, Dt::Font, "\u{642}"), (0x1EE93, Dt::Font, "\u{631}"),
+        (0x1EE94, Dt::Font, "\u{634}"), (0x1EE95, Dt::Font, "\u{62A}"),
+        (0x1EE96, Dt::Font, "\u{62B}"), (0x1EE97, Dt::Font, "\u{62E}"),
+        (0x1EE98, Dt::Font, "\u{630}"), (0x1EE99, Dt::Font, "\u{636}"),
+        (0x1EE9A, Dt::Font, "\u{638}"), (0x1EE9B, Dt::Font, "\u{63A}"),
+        (0x1EEA1, Dt::Font, "\u{628}"), (0x1EEA2, Dt::Font, "\u{62C}"),
+        (0x1EEA3, Dt::Font, "\u{62F}"), (0x1EEA5, Dt::Font, "\u{648}"),
+        (0x1EEA6, Dt::Font, "\u{632}"), (0x1EEA7, Dt::Font, "\u{62D}"),
+        (0x1EEA8, Dt::Font, "\u{637}"), (0x1EEA9, Dt::Font, "\u{64A}"),
+        (0x1EEAB, Dt::Font, "\u{644}"), (0x1EEAC, Dt::Font, "\u{645}"),
+        (0x1EEAD, Dt::Font, "\u{646}"), (0x1EEAE, Dt::Font, "\u{633}"),
+        (0x1EEAF, Dt::Font, "\u{639}"), (0x1EEB0, Dt::Font, "\u{641}"),
+        (0x1EEB1, Dt::Font, "\u{635}"), (0x1EEB2, Dt::Font, "\u{642}"),
+        (0x1EEB3, Dt::Font, "\u{631}"), (0x1EEB4, Dt::Font, "\u{634}"),
+        (0x1EEB5, Dt::Font, "\u{62A}"), (0x1EEB6, Dt::Font, "\u{62B}"),
+        (0x1EEB7, Dt::Font, "\u{62E}"), (0x1EEB8, Dt::Font, "\u{630}"),
+        (0x1EEB9, Dt::Font, "\u{636}"), (0x1EEBA, Dt::Font, "\u{638}"),
+        (0x1EEBB, Dt::Font, "\u{63A}"), (0x1F100, Dt::Compat, "0."), (0x1F101, Dt::Compat, "0,"),
+        (0x1F102, Dt::Compat, "1,"), (0x1F103, Dt::Compat, "2,"), (0x1F104, Dt::Compat, "3,"),
+        (0x1F105, Dt::Compat, "4,"), (0x1F106, Dt::Compat, "5,"), (0x1F107, Dt::Compat, "6,"),
+        (0x1F108, Dt::Compat, "7,"), (0x1F109, Dt::Compat, "8,"), (0x1F10A, Dt::Compat, "9,"),
+        (0x1F110, Dt::Compat, "(A)"), (0x1F111, Dt::Compat, "(B)"), (0x1F112, Dt::Compat, "(C)"),
+        (0x1F113, Dt::Compat, "(D)"), (0x1F114, Dt::Compat, "(E)"), (0x1F115, Dt::Compat, "(F)"),
+        (0x1F116, Dt::Compat, "(G)"), (0x1F117, Dt::Compat, "(H)"), (0x1F118, Dt::Compat, "(I)"),
+        (0x1F119, Dt::Compat, "(J)"), (0x1F11A, Dt::Compat, "(K)"), (0x1F11B, Dt::Compat, "(L)"),
+        (0x1F11C, Dt::Compat, "(M)"), (0x1F11D, Dt::Compat, "(N)"), (0x1F11E, Dt::Compat, "(O)"),
+        (0x1F11F, Dt::Compat, "(P)"), (0x1F120, Dt::Compat, "(Q)"), (0x1F121, Dt::Compat, "(R)"),
+        (0x1F122, Dt::Compat, "(S)"), (0x1F123, Dt::Compat, "(T)"), (0x1F124, Dt::Compat, "(U)"),
+        (0x1F125, Dt::Compat, "(V)"), (0x1F126, Dt::Compat, "(W)"), (0x1F127, Dt::Compat, "(X)"),
+        (0x1F128, Dt::Compat, "(Y)"), (0x1F129, Dt::Compat, "(Z)"),
+        (0x1F12A, Dt::Compat, "\u{3014}S\u{3015}"), (0x1F12B, Dt::Circle, "C"),
+        (0x1F12C, Dt::Circle, "R"), (0x1F12D, Dt::Circle, "CD"), (0x1F12E, Dt::Circle, "WZ"),
+        (0x1F130, Dt::Square, "A"), (0x1F131, Dt::Square, "B"), (0x1F132, Dt::Square, "C"),
+        (0x1F133, Dt::Square, "D"), (0x1F134, Dt::Square, "E"), (0x1F135, Dt::Square, "F"),
+        (0x1F136, Dt::Square, "G"), (0x1F137, Dt::Square, "H"), (0x1F138, Dt::Square, "I"),
+        (0x1F139, Dt::Square, "J"), (0x1F13A, Dt::Square, "K"), (0x1F13B, Dt::Square, "L"),
+        (0x1F13C, Dt::Square, "M"), (0x1F13D, Dt::Square, "N"), (0x1F13E, Dt::Square, "O"),
+        (0x1F13F, Dt::Square, "P"), (0x1F140, Dt::Square, "Q"), (0x1F141, Dt::Square, "R"),
+        (0x1F142, Dt::Square, "S"), (0x1F143, Dt::Square, "T"), (0x1F144, Dt::Square, "U"),
+        (0x1F145, Dt::Square, "V"), (0x1F146, Dt::Square, "W"), (0x1F147, Dt::Square, "X"),
+        (0x1F148, Dt::Square, "Y"), (0x1F149, Dt::Square, "Z"), (0x1F14A, Dt::Square, "HV"),
+        (0x1F14B, Dt::Square, "MV"), (0x1F14C, Dt::Square, "SD"), (0x1F14D, Dt::Square, "SS"),
+        (0x1F14E, Dt::Square, "PPV"), (0x1F14F, Dt::Square, "WC"), (0x1F16A, Dt::Super, "MC"),
+        (0x1F16B, Dt::Super, "MD"), (0x1F16C, Dt::Super, "MR"), (0x1F190, Dt::Square, "DJ"),
+        (0x1F200, Dt::Square, "\u{307B}\u{304B}"), (0x1F201, Dt::Square, "\u{30B3}\u{30B3}"),
+        (0x1F202, Dt::Square, "\u{30B5}"), (0x1F210, Dt::Square, "\u{624B}"),
+        (0x1F211, Dt::Square, "\u{5B57}"), (0x1F212, Dt::Square, "\u{53CC}"),
+        (0x1F213, Dt::Square, "\u{30C7}"), (0x1F214, Dt::Square, "\u{4E8C}"),
+        (0x1F215, Dt::Square, "\u{591A}"), (0x1F216, Dt::Square, "\u{89E3}"),
+        (0x1F217, Dt::Square, "\u{5929}"), (0x1F218, Dt::Square, "\u{4EA4}"),
+        (0x1F219, Dt::Square, "\u{6620}"), (0x1F21A, Dt::Square, "\u{7121}"),
+        (0x1F21B, Dt::Square, "\u{6599}"), (0x1F21C, Dt::Square, "\u{524D}"),
+        (0x1F21D, Dt::Square, "\u{5F8C}"), (0x1F21E, Dt::Square, "\u{518D}"),
+        (0x1F21F, Dt::Square, "\u{65B0}"), (0x1F220, Dt::Square, "\u{521D}"),
+        (0x1F221, Dt::Square, "\u{7D42}"), (0x1F222, Dt::Square, "\u{751F}"),
+        (0x1F223, Dt::Square, "\u{8CA9}"), (0x1F224, Dt::Square, "\u{58F0}"),
+        (0x1F225, Dt::Square, "\u{5439}"), (0x1F226, Dt::Square, "\u{6F14}"),
+        (0x1F227, Dt::Square, "\u{6295}"), (0x1F228, Dt::Square, "\u{6355}"),
+        (0x1F229, Dt::Square, "\u{4E00}"), (0x1F22A, Dt::Square, "\u{4E09}"),
+        (0x1F22B, Dt::Square, "\u{904A}"), (0x1F22C, Dt::Square, "\u{5DE6}"),
+        (0x1F22D, Dt::Square, "\u{4E2D}"), (0x1F22E, Dt::Square, "\u{53F3}"),
+        (0x1F22F, Dt::Square, "\u{6307}"), (0x1F230, Dt::Square, "\u{8D70}"),
+        (0x1F231, Dt::Square, "\u{6253}"), (0x1F232, Dt::Square, "\u{7981}"),
+        (0x1F233, Dt::Square, "\u{7A7A}"), (0x1F234, Dt::Square, "\u{5408}"),
+        (0x1F235, Dt::Square, "\u{6E80}"), (0x1F236, Dt::Square, "\u{6709}"),
+        (0x1F237, Dt::Square, "\u{6708}"), (0x1F238, Dt::Square, "\u{7533}"),
+        (0x1F239, Dt::Square, "\u{5272}"), (0x1F23A, Dt::Square, "\u{55B6}"),
+        (0x1F23B, Dt::Square, "\u{914D}"), (0x1F240, Dt::Compat, "\u{3014}\u{672C}\u{3015}"),
+        (0x1F241, Dt::Compat, "\u{3014}\u{4E09}\u{3015}"),
+        (0x1F242, Dt::Compat, "\u{3014}\u{4E8C}\u{3015}"),
+        (0x1F243, Dt::Compat, "\u{3014}\u{5B89}\u{3015}"),
+        (0x1F244, Dt::Compat, "\u{3014}\u{70B9}\u{3015}"),
+        (0x1F245, Dt::Compat, "\u{3014}\u{6253}\u{3015}"),
+        (0x1F246, Dt::Compat, "\u{3014}\u{76D7}\u{3015}"),
+        (0x1F247, Dt::Compat, "\u{3014}\u{52DD}\u{3015}"),
+        (0x1F248, Dt::Compat, "\u{3014}\u{6557}\u{3015}"), (0x1F250, Dt::Circle, "\u{5F97}"),
+        (0x1F251, Dt::Circle, "\u{53EF}"), (0x1FBF0, Dt::Font, "0"), (0x1FBF1, Dt::Font, "1"),
+        (0x1FBF2, Dt::Font, "2"), (0x1FBF3, Dt::Font, "3"), (0x1FBF4, Dt::Font, "4"),
+        (0x1FBF5, Dt::Font, "5"), (0x1FBF6, Dt::Font, "6"), (0x1FBF7, Dt::Font, "7"),
+        (0x1FBF8, Dt::Font, "8"), (0x1FBF9, Dt::Font, "9"), (0x2F800, Dt::Canonical, "\u{4E3D}"),
+        (0x2F801, Dt::Canonical, "\u{4E38}"), (0x2F802, Dt::Canonical, "\u{4E41}"),
+        (0x2F803, Dt::Canonical, "\u{20122}"), (0x2F804, Dt::Canonical, "\u{4F60}"),
+        (0x2F805, Dt::Canonical, "\u{4FAE}"), (0x2F806, Dt::Canonical, "\u{4FBB}"),
+        (0x2F807, Dt::Canonical, "\u{5002}"), (0x2F808, Dt::Canonical, "\u{507A}"),
+        (0x2F809, Dt::Canonical, "\u{5099}"), (0x2F80A, Dt::Canonical, "\u{50E7}"),
+        (0x2F80B, Dt::Canonical, "\u{50CF}"), (0x2F80C, Dt::Canonical, "\u{349E}"),
+        (0x2F80D, Dt::Canonical, "\u{2063A}"), (0x2F80E, Dt::Canonical, "\u{514D}"),
+        (0x2F80F, Dt::Canonical, "\u{5154}"), (0x2F810, Dt::Canonical, "\u{5164}"),
+        (0x2F811, Dt::Canonical, "\u{5177}"), (0x2F812, Dt::Canonical, "\u{2051C}"),
+        (0x2F813, Dt::Canonical, "\u{34B9}"), (0x2F814, Dt::Canonical, "\u{5167}"),
+        (0x2F815, Dt::Canonical, "\u{518D}"), (0x2F816, Dt::Canonical, "\u{2054B}"),
+        (0x2F817, Dt::Canonical, "\u{5197}"), (0x2F818, Dt::Canonical, "\u{51A4}"),
+        (0x2F819, Dt::Canonical, "\u{4ECC}"), (0x2F81A, Dt::Canonical, "\u{51AC}"),
+        (0x2F81B, Dt::Canonical, "\u{51B5}"), (0x2F81C, Dt::Canonical, "\u{291DF}"),
+        (0x2F81D, Dt::Canonical, "\u{51F5}"), (0x2F81E, Dt::Canonical, "\u{5203}"),
+        (0x2F81F, Dt::Canonical, "\u{34DF}"), (0x2F820, Dt::Canonical, "\u{523B}"),
+        (0x2F821, Dt::Canonical, "\u{5246}"), (0x2F822, Dt::Canonical, "\u{5272}"),
+        (0x2F823, Dt::Canonical, "\u{5277}"), (0x2F824, Dt::Canonical, "\u{3515}"),
+        (0x2F825, Dt::Canonical, "\u{52C7}"), (0x2F826, Dt::Canonical, "\u{52C9}"),
+        (0x2F827, Dt::Canonical, "\u{52E4}"), (0x2F828, Dt::Canonical, "\u{52FA}"),
+        (0x2F829, Dt::Canonical, "\u{5305}"), (0x2F82A, Dt::Canonical, "\u{5306}"),
+        (0x2F82B, Dt::Canonical, "\u{5317}"), (0x2F82C, Dt::Canonical, "\u{5349}"),
+        (0x2F82D, Dt::Canonical, "\u{5351}"), (0x2F82E, Dt::Canonical, "\u{535A}"),
+        (0x2F82F, Dt::Canonical, "\u{5373}"), (0x2F830, Dt::Canonical, "\u{537D}"),
+        (0x2F831, Dt::Canonical, "\u{537F}"), (0x2F832, Dt::Canonical, "\u{537F}"),
+        (0x2F833, Dt::Canonical, "\u{537F}"), (0x2F834, Dt::Canonical, "\u{20A2C}"),
+        (0x2F835, Dt::Canonical, "\u{7070}"), (0x2F836, Dt::Canonical, "\u{53CA}"),
+        (0x2F837, Dt::Canonical, "\u{53DF}"), (0x2F838, Dt::Canonical, "\u{20B63}"),
+        (0x2F839, Dt::Canonical, "\u{53EB}"), (0x2F83A, Dt::Canonical, "\u{53F1}"),
+        (0x2F83B, Dt::Canonical, "\u{5406}"), (0x2F83C, Dt::Canonical, "\u{549E}"),
+        (0x2F83D, Dt::Canonical, "\u{5438}"), (0x2F83E, Dt::Canonical, "\u{5448}"),
+        (0x2F83F, Dt::Canonical, "\u{5468}"), (0x2F840, Dt::Canonical, "\u{54A2}"),
+        (0x2F841, Dt::Canonical, "\u{54F6}"), (0x2F842, Dt::Canonical, "\u{5510}"),
+        (0x2F843, Dt::Canonical, "\u{5553}"), (0x2F844, Dt::Canonical, "\u{5563}"),
+        (0x2F845, Dt::Canonical, "\u{5584}"), (0x2F846, Dt::Canonical, "\u{5584}"),
+        (0x2F847, Dt::Canonical, "\u{5599}"), (0x2F848, Dt::Canonical, "\u{55AB}"),
+        (0x2F849, Dt::Canonical, "\u{55B3}"), (0x2F84A, Dt::Canonical, "\u{55C2}"),
+        (0x2F84B, Dt::Canonical, "\u{5716}"), (0x2F84C, Dt::Canonical, "\u{5606}"),
+        (0x2F84D, Dt::Canonical, "\u{5717}"), (0x2F84E, Dt::Canonical, "\u{5651}"),
+        (0x2F84F, Dt::Canonical, "\u{5674}"), (0x2F850, Dt::Canonical, "\u{5207}"),
+        (0x2F851, Dt::Canonical, "\u{58EE}"), (0x2F852, Dt::Canonical, "\u{57CE}"),
+        (0x2F853, Dt::Canonical, "\u{57F4}"), (0x2F854, Dt::Canonical, "\u{580D}"),
+        (0x2F855, Dt::Canonical, "\u{578B}"), (0x2F856, Dt::Canonical, "\u{5832}"),
+        (0x2F857, Dt::Canonical, "\u{5831}"), (0x2F858, Dt::Canonical, "\u{58AC}"),
+        (0x2F859, Dt::Canonical, "\u{214E4}"), (0x2F85A, Dt::Canonical, "\u{58F2}"),
+        (0x2F85B, Dt::Canonical, "\u{58F7}"), (0x2F85C, Dt::Canonical, "\u{5906}"),
+        (0x2F85D, Dt::Canonical, "\u{591A}"), (0x2F85E, Dt::Canonical, "\u{5922}"),
+        (0x2F85F, Dt::Canonical, "\u{5962}"), (0x2F860, Dt::Canonical, "\u{216A8}"),
+        (0x2F861, Dt::Canonical, "\u{216EA}"), (0x2F862, Dt::Canonical, "\u{59EC}"),
+        (0x2F863, Dt::Canonical, "\u{5A1B}"), (0x2F864, Dt::Canonical, "\u{5A27}"),
+        (0x2F865, Dt::Canonical, "\u{59D8}"), (0x2F866, Dt::Canonical, "\u{5A66}"),
+        (0x2F867, Dt::Canonical, "\u{36EE}"), (0x2F868, Dt::Canonical, "\u{36FC}"),
+        (0x2F869, Dt::Canonical, "\u{5B08}"), (0x2F86A, Dt::Canonical, "\u{5B3E}"),
+        (0x2F86B, Dt::Canonical, "\u{5B3E}"), (0x2F86C, Dt::Canonical, "\u{219C8}"),
+        (0x2F86D, Dt::Canonical, "\u{5BC3}"), (0x2F86E, Dt::Canonical, "\u{5BD8}"),
+        (0x2F86F, Dt::Canonical, "\u{5BE7}"), (0x2F870, Dt::Canonical, "\u{5BF3}"),
+        (0x2F871, Dt::Canonical, "\u{21B18}"), (0x2F872, Dt::Canonical, "\u{5BFF}"),
+        (0x2F873, Dt::Canonical, "\u{5C06}"), (0x2F874, Dt::Canonical, "\u{5F53}"),
+        (0x2F875, Dt::Canonical, "\u{5C22}"), (0x2F876, Dt::Canonical, "\u{3781}"),
+        (0x2F877, Dt::Canonical, "\u{5C60}"), (0x2F878, Dt::Canonical, "\u{5C6E}"),
+        (0x2F879, Dt::Canonical, "\u{5CC0}"), (0x2F87A, Dt::Canonical, "\u{5C8D}"),
+        (0x2F87B, Dt::Canonical, "\u{21DE4}"), (0x2F87C, Dt::Canonical, "\u{5D43}"),
+        (0x2F87D, Dt::Canonical, "\u{21DE6}"), (0x2F87E, Dt::Canonical, "\u{5D6E}"),
+        (0x2F87F, Dt::Canonical, "\u{5D6B}"), (0x2F880, Dt::Canonical, "\u{5D7C}"),
+        (0x2F881, Dt::Canonical, "\u{5DE1}"), (0x2F882, Dt::Canonical, "\u{5DE2}"),
+        (0x2F883, Dt::Canonical, "\u{382F}"), (0x2F884, Dt::Canonical, "\u{5DFD}"),
+        (0x2F885, Dt::Canonical, "\u{5E28}"), (0x2F886, Dt::Canonical, "\u{5E3D}"),
+        (0x2F887, Dt::Canonical, "\u{5E69}"), (0x2F888, Dt::Canonical, "\u{3862}"),
+        (0x2F889, Dt::Canonical, "\u{22183}"), (0x2F88A, Dt::Canonical, "\u{387C}"),
+        (0x2F88B, Dt::Canonical, "\u{5EB0}"), (0x2F88C, Dt::Canonical, "\u{5EB3}"),
+        (0x2F88D, Dt::Canonical, "\u{5EB6}"), (0x2F88E, Dt::Canonical, "\u{5ECA}"),
+        (0x2F88F, Dt::Canonical, "\u{2A392}"), (0x2F890, Dt::Canonical, "\u{5EFE}"),
+        (0x2F891, Dt::Canonical, "\u{22331}"), (0x2F892, Dt::Canonical, "\u{22331}"),
+        (0x2F893, Dt::Canonical, "\u{8201}"), (0x2F894, Dt::Canonical, "\u{5F22}"),
+        (0x2F895, Dt::Canonical, "\u{5F22}"), (0x2F896, Dt::Canonical, "\u{38C7}"),
+        (0x2F897, Dt::Canonical, "\u{232B8}"), (0x2F898, Dt::Canonical, "\u{261DA}"),
+        (0x2F899, Dt::Canonical, "\u{5F62}"), (0x2F89A, Dt::Canonical, "\u{5F6B}"),
+        (0x2F89B, Dt::Canonical, "\u{38E3}"), (0x2F89C, Dt::Canonical, "\u{5F9A}"),
+        (0x2F89D, Dt::Canonical, "\u{5FCD}"), (0x2F89E, Dt::Canonical, "\u{5FD7}"),
+        (0x2F89F, Dt::Canonical, "\u{5FF9}"), (0x2F8A0, Dt::Canonical, "\u{6081}"),
+        (0x2F8A1, Dt::Canonical, "\u{393A}"), (0x2F8A2, Dt::Canonical, "\u{391C}"),
+        (0x2F8A3, Dt::Canonical, "\u{6094}"), (0x2F8A4, Dt::Canonical, "\u{226D4}"),
+        (0x2F8A5, Dt::Canonical, "\u{60C7}"), (0x2F8A6, Dt::Canonical, "\u{6148}"),
+        (0x2F8A7, Dt::Canonical, "\u{614C}"), (0x2F8A8, Dt::Canonical, "\u{614E}"),
+        (0x2F8A9, Dt::Canonical, "\u{614C}"), (0x2F8AA, Dt::Canonical, "\u{617A}"),
+        (0x2F8AB, Dt::Canonical, "\u{618E}"), (0x2F8AC, Dt::Canonical, "\u{61B2}"),
+        (0x2F8AD, Dt::Canonical, "\u{61A4}"), (0x2F8AE, Dt::Canonical, "\u{61AF}"),
+        (0x2F8AF, Dt::Canonical, "\u{61DE}"), (0x2F8B0, Dt::Canonical, "\u{61F2}"),
+        (0x2F8B1, Dt::Canonical, "\u{61F6}"), (0x2F8B2, Dt::Canonical, "\u{6210}"),
+        (0x2F8B3, Dt::Canonical, "\u{621B}"), (0x2F8B4, Dt::Canonical, "\u{625D}"),
+        (0x2F8B5, Dt::Canonical, "\u{62B1}"), (0x2F8B6, Dt::Canonical, "\u{62D4}"),
+        (0x2F8B7, Dt::Canonical, "\u{6350}"), (0x2F8B8, Dt::Canonical, "\u{22B0C}"),
+        (0x2F8B9, Dt::Canonical, "\u{633D}"), (0x2F8BA, Dt::Canonical, "\u{62FC}"),
+        (0x2F8BB, Dt::Canonical, "\u{6368}"), (0x2F8BC, Dt::Canonical, "\u{6383}"),
+        (0x2F8BD, Dt::Canonical, "\u{63E4}"), (0x2F8BE, Dt::Canonical, "\u{22BF1}"),
+        (0x2F8BF, Dt::Canonical, "\u{6422}"), (0x2F8C0, Dt::Canonical, "\u{63C5}"),
+        (0x2F8C1, Dt::Canonical, "\u{63A9}"), (0x2F8C2, Dt::Canonical, "\u{3A2E}"),
+        (0x2F8C3, Dt::Canonical, "\u{6469}"), (0x2F8C4, Dt::Canonical, "\u{647E}"),
+        (0x2F8C5, Dt::Canonical, "\u{649D}"), (0x2F8C6, Dt::Canonical, "\u{6477}"),
+        (0x2F8C7, Dt::Canonical, "\u{3A6C}"), (0x2F8C8, Dt::Canonical, "\u{654F}"),
+        (0x2F8C9, Dt::Canonical, "\u{656C}"), (0x2F8CA, Dt::Canonical, "\u{2300A}"),
+        (0x2F8CB, Dt::Canonical, "\u{65E3}"), (0x2F8CC, Dt::Canonical, "\u{66F8}"),
+        (0x2F8CD, Dt::Canonical, "\u{6649}"), (0x2F8CE, Dt::Canonical, "\u{3B19}"),
+        (0x2F8CF, Dt::Canonical, "\u{6691}"), (0x2F8D0, Dt::Canonical, "\u{3B08}"),
+        (0x2F8D1, Dt::Canonical, "\u{3AE4}"), (0x2F8D2, Dt::Canonical, "\u{5192}"),
+        (0x2F8D3, Dt::Canonical, "\u{5195}"), (0x2F8D4, Dt::Canonical, "\u{6700}"),
+        (0x2F8D5, Dt::Canonical, "\u{669C}"), (0x2F8D6, Dt::Canonical, "\u{80AD}"),
+        (0x2F8D7, Dt::Canonical, "\u{43D9}"), (0x2F8D8, Dt::Canonical, "\u{6717}"),
+        (0x2F8D9, Dt::Canonical, "\u{671B}"), (0x2F8DA, Dt::Canonical, "\u{6721}"),
+        (0x2F8DB, Dt::Canonical, "\u{675E}"), (0x2F8DC, Dt::Canonical, "\u{6753}"),
+        (0x2F8DD, Dt::Canonical, "\u{233C3}"), (0x2F8DE, Dt::Canonical, "\u{3B49}"),
+        (0x2F8DF, Dt::Canonical, "\u{67FA}"), (0x2F8E0, Dt::Canonical, "\u{6785}"),
+        (0x2F8E1, Dt::Canonical, "\u{6852}"), (0x2F8E2, Dt::Canonical, "\u{6885}"),
+        (0x2F8E3, Dt::Canonical, "\u{2346D}"), (0x2F8E4, Dt::Canonical, "\u{688E}"),
+        (0x2F8E5, Dt::Canonical, "\u{681F}"), (0x2F8E6, Dt::Canonical, "\u{6914}"),
+        (0x2F8E7, Dt::Canonical, "\u{3B9D}"), (0x2F8E8, Dt::Canonical, "\u{6942}"),
+        (0x2F8E9, Dt::Canonical, "\u{69A3}"), (0x2F8EA, Dt::Canonical, "\u{69EA}"),
+        (0x2F8EB, Dt::Canonical, "\u{6AA8}"), (0x2F8EC, Dt::Canonical, "\u{236A3}"),
+        (0x2F8ED, Dt::Canonical, "\u{6ADB}"), (0x2F8EE, Dt::Canonical, "\u{3C18}"),
+        (0x2F8EF, Dt::Canonical, "\u{6B21}"), (0x2F8F0, Dt::Canonical, "\u{238A7}"),
+        (0x2F8F1, Dt::Canonical, "\u{6B54}"), (0x2F8F2, Dt::Canonical, "\u{3C4E}"),
+        (0x2F8F3, Dt::Canonical, "\u{6B72}"), (0x2F8F4, Dt::Canonical, "\u{6B9F}"),
+        (0x2F8F5, Dt::Canonical, "\u{6BBA}"), (0x2F8F6, Dt::Canonical, "\u{6BBB}"),
+        (0x2F8F7, Dt::Canonical, "\u{23A8D}"), (0x2F8F8, Dt::Canonical, "\u{21D0B}"),
+        (0x2F8F9, Dt::Canonical, "\u{23AFA}"), (0x2F8FA, Dt::Canonical, "\u{6C4E}"),
+        (0x2F8FB, Dt::Canonical, "\u{23CBC}"), (0x2F8FC, Dt::Canonical, "\u{6CBF}"),
+        (0x2F8FD, Dt::Canonical, "\u{6CCD}"), (0x2F8FE, Dt::Canonical, "\u{6C67}"),
+        (0x2F8FF, Dt::Canonical, "\u{6D16}"), (0x2F900, Dt::Canonical, "\u{6D3E}"),
+        (0x2F901, Dt::Canonical, "\u{6D77}"), (0x2F902, Dt::Canonical, "\u{6D41}"),
+        (0x2F903, Dt::Canonical, "\u{6D69}"), (0x2F904, Dt::Canonical, "\u{6D78}"),
+        (0x2F905, Dt::Canonical, "\u{6D85}"), (0x2F906, Dt::Canonical, "\u{23D1E}"),
+        (0x2F907, Dt::Canonical, "\u{6D34}"), (0x2F908, Dt::Canonical, "\u{6E2F}"),
+        (0x2F909, Dt::Canonical, "\u{6E6E}"), (0x2F90A, Dt::Canonical, "\u{3D33}"),
+        (0x2F90B, Dt::Canonical, "\u{6ECB}"), (0x2F90C, Dt::Canonical, "\u{6EC7}"),
+        (0x2F90D, Dt::Canonical, "\u{23ED1}"), (0x2F90E, Dt::Canonical, "\u{6DF9}"),
+        (0x2F90F, Dt::Canonical, "\u{6F6E}"), (0x2F910, Dt::Canonical, "\u{23F5E}"),
+        (0x2F911, Dt::Canonical, "\u{23F8E}"), (0x2F912, Dt::Canonical, "\u{6FC6}"),
+        (0x2F913, Dt::Canonical, "\u{7039}"), (0x2F914, Dt::Canonical, "\u{701E}"),
+        (0x2F915, Dt::Canonical, "\u{701B}"), (0x2F916, Dt::Canonical, "\u{3D96}"),
+        (0x2F917, Dt::Canonical, "\u{704A}"), (0x2F918, Dt::Canonical, "\u{707D}"),
+        (0x2F919, Dt::Canonical, "\u{7077}"), (0x2F91A, Dt::Canonical, "\u{70AD}"),
+        (0x2F91B, Dt::Canonical, "\u{20525}"), (0x2F91C, Dt::Canonical, "\u{7145}"),
+        (0x2F91D, Dt::Canonical, "\u{24263}"), (0x2F91E, Dt::Canonical, "\u{719C}"),
+        (0x2F91F, Dt::Canonical, "\u{243AB}"), (0x2F920, Dt::Canonical, "\u{7228}"),
+        (0x2F921, Dt::Canonical, "\u{7235}"), (0x2F922, Dt::Canonical, "\u{7250}"),
+        (0x2F923, Dt::Canonical, "\u{24608}"), (0x2F924, Dt::Canonical, "\u{7280}"),
+        (0x2F925, Dt::Canonical, "\u{7295}"), (0x2F926, Dt::Canonical, "\u{24735}"),
+        (0x2F927, Dt::Canonical, "\u{24814}"), (0x2F928, Dt::Canonical, "\u{737A}"),
+        (0x2F929, Dt::Canonical, "\u{738B}"), (0x2F92A, Dt::Canonical, "\u{3EAC}"),
+        (0x2F92B, Dt::Canonical, "\u{73A5}"), (0x2F92C, Dt::Canonical, "\u{3EB8}"),
+        (0x2F92D, Dt::Canonical, "\u{3EB8}"), (0x2F92E, Dt::Canonical, "\u{7447}"),
+        (0x2F92F, Dt::Canonical, "\u{745C}"), (0x2F930, Dt::Canonical, "\u{7471}"),
+        (0x2F931, Dt::Canonical, "\u{7485}"), (0x2F932, Dt::Canonical, "\u{74CA}"),
+        (0x2F933, Dt::Canonical, "\u{3F1B}"), (0x2F934, Dt::Canonical, "\u{7524}"),
+        (0x2F935, Dt::Canonical, "\u{24C36}"), (0x2F936, Dt::Canonical, "\u{753E}"),
+        (0x2F937, Dt::Canonical, "\u{24C92}"), (0x2F938, Dt::Canonical, "\u{7570}"),
+        (0x2F939, Dt::Canonical, "\u{2219F}"), (0x2F93A, Dt::Canonical, "\u{7610}"),
+        (0x2F93B, Dt::Canonical, "\u{24FA1}"), (0x2F93C, Dt::Canonical, "\u{24FB8}"),
+        (0x2F93D, Dt::Canonical, "\u{25044}"), (0x2F93E, Dt::Canonical, "\u{3FFC}"),
+        (0x2F93F, Dt::Canonical, "\u{4008}"), (0x2F940, Dt::Canonical, "\u{76F4}"),
+        (0x2F941, Dt::Canonical, "\u{250F3}"), (0x2F942, Dt::Canonical, "\u{250F2}"),
+        (0x2F943, Dt::Canonical, "\u{25119}"), (0x2F944, Dt::Canonical, "\u{25133}"),
+        (0x2F945, Dt::Canonical, "\u{771E}"), (0x2F946, Dt::Canonical, "\u{771F}"),
+        (0x2F947, Dt::Canonical, "\u{771F}"), (0x2F948, Dt::Canonical, "\u{774A}"),
+        (0x2F949, Dt::Canonical, "\u{4039}"), (0x2F94A, Dt::Canonical, "\u{778B}"),
+        (0x2F94B, Dt::Canonical, "\u{4046}"), (0x2F94C, Dt::Canonical, "\u{4096}"),
+        (0x2F94D, Dt::Canonical, "\u{2541D}"), (0x2F94E, Dt::Canonical, "\u{784E}"),
+        (0x2F94F, Dt::Canonical, "\u{788C}"), (0x2F950, Dt::Canonical, "\u{78CC}"),
+        (0x2F951, Dt::Canonical, "\u{40E3}"), (0x2F952, Dt::Canonical, "\u{25626}"),
+        (0x2F953, Dt::Canonical, "\u{7956}"), (0x2F954, Dt::Canonical, "\u{2569A}"),
+        (0x2F955, Dt::Canonical, "\u{256C5}"), (0x2F956, Dt::Canonical, "\u{798F}"),
+        (0x2F957, Dt::Canonical, "\u{79EB}"), (0x2F958, Dt::Canonical, "\u{412F}"),
+        (0x2F959, Dt::Canonical, "\u{7A40}"), (0x2F95A, Dt::Canonical, "\u{7A4A}"),
+        (0x2F95B, Dt::Canonical, "\u{7A4F}"), (0x2F95C, Dt::Canonical, "\u{2597C}"),
+        (0x2F95D, Dt::Canonical, "\u{25AA7}"), (0x2F95E, Dt::Canonical, "\u{25AA7}"),
+        (0x2F95F, Dt::Canonical, "\u{7AEE}"), (0x2F960, Dt::Canonical, "\u{4202}"),
+        (0x2F961, Dt::Canonical, "\u{25BAB}"), (0x2F962, Dt::Canonical, "\u{7BC6}"),
+        (0x2F963, Dt::Canonical, "\u{7BC9}"), (0x2F964, Dt::Canonical, "\u{4227}"),
+        (0x2F965, Dt::Canonical, "\u{25C80}"), (0x2F966, Dt::Canonical, "\u{7CD2}"),
+        (0x2F967, Dt::Canonical, "\u{42A0}"), (0x2F968, Dt::Canonical, "\u{7CE8}"),
+        (0x2F969, Dt::Canonical, "\u{7CE3}"), (0x2F96A, Dt::Canonical, "\u{7D00}"),
+        (0x2F96B, Dt::Canonical, "\u{25F86}"), (0x2F96C, Dt::Canonical, "\u{7D63}"),
+        (0x2F96D, Dt::Canonical, "\u{4301}"), (0x2F96E, Dt::Canonical, "\u{7DC7}"),
+        (0x2F96F, Dt::Canonical, "\u{7E02}"), (0x2F970, Dt::Canonical, "\u{7E45}"),
+        (0x2F971, Dt::Canonical, "\u{4334}"), (0x2F972, Dt::Canonical, "\u{26228}"),
+        (0x2F973, Dt::Canonical, "\u{26247}"), (0x2F974, Dt::Canonical, "\u{4359}"),
+        (0x2F975, Dt::Canonical, "\u{262D9}"), (0x2F976, Dt::Canonical, "\u{7F7A}"),
+        (0x2F977, Dt::Canonical, "\u{2633E}"), (0x2F978, Dt::Canonical, "\u{7F95}"),
+        (0x2F979, Dt::Canonical, "\u{7FFA}"), (0x2F97A, Dt::Canonical, "\u{8005}"),
+        (0x2F97B, Dt::Canonical, "\u{264DA}"), (0x2F97C, Dt::Canonical, "\u{26523}"),
+        (0x2F97D, Dt::Canonical, "\u{8060}"), (0x2F97E, Dt::Canonical, "\u{265A8}"),
+        (0x2F97F, Dt::Canonical, "\u{8070}"), (0x2F980, Dt::Canonical, "\u{2335F}"),
+        (0x2F981, Dt::Canonical, "\u{43D5}"), (0x2F982, Dt::Canonical, "\u{80B2}"),
+        (0x2F983, Dt::Canonical, "\u{8103}"), (0x2F984, Dt::Canonical, "\u{440B}"),
+        (0x2F985, Dt::Canonical, "\u{813E}"), (0x2F986, Dt::Canonical, "\u{5AB5}"),
+        (0x2F987, Dt::Canonical, "\u{267A7}"), (0x2F988, Dt::Canonical, "\u{267B5}"),
+        (0x2F989, Dt::Canonical, "\u{23393}"), (0x2F98A, Dt::Canonical, "\u{2339C}"),
+        (0x2F98B, Dt::Canonical, "\u{8201}"), (0x2F98C, Dt::Canonical, "\u{8204}"),
+        (0x2F98D, Dt::Canonical, "\u{8F9E}"), (0x2F98E, Dt::Canonical, "\u{446B}"),
+        (0x2F98F, Dt::Canonical, "\u{8291}"), (0x2F990, Dt::Canonical, "\u{828B}"),
+        (0x2F991, Dt::Canonical, "\u{829D}"), (0x2F992, Dt::Canonical, "\u{52B3}"),
+        (0x2F993, Dt::Canonical, "\u{82B1}"), (0x2F994, Dt::Canonical, "\u{82B3}"),
+        (0x2F995, Dt::Canonical, "\u{82BD}"), (0x2F996, Dt::Canonical, "\u{82E6}"),
+        (0x2F997, Dt::Canonical, "\u{26B3C}"), (0x2F998, Dt::Canonical, "\u{82E5}"),
+        (0x2F999, Dt::Canonical, "\u{831D}"), (0x2F99A, Dt::Canonical, "\u{8363}"),
+        (0x2F99B, Dt::Canonical, "\u{83AD}"), (0x2F99C, Dt::Canonical, "\u{8323}"),
+        (0x2F99D, Dt::Canonical, "\u{83BD}"), (0x2F99E, Dt::Canonical, "\u{83E7}"),
+        (0x2F99F, Dt::Canonical, "\u{8457}"), (0x2F9A0, Dt::Canonical, "\u{8353}"),
+        (0x2F9A1, Dt::Canonical, "\u{83CA}"), (0x2F9A2, Dt::Canonical, "\u{83CC}"),
+        (0x2F9A3, Dt::Canonical, "\u{83DC}"), (0x2F9A4, Dt::Canonical, "\u{26C36}"),
+        (0x2F9A5, Dt::Canonical, "\u{26D6B}"), (0x2F9A6, Dt::Canonical, "\u{26CD5}"),
+        (0x2F9A7, Dt::Canonical, "\u{452B}"), (0x2F9A8, Dt::Canonical, "\u{84F1}"),
+        (0x2F9A9, Dt::Canonical, "\u{84F3}"), (0x2F9AA, Dt::Canonical, "\u{8516}"),
+        (0x2F9AB, Dt::Canonical, "\u{273CA}"), (0x2F9AC, Dt::Canonical, "\u{8564}"),
+        (0x2F9AD, Dt::Canonical, "\u{26F2C}"), (0x2F9AE, Dt::Canonical, "\u{455D}"),
+        (0x2F9AF, Dt::Canonical, "\u{4561}"), (0x2F9B0, Dt::Canonical, "\u{26FB1}"),
+        (0x2F9B1, Dt::Canonical, "\u{270D2}"), (0x2F9B2, Dt::Canonical, "\u{456B}"),
+        (0x2F9B3, Dt::Canonical, "\u{8650}"), (0x2F9B4, Dt::Canonical, "\u{865C}"),
+        (0x2F9B5, Dt::Canonical, "\u{8667}"), (0x2F9B6, Dt::Canonical, "\u{8669}"),
+        (0x2F9B7, Dt::Canonical, "\u{86A9}"), (0x2F9B8, Dt::Canonical, "\u{8688}"),
+        (0x2F9B9, Dt::Canonical, "\u{870E}"), (0x2F9BA, Dt::Canonical, "\u{86E2}"),
+        (0x2F9BB, Dt::Canonical, "\u{8779}"), (0x2F9BC, Dt::Canonical, "\u{8728}"),
+        (0x2F9BD, Dt::Canonical, "\u{876B}"), (0x2F9BE, Dt::Canonical, "\u{8786}"),
+        (0x2F9BF, Dt::Canonical, "\u{45D7}"), (0x2F9C0, Dt::Canonical, "\u{87E1}"),
+        (0x2F9C1, Dt::Canonical, "\u{8801}"), (0x2F9C2, Dt::Canonical, "\u{45F9}"),
+        (0x2F9C3, Dt::Canonical, "\u{8860}"), (0x2F9C4, Dt::Canonical, "\u{8863}"),
+        (0x2F9C5, Dt::Canonical, "\u{27667}"), (0x2F9C6, Dt::Canonical, "\u{88D7}"),
+        (0x2F9C7, Dt::Canonical, "\u{88DE}"), (0x2F9C8, Dt::Canonical, "\u{4635}"),
+        (0x2F9C9, Dt::Canonical, "\u{88FA}"), (0x2F9CA, Dt::Canonical, "\u{34BB}"),
+        (0x2F9CB, Dt::Canonical, "\u{278AE}"), (0x2F9CC, Dt::Canonical, "\u{27966}"),
+        (0x2F9CD, Dt::Canonical, "\u{46BE}"), (0x2F9CE, Dt::Canonical, "\u{46C7}"),
+        (0x2F9CF, Dt::Canonical, "\u{8AA0}"), (0x2F9D0, Dt::Canonical, "\u{8AED}"),
+        (0x2F9D1, Dt::Canonical, "\u{8B8A}"), (0x2F9D2, Dt::Canonical, "\u{8C55}"),
+        (0x2F9D3, Dt::Canonical, "\u{27CA8}"), (0x2F9D4, Dt::Canonical, "\u{8CAB}"),
+        (0x2F9D5, Dt::Canonical, "\u{8CC1}"), (0x2F9D6, Dt::Canonical, "\u{8D1B}"),
+        (0x2F9D7, Dt::Canonical, "\u{8D77}"), (0x2F9D8, Dt::Canonical, "\u{27F2F}"),
+        (0x2F9D9, Dt::Canonical, "\u{20804}"), (0x2F9DA, Dt::Canonical, "\u{8DCB}"),
+        (0x2F9DB, Dt::Canonical, "\u{8DBC}"), (0x2F9DC, Dt::Canonical, "\u{8DF0}"),
+        (0x2F9DD, Dt::Canonical, "\u{208DE}"), (0x2F9DE, Dt::Canonical, "\u{8ED4}"),
+        (0x2F9DF, Dt::Canonical, "\u{8F38}"), (0x2F9E0, Dt::Canonical, "\u{285D2}"),
+        (0x2F9E1, Dt::Canonical, "\u{285ED}"), (0x2F9E2, Dt::Canonical, "\u{9094}"),
+        (0x2F9E3, Dt::Canonical, "\u{90F1}"), (0x2F9E4, Dt::Canonical, "\u{9111}"),
+        (0x2F9E5, Dt::Canonical, "\u{2872E}"), (0x2F9E6, Dt::Canonical, "\u{911B}"),
+        (0x2F9E7, Dt::Canonical, "\u{9238}"), (0x2F9E8, Dt::Canonical, "\u{92D7}"),
+        (0x2F9E9, Dt::Canonical, "\u{92D8}"), (0x2F9EA, Dt::Canonical, "\u{927C}"),
+        (0x2F9EB, Dt::Canonical, "\u{93F9}"), (0x2F9EC, Dt::Canonical, "\u{9415}"),
+        (0x2F9ED, Dt::Canonical, "\u{28BFA}"), (0x2F9EE, Dt::Canonical, "\u{958B}"),
+        (0x2F9EF, Dt::Canonical, "\u{4995}"), (0x2F9F0, Dt::Canonical, "\u{95B7}"),
+        (0x2F9F1, Dt::Canonical, "\u{28D77}"), (0x2F9F2, Dt::Canonical, "\u{49E6}"),
+        (0x2F9F3, Dt::Canonical, "\u{96C3}"), (0x2F9F4, Dt::Canonical, "\u{5DB2}"),
+        (0x2F9F5, Dt::Canonical, "\u{9723}"), (0x2F9F6, Dt::Canonical, "\u{29145}"),
+        (0x2F9F7, Dt::Canonical, "\u{2921A}"), (0x2F9F8, Dt::Canonical, "\u{4A6E}"),
+        (0x2F9F9, Dt::Canonical, "\u{4A76}"), (0x2F9FA, Dt::Canonical, "\u{97E0}"),
+        (0x2F9FB, Dt::Canonical, "\u{2940A}"), (0x2F9FC, Dt::Canonical, "\u{4AB2}"),
+        (0x2F9FD, Dt::Canonical, "\u{29496}"), (0x2F9FE, Dt::Canonical, "\u{980B}"),
+        (0x2F9FF, Dt::Canonical, "\u{980B}"), (0x2FA00, Dt::Canonical, "\u{9829}"),
+        (0x2FA01, Dt::Canonical, "\u{295B6}"), (0x2FA02, Dt::Canonical, "\u{98E2}"),
+        (0x2FA03, Dt::Canonical, "\u{4B33}"), (0x2FA04, Dt::Canonical, "\u{9929}"),
+        (0x2FA05, Dt::Canonical, "\u{99A7}"), (0x2FA06, Dt::Canonical, "\u{99C2}"),
+        (0x2FA07, Dt::Canonical, "\u{99FE}"), (0x2FA08, Dt::Canonical, "\u{4BCE}"),
+        (0x2FA09, Dt::Canonical, "\u{29B30}"), (0x2FA0A, Dt::Canonical, "\u{9B12}"),
+        (0x2FA0B, Dt::Canonical, "\u{9C40}"), (0x2FA0C, Dt::Canonical, "\u{9CFD}"),
+        (0x2FA0D, Dt::Canonical, "\u{4CCE}"), (0x2FA0E, Dt::Canonical, "\u{4CED}"),
+        (0x2FA0F, Dt::Canonical, "\u{9D67}"), (0x2FA10, Dt::Canonical, "\u{2A0CE}"),
+        (0x2FA11, Dt::Canonical, "\u{4CF8}"), (0x2FA12, Dt::Canonical, "\u{2A105}"),
+        (0x2FA13, Dt::Canonical, "\u{2A20E}"), (0x2FA14, Dt::Canonical, "\u{2A291}"),
+        (0x2FA15, Dt::Canonical, "\u{9EBB}"), (0x2FA16, Dt::Canonical, "\u{4D56}"),
+        (0x2FA17, Dt::Canonical, "\u{9EF9}"), (0x2FA18, Dt::Canonical, "\u{9EFE}"),
+        (0x2FA19, Dt::Canonical, "\u{9F05}"), (0x2FA1A, Dt::Canonical, "\u{9F0F}"),
+        (0x2FA1B, Dt::Canonical, "\u{9F16}"), (0x2FA1C, Dt::Canonical, "\u{9F3B}"),
+        (0x2FA1D, Dt::Canonical, "\u{2A600}"),
+    ]
+};
+
+// Prototypes of characters that can be confused with others, from `confusables.txt` in UTS #39 for
+// Unicode 16.0.0, in order. This is the version that `unicode-security` 0.1.2 uses, so that
+// identifier statuses and skeletons agree. Regenerate it with `scripts/confusables.py`.
+const CONFUSABLES: &[(u32, &str)] = &[
+    (0x22, "''"), (0x25, "\u{BA}/\u{2080}"), (0x30, "O"), (0x31, "l"), (0x49, "l"), (0x60, "'"),
+    (0x6D, "rn"), (0x7C, "l"), (0xA0, " "), (0xA2, "c\u{338}"), (0xA5, "Y\u{335}"),
+    (0xAF, "\u{2C9}"), (0xB4, "'"), (0xB5, "\u{3BC}"), (0xB8, ","), (0xC6, "AE"),
+    (0xC7, "C\u{326}"), (0xD0, "D\u{335}"), (0xD7, "x"), (0xD8, "O\u{338}"), (0xE6, "ae"),
+    (0xE7, "c\u{326}"), (0xF0, "\u{2202}\u{335}"), (0xF6, "\u{629}"), (0xF8, "o\u{338}"),
+    (0x110, "D\u{335}"), (0x111, "d\u{335}"), (0x11A, "\u{114}"), (0x11B, "\u{115}"),
+    (0x126, "H\u{335}"), (0x127, "h\u{335}"), (0x131, "i"), (0x132, "lJ"), (0x133, "ij"),
+    (0x13F, "l\u{B7}"), (0x140, "l\u{B7}"), (0x141, "L\u{338}"), (0x142, "l\u{338}"),
+    (0x146, "\u{272}"), (0x149, "'n"), (0x150, "\u{D6}"), (0x152, "OE"), (0x153, "oe"),
+    (0x163, "\u{1AB}"), (0x166, "T\u{335}"), (0x167, "t\u{335}"), (0x17F, "f"), (0x180, "b\u{335}"),
+    (0x181, "'B"), (0x182, "b\u{304}"), (0x183, "b\u{304}"), (0x184, "b"), (0x187, "C'"),
+    (0x189, "D\u{335}"), (0x18A, "'D"), (0x18C, "d\u{304}"), (0x18D, "g"), (0x191, "F\u{326}"),
+    (0x192, "f\u{326}"), (0x193, "G'"), (0x196, "l"), (0x197, "l\u{335}"), (0x198, "K'"),
+    (0x199, "k\u{314}"), (0x19A, "l\u{335}"), (0x19B, "\u{3BB}\u{338}"), (0x19D, "N\u{326}"),
+    (0x19E, "n\u{329}"), (0x19F, "O\u{335}"), (0x1A0, "O'"), (0x1A1, "o'"), (0x1A4, "'P"),
+    (0x1A5, "p\u{314}"), (0x1A6, "R"), (0x1A7, "2"), (0x1AC, "'T"), (0x1AD, "t\u{314}"),
+    (0x1AE, "T\u{328}"), (0x1B3, "'Y"), (0x1B4, "y\u{314}"), (0x1B5, "Z\u{335}"),
+    (0x1B6, "z\u{335}"), (0x1B7, "3"), (0x1BB, "2\u{335}"), (0x1BC, "5"), (0x1BD, "s"),
+    (0x1BF, "\u{FE}"), (0x1C0, "l"), (0x1C1, "ll"), (0x1C3, "!"), (0x1C4, "D\u{17D}"),
+    (0x1C5, "D\u{17E}"), (0x1C6, "d\u{17E}"), (0x1C7, "LJ"), (0x1C8, "Lj"), (0x1C9, "lj"),
+    (0x1CA, "NJ"), (0x1CB, "Nj"), (0x1CC, "nj"), (0x1CD, "\u{102}"), (0x1CE, "\u{103}"),
+    (0x1CF, "\u{12C}"), (0x1D0, "\u{12D}"), (0x1D1, "\u{14E}"), (0x1D2, "\u{14F}"),
+    (0x1D3, "\u{16C}"), (0x1D4, "\u{16D}"), (0x1E4, "G\u{335}"), (0x1E5, "g\u{335}"),
+    (0x1E6, "\u{11E}"), (0x1E7, "\u{11F}"), (0x1F1, "DZ"), (0x1F2, "Dz"), (0x1F3, "dz"),
+    (0x1F5, "\u{123}"), (0x1FE, "O\u{338}\u{301}"), (0x21A, "\u{162}"), (0x21B, "\u{1AB}"),
+    (0x21C, "3"), (0x222, "8"), (0x223, "8"), (0x224, "Z\u{326}"), (0x225, "z\u{326}"),
+    (0x226, "\u{C5}"), (0x227, "\u{E5}"), (0x23C, "c\u{338}"), (0x23E, "T\u{338}"), (0x241, "?"),
+    (0x244, "U\u{335}"), (0x246, "E\u{338}"), (0x247, "e\u{338}"), (0x248, "J\u{335}"),
+    (0x249, "j\u{335}"), (0x24D, "r\u{335}"), (0x24E, "Y\u{335}"), (0x24F, "y\u{335}"),
+    (0x251, "a"), (0x253, "b\u{314}"), (0x256, "d\u{328}"), (0x257, "d\u{314}"), (0x259, "\u{1DD}"),
+    (0x25A, "\u{1DD}\u{2DE}"), (0x25B, "\u{A793}"), (0x260, "g\u{314}"), (0x261, "g"), (0x263, "y"),
+    (0x266, "h\u{314}"), (0x268, "i\u{335}"), (0x269, "i"), (0x26A, "i"), (0x26B, "l\u{334}"),
+    (0x26D, "l\u{328}"), (0x26E, "l\u{21D}"), (0x26F, "w"), (0x271, "rn\u{326}"),
+    (0x273, "n\u{328}"), (0x275, "o\u{335}"), (0x276, "o\u{1D07}"), (0x27C, "r\u{329}"),
+    (0x27D, "r\u{328}"), (0x282, "s\u{328}"), (0x28B, "u"), (0x28F, "y"), (0x290, "z\u{328}"),
+    (0x292, "\u{21D}"), (0x294, "?"), (0x2A0, "q\u{314}"), (0x2A3, "dz"), (0x2A4, "d\u{21D}"),
+    (0x2A5, "d\u{291}"), (0x2A6, "ts"), (0x2A7, "t\u{283}"), (0x2A8, "t\u{255}"),
+    (0x2A9, "f\u{14B}"), (0x2AA, "ls"), (0x2AB, "lz"), (0x2B3, "\u{18F4}"), (0x2B9, "'"),
+    (0x2BA, "''"), (0x2BB, "'"), (0x2BC, "'"), (0x2BD, "'"), (0x2BE, "'"), (0x2BF, "\u{559}"),
+    (0x2C2, "<"), (0x2C3, ">"), (0x2C4, "^"), (0x2C6, "^"), (0x2C8, "'"), (0x2CA, "'"),
+    (0x2CB, "'"), (0x2D0, ":"), (0x2D3, "\u{559}"), (0x2D7, "-"), (0x2D8, "\u{2C7}"),
+    (0x2D9, "\u{971}"), (0x2DA, "\u{B0}"), (0x2DB, "i"), (0x2DC, "~"), (0x2DD, "''"),
+    (0x2E1, "\u{18F3}"), (0x2E2, "\u{18F5}"), (0x2E4, "\u{2C1}"), (0x2EE, "''"), (0x2F4, "'"),
+    (0x2F6, "''"), (0x2F8, ":"), (0x2FB, "\u{2EA}"), (0x305, "\u{304}"), (0x30C, "\u{306}"),
+    (0x30D, "\u{670}"), (0x310, "\u{306}\u{307}"), (0x311, "\u{302}"), (0x315, "\u{313}"),
+    (0x317, "\u{650}"), (0x320, "\u{331}"), (0x321, "\u{326}"), (0x322, "\u{328}"),
+    (0x327, "\u{326}"), (0x336, "\u{335}"), (0x337, "\u{338}"), (0x339, "\u{326}"),
+    (0x340, "\u{300}"), (0x341, "\u{301}"), (0x342, "\u{303}"), (0x343, "\u{313}"),
+    (0x345, "\u{328}"), (0x347, "\u{333}"), (0x357, "\u{350}"), (0x358, "\u{307}"),
+    (0x366, "\u{30A}"), (0x36E, "\u{306}"), (0x370, "\u{2C75}"), (0x374, "'"), (0x375, "\u{2CF}"),
+    (0x376, "\u{418}"), (0x377, "\u{1D0E}"), (0x37A, "i"), (0x37B, "\u{254}"), (0x37D, "\u{A73F}"),
+    (0x37E, ";"), (0x37F, "J"), (0x384, "'"), (0x387, "\u{B7}"), (0x391, "A"), (0x392, "B"),
+    (0x395, "E"), (0x396, "Z"), (0x397, "H"), (0x398, "O\u{335}"), (0x399, "l"), (0x39A, "K"),
+    (0x39B, "\u{245}"), (0x39C, "M"), (0x39D, "N"), (0x39F, "O"), (0x3A1, "P"), (0x3A3, "\u{1A9}"),
+    (0x3A4, "T"), (0x3A5, "Y"), (0x3A7, "X"), (0x3B1, "a"), (0x3B2, "\u{DF}"), (0x3B3, "y"),
+    (0x3B4, "\u{1E9F}"), (0x3B5, "\u{A793}"), (0x3B7, "n\u{329}"), (0x3B8, "O\u{335}"),
+    (0x3B9, "i"), (0x3BA, "\u{138}"), (0x3BD, "v"), (0x3BF, "o"), (0x3C1, "p"), (0x3C3, "o"),
+    (0x3C4, "\u{1D1B}"), (0x3C5, "u"), (0x3C6, "\u{278}"), (0x3D0, "\u{DF}"), (0x3D1, "O\u{335}"),
+    (0x3D2, "Y"), (0x3D5, "\u{278}"), (0x3D6, "\u{3C0}"), (0x3DB, "\u{3C2}"), (0x3DC, "F"),
+    (0x3E8, "2"), (0x3E9, "\u{1A8}"), (0x3F0, "\u{138}"), (0x3F1, "p"), (0x3F2, "c"), (0x3F3, "j"),
+    (0x3F4, "O\u{335}"), (0x3F5, "\u{A793}"), (0x3F7, "\u{DE}"), (0x3F8, "\u{FE}"), (0x3F9, "C"),
+    (0x3FA, "M"), (0x3FD, "\u{186}"), (0x3FF, "\u{A73E}"), (0x404, "\u{A792}"), (0x405, "S"),
+    (0x406, "l"), (0x408, "J"), (0x410, "A"), (0x411, "b\u{304}"), (0x412, "B"), (0x413, "\u{393}"),
+    (0x415, "E"), (0x417, "3"), (0x419, "\u{40D}"), (0x41A, "K"), (0x41B, "\u{245}"), (0x41C, "M"),
+    (0x41D, "H"), (0x41E, "O"), (0x41F, "\u{3A0}"), (0x420, "P"), (0x421, "C"), (0x422, "T"),
+    (0x423, "Y"), (0x424, "\u{3A6}"), (0x425, "X"), (0x42B, "bl"), (0x42C, "b"), (0x42E, "lO"),
+    (0x430, "a"), (0x431, "6"), (0x432, "\u{299}"), (0x433, "r"), (0x435, "e"), (0x437, "\u{25C}"),
+    (0x438, "\u{1D0E}"), (0x43A, "\u{138}"), (0x43C, "\u{28D}"), (0x43D, "\u{29C}"), (0x43E, "o"),
+    (0x43F, "\u{3C0}"), (0x440, "p"), (0x441, "c"), (0x442, "\u{1D1B}"), (0x443, "y"),
+    (0x444, "\u{278}"), (0x445, "x"), (0x44A, "\u{2C9}b"), (0x44B, "\u{185}i"), (0x44C, "\u{185}"),
+    (0x44F, "\u{1D19}"), (0x454, "\u{A793}"), (0x455, "s"), (0x456, "i"), (0x458, "j"),
+    (0x45B, "h\u{335}"), (0x45D, "\u{439}"), (0x461, "w"), (0x462, "b\u{335}"), (0x463, "b\u{335}"),
+    (0x470, "\u{3A8}"), (0x471, "\u{3C8}"), (0x472, "O\u{335}"), (0x473, "o\u{335}"), (0x474, "V"),
+    (0x475, "v"), (0x47C, "\u{460}\u{486}\u{487}"), (0x47D, "w\u{486}\u{487}"),
+    (0x48A, "\u{40D}\u{326}"), (0x48B, "\u{439}\u{326}"), (0x48C, "b\u{335}"), (0x48D, "b\u{335}"),
+    (0x490, "\u{393}'"), (0x491, "r'"), (0x492, "\u{393}\u{335}"), (0x493, "r\u{335}"),
+    (0x496, "\u{416}\u{329}"), (0x497, "\u{436}\u{329}"), (0x498, "3\u{326}"),
+    (0x499, "\u{25C}\u{326}"), (0x49A, "K\u{329}"), (0x49B, "\u{138}\u{329}"), (0x49E, "K\u{335}"),
+    (0x49F, "\u{138}\u{335}"), (0x4A2, "H\u{329}"), (0x4A3, "\u{29C}\u{329}"), (0x4AA, "C\u{326}"),
+    (0x4AB, "c\u{326}"), (0x4AC, "T\u{329}"), (0x4AD, "\u{1D1B}\u{329}"), (0x4AE, "Y"),
+    (0x4AF, "y"), (0x4B0, "Y\u{335}"), (0x4B1, "y\u{335}"), (0x4B2, "X\u{329}"), (0x4BB, "h"),
+    (0x4BD, "e"), (0x4BE, "\u{4BC}\u{328}"), (0x4BF, "e\u{328}"), (0x4C0, "l"),
+    (0x4C5, "\u{245}\u{326}"), (0x4C6, "\u{43B}\u{326}"), (0x4C7, "H\u{326}"),
+    (0x4C8, "\u{29C}\u{326}"), (0x4C9, "H\u{326}"), (0x4CA, "\u{29C}\u{326}"), (0x4CB, "\u{4B6}"),
+    (0x4CC, "\u{4B7}"), (0x4CD, "M\u{326}"), (0x4CE, "\u{28D}\u{326}"), (0x4CF, "i"), (0x4D4, "AE"),
+    (0x4D5, "ae"), (0x4D8, "\u{18F}"), (0x4D9, "\u{1DD}"), (0x4E0, "3"), (0x4E1, "\u{21D}"),
+    (0x4E8, "O\u{335}"), (0x4E9, "o\u{335}"), (0x501, "d"), (0x50A, "\u{1F6}"), (0x50C, "G"),
+    (0x50D, "\u{262}"), (0x510, "\u{190}"), (0x511, "\u{A793}"), (0x51B, "q"), (0x51C, "W"),
+    (0x51D, "w"), (0x53B, "\u{12AE}"), (0x544, "\u{1206}"), (0x54A, "\u{1323}"),
+    (0x54C, "\u{1261}"), (0x54D, "U"), (0x54F, "S"), (0x553, "\u{3A6}"), (0x555, "O"), (0x55A, "'"),
+    (0x55D, "'"), (0x561, "w"), (0x563, "q"), (0x566, "q"), (0x56E, "\u{1E9F}"), (0x570, "h"),
+    (0x575, "\u{237}"), (0x578, "n"), (0x57A, "\u{270}"), (0x57C, "n"), (0x57D, "u"), (0x581, "g"),
+    (0x584, "f"), (0x585, "o"), (0x587, "\u{565}\u{582}"), (0x589, ":"), (0x59C, "\u{301}"),
+    (0x59D, "\u{301}"), (0x5A4, "\u{59A}"), (0x5A8, "\u{599}"), (0x5AD, "\u{596}"),
+    (0x5AE, "\u{598}"), (0x5AF, "\u{30A}"), (0x5B4, "\u{323}"), (0x5B9, "\u{307}"),
+    (0x5BA, "\u{307}"), (0x5C0, "l"), (0x5C1, "\u{307}"), (0x5C2, "\u{307}"), (0x5C3, ":"),
+    (0x5C4, "\u{307}"), (0x5C5, "\u{323}"), (0x5D5, "l"), (0x5D8, "v"), (0x5D9, "'"), (0x5DF, "l"),
+    (0x5E1, "o"), (0x5F0, "ll"), (0x5F1, "l'"), (0x5F2, "''"), (0x5F3, "'"), (0x5F4, "''"),
+    (0x609, "\u{BA}/\u{2080}\u{2080}"), (0x60A, "\u{BA}/\u{2080}\u{2080}\u{2080}"), (0x60D, ","),
+    (0x60F, "\u{639}"), (0x618, "\u{301}"), (0x619, "\u{313}"), (0x61A, "\u{650}"),
+    (0x623, "l\u{674}"), (0x624, "\u{648}\u{674}"), (0x625, "l\u{655}"), (0x626, "\u{649}\u{674}"),
+    (0x627, "l"), (0x62B, "\u{649}\u{6DB}"), (0x634, "\u{633}\u{6DB}"), (0x63D, "\u{649}\u{302}"),
+    (0x63F, "\u{649}\u{6DB}"), (0x647, "o"), (0x64A, "\u{649}"), (0x64B, "\u{30B}"),
+    (0x64E, "\u{301}"), (0x64F, "\u{313}"), (0x652, "\u{30A}"), (0x653, "\u{303}"),
+    (0x656, "\u{329}"), (0x657, "\u{312}"), (0x658, "\u{306}"), (0x659, "\u{304}"),
+    (0x65A, "\u{306}"), (0x65B, "\u{302}"), (0x65C, "\u{323}"), (0x65D, "\u{314}"),
+    (0x65F, "\u{655}"), (0x660, "."), (0x661, "l"), (0x665, "o"), (0x667, "V"), (0x668, "\u{245}"),
+    (0x66A, "\u{BA}/\u{2080}"), (0x66B, ","), (0x66C, "\u{60C}"), (0x66D, "*"), (0x66E, "\u{649}"),
+    (0x66F, "\u{6A1}"), (0x672, "l\u{674}"), (0x673, "l\u{655}"), (0x675, "l\u{674}"),
+    (0x676, "\u{648}\u{674}"), (0x677, "\u{648}\u{313}\u{674}"), (0x678, "\u{649}\u{674}"),
+    (0x679, "\u{649}\u{615}"), (0x67E, "\u{649}\u{6DB}"), (0x681, "\u{62D}\u{654}"),
+    (0x685, "\u{62D}\u{6DB}"), (0x688, "\u{62F}\u{615}"), (0x68B, "\u{68A}\u{615}"),
+    (0x68E, "\u{62F}\u{6DB}"), (0x691, "\u{631}\u{615}"), (0x692, "\u{631}\u{306}"),
+    (0x698, "\u{631}\u{6DB}"), (0x69E, "\u{635}\u{6DB}"), (0x69F, "\u{637}\u{6DB}"),
+    (0x6A4, "\u{6A1}\u{6DB}"), (0x6A7, "\u{641}"), (0x6A8, "\u{6A1}\u{6DB}"), (0x6A9, "\u{643}"),
+    (0x6AA, "\u{643}"), (0x6AD, "\u{643}\u{6DB}"), (0x6B4, "\u{6AF}\u{6DB}"),
+    (0x6B5, "\u{644}\u{306}"), (0x6B7, "\u{644}\u{6DB}"), (0x6BA, "\u{649}"),
+    (0x6BB, "\u{649}\u{615}"), (0x6BD, "\u{649}\u{6DB}"), (0x6BE, "o"), (0x6C1, "o"),
+    (0x6C2, "\u{6C0}"), (0x6C3, "\u{629}"), (0x6C6, "\u{648}\u{306}"), (0x6C7, "\u{648}\u{313}"),
+    (0x6C8, "\u{648}\u{670}"), (0x6C9, "\u{648}\u{302}"), (0x6CB, "\u{648}\u{6DB}"),
+    (0x6CC, "\u{649}"), (0x6CE, "\u{649}\u{306}"), (0x6D0, "\u{67B}"), (0x6D1, "\u{649}\u{6DB}"),
+    (0x6D2, "\u{649}"), (0x6D4, "-"), (0x6D5, "o"), (0x6DF, "\u{30A}"), (0x6E8, "\u{306}\u{307}"),
+    (0x6EC, "\u{307}"), (0x6EE, "\u{62F}\u{302}"), (0x6EF, "\u{631}\u{302}"), (0x6F0, "."),
+    (0x6F1, "l"), (0x6F2, "\u{662}"), (0x6F3, "\u{663}"), (0x6F4, "\u{664}"), (0x6F5, "o"),
+    (0x6F6, "\u{666}"), (0x6F7, "V"), (0x6F8, "\u{245}"), (0x6F9, "\u{669}"),
+    (0x6FD, "\u{621}\u{348}"), (0x6FE, "\u{645}\u{348}"), (0x6FF, "o\u{302}"), (0x701, "."),
+    (0x702, "."), (0x703, ":"), (0x704, ":"), (0x740, "\u{307}"), (0x741, "\u{307}"),
+    (0x742, "\u{73C}"), (0x747, "\u{301}"), (0x751, "\u{628}\u{6DB}"), (0x756, "\u{649}\u{306}"),
+    (0x762, "\u{6AC}"), (0x763, "\u{643}\u{6DB}"), (0x767, "\u{754}"), (0x768, "\u{646}\u{615}"),
+    (0x769, "\u{646}\u{306}"), (0x76C, "\u{631}\u{654}"), (0x771, "\u{697}\u{615}"),
+    (0x772, "\u{62D}\u{654}"), (0x77E, "\u{633}\u{302}"), (0x7C0, "O"), (0x7CA, "l"),
+    (0x7EB, "\u{304}"), (0x7ED, "\u{307}"), (0x7EE, "\u{302}"), (0x7F3, "\u{308}"), (0x7F4, "'"),
+    (0x7F5, "'"), (0x7FA, "_"), (0x8A1, "\u{628}\u{654}"), (0x8A4, "\u{6A2}\u{6DB}"),
+    (0x8A7, "\u{645}\u{6DB}"), (0x8A8, "\u{649}\u{654}"), (0x8A9, "\u{754}"),
+    (0x8AE, "\u{62F}\u{324}\u{323}"), (0x8AF, "\u{635}\u{324}\u{323}"), (0x8B0, "\u{6AF}"),
+    (0x8B1, "\u{648}"), (0x8B2, "\u{632}\u{302}"), (0x8B6, "\u{628}\u{6E2}"),
+    (0x8B7, "\u{649}\u{6DB}\u{6E2}"), (0x8B9, "\u{631}\u{306}\u{307}"),
+    (0x8BA, "\u{649}\u{306}\u{307}"), (0x8BB, "\u{6A1}"), (0x8BC, "\u{6A1}"), (0x8BD, "\u{649}"),
+    (0x8E5, "\u{64C}"), (0x8E8, "\u{64C}"), (0x8EA, "\u{307}"), (0x8EB, "\u{308}"),
+    (0x8ED, "\u{323}"), (0x8EE, "\u{324}"), (0x8F0, "\u{30B}"), (0x8F1, "\u{64C}"),
+    (0x8F2, "\u{64D}"), (0x8F3, "\u{313}"), (0x8F8, "\u{350}"), (0x8F9, "\u{354}"),
+    (0x8FA, "\u{355}"), (0x8FF, "\u{350}"), (0x900, "\u{352}"), (0x901, "\u{306}\u{307}"),
+    (0x902, "\u{307}"), (0x903, ":"), (0x904, "\u{905}\u{946}"), (0x906, "\u{905}\u{93E}"),
+    (0x908, "\u{930}\u{94D}\u{907}"), (0x90D, "\u{90F}\u{945}"), (0x90E, "\u{90F}\u{946}"),
+    (0x910, "\u{90F}\u{947}"), (0x911, "\u{905}\u{949}"), (0x912, "\u{905}\u{93E}\u{946}"),
+    (0x913, "\u{905}\u{93E}\u{947}"), (0x914, "\u{905}\u{93E}\u{948}"), (0x93C, "\u{323}"),
+    (0x952, "\u{331}"), (0x953, "\u{300}"), (0x954, "\u{301}"), (0x965, "\u{964}\u{964}"),
+    (0x966, "o"), (0x967, "\u{669}"), (0x97D, "?"), (0x981, "\u{306}\u{307}"),
+    (0x986, "\u{985}\u{9BE}"), (0x9BC, "\u{323}"), (0x9E0, "\u{98B}\u{9C3}"),
+    (0x9E1, "\u{98B}\u{9C3}"), (0x9E6, "O"), (0x9EA, "8"), (0x9ED, "9"), (0xA02, "\u{307}"),
+    (0xA03, "\u{983}"), (0xA06, "\u{A05}\u{A3E}"), (0xA07, "\u{A72}\u{A3F}"),
+    (0xA08, "\u{A72}\u{A40}"), (0xA09, "\u{A73}\u{A41}"), (0xA0A, "\u{A73}\u{A42}"),
+    (0xA0F, "\u{A72}\u{A47}"), (0xA10, "\u{A05}\u{A48}"), (0xA14, "\u{A05}\u{A4C}"),
+    (0xA3C, "\u{323}"), (0xA4B, "\u{946}"), (0xA4D, "\u{94D}"), (0xA66, "o"), (0xA67, "9"),
+    (0xA6A, "8"), (0xA81, "\u{306}\u{307}"), (0xA82, "\u{307}"), (0xA83, ":"),
+    (0xA86, "\u{A85}\u{ABE}"), (0xA8D, "\u{A85}\u{AC5}"), (0xA8F, "\u{A85}\u{AC7}"),
+    (0xA90, "\u{A85}\u{AC8}"), (0xA91, "\u{A85}\u{ABE}\u{AC5}"), (0xA93, "\u{A85}\u{ABE}\u{AC7}"),
+    (0xA94, "\u{A85}\u{ABE}\u{AC8}"), (0xABC, "\u{323}"), (0xABD, "\u{93D}"), (0xAC1, "\u{941}"),
+    (0xAC2, "\u{942}"), (0xACD, "\u{94D}"), (0xAE6, "o"), (0xAE8, "\u{968}"), (0xAE9, "\u{969}"),
+    (0xAEA, "\u{96A}"), (0xAEE, "\u{96E}"), (0xAF0, "\u{970}"), (0xB01, "\u{306}\u{307}"),
+    (0xB03, "8"), (0xB06, "\u{B05}\u{B3E}"), (0xB20, "O"), (0xB3C, "\u{323}"), (0xB66, "O"),
+    (0xB68, "9"), (0xB82, "\u{30A}"), (0xB8A, "\u{B89}\u{BB3}"), (0xB9C, "\u{B90}"),
+    (0xBB0, "\u{B88}"), (0xBBE, "\u{B88}"), (0xBC8, "\u{BA9}"), (0xBCA, "\u{BC6}\u{B88}"),
+    (0xBCB, "\u{BC7}\u{B88}"), (0xBCC, "\u{BC6}\u{BB3}"), (0xBCD, "\u{307}"), (0xBD7, "\u{BB3}"),
+    (0xBE6, "o"), (0xBE7, "\u{B95}"), (0xBE8, "\u{B89}"), (0xBEA, "\u{B9A}"),
+    (0xBEB, "\u{B88}\u{BC1}"), (0xBEC, "\u{B9A}\u{BC1}"), (0xBED, "\u{B8E}"), (0xBEE, "\u{B85}"),
+    (0xBF0, "\u{BAF}"), (0xBF2, "\u{B9A}\u{BC2}"), (0xBF4, "\u{BAE}\u{BC0}"), (0xBF5, "\u{BF3}"),
+    (0xBF7, "\u{B8E}\u{BB5}"), (0xBF8, "\u{BB7}"), (0xBFA, "\u{BA8}\u{BC0}"),
+    (0xC00, "\u{306}\u{307}"), (0xC02, "o"), (0xC03, "\u{983}"), (0xC13, "\u{C12}\u{C55}"),
+    (0xC14, "\u{C12}\u{C4C}"), (0xC20, "\u{C30}\u{5BC}"), (0xC22, "\u{C21}\u{323}"),
+    (0xC25, "\u{C27}\u{5BC}"), (0xC2D, "\u{C2C}\u{323}"), (0xC2E, "\u{C35}\u{C41}"),
+    (0xC37, "\u{C35}\u{323}"), (0xC39, "\u{C35}\u{C3E}"), (0xC42, "\u{C41}\u{C3E}"),
+    (0xC44, "\u{C43}\u{C3E}"), (0xC60, "\u{C0B}\u{C3E}"), (0xC61, "\u{C0C}\u{C3E}"), (0xC66, "o"),
+    (0xC81, "\u{306}\u{307}"), (0xC82, "o"), (0xC83, "\u{983}"), (0xC85, "\u{C05}"),
+    (0xC86, "\u{C06}"), (0xC87, "\u{C07}"), (0xC92, "\u{C12}"), (0xC93, "\u{C12}\u{C55}"),
+    (0xC94, "\u{C12}\u{C4C}"), (0xC9C, "\u{C1C}"), (0xC9E, "\u{C1E}"), (0xCA3, "\u{C23}"),
+    (0xCAF, "\u{C2F}"), (0xCB1, "\u{C31}"), (0xCB2, "\u{C32}"), (0xCE1, "\u{C8C}\u{CBE}"),
+    (0xCE6, "o"), (0xCE7, "\u{C67}"), (0xCE8, "\u{C68}"), (0xCEF, "\u{C6F}"),
+    (0xD01, "\u{306}\u{307}"), (0xD02, "o"), (0xD03, "\u{983}"), (0xD08, "\u{D07}\u{D57}"),
+    (0xD09, "\u{B89}"), (0xD0A, "\u{B89}\u{D57}"), (0xD0C, "\u{D28}\u{D41}"),
+    (0xD10, "\u{D0E}\u{D46}"), (0xD13, "\u{D12}\u{D3E}"), (0xD14, "\u{D12}\u{D57}"),
+    (0xD19, "\u{D28}\u{D41}"), (0xD1C, "\u{B90}"), (0xD20, "o"), (0xD23, "\u{BA3}"),
+    (0xD31, "\u{D30}"), (0xD34, "\u{BB4}"), (0xD36, "\u{BB6}"), (0xD3A, "\u{B9F}\u{BBF}"),
+    (0xD3F, "\u{BBF}"), (0xD40, "\u{BBF}"), (0xD42, "\u{D41}"), (0xD43, "\u{D41}"),
+    (0xD48, "\u{D46}\u{D46}"), (0xD4E, "\u{971}"), (0xD5A, "\u{D28}\u{D4D}\u{D2E}"),
+    (0xD5F, "o\u{D30}o"), (0xD61, "\u{D1E}"), (0xD66, "o"), (0xD6A, "\u{D30}\u{D4D}"),
+    (0xD6B, "\u{D26}\u{D4D}\u{D30}"), (0xD6C, "\u{D28}\u{D4D}\u{D28}"), (0xD6D, "9"),
+    (0xD6E, "\u{D35}\u{D4D}\u{D30}"), (0xD6F, "\u{D28}\u{D4D}"), (0xD76, "\u{D39}\u{D4D}\u{D2E}"),
+    (0xD79, "\u{D28}\u{D41}"), (0xD7B, "\u{D28}\u{D4D}"), (0xD7C, "\u{D30}\u{D4D}"), (0xD82, "o"),
+    (0xD83, "\u{983}"), (0xDE9, "\u{DE8}\u{DCF}"), (0xDEA, "\u{DA2}"), (0xDEB, "\u{DAF}"),
+    (0xDEF, "\u{DE8}\u{DD3}"), (0xE03, "\u{E02}"), (0xE0B, "\u{E0A}"), (0xE0F, "\u{E0E}"),
+    (0xE14, "\u{E04}"), (0xE15, "\u{E04}"), (0xE17, "\u{E11}"), (0xE21, "\u{E06}"),
+    (0xE26, "\u{E20}"), (0xE33, "\u{30A}\u{E32}"), (0xE41, "\u{E40}\u{E40}"), (0xE45, "\u{E32}"),
+    (0xE4D, "\u{30A}"), (0xE50, "o"), (0xE88, "\u{E08}"), (0xE8D, "\u{E22}"), (0xE9A, "\u{E1A}"),
+    (0xE9B, "\u{E1B}"), (0xE9D, "\u{E1D}"), (0xE9E, "\u{E1E}"), (0xE9F, "\u{E1F}"),
+    (0xEB3, "\u{30A}\u{EB2}"), (0xEB8, "\u{E38}"), (0xEB9, "\u{E39}"), (0xEC8, "\u{E48}"),
+    (0xEC9, "\u{E49}"), (0xECA, "\u{E4A}"), (0xECB, "\u{E4B}"), (0xECD, "\u{30A}"), (0xED0, "o"),
+    (0xEDC, "\u{EAB}\u{E99}"), (0xEDD, "\u{EAB}\u{EA1}"), (0xF00, "\u{F68}\u{F7C}\u{F7E}"),
+    (0xF02, "\u{F60}\u{F74}\u{F82}\u{F7F}"), (0xF03, "\u{F60}\u{F74}\u{F82}\u{F14}"),
+    (0xF0C, "\u{F0B}"), (0xF0E, "\u{F0D}\u{F0D}"), (0xF1B, "\u{F1A}\u{F1A}"),
+    (0xF1E, "\u{F1D}\u{F1D}"), (0xF1F, "\u{F1A}\u{F1D}"), (0xF37, "\u{325}"), (0xF6A, "\u{F62}"),
+    (0xF77, "\u{FB2}\u{F71}\u{F80}"), (0xF79, "\u{FB3}\u{F71}\u{F80}"), (0xFCE, "\u{F1D}\u{F1A}"),
+    (0xFD5, "\u{5350}"), (0xFD6, "\u{534D}"), (0x1000, "\u{1002}\u{102C}"), (0x1010, "o\u{102C}"),
+    (0x101D, "o"), (0x101F, "\u{1015}\u{102C}"), (0x1029, "\u{101E}\u{103C}"),
+    (0x102A, "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}"), (0x1036, "\u{30A}"), (0x1038, "\u{983}"),
+    (0x1040, "o"), (0x104B, "\u{104A}\u{104A}"), (0x1065, "\u{1041}"), (0x1066, "\u{1015}\u{103E}"),
+    (0x106F, "\u{1015}\u{102C}\u{103E}"), (0x1070, "\u{1003}\u{103E}"),
+    (0x107E, "\u{107D}\u{103E}"), (0x1081, "\u{1002}\u{103E}"), (0x109E, "\u{1083}\u{30A}"),
+    (0x10A0, "\u{A786}"), (0x10E7, "y"), (0x10F3, "\u{21D}"), (0x10FF, "o"),
+    (0x1101, "\u{1100}\u{1100}"), (0x1104, "\u{1103}\u{1103}"), (0x1108, "\u{1107}\u{1107}"),
+    (0x110A, "\u{1109}\u{1109}"), (0x110D, "\u{110C}\u{110C}"), (0x1113, "\u{1102}\u{1100}"),
+    (0x1114, "\u{1102}\u{1102}"), (0x1115, "\u{1102}\u{1103}"), (0x1116, "\u{1102}\u{1107}"),
+    (0x1117, "\u{1103}\u{1100}"), (0x1118, "\u{1105}\u{1102}"), (0x1119, "\u{1105}\u{1105}"),
+    (0x111A, "\u{1105}\u{1112}"), (0x111B, "\u{1105}\u{110B}"), (0x111C, "\u{1106}\u{1107}"),
+    (0x111D, "\u{1106}\u{110B}"), (0x111E, "\u{1107}\u{1100}"), (0x111F, "\u{1107}\u{1102}"),
+    (0x1120, "\u{1107}\u{1103}"), (0x1121, "\u{1107}\u{1109}"),
+    (0x1122, "\u{1107}\u{1109}\u{1100}"), (0x1123, "\u{1107}\u{1109}\u{1103}"),
+    (0x1124, "\u{1107}\u{1109}\u{1107}"), (0x1125, "\u{1107}\u{1109}\u{1109}"),
+    (0x1126, "\u{1107}\u{1109}\u{110C}"), (0x1127, "\u{1107}\u{110C}"),
+    (0x1128, "\u{1107}\u{110E}"), (0x1129, "\u{1107}\u{1110}"), (0x112A, "\u{1107}\u{1111}"),
+    (0x112B, "\u{1107}\u{110B}"), (0x112C, "\u{1107}\u{1107}\u{110B}"),
+    (0x112D, "\u{1109}\u{1100}"), (0x112E, "\u{1109}\u{1102}"), (0x112F, "\u{1109}\u{1103}"),
+    (0x1130, "\u{1109}\u{1105}"), (0x1131, "\u{1109}\u{1106}"), (0x1132, "\u{1109}\u{1107}"),
+    (0x1133, "\u{1109}\u{1107}\u{1100}"), (0x1134, "\u{1109}\u{1109}\u{1109}"),
+    (0x1135, "\u{1109}\u{110B}"), (0x1136, "\u{1109}\u{110C}"), (0x1137, "\u{1109}\u{110E}"),
+    (0x1138, "\u{1109}\u{110F}"), (0x1139, "\u{1109}\u{1110}"), (0x113A, "\u{1109}\u{1111}"),
+    (0x113B, "\u{1105}\u{1112}"), (0x113D, "\u{113C}\u{113C}"), (0x113F, "\u{113E}\u{113E}"),
+    (0x1141, "\u{110B}\u{1100}"), (0x1142, "\u{110B}\u{1103}"), (0x1143, "\u{110B}\u{1106}"),
+    (0x1144, "\u{110B}\u{1107}"), (0x1145, "\u{110B}\u{1109}"), (0x1146, "\u{110B}\u{1140}"),
+    (0x1147, "\u{110B}\u{110B}"), (0x1148, "\u{110B}\u{110C}"), (0x1149, "\u{110B}\u{110E}"),
+    (0x114A, "\u{110B}\u{1110}"), (0x114B, "\u{110B}\u{1111}"), (0x114D, "\u{110C}\u{110B}"),
+    (0x114F, "\u{114E}\u{114E}"), (0x1151, "\u{1150}\u{1150}"), (0x1152, "\u{110E}\u{110F}"),
+    (0x1153, "\u{110E}\u{1112}"), (0x1156, "\u{1111}\u{1107}"), (0x1157, "\u{1111}\u{110B}"),
+    (0x1158, "\u{1112}\u{1112}"), (0x115A, "\u{1100}\u{1103}"), (0x115B, "\u{1102}\u{1109}"),
+    (0x115C, "\u{1102}\u{110C}"), (0x115D, "\u{1102}\u{1112}"), (0x115E, "\u{1103}\u{1105}"),
+    (0x1162, "\u{1161}\u{4E28}"), (0x1164, "\u{1163}\u{4E28}"), (0x1166, "\u{1165}\u{4E28}"),
+    (0x1168, "\u{1167}\u{4E28}"), (0x116A, "\u{1169}\u{1161}"),
+    (0x116B, "\u{1169}\u{1161}\u{4E28}"), (0x116C, "\u{1169}\u{4E28}"),
+    (0x116F, "\u{116E}\u{1165}"), (0x1170, "\u{116E}\u{1165}\u{4E28}"),
+    (0x1171, "\u{116E}\u{4E28}"), (0x1173, "\u{30FC}"), (0x1174, "\u{30FC}\u{4E28}"),
+    (0x1175, "\u{4E28}"), (0x1176, "\u{1161}\u{1169}"), (0x1177, "\u{1161}\u{116E}"),
+    (0x1178, "\u{1163}\u{1169}"), (0x1179, "\u{1163}\u{116D}"), (0x117A, "\u{1165}\u{1169}"),
+    (0x117B, "\u{1165}\u{116E}"), (0x117C, "\u{1165}\u{30FC}"), (0x117D, "\u{1167}\u{1169}"),
+    (0x117E, "\u{1167}\u{116E}"), (0x117F, "\u{1169}\u{1165}"),
+    (0x1180, "\u{1169}\u{1165}\u{4E28}"), (0x1181, "\u{1169}\u{1167}\u{4E28}"),
+    (0x1182, "\u{1169}\u{1169}"), (0x1183, "\u{1169}\u{116E}"), (0x1184, "\u{116D}\u{1163}"),
+    (0x1185, "\u{116D}\u{1163}\u{4E28}"), (0x1186, "\u{116D}\u{1163}"),
+    (0x1187, "\u{116D}\u{1169}"), (0x1188, "\u{116D}\u{4E28}"), (0x1189, "\u{116E}\u{1161}"),
+    (0x118A, "\u{116E}\u{1161}\u{4E28}"), (0x118B, "\u{116E}\u{1165}\u{30FC}"),
+    (0x118C, "\u{116E}\u{1167}\u{4E28}"), (0x118D, "\u{116E}\u{116E}"),
+    (0x118E, "\u{1172}\u{1161}"), (0x118F, "\u{1172}\u{1165}"),
+    (0x1190, "\u{1172}\u{1165}\u{4E28}"), (0x1191, "\u{1172}\u{1167}"),
+    (0x1192, "\u{1172}\u{1167}\u{4E28}"), (0x1193, "\u{1172}\u{116E}"),
+    (0x1194, "\u{1172}\u{4E28}"), (0x1195, "\u{30FC}\u{116E}"), (0x1196, "\u{30FC}\u{30FC}"),
+    (0x1197, "\u{30FC}\u{4E28}\u{116E}"), (0x1198, "\u{4E28}\u{1161}"),
+    (0x1199, "\u{4E28}\u{1163}"), (0x119A, "\u{4E28}\u{1169}"), (0x119B, "\u{4E28}\u{116E}"),
+    (0x119C, "\u{4E28}\u{30FC}"), (0x119D, "\u{4E28}\u{119E}"), (0x119F, "\u{119E}\u{1165}"),
+    (0x11A0, "\u{119E}\u{116E}"), (0x11A1, "\u{119E}\u{4E28}"), (0x11A2, "\u{119E}\u{119E}"),
+    (0x11A3, "\u{1161}\u{30FC}"), (0x11A4, "\u{1163}\u{116E}"), (0x11A5, "\u{1167}\u{1163}"),
+    (0x11A6, "\u{1169}\u{1163}"), (0x11A7, "\u{1169}\u{1163}\u{4E28}"), (0x11A8, "\u{1100}"),
+    (0x11A9, "\u{1100}\u{1100}"), (0x11AA, "\u{1100}\u{1109}"), (0x11AB, "\u{1102}"),
+    (0x11AC, "\u{1102}\u{110C}"), (0x11AD, "\u{1102}\u{1112}"), (0x11AE, "\u{1103}"),
+    (0x11AF, "\u{1105}"), (0x11B0, "\u{1105}\u{1100}"), (0x11B1, "\u{1105}\u{1106}"),
+    (0x11B2, "\u{1105}\u{1107}"), (0x11B3, "\u{1105}\u{1109}"), (0x11B4, "\u{1105}\u{1110}"),
+    (0x11B5, "\u{1105}\u{1111}"), (0x11B6, "\u{1105}\u{1112}"), (0x11B7, "\u{1106}"),
+    (0x11B8, "\u{1107}"), (0x11B9, "\u{1107}\u{1109}"), (0x11BA, "\u{1109}"),
+    (0x11BB, "\u{1109}\u{1109}"), (0x11BC, "\u{110B}"), (0x11BD, "\u{110C}"), (0x11BE, "\u{110E}"),
+    (0x11BF, "\u{110F}"), (0x11C0, "\u{1110}"), (0x11C1, "\u{1111}"), (0x11C2, "\u{1112}"),
+    (0x11C3, "\u{1100}\u{1105}"), (0x11C4, "\u{1100}\u{1109}\u{1100}"),
+    (0x11C5, "\u{1102}\u{1100}"), (0x11C6, "\u{1102}\u{1103}"), (0x11C7, "\u{1102}\u{1109}"),
+    (0x11C8, "\u{1102}\u{1140}"), (0x11C9, "\u{1102}\u{1110}"), (0x11CA, "\u{1103}\u{1100}"),
+    (0x11CB, "\u{1103}\u{1105}"), (0x11CC, "\u{1105}\u{1100}\u{1109}"),
+    (0x11CD, "\u{1105}\u{1102}"), (0x11CE, "\u{1105}\u{1103}"),
+    (0x11CF, "\u{1105}\u{1103}\u{1112}"), (0x11D0, "\u{1105}\u{1105}"),
+    (0x11D1, "\u{1105}\u{1106}\u{1100}"), (0x11D2, "\u{1105}\u{1106}\u{1109}"),
+    (0x11D3, "\u{1105}\u{1107}\u{1109}"), (0x11D4, "\u{1105}\u{1107}\u{1112}"),
+    (0x11D5, "\u{1105}\u{1107}\u{110B}"), (0x11D6, "\u{1105}\u{1109}\u{1109}"),
+    (0x11D7, "\u{1105}\u{1140}"), (0x11D8, "\u{1105}\u{110F}"), (0x11D9, "\u{1105}\u{1159}"),
+    (0x11DA, "\u{1106}\u{1100}"), (0x11DB, "\u{1106}\u{1105}"), (0x11DC, "\u{1106}\u{1107}"),
+    (0x11DD, "\u{1106}\u{1109}"), (0x11DE, "\u{1106}\u{1109}\u{1109}"),
+    (0x11DF, "\u{1106}\u{1140}"), (0x11E0, "\u{1106}\u{110E}"), (0x11E1, "\u{1106}\u{1112}"),
+    (0x11E2, "\u{1106}\u{110B}"), (0x11E3, "\u{1107}\u{1105}"), (0x11E4, "\u{1107}\u{1111}"),
+    (0x11E5, "\u{1107}\u{1112}"), (0x11E6, "\u{1107}\u{110B}"), (0x11E7, "\u{1109}\u{1100}"),
+    (0x11E8, "\u{1109}\u{1103}"), (0x11E9, "\u{1109}\u{1105}"), (0x11EA, "\u{1109}\u{1107}"),
+    (0x11EB, "\u{1140}"), (0x11EC, "\u{110B}\u{1100}"), (0x11ED, "\u{110B}\u{1100}\u{1100}"),
+    (0x11EE, "\u{110B}\u{110B}"), (0x11EF, "\u{110B}\u{110F}"), (0x11F0, "\u{114C}"),
+    (0x11F1, "\u{110B}\u{1109}"), (0x11F2, "\u{110B}\u{1140}"), (0x11F3, "\u{1111}\u{1107}"),
+    (0x11F4, "\u{1111}\u{110B}"), (0x11F5, "\u{1112}\u{1102}"), (0x11F6, "\u{1112}\u{1105}"),
+    (0x11F7, "\u{1112}\u{1106}"), (0x11F8, "\u{1112}\u{1107}"), (0x11F9, "\u{1159}"),
+    (0x11FA, "\u{1100}\u{1102}"), (0x11FB, "\u{1100}\u{1107}"), (0x11FC, "\u{1100}\u{110E}"),
+    (0x11FD, "\u{1100}\u{110F}"), (0x11FE, "\u{1100}\u{1112}"), (0x11FF, "\u{1102}\u{1102}"),
+    (0x1200, "U"), (0x1223, "\u{270}"), (0x1240, "\u{3A6}"), (0x1260, "\u{548}"),
+    (0x1294, "\u{571}"), (0x12D0, "O"), (0x13A0, "D"), (0x13A1, "R"), (0x13A2, "T"), (0x13A4, "O'"),
+    (0x13A5, "i"), (0x13A8, "\u{2C75}"), (0x13A9, "Y"), (0x13AA, "A"), (0x13AB, "J"), (0x13AC, "E"),
+    (0x13AE, "?"), (0x13B0, "\u{2C75}"), (0x13B1, "\u{393}"), (0x13B3, "W"), (0x13B7, "M"),
+    (0x13BB, "H"), (0x13BD, "Y"), (0x13BE, "O\u{335}"), (0x13BF, "\u{1AB}"), (0x13C0, "G"),
+    (0x13C2, "h"), (0x13C3, "Z"), (0x13C7, "\u{460}"), (0x13CB, "\u{190}"), (0x13CC, "U\u{335}"),
+    (0x13CE, "4"), (0x13CF, "b"), (0x13D2, "R"), (0x13D4, "W"), (0x13D5, "S"), (0x13D9, "V"),
+    (0x13DA, "S"), (0x13DE, "L"), (0x13DF, "C"), (0x13E2, "P"), (0x13E6, "K"), (0x13E7, "d"),
+    (0x13EB, "O\u{335}"), (0x13EE, "6"), (0x13F0, "\u{DF}"), (0x13F2, "h\u{314}"), (0x13F3, "G"),
+    (0x13F4, "B"), (0x13FB, "\u{262}"), (0x13FC, "\u{299}"), (0x1400, "="), (0x1403, "\u{394}"),
+    (0x140C, "\u{B7}\u{1401}"), (0x140D, "\u{1401}\u{B7}"), (0x140E, "\u{B7}\u{394}"),
+    (0x140F, "\u{394}\u{B7}"), (0x1410, "\u{B7}\u{1404}"), (0x1411, "\u{1404}\u{B7}"),
+    (0x1412, "\u{B7}\u{1405}"), (0x1413, "\u{1405}\u{B7}"), (0x1414, "\u{B7}\u{1406}"),
+    (0x1415, "\u{1406}\u{B7}"), (0x1417, "\u{B7}\u{140A}"), (0x1418, "\u{140A}\u{B7}"),
+    (0x1419, "\u{B7}\u{140B}"), (0x141A, "\u{140B}\u{B7}"), (0x1427, "\u{B7}"),
+    (0x142B, "\u{1401}\u{1420}"), (0x142C, "\u{394}\u{1420}"), (0x142D, "\u{1405}\u{1420}"),
+    (0x142E, "\u{140A}\u{1420}"), (0x142F, "V"), (0x1431, "\u{245}"), (0x1433, ">"),
+    (0x1437, "\u{B7}>"), (0x1438, "<"), (0x143A, "\u{B7}V"), (0x143B, "V\u{B7}"),
+    (0x143C, "\u{B7}\u{245}"), (0x143D, "\u{245}\u{B7}"), (0x143E, "\u{B7}\u{1432}"),
+    (0x143F, "\u{1432}\u{B7}"), (0x1440, "\u{B7}>"), (0x1441, ">\u{B7}"),
+    (0x1442, "\u{B7}\u{1434}"), (0x1443, "\u{1434}\u{B7}"), (0x1444, "\u{B7}<"),
+    (0x1445, "<\u{B7}"), (0x1446, "\u{B7}\u{1439}"), (0x1447, "\u{1439}\u{B7}"), (0x144A, "'"),
+    (0x144C, "U"), (0x144E, "\u{548}"), (0x1454, "\u{B7}\u{1450}"), (0x1457, "\u{B7}U"),
+    (0x1458, "U\u{B7}"), (0x1459, "\u{B7}\u{548}"), (0x145A, "\u{548}\u{B7}"),
+    (0x145B, "\u{B7}\u{144F}"), (0x145C, "\u{144F}\u{B7}"), (0x145D, "\u{B7}\u{1450}"),
+    (0x145E, "\u{1450}\u{B7}"), (0x145F, "\u{B7}\u{1451}"), (0x1460, "\u{1451}\u{B7}"),
+    (0x1461, "\u{B7}\u{1455}"), (0x1462, "\u{1455}\u{B7}"), (0x1463, "\u{B7}\u{1456}"),
+    (0x1464, "\u{1456}\u{B7}"), (0x1467, "U'"), (0x1468, "\u{548}'"), (0x1469, "\u{1450}'"),
+    (0x146A, "\u{1455}'"), (0x146D, "P"), (0x146F, "d"), (0x1472, "b"), (0x1473, "b\u{307}"),
+    (0x1474, "\u{B7}\u{146B}"), (0x1475, "\u{146B}\u{B7}"), (0x1476, "\u{B7}P"),
+    (0x1477, "p\u{B7}"), (0x1478, "\u{B7}\u{146E}"), (0x1479, "\u{146E}\u{B7}"),
+    (0x147A, "\u{B7}d"), (0x147B, "d\u{B7}"), (0x147C, "\u{B7}\u{1470}"),
+    (0x147D, "\u{1470}\u{B7}"), (0x147E, "\u{B7}b"), (0x147F, "b\u{B7}"),
+    (0x1480, "\u{B7}b\u{307}"), (0x1481, "b\u{307}\u{B7}"), (0x1485, "\u{146B}'"), (0x1486, "P'"),
+    (0x1487, "d'"), (0x1488, "b'"), (0x148D, "J"), (0x1492, "\u{B7}\u{1489}"),
+    (0x1493, "\u{1489}\u{B7}"), (0x1494, "\u{B7}\u{148B}"), (0x1495, "\u{148B}\u{B7}"),
+    (0x1496, "\u{B7}\u{148C}"), (0x1497, "\u{148C}\u{B7}"), (0x1498, "\u{B7}J"),
+    (0x1499, "J\u{B7}"), (0x149A, "\u{B7}\u{148E}"), (0x149B, "\u{148E}\u{B7}"),
+    (0x149C, "\u{B7}\u{1490}"), (0x149D, "\u{1490}\u{B7}"), (0x149E, "\u{B7}\u{1491}"),
+    (0x149F, "\u{1491}\u{B7}"), (0x14A5, "\u{393}"), (0x14AA, "L"), (0x14AC, "\u{B7}\u{14A3}"),
+    (0x14AD, "\u{14A3}\u{B7}"), (0x14AE, "\u{B7}\u{393}"), (0x14AF, "\u{393}\u{B7}"),
+    (0x14B0, "\u{B7}\u{14A6}"), (0x14B1, "\u{14A6}\u{B7}"), (0x14B2, "\u{B7}\u{14A7}"),
+    (0x14B3, "\u{14A7}\u{B7}"), (0x14B4, "\u{B7}\u{14A8}"), (0x14B5, "\u{14A8}\u{B7}"),
+    (0x14B6, "\u{B7}L"), (0x14B7, "l\u{B7}"), (0x14B8, "\u{B7}\u{14AB}"),
+    (0x14B9, "\u{14AB}\u{B7}"), (0x14BF, "2"), (0x14C9, "\u{B7}\u{14C0}"),
+    (0x14CA, "\u{14C0}\u{B7}"), (0x14CB, "\u{B7}\u{14C7}"), (0x14CC, "\u{14C7}\u{B7}"),
+    (0x14CD, "\u{B7}\u{14C8}"), (0x14CE, "\u{14C8}\u{B7}"), (0x14D1, "\u{1421}"),
+    (0x14DC, "\u{B7}\u{14D3}"), (0x14DD, "\u{14D3}\u{B7}"), (0x14DE, "\u{B7}\u{14D5}"),
+    (0x14DF, "\u{14D5}\u{B7}"), (0x14E0, "\u{B7}\u{14D6}"), (0x14E1, "\u{14D6}\u{B7}"),
+    (0x14E2, "\u{B7}\u{14D7}"), (0x14E3, "\u{14D7}\u{B7}"), (0x14E4, "\u{B7}\u{14D8}"),
+    (0x14E5, "\u{14D8}\u{B7}"), (0x14E6, "\u{B7}\u{14DA}"), (0x14E7, "\u{14DA}\u{B7}"),
+    (0x14E8, "\u{B7}\u{14DB}"), (0x14E9, "\u{14DB}\u{B7}"), (0x14F6, "\u{B7}\u{14ED}"),
+    (0x14F7, "\u{14ED}\u{B7}"), (0x14F8, "\u{B7}\u{14EF}"), (0x14F9, "\u{14EF}\u{B7}"),
+    (0x14FA, "\u{B7}\u{14F0}"), (0x14FB, "\u{14F0}\u{B7}"), (0x14FC, "\u{B7}\u{14F1}"),
+    (0x14FD, "\u{14F1}\u{B7}"), (0x14FE, "\u{B7}\u{14F2}"), (0x14FF, "\u{14F2}\u{B7}"),
+    (0x1500, "\u{B7}\u{14F4}"), (0x1501, "\u{14F4}\u{B7}"), (0x1502, "\u{B7}\u{14F5}"),
+    (0x1503, "\u{14F5}\u{B7}"), (0x150C, "\u{150B}<"), (0x150D, "\u{150B}\u{1455}"),
+    (0x150E, "\u{150B}b"), (0x150F, "\u{150B}\u{1490}"), (0x1517, "\u{B7}\u{1510}"),
+    (0x1518, "\u{1510}\u{B7}"), (0x1519, "\u{B7}\u{1511}"), (0x151A, "\u{1511}\u{B7}"),
+    (0x151B, "\u{B7}\u{1512}"), (0x151C, "\u{1512}\u{B7}"), (0x151D, "\u{B7}\u{1513}"),
+    (0x151E, "\u{1513}\u{B7}"), (0x151F, "\u{B7}\u{1514}"), (0x1520, "\u{1514}\u{B7}"),
+    (0x1521, "\u{B7}\u{1515}"), (0x1522, "\u{1515}\u{B7}"), (0x1523, "\u{B7}\u{1516}"),
+    (0x1524, "\u{1516}\u{B7}"), (0x152F, "\u{B7}4"), (0x1530, "4\u{B7}"),
+    (0x1531, "\u{B7}\u{1528}"), (0x1532, "\u{1528}\u{B7}"), (0x1533, "\u{B7}\u{1529}"),
+    (0x1534, "\u{1529}\u{B7}"), (0x1535, "\u{B7}\u{152A}"), (0x1536, "\u{152A}\u{B7}"),
+    (0x1537, "\u{B7}\u{152B}"), (0x1538, "\u{152B}\u{B7}"), (0x1539, "\u{B7}\u{152D}"),
+    (0x153A, "\u{152D}\u{B7}"), (0x153B, "\u{B7}\u{152E}"), (0x153C, "\u{152E}\u{B7}"),
+    (0x1540, "\u{1429}"), (0x1541, "x"), (0x154E, "\u{B7}\u{154C}"), (0x154F, "\u{154C}\u{B7}"),
+    (0x155B, "\u{B7}\u{155A}"), (0x155C, "\u{155A}\u{B7}"), (0x1568, "\u{B7}\u{1567}"),
+    (0x1569, "\u{1567}\u{B7}"), (0x1577, "\u{1E9F}"), (0x157C, "H"), (0x157D, "x"),
+    (0x157E, "\u{1550}\u{146C}"), (0x157F, "\u{1550}P"), (0x1580, "\u{1550}\u{146E}"),
+    (0x1581, "\u{1550}d"), (0x1582, "\u{1550}\u{1470}"), (0x1583, "\u{1550}b"),
+    (0x1584, "\u{1550}b\u{307}"), (0x1585, "\u{1550}\u{1483}"), (0x1587, "R"),
+    (0x158E, "\u{1595}\u{148A}"), (0x158F, "\u{1595}\u{148B}"), (0x1590, "\u{1595}\u{148C}"),
+    (0x1591, "\u{1595}J"), (0x1592, "\u{1595}\u{148E}"), (0x1593, "\u{1595}\u{1490}"),
+    (0x1594, "\u{1595}\u{1491}"), (0x15AF, "b"), (0x15B4, "F"), (0x15B5, "\u{2132}"),
+    (0x15B7, "\u{A7FB}"), (0x15C4, "\u{2C6F}"), (0x15C5, "A"), (0x15DE, "D"), (0x15EA, "D"),
+    (0x15EF, "\u{460}"), (0x15F0, "M"), (0x15F7, "B"), (0x1602, "\u{1490}"), (0x1603, "\u{1489}"),
+    (0x1604, "\u{14D3}"), (0x1607, "\u{14DA}"), (0x1622, "\u{1543}"), (0x1623, "\u{1546}"),
+    (0x1624, "\u{154A}"), (0x162E, "\u{1B1}"), (0x162F, "\u{3A9}"), (0x1634, "\u{1B1}"),
+    (0x1635, "\u{3A9}"), (0x166D, "X"), (0x166E, "x"), (0x166F, "\u{1550}\u{146B}"),
+    (0x1670, "\u{1595}\u{1489}"), (0x1671, "\u{1596}\u{148B}"), (0x1672, "\u{1596}\u{148C}"),
+    (0x1673, "\u{1596}J"), (0x1674, "\u{1596}\u{148E}"), (0x1675, "\u{1596}\u{1490}"),
+    (0x1676, "\u{1596}\u{1491}"), (0x1677, "\u{15A7}\u{B7}"), (0x1678, "\u{15A8}\u{B7}"),
+    (0x1679, "\u{15A9}\u{B7}"), (0x167A, "\u{15AA}\u{B7}"), (0x167B, "\u{15AB}\u{B7}"),
+    (0x167C, "\u{15AC}\u{B7}"), (0x167D, "\u{15AD}\u{B7}"), (0x1680, " "), (0x16B2, "<"),
+    (0x16B7, "X"), (0x16C1, "l"), (0x16C2, "\u{16BD}"), (0x16CC, "'"), (0x16D5, "K"), (0x16D6, "M"),
+    (0x16D8, "\u{3A8}"), (0x16E1, "\u{16BC}"), (0x16EB, "\u{B7}"), (0x16EC, ":"), (0x16ED, "+"),
+    (0x16F0, "\u{3A6}"), (0x1734, "\u{1715}"), (0x1735, "/"), (0x17A3, "\u{17A2}"),
+    (0x17B7, "\u{E34}"), (0x17B8, "\u{E35}"), (0x17B9, "\u{E36}"), (0x17BA, "\u{E37}"),
+    (0x17C6, "\u{30A}"), (0x17CB, "\u{E48}"), (0x17D3, "\u{30A}"), (0x17D4, "\u{E2F}"),
+    (0x17D5, "\u{E5A}"), (0x17D9, "\u{E4F}"), (0x17DA, "\u{E5B}"), (0x1803, ":"), (0x1809, ":"),
+    (0x1855, "\u{1835}"), (0x1896, "\u{185C}"), (0x18B3, "\u{B7}\u{18B1}"),
+    (0x18B6, "\u{B7}\u{18B4}"), (0x18B9, "\u{B7}\u{18B8}"), (0x18C2, "\u{B7}\u{18C0}"),
+    (0x18C6, "\u{B7}\u{14C2}"), (0x18C7, "\u{14C2}\u{B7}"), (0x18C8, "\u{B7}\u{14C3}"),
+    (0x18C9, "\u{14C3}\u{B7}"), (0x18CA, "\u{B7}\u{14C4}"), (0x18CB, "\u{14C4}\u{B7}"),
+    (0x18CC, "\u{B7}\u{14C5}"), (0x18CD, "\u{14C5}\u{B7}"), (0x18CE, "\u{B7}\u{1543}"),
+    (0x18CF, "\u{B7}\u{1546}"), (0x18D0, "\u{B7}\u{1547}"), (0x18D1, "\u{B7}\u{1548}"),
+    (0x18D2, "\u{B7}\u{1549}"), (0x18D3, "\u{B7}\u{154B}"), (0x18DB, "\u{18F5}"),
+    (0x18DC, "\u{18DF}\u{141E}"), (0x18DD, "\u{141E}\u{18DF}"), (0x18E0, "\u{1543}\u{B7}"),
+    (0x18E3, "\u{155E}\u{B7}"), (0x18E4, "\u{1566}\u{B7}"), (0x18E5, "\u{156B}\u{B7}"),
+    (0x18E8, "\u{1586}\u{B7}"), (0x18EA, "\u{1597}\u{B7}"), (0x18ED, "\u{460}\u{B7}"),
+    (0x18F0, "\u{15F4}\u{B7}"), (0x18F2, "\u{161B}\u{B7}"), (0x19D0, "\u{199E}"),
+    (0x19D1, "\u{19B1}"), (0x1A80, "\u{1A45}"), (0x1A90, "\u{1A45}"), (0x1AA9, "\u{1AA8}\u{1AA8}"),
+    (0x1AAB, "\u{1AAA}\u{1AA8}"), (0x1AB4, "\u{6DB}"), (0x1AB7, "\u{328}"), (0x1B52, "\u{1B0D}"),
+    (0x1B53, "\u{1B11}"), (0x1B58, "\u{1B28}"), (0x1B5C, "\u{1B50}"), (0x1B5F, "\u{1B5E}\u{1B5E}"),
+    (0x1C3C, "\u{1C3B}\u{1C3B}"), (0x1C7F, "\u{1C7E}\u{1C7E}"), (0x1CD0, "\u{302}"),
+    (0x1CD2, "\u{304}"), (0x1CD3, "''"), (0x1CD5, "\u{32B}"), (0x1CD8, "\u{32E}"),
+    (0x1CD9, "\u{32D}"), (0x1CDA, "\u{30E}"), (0x1CDC, "\u{329}"), (0x1CDD, "\u{323}"),
+    (0x1CDE, "\u{324}"), (0x1CED, "\u{316}"), (0x1D04, "c"), (0x1D08, "\u{25C}"),
+    (0x1D0B, "\u{138}"), (0x1D0D, "\u{28D}"), (0x1D0F, "o"), (0x1D10, "\u{254}"), (0x1D11, "o"),
+    (0x1D14, "\u{1DD}o"), (0x1D1C, "u"), (0x1D20, "v"), (0x1D21, "w"), (0x1D22, "z"),
+    (0x1D24, "\u{1A8}"), (0x1D26, "r"), (0x1D27, "\u{28C}"), (0x1D28, "\u{3C0}"),
+    (0x1D29, "\u{1D18}"), (0x1D2B, "\u{43B}"), (0x1D3E, "\u{18D6}"), (0x1D52, "\u{BA}"),
+    (0x1D6B, "ue"), (0x1D6E, "f\u{334}"), (0x1D6F, "rn\u{334}"), (0x1D70, "n\u{334}"),
+    (0x1D72, "r\u{334}"), (0x1D73, "\u{27E}\u{334}"), (0x1D74, "s\u{334}"), (0x1D75, "t\u{334}"),
+    (0x1D76, "z\u{334}"), (0x1D78, "\u{1D34}"), (0x1D7B, "i\u{335}"), (0x1D7C, "i\u{335}"),
+    (0x1D7D, "p\u{335}"), (0x1D7E, "u\u{335}"), (0x1D7F, "\u{28A}\u{335}"), (0x1D83, "g"),
+    (0x1D8C, "y"), (0x1D90, "\u{24B}"), (0x1D9F, "\u{1D4B}"), (0x1DA2, "\u{1D4D}"),
+    (0x1DBA, "\u{18D4}"), (0x1DBB, "\u{1646}"), (0x1DEE, "\u{2DEC}"), (0x1E43, "\u{AB51}"),
+    (0x1E9A, "\u{1EA3}"), (0x1E9D, "f"), (0x1E9E, "\u{DF}"), (0x1EFF, "y"), (0x1F7D, "\u{1FF4}"),
+    (0x1FBD, "'"), (0x1FBE, "i"), (0x1FBF, "'"), (0x1FC0, "~"), (0x1FEF, "'"), (0x1FF6, "\u{13EF}"),
+    (0x1FFD, "'"), (0x1FFE, "'"), (0x2000, " "), (0x2001, " "), (0x2002, " "), (0x2003, " "),
+    (0x2004, " "), (0x2005, " "), (0x2006, " "), (0x2007, " "), (0x2008, " "), (0x2009, " "),
+    (0x200A, " "), (0x2010, "-"), (0x2011, "-"), (0x2012, "-"), (0x2013, "-"), (0x2014, "\u{30FC}"),
+    (0x2015, "\u{30FC}"), (0x2016, "ll"), (0x2018, "'"), (0x2019, "'"), (0x201A, ","),
+    (0x201B, "'"), (0x201C, "''"), (0x201D, "''"), (0x201F, "''"), (0x2022, "\u{B7}"),
+    (0x2024, "."), (0x2025, ".."), (0x2026, "..."), (0x2027, "\u{B7}"), (0x2028, " "),
+    (0x2029, " "), (0x202F, " "), (0x2030, "\u{BA}/\u{2080}\u{2080}"),
+    (0x2031, "\u{BA}/\u{2080}\u{2080}\u{2080}"), (0x2032, "'"), (0x2033, "''"), (0x2034, "'''"),
+    (0x2035, "'"), (0x2036, "''"), (0x2037, "'''"), (0x2039, "<"), (0x203A, ">"), (0x203C, "!!"),
+    (0x203E, "\u{2C9}"), (0x2041, "/"), (0x2043, "-"), (0x2044, "/"), (0x2047, "??"),
+    (0x2048, "?!"), (0x2049, "!?"), (0x204E, "*"), (0x2052, "\u{BA}/\u{2080}"), (0x2053, "~"),
+    (0x2057, "''''"), (0x205A, ":"), (0x205D, "\u{2D57}"), (0x205E, "\u{2D42}"), (0x205F, " "),
+    (0x2070, "\u{BA}"), (0x2079, "\u{A770}"), (0x20A1, "C\u{20EB}"), (0x20A4, "\u{A3}"),
+    (0x20A5, "rn\u{338}"), (0x20A8, "Rs"), (0x20A9, "W\u{335}"), (0x20AB, "d\u{335}\u{331}"),
+    (0x20AC, "\u{A792}"), (0x20AD, "K\u{335}"), (0x20AE, "T\u{20EB}"), (0x20B6, "lt"),
+    (0x20BD, "\u{554}"), (0x20DB, "\u{6DB}"), (0x2100, "a/c"), (0x2101, "a/s"), (0x2102, "C"),
+    (0x2103, "\u{B0}C"), (0x2105, "c/o"), (0x2106, "c/u"), (0x2107, "\u{190}"), (0x2108, "\u{42D}"),
+    (0x2109, "\u{B0}F"), (0x210A, "g"), (0x210B, "H"), (0x210C, "H"), (0x210D, "H"), (0x210E, "h"),
+    (0x210F, "h\u{335}"), (0x2110, "l"), (0x2111, "l"), (0x2112, "L"), (0x2113, "l"), (0x2115, "N"),
+    (0x2116, "No"), (0x2119, "P"), (0x211A, "Q"), (0x211B, "R"), (0x211C, "R"), (0x211D, "R"),
+    (0x2121, "TEL"), (0x2124, "Z"), (0x2126, "\u{3A9}"), (0x2127, "\u{1B1}"), (0x2128, "Z"),
+    (0x2129, "\u{27F}"), (0x212A, "K"), (0x212C, "B"), (0x212D, "C"), (0x212E, "e"), (0x212F, "e"),
+    (0x2130, "E"), (0x2131, "F"), (0x2133, "M"), (0x2134, "o"), (0x2135, "\u{5D0}"),
+    (0x2136, "\u{5D1}"), (0x2137, "\u{5D2}"), (0x2138, "\u{5D3}"), (0x2139, "i"), (0x213B, "FAX"),
+    (0x213C, "\u{3C0}"), (0x213D, "y"), (0x213E, "\u{393}"), (0x213F, "\u{3A0}"),
+    (0x2140, "\u{1A9}"), (0x2141, "\u{A4E8}"), (0x2142, "\u{A4F6}"), (0x2143, "\u{16F00}"),
+    (0x2145, "D"), (0x2146, "d"), (0x2147, "e"), (0x2148, "i"), (0x2149, "j"), (0x2160, "l"),
+    (0x2161, "ll"), (0x2162, "lll"), (0x2163, "lV"), (0x2164, "V"), (0x2165, "Vl"), (0x2166, "Vll"),
+    (0x2167, "Vlll"), (0x2168, "lX"), (0x2169, "X"), (0x216A, "Xl"), (0x216B, "Xll"), (0x216C, "L"),
+    (0x216D, "C"), (0x216E, "D"), (0x216F, "M"), (0x2170, "i"), (0x2171, "ii"), (0x2172, "iii"),
+    (0x2173, "iv"), (0x2174, "v"), (0x2175, "vi"), (0x2176, "vii"), (0x2177, "viii"),
+    (0x2178, "ix"), (0x2179, "x"), (0x217A, "xi"), (0x217B, "xii"), (0x217C, "l"), (0x217D, "c"),
+    (0x217E, "d"), (0x217F, "rn"), (0x2183, "\u{186}"), (0x2184, "\u{254}"), (0x2191, "\u{16CF}"),
+    (0x2195, "\u{16E8}"), (0x21B5, "\u{21B2}"), (0x21BA, "\u{1F10E}"), (0x21BE, "\u{16DA}"),
+    (0x21BF, "\u{16D0}"), (0x2200, "\u{2C6F}"), (0x2203, "\u{18E}"), (0x2206, "\u{394}"),
+    (0x220F, "\u{3A0}"), (0x2211, "\u{1A9}"), (0x2212, "-"), (0x2214, "+\u{307}"), (0x2215, "/"),
+    (0x2216, "\u{5C}"), (0x2217, "*"), (0x2218, "\u{B0}"), (0x2219, "\u{B7}"), (0x221E, "oo"),
+    (0x2223, "l"), (0x2225, "ll"), (0x2228, "v"), (0x2229, "\u{548}"), (0x222A, "U"),
+    (0x222B, "\u{283}"), (0x222C, "\u{283}\u{283}"), (0x222D, "\u{283}\u{283}\u{283}"),
+    (0x222F, "\u{222E}\u{222E}"), (0x2230, "\u{222E}\u{222E}\u{222E}"), (0x2236, ":"),
+    (0x2238, "-\u{307}"), (0x223C, "~"), (0x2250, "=\u{307}"), (0x2251, "=\u{307}\u{323}"),
+    (0x2257, "=\u{30A}"), (0x2259, "=\u{302}"), (0x225A, "=\u{306}"), (0x225E, "=\u{36B}"),
+    (0x2263, "\u{2261}"), (0x226A, "<<"), (0x226B, ">>"), (0x2282, "\u{1455}"),
+    (0x2283, "\u{1450}"), (0x2295, "\u{102A8}"), (0x2296, "O\u{335}"), (0x2299, "\u{298}"),
+    (0x229D, "O\u{335}"), (0x22A4, "T"), (0x22A5, "\u{A4D5}"), (0x22C0, "\u{2227}"), (0x22C1, "v"),
+    (0x22C2, "\u{548}"), (0x22C3, "U"), (0x22C4, "\u{16DC}"), (0x22C5, "\u{B7}"),
+    (0x22C8, "\u{16DE}"), (0x22D6, "<\u{B7}"), (0x22D7, "\u{B7}>"), (0x22D8, "<<<"),
+    (0x22D9, ">>>"), (0x22EE, "\u{2D57}"), (0x22EF, "\u{B7}\u{B7}\u{B7}"), (0x22F4, "\u{A793}"),
+    (0x22FF, "E"), (0x2300, "\u{2205}"), (0x2325, "\u{2324}"), (0x2329, "\u{276C}"),
+    (0x232A, "\u{276D}"), (0x2341, "\u{303C}"), (0x2359, "\u{394}\u{332}"),
+    (0x235A, "\u{16DC}\u{332}"), (0x235C, "\u{B0}\u{332}"), (0x235F, "\u{229B}"),
+    (0x2361, "T\u{308}"), (0x2362, "\u{2207}\u{308}"), (0x2363, "\u{22C6}\u{308}"),
+    (0x2364, "\u{B0}\u{308}"), (0x2365, "\u{629}"), (0x2368, "~\u{308}"), (0x2369, "\u{1435}"),
+    (0x236B, "\u{2207}\u{334}"), (0x236C, "O\u{335}"), (0x2373, "i"), (0x2374, "p"),
+    (0x2375, "\u{3C9}"), (0x2376, "a\u{332}"), (0x2377, "\u{A793}\u{332}"), (0x2378, "i\u{332}"),
+    (0x2379, "\u{3C9}\u{332}"), (0x237A, "a"), (0x237F, "\u{16BD}"), (0x239C, "\u{4E28}"),
+    (0x239F, "\u{4E28}"), (0x23A2, "\u{4E28}"), (0x23A5, "\u{4E28}"), (0x23AA, "\u{4E28}"),
+    (0x23AE, "\u{4E28}"), (0x23C1, "\u{2355}"), (0x23C2, "\u{234E}"), (0x23C3, "\u{234B}"),
+    (0x23C6, "\u{236D}"), (0x23E8, "\u{2081}\u{2080}"), (0x23FC, "\u{23FB}"), (0x23FD, "l"),
+    (0x23FE, "\u{263E}"), (0x244A, "\u{5C}\u{5C}"), (0x2460, "\u{2780}"), (0x2461, "\u{2781}"),
+    (0x2462, "\u{2782}"), (0x2463, "\u{2783}"), (0x2464, "\u{2784}"), (0x2465, "\u{2785}"),
+    (0x2466, "\u{2786}"), (0x2467, "\u{2787}"), (0x2468, "\u{2788}"), (0x2469, "\u{2789}"),
+    (0x2474, "(l)"), (0x2475, "(2)"), (0x2476, "(3)"), (0x2477, "(4)"), (0x2478, "(5)"),
+    (0x2479, "(6)"), (0x247A, "(7)"), (0x247B, "(8)"), (0x247C, "(9)"), (0x247D, "(lO)"),
+    (0x247E, "(ll)"), (0x247F, "(l2)"), (0x2480, "(l3)"), (0x2481, "(l4)"), (0x2482, "(l5)"),
+    (0x2483, "(l6)"), (0x2484, "(l7)"), (0x2485, "(l8)"), (0x2486, "(l9)"), (0x2487, "(2O)"),
+    (0x2488, "l."), (0x2489, "2."), (0x248A, "3."), (0x248B, "4."), (0x248C, "5."), (0x248D, "6."),
+    (0x248E, "7."), (0x248F, "8."), (0x2490, "9."), (0x2491, "lO."), (0x2492, "ll."),
+    (0x2493, "l2."), (0x2494, "l3."), (0x2495, "l4."), (0x2496, "l5."), (0x2497, "l6."),
+    (0x2498, "l7."), (0x2499, "l8."), (0x249A, "l9."), (0x249B, "2O."), (0x249C, "(a)"),
+    (0x249D, "(b)"), (0x249E, "(c)"), (0x249F, "(d)"), (0x24A0, "(e)"), (0x24A1, "(f)"),
+    (0x24A2, "(g)"), (0x24A3, "(h)"), (0x24A4, "(i)"), (0x24A5, "(j)"), (0x24A6, "(k)"),
+    (0x24A7, "(l)"), (0x24A8, "(rn)"), (0x24A9, "(n)"), (0x24AA, "(o)"), (0x24AB, "(p)"),
+    (0x24AC, "(q)"), (0x24AD, "(r)"), (0x24AE, "(s)"), (0x24AF, "(t)"), (0x24B0, "(u)"),
+    (0x24B1, "(v)"), (0x24B2, "(w)"), (0x24B3, "(x)"), (0x24B4, "(y)"), (0x24B5, "(z)"),
+    (0x24B8, "\u{A9}"), (0x24C5, "\u{2117}"), (0x24C7, "\u{AE}"), (0x24DB, "\u{24BE}"),
+    (0x24EA, "\u{1F10D}"), (0x2500, "\u{30FC}"), (0x2501, "\u{30FC}"), (0x2503, "\u{2502}"),
+    (0x250F, "\u{250C}"), (0x2523, "\u{251C}"), (0x2571, "/"), (0x2573, "X"), (0x2588, "\u{220E}"),
+    (0x2590, "\u{258C}"), (0x2594, "\u{2C9}"), (0x2597, "\u{2596}"), (0x259D, "\u{2598}"),
+    (0x25A0, "\u{220E}"), (0x25B1, "\u{23E5}"), (0x25B3, "\u{394}"), (0x25B7, "\u{22B3}"),
+    (0x25B8, "\u{25B6}"), (0x25BA, "\u{25B6}"), (0x25BD, "\u{102BC}"), (0x25C1, "\u{22B2}"),
+    (0x25C7, "\u{16DC}"), (0x25CA, "\u{16DC}"), (0x25CB, "\u{B0}"), (0x25CE, "\u{233E}"),
+    (0x25E0, "\u{2312}"), (0x25E6, "\u{B0}"), (0x2609, "\u{298}"), (0x2610, "\u{25A1}"),
+    (0x2625, "\u{1099E}"), (0x2630, "\u{2CB6}"), (0x2638, "\u{2388}"), (0x264E, "\u{224F}"),
+    (0x2662, "\u{16DC}"), (0x2669, "\u{1D158}\u{1D165}"), (0x266A, "\u{1D158}\u{1D165}\u{1D16E}"),
+    (0x26AC, "\u{970}"), (0x2768, "("), (0x2769, ")"), (0x276E, "<"), (0x276F, ">"), (0x2772, "("),
+    (0x2773, ")"), (0x2774, "{"), (0x2775, "}"), (0x2795, "+"), (0x2796, "-"), (0x2797, "\u{F7}"),
+    (0x27C2, "\u{A4D5}"), (0x27C8, "\u{5C}\u{1455}"), (0x27C9, "\u{1450}/"), (0x27CB, "/"),
+    (0x27CD, "\u{5C}"), (0x27D9, "T"), (0x27E8, "\u{276C}"), (0x27E9, "\u{276D}"), (0x292B, "x"),
+    (0x292C, "x"), (0x2963, "\u{16D0}\u{16DA}"), (0x2965, "\u{21C3}\u{21C2}"),
+    (0x296E, "\u{16D0}\u{21C2}"), (0x296F, "\u{21C3}\u{16DA}"), (0x2999, "\u{2D42}"),
+    (0x29B0, "\u{2349}"), (0x29BE, "\u{233E}"), (0x29C4, "\u{303C}"), (0x29C5, "\u{2342}"),
+    (0x29C7, "\u{233B}"), (0x29D6, "\u{102C0}"), (0x29D9, "\u{299A}"), (0x29F4, ":\u{2192}"),
+    (0x29F5, "\u{5C}"), (0x29F6, "/\u{304}"), (0x29F8, "/"), (0x29F9, "\u{5C}"),
+    (0x2A00, "\u{298}"), (0x2A01, "\u{102A8}"), (0x2A02, "\u{2297}"), (0x2A03, "\u{228D}"),
+    (0x2A04, "\u{228E}"), (0x2A05, "\u{2293}"), (0x2A06, "\u{2294}"),
+    (0x2A0C, "\u{283}\u{283}\u{283}\u{283}"), (0x2A1D, "\u{16DE}"), (0x2A20, ">>"),
+    (0x2A21, "\u{16DA}"), (0x2A22, "+\u{30A}"), (0x2A23, "+\u{302}"), (0x2A24, "+\u{303}"),
+    (0x2A25, "+\u{323}"), (0x2A26, "+\u{330}"), (0x2A27, "+\u{2082}"), (0x2A29, "-\u{313}"),
+    (0x2A2A, "-\u{323}"), (0x2A2F, "x"), (0x2A30, "x\u{307}"), (0x2A3D, "\u{2319}"),
+    (0x2A3E, "\u{2A1F}"), (0x2A3F, "\u{2210}"), (0x2A6A, "~\u{307}"), (0x2A6E, "=\u{20F0}"),
+    (0x2A74, "::="), (0x2A75, "=="), (0x2A76, "==="), (0x2AA5, "><"), (0x2AAA, "\u{15D5}"),
+    (0x2AAB, "\u{15D2}"), (0x2AD7, "\u{1450}\u{1455}"), (0x2AFB, "///"), (0x2AFD, "//"),
+    (0x2BEC, "\u{219E}"), (0x2BED, "\u{219F}"), (0x2BEE, "\u{21A0}"), (0x2BEF, "\u{21A1}"),
+    (0x2C67, "H\u{329}"), (0x2C69, "K\u{329}"), (0x2C84, "\u{393}"), (0x2C85, "r"),
+    (0x2C86, "\u{394}"), (0x2C88, "\u{A792}"), (0x2C89, "\u{A793}"), (0x2C8E, "H"), (0x2C92, "l"),
+    (0x2C94, "K"), (0x2C95, "\u{138}"), (0x2C96, "\u{3BB}"), (0x2C98, "M"), (0x2C9A, "N"),
+    (0x2C9E, "O"), (0x2C9F, "o"), (0x2CA0, "\u{3A0}"), (0x2CA2, "P"), (0x2CA3, "p"), (0x2CA4, "C"),
+    (0x2CA5, "c"), (0x2CA6, "T"), (0x2CA8, "Y"), (0x2CAA, "\u{3A6}"), (0x2CAB, "\u{278}"),
+    (0x2CAC, "X"), (0x2CAD, "\u{3C7}"), (0x2CAE, "\u{3A8}"), (0x2CB1, "\u{3C9}"),
+    (0x2CB4, "<\u{B7}"), (0x2CBA, "-"), (0x2CBC, "\u{428}"), (0x2CBD, "\u{448}"), (0x2CC6, "/"),
+    (0x2CCA, "9"), (0x2CCC, "3"), (0x2CCD, "\u{21D}"), (0x2CD0, "L"), (0x2CD1, "\u{29F}"),
+    (0x2CD2, "6"), (0x2CDC, "\u{3EC}"), (0x2CE4, "\u{3D7}"), (0x2CE9, "\u{2627}"),
+    (0x2CF9, "\u{5C}\u{5C}"), (0x2D31, "O\u{335}"), (0x2D37, "\u{245}"), (0x2D38, "V"),
+    (0x2D39, "E"), (0x2D3A, "\u{18E}"), (0x2D41, "O\u{338}"), (0x2D48, "\u{B7}\u{B7}\u{B7}"),
+    (0x2D49, "\u{1A9}"), (0x2D4F, "l"), (0x2D51, "!"), (0x2D54, "O"), (0x2D55, "Q"),
+    (0x2D59, "\u{298}"), (0x2D5D, "X"), (0x2D60, "\u{394}"), (0x2D63, "\u{16EF}"),
+    (0x2DE8, "\u{1DDF}"), (0x2DEA, "\u{30A}"), (0x2DED, "\u{368}"), (0x2DEF, "\u{36F}"),
+    (0x2DF6, "\u{363}"), (0x2DF7, "\u{364}"), (0x2E1A, "-\u{308}"), (0x2E1E, "~\u{307}"),
+    (0x2E1F, "~\u{323}"), (0x2E26, "\u{1455}"), (0x2E27, "\u{1450}"), (0x2E28, "(("),
+    (0x2E29, "))"), (0x2E2A, "\u{2235}"), (0x2E2B, "\u{2234}"), (0x2E2C, "\u{2237}"),
+    (0x2E2E, "\u{61F}"), (0x2E30, "\u{B0}"), (0x2E31, "\u{B7}"), (0x2E32, "\u{60C}"),
+    (0x2E35, "\u{61B}"), (0x2E39, "\u{1E9F}"), (0x2E3D, "\u{2D42}"), (0x2E3F, "\u{B6}"),
+    (0x2E40, "="), (0x2E82, "\u{4E5B}"), (0x2E83, "\u{4E5A}"), (0x2E85, "\u{4EBB}"),
+    (0x2E89, "\u{5202}"), (0x2E8B, "\u{353E}"), (0x2E8E, "\u{5140}"), (0x2E8F, "\u{5C23}"),
+    (0x2E90, "\u{5C22}"), (0x2E92, "\u{5DF3}"), (0x2E93, "\u{5E7A}"), (0x2E94, "\u{5F51}"),
+    (0x2E96, "\u{5FC4}"), (0x2E97, "\u{38FA}"), (0x2E98, "\u{624C}"), (0x2E99, "\u{6535}"),
+    (0x2E9B, "\u{65E1}"), (0x2E9E, "\u{6B7A}"), (0x2E9F, "\u{6BCD}"), (0x2EA0, "\u{6C11}"),
+    (0x2EA1, "\u{6C35}"), (0x2EA2, "\u{6C3A}"), (0x2EA3, "\u{706C}"), (0x2EA4, "\u{722B}"),
+    (0x2EA6, "\u{4E2C}"), (0x2EA8, "\u{72AD}"), (0x2EAB, "\u{7F52}"), (0x2EAD, "\u{793B}"),
+    (0x2EAF, "\u{7CF9}"), (0x2EB1, "\u{7F53}"), (0x2EB2, "\u{7F52}"), (0x2EB9, "\u{8002}"),
+    (0x2EBA, "\u{8080}"), (0x2EBE, "\u{8279}"), (0x2EBF, "\u{8279}"), (0x2EC0, "\u{8279}"),
+    (0x2EC1, "\u{864E}"), (0x2EC2, "\u{8864}"), (0x2EC3, "\u{8980}"), (0x2EC4, "\u{897F}"),
+    (0x2EC5, "\u{89C1}"), (0x2EC8, "\u{8BA0}"), (0x2EC9, "\u{8D1D}"), (0x2ECB, "\u{8F66}"),
+    (0x2ECC, "\u{8FB6}"), (0x2ECD, "\u{8FB6}"), (0x2ECF, "\u{961D}"), (0x2ED0, "\u{9485}"),
+    (0x2ED1, "\u{9577}"), (0x2ED2, "\u{9578}"), (0x2ED3, "\u{957F}"), (0x2ED4, "\u{95E8}"),
+    (0x2ED6, "\u{961D}"), (0x2ED8, "\u{9752}"), (0x2ED9, "\u{97E6}"), (0x2EDA, "\u{9875}"),
+    (0x2EDB, "\u{98CE}"), (0x2EDC, "\u{98DE}"), (0x2EDD, "\u{98DF}"), (0x2EDF, "\u{98E0}"),
+    (0x2EE0, "\u{9963}"), (0x2EE2, "\u{9A6C}"), (0x2EE4, "\u{9B3C}"), (0x2EE5, "\u{9C7C}"),
+    (0x2EE8, "\u{9EA6}"), (0x2EE9, "\u{9EC4}"), (0x2EEB, "\u{6589}"), (0x2EEC, "\u{9F50}"),
+    (0x2EED, "\u{6B6F}"), (0x2EEE, "\u{9F7F}"), (0x2EEF, "\u{7ADC}"), (0x2EF0, "\u{9F99}"),
+    (0x2EF2, "\u{4E80}"), (0x2EF3, "\u{9F9F}"), (0x2F00, "\u{30FC}"), (0x2F01, "\u{4E28}"),
+    (0x2F02, "\u{5C}"), (0x2F03, "/"), (0x2F04, "\u{4E59}"), (0x2F05, "\u{4E85}"),
+    (0x2F06, "\u{4E8C}"), (0x2F07, "\u{4EA0}"), (0x2F08, "\u{4EBA}"), (0x2F09, "\u{513F}"),
+    (0x2F0A, "\u{5165}"), (0x2F0B, "\u{516B}"), (0x2F0C, "\u{5182}"), (0x2F0D, "\u{5196}"),
+    (0x2F0E, "\u{51AB}"), (0x2F0F, "\u{51E0}"), (0x2F10, "\u{51F5}"), (0x2F11, "\u{5200}"),
+    (0x2F12, "\u{529B}"), (0x2F13, "\u{52F9}"), (0x2F14, "\u{5315}"), (0x2F15, "\u{531A}"),
+    (0x2F16, "\u{5338}"), (0x2F17, "\u{5341}"), (0x2F18, "\u{535C}"), (0x2F19, "\u{5369}"),
+    (0x2F1A, "\u{5382}"), (0x2F1B, "\u{53B6}"), (0x2F1C, "\u{53C8}"), (0x2F1D, "\u{53E3}"),
+    (0x2F1E, "\u{53E3}"), (0x2F1F, "\u{571F}"), (0x2F20, "\u{571F}"), (0x2F21, "\u{5902}"),
+    (0x2F22, "\u{590A}"), (0x2F23, "\u{5915}"), (0x2F24, "\u{5927}"), (0x2F25, "\u{5973}"),
+    (0x2F26, "\u{5B50}"), (0x2F27, "\u{5B80}"), (0x2F28, "\u{5BF8}"), (0x2F29, "\u{5C0F}"),
+    (0x2F2A, "\u{5C22}"), (0x2F2B, "\u{5C38}"), (0x2F2C, "\u{5C6E}"), (0x2F2D, "\u{5C71}"),
+    (0x2F2E, "\u{5DDB}"), (0x2F2F, "\u{5DE5}"), (0x2F30, "\u{5DF1}"), (0x2F31, "\u{5DFE}"),
+    (0x2F32, "\u{5E72}"), (0x2F33, "\u{5E7A}"), (0x2F34, "\u{5E7F}"), (0x2F35, "\u{5EF4}"),
+    (0x2F36, "\u{5EFE}"), (0x2F37, "\u{5F0B}"), (0x2F38, "\u{5F13}"), (0x2F39, "\u{5F50}"),
+    (0x2F3A, "\u{5F61}"), (0x2F3B, "\u{5F73}"), (0x2F3C, "\u{5FC3}"), (0x2F3D, "\u{6208}"),
+    (0x2F3E, "\u{6236}"), (0x2F3F, "\u{624B}"), (0x2F40, "\u{652F}"), (0x2F41, "\u{6534}"),
+    (0x2F42, "\u{6587}"), (0x2F43, "\u{6597}"), (0x2F44, "\u{65A4}"), (0x2F45, "\u{65B9}"),
+    (0x2F46, "\u{65E0}"), (0x2F47, "\u{65E5}"), (0x2F48, "\u{66F0}"), (0x2F49, "\u{6708}"),
+    (0x2F4A, "\u{6728}"), (0x2F4B, "\u{6B20}"), (0x2F4C, "\u{6B62}"), (0x2F4D, "\u{6B79}"),
+    (0x2F4E, "\u{6BB3}"), (0x2F4F, "\u{6BCB}"), (0x2F50, "\u{6BD4}"), (0x2F51, "\u{6BDB}"),
+    (0x2F52, "\u{6C0F}"), (0x2F53, "\u{6C14}"), (0x2F54, "\u{6C34}"), (0x2F55, "\u{706B}"),
+    (0x2F56, "\u{722A}"), (0x2F57, "\u{7236}"), (0x2F58, "\u{723B}"), (0x2F59, "\u{723F}"),
+    (0x2F5A, "\u{7247}"), (0x2F5B, "\u{7259}"), (0x2F5C, "\u{725B}"), (0x2F5D, "\u{72AC}"),
+    (0x2F5E, "\u{7384}"), (0x2F5F, "\u{7389}"), (0x2F60, "\u{74DC}"), (0x2F61, "\u{74E6}"),
+    (0x2F62, "\u{7518}"), (0x2F63, "\u{751F}"), (0x2F64, "\u{7528}"), (0x2F65, "\u{7530}"),
+    (0x2F66, "\u{758B}"), (0x2F67, "\u{7592}"), (0x2F68, "\u{7676}"), (0x2F69, "\u{767D}"),
+    (0x2F6A, "\u{76AE}"), (0x2F6B, "\u{76BF}"), (0x2F6C, "\u{76EE}"), (0x2F6D, "\u{77DB}"),
+    (0x2F6E, "\u{77E2}"), (0x2F6F, "\u{77F3}"), (0x2F70, "\u{793A}"), (0x2F71, "\u{79B8}"),
+    (0x2F72, "\u{79BE}"), (0x2F73, "\u{7A74}"), (0x2F74, "\u{7ACB}"), (0x2F75, "\u{7AF9}"),
+    (0x2F76, "\u{7C73}"), (0x2F77, "\u{7CF8}"), (0x2F78, "\u{7F36}"), (0x2F79, "\u{7F51}"),
+    (0x2F7A, "\u{7F8A}"), (0x2F7B, "\u{7FBD}"), (0x2F7C, "\u{8001}"), (0x2F7D, "\u{800C}"),
+    (0x2F7E, "\u{8012}"), (0x2F7F, "\u{8033}"), (0x2F80, "\u{807F}"), (0x2F81, "\u{8089}"),
+    (0x2F82, "\u{81E3}"), (0x2F83, "\u{81EA}"), (0x2F84, "\u{81F3}"), (0x2F85, "\u{81FC}"),
+    (0x2F86, "\u{820C}"), (0x2F87, "\u{821B}"), (0x2F88, "\u{821F}"), (0x2F89, "\u{826E}"),
+    (0x2F8A, "\u{8272}"), (0x2F8B, "\u{8278}"), (0x2F8C, "\u{864D}"), (0x2F8D, "\u{866B}"),
+    (0x2F8E, "\u{8840}"), (0x2F8F, "\u{884C}"), (0x2F90, "\u{8863}"), (0x2F91, "\u{897E}"),
+    (0x2F92, "\u{898B}"), (0x2F93, "\u{89D2}"), (0x2F94, "\u{8A00}"), (0x2F95, "\u{8C37}"),
+    (0x2F96, "\u{8C46}"), (0x2F97, "\u{8C55}"), (0x2F98, "\u{8C78}"), (0x2F99, "\u{8C9D}"),
+    (0x2F9A, "\u{8D64}"), (0x2F9B, "\u{8D70}"), (0x2F9C, "\u{8DB3}"), (0x2F9D, "\u{8EAB}"),
+    (0x2F9E, "\u{8ECA}"), (0x2F9F, "\u{8F9B}"), (0x2FA0, "\u{8FB0}"), (0x2FA1, "\u{8FB5}"),
+    (0x2FA2, "\u{9091}"), (0x2FA3, "\u{9149}"), (0x2FA4, "\u{91C6}"), (0x2FA5, "\u{91CC}"),
+    (0x2FA6, "\u{91D1}"), (0x2FA7, "\u{9577}"), (0x2FA8, "\u{9580}"), (0x2FA9, "\u{961C}"),
+    (0x2FAA, "\u{96B6}"), (0x2FAB, "\u{96B9}"), (0x2FAC, "\u{96E8}"), (0x2FAD, "\u{9751}"),
+    (0x2FAE, "\u{975E}"), (0x2FAF, "\u{9762}"), (0x2FB0, "\u{9769}"), (0x2FB1, "\u{97CB}"),
+    (0x2FB2, "\u{97ED}"), (0x2FB3, "\u{97F3}"), (0x2FB4, "\u{9801}"), (0x2FB5, "\u{98A8}"),
+    (0x2FB6, "\u{98DB}"), (0x2FB7, "\u{98DF}"), (0x2FB8, "\u{9996}"), (0x2FB9, "\u{9999}"),
+    (0x2FBA, "\u{99AC}"), (0x2FBB, "\u{9AA8}"), (0x2FBC, "\u{9AD8}"), (0x2FBD, "\u{9ADF}"),
+    (0x2FBE, "\u{9B25}"), (0x2FBF, "\u{9B2F}"), (0x2FC0, "\u{9B32}"), (0x2FC1, "\u{9B3C}"),
+    (0x2FC2, "\u{9B5A}"), (0x2FC3, "\u{9CE5}"), (0x2FC4, "\u{9E75}"), (0x2FC5, "\u{9E7F}"),
+    (0x2FC6, "\u{9EA5}"), (0x2FC7, "\u{9EBB}"), (0x2FC8, "\u{9EC3}"), (0x2FC9, "\u{9ECD}"),
+    (0x2FCA, "\u{9ED1}"), (0x2FCB, "\u{9EF9}"), (0x2FCC, "\u{9EFD}"), (0x2FCD, "\u{9F0E}"),
+    (0x2FCE, "\u{9F13}"), (0x2FCF, "\u{9F20}"), (0x2FD0, "\u{9F3B}"), (0x2FD1, "\u{9F4A}"),
+    (0x2FD2, "\u{9F52}"), (0x2FD3, "\u{9F8D}"), (0x2FD4, "\u{9F9C}"), (0x2FD5, "\u{9FA0}"),
+    (0x3002, "\u{2F3}"), (0x3003, "''"), (0x3007, "O"), (0x3008, "\u{276C}"), (0x3009, "\u{276D}"),
+    (0x3012, "\u{20B8}"), (0x3014, "("), (0x3015, ")"), (0x301A, "\u{27E6}"), (0x301B, "\u{27E7}"),
+    (0x302C, "\u{309}"), (0x302D, "\u{325}"), (0x3033, "/"), (0x3036, "\u{20B8}"),
+    (0x3038, "\u{5341}"), (0x3039, "\u{5344}"), (0x303A, "\u{5345}"), (0x304F, "\u{276C}"),
+    (0x309A, "\u{30A}"), (0x309B, "\u{FF9E}"), (0x309C, "\u{FF9F}"), (0x30A0, "="),
+    (0x30A4, "\u{4EBB}"), (0x30A8, "\u{5DE5}"), (0x30AB, "\u{529B}"), (0x30BF, "\u{5915}"),
+    (0x30C8, "\u{535C}"), (0x30CB, "\u{4E8C}"), (0x30CE, "/"), (0x30CF, "\u{516B}"),
+    (0x30D8, "\u{3078}"), (0x30ED, "\u{53E3}"), (0x30FB, "\u{B7}"), (0x3126, "\u{513F}"),
+    (0x3131, "\u{1100}"), (0x3132, "\u{1100}\u{1100}"), (0x3133, "\u{1100}\u{1109}"),
+    (0x3134, "\u{1102}"), (0x3135, "\u{1102}\u{110C}"), (0x3136, "\u{1102}\u{1112}"),
+    (0x3137, "\u{1103}"), (0x3138, "\u{1103}\u{1103}"), (0x3139, "\u{1105}"),
+    (0x313A, "\u{1105}\u{1100}"), (0x313B, "\u{1105}\u{1106}"), (0x313C, "\u{1105}\u{1107}"),
+    (0x313D, "\u{1105}\u{1109}"), (0x313E, "\u{1105}\u{1110}"), (0x313F, "\u{1105}\u{1111}"),
+    (0x3140, "\u{1105}\u{1112}"), (0x3141, "\u{1106}"), (0x3142, "\u{1107}"),
+    (0x3143, "\u{1107}\u{1107}"), (0x3144, "\u{1107}\u{1109}"), (0x3145, "\u{1109}"),
+    (0x3146, "\u{1109}\u{1109}"), (0x3147, "\u{110B}"), (0x3148, "\u{110C}"),
+    (0x3149, "\u{110C}\u{110C}"), (0x314A, "\u{110E}"), (0x314B, "\u{110F}"), (0x314C, "\u{1110}"),
+    (0x314D, "\u{1111}"), (0x314E, "\u{1112}"), (0x314F, "\u{1161}"), (0x3150, "\u{1161}\u{4E28}"),
+    (0x3151, "\u{1163}"), (0x3152, "\u{1163}\u{4E28}"), (0x3153, "\u{1165}"),
+    (0x3154, "\u{1165}\u{4E28}"), (0x3155, "\u{1167}"), (0x3156, "\u{1167}\u{4E28}"),
+    (0x3157, "\u{1169}"), (0x3158, "\u{1169}\u{1161}"), (0x3159, "\u{1169}\u{1161}\u{4E28}"),
+    (0x315A, "\u{1169}\u{4E28}"), (0x315B, "\u{116D}"), (0x315C, "\u{116E}"),
+    (0x315D, "\u{116E}\u{1165}"), (0x315E, "\u{116E}\u{1165}\u{4E28}"),
+    (0x315F, "\u{116E}\u{4E28}"), (0x3160, "\u{1172}"), (0x3161, "\u{30FC}"),
+    (0x3162, "\u{30FC}\u{4E28}"), (0x3163, "\u{4E28}"), (0x3164, "\u{1160}"),
+    (0x3165, "\u{1102}\u{1102}"), (0x3166, "\u{1102}\u{1103}"), (0x3167, "\u{1102}\u{1109}"),
+    (0x3168, "\u{1102}\u{1140}"), (0x3169, "\u{1105}\u{1100}\u{1109}"),
+    (0x316A, "\u{1105}\u{1103}"), (0x316B, "\u{1105}\u{1107}\u{1109}"),
+    (0x316C, "\u{1105}\u{1140}"), (0x316D, "\u{1105}\u{1159}"), (0x316E, "\u{1106}\u{1107}"),
+    (0x316F, "\u{1106}\u{1109}"), (0x3170, "\u{1106}\u{1140}"), (0x3171, "\u{1106}\u{110B}"),
+    (0x3172, "\u{1107}\u{1100}"), (0x3173, "\u{1107}\u{1103}"),
+    (0x3174, "\u{1107}\u{1109}\u{1100}"), (0x3175, "\u{1107}\u{1109}\u{1103}"),
+    (0x3176, "\u{1107}\u{110C}"), (0x3177, "\u{1107}\u{1110}"), (0x3178, "\u{1107}\u{110B}"),
+    (0x3179, "\u{1107}\u{1107}\u{110B}"), (0x317A, "\u{1109}\u{1100}"),
+    (0x317B, "\u{1109}\u{1102}"), (0x317C, "\u{1109}\u{1103}"), (0x317D, "\u{1109}\u{1107}"),
+    (0x317E, "\u{1109}\u{110C}"), (0x317F, "\u{1140}"), (0x3180, "\u{110B}\u{110B}"),
+    (0x3181, "\u{114C}"), (0x3182, "\u{110B}\u{1109}"), (0x3183, "\u{110B}\u{1140}"),
+    (0x3184, "\u{1111}\u{110B}"), (0x3185, "\u{1112}\u{1112}"), (0x3186, "\u{1159}"),
+    (0x3187, "\u{116D}\u{1163}"), (0x3188, "\u{116D}\u{1163}\u{4E28}"),
+    (0x3189, "\u{116D}\u{4E28}"), (0x318A, "\u{1172}\u{1167}"),
+    (0x318B, "\u{1172}\u{1167}\u{4E28}"), (0x318C, "\u{1172}\u{4E28}"), (0x318D, "\u{119E}"),
+    (0x318E, "\u{119E}\u{4E28}"), (0x31D0, "\u{30FC}"), (0x31D1, "\u{4E28}"), (0x31D3, "/"),
+    (0x31D4, "\u{5C}"), (0x31D6, "\u{4E5B}"), (0x31DA, "\u{4E85}"), (0x31DB, "\u{276C}"),
+    (0x31DF, "\u{4E5A}"), (0x31E0, "\u{4E59}"), (0x3200, "(\u{1100})"), (0x3201, "(\u{1102})"),
+    (0x3202, "(\u{1103})"), (0x3203, "(\u{1105})"), (0x3204, "(\u{1106})"), (0x3205, "(\u{1107})"),
+    (0x3206, "(\u{1109})"), (0x3207, "(\u{110B})"), (0x3208, "(\u{110C})"), (0x3209, "(\u{110E})"),
+    (0x320A, "(\u{110F})"), (0x320B, "(\u{1110})"), (0x320C, "(\u{1111})"), (0x320D, "(\u{1112})"),
+    (0x320E, "(\u{AC00})"), (0x320F, "(\u{B098})"), (0x3210, "(\u{B2E4})"), (0x3211, "(\u{B77C})"),
+    (0x3212, "(\u{B9C8})"), (0x3213, "(\u{BC14})"), (0x3214, "(\u{C0AC})"), (0x3215, "(\u{C544})"),
+    (0x3216, "(\u{C790})"), (0x3217, "(\u{CC28})"), (0x3218, "(\u{CE74})"), (0x3219, "(\u{D0C0})"),
+    (0x321A, "(\u{D30C})"), (0x321B, "(\u{D558})"), (0x321C, "(\u{C8FC})"),
+    (0x321D, "(\u{C624}\u{C804})"), (0x321E, "(\u{C624}\u{D6C4})"), (0x3220, "(\u{30FC})"),
+    (0x3221, "(\u{4E8C})"), (0x3222, "(\u{4E09})"), (0x3223, "(\u{56DB})"), (0x3224, "(\u{4E94})"),
+    (0x3225, "(\u{516D})"), (0x3226, "(\u{4E03})"), (0x3227, "(\u{516B})"), (0x3228, "(\u{4E5D})"),
+    (0x3229, "(\u{5341})"), (0x322A, "(\u{6708})"), (0x322B, "(\u{706B})"), (0x322C, "(\u{6C34})"),
+    (0x322D, "(\u{6728})"), (0x322E, "(\u{91D1})"), (0x322F, "(\u{571F})"), (0x3230, "(\u{65E5})"),
+    (0x3231, "(\u{682A})"), (0x3232, "(\u{6709})"), (0x3233, "(\u{793E})"), (0x3234, "(\u{540D})"),
+    (0x3235, "(\u{7279})"), (0x3236, "(\u{8CA1})"), (0x3237, "(\u{795D})"), (0x3238, "(\u{52B4})"),
+    (0x3239, "(\u{4EE3})"), (0x323A, "(\u{547C})"), (0x323B, "(\u{5B66})"), (0x323C, "(\u{76E3})"),
+    (0x323D, "(\u{4F01})"), (0x323E, "(\u{8CC7})"), (0x323F, "(\u{5354})"), (0x3240, "(\u{796D})"),
+    (0x3241, "(\u{4F11})"), (0x3242, "(\u{81EA})"), (0x3243, "(\u{81F3})"), (0x32C0, "l\u{6708}"),
+    (0x32C1, "2\u{6708}"), (0x32C2, "3\u{6708}"), (0x32C3, "4\u{6708}"), (0x32C4, "5\u{6708}"),
+    (0x32C5, "6\u{6708}"), (0x32C6, "7\u{6708}"), (0x32C7, "8\u{6708}"), (0x32C8, "9\u{6708}"),
+    (0x32C9, "lO\u{6708}"), (0x32CA, "ll\u{6708}"), (0x32CB, "l2\u{6708}"), (0x3358, "O\u{70B9}"),
+    (0x3359, "l\u{70B9}"), (0x335A, "2\u{70B9}"), (0x335B, "3\u{70B9}"), (0x335C, "4\u{70B9}"),
+    (0x335D, "5\u{70B9}"), (0x335E, "6\u{70B9}"), (0x335F, "7\u{70B9}"), (0x3360, "8\u{70B9}"),
+    (0x3361, "9\u{70B9}"), (0x3362, "lO\u{70B9}"), (0x3363, "ll\u{70B9}"), (0x3364, "l2\u{70B9}"),
+    (0x3365, "l3\u{70B9}"), (0x3366, "l4\u{70B9}"), (0x3367, "l5\u{70B9}"), (0x3368, "l6\u{70B9}"),
+    (0x3369, "l7\u{70B9}"), (0x336A, "l8\u{70B9}"), (0x336B, "l9\u{70B9}"), (0x336C, "2O\u{70B9}"),
+    (0x336D, "2l\u{70B9}"), (0x336E, "22\u{70B9}"), (0x336F, "23\u{70B9}"), (0x3370, "24\u{70B9}"),
+    (0x33E0, "l\u{65E5}"), (0x33E1, "2\u{65E5}"), (0x33E2, "3\u{65E5}"), (0x33E3, "4\u{65E5}"),
+    (0x33E4, "5\u{65E5}"), (0x33E5, "6\u{65E5}"), (0x33E6, "7\u{65E5}"), (0x33E7, "8\u{65E5}"),
+    (0x33E8, "9\u{65E5}"), (0x33E9, "lO\u{65E5}"), (0x33EA, "ll\u{65E5}"), (0x33EB, "l2\u{65E5}"),
+    (0x33EC, "l3\u{65E5}"), (0x33ED, "l4\u{65E5}"), (0x33EE, "l5\u{65E5}"), (0x33EF, "l6\u{65E5}"),
+    (0x33F0, "l7\u{65E5}"), (0x33F1, "l8\u{65E5}"), (0x33F2, "l9\u{65E5}"), (0x33F3, "2O\u{65E5}"),
+    (0x33F4, "2l\u{65E5}"), (0x33F5, "22\u{65E5}"), (0x33F6, "23\u{65E5}"), (0x33F7, "24\u{65E5}"),
+    (0x33F8, "25\u{65E5}"), (0x33F9, "26\u{65E5}"), (0x33FA, "27\u{65E5}"), (0x33FB, "28\u{65E5}"),
+    (0x33FC, "29\u{65E5}"), (0x33FD, "3O\u{65E5}"), (0x33FE, "3l\u{65E5}"), (0x39B3, "\u{363D}"),
+    (0x439B, "\u{3588}"), (0x4420, "\u{3B3B}"), (0x4E00, "\u{30FC}"), (0x4E36, "\u{5C}"),
+    (0x4E3F, "/"), (0x5002, "\u{4F75}"), (0x503C, "\u{5024}"), (0x555F, "\u{5553}"),
+    (0x56D7, "\u{53E3}"), (0x586B, "\u{5861}"), (0x58EB, "\u{571F}"), (0x58FF, "\u{58AB}"),
+    (0x5B00, "\u{5AAF}"), (0x5E32, "\u{5E21}"), (0x5E50, "\u{3B3A}"), (0x6238, "\u{6236}"),
+    (0x6409, "\u{3A41}"), (0x6663, "\u{403F}"), (0x6669, "\u{665A}"), (0x66F6, "\u{3ADA}"),
+    (0x6726, "\u{4443}"), (0x67FF, "\u{676E}"), (0x69E9, "\u{3BA3}"), (0x6A27, "\u{699D}"),
+    (0x6F59, "\u{6E88}"), (0x784F, "\u{7814}"), (0x7D76, "\u{7D55}"), (0x80A6, "\u{670C}"),
+    (0x80CA, "\u{6710}"), (0x80D0, "\u{670F}"), (0x80F6, "\u{3B35}"), (0x8101, "\u{6713}"),
+    (0x8127, "\u{6718}"), (0x8141, "\u{80FC}"), (0x81A7, "\u{6723}"), (0x853F, "\u{848D}"),
+    (0x8641, "\u{8637}"), (0x8A1E, "\u{46B6}"), (0x8A7D, "\u{8A2E}"), (0x8B8F, "\u{8B86}"),
+    (0x8C63, "\u{8C5C}"), (0x8D86, "\u{8D7F}"), (0x8DFA, "\u{8DE5}"), (0x8E9B, "\u{8E97}"),
+    (0x8F27, "\u{8EFF}"), (0x90DE, "\u{90CE}"), (0x93AE, "\u{93AD}"), (0x96B8, "\u{96B7}"),
+    (0x9E43, "\u{9E42}"), (0x9ED2, "\u{9ED1}"), (0x9FC3, "\u{4039}"), (0xA494, "\u{A2CD}"),
+    (0xA49C, "\u{A0C0}"), (0xA49E, "\u{A04A}"), (0xA4A7, "\u{A458}"), (0xA4A8, "\u{A132}"),
+    (0xA4AC, "\u{A050}"), (0xA4B0, "\u{A3C2}"), (0xA4BA, "\u{A3BF}"), (0xA4BE, "\u{A2B1}"),
+    (0xA4BF, "\u{A259}"), (0xA4C0, "\u{A3AB}"), (0xA4C2, "\u{A3B5}"), (0xA4D0, "B"), (0xA4D1, "P"),
+    (0xA4D2, "d"), (0xA4D3, "D"), (0xA4D4, "T"), (0xA4D6, "G"), (0xA4D7, "K"), (0xA4D9, "J"),
+    (0xA4DA, "C"), (0xA4DB, "\u{186}"), (0xA4DC, "Z"), (0xA4DD, "F"), (0xA4DE, "\u{2132}"),
+    (0xA4DF, "M"), (0xA4E0, "N"), (0xA4E1, "L"), (0xA4E2, "S"), (0xA4E3, "R"), (0xA4E5, "\u{245}"),
+    (0xA4E6, "V"), (0xA4E7, "H"), (0xA4EA, "W"), (0xA4EB, "X"), (0xA4EC, "Y"), (0xA4ED, "\u{1660}"),
+    (0xA4EE, "A"), (0xA4EF, "\u{2C6F}"), (0xA4F0, "E"), (0xA4F1, "\u{18E}"), (0xA4F2, "l"),
+    (0xA4F3, "O"), (0xA4F4, "U"), (0xA4F5, "\u{548}"), (0xA4F7, "\u{15E1}"), (0xA4F8, "."),
+    (0xA4F9, ","), (0xA4FA, ".."), (0xA4FB, ".,"), (0xA4FD, ":"), (0xA4FE, "-."), (0xA4FF, "="),
+    (0xA60E, "."), (0xA644, "2"), (0xA645, "\u{1A8}"), (0xA647, "i"), (0xA64D, "\u{3C9}"),
+    (0xA650, "\u{42A}l"), (0xA651, "\u{2C9}bi"), (0xA668, "\u{298}"), (0xA66F, "\u{20E9}"),
+    (0xA67C, "\u{306}"), (0xA67E, "\u{2C7}"), (0xA695, "h\u{314}"), (0xA698, "OO"), (0xA699, "oo"),
+    (0xA69A, "\u{102A8}"), (0xA6A1, "\u{418}"), (0xA6B0, "\u{16B9}"), (0xA6B1, "\u{2C75}"),
+    (0xA6CD, "\u{2A1}"), (0xA6CE, "\u{245}"), (0xA6DB, "\u{3A0}"), (0xA6DF, "V"), (0xA6EB, "?"),
+    (0xA6EF, "2"), (0xA6F0, "\u{302}"), (0xA6F1, "\u{304}"), (0xA6F4, "\u{A6F3}\u{A6F3}"),
+    (0xA714, "\u{2EB}"), (0xA716, "\u{2EA}"), (0xA728, "T3"), (0xA729, "t\u{21D}"), (0xA731, "s"),
+    (0xA732, "AA"), (0xA733, "aa"), (0xA734, "AO"), (0xA735, "ao"), (0xA736, "AU"), (0xA737, "au"),
+    (0xA738, "AV"), (0xA739, "av"), (0xA73A, "AV"), (0xA73B, "av"), (0xA73C, "AY"), (0xA73D, "ay"),
+    (0xA740, "K\u{335}"), (0xA74A, "O\u{335}"), (0xA74B, "o\u{335}"), (0xA74E, "OO"),
+    (0xA74F, "oo"), (0xA75A, "2"), (0xA761, "w\u{326}"), (0xA76A, "3"), (0xA76B, "\u{21D}"),
+    (0xA76E, "9"), (0xA777, "tf"), (0xA778, "&"), (0xA77A, "\u{A779}"), (0xA789, ":"),
+    (0xA78C, "'"), (0xA78F, "\u{B7}"), (0xA795, "\u{A727}"), (0xA798, "F"), (0xA799, "f"),
+    (0xA79A, "\u{10412}"), (0xA79B, "\u{1043A}"), (0xA79D, "\u{29A}"), (0xA79E, "\u{A4E4}"),
+    (0xA79F, "u"), (0xA7AB, "3"), (0xA7B1, "\u{A4D5}"), (0xA7B2, "J"), (0xA7B3, "X"), (0xA7B4, "B"),
+    (0xA7B5, "\u{DF}"), (0xA7B6, "\u{A64C}"), (0xA7B7, "\u{3C9}"), (0xA7D6, "\u{DF}"),
+    (0xA7DA, "\u{245}"), (0xA7DB, "\u{3BB}"), (0xA7DC, "\u{245}\u{338}"), (0xA7F7, "\u{30FC}"),
+    (0xA830, "\u{964}"), (0xA960, "\u{1103}\u{1106}"), (0xA961, "\u{1103}\u{1107}"),
+    (0xA962, "\u{1103}\u{1109}"), (0xA963, "\u{1103}\u{110C}"), (0xA964, "\u{1105}\u{1100}"),
+    (0xA965, "\u{1105}\u{1100}\u{1100}"), (0xA966, "\u{1105}\u{1103}"),
+    (0xA967, "\u{1105}\u{1103}\u{1103}"), (0xA968, "\u{1105}\u{1106}"),
+    (0xA969, "\u{1105}\u{1107}"), (0xA96A, "\u{1105}\u{1107}\u{1107}"),
+    (0xA96B, "\u{1105}\u{1107}\u{110B}"), (0xA96C, "\u{1105}\u{1109}"),
+    (0xA96D, "\u{1105}\u{110C}"), (0xA96E, "\u{1105}\u{110F}"), (0xA96F, "\u{1106}\u{1100}"),
+    (0xA970, "\u{1106}\u{1103}"), (0xA971, "\u{1106}\u{1109}"),
+    (0xA972, "\u{1107}\u{1109}\u{1110}"), (0xA973, "\u{1107}\u{110F}"),
+    (0xA974, "\u{1107}\u{1112}"), (0xA975, "\u{1109}\u{1109}\u{1107}"),
+    (0xA976, "\u{110B}\u{1105}"), (0xA977, "\u{110B}\u{1112}"),
+    (0xA978, "\u{110C}\u{110C}\u{1112}"), (0xA979, "\u{1110}\u{1110}"),
+    (0xA97A, "\u{1111}\u{1112}"), (0xA97B, "\u{1112}\u{1109}"), (0xA97C, "\u{1159}\u{1159}"),
+    (0xA992, "\u{2C3F}"), (0xA9A3, "\u{A99D}"), (0xA9C6, "\u{A9D0}"), (0xA9CF, "\u{662}"),
+    (0xAA53, "\u{AA01}"), (0xAA56, "\u{AA23}"), (0xAB32, "e"), (0xAB35, "f"), (0xAB3D, "o"),
+    (0xAB3E, "o\u{338}"), (0xAB3F, "\u{254}\u{338}"), (0xAB41, "\u{1DD}o\u{338}"),
+    (0xAB42, "\u{1DD}o\u{335}"), (0xAB47, "r"), (0xAB48, "r"), (0xAB4D, "\u{283}"), (0xAB4E, "u"),
+    (0xAB52, "u"), (0xAB53, "\u{3C7}"), (0xAB55, "\u{3C7}"), (0xAB5A, "y"), (0xAB60, "\u{459}"),
+    (0xAB62, "\u{254}e"), (0xAB63, "uo"), (0xAB70, "\u{1D05}"), (0xAB71, "\u{280}"),
+    (0xAB72, "\u{1D1B}"), (0xAB74, "o\u{31B}"), (0xAB75, "i"), (0xAB7A, "\u{1D00}"),
+    (0xAB7B, "\u{1D0A}"), (0xAB7C, "\u{1D07}"), (0xAB7E, "\u{242}"), (0xAB80, "\u{2C76}"),
+    (0xAB81, "r"), (0xAB83, "w"), (0xAB87, "\u{28D}"), (0xAB8B, "\u{29C}"), (0xAB8E, "o\u{335}"),
+    (0xAB90, "\u{262}"), (0xAB93, "z"), (0xAB9B, "\u{A793}"), (0xAB9C, "u\u{335}"),
+    (0xAB9F, "\u{185}"), (0xABA2, "\u{280}"), (0xABA9, "v"), (0xABAA, "s"), (0xABAE, "\u{29F}"),
+    (0xABAF, "c"), (0xABB2, "\u{1D18}"), (0xABB6, "\u{138}"), (0xABBB, "o\u{335}"),
+    (0xD7B0, "\u{1169}\u{1167}"), (0xD7B1, "\u{1169}\u{1169}\u{4E28}"),
+    (0xD7B2, "\u{116D}\u{1161}"), (0xD7B3, "\u{116D}\u{1161}\u{4E28}"),
+    (0xD7B4, "\u{116D}\u{1165}"), (0xD7B5, "\u{116E}\u{1167}"),
+    (0xD7B6, "\u{116E}\u{4E28}\u{4E28}"), (0xD7B7, "\u{1172}\u{1161}\u{4E28}"),
+    (0xD7B8, "\u{1172}\u{1169}"), (0xD7B9, "\u{30FC}\u{1161}"), (0xD7BA, "\u{30FC}\u{1165}"),
+    (0xD7BB, "\u{30FC}\u{1165}\u{4E28}"), (0xD7BC, "\u{30FC}\u{1169}"),
+    (0xD7BD, "\u{4E28}\u{1163}\u{1169}"), (0xD7BE, "\u{4E28}\u{1163}\u{4E28}"),
+    (0xD7BF, "\u{4E28}\u{1167}"), (0xD7C0, "\u{4E28}\u{1167}\u{4E28}"),
+    (0xD7C1, "\u{4E28}\u{1169}\u{4E28}"), (0xD7C2, "\u{4E28}\u{116D}"),
+    (0xD7C3, "\u{4E28}\u{1172}"), (0xD7C4, "\u{4E28}\u{4E28}"), (0xD7C5, "\u{119E}\u{1161}"),
+    (0xD7C6, "\u{119E}\u{1165}\u{4E28}"), (0xD7CB, "\u{1102}\u{1105}"),
+    (0xD7CC, "\u{1102}\u{110E}"), (0xD7CD, "\u{1103}\u{1103}"),
+    (0xD7CE, "\u{1103}\u{1103}\u{1107}"), (0xD7CF, "\u{1103}\u{1107}"),
+    (0xD7D0, "\u{1103}\u{1109}"), (0xD7D1, "\u{1103}\u{1109}\u{1100}"),
+    (0xD7D2, "\u{1103}\u{110C}"), (0xD7D3, "\u{1103}\u{110E}"), (0xD7D4, "\u{1103}\u{1110}"),
+    (0xD7D5, "\u{1105}\u{1100}\u{1100}"), (0xD7D6, "\u{1105}\u{1100}\u{1112}"),
+    (0xD7D7, "\u{1105}\u{1105}\u{110F}"), (0xD7D8, "\u{1105}\u{1106}\u{1112}"),
+    (0xD7D9, "\u{1105}\u{1107}\u{1103}"), (0xD7DA, "\u{1105}\u{1107}\u{1111}"),
+    (0xD7DB, "\u{1105}\u{114C}"), (0xD7DC, "\u{1105}\u{1159}\u{1112}"),
+    (0xD7DD, "\u{1105}\u{110B}"), (0xD7DE, "\u{1106}\u{1102}"),
+    (0xD7DF, "\u{1106}\u{1102}\u{1102}"), (0xD7E0, "\u{1106}\u{1106}"),
+    (0xD7E1, "\u{1106}\u{1107}\u{1109}"), (0xD7E2, "\u{1106}\u{110C}"),
+    (0xD7E3, "\u{1107}\u{1103}"), (0xD7E4, "\u{1107}\u{1105}\u{1111}"),
+    (0xD7E5, "\u{1107}\u{1106}"), (0xD7E6, "\u{1107}\u{1107}"),
+    (0xD7E7, "\u{1107}\u{1109}\u{1103}"), (0xD7E8, "\u{1107}\u{110C}"),
+    (0xD7E9, "\u{1107}\u{110E}"), (0xD7EA, "\u{1109}\u{1106}"),
+    (0xD7EB, "\u{1109}\u{1107}\u{110B}"), (0xD7EC, "\u{1109}\u{1109}\u{1100}"),
+    (0xD7ED, "\u{1109}\u{1109}\u{1103}"), (0xD7EE, "\u{1109}\u{1140}"),
+    (0xD7EF, "\u{1109}\u{110C}"), (0xD7F0, "\u{1109}\u{110E}"), (0xD7F1, "\u{1109}\u{1110}"),
+    (0xD7F2, "\u{1105}\u{1112}"), (0xD7F3, "\u{1140}\u{1107}"),
+    (0xD7F4, "\u{1140}\u{1107}\u{110B}"), (0xD7F5, "\u{114C}\u{1106}"),
+    (0xD7F6, "\u{114C}\u{1112}"), (0xD7F7, "\u{110C}\u{1107}"),
+    (0xD7F8, "\u{110C}\u{1107}\u{1107}"), (0xD7F9, "\u{110C}\u{110C}"),
+    (0xD7FA, "\u{1111}\u{1109}"), (0xD7FB, "\u{1111}\u{1110}"), (0xF900, "\u{8C48}"),
+    (0xF901, "\u{66F4}"), (0xF902, "\u{8ECA}"), (0xF903, "\u{8CC8}"), (0xF904, "\u{6ED1}"),
+    (0xF905, "\u{4E32}"), (0xF906, "\u{53E5}"), (0xF907, "\u{9F9C}"), (0xF908, "\u{9F9C}"),
+    (0xF909, "\u{5951}"), (0xF90A, "\u{91D1}"), (0xF90B, "\u{5587}"), (0xF90C, "\u{5948}"),
+    (0xF90D, "\u{61F6}"), (0xF90E, "\u{7669}"), (0xF90F, "\u{7F85}"), (0xF910, "\u{863F}"),
+    (0xF911, "\u{87BA}"), (0xF912, "\u{88F8}"), (0xF913, "\u{908F}"), (0xF914, "\u{6A02}"),
+    (0xF915, "\u{6D1B}"), (0xF916, "\u{70D9}"), (0xF917, "\u{73DE}"), (0xF918, "\u{843D}"),
+    (0xF919, "\u{916A}"), (0xF91A, "\u{99F1}"), (0xF91B, "\u{4E82}"), (0xF91C, "\u{5375}"),
+    (0xF91D, "\u{6B04}"), (0xF91E, "\u{721B}"), (0xF91F, "\u{862D}"), (0xF920, "\u{9E1E}"),
+    (0xF921, "\u{5D50}"), (0xF922, "\u{6FEB}"), (0xF923, "\u{85CD}"), (0xF924, "\u{8964}"),
+    (0xF925, "\u{62C9}"), (0xF926, "\u{81D8}"), (0xF927, "\u{881F}"), (0xF928, "\u{5ECA}"),
+    (0xF929, "\u{6717}"), (0xF92A, "\u{6D6A}"), (0xF92B, "\u{72FC}"), (0xF92C, "\u{90CE}"),
+    (0xF92D, "\u{4F86}"), (0xF92E, "\u{51B7}"), (0xF92F, "\u{52DE}"), (0xF930, "\u{64C4}"),
+    (0xF931, "\u{6AD3}"), (0xF932, "\u{7210}"), (0xF933, "\u{76E7}"), (0xF934, "\u{8001}"),
+    (0xF935, "\u{8606}"), (0xF936, "\u{865C}"), (0xF937, "\u{8DEF}"), (0xF938, "\u{9732}"),
+    (0xF939, "\u{9B6F}"), (0xF93A, "\u{9DFA}"), (0xF93B, "\u{788C}"), (0xF93C, "\u{797F}"),
+    (0xF93D, "\u{7DA0}"), (0xF93E, "\u{83C9}"), (0xF93F, "\u{9304}"), (0xF940, "\u{9E7F}"),
+    (0xF941, "\u{8AD6}"), (0xF942, "\u{58DF}"), (0xF943, "\u{5F04}"), (0xF944, "\u{7C60}"),
+    (0xF945, "\u{807E}"), (0xF946, "\u{7262}"), (0xF947, "\u{78CA}"), (0xF948, "\u{8CC2}"),
+    (0xF949, "\u{96F7}"), (0xF94A, "\u{58D8}"), (0xF94B, "\u{5C62}"), (0xF94C, "\u{6A13}"),
+    (0xF94D, "\u{6DDA}"), (0xF94E, "\u{6F0F}"), (0xF94F, "\u{7D2F}"), (0xF950, "\u{7E37}"),
+    (0xF951, "\u{964B}"), (0xF952, "\u{52D2}"), (0xF953, "\u{808B}"), (0xF954, "\u{51DC}"),
+    (0xF955, "\u{51CC}"), (0xF956, "\u{7A1C}"), (0xF957, "\u{7DBE}"), (0xF958, "\u{83F1}"),
+    (0xF959, "\u{9675}"), (0xF95A, "\u{8B80}"), (0xF95B, "\u{62CF}"), (0xF95C, "\u{6A02}"),
+    (0xF95D, "\u{8AFE}"), (0xF95E, "\u{4E39}"), (0xF95F, "\u{5BE7}"), (0xF960, "\u{6012}"),
+    (0xF961, "\u{7387}"), (0xF962, "\u{7570}"), (0xF963, "\u{5317}"), (0xF964, "\u{78FB}"),
+    (0xF965, "\u{4FBF}"), (0xF966, "\u{5FA9}"), (0xF967, "\u{4E0D}"), (0xF968, "\u{6CCC}"),
+    (0xF969, "\u{6578}"), (0xF96A, "\u{7D22}"), (0xF96B, "\u{53C3}"), (0xF96C, "\u{585E}"),
+    (0xF96D, "\u{7701}"), (0xF96E, "\u{8449}"), (0xF96F, "\u{8AAA}"), (0xF970, "\u{6BBA}"),
+    (0xF971, "\u{8FB0}"), (0xF972, "\u{6C88}"), (0xF973, "\u{62FE}"), (0xF974, "\u{82E5}"),
+    (0xF975, "\u{63A0}"), (0xF976, "\u{7565}"), (0xF977, "\u{4EAE}"), (0xF978, "\u{5169}"),
+    (0xF979, "\u{51C9}"), (0xF97A, "\u{6881}"), (0xF97B, "\u{7CE7}"), (0xF97C, "\u{826F}"),
+    (0xF97D, "\u{8AD2}"), (0xF97E, "\u{91CF}"), (0xF97F, "\u{52F5}"), (0xF980, "\u{5442}"),
+    (0xF981, "\u{5973}"), (0xF982, "\u{5EEC}"), (0xF983, "\u{65C5}"), (0xF984, "\u{6FFE}"),
+    (0xF985, "\u{792A}"), (0xF986, "\u{95AD}"), (0xF987, "\u{9A6A}"), (0xF988, "\u{9E97}"),
+    (0xF989, "\u{9ECE}"), (0xF98A, "\u{529B}"), (0xF98B, "\u{66C6}"), (0xF98C, "\u{6B77}"),
+    (0xF98D, "\u{8F62}"), (0xF98E, "\u{5E74}"), (0xF98F, "\u{6190}"), (0xF990, "\u{6200}"),
+    (0xF991, "\u{649A}"), (0xF992, "\u{6F23}"), (0xF993, "\u{7149}"), (0xF994, "\u{7489}"),
+    (0xF995, "\u{79CA}"), (0xF996, "\u{7DF4}"), (0xF997, "\u{806F}"), (0xF998, "\u{8F26}"),
+    (0xF999, "\u{84EE}"), (0xF99A, "\u{9023}"), (0xF99B, "\u{934A}"), (0xF99C, "\u{5217}"),
+    (0xF99D, "\u{52A3}"), (0xF99E, "\u{54BD}"), (0xF99F, "\u{70C8}"), (0xF9A0, "\u{88C2}"),
+    (0xF9A1, "\u{8AAA}"), (0xF9A2, "\u{5EC9}"), (0xF9A3, "\u{5FF5}"), (0xF9A4, "\u{637B}"),
+    (0xF9A5, "\u{6BAE}"), (0xF9A6, "\u{7C3E}"), (0xF9A7, "\u{7375}"), (0xF9A8, "\u{4EE4}"),
+    (0xF9A9, "\u{56F9}"), (0xF9AA, "\u{5BE7}"), (0xF9AB, "\u{5DBA}"), (0xF9AC, "\u{601C}"),
+    (0xF9AD, "\u{73B2}"), (0xF9AE, "\u{7469}"), (0xF9AF, "\u{7F9A}"), (0xF9B0, "\u{8046}"),
+    (0xF9B1, "\u{9234}"), (0xF9B2, "\u{96F6}"), (0xF9B3, "\u{9748}"), (0xF9B4, "\u{9818}"),
+    (0xF9B5, "\u{4F8B}"), (0xF9B6, "\u{79AE}"), (0xF9B7, "\u{91B4}"), (0xF9B8, "\u{96B7}"),
+    (0xF9B9, "\u{60E1}"), (0xF9BA, "\u{4E86}"), (0xF9BB, "\u{50DA}"), (0xF9BC, "\u{5BEE}"),
+    (0xF9BD, "\u{5C3F}"), (0xF9BE, "\u{6599}"), (0xF9BF, "\u{6A02}"), (0xF9C0, "\u{71CE}"),
+    (0xF9C1, "\u{7642}"), (0xF9C2, "\u{84FC}"), (0xF9C3, "\u{907C}"), (0xF9C4, "\u{9F8D}"),
+    (0xF9C5, "\u{6688}"), (0xF9C6, "\u{962E}"), (0xF9C7, "\u{5289}"), (0xF9C8, "\u{677B}"),
+    (0xF9C9, "\u{67F3}"), (0xF9CA, "\u{6D41}"), (0xF9CB, "\u{6E9C}"), (0xF9CC, "\u{7409}"),
+    (0xF9CD, "\u{7559}"), (0xF9CE, "\u{786B}"), (0xF9CF, "\u{7D10}"), (0xF9D0, "\u{985E}"),
+    (0xF9D1, "\u{516D}"), (0xF9D2, "\u{622E}"), (0xF9D3, "\u{9678}"), (0xF9D4, "\u{502B}"),
+    (0xF9D5, "\u{5D19}"), (0xF9D6, "\u{6DEA}"), (0xF9D7, "\u{8F2A}"), (0xF9D8, "\u{5F8B}"),
+    (0xF9D9, "\u{6144}"), (0xF9DA, "\u{6817}"), (0xF9DB, "\u{7387}"), (0xF9DC, "\u{9686}"),
+    (0xF9DD, "\u{5229}"), (0xF9DE, "\u{540F}"), (0xF9DF, "\u{5C65}"), (0xF9E0, "\u{6613}"),
+    (0xF9E1, "\u{674E}"), (0xF9E2, "\u{68A8}"), (0xF9E3, "\u{6CE5}"), (0xF9E4, "\u{7406}"),
+    (0xF9E5, "\u{75E2}"), (0xF9E6, "\u{7F79}"), (0xF9E7, "\u{88CF}"), (0xF9E8, "\u{88E1}"),
+    (0xF9E9, "\u{91CC}"), (0xF9EA, "\u{96E2}"), (0xF9EB, "\u{533F}"), (0xF9EC, "\u{6EBA}"),
+    (0xF9ED, "\u{541D}"), (0xF9EE, "\u{71D0}"), (0xF9EF, "\u{7498}"), (0xF9F0, "\u{85FA}"),
+    (0xF9F1, "\u{96A3}"), (0xF9F2, "\u{9C57}"), (0xF9F3, "\u{9E9F}"), (0xF9F4, "\u{6797}"),
+    (0xF9F5, "\u{6DCB}"), (0xF9F6, "\u{81E8}"), (0xF9F7, "\u{7ACB}"), (0xF9F8, "\u{7B20}"),
+    (0xF9F9, "\u{7C92}"), (0xF9FA, "\u{72C0}"), (0xF9FB, "\u{7099}"), (0xF9FC, "\u{8B58}"),
+    (0xF9FD, "\u{4EC0}"), (0xF9FE, "\u{8336}"), (0xF9FF, "\u{523A}"), (0xFA00, "\u{5207}"),
+    (0xFA01, "\u{5EA6}"), (0xFA02, "\u{62D3}"), (0xFA03, "\u{7CD6}"), (0xFA04, "\u{5B85}"),
+    (0xFA05, "\u{6D1E}"), (0xFA06, "\u{66B4}"), (0xFA07, "\u{8F3B}"), (0xFA08, "\u{884C}"),
+    (0xFA09, "\u{964D}"), (0xFA0A, "\u{898B}"), (0xFA0B, "\u{5ED3}"), (0xFA0C, "\u{5140}"),
+    (0xFA0D, "\u{55C0}"), (0xFA10, "\u{585A}"), (0xFA12, "\u{6674}"), (0xFA15, "\u{51DE}"),
+    (0xFA16, "\u{732A}"), (0xFA17, "\u{76CA}"), (0xFA18, "\u{793C}"), (0xFA19, "\u{795E}"),
+    (0xFA1A, "\u{7965}"), (0xFA1B, "\u{798F}"), (0xFA1C, "\u{9756}"), (0xFA1D, "\u{7CBE}"),
+    (0xFA1E, "\u{7FBD}"), (0xFA20, "\u{8612}"), (0xFA22, "\u{8AF8}"), (0xFA25, "\u{9038}"),
+    (0xFA26, "\u{90FD}"), (0xFA2A, "\u{98EF}"), (0xFA2B, "\u{98FC}"), (0xFA2C, "\u{9928}"),
+    (0xFA2D, "\u{9DB4}"), (0xFA2E, "\u{90CE}"), (0xFA2F, "\u{96B7}"), (0xFA30, "\u{4FAE}"),
+    (0xFA31, "\u{50E7}"), (0xFA32, "\u{514D}"), (0xFA33, "\u{52C9}"), (0xFA34, "\u{52E4}"),
+    (0xFA35, "\u{5351}"), (0xFA36, "\u{559D}"), (0xFA37, "\u{5606}"), (0xFA38, "\u{5668}"),
+    (0xFA39, "\u{5840}"), (0xFA3A, "\u{58A8}"), (0xFA3B, "\u{5C64}"), (0xFA3C, "\u{5C6E}"),
+    (0xFA3D, "\u{6094}"), (0xFA3E, "\u{6168}"), (0xFA3F, "\u{618E}"), (0xFA40, "\u{61F2}"),
+    (0xFA41, "\u{654F}"), (0xFA42, "\u{65E2}"), (0xFA43, "\u{6691}"), (0xFA44, "\u{6885}"),
+    (0xFA45, "\u{6D77}"), (0xFA46, "\u{6E1A}"), (0xFA47, "\u{6F22}"), (0xFA48, "\u{716E}"),
+    (0xFA49, "\u{722B}"), (0xFA4A, "\u{7422}"), (0xFA4B, "\u{7891}"), (0xFA4C, "\u{793E}"),
+    (0xFA4D, "\u{7949}"), (0xFA4E, "\u{7948}"), (0xFA4F, "\u{7950}"), (0xFA50, "\u{7956}"),
+    (0xFA51, "\u{795D}"), (0xFA52, "\u{798D}"), (0xFA53, "\u{798E}"), (0xFA54, "\u{7A40}"),
+    (0xFA55, "\u{7A81}"), (0xFA56, "\u{7BC0}"), (0xFA57, "\u{7DF4}"), (0xFA58, "\u{7E09}"),
+    (0xFA59, "\u{7E41}"), (0xFA5A, "\u{7F72}"), (0xFA5B, "\u{8005}"), (0xFA5C, "\u{81ED}"),
+    (0xFA5D, "\u{8279}"), (0xFA5E, "\u{8279}"), (0xFA5F, "\u{8457}"), (0xFA60, "\u{8910}"),
+    (0xFA61, "\u{8996}"), (0xFA62, "\u{8B01}"), (0xFA63, "\u{8B39}"), (0xFA64, "\u{8CD3}"),
+    (0xFA65, "\u{8D08}"), (0xFA66, "\u{8FB6}"), (0xFA67, "\u{9038}"), (0xFA68, "\u{96E3}"),
+    (0xFA69, "\u{97FF}"), (0xFA6A, "\u{983B}"), (0xFA6B, "\u{6075}"), (0xFA6C, "\u{242EE}"),
+    (0xFA6D, "\u{8218}"), (0xFA70, "\u{4E26}"), (0xFA71, "\u{51B5}"), (0xFA72, "\u{5168}"),
+    (0xFA73, "\u{4F80}"), (0xFA74, "\u{5145}"), (0xFA75, "\u{5180}"), (0xFA76, "\u{52C7}"),
+    (0xFA77, "\u{52FA}"), (0xFA78, "\u{559D}"), (0xFA79, "\u{5555}"), (0xFA7A, "\u{5599}"),
+    (0xFA7B, "\u{55E2}"), (0xFA7C, "\u{585A}"), (0xFA7D, "\u{58B3}"), (0xFA7E, "\u{5944}"),
+    (0xFA7F, "\u{5954}"), (0xFA80, "\u{5A62}"), (0xFA81, "\u{5B28}"), (0xFA82, "\u{5ED2}"),
+    (0xFA83, "\u{5ED9}"), (0xFA84, "\u{5F69}"), (0xFA85, "\u{5FAD}"), (0xFA86, "\u{60D8}"),
+    (0xFA87, "\u{614E}"), (0xFA88, "\u{6108}"), (0xFA89, "\u{618E}"), (0xFA8A, "\u{6160}"),
+    (0xFA8B, "\u{61F2}"), (0xFA8C, "\u{6234}"), (0xFA8D, "\u{63C4}"), (0xFA8E, "\u{641C}"),
+    (0xFA8F, "\u{6452}"), (0xFA90, "\u{6556}"), (0xFA91, "\u{6674}"), (0xFA92, "\u{6717}"),
+    (0xFA93, "\u{671B}"), (0xFA94, "\u{6756}"), (0xFA95, "\u{6B79}"), (0xFA96, "\u{6BBA}"),
+    (0xFA97, "\u{6D41}"), (0xFA98, "\u{6EDB}"), (0xFA99, "\u{6ECB}"), (0xFA9A, "\u{6F22}"),
+    (0xFA9B, "\u{701E}"), (0xFA9C, "\u{716E}"), (0xFA9D, "\u{77A7}"), (0xFA9E, "\u{7235}"),
+    (0xFA9F, "\u{72AF}"), (0xFAA0, "\u{732A}"), (0xFAA1, "\u{7471}"), (0xFAA2, "\u{7506}"),
+    (0xFAA3, "\u{753B}"), (0xFAA4, "\u{761D}"), (0xFAA5, "\u{761F}"), (0xFAA6, "\u{76CA}"),
+    (0xFAA7, "\u{76DB}"), (0xFAA8, "\u{76F4}"), (0xFAA9, "\u{774A}"), (0xFAAA, "\u{7740}"),
+    (0xFAAB, "\u{78CC}"), (0xFAAC, "\u{7AB1}"), (0xFAAD, "\u{7BC0}"), (0xFAAE, "\u{7C7B}"),
+    (0xFAAF, "\u{7D5B}"), (0xFAB0, "\u{7DF4}"), (0xFAB1, "\u{7F3E}"), (0xFAB2, "\u{8005}"),
+    (0xFAB3, "\u{8352}"), (0xFAB4, "\u{83EF}"), (0xFAB5, "\u{8779}"), (0xFAB6, "\u{8941}"),
+    (0xFAB7, "\u{8986}"), (0xFAB8, "\u{8996}"), (0xFAB9, "\u{8ABF}"), (0xFABA, "\u{8AF8}"),
+    (0xFABB, "\u{8ACB}"), (0xFABC, "\u{8B01}"), (0xFABD, "\u{8AFE}"), (0xFABE, "\u{8AED}"),
+    (0xFABF, "\u{8B39}"), (0xFAC0, "\u{8B8A}"), (0xFAC1, "\u{8D08}"), (0xFAC2, "\u{8F38}"),
+    (0xFAC3, "\u{9072}"), (0xFAC4, "\u{9199}"), (0xFAC5, "\u{9276}"), (0xFAC6, "\u{967C}"),
+    (0xFAC7, "\u{96E3}"), (0xFAC8, "\u{9756}"), (0xFAC9, "\u{97DB}"), (0xFACA, "\u{97FF}"),
+    (0xFACB, "\u{980B}"), (0xFACC, "\u{983B}"), (0xFACD, "\u{9B12}"), (0xFACE, "\u{9F9C}"),
+    (0xFACF, "\u{2284A}"), (0xFAD0, "\u{22844}"), (0xFAD1, "\u{233D5}"), (0xFAD2, "\u{3B9D}"),
+    (0xFAD3, "\u{4018}"), (0xFAD4, "\u{4039}"), (0xFAD5, "\u{25249}"), (0xFAD6, "\u{25CD0}"),
+    (0xFAD7, "\u{27ED3}"), (0xFAD8, "\u{9F43}"), (0xFAD9, "\u{9F8E}"), (0xFB00, "ff"),
+    (0xFB01, "fi"), (0xFB02, "fl"), (0xFB03, "ffi"), (0xFB04, "ffl"), (0xFB06, "st"),
+    (0xFB13, "\u{574}\u{576}"), (0xFB14, "\u{574}\u{565}"), (0xFB15, "\u{574}\u{56B}"),
+    (0xFB16, "\u{57E}\u{576}"), (0xFB17, "\u{574}\u{56D}"), (0xFB20, "\u{5E2}"),
+    (0xFB21, "\u{5D0}"), (0xFB22, "\u{5D3}"), (0xFB23, "\u{5D4}"), (0xFB24, "\u{5DB}"),
+    (0xFB25, "\u{5DC}"), (0xFB26, "\u{5DD}"), (0xFB27, "\u{5E8}"), (0xFB28, "\u{5EA}"),
+    (0xFB29, "-\u{307}"), (0xFB2B, "\u{FB2A}"), (0xFB2D, "\u{FB2C}"), (0xFB2F, "\u{FB2E}"),
+    (0xFB30, "\u{FB2E}"), (0xFB39, "\u{FB1D}"), (0xFB49, "\u{FB2A}"), (0xFB4F, "\u{5D0}\u{5DC}"),
+    (0xFB50, "\u{671}"), (0xFB51, "\u{671}"), (0xFB52, "\u{67B}"), (0xFB53, "\u{67B}"),
+    (0xFB54, "\u{67B}"), (0xFB55, "\u{67B}"), (0xFB56, "\u{649}\u{6DB}"),
+    (0xFB57, "\u{649}\u{6DB}"), (0xFB58, "\u{649}\u{6DB}"), (0xFB59, "\u{649}\u{6DB}"),
+    (0xFB5A, "\u{680}"), (0xFB5B, "\u{680}"), (0xFB5C, "\u{680}"), (0xFB5D, "\u{680}"),
+    (0xFB5E, "\u{67A}"), (0xFB5F, "\u{67A}"), (0xFB60, "\u{67A}"), (0xFB61, "\u{67A}"),
+    (0xFB62, "\u{67F}"), (0xFB63, "\u{67F}"), (0xFB64, "\u{67F}"), (0xFB65, "\u{67F}"),
+    (0xFB66, "\u{649}\u{615}"), (0xFB67, "\u{649}\u{615}"), (0xFB68, "\u{649}\u{615}"),
+    (0xFB69, "\u{649}\u{615}"), (0xFB6A, "\u{6A1}\u{6DB}"), (0xFB6B, "\u{6A1}\u{6DB}"),
+    (0xFB6C, "\u{6A1}\u{6DB}"), (0xFB6D, "\u{6A1}\u{6DB}"), (0xFB6E, "\u{6A6}"),
+    (0xFB6F, "\u{6A6}"), (0xFB70, "\u{6A6}"), (0xFB71, "\u{6A6}"), (0xFB72, "\u{684}"),
+    (0xFB73, "\u{684}"), (0xFB74, "\u{684}"), (0xFB75, "\u{684}"), (0xFB76, "\u{683}"),
+    (0xFB77, "\u{683}"), (0xFB78, "\u{683}"), (0xFB79, "\u{683}"), (0xFB7A, "\u{686}"),
+    (0xFB7B, "\u{686}"), (0xFB7C, "\u{686}"), (0xFB7D, "\u{686}"), (0xFB7E, "\u{687}"),
+    (0xFB7F, "\u{687}"), (0xFB80, "\u{687}"), (0xFB81, "\u{687}"), (0xFB82, "\u{68D}"),
+    (0xFB83, "\u{68D}"), (0xFB84, "\u{68C}"), (0xFB85, "\u{68C}"), (0xFB86, "\u{62F}\u{6DB}"),
+    (0xFB87, "\u{62F}\u{6DB}"), (0xFB88, "\u{62F}\u{615}"), (0xFB89, "\u{62F}\u{615}"),
+    (0xFB8A, "\u{631}\u{6DB}"), (0xFB8B, "\u{631}\u{6DB}"), (0xFB8C, "\u{631}\u{615}"),
+    (0xFB8D, "\u{631}\u{615}"), (0xFB8E, "\u{643}"), (0xFB8F, "\u{643}"), (0xFB90, "\u{643}"),
+    (0xFB91, "\u{643}"), (0xFB92, "\u{6AF}"), (0xFB93, "\u{6AF}"), (0xFB94, "\u{6AF}"),
+    (0xFB95, "\u{6AF}"), (0xFB96, "\u{6B3}"), (0xFB97, "\u{6B3}"), (0xFB98, "\u{6B3}"),
+    (0xFB99, "\u{6B3}"), (0xFB9A, "\u{6B1}"), (0xFB9B, "\u{6B1}"), (0xFB9C, "\u{6B1}"),
+    (0xFB9D, "\u{6B1}"), (0xFB9E, "\u{649}"), (0xFB9F, "\u{649}"), (0xFBA0, "\u{649}\u{615}"),
+    (0xFBA1, "\u{649}\u{615}"), (0xFBA2, "\u{649}\u{615}"), (0xFBA3, "\u{649}\u{615}"),
+    (0xFBA4, "\u{6C0}"), (0xFBA5, "\u{6C0}"), (0xFBA6, "o"), (0xFBA7, "o"), (0xFBA8, "o"),
+    (0xFBA9, "o"), (0xFBAA, "o"), (0xFBAB, "o"), (0xFBAC, "o"), (0xFBAD, "o"), (0xFBAE, "\u{649}"),
+    (0xFBAF, "\u{649}"), (0xFBB0, "\u{6D3}"), (0xFBB1, "\u{6D3}"), (0xFBD3, "\u{643}\u{6DB}"),
+    (0xFBD4, "\u{643}\u{6DB}"), (0xFBD5, "\u{643}\u{6DB}"), (0xFBD6, "\u{643}\u{6DB}"),
+    (0xFBD7, "\u{648}\u{313}"), (0xFBD8, "\u{648}\u{313}"), (0xFBD9, "\u{648}\u{306}"),
+    (0xFBDA, "\u{648}\u{306}"), (0xFBDB, "\u{648}\u{670}"), (0xFBDC, "\u{648}\u{670}"),
+    (0xFBDD, "\u{648}\u{313}\u{674}"), (0xFBDE, "\u{648}\u{6DB}"), (0xFBDF, "\u{648}\u{6DB}"),
+    (0xFBE0, "\u{6C5}"), (0xFBE1, "\u{6C5}"), (0xFBE2, "\u{648}\u{302}"),
+    (0xFBE3, "\u{648}\u{302}"), (0xFBE4, "\u{67B}"), (0xFBE5, "\u{67B}"), (0xFBE6, "\u{67B}"),
+    (0xFBE7, "\u{67B}"), (0xFBE8, "\u{649}"), (0xFBE9, "\u{649}"), (0xFBEA, "\u{649}\u{674}l"),
+    (0xFBEB, "\u{649}\u{674}l"), (0xFBEC, "\u{649}\u{674}o"), (0xFBED, "\u{649}\u{674}o"),
+    (0xFBEE, "\u{649}\u{674}\u{648}"), (0xFBEF, "\u{649}\u{674}\u{648}"),
+    (0xFBF0, "\u{649}\u{674}\u{648}\u{313}"), (0xFBF1, "\u{649}\u{674}\u{648}\u{313}"),
+    (0xFBF2, "\u{649}\u{674}\u{648}\u{306}"), (0xFBF3, "\u{649}\u{674}\u{648}\u{306}"),
+    (0xFBF4, "\u{649}\u{674}\u{648}\u{670}"), (0xFBF5, "\u{649}\u{674}\u{648}\u{670}"),
+    (0xFBF6, "\u{649}\u{674}\u{67B}"), (0xFBF7, "\u{649}\u{674}\u{67B}"),
+    (0xFBF8, "\u{649}\u{674}\u{67B}"), (0xFBF9, "\u{649}\u{674}\u{649}"),
+    (0xFBFA, "\u{649}\u{674}\u{649}"), (0xFBFB, "\u{649}\u{674}\u{649}"), (0xFBFC, "\u{649}"),
+    (0xFBFD, "\u{649}"), (0xFBFE, "\u{649}"), (0xFBFF, "\u{649}"),
+    (0xFC00, "\u{649}\u{674}\u{62C}"), (0xFC01, "\u{649}\u{674}\u{62D}"),
+    (0xFC02, "\u{649}\u{674}\u{645}"), (0xFC03, "\u{649}\u{674}\u{649}"),
+    (0xFC04, "\u{649}\u{674}\u{649}"), (0xFC05, "\u{628}\u{62C}"), (0xFC06, "\u{628}\u{62D}"),
+    (0xFC07, "\u{628}\u{62E}"), (0xFC08, "\u{628}\u{645}"), (0xFC09, "\u{628}\u{649}"),
+    (0xFC0A, "\u{628}\u{649}"), (0xFC0B, "\u{62A}\u{62C}"), (0xFC0C, "\u{62A}\u{62D}"),
+    (0xFC0D, "\u{62A}\u{62E}"), (0xFC0E, "\u{62A}\u{645}"), (0xFC0F, "\u{62A}\u{649}"),
+    (0xFC10, "\u{62A}\u{649}"), (0xFC11, "\u{649}\u{6DB}\u{62C}"),
+    (0xFC12, "\u{649}\u{6DB}\u{645}"), (0xFC13, "\u{649}\u{6DB}\u{649}"),
+    (0xFC14, "\u{649}\u{6DB}\u{649}"), (0xFC15, "\u{62C}\u{62D}"), (0xFC16, "\u{62C}\u{645}"),
+    (0xFC17, "\u{62D}\u{62C}"), (0xFC18, "\u{62D}\u{645}"), (0xFC19, "\u{62E}\u{62C}"),
+    (0xFC1A, "\u{62E}\u{62D}"), (0xFC1B, "\u{62E}\u{645}"), (0xFC1C, "\u{633}\u{62C}"),
+    (0xFC1D, "\u{633}\u{62D}"), (0xFC1E, "\u{633}\u{62E}"), (0xFC1F, "\u{633}\u{645}"),
+    (0xFC20, "\u{635}\u{62D}"), (0xFC21, "\u{635}\u{645}"), (0xFC22, "\u{636}\u{62C}"),
+    (0xFC23, "\u{636}\u{62D}"), (0xFC24, "\u{636}\u{62E}"), (0xFC25, "\u{636}\u{645}"),
+    (0xFC26, "\u{637}\u{62D}"), (0xFC27, "\u{637}\u{645}"), (0xFC28, "\u{638}\u{645}"),
+    (0xFC29, "\u{639}\u{62C}"), (0xFC2A, "\u{639}\u{645}"), (0xFC2B, "\u{63A}\u{62C}"),
+    (0xFC2C, "\u{63A}\u{645}"), (0xFC2D, "\u{641}\u{62C}"), (0xFC2E, "\u{641}\u{62D}"),
+    (0xFC2F, "\u{641}\u{62E}"), (0xFC30, "\u{641}\u{645}"), (0xFC31, "\u{641}\u{649}"),
+    (0xFC32, "\u{641}\u{649}"), (0xFC33, "\u{642}\u{62D}"), (0xFC34, "\u{642}\u{645}"),
+    (0xFC35, "\u{642}\u{649}"), (0xFC36, "\u{642}\u{649}"), (0xFC37, "\u{643}l"),
+    (0xFC38, "\u{643}\u{62C}"), (0xFC39, "\u{643}\u{62D}"), (0xFC3A, "\u{643}\u{62E}"),
+    (0xFC3B, "\u{643}\u{644}"), (0xFC3C, "\u{643}\u{645}"), (0xFC3D, "\u{643}\u{649}"),
+    (0xFC3E, "\u{643}\u{649}"), (0xFC3F, "\u{644}\u{62C}"), (0xFC40, "\u{644}\u{62D}"),
+    (0xFC41, "\u{644}\u{62E}"), (0xFC42, "\u{644}\u{645}"), (0xFC43, "\u{644}\u{649}"),
+    (0xFC44, "\u{644}\u{649}"), (0xFC45, "\u{645}\u{62C}"), (0xFC46, "\u{645}\u{62D}"),
+    (0xFC47, "\u{645}\u{62E}"), (0xFC48, "\u{645}\u{645}"), (0xFC49, "\u{645}\u{649}"),
+    (0xFC4A, "\u{645}\u{649}"), (0xFC4B, "\u{628}\u{62E}"), (0xFC4C, "\u{646}\u{62D}"),
+    (0xFC4D, "\u{646}\u{62E}"), (0xFC4E, "\u{646}\u{645}"), (0xFC4F, "\u{646}\u{649}"),
+    (0xFC50, "\u{646}\u{649}"), (0xFC51, "o\u{62C}"), (0xFC52, "o\u{645}"), (0xFC53, "o\u{649}"),
+    (0xFC54, "o\u{649}"), (0xFC55, "\u{649}\u{62C}"), (0xFC56, "\u{649}\u{62D}"),
+    (0xFC57, "\u{649}\u{62E}"), (0xFC58, "\u{649}\u{645}"), (0xFC59, "\u{649}\u{649}"),
+    (0xFC5A, "\u{649}\u{649}"), (0xFC5B, "\u{630}\u{670}"), (0xFC5C, "\u{631}\u{670}"),
+    (0xFC5D, "\u{649}\u{670}"), (0xFC5E, "\u{FE72}\u{651}"), (0xFC5F, "\u{FE74}\u{651}"),
+    (0xFC60, "\u{FE76}\u{651}"), (0xFC61, "\u{FE78}\u{651}"), (0xFC62, "\u{FE7A}\u{651}"),
+    (0xFC63, "\u{FE7C}\u{670}"), (0xFC64, "\u{649}\u{674}\u{631}"),
+    (0xFC65, "\u{649}\u{674}\u{632}"), (0xFC66, "\u{649}\u{674}\u{645}"),
+    (0xFC67, "\u{649}\u{674}\u{646}"), (0xFC68, "\u{649}\u{674}\u{649}"),
+    (0xFC69, "\u{649}\u{674}\u{649}"), (0xFC6A, "\u{628}\u{631}"), (0xFC6B, "\u{628}\u{632}"),
+    (0xFC6C, "\u{628}\u{645}"), (0xFC6D, "\u{628}\u{646}"), (0xFC6E, "\u{628}\u{649}"),
+    (0xFC6F, "\u{628}\u{649}"), (0xFC70, "\u{62A}\u{631}"), (0xFC71, "\u{62A}\u{632}"),
+    (0xFC72, "\u{62A}\u{645}"), (0xFC73, "\u{62A}\u{646}"), (0xFC74, "\u{62A}\u{649}"),
+    (0xFC75, "\u{62A}\u{649}"), (0xFC76, "\u{649}\u{6DB}\u{631}"),
+    (0xFC77, "\u{649}\u{6DB}\u{632}"), (0xFC78, "\u{649}\u{6DB}\u{645}"),
+    (0xFC79, "\u{649}\u{6DB}\u{646}"), (0xFC7A, "\u{649}\u{6DB}\u{649}"),
+    (0xFC7B, "\u{649}\u{6DB}\u{649}"), (0xFC7C, "\u{641}\u{649}"), (0xFC7D, "\u{641}\u{649}"),
+    (0xFC7E, "\u{642}\u{649}"), (0xFC7F, "\u{642}\u{649}"), (0xFC80, "\u{643}l"),
+    (0xFC81, "\u{643}\u{644}"), (0xFC82, "\u{643}\u{645}"), (0xFC83, "\u{643}\u{649}"),
+    (0xFC84, "\u{643}\u{649}"), (0xFC85, "\u{644}\u{645}"), (0xFC86, "\u{644}\u{649}"),
+    (0xFC87, "\u{644}\u{649}"), (0xFC88, "\u{645}l"), (0xFC89, "\u{645}\u{645}"),
+    (0xFC8A, "\u{646}\u{631}"), (0xFC8B, "\u{646}\u{632}"), (0xFC8C, "\u{646}\u{645}"),
+    (0xFC8D, "\u{646}\u{646}"), (0xFC8E, "\u{646}\u{649}"), (0xFC8F, "\u{646}\u{649}"),
+    (0xFC90, "\u{649}\u{670}"), (0xFC91, "\u{649}\u{631}"), (0xFC92, "\u{649}\u{632}"),
+    (0xFC93, "\u{649}\u{645}"), (0xFC94, "\u{649}\u{646}"), (0xFC95, "\u{649}\u{649}"),
+    (0xFC96, "\u{649}\u{649}"), (0xFC97, "\u{649}\u{674}\u{62C}"),
+    (0xFC98, "\u{649}\u{674}\u{62D}"), (0xFC99, "\u{649}\u{674}\u{62E}"),
+    (0xFC9A, "\u{649}\u{674}\u{645}"), (0xFC9B, "\u{649}\u{674}o"), (0xFC9C, "\u{628}\u{62C}"),
+    (0xFC9D, "\u{628}\u{62D}"), (0xFC9E, "\u{628}\u{62E}"), (0xFC9F, "\u{628}\u{645}"),
+    (0xFCA0, "\u{628}o"), (0xFCA1, "\u{62A}\u{62C}"), (0xFCA2, "\u{62A}\u{62D}"),
+    (0xFCA3, "\u{62A}\u{62E}"), (0xFCA4, "\u{62A}\u{645}"), (0xFCA5, "\u{62A}o"),
+    (0xFCA6, "\u{649}\u{6DB}\u{645}"), (0xFCA7, "\u{62C}\u{62D}"), (0xFCA8, "\u{62C}\u{645}"),
+    (0xFCA9, "\u{62D}\u{62C}"), (0xFCAA, "\u{62D}\u{645}"), (0xFCAB, "\u{62E}\u{62C}"),
+    (0xFCAC, "\u{62E}\u{645}"), (0xFCAD, "\u{633}\u{62C}"), (0xFCAE, "\u{633}\u{62D}"),
+    (0xFCAF, "\u{633}\u{62E}"), (0xFCB0, "\u{633}\u{645}"), (0xFCB1, "\u{635}\u{62D}"),
+    (0xFCB2, "\u{635}\u{62E}"), (0xFCB3, "\u{635}\u{645}"), (0xFCB4, "\u{636}\u{62C}"),
+    (0xFCB5, "\u{636}\u{62D}"), (0xFCB6, "\u{636}\u{62E}"), (0xFCB7, "\u{636}\u{645}"),
+    (0xFCB8, "\u{637}\u{62D}"), (0xFCB9, "\u{638}\u{645}"), (0xFCBA, "\u{639}\u{62C}"),
+    (0xFCBB, "\u{639}\u{645}"), (0xFCBC, "\u{63A}\u{62C}"), (0xFCBD, "\u{63A}\u{645}"),
+    (0xFCBE, "\u{641}\u{62C}"), (0xFCBF, "\u{641}\u{62D}"), (0xFCC0, "\u{641}\u{62E}"),
+    (0xFCC1, "\u{641}\u{645}"), (0xFCC2, "\u{642}\u{62D}"), (0xFCC3, "\u{642}\u{645}"),
+    (0xFCC4, "\u{643}\u{62C}"), (0xFCC5, "\u{643}\u{62D}"), (0xFCC6, "\u{643}\u{62E}"),
+    (0xFCC7, "\u{643}\u{644}"), (0xFCC8, "\u{643}\u{645}"), (0xFCC9, "\u{644}\u{62C}"),
+    (0xFCCA, "\u{644}\u{62D}"), (0xFCCB, "\u{644}\u{62E}"), (0xFCCC, "\u{644}\u{645}"),
+    (0xFCCD, "\u{644}o"), (0xFCCE, "\u{645}\u{62C}"), (0xFCCF, "\u{645}\u{62D}"),
+    (0xFCD0, "\u{645}\u{62E}"), (0xFCD1, "\u{645}\u{645}"), (0xFCD2, "\u{628}\u{62E}"),
+    (0xFCD3, "\u{646}\u{62D}"), (0xFCD4, "\u{646}\u{62E}"), (0xFCD5, "\u{646}\u{645}"),
+    (0xFCD6, "\u{646}o"), (0xFCD7, "o\u{62C}"), (0xFCD8, "o\u{645}"), (0xFCD9, "o\u{670}"),
+    (0xFCDA, "\u{649}\u{62C}"), (0xFCDB, "\u{649}\u{62D}"), (0xFCDC, "\u{649}\u{62E}"),
+    (0xFCDD, "\u{649}\u{645}"), (0xFCDE, "\u{649}o"), (0xFCDF, "\u{649}\u{674}\u{645}"),
+    (0xFCE0, "\u{649}\u{674}o"), (0xFCE1, "\u{628}\u{645}"), (0xFCE2, "\u{628}o"),
+    (0xFCE3, "\u{62A}\u{645}"), (0xFCE4, "\u{62A}o"), (0xFCE5, "\u{649}\u{6DB}\u{645}"),
+    (0xFCE6, "\u{649}\u{6DB}o"), (0xFCE7, "\u{633}\u{645}"), (0xFCE8, "\u{633}o"),
+    (0xFCE9, "\u{633}\u{6DB}\u{645}"), (0xFCEA, "\u{633}\u{6DB}o"), (0xFCEB, "\u{643}\u{644}"),
+    (0xFCEC, "\u{643}\u{645}"), (0xFCED, "\u{644}\u{645}"), (0xFCEE, "\u{646}\u{645}"),
+    (0xFCEF, "\u{646}o"), (0xFCF0, "\u{649}\u{645}"), (0xFCF1, "\u{649}o"),
+    (0xFCF2, "\u{FE77}\u{651}"), (0xFCF3, "\u{FE79}\u{651}"), (0xFCF4, "\u{FE7B}\u{651}"),
+    (0xFCF5, "\u{637}\u{649}"), (0xFCF6, "\u{637}\u{649}"), (0xFCF7, "\u{639}\u{649}"),
+    (0xFCF8, "\u{639}\u{649}"), (0xFCF9, "\u{63A}\u{649}"), (0xFCFA, "\u{63A}\u{649}"),
+    (0xFCFB, "\u{633}\u{649}"), (0xFCFC, "\u{633}\u{649}"), (0xFCFD, "\u{633}\u{6DB}\u{649}"),
+    (0xFCFE, "\u{633}\u{6DB}\u{649}"), (0xFCFF, "\u{62D}\u{649}"), (0xFD00, "\u{62D}\u{649}"),
+    (0xFD01, "\u{62C}\u{649}"), (0xFD02, "\u{62C}\u{649}"), (0xFD03, "\u{62E}\u{649}"),
+    (0xFD04, "\u{62E}\u{649}"), (0xFD05, "\u{635}\u{649}"), (0xFD06, "\u{635}\u{649}"),
+    (0xFD07, "\u{636}\u{649}"), (0xFD08, "\u{636}\u{649}"), (0xFD09, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD0A, "\u{633}\u{6DB}\u{62D}"), (0xFD0B, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD0C, "\u{633}\u{6DB}\u{645}"), (0xFD0D, "\u{633}\u{6DB}\u{631}"),
+    (0xFD0E, "\u{633}\u{631}"), (0xFD0F, "\u{635}\u{631}"), (0xFD10, "\u{636}\u{631}"),
+    (0xFD11, "\u{637}\u{649}"), (0xFD12, "\u{637}\u{649}"), (0xFD13, "\u{639}\u{649}"),
+    (0xFD14, "\u{639}\u{649}"), (0xFD15, "\u{63A}\u{649}"), (0xFD16, "\u{63A}\u{649}"),
+    (0xFD17, "\u{633}\u{649}"), (0xFD18, "\u{633}\u{649}"), (0xFD19, "\u{633}\u{6DB}\u{649}"),
+    (0xFD1A, "\u{633}\u{6DB}\u{649}"), (0xFD1B, "\u{62D}\u{649}"), (0xFD1C, "\u{62D}\u{649}"),
+    (0xFD1D, "\u{62C}\u{649}"), (0xFD1E, "\u{62C}\u{649}"), (0xFD1F, "\u{62E}\u{649}"),
+    (0xFD20, "\u{62E}\u{649}"), (0xFD21, "\u{635}\u{649}"), (0xFD22, "\u{635}\u{649}"),
+    (0xFD23, "\u{636}\u{649}"), (0xFD24, "\u{636}\u{649}"), (0xFD25, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD26, "\u{633}\u{6DB}\u{62D}"), (0xFD27, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD28, "\u{633}\u{6DB}\u{645}"), (0xFD29, "\u{633}\u{6DB}\u{631}"),
+    (0xFD2A, "\u{633}\u{631}"), (0xFD2B, "\u{635}\u{631}"), (0xFD2C, "\u{636}\u{631}"),
+    (0xFD2D, "\u{633}\u{6DB}\u{62C}"), (0xFD2E, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD2F, "\u{633}\u{6DB}\u{62E}"), (0xFD30, "\u{633}\u{6DB}\u{645}"), (0xFD31, "\u{633}o"),
+    (0xFD32, "\u{633}\u{6DB}o"), (0xFD33, "\u{637}\u{645}"), (0xFD34, "\u{633}\u{62C}"),
+    (0xFD35, "\u{633}\u{62D}"), (0xFD36, "\u{633}\u{62E}"), (0xFD37, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD38, "\u{633}\u{6DB}\u{62D}"), (0xFD39, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD3A, "\u{637}\u{645}"), (0xFD3B, "\u{638}\u{645}"), (0xFD3C, "l\u{30B}"),
+    (0xFD3D, "l\u{30B}"), (0xFD3E, "("), (0xFD3F, ")"), (0xFD50, "\u{62A}\u{62C}\u{645}"),
+    (0xFD51, "\u{62A}\u{62D}\u{62C}"), (0xFD52, "\u{62A}\u{62D}\u{62C}"),
+    (0xFD53, "\u{62A}\u{62D}\u{645}"), (0xFD54, "\u{62A}\u{62E}\u{645}"),
+    (0xFD55, "\u{62A}\u{645}\u{62C}"), (0xFD56, "\u{62A}\u{645}\u{62D}"),
+    (0xFD57, "\u{62A}\u{645}\u{62E}"), (0xFD58, "\u{62C}\u{645}\u{62D}"),
+    (0xFD59, "\u{62C}\u{645}\u{62D}"), (0xFD5A, "\u{62D}\u{645}\u{649}"),
+    (0xFD5B, "\u{62D}\u{645}\u{649}"), (0xFD5C, "\u{633}\u{62D}\u{62C}"),
+    (0xFD5D, "\u{633}\u{62C}\u{62D}"), (0xFD5E, "\u{633}\u{62C}\u{649}"),
+    (0xFD5F, "\u{633}\u{645}\u{62D}"), (0xFD60, "\u{633}\u{645}\u{62D}"),
+    (0xFD61, "\u{633}\u{645}\u{62C}"), (0xFD62, "\u{633}\u{645}\u{645}"),
+    (0xFD63, "\u{633}\u{645}\u{645}"), (0xFD64, "\u{635}\u{62D}\u{62D}"),
+    (0xFD65, "\u{635}\u{62D}\u{62D}"), (0xFD66, "\u{635}\u{645}\u{645}"),
+    (0xFD67, "\u{633}\u{6DB}\u{62D}\u{645}"), (0xFD68, "\u{633}\u{6DB}\u{62D}\u{645}"),
+    (0xFD69, "\u{633}\u{6DB}\u{62C}\u{649}"), (0xFD6A, "\u{633}\u{6DB}\u{645}\u{62E}"),
+    (0xFD6B, "\u{633}\u{6DB}\u{645}\u{62E}"), (0xFD6C, "\u{633}\u{6DB}\u{645}\u{645}"),
+    (0xFD6D, "\u{633}\u{6DB}\u{645}\u{645}"), (0xFD6E, "\u{636}\u{62D}\u{649}"),
+    (0xFD6F, "\u{636}\u{62E}\u{645}"), (0xFD70, "\u{636}\u{62E}\u{645}"),
+    (0xFD71, "\u{637}\u{645}\u{62D}"), (0xFD72, "\u{637}\u{645}\u{62D}"),
+    (0xFD73, "\u{637}\u{645}\u{645}"), (0xFD74, "\u{637}\u{645}\u{649}"),
+    (0xFD75, "\u{639}\u{62C}\u{645}"), (0xFD76, "\u{639}\u{645}\u{645}"),
+    (0xFD77, "\u{639}\u{645}\u{645}"), (0xFD78, "\u{639}\u{645}\u{649}"),
+    (0xFD79, "\u{63A}\u{645}\u{645}"), (0xFD7A, "\u{63A}\u{645}\u{649}"),
+    (0xFD7B, "\u{63A}\u{645}\u{649}"), (0xFD7C, "\u{641}\u{62E}\u{645}"),
+    (0xFD7D, "\u{641}\u{62E}\u{645}"), (0xFD7E, "\u{642}\u{645}\u{62D}"),
+    (0xFD7F, "\u{642}\u{645}\u{645}"), (0xFD80, "\u{644}\u{62D}\u{645}"),
+    (0xFD81, "\u{644}\u{62D}\u{649}"), (0xFD82, "\u{644}\u{62D}\u{649}"),
+    (0xFD83, "\u{644}\u{62C}\u{62C}"), (0xFD84, "\u{644}\u{62C}\u{62C}"),
+    (0xFD85, "\u{644}\u{62E}\u{645}"), (0xFD86, "\u{644}\u{62E}\u{645}"),
+    (0xFD87, "\u{644}\u{645}\u{62D}"), (0xFD88, "\u{644}\u{645}\u{62D}"),
+    (0xFD89, "\u{645}\u{62D}\u{62C}"), (0xFD8A, "\u{645}\u{62D}\u{645}"),
+    (0xFD8B, "\u{645}\u{62D}\u{649}"), (0xFD8C, "\u{645}\u{62C}\u{62D}"),
+    (0xFD8D, "\u{645}\u{62C}\u{645}"), (0xFD8E, "\u{645}\u{62E}\u{62C}"),
+    (0xFD8F, "\u{645}\u{62E}\u{645}"), (0xFD92, "\u{645}\u{62C}\u{62E}"),
+    (0xFD93, "o\u{645}\u{62C}"), (0xFD94, "o\u{645}\u{645}"), (0xFD95, "\u{646}\u{62D}\u{645}"),
+    (0xFD96, "\u{646}\u{62D}\u{649}"), (0xFD97, "\u{646}\u{62C}\u{645}"),
+    (0xFD98, "\u{646}\u{62C}\u{645}"), (0xFD99, "\u{646}\u{62C}\u{649}"),
+    (0xFD9A, "\u{646}\u{645}\u{649}"), (0xFD9B, "\u{646}\u{645}\u{649}"),
+    (0xFD9C, "\u{649}\u{645}\u{645}"), (0xFD9D, "\u{649}\u{645}\u{645}"),
+    (0xFD9E, "\u{628}\u{62E}\u{649}"), (0xFD9F, "\u{62A}\u{62C}\u{649}"),
+    (0xFDA0, "\u{62A}\u{62C}\u{649}"), (0xFDA1, "\u{62A}\u{62E}\u{649}"),
+    (0xFDA2, "\u{62A}\u{62E}\u{649}"), (0xFDA3, "\u{62A}\u{645}\u{649}"),
+    (0xFDA4, "\u{62A}\u{645}\u{649}"), (0xFDA5, "\u{62C}\u{645}\u{649}"),
+    (0xFDA6, "\u{62C}\u{62D}\u{649}"), (0xFDA7, "\u{62C}\u{645}\u{649}"),
+    (0xFDA8, "\u{633}\u{62E}\u{649}"), (0xFDA9, "\u{635}\u{62D}\u{649}"),
+    (0xFDAA, "\u{633}\u{6DB}\u{62D}\u{649}"), (0xFDAB, "\u{636}\u{62D}\u{649}"),
+    (0xFDAC, "\u{644}\u{62C}\u{649}"), (0xFDAD, "\u{644}\u{645}\u{649}"),
+    (0xFDAE, "\u{649}\u{62D}\u{649}"), (0xFDAF, "\u{649}\u{62C}\u{649}"),
+    (0xFDB0, "\u{649}\u{645}\u{649}"), (0xFDB1, "\u{645}\u{645}\u{649}"),
+    (0xFDB2, "\u{642}\u{645}\u{649}"), (0xFDB3, "\u{646}\u{62D}\u{649}"),
+    (0xFDB4, "\u{642}\u{645}\u{62D}"), (0xFDB5, "\u{644}\u{62D}\u{645}"),
+    (0xFDB6, "\u{639}\u{645}\u{649}"), (0xFDB7, "\u{643}\u{645}\u{649}"),
+    (0xFDB8, "\u{646}\u{62C}\u{62D}"), (0xFDB9, "\u{645}\u{62E}\u{649}"),
+    (0xFDBA, "\u{644}\u{62C}\u{645}"), (0xFDBB, "\u{643}\u{645}\u{645}"),
+    (0xFDBC, "\u{644}\u{62C}\u{645}"), (0xFDBD, "\u{646}\u{62C}\u{62D}"),
+    (0xFDBE, "\u{62C}\u{62D}\u{649}"), (0xFDBF, "\u{62D}\u{62C}\u{649}"),
+    (0xFDC0, "\u{645}\u{62C}\u{649}"), (0xFDC1, "\u{641}\u{645}\u{649}"),
+    (0xFDC2, "\u{628}\u{62D}\u{649}"), (0xFDC3, "\u{643}\u{645}\u{645}"),
+    (0xFDC4, "\u{639}\u{62C}\u{645}"), (0xFDC5, "\u{635}\u{645}\u{645}"),
+    (0xFDC6, "\u{633}\u{62E}\u{649}"), (0xFDC7, "\u{646}\u{62C}\u{649}"),
+    (0xFDF0, "\u{635}\u{644}\u{649}"), (0xFDF1, "\u{642}\u{644}\u{649}"),
+    (0xFDF2, "l\u{644}\u{644}\u{651}\u{670}o"), (0xFDF3, "l\u{643}\u{628}\u{631}"),
+    (0xFDF4, "\u{645}\u{62D}\u{645}\u{62F}"), (0xFDF5, "\u{635}\u{644}\u{639}\u{645}"),
+    (0xFDF6, "\u{631}\u{633}\u{648}\u{644}"), (0xFDF7, "\u{639}\u{644}\u{649}o"),
+    (0xFDF8, "\u{648}\u{633}\u{644}\u{645}"), (0xFDF9, "\u{635}\u{644}\u{649}"),
+    (0xFDFA, "\u{635}\u{644}\u{649} l\u{644}\u{644}o \u{639}\u{644}\u{649}o \u{648}\u{633}\
+        \u{644}\u{645}"), (0xFDFB, "\u{62C}\u{644} \u{62C}\u{644}l\u{644}o"),
+    (0xFDFC, "\u{631}\u{649}l\u{644}"), (0xFE19, "\u{2D57}"), (0xFE30, ":"), (0xFE31, "\u{2502}"),
+    (0xFE34, "\u{2307}"), (0xFE35, "\u{23DC}"), (0xFE36, "\u{23DD}"), (0xFE37, "\u{23DE}"),
+    (0xFE38, "\u{23DF}"), (0xFE39, "\u{23E0}"), (0xFE3A, "\u{23E1}"), (0xFE49, "\u{2C9}"),
+    (0xFE4A, "\u{2C9}"), (0xFE4B, "\u{2C9}"), (0xFE4C, "\u{2C9}"), (0xFE4D, "_"), (0xFE4E, "_"),
+    (0xFE4F, "_"), (0xFE58, "-"), (0xFE68, "\u{5C}"), (0xFE80, "\u{621}"), (0xFE81, "\u{622}"),
+    (0xFE82, "\u{622}"), (0xFE83, "l\u{674}"), (0xFE84, "l\u{674}"), (0xFE85, "\u{648}\u{674}"),
+    (0xFE86, "\u{648}\u{674}"), (0xFE87, "l\u{655}"), (0xFE88, "l\u{655}"),
+    (0xFE89, "\u{649}\u{674}"), (0xFE8A, "\u{649}\u{674}"), (0xFE8B, "\u{649}\u{674}"),
+    (0xFE8C, "\u{649}\u{674}"), (0xFE8D, "l"), (0xFE8E, "l"), (0xFE8F, "\u{628}"),
+    (0xFE90, "\u{628}"), (0xFE91, "\u{628}"), (0xFE92, "\u{628}"), (0xFE93, "\u{629}"),
+    (0xFE94, "\u{629}"), (0xFE95, "\u{62A}"), (0xFE96, "\u{62A}"), (0xFE97, "\u{62A}"),
+    (0xFE98, "\u{62A}"), (0xFE99, "\u{649}\u{6DB}"), (0xFE9A, "\u{649}\u{6DB}"),
+    (0xFE9B, "\u{649}\u{6DB}"), (0xFE9C, "\u{649}\u{6DB}"), (0xFE9D, "\u{62C}"),
+    (0xFE9E, "\u{62C}"), (0xFE9F, "\u{62C}"), (0xFEA0, "\u{62C}"), (0xFEA1, "\u{62D}"),
+    (0xFEA2, "\u{62D}"), (0xFEA3, "\u{62D}"), (0xFEA4, "\u{62D}"), (0xFEA5, "\u{62E}"),
+    (0xFEA6, "\u{62E}"), (0xFEA7, "\u{62E}"), (0xFEA8, "\u{62E}"), (0xFEA9, "\u{62F}"),
+    (0xFEAA, "\u{62F}"), (0xFEAB, "\u{630}"), (0xFEAC, "\u{630}"), (0xFEAD, "\u{631}"),
+    (0xFEAE, "\u{631}"), (0xFEAF, "\u{632}"), (0xFEB0, "\u{632}"), (0xFEB1, "\u{633}"),
+    (0xFEB2, "\u{633}"), (0xFEB3, "\u{633}"), (0xFEB4, "\u{633}"), (0xFEB5, "\u{633}\u{6DB}"),
+    (0xFEB6, "\u{633}\u{6DB}"), (0xFEB7, "\u{633}\u{6DB}"), (0xFEB8, "\u{633}\u{6DB}"),
+    (0xFEB9, "\u{635}"), (0xFEBA, "\u{635}"), (0xFEBB, "\u{635}"), (0xFEBC, "\u{635}"),
+    (0xFEBD, "\u{636}"), (0xFEBE, "\u{636}"), (0xFEBF, "\u{636}"), (0xFEC0, "\u{636}"),
+    (0xFEC1, "\u{637}"), (0xFEC2, "\u{637}"), (0xFEC3, "\u{637}"), (0xFEC4, "\u{637}"),
+    (0xFEC5, "\u{638}"), (0xFEC6, "\u{638}"), (0xFEC7, "\u{638}"), (0xFEC8, "\u{638}"),
+    (0xFEC9, "\u{639}"), (0xFECA, "\u{639}"), (0xFECB, "\u{639}"), (0xFECC, "\u{639}"),
+    (0xFECD, "\u{63A}"), (0xFECE, "\u{63A}"), (0xFECF, "\u{63A}"), (0xFED0, "\u{63A}"),
+    (0xFED1, "\u{641}"), (0xFED2, "\u{641}"), (0xFED3, "\u{641}"), (0xFED4, "\u{641}"),
+    (0xFED5, "\u{642}"), (0xFED6, "\u{642}"), (0xFED7, "\u{642}"), (0xFED8, "\u{642}"),
+    (0xFED9, "\u{643}"), (0xFEDA, "\u{643}"), (0xFEDB, "\u{643}"), (0xFEDC, "\u{643}"),
+    (0xFEDD, "\u{644}"), (0xFEDE, "\u{644}"), (0xFEDF, "\u{644}"), (0xFEE0, "\u{644}"),
+    (0xFEE1, "\u{645}"), (0xFEE2, "\u{645}"), (0xFEE3, "\u{645}"), (0xFEE4, "\u{645}"),
+    (0xFEE5, "\u{646}"), (0xFEE6, "\u{646}"), (0xFEE7, "\u{646}"), (0xFEE8, "\u{646}"),
+    (0xFEE9, "o"), (0xFEEA, "o"), (0xFEEB, "o"), (0xFEEC, "o"), (0xFEED, "\u{648}"),
+    (0xFEEE, "\u{648}"), (0xFEEF, "\u{649}"), (0xFEF0, "\u{649}"), (0xFEF1, "\u{649}"),
+    (0xFEF2, "\u{649}"), (0xFEF3, "\u{649}"), (0xFEF4, "\u{649}"), (0xFEF5, "\u{644}\u{622}"),
+    (0xFEF6, "\u{644}\u{622}"), (0xFEF7, "\u{644}l\u{674}"), (0xFEF8, "\u{644}l\u{674}"),
+    (0xFEF9, "\u{644}l\u{655}"), (0xFEFA, "\u{644}l\u{655}"), (0xFEFB, "\u{644}l"),
+    (0xFEFC, "\u{644}l"), (0xFF01, "!"), (0xFF02, "''"), (0xFF07, "'"), (0xFF0D, "\u{30FC}"),
+    (0xFF1A, ":"), (0xFF21, "A"), (0xFF22, "B"), (0xFF23, "C"), (0xFF25, "E"), (0xFF28, "H"),
+    (0xFF29, "l"), (0xFF2A, "J"), (0xFF2B, "K"), (0xFF2D, "M"), (0xFF2E, "N"), (0xFF2F, "O"),
+    (0xFF30, "P"), (0xFF33, "S"), (0xFF34, "T"), (0xFF38, "X"), (0xFF39, "Y"), (0xFF3A, "Z"),
+    (0xFF3B, "("), (0xFF3C, "\u{5C}"), (0xFF3D, ")"), (0xFF3E, "\u{FE3F}"), (0xFF40, "'"),
+    (0xFF41, "a"), (0xFF43, "c"), (0xFF45, "e"), (0xFF47, "g"), (0xFF48, "h"), (0xFF49, "i"),
+    (0xFF4A, "j"), (0xFF4C, "l"), (0xFF4F, "o"), (0xFF50, "p"), (0xFF53, "s"), (0xFF56, "v"),
+    (0xFF58, "x"), (0xFF59, "y"), (0xFF5C, "\u{2502}"), (0xFF5E, "\u{301C}"), (0xFF65, "\u{B7}"),
+    (0xFFE3, "\u{2C9}"), (0xFFE8, "l"), (0xFFED, "\u{25AA}"), (0x10101, "\u{B7}"),
+    (0x1018E, "N\u{30A}"), (0x10196, "X\u{335}"), (0x10197, "V\u{335}"),
+    (0x10198, "l\u{335}l\u{335}S\u{335}"), (0x10199, "l\u{335}l\u{335}"), (0x101A0, "\u{2CE8}"),
+    (0x10282, "B"), (0x10285, "\u{394}"), (0x10286, "E"), (0x10287, "F"), (0x1028A, "l"),
+    (0x1028D, "\u{245}"), (0x10290, "X"), (0x10292, "O"), (0x10294, "\u{16DC}"), (0x10295, "P"),
+    (0x10296, "S"), (0x10297, "T"), (0x1029B, "+"), (0x102A0, "A"), (0x102A1, "B"), (0x102A2, "C"),
+    (0x102A3, "\u{394}"), (0x102A5, "F"), (0x102AB, "O"), (0x102AD, "\u{3D8}"), (0x102B0, "M"),
+    (0x102B1, "T"), (0x102B2, "Y"), (0x102B3, "\u{3A6}"), (0x102B4, "X"), (0x102B5, "\u{3A8}"),
+    (0x102B6, "\u{3A9}"), (0x102B8, "\u{2D40}"), (0x102CF, "H"), (0x102E1, "\u{62F}"),
+    (0x102E4, "\u{648}"), (0x102E8, "\u{637}"), (0x102F2, "\u{635}"), (0x102F5, "Z"),
+    (0x10301, "B"), (0x10302, "C"), (0x10309, "l"), (0x10311, "M"), (0x10312, "\u{3D8}"),
+    (0x10315, "T"), (0x10317, "X"), (0x1031A, "8"), (0x1031F, "*"), (0x10320, "l"), (0x10322, "X"),
+    (0x103D1, "\u{10382}"), (0x103D3, "\u{10393}"), (0x10401, "\u{190}"), (0x10404, "O"),
+    (0x10411, "\u{A4F6}"), (0x10415, "C"), (0x1041B, "L"), (0x1041F, "\u{2C70}"), (0x10420, "S"),
+    (0x10423, "\u{186}"), (0x10425, "\u{418}"), (0x10429, "\u{A793}"), (0x1042A, "\u{29A}"),
+    (0x1042C, "o"), (0x1043D, "c"), (0x1043F, "\u{277}"), (0x10442, "\u{25E}"),
+    (0x10443, "\u{29F}"), (0x10448, "s"), (0x1044B, "\u{254}"), (0x1044D, "\u{1D0E}"),
+    (0x104A0, "\u{10486}"), (0x104B0, "\u{245}"), (0x104B4, "R"), (0x104BC, "\u{4C3}"),
+    (0x104C2, "O"), (0x104C3, "\u{298}"), (0x104C4, "\u{DE}"), (0x104CD, "\u{40B}"), (0x104CE, "U"),
+    (0x104D0, "\u{16E6}"), (0x104D1, "\u{3A8}"), (0x104D2, "7"), (0x104D8, "\u{28C}"),
+    (0x104DB, "\u{3BB}"), (0x104EA, "o"), (0x104EB, "\u{A669}"), (0x104F6, "u"),
+    (0x104F9, "\u{3C8}"), (0x10513, "N"), (0x10516, "O"), (0x10518, "K"), (0x1051C, "C"),
+    (0x1051D, "V"), (0x10525, "F"), (0x10526, "L"), (0x10527, "X"), (0x10A3A, "\u{323}"),
+    (0x10A50, "."), (0x10A57, "\u{10A56}\u{10A56}"), (0x10CFA, "\u{10CA5}"), (0x10CFC, "\u{10C82}"),
+    (0x110BB, "\u{970}"), (0x111C7, "\u{970}"), (0x111CA, "\u{323}"), (0x111CB, "\u{93A}"),
+    (0x111DB, "\u{A8FC}"), (0x111DC, "\u{A8FB}"), (0x111DE, "\u{2248}"), (0x11300, "\u{30A}"),
+    (0x11413, "\u{11434}\u{11442}\u{11412}"), (0x11419, "\u{11434}\u{11442}\u{11418}"),
+    (0x11424, "\u{11434}\u{11442}\u{11423}"), (0x1142A, "\u{11434}\u{11442}\u{11429}"),
+    (0x1142D, "\u{11434}\u{11442}\u{1142C}"), (0x1142F, "\u{11434}\u{11442}\u{1142E}"),
+    (0x1144C, "\u{1144B}\u{1144B}"), (0x11492, "\u{998}"), (0x11494, "\u{99A}"),
+    (0x11496, "\u{99C}"), (0x11498, "\u{99E}"), (0x11499, "\u{99F}"), (0x1149B, "\u{9A1}"),
+    (0x1149D, "\u{9B2}"), (0x1149E, "\u{9A4}"), (0x1149F, "\u{9A5}"), (0x114A0, "\u{9A6}"),
+    (0x114A1, "\u{9A7}"), (0x114A2, "\u{9A8}"), (0x114A3, "\u{9AA}"), (0x114A7, "\u{9AE}"),
+    (0x114A8, "\u{9AF}"), (0x114A9, "\u{9AC}"), (0x114AA, "\u{9A3}"), (0x114AB, "\u{9B0}"),
+    (0x114AD, "\u{9B7}"), (0x114AE, "\u{9B8}"), (0x114B0, "\u{9BE}"), (0x114B1, "\u{9BF}"),
+    (0x114B9, "\u{9C7}"), (0x114BC, "\u{9CB}"), (0x114BD, "\u{9D7}"), (0x114BE, "\u{9CC}"),
+    (0x114BF, "\u{306}\u{307}"), (0x114C1, "\u{983}"), (0x114C2, "\u{9CD}"), (0x114C3, "\u{323}"),
+    (0x114C4, "\u{9BD}"), (0x114C5, "w\u{307}"), (0x114D0, "O"), (0x114D1, "\u{9E7}"),
+    (0x114D2, "\u{9E8}"), (0x114D6, "\u{9EC}"), (0x115D8, "\u{11582}"), (0x115D9, "\u{11582}"),
+    (0x115DA, "\u{11583}"), (0x115DB, "\u{11584}"), (0x115DC, "\u{115B2}"), (0x115DD, "\u{115B3}"),
+    (0x11642, "\u{11641}\u{11641}"), (0x11700, "rn"), (0x11706, "v"), (0x1170A, "w"),
+    (0x1170E, "w"), (0x1170F, "w"), (0x118A0, "V"), (0x118A2, "F"), (0x118A3, "L"), (0x118A4, "Y"),
+    (0x118A6, "E"), (0x118A8, "\u{2207}"), (0x118A9, "Z"), (0x118AC, "9"), (0x118AE, "E"),
+    (0x118AF, "4"), (0x118B2, "L"), (0x118B5, "O"), (0x118B7, "\u{16DC}"), (0x118B8, "U"),
+    (0x118BB, "5"), (0x118BC, "T"), (0x118C0, "v"), (0x118C1, "s"), (0x118C2, "F"), (0x118C3, "i"),
+    (0x118C4, "z"), (0x118C6, "7"), (0x118C8, "o"), (0x118CA, "3"), (0x118CC, "9"),
+    (0x118CE, "\u{A793}"), (0x118D5, "6"), (0x118D6, "9"), (0x118D7, "o"), (0x118D8, "u"),
+    (0x118DC, "y"), (0x118E0, "O"), (0x118E3, "rn"), (0x118E4, "\u{669}"), (0x118E5, "Z"),
+    (0x118E6, "W"), (0x118E9, "C"), (0x118EC, "X"), (0x118EF, "W"), (0x118F2, "C"),
+    (0x11AE6, "\u{11AE5}\u{11AEF}"), (0x11AE7, "\u{11AE5}\u{11AF0}"),
+    (0x11AE8, "\u{11AE5}\u{11AE5}"), (0x11AE9, "\u{11AE5}\u{11AE5}\u{11AEF}"),
+    (0x11AEA, "\u{11AE5}\u{11AE5}\u{11AF0}"), (0x11AEC, "\u{11AEB}\u{11AEF}"),
+    (0x11AED, "\u{11AEB}\u{11AEB}"), (0x11AEE, "\u{11AEB}\u{11AEB}\u{11AEF}"),
+    (0x11AF4, "\u{11AF3}\u{11AEF}"), (0x11AF5, "\u{11AF3}\u{11AF0}"),
+    (0x11AF6, "\u{11AF3}\u{11AF3}"), (0x11AF7, "\u{11AF3}\u{11AF3}\u{11AEF}"),
+    (0x11AF8, "\u{11AF3}\u{11AF3}\u{11AF0}"), (0x11C42, "\u{11C41}\u{11C41}"),
+    (0x11CB2, "\u{11CAA}"), (0x12038, "\u{1039A}"), (0x132F9, "\u{1099E}"), (0x16F07, "\u{393}"),
+    (0x16F08, "V"), (0x16F0A, "T"), (0x16F16, "L"), (0x16F1A, "\u{394}"), (0x16F1C, "\u{A658}"),
+    (0x16F26, "\u{A4F6}"), (0x16F28, "l"), (0x16F2D, "\u{190}"), (0x16F35, "R"), (0x16F3A, "S"),
+    (0x16F3B, "3"), (0x16F3D, "\u{245}"), (0x16F3F, ">"), (0x16F40, "A"), (0x16F42, "U"),
+    (0x16F43, "Y"), (0x16F51, "'"), (0x16F52, "'"), (0x1CCD6, "A"), (0x1CCD7, "B"), (0x1CCD8, "C"),
+    (0x1CCD9, "D"), (0x1CCDA, "E"), (0x1CCDB, "F"), (0x1CCDC, "G"), (0x1CCDD, "H"), (0x1CCDE, "l"),
+    (0x1CCDF, "J"), (0x1CCE0, "K"), (0x1CCE1, "L"), (0x1CCE2, "M"), (0x1CCE3, "N"), (0x1CCE4, "O"),
+    (0x1CCE5, "P"), (0x1CCE6, "Q"), (0x1CCE7, "R"), (0x1CCE8, "S"), (0x1CCE9, "T"), (0x1CCEA, "U"),
+    (0x1CCEB, "V"), (0x1CCEC, "W"), (0x1CCED, "X"), (0x1CCEE, "Y"), (0x1CCEF, "Z"), (0x1CCF0, "O"),
+    (0x1CCF1, "l"), (0x1CCF2, "2"), (0x1CCF3, "3"), (0x1CCF4, "4"), (0x1CCF5, "5"), (0x1CCF6, "6"),
+    (0x1CCF7, "7"), (0x1CCF8, "8"), (0x1CCF9, "9"), (0x1D114, "{"), (0x1D16D, "."),
+    (0x1D202, "\u{4FE}"), (0x1D206, "3"), (0x1D20B, "\u{418}"), (0x1D20D, "V"), (0x1D20F, "\u{5C}"),
+    (0x1D212, "7"), (0x1D213, "F"), (0x1D214, "\u{102BC}"), (0x1D215, "\u{A4F6}"), (0x1D216, "R"),
+    (0x1D217, "\u{2C6F}"), (0x1D21A, "O\u{335}"), (0x1D21B, "\u{2144}"), (0x1D21C, "\u{A4D5}"),
+    (0x1D221, "\u{190}"), (0x1D222, "\u{460}"), (0x1D22A, "L"), (0x1D22B, "\u{A4F6}"),
+    (0x1D230, "\u{A7FB}"), (0x1D236, "<"), (0x1D237, ">"), (0x1D238, "\u{228F}"),
+    (0x1D239, "\u{2290}"), (0x1D23A, "/"), (0x1D23B, "\u{5C}"), (0x1D23F, "\u{16CB}"),
+    (0x1D245, "\u{548}"), (0x1D400, "A"), (0x1D401, "B"), (0x1D402, "C"), (0x1D403, "D"),
+    (0x1D404, "E"), (0x1D405, "F"), (0x1D406, "G"), (0x1D407, "H"), (0x1D408, "l"), (0x1D409, "J"),
+    (0x1D40A, "K"), (0x1D40B, "L"), (0x1D40C, "M"), (0x1D40D, "N"), (0x1D40E, "O"), (0x1D40F, "P"),
+    (0x1D410, "Q"), (0x1D411, "R"), (0x1D412, "S"), (0x1D413, "T"), (0x1D414, "U"), (0x1D415, "V"),
+    (0x1D416, "W"), (0x1D417, "X"), (0x1D418, "Y"), (0x1D419, "Z"), (0x1D41A, "a"), (0x1D41B, "b"),
+    (0x1D41C, "c"), (0x1D41D, "d"), (0x1D41E, "e"), (0x1D41F, "f"), (0x1D420, "g"), (0x1D421, "h"),
+    (0x1D422, "i"), (0x1D423, "j"), (0x1D424, "k"), (0x1D425, "l"), (0x1D426, "rn"), (0x1D427, "n"),
+    (0x1D428, "o"), (0x1D429, "p"), (0x1D42A, "q"), (0x1D42B, "r"), (0x1D42C, "s"), (0x1D42D, "t"),
+    (0x1D42E, "u"), (0x1D42F, "v"), (0x1D430, "w"), (0x1D431, "x"), (0x1D432, "y"), (0x1D433, "z"),
+    (0x1D434, "A"), (0x1D435, "B"), (0x1D436, "C"), (0x1D437, "D"), (0x1D438, "E"), (0x1D439, "F"),
+    (0x1D43A, "G"), (0x1D43B, "H"), (0x1D43C, "l"), (0x1D43D, "J"), (0x1D43E, "K"), (0x1D43F, "L"),
+    (0x1D440, "M"), (0x1D441, "N"), (0x1D442, "O"), (0x1D443, "P"), (0x1D444, "Q"), (0x1D445, "R"),
+    (0x1D446, "S"), (0x1D447, "T"), (0x1D448, "U"), (0x1D449, "V"), (0x1D44A, "W"), (0x1D44B, "X"),
+    (0x1D44C, "Y"), (0x1D44D, "Z"), (0x1D44E, "a"), (0x1D44F, "b"), (0x1D450, "c"), (0x1D451, "d"),
+    (0x1D452, "e"), (0x1D453, "f"), (0x1D454, "g"), (0x1D456, "i"), (0x1D457, "j"), (0x1D458, "k"),
+    (0x1D459, "l"), (0x1D45A, "rn"), (0x1D45B, "n"), (0x1D45C, "o"), (0x1D45D, "p"), (0x1D45E, "q"),
+    (0x1D45F, "r"), (0x1D460, "s"), (0x1D461, "t"), (0x1D462, "u"), (0x1D463, "v"), (0x1D464, "w"),
+    (0x1D465, "x"), (0x1D466, "y"), (0x1D467, "z"), (0x1D468, "A"), (0x1D469, "B"), (0x1D46A, "C"),
+    (0x1D46B, "D"), (0x1D46C, "E"), (0x1D46D, "F"), (0x1D46E, "G"), (0x1D46F, "H"), (0x1D470, "l"),
+    (0x1D471, "J"), (0x1D472, "K"), (0x1D473, "L"), (0x1D474, "M"), (0x1D475, "N"), (0x1D476, "O"),
+    (0x1D477, "P"), (0x1D478, "Q"), (0x1D479, "R"), (0x1D47A, "S"), (0x1D47B, "T"), (0x1D47C, "U"),
+    (0x1D47D, "V"), (0x1D47E, "W"), (0x1D47F, "X"), (0x1D480, "Y"), (0x1D481, "Z"), (0x1D482, "a"),
+    (0x1D483, "b"), (0x1D484, "c"), (0x1D485, "d"), (0x1D486, "e"), (0x1D487, "f"), (0x1D488, "g"),
+    (0x1D489, "h"), (0x1D48A, "i"), (0x1D48B, "j"), (0x1D48C, "k"), (0x1D48D, "l"), (0x1D48E, "rn"),
+    (0x1D48F, "n"), (0x1D490, "o"), (0x1D491, "p"), (0x1D492, "q"), (0x1D493, "r"), (0x1D494, "s"),
+    (0x1D495, "t"), (0x1D496, "u"), (0x1D497, "v"), (0x1D498, "w"), (0x1D499, "x"), (0x1D49A, "y"),
+    (0x1D49B, "z"), (0x1D49C, "A"), (0x1D49E, "C"), (0x1D49F, "D"), (0x1D4A2, "G"), (0x1D4A5, "J"),
+    (0x1D4A6, "K"), (0x1D4A9, "N"), (0x1D4AA, "O"), (0x1D4AB, "P"), (0x1D4AC, "Q"), (0x1D4AE, "S"),
+    (0x1D4AF, "T"), (0x1D4B0, "U"), (0x1D4B1, "V"), (0x1D4B2, "W"), (0x1D4B3, "X"), (0x1D4B4, "Y"),
+    (0x1D4B5, "Z"), (0x1D4B6, "a"), (0x1D4B7, "b"), (0x1D4B8, "c"), (0x1D4B9, "d"), (0x1D4BB, "f"),
+    (0x1D4BD, "h"), (0x1D4BE, "i"), (0x1D4BF, "j"), (0x1D4C0, "k"), (0x1D4C1, "l"), (0x1D4C2, "rn"),
+    (0x1D4C3, "n"), (0x1D4C5, "p"), (0x1D4C6, "q"), (0x1D4C7, "r"), (0x1D4C8, "s"), (0x1D4C9, "t"),
+    (0x1D4CA, "u"), (0x1D4CB, "v"), (0x1D4CC, "w"), (0x1D4CD, "x"), (0x1D4CE, "y"), (0x1D4CF, "z"),
+    (0x1D4D0, "A"), (0x1D4D1, "B"), (0x1D4D2, "C"), (0x1D4D3, "D"), (0x1D4D4, "E"), (0x1D4D5, "F"),
+    (0x1D4D6, "G"), (0x1D4D7, "H"), (0x1D4D8, "l"), (0x1D4D9, "J"), (0x1D4DA, "K"), (0x1D4DB, "L"),
+    (0x1D4DC, "M"), (0x1D4DD, "N"), (0x1D4DE, "O"), (0x1D4DF, "P"), (0x1D4E0, "Q"), (0x1D4E1, "R"),
+    (0x1D4E2, "S"), (0x1D4E3, "T"), (0x1D4E4, "U"), (0x1D4E5, "V"), (0x1D4E6, "W"), (0x1D4E7, "X"),
+    (0x1D4E8, "Y"), (0x1D4E9, "Z"), (0x1D4EA, "a"), (0x1D4EB, "b"), (0x1D4EC, "c"), (0x1D4ED, "d"),
+    (0x1D4EE, "e"), (0x1D4EF, "f"), (0x1D4F0, "g"), (0x1D4F1, "h"), (0x1D4F2, "i"), (0x1D4F3, "j"),
+    (0x1D4F4, "k"), (0x1D4F5, "l"), (0x1D4F6, "rn"), (0x1D4F7, "n"), (0x1D4F8, "o"), (0x1D4F9, "p"),
+    (0x1D4FA, "q"), (0x1D4FB, "r"), (0x1D4FC, "s"), (0x1D4FD, "t"), (0x1D4FE, "u"), (0x1D4FF, "v"),
+    (0x1D500, "w"), (0x1D501, "x"), (0x1D502, "y"), (0x1D503, "z"), (0x1D504, "A"), (0x1D505, "B"),
+    (0x1D507, "D"), (0x1D508, "E"), (0x1D509, "F"), (0x1D50A, "G"), (0x1D50D, "J"), (0x1D50E, "K"),
+    (0x1D50F, "L"), (0x1D510, "M"), (0x1D511, "N"), (0x1D512, "O"), (0x1D513, "P"), (0x1D514, "Q"),
+    (0x1D516, "S"), (0x1D517, "T"), (0x1D518, "U"), (0x1D519, "V"), (0x1D51A, "W"), (0x1D51B, "X"),
+    (0x1D51C, "Y"), (0x1D51E, "a"), (0x1D51F, "b"), (0x1D520, "c"), (0x1D521, "d"), (0x1D522, "e"),
+    (0x1D523, "f"), (0x1D524, "g"), (0x1D525, "h"), (0x1D526, "i"), (0x1D527, "j"), (0x1D528, "k"),
+    (0x1D529, "l"), (0x1D52A, "rn"), (0x1D52B, "n"), (0x1D52C, "o"), (0x1D52D, "p"), (0x1D52E, "q"),
+    (0x1D52F, "r"), (0x1D530, "s"), (0x1D531, "t"), (0x1D532, "u"), (0x1D533, "v"), (0x1D534, "w"),
+    (0x1D535, "x"), (0x1D536, "y"), (0x1D537, "z"), (0x1D538, "A"), (0x1D539, "B"), (0x1D53B, "D"),
+    (0x1D53C, "E"), (0x1D53D, "F"), (0x1D53E, "G"), (0x1D540, "l"), (0x1D541, "J"), (0x1D542, "K"),
+    (0x1D543, "L"), (0x1D544, "M"), (0x1D546, "O"), (0x1D54A, "S"), (0x1D54B, "T"), (0x1D54C, "U"),
+    (0x1D54D, "V"), (0x1D54E, "W"), (0x1D54F, "X"), (0x1D550, "Y"), (0x1D552, "a"), (0x1D553, "b"),
+    (0x1D554, "c"), (0x1D555, "d"), (0x1D556, "e"), (0x1D557, "f"), (0x1D558, "g"), (0x1D559, "h"),
+    (0x1D55A, "i"), (0x1D55B, "j"), (0x1D55C, "k"), (0x1D55D, "l"), (0x1D55E, "rn"), (0x1D55F, "n"),
+    (0x1D560, "o"), (0x1D561, "p"), (0x1D562, "q"), (0x1D563, "r"), (0x1D564, "s"), (0x1D565, "t"),
+    (0x1D566, "u"), (0x1D567, "v"), (0x1D568, "w"), (0x1D569, "x"), (0x1D56A, "y"), (0x1D56B, "z"),
+    (0x1D56C, "A"), (0x1D56D, "B"), (0x1D56E, "C"), (0x1D56F, "D"), (0x1D570, "E"), (0x1D571, "F"),
+    (0x1D572, "G"), (0x1D573, "H"), (0x1D574, "l"), (0x1D575, "J"), (0x1D576, "K"), (0x1D577, "L"),
+    (0x1D578, "M"), (0x1D579, "N"), (0x1D57A, "O"), (0x1D57B, "P"), (0x1D57C, "Q"), (0x1D57D, "R"),
+    (0x1D57E, "S"), (0x1D57F, "T"), (0x1D580, "U"), (0x1D581, "V"), (0x1D582, "W"), (0x1D583, "X"),
+    (0x1D584, "Y"), (0x1D585, "Z"), (0x1D586, "a"), (0x1D587, "b"), (0x1D588, "c"), (0x1D589, "d"),
+    (0x1D58A, "e"), (0x1D58B, "f"), (0x1D58C, "g"), (0x1D58D, "h"), (0x1D58E, "i"), (0x1D58F, "j"),
+    (0x1D590, "k"), (0x1D591, "l"), (0x1D592, "rn"), (0x1D593, "n"), (0x1D594, "o"), (0x1D595, "p"),
+    (0x1D596, "q"), (0x1D597, "r"), (0x1D598, "s"), (0x1D599, "t"), (0x1D59A, "u"), (0x1D59B, "v"),
+    (0x1D59C, "w"), (0x1D59D, "x"), (0x1D59E, "y"), (0x1D59F, "z"), (0x1D5A0, "A"), (0x1D5A1, "B"),
+    (0x1D5A2, "C"), (0x1D5A3, "D"), (0x1D5A4, "E"), (0x1D5A5, "F"), (0x1D5A6, "G"), (0x1D5A7, "H"),
+    (0x1D5A8, "l"), (0x1D5A9, "J"), (0x1D5AA, "K"), (0x1D5AB, "L"), (0x1D5AC, "M"), (0x1D5AD, "N"),
+    (0x1D5AE, "O"), (0x1D5AF, "P"), (0x1D5B0, "Q"), (0x1D5B1, "R"), (0x1D5B2, "S"), (0x1D5B3, "T"),
+    (0x1D5B4, "U"), (0x1D5B5, "V"), (0x1D5B6, "W"), (0x1D5B7, "X"), (0x1D5B8, "Y"), (0x1D5B9, "Z"),
+    (0x1D5BA, "a"), (0x1D5BB, "b"), (0x1D5BC, "c"), (0x1D5BD, "d"), (0x1D5BE, "e"), (0x1D5BF, "f"),
+    (0x1D5C0, "g"), (0x1D5C1, "h"), (0x1D5C2, "i"), (0x1D5C3, "j"), (0x1D5C4, "k"), (0x1D5C5, "l"),
+    (0x1D5C6, "rn"), (0x1D5C7, "n"), (0x1D5C8, "o"), (0x1D5C9, "p"), (0x1D5CA, "q"), (0x1D5CB, "r"),
+    (0x1D5CC, "s"), (0x1D5CD, "t"), (0x1D5CE, "u"), (0x1D5CF, "v"), (0x1D5D0, "w"), (0x1D5D1, "x"),
+    (0x1D5D2, "y"), (0x1D5D3, "z"), (0x1D5D4, "A"), (0x1D5D5, "B"), (0x1D5D6, "C"), (0x1D5D7, "D"),
+    (0x1D5D8, "E"), (0x1D5D9, "F"), (0x1D5DA, "G"), (0x1D5DB, "H"), (0x1D5DC, "l"), (0x1D5DD, "J"),
+    (0x1D5DE, "K"), (0x1D5DF, "L"), (0x1D5E0, "M"), (0x1D5E1, "N"), (0x1D5E2, "O"), (0x1D5E3, "P"),
+    (0x1D5E4, "Q"), (0x1D5E5, "R"), (0x1D5E6, "S"), (0x1D5E7, "T"), (0x1D5E8, "U"), (0x1D5E9, "V"),
+    (0x1D5EA, "W"), (0x1D5EB, "X"), (0x1D5EC, "Y"), (0x1D5ED, "Z"), (0x1D5EE, "a"), (0x1D5EF, "b"),
+    (0x1D5F0, "c"), (0x1D5F1, "d"), (0x1D5F2, "e"), (0x1D5F3, "f"), (0x1D5F4, "g"), (0x1D5F5, "h"),
+    (0x1D5F6, "i"), (0x1D5F7, "j"), (0x1D5F8, "k"), (0x1D5F9, "l"), (0x1D5FA, "rn"), (0x1D5FB, "n"),
+    (0x1D5FC, "o"), (0x1D5FD, "p"), (0x1D5FE, "q"), (0x1D5FF, "r"), (0x1D600, "s"), (0x1D601, "t"),
+    (0x1D602, "u"), (0x1D603, "v"), (0x1D604, "w"), (0x1D605, "x"), (0x1D606, "y"), (0x1D607, "z"),
+    (0x1D608, "A"), (0x1D609, "B"), (0x1D60A, "C"), (0x1D60B, "D"), (0x1D60C, "E"), (0x1D60D, "F"),
+    (0x1D60E, "G"), (0x1D60F, "H"), (0x1D610, "l"), (0x1D611, "J"), (0x1D612, "K"), (0x1D613, "L"),
+    (0x1D614, "M"), (0x1D615, "N"), (0x1D616, "O"), (0x1D617, "P"), (0x1D618, "Q"), (0x1D619, "R"),
+    (0x1D61A, "S"), (0x1D61B, "T"), (0x1D61C, "U"), (0x1D61D, "V"), (0x1D61E, "W"), (0x1D61F, "X"),
+    (0x1D620, "Y"), (0x1D621, "Z"), (0x1D622, "a"), (0x1D623, "b"), (0x1D624, "c"), (0x1D625, "d"),
+    (0x1D626, "e"), (0x1D627, "f"), (0x1D628, "g"), (0x1D629, "h"), (0x1D62A, "i"), (0x1D62B, "j"),
+    (0x1D62C, "k"), (0x1D62D, "l"), (0x1D62E, "rn"), (0x1D62F, "n"), (0x1D630, "o"), (0x1D631, "p"),
+    (0x1D632, "q"), (0x1D633, "r"), (0x1D634, "s"), (0x1D635, "t"), (0x1D636, "u"), (0x1D637, "v"),
+    (0x1D638, "w"), (0x1D639, "x"), (0x1D63A, "y"), (0x1D63B, "z"), (0x1D63C, "A"), (0x1D63D, "B"),
+    (0x1D63E, "C"), (0x1D63F, "D"), (0x1D640, "E"), (0x1D641, "F"), (0x1D642, "G"), (0x1D643, "H"),
+    (0x1D644, "l"), (0x1D645, "J"), (0x1D646, "K"), (0x1D647, "L"), (0x1D648, "M"), (0x1D649, "N"),
+    (0x1D64A, "O"), (0x1D64B, "P"), (0x1D64C, "Q"), (0x1D64D, "R"), (0x1D64E, "S"), (0x1D64F, "T"),
+    (0x1D650, "U"), (0x1D651, "V"), (0x1D652, "W"), (0x1D653, "X"), (0x1D654, "Y"), (0x1D655, "Z"),
+    (0x1D656, "a"), (0x1D657, "b"), (0x1D658, "c"), (0x1D659, "d"), (0x1D65A, "e"), (0x1D65B, "f"),
+    (0x1D65C, "g"), (0x1D65D, "h"), (0x1D65E, "i"), (0x1D65F, "j"), (0x1D660, "k"), (0x1D661, "l"),
+    (0x1D662, "rn"), (0x1D663, "n"), (0x1D664, "o"), (0x1D665, "p"), (0x1D666, "q"), (0x1D667, "r"),
+    (0x1D668, "s"), (0x1D669, "t"), (0x1D66A, "u"), (0x1D66B, "v"), (0x1D66C, "w"), (0x1D66D, "x"),
+    (0x1D66E, "y"), (0x1D66F, "z"), (0x1D670, "A"), (0x1D671, "B"), (0x1D672, "C"), (0x1D673, "D"),
+    (0x1D674, "E"), (0x1D675, "F"), (0x1D676, "G"), (0x1D677, "H"), (0x1D678, "l"), (0x1D679, "J"),
+    (0x1D67A, "K"), (0x1D67B, "L"), (0x1D67C, "M"), (0x1D67D, "N"), (0x1D67E, "O"), (0x1D67F, "P"),
+    (0x1D680, "Q"), (0x1D681, "R"), (0x1D682, "S"), (0x1D683, "T"), (0x1D684, "U"), (0x1D685, "V"),
+    (0x1D686, "W"), (0x1D687, "X"), (0x1D688, "Y"), (0x1D689, "Z"), (0x1D68A, "a"), (0x1D68B, "b"),
+    (0x1D68C, "c"), (0x1D68D, "d"), (0x1D68E, "e"), (0x1D68F, "f"), (0x1D690, "g"), (0x1D691, "h"),
+    (0x1D692, "i"), (0x1D693, "j"), (0x1D694, "k"), (0x1D695, "l"), (0x1D696, "rn"), (0x1D697, "n"),
+    (0x1D698, "o"), (0x1D699, "p"), (0x1D69A, "q"), (0x1D69B, "r"), (0x1D69C, "s"), (0x1D69D, "t"),
+    (0x1D69E, "u"), (0x1D69F, "v"), (0x1D6A0, "w"), (0x1D6A1, "x"), (0x1D6A2, "y"), (0x1D6A3, "z"),
+    (0x1D6A4, "i"), (0x1D6A5, "\u{237}"), (0x1D6A8, "A"), (0x1D6A9, "B"), (0x1D6AA, "\u{393}"),
+    (0x1D6AB, "\u{394}"), (0x1D6AC, "E"), (0x1D6AD, "Z"), (0x1D6AE, "H"), (0x1D6AF, "O\u{335}"),
+    (0x1D6B0, "l"), (0x1D6B1, "K"), (0x1D6B2, "\u{245}"), (0x1D6B3, "M"), (0x1D6B4, "N"),
+    (0x1D6B5, "\u{39E}"), (0x1D6B6, "O"), (0x1D6B7, "\u{3A0}"), (0x1D6B8, "P"),
+    (0x1D6B9, "O\u{335}"), (0x1D6BA, "\u{1A9}"), (0x1D6BB, "T"), (0x1D6BC, "Y"),
+    (0x1D6BD, "\u{3A6}"), (0x1D6BE, "X"), (0x1D6BF, "\u{3A8}"), (0x1D6C0, "\u{3A9}"),
+    (0x1D6C1, "\u{2207}"), (0x1D6C2, "a"), (0x1D6C3, "\u{DF}"), (0x1D6C4, "y"),
+    (0x1D6C5, "\u{1E9F}"), (0x1D6C6, "\u{A793}"), (0x1D6C7, "\u{3B6}"), (0x1D6C8, "n\u{329}"),
+    (0x1D6C9, "O\u{335}"), (0x1D6CA, "i"), (0x1D6CB, "\u{138}"), (0x1D6CC, "\u{3BB}"),
+    (0x1D6CD, "\u{3BC}"), (0x1D6CE, "v"), (0x1D6CF, "\u{3BE}"), (0x1D6D0, "o"),
+    (0x1D6D1, "\u{3C0}"), (0x1D6D2, "p"), (0x1D6D3, "\u{3C2}"), (0x1D6D4, "o"),
+    (0x1D6D5, "\u{1D1B}"), (0x1D6D6, "u"), (0x1D6D7, "\u{278}"), (0x1D6D8, "\u{3C7}"),
+    (0x1D6D9, "\u{3C8}"), (0x1D6DA, "\u{3C9}"), (0x1D6DB, "\u{2202}"), (0x1D6DC, "\u{A793}"),
+    (0x1D6DD, "O\u{335}"), (0x1D6DE, "\u{138}"), (0x1D6DF, "\u{278}"), (0x1D6E0, "p"),
+    (0x1D6E1, "\u{3C0}"), (0x1D6E2, "A"), (0x1D6E3, "B"), (0x1D6E4, "\u{393}"),
+    (0x1D6E5, "\u{394}"), (0x1D6E6, "E"), (0x1D6E7, "Z"), (0x1D6E8, "H"), (0x1D6E9, "O\u{335}"),
+    (0x1D6EA, "l"), (0x1D6EB, "K"), (0x1D6EC, "\u{245}"), (0x1D6ED, "M"), (0x1D6EE, "N"),
+    (0x1D6EF, "\u{39E}"), (0x1D6F0, "O"), (0x1D6F1, "\u{3A0}"), (0x1D6F2, "P"),
+    (0x1D6F3, "O\u{335}"), (0x1D6F4, "\u{1A9}"), (0x1D6F5, "T"), (0x1D6F6, "Y"),
+    (0x1D6F7, "\u{3A6}"), (0x1D6F8, "X"), (0x1D6F9, "\u{3A8}"), (0x1D6FA, "\u{3A9}"),
+    (0x1D6FB, "\u{2207}"), (0x1D6FC, "a"), (0x1D6FD, "\u{DF}"), (0x1D6FE, "y"),
+    (0x1D6FF, "\u{1E9F}"), (0x1D700, "\u{A793}"), (0x1D701, "\u{3B6}"), (0x1D702, "n\u{329}"),
+    (0x1D703, "O\u{335}"), (0x1D704, "i"), (0x1D705, "\u{138}"), (0x1D706, "\u{3BB}"),
+    (0x1D707, "\u{3BC}"), (0x1D708, "v"), (0x1D709, "\u{3BE}"), (0x1D70A, "o"),
+    (0x1D70B, "\u{3C0}"), (0x1D70C, "p"), (0x1D70D, "\u{3C2}"), (0x1D70E, "o"),
+    (0x1D70F, "\u{1D1B}"), (0x1D710, "u"), (0x1D711, "\u{278}"), (0x1D712, "\u{3C7}"),
+    (0x1D713, "\u{3C8}"), (0x1D714, "\u{3C9}"), (0x1D715, "\u{2202}"), (0x1D716, "\u{A793}"),
+    (0x1D717, "O\u{335}"), (0x1D718, "\u{138}"), (0x1D719, "\u{278}"), (0x1D71A, "p"),
+    (0x1D71B, "\u{3C0}"), (0x1D71C, "A"), (0x1D71D, "B"), (0x1D71E, "\u{393}"),
+    (0x1D71F, "\u{394}"), (0x1D720, "E"), (0x1D721, "Z"), (0x1D722, "H"), (0x1D723, "O\u{335}"),
+    (0x1D724, "l"), (0x1D725, "K"), (0x1D726, "\u{245}"), (0x1D727, "M"), (0x1D728, "N"),
+    (0x1D729, "\u{39E}"), (0x1D72A, "O"), (0x1D72B, "\u{3A0}"), (0x1D72C, "P"),
+    (0x1D72D, "O\u{335}"), (0x1D72E, "\u{1A9}"), (0x1D72F, "T"), (0x1D730, "Y"),
+    (0x1D731, "\u{3A6}"), (0x1D732, "X"), (0x1D733, "\u{3A8}"), (0x1D734, "\u{3A9}"),
+    (0x1D735, "\u{2207}"), (0x1D736, "a"), (0x1D737, "\u{DF}"), (0x1D738, "y"),
+    (0x1D739, "\u{1E9F}"), (0x1D73A, "\u{A793}"), (0x1D73B, "\u{3B6}"), (0x1D73C, "n\u{329}"),
+    (0x1D73D, "O\u{335}"), (0x1D73E, "i"), (0x1D73F, "\u{138}"), (0x1D740, "\u{3BB}"),
+    (0x1D741, "\u{3BC}"), (0x1D742, "v"), (0x1D743, "\u{3BE}"), (0x1D744, "o"),
+    (0x1D745, "\u{3C0}"), (0x1D746, "p"), (0x1D747, "\u{3C2}"), (0x1D748, "o"),
+    (0x1D749, "\u{1D1B}"), (0x1D74A, "u"), (0x1D74B, "\u{278}"), (0x1D74C, "\u{3C7}"),
+    (0x1D74D, "\u{3C8}"), (0x1D74E, "\u{3C9}"), (0x1D74F, "\u{2202}"), (0x1D750, "\u{A793}"),
+    (0x1D751, "O\u{335}"), (0x1D752, "\u{138}"), (0x1D753, "\u{278}"), (0x1D754, "p"),
+    (0x1D755, "\u{3C0}"), (0x1D756, "A"), (0x1D757, "B"), (0x1D758, "\u{393}"),
+    (0x1D759, "\u{394}"), (0x1D75A, "E"), (0x1D75B, "Z"), (0x1D75C, "H"), (0x1D75D, "O\u{335}"),
+    (0x1D75E, "l"), (0x1D75F, "K"), (0x1D760, "\u{245}"), (0x1D761, "M"), (0x1D762, "N"),
+    (0x1D763, "\u{39E}"), (0x1D764, "O"), (0x1D765, "\u{3A0}"), (0x1D766, "P"),
+    (0x1D767, "O\u{335}"), (0x1D768, "\u{1A9}"), (0x1D769, "T"), (0x1D76A, "Y"),
+    (0x1D76B, "\u{3A6}"), (0x1D76C, "X"), (0x1D76D, "\u{3A8}"), (0x1D76E, "\u{3A9}"),
+    (0x1D76F, "\u{2207}"), (0x1D770, "a"), (0x1D771, "\u{DF}"), (0x1D772, "y"),
+    (0x1D773, "\u{1E9F}"), (0x1D774, "\u{A793}"), (0x1D775, "\u{3B6}"), (0x1D776, "n\u{329}"),
+    (0x1D777, "O\u{335}"), (0x1D778, "i"), (0x1D779, "\u{138}"), (0x1D77A, "\u{3BB}"),
+    (0x1D77B, "\u{3BC}"), (0x1D77C, "v"), (0x1D77D, "\u{3BE}"), (0x1D77E, "o"),
+    (0x1D77F, "\u{3C0}"), (0x1D780, "p"), (0x1D781, "\u{3C2}"), (0x1D782, "o"),
+    (0x1D783, "\u{1D1B}"), (0x1D784, "u"), (0x1D785, "\u{278}"), (0x1D786, "\u{3C7}"),
+    (0x1D787, "\u{3C8}"), (0x1D788, "\u{3C9}"), (0x1D789, "\u{2202}"), (0x1D78A, "\u{A793}"),
+    (0x1D78B, "O\u{335}"), (0x1D78C, "\u{138}"), (0x1D78D, "\u{278}"), (0x1D78E, "p"),
+    (0x1D78F, "\u{3C0}"), (0x1D790, "A"), (0x1D791, "B"), (0x1D792, "\u{393}"),
+    (0x1D793, "\u{394}"), (0x1D794, "E"), (0x1D795, "Z"), (0x1D796, "H"), (0x1D797, "O\u{335}"),
+    (0x1D798, "l"), (0x1D799, "K"), (0x1D79A, "\u{245}"), (0x1D79B, "M"), (0x1D79C, "N"),
+    (0x1D79D, "\u{39E}"), (0x1D79E, "O"), (0x1D79F, "\u{3A0}"), (0x1D7A0, "P"),
+    (0x1D7A1, "O\u{335}"), (0x1D7A2, "\u{1A9}"), (0x1D7A3, "T"), (0x1D7A4, "Y"),
+    (0x1D7A5, "\u{3A6}"), (0x1D7A6, "X"), (0x1D7A7, "\u{3A8}"), (0x1D7A8, "\u{3A9}"),
+    (0x1D7A9, "\u{2207}"), (0x1D7AA, "a"), (0x1D7AB, "\u{DF}"), (0x1D7AC, "y"),
+    (0x1D7AD, "\u{1E9F}"), (0x1D7AE, "\u{A793}"), (0x1D7AF, "\u{3B6}"), (0x1D7B0, "n\u{329}"),
+    (0x1D7B1, "O\u{335}"), (0x1D7B2, "i"), (0x1D7B3, "\u{138}"), (0x1D7B4, "\u{3BB}"),
+    (0x1D7B5, "\u{3BC}"), (0x1D7B6, "v"), (0x1D7B7, "\u{3BE}"), (0x1D7B8, "o"),
+    (0x1D7B9, "\u{3C0}"), (0x1D7BA, "p"), (0x1D7BB, "\u{3C2}"), (0x1D7BC, "o"),
+    (0x1D7BD, "\u{1D1B}"), (0x1D7BE, "u"), (0x1D7BF, "\u{278}"), (0x1D7C0, "\u{3C7}"),
+    (0x1D7C1, "\u{3C8}"), (0x1D7C2, "\u{3C9}"), (0x1D7C3, "\u{2202}"), (0x1D7C4, "\u{A793}"),
+    (0x1D7C5, "O\u{335}"), (0x1D7C6, "\u{138}"), (0x1D7C7, "\u{278}"), (0x1D7C8, "p"),
+    (0x1D7C9, "\u{3C0}"), (0x1D7CA, "F"), (0x1D7CB, "\u{3DD}"), (0x1D7CE, "O"), (0x1D7CF, "l"),
+    (0x1D7D0, "2"), (0x1D7D1, "3"), (0x1D7D2, "4"), (0x1D7D3, "5"), (0x1D7D4, "6"), (0x1D7D5, "7"),
+    (0x1D7D6, "8"), (0x1D7D7, "9"), (0x1D7D8, "O"), (0x1D7D9, "l"), (0x1D7DA, "2"), (0x1D7DB, "3"),
+    (0x1D7DC, "4"), (0x1D7DD, "5"), (0x1D7DE, "6"), (0x1D7DF, "7"), (0x1D7E0, "8"), (0x1D7E1, "9"),
+    (0x1D7E2, "O"), (0x1D7E3, "l"), (0x1D7E4, "2"), (0x1D7E5, "3"), (0x1D7E6, "4"), (0x1D7E7, "5"),
+    (0x1D7E8, "6"), (0x1D7E9, "7"), (0x1D7EA, "8"), (0x1D7EB, "9"), (0x1D7EC, "O"), (0x1D7ED, "l"),
+    (0x1D7EE, "2"), (0x1D7EF, "3"), (0x1D7F0, "4"), (0x1D7F1, "5"), (0x1D7F2, "6"), (0x1D7F3, "7"),
+    (0x1D7F4, "8"), (0x1D7F5, "9"), (0x1D7F6, "O"), (0x1D7F7, "l"), (0x1D7F8, "2"), (0x1D7F9, "3"),
+    (0x1D7FA, "4"), (0x1D7FB, "5"), (0x1D7FC, "6"), (0x1D7FD, "7"), (0x1D7FE, "8"), (0x1D7FF, "9"),
+    (0x1E8C7, "l"), (0x1E8C8, "\u{2220}"), (0x1E8C9, "\u{663}"), (0x1E8CB, "8"),
+    (0x1E8CC, "\u{2202}"), (0x1E8CD, "\u{2202}\u{335}"), (0x1EE00, "l"), (0x1EE01, "\u{628}"),
+    (0x1EE02, "\u{62C}"), (0x1EE03, "\u{62F}"), (0x1EE05, "\u{648}"), (0x1EE06, "\u{632}"),
+    (0x1EE07, "\u{62D}"), (0x1EE08, "\u{637}"), (0x1EE09, "\u{649}"), (0x1EE0A, "\u{643}"),
+    (0x1EE0B, "\u{644}"), (0x1EE0C, "\u{645}"), (0x1EE0D, "\u{646}"), (0x1EE0E, "\u{633}"),
+    (0x1EE0F, "\u{639}"), (0x1EE10, "\u{641}"), (0x1EE11, "\u{635}"), (0x1EE12, "\u{642}"),
+    (0x1EE13, "\u{631}"), (0x1EE14, "\u{633}\u{6DB}"), (0x1EE15, "\u{62A}"),
+    (0x1EE16, "\u{649}\u{6DB}"), (0x1EE17, "\u{62E}"), (0x1EE18, "\u{630}"), (0x1EE19, "\u{636}"),
+    (0x1EE1A, "\u{638}"), (0x1EE1B, "\u{63A}"), (0x1EE1C, "\u{649}"), (0x1EE1D, "\u{649}"),
+    (0x1EE1E, "\u{6A1}"), (0x1EE1F, "\u{6A1}"), (0x1EE21, "\u{628}"), (0x1EE22, "\u{62C}"),
+    (0x1EE24, "o"), (0x1EE27, "\u{62D}"), (0x1EE29, "\u{649}"), (0x1EE2A, "\u{643}"),
+    (0x1EE2B, "\u{644}"), (0x1EE2C, "\u{645}"), (0x1EE2D, "\u{646}"), (0x1EE2E, "\u{633}"),
+    (0x1EE2F, "\u{639}"), (0x1EE30, "\u{641}"), (0x1EE31, "\u{635}"), (0x1EE32, "\u{642}"),
+    (0x1EE34, "\u{633}\u{6DB}"), (0x1EE35, "\u{62A}"), (0x1EE36, "\u{649}\u{6DB}"),
+    (0x1EE37, "\u{62E}"), (0x1EE39, "\u{636}"), (0x1EE3B, "\u{63A}"), (0x1EE42, "\u{62C}"),
+    (0x1EE47, "\u{62D}"), (0x1EE49, "\u{649}"), (0x1EE4B, "\u{644}"), (0x1EE4D, "\u{646}"),
+    (0x1EE4E, "\u{633}"), (0x1EE4F, "\u{639}"), (0x1EE51, "\u{635}"), (0x1EE52, "\u{642}"),
+    (0x1EE54, "\u{633}\u{6DB}"), (0x1EE57, "\u{62E}"), (0x1EE59, "\u{636}"), (0x1EE5B, "\u{63A}"),
+    (0x1EE5D, "\u{649}"), (0x1EE5F, "\u{6A1}"), (0x1EE61, "\u{628}"), (0x1EE62, "\u{62C}"),
+    (0x1EE64, "o"), (0x1EE67, "\u{62D}"), (0x1EE68, "\u{637}"), (0x1EE69, "\u{649}"),
+    (0x1EE6A, "\u{643}"), (0x1EE6C, "\u{645}"), (0x1EE6D, "\u{646}"), (0x1EE6E, "\u{633}"),
+    (0x1EE6F, "\u{639}"), (0x1EE70, "\u{641}"), (0x1EE71, "\u{635}"), (0x1EE72, "\u{642}"),
+    (0x1EE74, "\u{633}\u{6DB}"), (0x1EE75, "\u{62A}"), (0x1EE76, "\u{649}\u{6DB}"),
+    (0x1EE77, "\u{62E}"), (0x1EE79, "\u{636}"), (0x1EE7A, "\u{638}"), (0x1EE7B, "\u{63A}"),
+    (0x1EE7C, "\u{649}"), (0x1EE7E, "\u{6A1}"), (0x1EE80, "l"), (0x1EE81, "\u{628}"),
+    (0x1EE82, "\u{62C}"), (0x1EE83, "\u{62F}"), (0x1EE84, "o"), (0x1EE85, "\u{648}"),
+    (0x1EE86, "\u{632}"), (0x1EE87, "\u{62D}"), (0x1EE88, "\u{637}"), (0x1EE89, "\u{649}"),
+    (0x1EE8B, "\u{644}"), (0x1EE8C, "\u{645}"), (0x1EE8D, "\u{646}"), (0x1EE8E, "\u{633}"),
+    (0x1EE8F, "\u{639}"), (0x1EE90, "\u{641}"), (0x1EE91, "\u{635}"), (0x1EE92, "\u{642}"),
+    (0x1EE93, "\u{631}"), (0x1EE94, "\u{633}\u{6DB}"), (0x1EE95, "\u{62A}"),
+    (0x1EE96, "\u{649}\u{6DB}"), (0x1EE97, "\u{62E}"), (0x1EE98, "\u{630}"), (0x1EE99, "\u{636}"),
+    (0x1EE9A, "\u{638}"), (0x1EE9B, "\u{63A}"), (0x1EEA1, "\u{628}"), (0x1EEA2, "\u{62C}"),
+    (0x1EEA3, "\u{62F}"), (0x1EEA5, "\u{648}"), (0x1EEA6, "\u{632}"), (0x1EEA7, "\u{62D}"),
+    (0x1EEA8, "\u{637}"), (0x1EEA9, "\u{649}"), (0x1EEAB, "\u{644}"), (0x1EEAC, "\u{645}"),
+    (0x1EEAD, "\u{646}"), (0x1EEAE, "\u{633}"), (0x1EEAF, "\u{639}"), (0x1EEB0, "\u{641}"),
+    (0x1EEB1, "\u{635}"), (0x1EEB2, "\u{642}"), (0x1EEB3, "\u{631}"), (0x1EEB4, "\u{633}\u{6DB}"),
+    (0x1EEB5, "\u{62A}"), (0x1EEB6, "\u{649}\u{6DB}"), (0x1EEB7, "\u{62E}"), (0x1EEB8, "\u{630}"),
+    (0x1EEB9, "\u{636}"), (0x1EEBA, "\u{638}"), (0x1EEBB, "\u{63A}"), (0x1F100, "O."),
+    (0x1F101, "O,"), (0x1F102, "l,"), (0x1F103, "2,"), (0x1F104, "3,"), (0x1F105, "4,"),
+    (0x1F106, "5,"), (0x1F107, "6,"), (0x1F108, "7,"), (0x1F109, "8,"), (0x1F10A, "9,"),
+    (0x1F10F, "$\u{20E0}"), (0x1F110, "(A)"), (0x1F111, "(B)"), (0x1F112, "(C)"), (0x1F113, "(D)"),
+    (0x1F114, "(E)"), (0x1F115, "(F)"), (0x1F116, "(G)"), (0x1F117, "(H)"), (0x1F118, "(l)"),
+    (0x1F119, "(J)"), (0x1F11A, "(K)"), (0x1F11B, "(L)"), (0x1F11C, "(M)"), (0x1F11D, "(N)"),
+    (0x1F11E, "(O)"), (0x1F11F, "(P)"), (0x1F120, "(Q)"), (0x1F121, "(R)"), (0x1F122, "(S)"),
+    (0x1F123, "(T)"), (0x1F124, "(U)"), (0x1F125, "(V)"), (0x1F126, "(W)"), (0x1F127, "(X)"),
+    (0x1F128, "(Y)"), (0x1F129, "(Z)"), (0x1F12A, "(S)"), (0x1F16D, "\u{33C4}\u{9}\u{20DD}"),
+    (0x1F16E, "C\u{20E0}"), (0x1F240, "(\u{672C})"), (0x1F241, "(\u{4E09})"),
+    (0x1F242, "(\u{4E8C})"), (0x1F243, "(\u{5B89})"), (0x1F244, "(\u{70B9})"),
+    (0x1F245, "(\u{6253})"), (0x1F246, "(\u{76D7})"), (0x1F247, "(\u{52DD})"),
+    (0x1F248, "(\u{6557})"), (0x1F312, "\u{263D}"), (0x1F318, "\u{263E}"), (0x1F319, "\u{263D}"),
+    (0x1F700, "QE"), (0x1F701, "\u{A658}"), (0x1F702, "\u{394}"), (0x1F704, "\u{102BC}"),
+    (0x1F707, "AR"), (0x1F708, "V\u{1DE4}"), (0x1F70A, "\u{2629}"), (0x1F714, "O\u{335}"),
+    (0x1F728, "\u{102A8}"), (0x1F73A, "\u{29DF}"), (0x1F74C, "C"), (0x1F754, "\u{16DC}"),
+    (0x1F755, "\u{22A1}"), (0x1F75C, "sss"), (0x1F75E, "\u{224F}"), (0x1F768, "T"), (0x1F76B, "MB"),
+    (0x1F76C, "VB"), (0x1F771, "\u{22A0}"), (0x1FBF0, "O"), (0x1FBF1, "l"), (0x1FBF2, "2"),
+    (0x1FBF3, "3"), (0x1FBF4, "4"), (0x1FBF5, "5"), (0x1FBF6, "6"), (0x1FBF7, "7"), (0x1FBF8, "8"),
+    (0x1FBF9, "9"), (0x21FE8, "\u{276C}"), (0x2F800, "\u{4E3D}"), (0x2F801, "\u{4E38}"),
+    (0x2F802, "\u{4E41}"), (0x2F803, "\u{20122}"), (0x2F804, "\u{4F60}"), (0x2F805, "\u{4FAE}"),
+    (0x2F806, "\u{4FBB}"), (0x2F807, "\u{4F75}"), (0x2F808, "\u{507A}"), (0x2F809, "\u{5099}"),
+    (0x2F80A, "\u{50E7}"), (0x2F80B, "\u{50CF}"), (0x2F80C, "\u{349E}"), (0x2F80D, "\u{2063A}"),
+    (0x2F80E, "\u{514D}"), (0x2F80F, "\u{5154}"), (0x2F810, "\u{5164}"), (0x2F811, "\u{5177}"),
+    (0x2F812, "\u{2051C}"), (0x2F813, "\u{34B9}"), (0x2F814, "\u{5167}"), (0x2F815, "\u{518D}"),
+    (0x2F816, "\u{2054B}"), (0x2F817, "\u{5197}"), (0x2F818, "\u{51A4}"), (0x2F819, "\u{4ECC}"),
+    (0x2F81A, "\u{51AC}"), (0x2F81B, "\u{51B5}"), (0x2F81C, "\u{291DF}"), (0x2F81D, "\u{51F5}"),
+    (0x2F81E, "\u{5203}"), (0x2F81F, "\u{34DF}"), (0x2F820, "\u{523B}"), (0x2F821, "\u{5246}"),
+    (0x2F822, "\u{5272}"), (0x2F823, "\u{5277}"), (0x2F824, "\u{3515}"), (0x2F825, "\u{52C7}"),
+    (0x2F826, "\u{52C9}"), (0x2F827, "\u{52E4}"), (0x2F828, "\u{52FA}"), (0x2F829, "\u{5305}"),
+    (0x2F82A, "\u{5306}"), (0x2F82B, "\u{5317}"), (0x2F82C, "\u{5349}"), (0x2F82D, "\u{5351}"),
+    (0x2F82E, "\u{535A}"), (0x2F82F, "\u{5373}"), (0x2F830, "\u{537D}"), (0x2F831, "\u{537F}"),
+    (0x2F832, "\u{537F}"), (0x2F833, "\u{537F}"), (0x2F834, "\u{20A2C}"), (0x2F835, "\u{7070}"),
+    (0x2F836, "\u{53CA}"), (0x2F837, "\u{53DF}"), (0x2F838, "\u{20B63}"), (0x2F839, "\u{53EB}"),
+    (0x2F83A, "\u{53F1}"), (0x2F83B, "\u{5406}"), (0x2F83C, "\u{549E}"), (0x2F83D, "\u{5438}"),
+    (0x2F83E, "\u{5448}"), (0x2F83F, "\u{5468}"), (0x2F840, "\u{54A2}"), (0x2F841, "\u{54F6}"),
+    (0x2F842, "\u{5510}"), (0x2F843, "\u{5553}"), (0x2F844, "\u{5563}"), (0x2F845, "\u{5584}"),
+    (0x2F846, "\u{5584}"), (0x2F847, "\u{5599}"), (0x2F848, "\u{55AB}"), (0x2F849, "\u{55B3}"),
+    (0x2F84A, "\u{55C2}"), (0x2F84B, "\u{5716}"), (0x2F84C, "\u{5606}"), (0x2F84D, "\u{5717}"),
+    (0x2F84E, "\u{5651}"), (0x2F84F, "\u{5674}"), (0x2F850, "\u{5207}"), (0x2F851, "\u{58EE}"),
+    (0x2F852, "\u{57CE}"), (0x2F853, "\u{57F4}"), (0x2F854, "\u{580D}"), (0x2F855, "\u{578B}"),
+    (0x2F856, "\u{5832}"), (0x2F857, "\u{5831}"), (0x2F858, "\u{58AC}"), (0x2F859, "\u{214E4}"),
+    (0x2F85A, "\u{58F2}"), (0x2F85B, "\u{58F7}"), (0x2F85C, "\u{5906}"), (0x2F85D, "\u{591A}"),
+    (0x2F85E, "\u{5922}"), (0x2F85F, "\u{5962}"), (0x2F860, "\u{216A8}"), (0x2F861, "\u{216EA}"),
+    (0x2F862, "\u{59EC}"), (0x2F863, "\u{5A1B}"), (0x2F864, "\u{5A27}"), (0x2F865, "\u{59D8}"),
+    (0x2F866, "\u{5A66}"), (0x2F867, "\u{36EE}"), (0x2F868, "\u{36FC}"), (0x2F869, "\u{5B08}"),
+    (0x2F86A, "\u{5B3E}"), (0x2F86B, "\u{5B3E}"), (0x2F86C, "\u{219C8}"), (0x2F86D, "\u{5BC3}"),
+    (0x2F86E, "\u{5BD8}"), (0x2F86F, "\u{5BE7}"), (0x2F870, "\u{5BF3}"), (0x2F871, "\u{21B18}"),
+    (0x2F872, "\u{5BFF}"), (0x2F873, "\u{5C06}"), (0x2F874, "\u{5F53}"), (0x2F875, "\u{5C22}"),
+    (0x2F876, "\u{3781}"), (0x2F877, "\u{5C60}"), (0x2F878, "\u{5C6E}"), (0x2F879, "\u{5CC0}"),
+    (0x2F87A, "\u{5C8D}"), (0x2F87B, "\u{21DE4}"), (0x2F87C, "\u{5D43}"), (0x2F87D, "\u{21DE6}"),
+    (0x2F87E, "\u{5D6E}"), (0x2F87F, "\u{5D6B}"), (0x2F880, "\u{5D7C}"), (0x2F881, "\u{5DE1}"),
+    (0x2F882, "\u{5DE2}"), (0x2F883, "\u{382F}"), (0x2F884, "\u{5DFD}"), (0x2F885, "\u{5E28}"),
+    (0x2F886, "\u{5E3D}"), (0x2F887, "\u{5E69}"), (0x2F888, "\u{3862}"), (0x2F889, "\u{22183}"),
+    (0x2F88A, "\u{387C}"), (0x2F88B, "\u{5EB0}"), (0x2F88C, "\u{5EB3}"), (0x2F88D, "\u{5EB6}"),
+    (0x2F88E, "\u{5ECA}"), (0x2F88F, "\u{2A392}"), (0x2F890, "\u{5EFE}"), (0x2F891, "\u{22331}"),
+    (0x2F892, "\u{22331}"), (0x2F893, "\u{8201}"), (0x2F894, "\u{5F22}"), (0x2F895, "\u{5F22}"),
+    (0x2F896, "\u{38C7}"), (0x2F897, "\u{232B8}"), (0x2F898, "\u{261DA}"), (0x2F899, "\u{5F62}"),
+    (0x2F89A, "\u{5F6B}"), (0x2F89B, "\u{38E3}"), (0x2F89C, "\u{5F9A}"), (0x2F89D, "\u{5FCD}"),
+    (0x2F89E, "\u{5FD7}"), (0x2F89F, "\u{5FF9}"), (0x2F8A0, "\u{6081}"), (0x2F8A1, "\u{393A}"),
+    (0x2F8A2, "\u{391C}"), (0x2F8A3, "\u{6094}"), (0x2F8A4, "\u{226D4}"), (0x2F8A5, "\u{60C7}"),
+    (0x2F8A6, "\u{6148}"), (0x2F8A7, "\u{614C}"), (0x2F8A8, "\u{614E}"), (0x2F8A9, "\u{614C}"),
+    (0x2F8AA, "\u{617A}"), (0x2F8AB, "\u{618E}"), (0x2F8AC, "\u{61B2}"), (0x2F8AD, "\u{61A4}"),
+    (0x2F8AE, "\u{61AF}"), (0x2F8AF, "\u{61DE}"), (0x2F8B0, "\u{61F2}"), (0x2F8B1, "\u{61F6}"),
+    (0x2F8B2, "\u{6210}"), (0x2F8B3, "\u{621B}"), (0x2F8B4, "\u{625D}"), (0x2F8B5, "\u{62B1}"),
+    (0x2F8B6, "\u{62D4}"), (0x2F8B7, "\u{6350}"), (0x2F8B8, "\u{22B0C}"), (0x2F8B9, "\u{633D}"),
+    (0x2F8BA, "\u{62FC}"), (0x2F8BB, "\u{6368}"), (0x2F8BC, "\u{6383}"), (0x2F8BD, "\u{63E4}"),
+    (0x2F8BE, "\u{22BF1}"), (0x2F8BF, "\u{6422}"), (0x2F8C0, "\u{63C5}"), (0x2F8C1, "\u{63A9}"),
+    (0x2F8C2, "\u{3A2E}"), (0x2F8C3, "\u{6469}"), (0x2F8C4, "\u{647E}"), (0x2F8C5, "\u{649D}"),
+    (0x2F8C6, "\u{6477}"), (0x2F8C7, "\u{3A6C}"), (0x2F8C8, "\u{654F}"), (0x2F8C9, "\u{656C}"),
+    (0x2F8CA, "\u{2300A}"), (0x2F8CB, "\u{65E3}"), (0x2F8CC, "\u{66F8}"), (0x2F8CD, "\u{6649}"),
+    (0x2F8CE, "\u{3B19}"), (0x2F8CF, "\u{6691}"), (0x2F8D0, "\u{3B08}"), (0x2F8D1, "\u{3AE4}"),
+    (0x2F8D2, "\u{5192}"), (0x2F8D3, "\u{5195}"), (0x2F8D4, "\u{6700}"), (0x2F8D5, "\u{669C}"),
+    (0x2F8D6, "\u{80AD}"), (0x2F8D7, "\u{43D9}"), (0x2F8D8, "\u{6717}"), (0x2F8D9, "\u{671B}"),
+    (0x2F8DA, "\u{6721}"), (0x2F8DB, "\u{675E}"), (0x2F8DC, "\u{6753}"), (0x2F8DD, "\u{233C3}"),
+    (0x2F8DE, "\u{3B49}"), (0x2F8DF, "\u{67FA}"), (0x2F8E0, "\u{6785}"), (0x2F8E1, "\u{6852}"),
+    (0x2F8E2, "\u{6885}"), (0x2F8E3, "\u{2346D}"), (0x2F8E4, "\u{688E}"), (0x2F8E5, "\u{681F}"),
+    (0x2F8E6, "\u{6914}"), (0x2F8E7, "\u{3B9D}"), (0x2F8E8, "\u{6942}"), (0x2F8E9, "\u{69A3}"),
+    (0x2F8EA, "\u{69EA}"), (0x2F8EB, "\u{6AA8}"), (0x2F8EC, "\u{236A3}"), (0x2F8ED, "\u{6ADB}"),
+    (0x2F8EE, "\u{3C18}"), (0x2F8EF, "\u{6B21}"), (0x2F8F0, "\u{238A7}"), (0x2F8F1, "\u{6B54}"),
+    (0x2F8F2, "\u{3C4E}"), (0x2F8F3, "\u{6B72}"), (0x2F8F4, "\u{6B9F}"), (0x2F8F5, "\u{6BBA}"),
+    (0x2F8F6, "\u{6BBB}"), (0x2F8F7, "\u{23A8D}"), (0x2F8F8, "\u{21D0B}"), (0x2F8F9, "\u{23AFA}"),
+    (0x2F8FA, "\u{6C4E}"), (0x2F8FB, "\u{23CBC}"), (0x2F8FC, "\u{6CBF}"), (0x2F8FD, "\u{6CCD}"),
+    (0x2F8FE, "\u{6C67}"), (0x2F8FF, "\u{6D16}"), (0x2F900, "\u{6D3E}"), (0x2F901, "\u{6D77}"),
+    (0x2F902, "\u{6D41}"), (0x2F903, "\u{6D69}"), (0x2F904, "\u{6D78}"), (0x2F905, "\u{6D85}"),
+    (0x2F906, "\u{23D1E}"), (0x2F907, "\u{6D34}"), (0x2F908, "\u{6E2F}"), (0x2F909, "\u{6E6E}"),
+    (0x2F90A, "\u{3D33}"), (0x2F90B, "\u{6ECB}"), (0x2F90C, "\u{6EC7}"), (0x2F90D, "\u{23ED1}"),
+    (0x2F90E, "\u{6DF9}"), (0x2F90F, "\u{6F6E}"), (0x2F910, "\u{23F5E}"), (0x2F911, "\u{23F8E}"),
+    (0x2F912, "\u{6FC6}"), (0x2F913, "\u{7039}"), (0x2F914, "\u{701E}"), (0x2F915, "\u{701B}"),
+    (0x2F916, "\u{3D96}"), (0x2F917, "\u{704A}"), (0x2F918, "\u{707D}"), (0x2F919, "\u{7077}"),
+    (0x2F91A, "\u{70AD}"), (0x2F91B, "\u{20525}"), (0x2F91C, "\u{7145}"), (0x2F91D, "\u{24263}"),
+    (0x2F91E, "\u{719C}"), (0x2F91F, "\u{243AB}"), (0x2F920, "\u{7228}"), (0x2F921, "\u{7235}"),
+    (0x2F922, "\u{7250}"), (0x2F923, "\u{24608}"), (0x2F924, "\u{7280}"), (0x2F925, "\u{7295}"),
+    (0x2F926, "\u{24735}"), (0x2F927, "\u{24814}"), (0x2F928, "\u{737A}"), (0x2F929, "\u{738B}"),
+    (0x2F92A, "\u{3EAC}"), (0x2F92B, "\u{73A5}"), (0x2F92C, "\u{3EB8}"), (0x2F92D, "\u{3EB8}"),
+    (0x2F92E, "\u{7447}"), (0x2F92F, "\u{745C}"), (0x2F930, "\u{7471}"), (0x2F931, "\u{7485}"),
+    (0x2F932, "\u{74CA}"), (0x2F933, "\u{3F1B}"), (0x2F934, "\u{7524}"), (0x2F935, "\u{24C36}"),
+    (0x2F936, "\u{753E}"), (0x2F937, "\u{24C92}"), (0x2F938, "\u{7570}"), (0x2F939, "\u{2219F}"),
+    (0x2F93A, "\u{7610}"), (0x2F93B, "\u{24FA1}"), (0x2F93C, "\u{24FB8}"), (0x2F93D, "\u{25044}"),
+    (0x2F93E, "\u{3FFC}"), (0x2F93F, "\u{4008}"), (0x2F940, "\u{76F4}"), (0x2F941, "\u{250F3}"),
+    (0x2F942, "\u{250F2}"), (0x2F943, "\u{25119}"), (0x2F944, "\u{25133}"), (0x2F945, "\u{771E}"),
+    (0x2F946, "\u{771F}"), (0x2F947, "\u{771F}"), (0x2F948, "\u{774A}"), (0x2F949, "\u{4039}"),
+    (0x2F94A, "\u{778B}"), (0x2F94B, "\u{4046}"), (0x2F94C, "\u{4096}"), (0x2F94D, "\u{2541D}"),
+    (0x2F94E, "\u{784E}"), (0x2F94F, "\u{788C}"), (0x2F950, "\u{78CC}"), (0x2F951, "\u{40E3}"),
+    (0x2F952, "\u{25626}"), (0x2F953, "\u{7956}"), (0x2F954, "\u{2569A}"), (0x2F955, "\u{256C5}"),
+    (0x2F956, "\u{798F}"), (0x2F957, "\u{79EB}"), (0x2F958, "\u{412F}"), (0x2F959, "\u{7A40}"),
+    (0x2F95A, "\u{7A4A}"), (0x2F95B, "\u{7A4F}"), (0x2F95C, "\u{2597C}"), (0x2F95D, "\u{25AA7}"),
+    (0x2F95E, "\u{25AA7}"), (0x2F95F, "\u{7AEE}"), (0x2F960, "\u{4202}"), (0x2F961, "\u{25BAB}"),
+    (0x2F962, "\u{7BC6}"), (0x2F963, "\u{7BC9}"), (0x2F964, "\u{4227}"), (0x2F965, "\u{25C80}"),
+    (0x2F966, "\u{7CD2}"), (0x2F967, "\u{42A0}"), (0x2F968, "\u{7CE8}"), (0x2F969, "\u{7CE3}"),
+    (0x2F96A, "\u{7D00}"), (0x2F96B, "\u{25F86}"), (0x2F96C, "\u{7D63}"), (0x2F96D, "\u{4301}"),
+    (0x2F96E, "\u{7DC7}"), (0x2F96F, "\u{7E02}"), (0x2F970, "\u{7E45}"), (0x2F971, "\u{4334}"),
+    (0x2F972, "\u{26228}"), (0x2F973, "\u{26247}"), (0x2F974, "\u{4359}"), (0x2F975, "\u{262D9}"),
+    (0x2F976, "\u{7F7A}"), (0x2F977, "\u{2633E}"), (0x2F978, "\u{7F95}"), (0x2F979, "\u{7FFA}"),
+    (0x2F97A, "\u{8005}"), (0x2F97B, "\u{264DA}"), (0x2F97C, "\u{26523}"), (0x2F97D, "\u{8060}"),
+    (0x2F97E, "\u{265A8}"), (0x2F97F, "\u{8070}"), (0x2F980, "\u{2335F}"), (0x2F981, "\u{43D5}"),
+    (0x2F982, "\u{80B2}"), (0x2F983, "\u{8103}"), (0x2F984, "\u{440B}"), (0x2F985, "\u{813E}"),
+    (0x2F986, "\u{5AB5}"), (0x2F987, "\u{267A7}"), (0x2F988, "\u{267B5}"), (0x2F989, "\u{23393}"),
+    (0x2F98A, "\u{2339C}"), (0x2F98B, "\u{8201}"), (0x2F98C, "\u{8204}"), (0x2F98D, "\u{8F9E}"),
+    (0x2F98E, "\u{446B}"), (0x2F98F, "\u{8291}"), (0x2F990, "\u{828B}"), (0x2F991, "\u{829D}"),
+    (0x2F992, "\u{52B3}"), (0x2F993, "\u{82B1}"), (0x2F994, "\u{82B3}"), (0x2F995, "\u{82BD}"),
+    (0x2F996, "\u{82E6}"), (0x2F997, "\u{26B3C}"), (0x2F998, "\u{82E5}"), (0x2F999, "\u{831D}"),
+    (0x2F99A, "\u{8363}"), (0x2F99B, "\u{83AD}"), (0x2F99C, "\u{8323}"), (0x2F99D, "\u{83BD}"),
+    (0x2F99E, "\u{83E7}"), (0x2F99F, "\u{8457}"), (0x2F9A0, "\u{8353}"), (0x2F9A1, "\u{83CA}"),
+    (0x2F9A2, "\u{83CC}"), (0x2F9A3, "\u{83DC}"), (0x2F9A4, "\u{26C36}"), (0x2F9A5, "\u{26D6B}"),
+    (0x2F9A6, "\u{26CD5}"), (0x2F9A7, "\u{452B}"), (0x2F9A8, "\u{84F1}"), (0x2F9A9, "\u{84F3}"),
+    (0x2F9AA, "\u{8516}"), (0x2F9AB, "\u{273CA}"), (0x2F9AC, "\u{8564}"), (0x2F9AD, "\u{26F2C}"),
+    (0x2F9AE, "\u{455D}"), (0x2F9AF, "\u{4561}"), (0x2F9B0, "\u{26FB1}"), (0x2F9B1, "\u{270D2}"),
+    (0x2F9B2, "\u{456B}"), (0x2F9B3, "\u{8650}"), (0x2F9B4, "\u{865C}"), (0x2F9B5, "\u{8667}"),
+    (0x2F9B6, "\u{8669}"), (0x2F9B7, "\u{86A9}"), (0x2F9B8, "\u{8688}"), (0x2F9B9, "\u{870E}"),
+    (0x2F9BA, "\u{86E2}"), (0x2F9BB, "\u{8779}"), (0x2F9BC, "\u{8728}"), (0x2F9BD, "\u{876B}"),
+    (0x2F9BE, "\u{8786}"), (0x2F9BF, "\u{45D7}"), (0x2F9C0, "\u{87E1}"), (0x2F9C1, "\u{8801}"),
+    (0x2F9C2, "\u{45F9}"), (0x2F9C3, "\u{8860}"), (0x2F9C4, "\u{8863}"), (0x2F9C5, "\u{27667}"),
+    (0x2F9C6, "\u{88D7}"), (0x2F9C7, "\u{88DE}"), (0x2F9C8, "\u{4635}"), (0x2F9C9, "\u{88FA}"),
+    (0x2F9CA, "\u{34BB}"), (0x2F9CB, "\u{278AE}"), (0x2F9CC, "\u{27966}"), (0x2F9CD, "\u{46BE}"),
+    (0x2F9CE, "\u{46C7}"), (0x2F9CF, "\u{8AA0}"), (0x2F9D0, "\u{8AED}"), (0x2F9D1, "\u{8B8A}"),
+    (0x2F9D2, "\u{8C55}"), (0x2F9D3, "\u{27CA8}"), (0x2F9D4, "\u{8CAB}"), (0x2F9D5, "\u{8CC1}"),
+    (0x2F9D6, "\u{8D1B}"), (0x2F9D7, "\u{8D77}"), (0x2F9D8, "\u{27F2F}"), (0x2F9D9, "\u{20804}"),
+    (0x2F9DA, "\u{8DCB}"), (0x2F9DB, "\u{8DBC}"), (0x2F9DC, "\u{8DF0}"), (0x2F9DD, "\u{208DE}"),
+    (0x2F9DE, "\u{8ED4}"), (0x2F9DF, "\u{8F38}"), (0x2F9E0, "\u{285D2}"), (0x2F9E1, "\u{285ED}"),
+    (0x2F9E2, "\u{9094}"), (0x2F9E3, "\u{90F1}"), (0x2F9E4, "\u{9111}"), (0x2F9E5, "\u{2872E}"),
+    (0x2F9E6, "\u{911B}"), (0x2F9E7, "\u{9238}"), (0x2F9E8, "\u{92D7}"), (0x2F9E9, "\u{92D8}"),
+    (0x2F9EA, "\u{927C}"), (0x2F9EB, "\u{93F9}"), (0x2F9EC, "\u{9415}"), (0x2F9ED, "\u{28BFA}"),
+    (0x2F9EE, "\u{958B}"), (0x2F9EF, "\u{4995}"), (0x2F9F0, "\u{95B7}"), (0x2F9F1, "\u{28D77}"),
+    (0x2F9F2, "\u{49E6}"), (0x2F9F3, "\u{96C3}"), (0x2F9F4, "\u{5DB2}"), (0x2F9F5, "\u{9723}"),
+    (0x2F9F6, "\u{29145}"), (0x2F9F7, "\u{2921A}"), (0x2F9F8, "\u{4A6E}"), (0x2F9F9, "\u{4A76}"),
+    (0x2F9FA, "\u{97E0}"), (0x2F9FB, "\u{2940A}"), (0x2F9FC, "\u{4AB2}"), (0x2F9FD, "\u{29496}"),
+    (0x2F9FE, "\u{980B}"), (0x2F9FF, "\u{980B}"), (0x2FA00, "\u{9829}"), (0x2FA01, "\u{295B6}"),
+    (0x2FA02, "\u{98E2}"), (0x2FA03, "\u{4B33}"), (0x2FA04, "\u{9929}"), (0x2FA05, "\u{99A7}"),
+    (0x2FA06, "\u{99C2}"), (0x2FA07, "\u{99FE}"), (0x2FA08, "\u{4BCE}"), (0x2FA09, "\u{29B30}"),
+    (0x2FA0A, "\u{9B12}"), (0x2FA0B, "\u{9C40}"), (0x2FA0C, "\u{9CFD}"), (0x2FA0D, "\u{4CCE}"),
+    (0x2FA0E, "\u{4CED}"), (0x2FA0F, "\u{9D67}"), (0x2FA10, "\u{2A0CE}"), (0x2FA11, "\u{4CF8}"),
+    (0x2FA12, "\u{2A105}"), (0x2FA13, "\u{2A20E}"), (0x2FA14, "\u{2A291}"), (0x2FA15, "\u{9EBB}"),
+    (0x2FA16, "\u{4D56}"), (0x2FA17, "\u{9EF9}"), (0x2FA18, "\u{9EFE}"), (0x2FA19, "\u{9F05}"),
+    (0x2FA1A, "\u{9F0F}"), (0x2FA1B, "\u{9F16}"), (0x2FA1C, "\u{9F3B}"), (0x2FA1D, "\u{2A600}"),
+];
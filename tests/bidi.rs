@@ -0,0 +1,62 @@
+use unicode_analyze::{Codepoint, Direction, Text};
+
+fn values<'a>(codepoints: impl Iterator<Item = &'a Codepoint>) -> String {
+    codepoints.map(|c| c.value().unwrap()).collect()
+}
+
+#[test]
+fn levels_and_visual_order() {
+    let text = Text::parse_str("abc \u{5D0}\u{5D1}\u{5D2} 123!");
+    let paragraphs = text.bidi_paragraphs();
+    assert_eq!(paragraphs.len(), 1);
+
+    let paragraph = &paragraphs[0];
+    assert_eq!(paragraph.direction(), Direction::Ltr);
+    assert_eq!(paragraph.levels().iter().map(|l| l.unwrap()).collect::<Vec<_>>(), [
+        0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 0,
+    ]);
+    assert_eq!(values(paragraph.visual()), "abc 123 \u{5D2}\u{5D1}\u{5D0}!");
+    assert_eq!(paragraph.unmatched().count(), 0);
+}
+
+#[test]
+fn paragraphs() {
+    let text = Text::parse_str("\u{5D0}bc\nabc\u{2029}");
+    let paragraphs = text.bidi_paragraphs();
+
+    let directions = paragraphs.iter().map(|p| p.direction()).collect::<Vec<_>>();
+    assert_eq!(directions, [Direction::Rtl, Direction::Ltr]);
+    assert_eq!(paragraphs[0].level(), 1);
+    assert_eq!(values(paragraphs[0].codepoints()), "\u{5D0}bc\n");
+    assert_eq!(values(paragraphs[1].visual()), "abc\u{2029}");
+    assert_eq!(paragraphs[1].span().start.byte, 5);
+}
+
+#[test]
+fn unmatched_controls() {
+    // RLO b LRI c LRE d PDI e PDF, where the LRE is closed by the PDI.
+    let text = Text::parse_str("a\u{202E}b\u{2066}c\u{202A}d\u{2069}e\u{202C}");
+    let paragraph = &text.bidi_paragraphs()[0];
+    assert_eq!(values(paragraph.unmatched()), "\u{202A}");
+    assert_eq!(paragraph.levels()[1], None);
+    assert_eq!(paragraph.levels()[6], Some(4));
+
+    let text = Text::parse_str("\u{2067}x\u{202C}\u{2069}\u{2069}\u{202B}");
+    let paragraph = &text.bidi_paragraphs()[0];
+    assert_eq!(values(paragraph.unmatched()), "\u{202C}\u{2069}\u{202B}");
+}
+
+#[test]
+fn nested_controls_and_display() {
+    // RLI, then an RLO inside it that's never closed, hiding the comment's end.
+    let text = Text::parse_str("/* \u{2067}\u{202E} */ x\u{2069}\n");
+    let paragraph = &text.bidi_paragraphs()[0];
+    assert_eq!(values(paragraph.nested()), "\u{202E}");
+    assert_eq!(values(paragraph.unmatched()), "\u{202E}");
+    assert_eq!(paragraph.displayed(), "/* x /* ");
+
+    let text = Text::parse_str("a\u{2066}b\u{2069}c");
+    let paragraph = &text.bidi_paragraphs()[0];
+    assert_eq!(paragraph.nested().count(), 0);
+    assert_eq!(paragraph.displayed(), "abc");
+}
@@ -0,0 +1,37 @@
+use unicode_analyze::{ByteOrder, Text};
+
+#[test]
+fn encodings() {
+    let text = Text::parse_str("A\u{200D}😀");
+    let codepoints = text.codepoints().collect::<Vec<_>>();
+
+    let utf8 = codepoints.iter().map(|c| c.display_bytes().to_string()).collect::<Vec<_>>();
+    assert_eq!(utf8, ["41", "E2 80 8D", "F0 9F 98 80"]);
+
+    let utf16be = codepoints.iter()
+        .map(|c| c.display_utf16(ByteOrder::BigEndian).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(utf16be, ["0041", "200D", "D83D DE00"]);
+
+    let utf16le = codepoints.iter()
+        .map(|c| c.display_utf16(ByteOrder::LittleEndian).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(utf16le, ["4100", "0D20", "3DD8 00DE"]);
+
+    let utf32le = codepoints.iter()
+        .map(|c| c.display_utf32(ByteOrder::LittleEndian).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(utf32le, ["41000000", "0D200000", "00F60100"]);
+}
+
+#[test]
+fn invalid_sequences() {
+    let text = Text::parse_bytes(b"\xF0\x9F\x98A\xFF");
+    let bytes = text.codepoints().map(|c| c.display_bytes().to_string()).collect::<Vec<_>>();
+    assert_eq!(bytes, ["F0 9F 98", "41", "FF"]);
+
+    let utf16 = text.codepoints()
+        .map(|c| c.display_utf16(ByteOrder::BigEndian).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(utf16, ["-", "0041", "-"]);
+}
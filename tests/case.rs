@@ -0,0 +1,44 @@
+use unicode_analyze::{Case, CaseLocale as Locale, Codepoint, Text};
+
+fn to_case(s: &str, case: Case, locale: Locale) -> String {
+    let text = Text::parse_str(s).to_case(case, locale);
+    text.codepoints().map(|c| c.value().unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
+}
+
+#[test]
+fn codepoints() {
+    let codepoint = Codepoint::from_valid('ß');
+    assert_eq!(codepoint.uppercase(), Some(vec!['S', 'S']));
+    assert_eq!(codepoint.lowercase(), Some(vec!['ß']));
+    assert_eq!(codepoint.titlecase(), Some(vec!['S', 's']));
+    assert_eq!(codepoint.case_folding(), Some(vec!['s', 's']));
+
+    let codepoint = Codepoint::from_valid('\u{149}');
+    assert_eq!(codepoint.uppercase(), Some(vec!['\u{2BC}', 'N']));
+    assert_eq!(Codepoint::from_valid('ǆ').titlecase(), Some(vec!['ǅ']));
+    assert_eq!(Codepoint::from_surrogate(0xD800).unwrap().uppercase(), None);
+}
+
+#[test]
+fn context() {
+    assert_eq!(to_case("ΌΣΟΣ Σ", Case::Lower, Locale::Default), "όσος σ");
+    assert_eq!(to_case("ΌΣΟΣ'.", Case::Lower, Locale::Default), "όσος'.");
+    assert_eq!(to_case("ΣΑ'Σ'Α", Case::Lower, Locale::Default), "σα'σ'α");
+    assert_eq!(to_case("ǆemal straße", Case::Title, Locale::Default), "ǅemal Straße");
+    assert_eq!(to_case("'hello' world", Case::Title, Locale::Default), "'Hello' World");
+    assert_eq!(to_case("Straße", Case::Folded, Locale::Default), "strasse");
+}
+
+#[test]
+fn languages() {
+    assert_eq!(to_case("DİYARBAKIR", Case::Lower, Locale::Turkic), "diyarbakır");
+    assert_eq!(to_case("I\u{307}\u{316}", Case::Lower, Locale::Turkic), "i\u{316}");
+    assert_eq!(to_case("istanbul", Case::Title, Locale::Turkic), "İstanbul");
+    assert_eq!(to_case("Iİ", Case::Folded, Locale::Turkic), "ıi");
+    assert_eq!(to_case("Iİ", Case::Lower, Locale::Default), "ii\u{307}");
+
+    let lowercase = to_case("\u{CC}I\u{301}", Case::Lower, Locale::Lithuanian);
+    assert_eq!(lowercase, "i\u{307}\u{300}i\u{307}\u{301}");
+    assert_eq!(to_case("i\u{307}\u{301}", Case::Upper, Locale::Lithuanian), "I\u{301}");
+    assert_eq!(to_case("i\u{307}\u{301}", Case::Upper, Locale::Default), "I\u{307}\u{301}");
+}
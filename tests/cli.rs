@@ -0,0 +1,160 @@
+#![cfg(feature = "cli")]
+
+use std::{env, fs, io::Write, process::{self, Command, Output, Stdio}};
+use serde_json::{json, Value};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_unicode-analyze"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn lint_long_lines() {
+    // Longer than the chunks that lines are read in, so the isolate is split from its terminator.
+    let input = format!("\u{2066}{}\u{2069}\nok\n", "a".repeat(70_000));
+    let output = run(&["lint", "-"], &input);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let output = run(&["lint", "-"], &format!("{input}\u{202E}\n"));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success());
+    assert!(stdout.starts_with("standard input:3: unterminated"));
+}
+
+#[test]
+fn lint_paths() {
+    let path = env::temp_dir().join(format!("unicode-analyze-lint-{}.c", process::id()));
+    fs::write(&path, "int admin = 0; /* \u{202E} } if (admin) { */\n").unwrap();
+
+    let output = run(&["lint", "-", path.to_str().unwrap()], "fine\n");
+    fs::remove_file(&path).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success());
+    assert!(stdout.starts_with(&format!("{}:1: unterminated U+202E", path.display())));
+}
+
+#[test]
+fn unescape_lone_surrogates() {
+    let output = run(&["--unescape", r"a\ud800"], "");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success());
+    assert!(stdout.ends_with("U+D800 ◐ HIGH SURROGATE\n"));
+}
+
+// The fields of each codepoint in JSON output, which shouldn't change without a new version.
+const CODEPOINT_FIELDS: [&str; 34] = [
+    "value", "scalar", "bytes", "offset", "char_offset", "utf16_offset", "character", "name",
+    "kind", "invalid", "width", "diacritic", "direction", "general_category", "bidi_class",
+    "bidi_mirrored", "bidi_paired_bracket", "bidi_paired_bracket_type", "script",
+    "script_extensions", "block", "age", "canonical_combining_class", "decomposition_type",
+    "decomposition_mapping", "canonical_decomposition", "compatibility_decomposition", "uppercase",
+    "lowercase", "titlecase", "case_folding", "prototype", "white_space", "default_ignorable",
+];
+
+fn json(args: &[&str]) -> Vec<Value> {
+    let output = run(args, "");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+}
+
+fn keys(value: &Value) -> Vec<&str> {
+    let mut keys = value.as_object().unwrap().keys().map(String::as_str).collect::<Vec<_>>();
+    keys.sort_unstable();
+    keys
+}
+
+fn sorted<const N: usize>(mut keys: [&str; N]) -> [&str; N] {
+    keys.sort_unstable();
+    keys
+}
+
+#[test]
+fn json_schema() {
+    let documents = json(&["--format", "json", "--unescape", r"a\xFF"]);
+    assert_eq!(documents.len(), 1);
+
+    let document = &documents[0];
+    assert_eq!(keys(document), ["inputs", "version"]);
+    assert_eq!(document["version"], 1);
+
+    let input = &document["inputs"][0];
+    assert_eq!(keys(input), ["graphemes", "scripts", "source"]);
+    assert_eq!(input["source"], json!({"type": "argument", "index": 0}));
+    assert_eq!(input["scripts"], json!(["Latn"]));
+
+    let graphemes = input["graphemes"].as_array().unwrap();
+    assert_eq!(graphemes.len(), 2);
+    assert_eq!(keys(&graphemes[0]), ["char_offset", "codepoints", "offset", "utf16_offset"]);
+    assert_eq!(keys(&graphemes[0]["codepoints"][0]), sorted(CODEPOINT_FIELDS));
+
+    assert_eq!(graphemes[1], json!({
+        "offset": 1,
+        "char_offset": 1,
+        "utf16_offset": 1,
+        "codepoints": [{
+            "value": "0xFF",
+            "scalar": null,
+            "bytes": [255],
+            "offset": 1,
+            "char_offset": 1,
+            "utf16_offset": 1,
+            "character": "\u{FFFD}",
+            "name": "INVALID WTF-8 BYTE",
+            "kind": "invalid",
+            "invalid": "invalid_byte",
+            "width": null,
+            "diacritic": null,
+            "direction": null,
+            "general_category": null,
+            "bidi_class": null,
+            "bidi_mirrored": false,
+            "bidi_paired_bracket": null,
+            "bidi_paired_bracket_type": null,
+            "script": null,
+            "script_extensions": null,
+            "block": null,
+            "age": null,
+            "canonical_combining_class": null,
+            "decomposition_type": null,
+            "decomposition_mapping": null,
+            "canonical_decomposition": null,
+            "compatibility_decomposition": null,
+            "uppercase": null,
+            "lowercase": null,
+            "titlecase": null,
+            "case_folding": null,
+            "prototype": null,
+            "white_space": false,
+            "default_ignorable": false,
+        }],
+    }));
+}
+
+#[test]
+fn ndjson_schema() {
+    let records = json(&["--format", "ndjson", "--unescape", r"a\xFF"]);
+    assert_eq!(records.len(), 2);
+
+    for (record, offset) in records.iter().zip([0, 1]) {
+        let fields = ["char_offset", "codepoints", "offset", "source", "utf16_offset"];
+        assert_eq!(keys(record), fields);
+        assert_eq!(record["source"], json!({"type": "argument", "index": 0}));
+        assert_eq!(record["offset"], offset);
+        assert_eq!(keys(&record["codepoints"][0]), sorted(CODEPOINT_FIELDS));
+    }
+
+    assert_eq!(records[1]["codepoints"][0]["value"], "0xFF");
+    assert_eq!(records[1]["codepoints"][0]["kind"], "invalid");
+    assert_eq!(records[1]["codepoints"][0]["invalid"], "invalid_byte");
+}
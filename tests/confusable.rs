@@ -0,0 +1,28 @@
+use unicode_analyze::{Codepoint, Equivalence, Text};
+
+fn string(text: &Text) -> String {
+    text.codepoints().map(|c| c.value().unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
+}
+
+#[test]
+fn prototypes() {
+    assert_eq!(Codepoint::from_valid('\u{430}').prototype(), Some(vec!['a']));
+    assert_eq!(Codepoint::from_valid('m').prototype(), Some(vec!['r', 'n']));
+    assert_eq!(Codepoint::from_valid('a').prototype(), None);
+    assert_eq!(Codepoint::from_surrogate(0xD800).unwrap().prototype(), None);
+}
+
+#[test]
+fn skeleton() {
+    let text = Text::parse_str("p\u{430}ypal");
+    assert_eq!(string(&text.skeleton()), "paypal");
+    assert_eq!(unicode_analyze::skeleton(&text), Text::parse_str("paypal"));
+    assert!(text.is_equivalent(&Text::parse_str("paypal"), Equivalence::Skeleton));
+
+    // Characters are decomposed before and after they're replaced with their prototypes.
+    assert_eq!(string(&Text::parse_str("\u{E9}m").skeleton()), "e\u{301}rn");
+
+    let text = Text::parse_bytes(b"\xFFI");
+    assert_eq!(string(&text.skeleton()), "\u{FFFD}l");
+    assert_eq!(text.skeleton().codepoints().last().unwrap().offset().byte, 1);
+}
@@ -0,0 +1,62 @@
+use unicode_analyze::{Edit, Equivalence, Text};
+
+// Describes edits as strings like "=a", "-b", "+c" and "d>e".
+fn describe<T: ToString>(edits: Vec<Edit<T>>) -> Vec<String> {
+    edits.into_iter()
+        .map(|edit| match edit {
+            Edit::Equal(a, _) => format!("={}", a.to_string()),
+            Edit::Delete(a) => format!("-{}", a.to_string()),
+            Edit::Insert(b) => format!("+{}", b.to_string()),
+            Edit::Substitute(a, b) => format!("{}>{}", a.to_string(), b.to_string()),
+        })
+        .collect()
+}
+
+#[test]
+fn graphemes() {
+    let (a, b) = (Text::parse_str("kitten"), Text::parse_str("sitting"));
+    assert_eq!(describe(a.diff(&b)), [
+        "'k'>'s'", "='i'", "='t'", "='t'", "'e'>'i'", "='n'", "+'g'",
+    ]);
+
+    let (a, b) = (Text::parse_str("abc"), Text::parse_str("ac"));
+    assert_eq!(describe(a.diff(&b)), ["='a'", "-'b'", "='c'"]);
+    assert!(Text::default().diff(&Text::default()).is_empty());
+}
+
+#[test]
+fn codepoints() {
+    let (a, b) = (Text::parse_str("\u{E9}"), Text::parse_str("e\u{301}"));
+    let edits = a.diff(&b);
+    assert_eq!(edits.len(), 1);
+
+    let Edit::Substitute(a, b) = edits[0] else { panic!("expected a substitution") };
+    assert_eq!(describe(a.diff(b)), ["'\u{E9}'>'e'", "+'\u{25CC}\u{301}'"]);
+}
+
+#[test]
+fn equivalences() {
+    let equivalences = |a, b| {
+        let (a, b) = (Text::parse_str(a), Text::parse_str(b));
+        Equivalence::ALL.into_iter().filter(|&e| a.is_equivalent(&b, e)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(equivalences("caf\u{E9}", "cafe\u{301}"), [
+        Equivalence::Nfc,
+        Equivalence::Nfkc,
+        Equivalence::CaseFolding,
+        Equivalence::Skeleton,
+    ]);
+    assert_eq!(equivalences("\u{FB01}", "fi"), [
+        Equivalence::Nfkc,
+        Equivalence::CaseFolding,
+        Equivalence::Skeleton,
+    ]);
+    assert_eq!(equivalences("Stra\u{DF}e", "STRASSE"), [Equivalence::CaseFolding]);
+    assert_eq!(equivalences("paypal", "p\u{430}ypa1"), [Equivalence::Skeleton]);
+    assert_eq!(equivalences("a", "b"), []);
+
+    let (a, b) = (Text::parse_bytes(b"A\xFF"), Text::parse_bytes(b"a\xFE"));
+    assert!(!a.is_equivalent(&b, Equivalence::CaseFolding));
+    assert!(a.is_equivalent(&Text::parse_bytes(b"a\xFF"), Equivalence::CaseFolding));
+}
@@ -0,0 +1,133 @@
+use std::io::BufReader;
+use unicode_analyze::{ByteOrder, Codepoint, Encoding, InvalidKind, Kind, Offset, Text, TextReader};
+
+fn names(text: &Text) -> Vec<String> {
+    text.codepoints().map(|c| c.name()).collect()
+}
+
+#[test]
+fn utf16() {
+    let text = Text::decode(b"A\0=\xD8\x00\xDE\x00\xD8!\0\x00\xDC\n", Encoding::Utf16Le);
+    assert_eq!(text.to_string(), "['A', '😀', 0x00 0xD8, '!', 0x00 0xDC, 0x0A]");
+    assert_eq!(names(&text)[2..], [
+        "UNPAIRED SURROGATE U+D800",
+        "EXCLAMATION MARK",
+        "UNPAIRED SURROGATE U+DC00",
+        "TRUNCATED UTF-16LE SEQUENCE",
+    ]);
+
+    let offsets = text.codepoints().map(|c| c.offset().byte).collect::<Vec<_>>();
+    assert_eq!(offsets, [0, 2, 6, 8, 10, 12]);
+    assert_eq!(text.span().end.utf16, 7);
+
+    let text = Text::decode(b"\0A\xD8\x3D\xDE\x00", Encoding::Utf16Be);
+    assert_eq!(text.to_string(), "['A', '😀']");
+}
+
+#[test]
+fn utf32() {
+    let text = Text::decode(b"A\0\0\0\0\xD8\0\0\0\0\x11\0\0\xF6\x01\0\0", Encoding::Utf32Le);
+    let kinds = text.codepoints().map(|c| c.invalid_kind()).collect::<Vec<_>>();
+    assert_eq!(kinds, [
+        None,
+        Some(InvalidKind::Surrogate(0xD800)),
+        Some(InvalidKind::OutOfRange(0x110000)),
+        None,
+        Some(InvalidKind::Truncated),
+    ]);
+    assert_eq!(text.codepoints().nth(3).unwrap().value(), Ok('😀'));
+
+    let text = Text::decode(b"\0\x01\xF6\x00", Encoding::Utf32Be);
+    assert_eq!(text.to_string(), "['😀']");
+}
+
+#[test]
+fn single_byte() {
+    let text = Text::decode(b"caf\xE9 \x80\x81\x9F", Encoding::Latin1);
+    assert_eq!(text.to_string(), "['c', 'a', 'f', 'é', ' ', PAD, HOP, APC]");
+
+    let text = Text::decode(b"caf\xE9 \x80\x81\x9F", Encoding::Windows1252);
+    assert_eq!(text.to_string(), "['c', 'a', 'f', 'é', ' ', '€', 0x81, 'Ÿ']");
+    assert_eq!(names(&text)[6], "UNDEFINED WINDOWS-1252 BYTE");
+    assert_eq!(text.span().end.byte, 8);
+}
+
+#[test]
+fn byte_order_marks() {
+    assert_eq!(Encoding::sniff(b"\xEF\xBB\xBFA"), Some(Encoding::Utf8));
+    assert_eq!(Encoding::sniff(b"\xFF\xFEA\0"), Some(Encoding::Utf16Le));
+    assert_eq!(Encoding::sniff(b"\xFE\xFF\0A"), Some(Encoding::Utf16Be));
+    assert_eq!(Encoding::sniff(b"\xFF\xFE\0\0"), Some(Encoding::Utf32Le));
+    assert_eq!(Encoding::sniff(b"\0\0\xFE\xFF"), Some(Encoding::Utf32Be));
+    assert_eq!(Encoding::sniff(b"A\0"), None);
+
+    let reader = TextReader::detect_encoding(&b"\xFF\xFEA\0\n\0B\0"[..], Encoding::Utf8).unwrap();
+    assert_eq!(reader.encoding(), Encoding::Utf16Le);
+
+    let texts = reader.map(|text| text.unwrap().to_string()).collect::<Vec<_>>();
+    assert_eq!(texts, ["[BOM, 'A', LF]", "['B']"]);
+
+    // The byte order mark is found even if the reader returns it one byte at a time.
+    let input = BufReader::with_capacity(1, &b"\0\0\xFE\xFF\0\0\0\n\0\0\0B"[..]);
+    let reader = TextReader::detect_encoding(input, Encoding::Utf8).unwrap();
+    assert_eq!(reader.encoding(), Encoding::Utf32Be);
+
+    let texts = reader.map(|text| text.unwrap().to_string()).collect::<Vec<_>>();
+    assert_eq!(texts, ["[BOM, LF]", "['B']"]);
+
+    let reader = TextReader::detect_encoding(&b"\xFF\xFE"[..], Encoding::Utf8).unwrap();
+    assert_eq!(reader.map(|text| text.unwrap().to_string()).collect::<Vec<_>>(), ["[BOM]"]);
+}
+
+#[test]
+fn reading() {
+    // U+010A is not a line feed, even though it contains the byte 0x0A.
+    let input = b"\n\x01A\0\n\0";
+    let texts = TextReader::with_encoding(&input[..], Encoding::Utf16Le)
+        .map(|text| text.unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(texts, ["['Ċ', 'A', LF]"]);
+
+    let input = "😀".encode_utf16()
+        .cycle()
+        .take(70_001)
+        .flat_map(u16::to_be_bytes)
+        .collect::<Vec<_>>();
+    let texts = TextReader::with_encoding(&input[..], Encoding::Utf16Be)
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+
+    assert!(texts.len() > 1);
+    assert!(texts.iter().flat_map(Text::codepoints).take(35_000).all(|c| c.value() == Ok('😀')));
+    assert_eq!(texts.last().unwrap().span().end.byte, 140_002);
+}
+
+#[test]
+fn wtf8() {
+    let input = b"a\xED\xA0\x80\xED\xB0\x80\xF0\x9F\x98\x80\xED\xAF\xBF\xC0";
+    let text = Text::decode(input, Encoding::Wtf8);
+    assert_eq!(text.to_string(), "['a', U+D800, U+DC00, '😀', U+DBFF, 0xC0]");
+    assert_eq!(names(&text)[1..], [
+        "HIGH SURROGATE",
+        "LOW SURROGATE",
+        "GRINNING FACE",
+        "HIGH PRIVATE USE SURROGATE",
+        "INVALID WTF-8 BYTE",
+    ]);
+
+    let surrogate = text.codepoints().nth(1).unwrap();
+    assert_eq!(surrogate.kind(), Kind::Surrogate);
+    assert_eq!(surrogate.surrogate(), Some(0xD800));
+    assert_eq!(surrogate.display_bytes().to_string(), "ED A0 80");
+    assert_eq!(surrogate.display_utf16(ByteOrder::LittleEndian).to_string(), "00D8");
+    assert_eq!(Codepoint::from_surrogate(0xD800).as_ref(), Some(surrogate));
+    assert_eq!(Codepoint::from_surrogate(0xE000), None);
+
+    let offsets = text.codepoints().map(|c| c.offset()).collect::<Vec<_>>();
+    assert_eq!(offsets[4], Offset { byte: 11, char: 4, utf16: 5 });
+
+    // In UTF-8, the same bytes are invalid.
+    let text = Text::decode(b"\xED\xA0\x80", Encoding::Utf8);
+    assert_eq!(text.codepoints().next().unwrap().kind(), Kind::Invalid);
+    assert_ne!(text.codepoints().next(), Some(surrogate));
+}
@@ -0,0 +1,111 @@
+use unicode_analyze::{Encoding, EscapeStyle, Text, unescape};
+
+fn unescaped(text: &str) -> String {
+    String::from_utf8(unescape(text.as_bytes()).unwrap()).unwrap()
+}
+
+fn error(text: &str) -> String {
+    unescape(text.as_bytes()).unwrap_err().to_string()
+}
+
+#[test]
+fn languages() {
+    assert_eq!(unescaped(r"\u{1F600}\x41\n\t\\\'\0"), "😀A\n\t\\'\0");
+    assert_eq!(unescaped(r"\101\x7\a\e\?"), "A\x07\x07\x1B?");
+    assert_eq!(unescaped(r#"\"\/\uD83D\uDE00\u00e9"#), "\"/😀é");
+    assert_eq!(unescaped(r"\U0001F600\N{SNOWMAN}\N{zwj}"), "😀☃\u{200D}");
+    assert_eq!(unescaped("a\\\nb"), "ab");
+    assert_eq!(unescaped("plain text, 100% ünescaped"), "plain text, 100% ünescaped");
+}
+
+#[test]
+fn code_point_lists() {
+    assert_eq!(unescaped("U+0048 U+0069"), "Hi");
+    assert_eq!(unescaped("U+0065, U+0301 is é"), "e\u{301} is é");
+    assert_eq!(unescaped("U+1F600"), "😀");
+    assert_eq!(unescaped("U+12 and U+1234567"), "U+12 and U+1234567");
+}
+
+#[test]
+fn html() {
+    assert_eq!(unescaped("&lt;&eacute;&#233;&#xE9;&NotNestedLessLess;"), "<éé\u{E9}⪡\u{338}");
+    assert_eq!(unescaped("AT&T & co"), "AT&T & co");
+}
+
+#[test]
+fn bytes() {
+    let text = Text::unescape(br"\xF0\x9F\x98\xFF\uD800").unwrap();
+    let names = text.codepoints().map(|c| c.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["TRUNCATED WTF-8 SEQUENCE", "INVALID WTF-8 BYTE", "HIGH SURROGATE"]);
+    assert_eq!(text.codepoints().last().unwrap().surrogate(), Some(0xD800));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(error(r"ab\q"), r"unknown escape '\q' at byte 2");
+    assert_eq!(error(r"\u{110000}"), r"out of range escape '\u{110000}' at byte 0");
+    assert_eq!(error(r"\u12"), r"incomplete escape '\u12' at byte 0");
+    assert_eq!(error(r"\u{12"), r"incomplete escape '\u{12' at byte 0");
+    assert_eq!(error(r"\x"), r"incomplete escape '\x' at byte 0");
+    assert_eq!(error(r"\777"), r"out of range escape '\777' at byte 0");
+    assert_eq!(error(r"\N{NOPE}"), r"unknown character name in escape '\N{NOPE}' at byte 0");
+    assert_eq!(error("x&bogus;"), "unknown HTML entity '&bogus;' at byte 1");
+    assert_eq!(error("&#12"), "incomplete escape '&#12' at byte 0");
+    assert_eq!(error("U+110000"), "out of range escape 'U+110000' at byte 0");
+    assert_eq!(error("\\"), r"incomplete escape '\' at byte 0");
+    assert_eq!(unescape(br"..\q").unwrap_err().offset(), 2);
+}
+
+#[test]
+fn escaping() {
+    let text = Text::parse_str("a\"\\\te\u{301}\u{200D}😀\x1B\u{A0}<é&");
+    let escaped = |style| text.escape(style);
+
+    assert_eq!(escaped(EscapeStyle::Rust), r#""a\"\\\te\u{301}\u{200d}😀\u{1b}\u{a0}<é&""#);
+    assert_eq!(escaped(EscapeStyle::C), r#""a\"\\\te\u0301\u200d😀\033\u00a0<é&""#);
+    assert_eq!(escaped(EscapeStyle::Json), r#""a\"\\\te\u0301\u200d😀\u001b\u00a0<é&""#);
+    assert_eq!(escaped(EscapeStyle::Python), r#""a\"\\\te\u0301\u200d😀\x1b\u00a0<é&""#);
+    assert_eq!(escaped(EscapeStyle::JavaScript), r#""a\"\\\te\u0301\u200d😀\x1b\u00a0<é&""#);
+    assert_eq!(
+        escaped(EscapeStyle::Html),
+        r"a&quot;\&#x9;e&#x301;&#x200D;😀&#x1B;&#xA0;&lt;é&amp;",
+    );
+    assert_eq!(
+        escaped(EscapeStyle::Url),
+        "a%22%5C%09e%CC%81%E2%80%8D%F0%9F%98%80%1B%C2%A0%3C%C3%A9%26",
+    );
+
+    // Round trips through unescaping, except for HTML which has no backslash escapes.
+    for style in [EscapeStyle::Rust, EscapeStyle::C, EscapeStyle::Json, EscapeStyle::Python] {
+        let escaped = escaped(style);
+        assert_eq!(Text::unescape(&escaped.as_bytes()[1..escaped.len() - 1]).unwrap(), text);
+    }
+}
+
+#[test]
+fn escaping_fillers() {
+    // Hangul fillers take up space, but are default ignorable, so they're blank or hidden.
+    let text = Text::parse_str("a\u{115F}b\u{3164}");
+    let escaped = |style| text.escape(style);
+
+    assert_eq!(escaped(EscapeStyle::Rust), r#""a\u{115f}b\u{3164}""#);
+    assert_eq!(escaped(EscapeStyle::C), r#""a\u115fb\u3164""#);
+    assert_eq!(escaped(EscapeStyle::Json), r#""a\u115fb\u3164""#);
+    assert_eq!(escaped(EscapeStyle::Python), r#""a\u115fb\u3164""#);
+    assert_eq!(escaped(EscapeStyle::JavaScript), r#""a\u115fb\u3164""#);
+    assert_eq!(escaped(EscapeStyle::Html), "a&#x115F;b&#x3164;");
+    assert_eq!(escaped(EscapeStyle::Url), "a%E1%85%9Fb%E3%85%A4");
+}
+
+#[test]
+fn escaping_invalid_data() {
+    let text = Text::decode(b"x\xFF\xED\xA0\x80\xC3\xA9", Encoding::Wtf8);
+    let escaped = |style| text.escape(style);
+
+    assert_eq!(escaped(EscapeStyle::Rust), r#"b"x\xff\xed\xa0\x80\xc3\xa9""#);
+    assert_eq!(escaped(EscapeStyle::C), r#""x\377\355\240\200é""#);
+    assert_eq!(escaped(EscapeStyle::Json), r#""x\ufffd\ud800é""#);
+    assert_eq!(escaped(EscapeStyle::Python), r#"b"x\xff\xed\xa0\x80\xc3\xa9""#);
+    assert_eq!(escaped(EscapeStyle::Html), "x&#xFFFD;&#xFFFD;é");
+    assert_eq!(escaped(EscapeStyle::Url), "x%FF%ED%A0%80%C3%A9");
+}
@@ -0,0 +1,15 @@
+use unicode_analyze::{Codepoint, Kind};
+
+#[test]
+fn names() {
+    let zwj = Codepoint::from_name("zwj").unwrap();
+    assert_eq!(zwj.value(), Ok('\u{200D}'));
+    assert_eq!(zwj.kind(), Kind::ControlCode);
+
+    let snowman = Codepoint::from_name("Snowman").unwrap();
+    assert_eq!(snowman.value(), Ok('☃'));
+    assert_eq!(snowman.kind(), Kind::Character);
+
+    assert_eq!(Codepoint::from_name("carriage return").unwrap().value(), Ok('\r'));
+    assert!(Codepoint::from_name("nonsense").is_none());
+}
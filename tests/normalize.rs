@@ -0,0 +1,48 @@
+use unicode_analyze::{Encoding, NormalizationForm as Form, QuickCheck, Text};
+
+fn string(text: &Text) -> String {
+    text.codepoints().map(|c| c.value().unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
+}
+
+#[test]
+fn normalize() {
+    let text = Text::parse_str("e\u{301}\u{212B}\u{FB01}");
+    assert_eq!(string(&text.normalize(Form::Nfc)), "\u{E9}\u{C5}\u{FB01}");
+    assert_eq!(string(&text.normalize(Form::Nfd)), "e\u{301}A\u{30A}\u{FB01}");
+    assert_eq!(string(&text.normalize(Form::Nfkc)), "\u{E9}\u{C5}fi");
+    assert_eq!(string(&text.normalize(Form::Nfkd)), "e\u{301}A\u{30A}fi");
+    assert_eq!(text.normalize(Form::Nfc).graphemes().count(), 3);
+}
+
+#[test]
+fn invalid_data_is_kept() {
+    // Characters don't compose across invalid data.
+    let text = Text::parse_bytes(&[b"A\xCC\x8A\xFFe".as_slice(), "\u{301}".as_bytes()].concat());
+    let normalized = text.normalize(Form::Nfc);
+    assert_eq!(string(&normalized), "\u{C5}\u{FFFD}\u{E9}");
+    assert_eq!(normalized.codepoints().nth(1).unwrap().value(), Err(&[0xFF][..]));
+    assert_eq!(normalized.codepoints().last().unwrap().offset().byte, 3);
+
+    let text = Text::parse_bytes(&[b"e\xFF".as_slice(), "\u{301}".as_bytes()].concat());
+    assert!(text.is_normalized(Form::Nfc));
+
+    // Latin-1 can't encode everything that normalization produces.
+    let text = Text::decode(b"\xBD", Encoding::Latin1).normalize(Form::Nfkd);
+    assert_eq!(string(&text), "1\u{2044}2");
+    assert!(text.codepoints().all(|c| c.encoding() == Encoding::Utf8));
+}
+
+#[test]
+fn quick_check() {
+    let text = Text::parse_str("caf\u{E9}");
+    assert_eq!(text.quick_check(Form::Nfc), QuickCheck::Yes);
+    assert_eq!(text.quick_check(Form::Nfd), QuickCheck::No);
+    assert!(text.is_normalized(Form::Nfkc));
+    assert!(!text.is_normalized(Form::Nfkd));
+
+    // A combining acute accent might compose with what comes before it.
+    let text = Text::parse_str("x\u{301}");
+    assert_eq!(text.quick_check(Form::Nfc), QuickCheck::Maybe);
+    assert!(text.is_normalized(Form::Nfc));
+    assert!(!Text::parse_str("e\u{301}").is_normalized(Form::Nfc));
+}
@@ -0,0 +1,54 @@
+use unicode_analyze::{Offset, Text};
+
+#[test]
+fn codepoint_offsets() {
+    let text = Text::parse_bytes("a😀\u{65}\u{301}\u{FF}".as_bytes());
+    let spans = text.codepoints()
+        .map(|c| c.span())
+        .map(|span| (span.bytes(), span.chars(), span.utf16()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(spans, [
+        (0..1, 0..1, 0..1),
+        (1..5, 1..2, 1..3),
+        (5..6, 2..3, 3..4),
+        (6..8, 3..4, 4..5),
+        (8..10, 4..5, 5..6),
+    ]);
+
+    let graphemes = text.graphemes().map(|g| g.span().utf16()).collect::<Vec<_>>();
+    assert_eq!(graphemes, [0..1, 1..3, 3..5, 5..6]);
+    assert_eq!(text.span().end, Offset { byte: 10, char: 5, utf16: 6 });
+}
+
+#[test]
+fn invalid_offsets() {
+    let text = Text::parse_bytes(b"\xFFa");
+    let starts = text.codepoints().map(|c| c.offset()).collect::<Vec<_>>();
+
+    assert_eq!(starts, [
+        Offset { byte: 0, char: 0, utf16: 0 },
+        Offset { byte: 1, char: 1, utf16: 1 },
+    ]);
+}
+
+#[test]
+fn reader_offsets() {
+    let starts = Text::parse_reader("😀\né\n".as_bytes())
+        .map(|text| text.unwrap().span().start)
+        .collect::<Vec<_>>();
+
+    assert_eq!(starts, [
+        Offset { byte: 0, char: 0, utf16: 0 },
+        Offset { byte: 5, char: 2, utf16: 3 },
+    ]);
+}
+
+#[test]
+fn equality_ignores_offsets() {
+    let text = Text::parse_str("aa");
+    let codepoints = text.codepoints().collect::<Vec<_>>();
+
+    assert_ne!(codepoints[0].offset(), codepoints[1].offset());
+    assert_eq!(codepoints[0], codepoints[1]);
+}
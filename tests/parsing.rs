@@ -141,7 +141,30 @@ fn invalid() {
             ("U+080000", "?", "UNKNOWN CHARACTER"),
             ("U+10FFFD", "▨", "RESERVED FOR PRIVATE USE"),
             ("U+FFFF", "∅", "NOT A CHARACTER"),
-            ("0xFF", "�", "INVALID UTF-8"),
+            ("0xFF", "�", "INVALID UTF-8 BYTE"),
+        ],
+    }
+    .run()
+}
+
+#[test]
+fn invalid_sequences() {
+    TestCase {
+        text: Text::parse_bytes(
+            b"\x80\xE2\x80A\xC0\xAF\xC1\xED\xA0\x80\xED\xA0\xBD\xED\xB8\x80\xF4\x90\x80\x80\xF0\x9F\x98",
+        ),
+        string_rep: "[0x80, 0xE2 0x80, 'A', 0xC0 0xAF, 0xC1, 0xED 0xA0 0x80, \
+                     0xED 0xA0 0xBD 0xED 0xB8 0x80, 0xF4 0x90 0x80 0x80, 0xF0 0x9F 0x98]",
+        out: &[
+            ("0x80", "�", "UNEXPECTED CONTINUATION BYTE"),
+            ("0xE2 0x80", "�", "TRUNCATED UTF-8 SEQUENCE"),
+            ("U+0041", "'A'", "LATIN CAPITAL LETTER A"),
+            ("0xC0 0xAF", "�", "OVERLONG ENCODING OF U+002F"),
+            ("0xC1", "�", "INVALID UTF-8 BYTE"),
+            ("0xED 0xA0 0x80", "�", "ENCODED SURROGATE U+D800"),
+            ("0xED 0xA0 0xBD 0xED 0xB8 0x80", "�", "CESU-8 ENCODING OF U+01F600"),
+            ("0xF4 0x90 0x80 0x80", "�", "OUT OF RANGE VALUE 0x110000"),
+            ("0xF0 0x9F 0x98", "�", "TRUNCATED UTF-8 SEQUENCE"),
         ],
     }
     .run()
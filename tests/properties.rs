@@ -0,0 +1,221 @@
+use unicode_analyze::{
+    Age, BidiClass, Block, Bracket, Codepoint, DecompositionType, Diacritic, Direction,
+    GeneralCategory, Kind, Script, Text,
+};
+
+#[test]
+fn character_properties() {
+    let text = Text::parse_bytes("a\u{035D}ب😀\t\u{E000}".as_bytes());
+    let codepoints = text.codepoints().collect::<Vec<_>>();
+
+    let kinds = codepoints.iter().map(|c| c.kind()).collect::<Vec<_>>();
+    assert_eq!(kinds, [
+        Kind::Character,
+        Kind::Character,
+        Kind::Character,
+        Kind::Character,
+        Kind::ControlCode,
+        Kind::PrivateUse,
+    ]);
+
+    let widths = codepoints.iter().map(|c| c.width()).collect::<Vec<_>>();
+    assert_eq!(widths, [Some(1), Some(0), Some(1), Some(2), None, None]);
+
+    let diacritics = codepoints.iter().map(|c| c.diacritic()).collect::<Vec<_>>();
+    assert_eq!(diacritics, [
+        Some(Diacritic::None),
+        Some(Diacritic::Double),
+        Some(Diacritic::None),
+        Some(Diacritic::None),
+        None,
+        None,
+    ]);
+
+    let directions = codepoints.iter().map(|c| c.direction()).collect::<Vec<_>>();
+    assert_eq!(directions, [
+        Some(Direction::Ltr),
+        Some(Direction::Neutral),
+        Some(Direction::Rtl),
+        Some(Direction::Neutral),
+        None,
+        None,
+    ]);
+}
+
+#[test]
+fn general_categories() {
+    let bytes = ["a\u{035D}ب😀\t\u{E000}\u{378}\u{200D}".as_bytes(), b"\xFF"].concat();
+    let text = Text::parse_bytes(&bytes);
+    let categories = text.codepoints().map(|c| c.general_category()).collect::<Vec<_>>();
+    assert_eq!(categories, [
+        Some(GeneralCategory::LowercaseLetter),
+        Some(GeneralCategory::NonspacingMark),
+        Some(GeneralCategory::OtherLetter),
+        Some(GeneralCategory::OtherSymbol),
+        Some(GeneralCategory::Control),
+        Some(GeneralCategory::PrivateUse),
+        Some(GeneralCategory::Unassigned),
+        Some(GeneralCategory::Format),
+        None,
+    ]);
+
+    let surrogate = Codepoint::from_surrogate(0xDC00).unwrap();
+    assert_eq!(surrogate.general_category(), Some(GeneralCategory::Surrogate));
+    assert_eq!(GeneralCategory::DashPunctuation.short_name(), "Pd");
+    assert_eq!(GeneralCategory::DashPunctuation.long_name(), "Dash_Punctuation");
+}
+
+#[test]
+fn scripts() {
+    let text = Text::parse_str("Aя中\u{30FC}\u{301}1\u{E000}");
+    let names = |scripts: Vec<Script>| scripts.iter().map(|s| s.full_name()).collect::<Vec<_>>();
+
+    let scripts = text.codepoints().map(|c| c.script().unwrap()).collect::<Vec<_>>();
+    assert_eq!(names(scripts), [
+        "Latin",
+        "Cyrillic",
+        "Han",
+        "Common",
+        "Inherited",
+        "Common",
+        "Unknown",
+    ]);
+
+    let extensions = text.codepoints().nth(3).unwrap().script_extensions().unwrap();
+    assert_eq!(names(extensions), ["Hiragana", "Katakana"]);
+    let extensions = text.codepoints().nth(5).unwrap().script_extensions().unwrap();
+    assert_eq!(extensions, [Script::COMMON]);
+
+    let scripts = names(text.scripts());
+    assert_eq!(scripts, ["Common", "Cyrillic", "Han", "Inherited", "Latin", "Unknown"]);
+    assert_eq!(Script::from_name("Cyrl"), Script::from_name("Cyrillic"));
+    assert_eq!(Script::from_name("Latn").unwrap().to_string(), "Latin");
+}
+
+#[test]
+fn blocks() {
+    // Kirat Rai is new in Unicode 16.0, and U+323B0 isn't in a block until 17.0.
+    let bytes = ["x𝐱\u{2014}\u{16D40}\u{E0080}\u{323B0}\u{10FFFF}".as_bytes(), b"\xFF"].concat();
+    let text = Text::parse_bytes(&bytes);
+    let blocks = text.codepoints().map(|c| c.block().map(|b| b.name)).collect::<Vec<_>>();
+    assert_eq!(blocks, [
+        Some("Basic Latin"),
+        Some("Mathematical Alphanumeric Symbols"),
+        Some("General Punctuation"),
+        Some("Kirat Rai"),
+        None,
+        None,
+        Some("Supplementary Private Use Area-B"),
+        None,
+    ]);
+
+    let surrogate = Codepoint::from_surrogate(0xDB80).unwrap();
+    assert_eq!(surrogate.block(), Some(Block {
+        name: "High Private Use Surrogates",
+        start: 0xDB80,
+        end: 0xDBFF,
+    }));
+}
+
+#[test]
+fn ages() {
+    // U+323B0 is unassigned in Unicode 16.0, which the other properties come from.
+    let text = Text::parse_str("a\u{20AC}😀🥺\u{1FAE8}\u{16D40}\u{E000}\u{378}\u{323B0}");
+    let ages = text.codepoints().map(|c| c.age()).collect::<Vec<_>>();
+    assert_eq!(ages, [
+        Some(Age { major: 1, minor: 1 }),
+        Some(Age { major: 2, minor: 1 }),
+        Some(Age { major: 6, minor: 1 }),
+        Some(Age { major: 11, minor: 0 }),
+        Some(Age { major: 15, minor: 0 }),
+        Some(Age { major: 16, minor: 0 }),
+        Some(Age { major: 1, minor: 1 }),
+        None,
+        None,
+    ]);
+
+    assert_eq!(Codepoint::from_surrogate(0xDFFF).unwrap().age(), Some(Age { major: 2, minor: 0 }));
+    assert!(Age { major: 10, minor: 0 } > Age { major: 9, minor: 1 });
+    assert_eq!(Age { major: 15, minor: 1 }.to_string(), "15.1");
+}
+
+#[test]
+fn bidi() {
+    let text = Text::parse_str("a(1,ب٣<]\u{202E}");
+    let classes = text.codepoints().map(|c| c.bidi_class().unwrap()).collect::<Vec<_>>();
+    assert_eq!(classes, [
+        BidiClass::LeftToRight,
+        BidiClass::OtherNeutral,
+        BidiClass::EuropeanNumber,
+        BidiClass::CommonSeparator,
+        BidiClass::ArabicLetter,
+        BidiClass::ArabicNumber,
+        BidiClass::OtherNeutral,
+        BidiClass::OtherNeutral,
+        BidiClass::RightToLeftOverride,
+    ]);
+
+    let mirrored = text.codepoints().map(|c| c.bidi_mirrored()).collect::<Vec<_>>();
+    assert_eq!(mirrored, [false, true, false, false, false, false, true, true, false]);
+
+    let brackets = text.codepoints().map(|c| c.bidi_paired_bracket()).collect::<Vec<_>>();
+    assert_eq!(brackets, [
+        None,
+        Some(Bracket::Open(')')),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bracket::Close('[')),
+        None,
+    ]);
+
+    assert_eq!(BidiClass::NonspacingMark.short_name(), "NSM");
+    assert_eq!(Codepoint::from_surrogate(0xD800).unwrap().bidi_class(), None);
+}
+
+#[test]
+fn decompositions() {
+    use DecompositionType as Dt;
+
+    let decomposition = |c| Codepoint::from_valid(c).decomposition().map(|d| (d.kind, d.mapping));
+    assert_eq!(decomposition('\u{E9}'), Some((Dt::Canonical, vec!['e', '\u{301}'])));
+    assert_eq!(decomposition('\u{FB01}'), Some((Dt::Compat, vec!['f', 'i'])));
+    assert_eq!(decomposition('\u{1D400}'), Some((Dt::Font, vec!['A'])));
+    assert_eq!(decomposition('\u{AC01}'), Some((Dt::Canonical, vec!['\u{AC00}', '\u{11A8}'])));
+    assert_eq!(decomposition('\u{AC00}'), Some((Dt::Canonical, vec!['\u{1100}', '\u{1161}'])));
+    assert_eq!(decomposition('a'), None);
+    // Unassigned until Unicode 17.0.
+    assert_eq!(decomposition('\u{A7F1}'), None);
+
+    let codepoint = Codepoint::from_valid('\u{1E9B}');
+    assert_eq!(codepoint.canonical_decomposition(), Some(vec!['\u{17F}', '\u{307}']));
+    assert_eq!(codepoint.compatibility_decomposition(), Some(vec!['s', '\u{307}']));
+    let hangul = Codepoint::from_valid('\u{AC01}').canonical_decomposition();
+    assert_eq!(hangul, Some(vec!['\u{1100}', '\u{1161}', '\u{11A8}']));
+
+    let classes = Text::parse_str("a\u{301}\u{316}").codepoints()
+        .map(|c| c.canonical_combining_class())
+        .collect::<Vec<_>>();
+    assert_eq!(classes, [Some(0), Some(230), Some(220)]);
+    assert_eq!(Codepoint::from_invalid(0xFF).canonical_combining_class(), None);
+    assert_eq!(Dt::Nobreak.tag(), Some("noBreak"));
+}
+
+#[test]
+fn white_space_and_default_ignorables() {
+    let codepoints = Text::parse_str(" \u{A0}\u{3000}\u{200B}\u{115F}\u{E0041}a").into_codepoints();
+    let properties = codepoints.map(|c| (c.white_space(), c.default_ignorable()));
+    assert_eq!(properties.collect::<Vec<_>>(), [
+        (true, false),
+        (true, false),
+        (true, false),
+        (false, true),
+        (false, true),
+        (false, true),
+        (false, false),
+    ]);
+
+    assert!(!Codepoint::from_surrogate(0xD800).unwrap().default_ignorable());
+}
@@ -0,0 +1,35 @@
+use unicode_analyze::Text;
+
+#[test]
+fn lines() {
+    let texts = Text::parse_reader(&b"Hello,\r\nWorld!\n\xFF"[..])
+        .map(|text| text.unwrap().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(texts, [
+        "['H', 'e', 'l', 'l', 'o', ',', [CR + LF]]",
+        "['W', 'o', 'r', 'l', 'd', '!', LF]",
+        "[0xFF]",
+    ]);
+}
+
+#[test]
+fn long_lines() {
+    let input = "é".repeat(40_000);
+    let texts = Text::parse_reader(input.as_bytes()).map(Result::unwrap).collect::<Vec<_>>();
+
+    assert!(texts.len() > 1);
+    assert!(texts.iter().flat_map(Text::codepoints).all(|c| c.value() == Ok('é')));
+    assert_eq!(texts.iter().map(|text| text.codepoints().count()).sum::<usize>(), 40_000);
+}
+
+#[test]
+fn long_lines_keep_graphemes() {
+    // The ZWJ sequences straddle the point where the first chunk of the line ends.
+    let input = format!("{}{}", "a".repeat(65_520), "👩\u{200D}👩\u{200D}👧".repeat(4));
+    let texts = Text::parse_reader(input.as_bytes()).map(Result::unwrap).collect::<Vec<_>>();
+
+    assert!(texts.len() > 1);
+    let text = texts.into_iter().flat_map(Text::into_graphemes).collect::<Text>();
+    assert_eq!(text, Text::parse_str(&input));
+}
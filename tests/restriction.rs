@@ -0,0 +1,37 @@
+use unicode_analyze::{AugmentedScript, RestrictionLevel as Level, Script, Text};
+
+fn restriction(s: &str) -> (Level, Option<Vec<&'static str>>, String) {
+    let text = Text::parse_str(s);
+    let restriction = text.restriction();
+    let scripts = restriction.scripts.map(|scripts| {
+        scripts.into_iter().map(AugmentedScript::short_name).collect()
+    });
+    let downgraded_by = restriction.downgraded_by.iter().map(|c| c.value().unwrap()).collect();
+    (restriction.level, scripts, downgraded_by)
+}
+
+#[test]
+fn levels() {
+    assert_eq!(restriction("paypal"), (Level::AsciiOnly, Some(vec!["Latn"]), String::new()));
+    assert_eq!(restriction("42"), (Level::AsciiOnly, None, String::new()));
+    assert_eq!(restriction("café"), (Level::SingleScript, Some(vec!["Latn"]), "é".into()));
+    assert_eq!(restriction("abc日本かな"), (Level::HighlyRestrictive, Some(vec![]), "abc".into()));
+    assert_eq!(restriction("abcاب"), (Level::ModeratelyRestrictive, Some(vec![]), "اب".into()));
+    assert_eq!(restriction("pаypal"), (Level::MinimallyRestrictive, Some(vec![]), "а".into()));
+    assert_eq!(restriction("a—b"), (Level::Unrestricted, Some(vec!["Latn"]), "—".into()));
+}
+
+#[test]
+fn augmented_scripts() {
+    let (level, scripts, _) = restriction("日本かな");
+    assert_eq!(level, Level::SingleScript);
+    assert_eq!(scripts, Some(vec!["Jpan"]));
+
+    let script = Script::from_name("Han").unwrap();
+    let scripts = Text::parse_str("日本").restriction().scripts.unwrap();
+    assert!(scripts.contains(&AugmentedScript::Script(script)));
+
+    // Invalid data is never allowed in identifiers.
+    let text = Text::parse_bytes(b"ab\xFF");
+    assert_eq!(text.restriction().level, Level::Unrestricted);
+}
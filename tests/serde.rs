@@ -0,0 +1,63 @@
+#![cfg(feature = "serde")]
+
+use unicode_analyze::{Codepoint, Direction, Encoding, Grapheme, Kind, Script, Text};
+
+#[test]
+fn round_trip() {
+    let text = Text::parse_bytes(b"e\xCC\x81\xFF\xE2\x80\x8D");
+    let json = serde_json::to_string(&text).unwrap();
+    assert_eq!(json, concat!(
+        r#"[[{"value":{"scalar":"e"},"offset":{"byte":0,"char":0,"utf16":0}},"#,
+        r#"{"value":{"scalar":"́"},"offset":{"byte":1,"char":1,"utf16":1}}],"#,
+        r#"[{"value":{"invalid":[255]},"offset":{"byte":3,"char":2,"utf16":2}}],"#,
+        r#"[{"value":{"scalar":"‍"},"offset":{"byte":4,"char":3,"utf16":3}}]]"#,
+    ));
+
+    let parsed = serde_json::from_str::<Text>(&json).unwrap();
+    assert_eq!(parsed, text);
+    assert_eq!(parsed.span(), text.span());
+    assert_eq!(parsed.to_string(), text.to_string());
+
+    let names = parsed.codepoints().map(Codepoint::name).collect::<Vec<_>>();
+    assert_eq!(names, [
+        "LATIN SMALL LETTER E",
+        "COMBINING ACUTE ACCENT",
+        "INVALID UTF-8 BYTE",
+        "ZERO WIDTH JOINER",
+    ]);
+}
+
+#[test]
+fn properties() {
+    assert_eq!(serde_json::to_string(&Kind::ControlCode).unwrap(), r#""control_code""#);
+    assert_eq!(serde_json::from_str::<Direction>(r#""rtl""#).unwrap(), Direction::Rtl);
+    assert_eq!(serde_json::to_string(&Script::from_name("Latin")).unwrap(), r#""Latn""#);
+    assert!(serde_json::from_str::<Script>(r#""Klingon""#).is_err());
+}
+
+#[test]
+fn invalid_sequences() {
+    let text = Text::parse_bytes(b"\xE2\x80");
+    let json = serde_json::to_string(&text).unwrap();
+    assert_eq!(serde_json::from_str::<Text>(&json).unwrap(), text);
+
+    let split = r#"[{"value":{"invalid":[226,128,65]},"offset":{"byte":0,"char":0,"utf16":0}}]"#;
+    assert!(serde_json::from_str::<Grapheme>(split).is_err());
+}
+
+#[test]
+fn encodings() {
+    let text = Text::decode(b"A\0\x00\xD8", Encoding::Utf16Le);
+    let json = serde_json::to_string(&text).unwrap();
+    assert!(json.contains(r#""encoding":"utf-16le""#));
+
+    let parsed = serde_json::from_str::<Text>(&json).unwrap();
+    assert_eq!(parsed.span(), text.span());
+    assert_eq!(parsed.to_string(), text.to_string());
+    assert_eq!(parsed.codepoints().last().unwrap().name(), "UNPAIRED SURROGATE U+D800");
+
+    let text = Text::decode(b"\xED\xA0\x80", Encoding::Wtf8);
+    let json = serde_json::to_string(&text).unwrap();
+    assert!(json.contains(r#""value":{"surrogate":55296}"#));
+    assert_eq!(serde_json::from_str::<Text>(&json).unwrap(), text);
+}